@@ -3,7 +3,7 @@
 //! simplicity, in reality you may want to store a lot more information.
 
 use std::env;
-use twilight_gateway::{Event, EventTypeFlags, Intents, Shard, ShardId, StreamExt as _};
+use twilight_gateway::{Event, EventTypeFlags, Intents, Shard, ShardId};
 use twilight_http::Client;
 
 mod models;
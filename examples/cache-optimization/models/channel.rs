@@ -60,4 +60,10 @@ impl CacheableChannel for MinimalCachedChannel {
     fn set_last_pin_timestamp(&mut self, _timestamp: Option<Timestamp>) {
         // We don't store this information, so this is a no-op
     }
+
+    fn merge_partial(&mut self, partial: Channel) {
+        self.kind = partial.kind;
+        self.guild_id = partial.guild_id.or(self.guild_id);
+        self.parent_id = partial.parent_id.or(self.parent_id);
+    }
 }
@@ -1,7 +1,7 @@
 use twilight_cache_inmemory::CacheableGuild;
 use twilight_model::{
     gateway::payload::incoming::GuildUpdate,
-    guild::Guild,
+    guild::GatewayGuild,
     id::{
         marker::{GuildMarker, UserMarker},
         Id,
@@ -15,8 +15,8 @@ pub struct MinimalCachedGuild {
     pub member_count: Option<u64>,
 }
 
-impl From<Guild> for MinimalCachedGuild {
-    fn from(guild: Guild) -> Self {
+impl From<GatewayGuild> for MinimalCachedGuild {
+    fn from(guild: GatewayGuild) -> Self {
         Self {
             id: guild.id,
             owner_id: guild.owner_id,
@@ -25,8 +25,8 @@ impl From<Guild> for MinimalCachedGuild {
     }
 }
 
-impl PartialEq<Guild> for MinimalCachedGuild {
-    fn eq(&self, other: &Guild) -> bool {
+impl PartialEq<GatewayGuild> for MinimalCachedGuild {
+    fn eq(&self, other: &GatewayGuild) -> bool {
         self.id == other.id
             && self.owner_id == other.owner_id
             && self.member_count == other.member_count
@@ -52,6 +52,10 @@ impl CacheableGuild for MinimalCachedGuild {
         self.member_count = guild_update.member_count;
     }
 
+    fn member_count(&self) -> Option<u64> {
+        self.member_count
+    }
+
     fn increase_member_count(&mut self, amount: u64) {
         if let Some(count) = self.member_count.as_mut() {
             *count += amount;
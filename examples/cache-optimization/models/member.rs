@@ -96,4 +96,13 @@ impl CacheableMember for MinimalCachedMember {
         self.roles.clone_from(&member_update.roles);
         self.avatar = member_update.avatar;
     }
+
+    fn update_with_partial_member(&mut self, member: &PartialMember) {
+        self.roles.clone_from(&member.roles);
+        self.avatar = member.avatar;
+    }
+
+    fn remove_role(&mut self, role_id: Id<RoleMarker>) {
+        self.roles.retain(|id| *id != role_id);
+    }
 }
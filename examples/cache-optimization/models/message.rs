@@ -1,27 +1,38 @@
 use twilight_cache_inmemory::CacheableMessage;
 use twilight_model::{
     channel::{message::Reaction, Message},
-    id::{marker::MessageMarker, Id},
+    id::{
+        marker::{MessageMarker, UserMarker},
+        Id,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MinimalCachedMessage {
+    pub author: Id<UserMarker>,
     pub id: Id<MessageMarker>,
     pub content: String,
+    pub mentions: Vec<Id<UserMarker>>,
 }
 
 impl From<Message> for MinimalCachedMessage {
     fn from(message: Message) -> Self {
         Self {
+            author: message.author.id,
             id: message.id,
             content: message.content,
+            mentions: message
+                .mentions
+                .into_iter()
+                .map(|mention| mention.id)
+                .collect(),
         }
     }
 }
 
 impl PartialEq<Message> for MinimalCachedMessage {
     fn eq(&self, other: &Message) -> bool {
-        self.id == other.id && self.content == other.content
+        self.id == other.id && self.author == other.author.id && self.content == other.content
     }
 }
 
@@ -30,10 +41,18 @@ impl CacheableMessage for MinimalCachedMessage {
         // No-op
     }
 
+    fn author(&self) -> Id<UserMarker> {
+        self.author
+    }
+
     fn clear_reactions(&mut self) {
         // No-op
     }
 
+    fn mentions(&self) -> &[Id<UserMarker>] {
+        &self.mentions
+    }
+
     fn reactions(&self) -> &[Reaction] {
         &[]
     }
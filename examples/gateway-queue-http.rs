@@ -6,9 +6,7 @@ use hyper_util::{
 };
 use std::env;
 use tokio::sync::oneshot;
-use twilight_gateway::{
-    queue::Queue, ConfigBuilder, EventTypeFlags, Intents, Shard, ShardId, StreamExt as _,
-};
+use twilight_gateway::{queue::Queue, ConfigBuilder, EventTypeFlags, Intents, Shard, ShardId};
 
 #[derive(Debug)]
 struct HttpQueue(Client<HttpConnector, Empty<Bytes>>);
@@ -6,9 +6,7 @@ use std::{
 };
 use tokio::{task::JoinSet, time};
 use tokio_stream::{StreamExt as _, StreamMap};
-use twilight_gateway::{
-    Config, ConfigBuilder, EventTypeFlags, Intents, Shard, ShardId, StreamExt as _,
-};
+use twilight_gateway::{Config, ConfigBuilder, EventTypeFlags, Intents, Shard, ShardId};
 use twilight_http::Client;
 
 #[tokio::main]
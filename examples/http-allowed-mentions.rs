@@ -26,7 +26,7 @@ async fn main() -> anyhow::Result<()> {
 
     client
         .create_message(channel_id)
-        .content(&format!(
+        .content(format!(
             "<@{user_id}> you are not allowed to ping @everyone!"
         ))
         .allowed_mentions(Some(&allowed_mentions))
@@ -14,7 +14,7 @@ async fn main() -> anyhow::Result<()> {
     future::join_all((1u8..=10).map(|x| {
         client
             .create_message(channel_id)
-            .content(&format!("Ping #{x}"))
+            .content(format!("Ping #{x}"))
             .into_future()
     }))
     .await;
@@ -5,9 +5,7 @@ use hyper_util::{
     rt::TokioExecutor,
 };
 use std::{env, future::Future, net::SocketAddr, str::FromStr, sync::Arc};
-use twilight_gateway::{
-    Event, EventTypeFlags, Intents, MessageSender, Shard, ShardId, StreamExt as _,
-};
+use twilight_gateway::{Event, EventTypeFlags, Intents, MessageSender, Shard, ShardId};
 use twilight_http::Client as HttpClient;
 use twilight_lavalink::{
     http::LoadedTracks,
@@ -131,7 +129,7 @@ async fn join(msg: Message, state: State) -> anyhow::Result<()> {
     state
         .http
         .create_message(msg.channel_id)
-        .content(&format!("Joined <#{channel_id}>!"))
+        .content(format!("Joined <#{channel_id}>!"))
         .await?;
 
     Ok(())
@@ -234,7 +232,7 @@ async fn pause(msg: Message, state: State) -> anyhow::Result<()> {
     state
         .http
         .create_message(msg.channel_id)
-        .content(&format!("{action} the track"))
+        .content(format!("{action} the track"))
         .await?;
 
     Ok(())
@@ -268,7 +266,7 @@ async fn seek(msg: Message, state: State) -> anyhow::Result<()> {
     state
         .http
         .create_message(msg.channel_id)
-        .content(&format!("Seeked to {position}s"))
+        .content(format!("Seeked to {position}s"))
         .await?;
 
     Ok(())
@@ -332,7 +330,7 @@ async fn volume(msg: Message, state: State) -> anyhow::Result<()> {
     state
         .http
         .create_message(msg.channel_id)
-        .content(&format!("Set the volume to {volume}"))
+        .content(format!("Set the volume to {volume}"))
         .await?;
 
     Ok(())
@@ -0,0 +1,169 @@
+use std::{env, fs, future::Future, mem, sync::Arc};
+use twilight_gateway::{Event, EventTypeFlags, Intents, MessageSender, Shard, ShardId};
+use twilight_http::Client as HttpClient;
+use twilight_model::{
+    channel::Message,
+    gateway::payload::outgoing::UpdateVoiceState,
+    id::{marker::UserMarker, Id},
+};
+use twilight_standby::Standby;
+use twilight_voice::{Connection, ConnectionInfo};
+
+type State = Arc<StateRef>;
+
+#[derive(Debug)]
+struct StateRef {
+    frames: Vec<Vec<u8>>,
+    http: HttpClient,
+    sender: MessageSender,
+    standby: Standby,
+    user_id: Id<UserMarker>,
+}
+
+fn spawn(fut: impl Future<Output = anyhow::Result<()>> + Send + 'static) {
+    tokio::spawn(async move {
+        if let Err(why) = fut.await {
+            tracing::debug!("handler error: {why:?}");
+        }
+    });
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Initialize the tracing subscriber.
+    tracing_subscriber::fmt::init();
+
+    let (mut shard, state) = {
+        let token = env::var("DISCORD_TOKEN")?;
+        // Path to an Ogg Opus file encoded as stereo at 48 kHz with 20
+        // millisecond frames, such as one created with
+        // `opusenc --framesize 20 input.wav clip.opus`.
+        let frames = opus_packets(&fs::read(env::var("OPUS_FILE")?)?)?;
+
+        let http = HttpClient::new(token.clone());
+        let user_id = http.current_user().await?.model().await?.id;
+
+        let intents =
+            Intents::GUILD_MESSAGES | Intents::GUILD_VOICE_STATES | Intents::MESSAGE_CONTENT;
+        let shard = Shard::new(ShardId::ONE, token, intents);
+        let sender = shard.sender();
+
+        (
+            shard,
+            Arc::new(StateRef {
+                frames,
+                http,
+                sender,
+                standby: Standby::new(),
+                user_id,
+            }),
+        )
+    };
+
+    while let Some(item) = shard.next_event(EventTypeFlags::all()).await {
+        let Ok(event) = item else {
+            tracing::warn!(source = ?item.unwrap_err(), "error receiving event");
+
+            continue;
+        };
+
+        state.standby.process(&event);
+
+        if let Event::MessageCreate(msg) = event {
+            if msg.guild_id.is_some() && msg.content.starts_with("!play") {
+                spawn(play(msg.0, Arc::clone(&state)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn play(msg: Message, state: State) -> anyhow::Result<()> {
+    let guild_id = msg.guild_id.expect("known to be present");
+    let Some(channel_id) = msg
+        .content
+        .split_whitespace()
+        .nth(1)
+        .and_then(|id| id.parse().ok())
+    else {
+        state
+            .http
+            .create_message(msg.channel_id)
+            .content("Usage: `!play <voice channel ID>`")
+            .await?;
+
+        return Ok(());
+    };
+
+    // Wait for both voice events before joining so that neither is missed.
+    let user_id = state.user_id;
+    let voice_state = state.standby.wait_for(guild_id, move |event: &Event| {
+        matches!(event, Event::VoiceStateUpdate(update) if update.user_id == user_id)
+    });
+    let voice_server = state.standby.wait_for(guild_id, |event: &Event| {
+        matches!(event, Event::VoiceServerUpdate(_))
+    });
+
+    state.sender.command(&UpdateVoiceState::new(
+        guild_id,
+        Some(channel_id),
+        true,
+        false,
+    ))?;
+
+    let (Event::VoiceStateUpdate(voice_state), Event::VoiceServerUpdate(voice_server)) =
+        tokio::try_join!(voice_state, voice_server)?
+    else {
+        unreachable!("checked by standby");
+    };
+    let info = ConnectionInfo::from_updates(&voice_state.0, &voice_server)
+        .ok_or_else(|| anyhow::anyhow!("voice server has no endpoint"))?;
+
+    let mut connection = Connection::connect(&info).await?;
+    tracing::debug!(mode = ?connection.mode(), "connected to voice server");
+    connection.play(&state.frames).await?;
+
+    state
+        .sender
+        .command(&UpdateVoiceState::new(guild_id, None, false, false))?;
+
+    Ok(())
+}
+
+/// Read the Opus packets of an Ogg Opus file, skipping its identification and
+/// comment headers.
+fn opus_packets(data: &[u8]) -> anyhow::Result<Vec<Vec<u8>>> {
+    let mut packets = Vec::new();
+    let mut packet = Vec::new();
+    let mut rest = data;
+
+    while !rest.is_empty() {
+        anyhow::ensure!(
+            rest.len() >= 27 && rest.starts_with(b"OggS"),
+            "invalid ogg page"
+        );
+
+        let segment_count = usize::from(rest[26]);
+        let segments = rest
+            .get(27..27 + segment_count)
+            .ok_or_else(|| anyhow::anyhow!("truncated ogg page"))?;
+        rest = &rest[27 + segment_count..];
+
+        for &len in segments {
+            let len = usize::from(len);
+            let segment = rest
+                .get(..len)
+                .ok_or_else(|| anyhow::anyhow!("truncated ogg page"))?;
+            packet.extend_from_slice(segment);
+            rest = &rest[len..];
+
+            // Packets continue across segments of the maximum length.
+            if len < 255 {
+                packets.push(mem::take(&mut packet));
+            }
+        }
+    }
+
+    Ok(packets.into_iter().skip(2).collect())
+}
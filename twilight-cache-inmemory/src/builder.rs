@@ -1,10 +1,12 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::{sync::Arc, time::SystemTime};
+use twilight_model::gateway::payload::incoming::MemberChunk;
 
 use crate::{CacheableModels, DefaultCacheModels};
 
 use super::{
-    config::{Config, ResourceType},
+    config::{ActivityDetail, Callback, Config, ResourceType},
     InMemoryCache,
 };
 
@@ -38,6 +40,111 @@ impl<CacheModels: CacheableModels> InMemoryCacheBuilder<CacheModels> {
         self
     }
 
+    /// Sets the level of detail of presence activities to store.
+    ///
+    /// Applies to presences from guilds being created and presence updates.
+    ///
+    /// Defaults to [`ActivityDetail::Full`].
+    pub const fn activity_detail(mut self, activity_detail: ActivityDetail) -> Self {
+        self.0.activity_detail = activity_detail;
+
+        self
+    }
+
+    /// Sets whether to cache direct message channels and their messages.
+    ///
+    /// When disabled, channels and messages without a guild are never cached,
+    /// regardless of the enabled [resource types]. Neither are the authors
+    /// and members of those messages.
+    ///
+    /// Defaults to true.
+    ///
+    /// [resource types]: Self::resource_types
+    pub const fn cache_dms(mut self, cache_dms: bool) -> Self {
+        self.0.cache_dms = cache_dms;
+
+        self
+    }
+
+    /// Sets a filter deciding whether to cache a member chunk.
+    ///
+    /// Chunks for which the filter returns false are not processed into the
+    /// cache, neither their members nor their presences. This is useful for
+    /// skipping large chunks requested by other parts of an application, such
+    /// as a one-off scrape of a guild's members, by their [nonce] or guild.
+    ///
+    /// Defaults to caching all member chunks.
+    ///
+    /// [nonce]: MemberChunk::nonce
+    pub fn chunk_filter(
+        mut self,
+        chunk_filter: impl Fn(&MemberChunk) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.0.chunk_filter = Some(Callback(Arc::new(chunk_filter)));
+
+        self
+    }
+
+    /// Sets the source of the current time.
+    ///
+    /// The cache consults the current time for time-dependent behavior, such
+    /// as whether a member's [communication is disabled] when calculating
+    /// permissions. This is useful for deterministic tests.
+    ///
+    /// Defaults to the [system time].
+    ///
+    /// [communication is disabled]: crate::permission#disabled-member-communication-caveats
+    /// [system time]: SystemTime::now
+    pub fn clock(mut self, clock: Arc<dyn Fn() -> SystemTime + Send + Sync>) -> Self {
+        self.0.clock = Some(Callback(clock));
+
+        self
+    }
+
+    /// Sets the number of guilds the cache is expected to hold.
+    ///
+    /// This is a hint used to pre-allocate the maps of guilds and their
+    /// resources when the cache is built, avoiding repeated rehashing as they
+    /// grow during startup. The cache may hold any number of guilds
+    /// regardless.
+    ///
+    /// Defaults to 0.
+    pub const fn expected_guilds(mut self, expected_guilds: usize) -> Self {
+        self.0.expected_guilds = expected_guilds;
+
+        self
+    }
+
+    /// Sets the number of members the cache is expected to hold, across all
+    /// guilds.
+    ///
+    /// This is a hint used to pre-allocate the map of members when the cache
+    /// is built. Refer to [`expected_guilds`] for more information.
+    ///
+    /// Defaults to 0.
+    ///
+    /// [`expected_guilds`]: Self::expected_guilds
+    pub const fn expected_members(mut self, expected_members: usize) -> Self {
+        self.0.expected_members = expected_members;
+
+        self
+    }
+
+    /// Sets the number of unique users the cache is expected to hold.
+    ///
+    /// This is a hint used to pre-allocate the maps of users and their guilds
+    /// when the cache is built. Refer to [`expected_guilds`] for more
+    /// information.
+    ///
+    /// Defaults to 0.
+    ///
+    /// [`expected_guilds`]: Self::expected_guilds
+    pub const fn expected_users(mut self, expected_users: usize) -> Self {
+        self.0.expected_users = expected_users;
+
+        self
+    }
+
     /// Sets the number of messages to cache per channel.
     ///
     /// Defaults to 100.
@@ -46,6 +153,53 @@ impl<CacheModels: CacheableModels> InMemoryCacheBuilder<CacheModels> {
 
         self
     }
+
+    /// Sets the number of previous versions to retain per edited message.
+    ///
+    /// When a cached message is edited, its previous content, embeds, and
+    /// edited timestamp are recorded in its [edit history], evicting the
+    /// oldest version beyond this number. The history is dropped along with
+    /// the message.
+    ///
+    /// Defaults to 0, retaining no previous versions.
+    ///
+    /// [edit history]: crate::model::CachedMessage::edit_history
+    pub const fn message_edit_history(mut self, message_edit_history: usize) -> Self {
+        self.0.message_edit_history = message_edit_history;
+
+        self
+    }
+
+    /// Sets whether to remove deleted roles from the roles of cached members.
+    ///
+    /// Discord doesn't send member updates when a role is deleted, so members
+    /// would otherwise keep referencing the role until they're next updated.
+    /// Removing the role iterates over all cached members of the guild, which
+    /// may be disabled for guilds with many members if stale role IDs are
+    /// acceptable.
+    ///
+    /// Defaults to true.
+    pub const fn remove_deleted_member_roles(mut self, remove_deleted_member_roles: bool) -> Self {
+        self.0.remove_deleted_member_roles = remove_deleted_member_roles;
+
+        self
+    }
+
+    /// Sets whether to retain the resources of guilds that become unavailable,
+    /// such as during an outage.
+    ///
+    /// When enabled, a guild becoming unavailable only marks it as unavailable
+    /// and leaves its channels, members, roles, and other resources intact
+    /// until the guild is available again, at which point they are replaced
+    /// wholesale. Guilds that the current user was removed from are always
+    /// purged.
+    ///
+    /// Defaults to false.
+    pub const fn retain_unavailable_guilds(mut self, retain_unavailable_guilds: bool) -> Self {
+        self.0.retain_unavailable_guilds = retain_unavailable_guilds;
+
+        self
+    }
 }
 
 impl<CacheModels: CacheableModels> Default for InMemoryCacheBuilder<CacheModels> {
@@ -0,0 +1,315 @@
+use std::cell::RefCell;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+/// Description of a resource that was changed by an update to the cache.
+///
+/// Returned by [`InMemoryCache::update_with_changes`], which is useful for
+/// invalidating downstream mirrors of the cache, such as dashboards or search
+/// indexes, without diffing the cache manually.
+///
+/// Changes are reported by the cache as it makes them, including resources
+/// removed along with their guild or channel. Only the resources listed here
+/// are reported; for example, changes to emojis, stickers, and integrations
+/// are not.
+///
+/// [`InMemoryCache::update_with_changes`]: crate::InMemoryCache::update_with_changes
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CacheChange {
+    /// A channel or thread was added.
+    ChannelAdded(Id<ChannelMarker>),
+    /// A channel or thread was removed.
+    ChannelRemoved(Id<ChannelMarker>),
+    /// A cached channel or thread was updated.
+    ChannelUpdated(Id<ChannelMarker>),
+    /// The current user was updated.
+    CurrentUserUpdated,
+    /// A guild was added.
+    GuildAdded(Id<GuildMarker>),
+    /// A guild was removed.
+    GuildRemoved(Id<GuildMarker>),
+    /// A guild was marked as unavailable.
+    GuildUnavailable(Id<GuildMarker>),
+    /// A cached guild was updated.
+    GuildUpdated(Id<GuildMarker>),
+    /// A member was added to a guild.
+    MemberAdded(Id<GuildMarker>, Id<UserMarker>),
+    /// A member was removed from a guild.
+    MemberRemoved(Id<GuildMarker>, Id<UserMarker>),
+    /// A cached member was updated.
+    MemberUpdated(Id<GuildMarker>, Id<UserMarker>),
+    /// A message was added to a channel.
+    MessageAdded(Id<ChannelMarker>, Id<MessageMarker>),
+    /// A message was removed from a channel.
+    MessageRemoved(Id<ChannelMarker>, Id<MessageMarker>),
+    /// A cached message was updated, including its reactions and poll results.
+    MessageUpdated(Id<ChannelMarker>, Id<MessageMarker>),
+    /// A member's presence in a guild was removed.
+    PresenceRemoved(Id<GuildMarker>, Id<UserMarker>),
+    /// A member's presence in a guild was added or updated.
+    PresenceUpdated(Id<GuildMarker>, Id<UserMarker>),
+    /// A role was added to a guild.
+    RoleAdded(Id<GuildMarker>, Id<RoleMarker>),
+    /// A role was removed from a guild.
+    RoleRemoved(Id<GuildMarker>, Id<RoleMarker>),
+    /// A cached role was updated.
+    RoleUpdated(Id<GuildMarker>, Id<RoleMarker>),
+    /// A member's voice state in a guild was added, updated, or removed.
+    VoiceStateChanged(Id<GuildMarker>, Id<UserMarker>),
+}
+
+thread_local! {
+    /// Changes made by the update running on this thread, if it reports them.
+    static CHANGES: RefCell<Option<Vec<CacheChange>>> = const { RefCell::new(None) };
+}
+
+/// Collect the changes made to the cache while running an update.
+///
+/// Updates are synchronous, so the changes reported on this thread while the
+/// update runs are the ones it made.
+pub(crate) fn collect(update: impl FnOnce()) -> Vec<CacheChange> {
+    /// Restores the previous collector, even if the update panics.
+    struct Restore(Option<Vec<CacheChange>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CHANGES.with_borrow_mut(|changes| *changes = previous);
+        }
+    }
+
+    let restore = Restore(CHANGES.with_borrow_mut(|changes| changes.replace(Vec::new())));
+    update();
+
+    let changes = CHANGES.with_borrow_mut(Option::take);
+    drop(restore);
+
+    changes.unwrap_or_default()
+}
+
+/// Report a change made to the cache to the update collecting them, if any.
+pub(crate) fn report(change: CacheChange) {
+    CHANGES.with_borrow_mut(|changes| {
+        if let Some(changes) = changes {
+            changes.push(change);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CacheChange;
+    use crate::{test, DefaultInMemoryCache, ResourceType};
+    use static_assertions::assert_impl_all;
+    use std::{borrow::Cow, fmt::Debug, hash::Hash};
+    use twilight_model::{
+        channel::message::EmojiReactionType,
+        gateway::{
+            event::Event,
+            payload::incoming::{
+                ChannelCreate, GuildCreate, GuildDelete, MemberAdd, MemberUpdate, MessageCreate,
+                MessageDelete, ReactionAdd, ReactionRemoveAll, VoiceStateUpdate,
+            },
+            GatewayReaction,
+        },
+        id::Id,
+    };
+
+    assert_impl_all!(CacheChange: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn guild() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let mut guild = test::guild(guild_id, None);
+        let create = Event::GuildCreate(Box::new(GuildCreate::Available(guild.clone())));
+
+        assert_eq!(
+            [CacheChange::GuildAdded(guild_id)].as_slice(),
+            cache.update_with_changes(&create),
+        );
+        assert!(cache.update_with_changes(&create).is_empty());
+
+        guild.name = "renamed".to_owned();
+        let update = Event::GuildCreate(Box::new(GuildCreate::Available(guild)));
+
+        assert_eq!(
+            [CacheChange::GuildUpdated(guild_id)].as_slice(),
+            cache.update_with_changes(&update),
+        );
+
+        let delete = Event::GuildDelete(GuildDelete {
+            id: guild_id,
+            unavailable: None,
+        });
+
+        assert_eq!(
+            [CacheChange::GuildRemoved(guild_id)].as_slice(),
+            cache.update_with_changes(&delete),
+        );
+        assert!(cache.update_with_changes(&delete).is_empty());
+    }
+
+    /// Test that resources removed along with their guild are reported.
+    #[test]
+    fn guild_delete_resources() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let message = test::message();
+
+        cache.update(&Event::GuildCreate(Box::new(GuildCreate::Available(
+            test::guild(guild_id, None),
+        ))));
+        cache.update(&Event::ChannelCreate(Box::new(ChannelCreate(channel))));
+        cache.update(&Event::MessageCreate(Box::new(MessageCreate(
+            message.clone(),
+        ))));
+
+        let changes = cache.update_with_changes(&Event::GuildDelete(GuildDelete {
+            id: guild_id,
+            unavailable: None,
+        }));
+
+        assert_eq!(
+            [
+                CacheChange::GuildRemoved(guild_id),
+                CacheChange::MessageRemoved(channel_id, message.id),
+                CacheChange::ChannelRemoved(channel_id),
+                CacheChange::MemberRemoved(guild_id, message.author.id),
+            ]
+            .as_slice(),
+            changes,
+        );
+    }
+
+    #[test]
+    fn member() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let member = test::member(user_id);
+
+        assert_eq!(
+            [CacheChange::MemberAdded(guild_id, user_id)].as_slice(),
+            cache.update_with_changes(&Event::MemberAdd(Box::new(MemberAdd {
+                guild_id,
+                member: member.clone(),
+            }))),
+        );
+
+        let update = Event::MemberUpdate(Box::new(MemberUpdate {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: None,
+            flags: None,
+            guild_id,
+            joined_at: member.joined_at,
+            mute: None,
+            nick: Some("nick".to_owned()),
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user: member.user.clone(),
+        }));
+
+        assert_eq!(
+            [CacheChange::MemberUpdated(guild_id, user_id)].as_slice(),
+            cache.update_with_changes(&update),
+        );
+        assert!(DefaultInMemoryCache::new()
+            .update_with_changes(&update)
+            .is_empty());
+    }
+
+    #[test]
+    fn message() {
+        let cache = DefaultInMemoryCache::new();
+        let message = test::message();
+        let channel_id = message.channel_id;
+
+        assert_eq!(
+            [
+                CacheChange::MemberAdded(message.guild_id.unwrap(), message.author.id),
+                CacheChange::MessageAdded(channel_id, message.id),
+            ]
+            .as_slice(),
+            cache.update_with_changes(&Event::MessageCreate(Box::new(MessageCreate(
+                message.clone()
+            )))),
+        );
+        assert_eq!(
+            [CacheChange::MessageUpdated(channel_id, message.id)].as_slice(),
+            cache.update_with_changes(&Event::ReactionAdd(Box::new(ReactionAdd(
+                GatewayReaction {
+                    burst: false,
+                    burst_colors: Vec::new(),
+                    channel_id,
+                    emoji: EmojiReactionType::Unicode {
+                        name: "😀".to_owned(),
+                    },
+                    guild_id: message.guild_id,
+                    member: None,
+                    message_author_id: None,
+                    message_id: message.id,
+                    user_id: Id::new(5),
+                }
+            )))),
+        );
+
+        let remove_all = Event::ReactionRemoveAll(ReactionRemoveAll {
+            channel_id,
+            guild_id: message.guild_id,
+            message_id: message.id,
+        });
+
+        assert_eq!(
+            [CacheChange::MessageUpdated(channel_id, message.id)].as_slice(),
+            cache.update_with_changes(&remove_all),
+        );
+        assert!(cache.update_with_changes(&remove_all).is_empty());
+
+        let delete = Event::MessageDelete(MessageDelete {
+            channel_id,
+            guild_id: message.guild_id,
+            id: message.id,
+        });
+
+        assert_eq!(
+            [CacheChange::MessageRemoved(channel_id, message.id)].as_slice(),
+            cache.update_with_changes(&delete),
+        );
+        assert!(cache.update_with_changes(&delete).is_empty());
+    }
+
+    #[test]
+    fn unwanted() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::GUILD)
+            .build();
+        let message = test::message();
+
+        assert!(cache
+            .update_with_changes(&Event::MessageCreate(Box::new(MessageCreate(message))))
+            .is_empty());
+    }
+
+    #[test]
+    fn voice_state() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let voice_state = test::voice_state(guild_id, Some(Id::new(3)), user_id);
+
+        cache.cache_user(Cow::Owned(test::user(user_id)), Some(guild_id));
+
+        assert_eq!(
+            [CacheChange::VoiceStateChanged(guild_id, user_id)].as_slice(),
+            cache.update_with_changes(&Event::VoiceStateUpdate(Box::new(VoiceStateUpdate(
+                voice_state
+            )))),
+        );
+    }
+}
@@ -1,4 +1,10 @@
 use bitflags::bitflags;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::SystemTime,
+};
+use twilight_model::gateway::payload::incoming::MemberChunk;
 
 bitflags! {
     /// A set of bitflags which can be used to specify what resource to process
@@ -39,16 +45,81 @@ bitflags! {
         const STICKER = 1 << 13;
         /// Information relating to guild scheduled events.
         const GUILD_SCHEDULED_EVENT = 1 << 14;
+        /// Information relating to guild invites.
+        const INVITE = 1 << 15;
+        /// Information relating to entitlements of users.
+        const ENTITLEMENT = 1 << 16;
     }
 }
 
+/// Level of detail of the presence activities stored in the cache.
+///
+/// Activities may carry large payloads, such as rich presence assets and
+/// buttons, that most users never read. Reducing the detail can considerably
+/// lower memory usage when caching presences of many users.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ActivityDetail {
+    /// Store activities as received.
+    #[default]
+    Full,
+    /// Store only the [type] and [name] of activities.
+    ///
+    /// [name]: twilight_model::gateway::presence::Activity::name
+    /// [type]: twilight_model::gateway::presence::Activity::kind
+    NameOnly,
+    /// Store no activities, only the status and client status of presences.
+    StatusOnly,
+}
+
+/// Function provided by the user to customize the cache's behavior.
+///
+/// Callbacks are compared by identity, as functions can't be compared.
+pub(super) struct Callback<F: ?Sized>(pub(super) Arc<F>);
+
+impl<F: ?Sized> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> Debug for Callback<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Callback").finish_non_exhaustive()
+    }
+}
+
+impl<F: ?Sized> Eq for Callback<F> {}
+
+impl<F: ?Sized> PartialEq for Callback<F> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Predicate deciding whether a [`MemberChunk`] is processed into the cache.
+pub(super) type ChunkFilter = Callback<dyn Fn(&MemberChunk) -> bool + Send + Sync>;
+
+/// Source of the current time.
+pub(super) type Clock = Callback<dyn Fn() -> SystemTime + Send + Sync>;
+
 /// Configuration for an [`InMemoryCache`].
 ///
 /// [`InMemoryCache`]: crate::InMemoryCache
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
+    pub(super) activity_detail: ActivityDetail,
+    pub(super) cache_dms: bool,
+    pub(super) chunk_filter: Option<ChunkFilter>,
+    pub(super) clock: Option<Clock>,
+    pub(super) expected_guilds: usize,
+    pub(super) expected_members: usize,
+    pub(super) expected_users: usize,
     pub(super) resource_types: ResourceType,
     pub(super) message_cache_size: usize,
+    pub(super) message_edit_history: usize,
+    pub(super) remove_deleted_member_roles: bool,
+    pub(super) retain_unavailable_guilds: bool,
 }
 
 impl Config {
@@ -57,11 +128,113 @@ impl Config {
     /// Refer to individual getters for their defaults.
     pub const fn new() -> Self {
         Self {
+            activity_detail: ActivityDetail::Full,
+            cache_dms: true,
+            chunk_filter: None,
+            clock: None,
+            expected_guilds: 0,
+            expected_members: 0,
+            expected_users: 0,
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            message_edit_history: 0,
+            remove_deleted_member_roles: true,
+            retain_unavailable_guilds: false,
         }
     }
 
+    /// Returns the level of detail of stored presence activities.
+    ///
+    /// Defaults to [`ActivityDetail::Full`].
+    pub const fn activity_detail(&self) -> ActivityDetail {
+        self.activity_detail
+    }
+
+    /// Returns a mutable reference to the level of detail of stored presence
+    /// activities.
+    pub fn activity_detail_mut(&mut self) -> &mut ActivityDetail {
+        &mut self.activity_detail
+    }
+
+    /// Returns whether direct message channels and their messages are cached.
+    ///
+    /// Defaults to true.
+    pub const fn cache_dms(&self) -> bool {
+        self.cache_dms
+    }
+
+    /// Returns a mutable reference to whether direct message channels and
+    /// their messages are cached.
+    pub fn cache_dms_mut(&mut self) -> &mut bool {
+        &mut self.cache_dms
+    }
+
+    /// Returns the filter deciding whether member chunks are cached, if any.
+    ///
+    /// Defaults to no filter, caching all member chunks.
+    pub fn chunk_filter(&self) -> Option<&(dyn Fn(&MemberChunk) -> bool + Send + Sync)> {
+        self.chunk_filter.as_ref().map(|filter| &*filter.0)
+    }
+
+    /// Whether a member chunk passes the configured filter.
+    pub(crate) fn wants_chunk(&self, chunk: &MemberChunk) -> bool {
+        self.chunk_filter().map_or(true, |filter| filter(chunk))
+    }
+
+    /// Returns the source of the current time, if one was configured.
+    ///
+    /// Defaults to none, using the [system time].
+    ///
+    /// [system time]: SystemTime::now
+    pub fn clock(&self) -> Option<&(dyn Fn() -> SystemTime + Send + Sync)> {
+        self.clock.as_ref().map(|clock| &*clock.0)
+    }
+
+    /// Current time according to the configured clock.
+    #[cfg(feature = "permission-calculator")]
+    pub(crate) fn now(&self) -> SystemTime {
+        self.clock().map_or_else(SystemTime::now, |clock| clock())
+    }
+
+    /// Returns the number of guilds the cache is expected to hold.
+    ///
+    /// Defaults to 0.
+    pub const fn expected_guilds(&self) -> usize {
+        self.expected_guilds
+    }
+
+    /// Returns a mutable reference to the number of guilds the cache is
+    /// expected to hold.
+    pub fn expected_guilds_mut(&mut self) -> &mut usize {
+        &mut self.expected_guilds
+    }
+
+    /// Returns the number of members the cache is expected to hold.
+    ///
+    /// Defaults to 0.
+    pub const fn expected_members(&self) -> usize {
+        self.expected_members
+    }
+
+    /// Returns a mutable reference to the number of members the cache is
+    /// expected to hold.
+    pub fn expected_members_mut(&mut self) -> &mut usize {
+        &mut self.expected_members
+    }
+
+    /// Returns the number of users the cache is expected to hold.
+    ///
+    /// Defaults to 0.
+    pub const fn expected_users(&self) -> usize {
+        self.expected_users
+    }
+
+    /// Returns a mutable reference to the number of users the cache is
+    /// expected to hold.
+    pub fn expected_users_mut(&mut self) -> &mut usize {
+        &mut self.expected_users
+    }
+
     /// Returns an immutable reference to the message cache size.
     ///
     /// Defaults to 100.
@@ -73,6 +246,33 @@ impl Config {
     pub fn message_cache_size_mut(&mut self) -> &mut usize {
         &mut self.message_cache_size
     }
+
+    /// Returns the number of previous versions retained per edited message.
+    ///
+    /// Defaults to 0, retaining no previous versions.
+    pub const fn message_edit_history(&self) -> usize {
+        self.message_edit_history
+    }
+
+    /// Returns a mutable reference to the number of previous versions retained
+    /// per edited message.
+    pub fn message_edit_history_mut(&mut self) -> &mut usize {
+        &mut self.message_edit_history
+    }
+
+    /// Returns whether deleted roles are removed from cached members.
+    ///
+    /// Defaults to true.
+    pub const fn remove_deleted_member_roles(&self) -> bool {
+        self.remove_deleted_member_roles
+    }
+
+    /// Returns a mutable reference to whether deleted roles are removed from
+    /// cached members.
+    pub fn remove_deleted_member_roles_mut(&mut self) -> &mut bool {
+        &mut self.remove_deleted_member_roles
+    }
+
     /// Returns an immutable reference to the resource types enabled.
     ///
     /// Defaults to all resource types.
@@ -84,6 +284,20 @@ impl Config {
     pub fn resource_types_mut(&mut self) -> &mut ResourceType {
         &mut self.resource_types
     }
+
+    /// Returns whether the resources of guilds that become unavailable are
+    /// retained.
+    ///
+    /// Defaults to false.
+    pub const fn retain_unavailable_guilds(&self) -> bool {
+        self.retain_unavailable_guilds
+    }
+
+    /// Returns a mutable reference to whether the resources of guilds that
+    /// become unavailable are retained.
+    pub fn retain_unavailable_guilds_mut(&mut self) -> &mut bool {
+        &mut self.retain_unavailable_guilds
+    }
 }
 
 impl Default for Config {
@@ -94,19 +308,55 @@ impl Default for Config {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, ResourceType};
+    use super::{ActivityDetail, Config, ResourceType};
     use static_assertions::assert_fields;
 
-    assert_fields!(Config: resource_types, message_cache_size);
+    assert_fields!(
+        Config: activity_detail,
+        cache_dms,
+        chunk_filter,
+        clock,
+        resource_types,
+        message_cache_size,
+        message_edit_history,
+        remove_deleted_member_roles,
+        retain_unavailable_guilds
+    );
 
     #[test]
     fn defaults() {
         let conf = Config {
+            activity_detail: ActivityDetail::Full,
+            cache_dms: true,
+            chunk_filter: None,
+            clock: None,
+            expected_guilds: 0,
+            expected_members: 0,
+            expected_users: 0,
             resource_types: ResourceType::all(),
             message_cache_size: 100,
+            message_edit_history: 0,
+            remove_deleted_member_roles: true,
+            retain_unavailable_guilds: false,
         };
         let default = Config::default();
+        assert_eq!(conf.activity_detail, default.activity_detail);
+        assert_eq!(conf.cache_dms, default.cache_dms);
+        assert!(default.chunk_filter.is_none());
+        assert!(default.clock.is_none());
+        assert_eq!(conf.expected_guilds, default.expected_guilds);
+        assert_eq!(conf.expected_members, default.expected_members);
+        assert_eq!(conf.expected_users, default.expected_users);
         assert_eq!(conf.resource_types, default.resource_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.message_edit_history, default.message_edit_history);
+        assert_eq!(
+            conf.remove_deleted_member_roles,
+            default.remove_deleted_member_roles
+        );
+        assert_eq!(
+            conf.retain_unavailable_guilds,
+            default.retain_unavailable_guilds
+        );
     }
 }
@@ -0,0 +1,249 @@
+use crate::{CacheableModels, InMemoryCache};
+use dashmap::DashMap;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashSet},
+    hash::Hash,
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+/// Entity to dump via [`InMemoryCache::debug_json`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum DebugEntity {
+    /// Channel by its ID.
+    Channel(Id<ChannelMarker>),
+    /// Guild by its ID.
+    Guild(Id<GuildMarker>),
+    /// Member by their guild's and user's IDs.
+    Member(Id<GuildMarker>, Id<UserMarker>),
+    /// Message by its ID.
+    Message(Id<MessageMarker>),
+    /// Role by its ID.
+    Role(Id<RoleMarker>),
+    /// User by their ID.
+    User(Id<UserMarker>),
+}
+
+impl DebugEntity {
+    /// Snake-case name of the entity's kind.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Channel(_) => "channel",
+            Self::Guild(_) => "guild",
+            Self::Member(_, _) => "member",
+            Self::Message(_) => "message",
+            Self::Role(_) => "role",
+            Self::User(_) => "user",
+        }
+    }
+}
+
+/// Serialized form of a dumped entity.
+#[derive(Serialize)]
+struct Dump<'a, T> {
+    entity: &'static str,
+    relations: BTreeMap<&'static str, usize>,
+    value: &'a T,
+}
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    /// Pretty-printed JSON of a cached entity, meant for inspecting the
+    /// cache's state while debugging.
+    ///
+    /// The output contains the entity's kind under `entity`, the cached model
+    /// under `value`, and the number of entities related to it in the cache
+    /// under `relations`, such as a guild's number of cached channels and
+    /// members. Relations are sorted by name, so dumps of the same state are
+    /// identical.
+    ///
+    /// The entity is cloned out of the cache before its relations are
+    /// counted, so that no guard is held while other maps are read.
+    ///
+    /// Returns `None` if the entity isn't cached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::{DebugEntity, DefaultInMemoryCache};
+    /// use twilight_model::id::Id;
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    ///
+    /// // later on...
+    /// if let Some(json) = cache.debug_json(DebugEntity::Guild(Id::new(1))) {
+    ///     println!("{json}");
+    /// }
+    /// ```
+    pub fn debug_json(&self, entity: DebugEntity) -> Option<String>
+    where
+        CacheModels::Channel: Serialize,
+        CacheModels::Guild: Serialize,
+        CacheModels::Member: Serialize,
+        CacheModels::Message: Serialize,
+        CacheModels::Role: Serialize,
+        CacheModels::User: Serialize,
+    {
+        match entity {
+            DebugEntity::Channel(channel_id) => {
+                let channel = self.channels.get(&channel_id)?.value().clone();
+                let relations = BTreeMap::from([
+                    (
+                        "messages",
+                        self.channel_messages
+                            .get(&channel_id)
+                            .map_or(0, |messages| messages.len()),
+                    ),
+                    ("voice_states", len(&self.voice_state_channels, &channel_id)),
+                ]);
+
+                dump(entity, relations, &channel)
+            }
+            DebugEntity::Guild(guild_id) => {
+                let guild = self.guilds.get(&guild_id)?.value().clone();
+                let relations = BTreeMap::from([
+                    ("channels", len(&self.guild_channels, &guild_id)),
+                    ("emojis", len(&self.guild_emojis, &guild_id)),
+                    ("integrations", len(&self.guild_integrations, &guild_id)),
+                    ("invites", len(&self.guild_invites, &guild_id)),
+                    ("members", len(&self.guild_members, &guild_id)),
+                    ("presences", len(&self.guild_presences, &guild_id)),
+                    ("roles", len(&self.guild_roles, &guild_id)),
+                    (
+                        "scheduled_events",
+                        len(&self.guild_scheduled_events, &guild_id),
+                    ),
+                    (
+                        "stage_instances",
+                        len(&self.guild_stage_instances, &guild_id),
+                    ),
+                    ("stickers", len(&self.guild_stickers, &guild_id)),
+                    ("voice_states", len(&self.voice_state_guilds, &guild_id)),
+                ]);
+
+                dump(entity, relations, &guild)
+            }
+            DebugEntity::Member(guild_id, user_id) => {
+                let member = self.members.get(&(guild_id, user_id))?.value().clone();
+                let relations = BTreeMap::from([
+                    ("guilds", len(&self.user_guilds, &user_id)),
+                    (
+                        "presences",
+                        usize::from(self.presences.contains_key(&(guild_id, user_id))),
+                    ),
+                    (
+                        "voice_states",
+                        usize::from(self.voice_states.contains_key(&(guild_id, user_id))),
+                    ),
+                ]);
+
+                dump(entity, relations, &member)
+            }
+            DebugEntity::Message(message_id) => {
+                let message = self.messages.get(&message_id)?;
+
+                dump(entity, BTreeMap::new(), message.value())
+            }
+            DebugEntity::Role(role_id) => {
+                let role = self.roles.get(&role_id)?;
+
+                dump(entity, BTreeMap::new(), role.resource())
+            }
+            DebugEntity::User(user_id) => {
+                let user = self.users.get(&user_id)?.value().clone();
+                let relations = BTreeMap::from([("guilds", len(&self.user_guilds, &user_id))]);
+
+                dump(entity, relations, &user)
+            }
+        }
+    }
+}
+
+/// Serialize a dump of an entity.
+fn dump<T: Serialize>(
+    entity: DebugEntity,
+    relations: BTreeMap<&'static str, usize>,
+    value: &T,
+) -> Option<String> {
+    serde_json::to_string_pretty(&Dump {
+        entity: entity.name(),
+        relations,
+        value,
+    })
+    .ok()
+}
+
+/// Number of entities in a relation set.
+fn len<K: Eq + Hash, V>(map: &DashMap<K, HashSet<V>>, key: &K) -> usize {
+    map.get(key).map_or(0, |set| set.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugEntity;
+    use crate::{test, DefaultInMemoryCache};
+    use serde_json::Value;
+    use twilight_model::{gateway::payload::incoming::GuildCreate, id::Id};
+
+    /// Test the structure of a seeded guild's dump.
+    #[test]
+    fn guild() {
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+        let mut guild = test::guild(guild_id, Some(2));
+        guild.channels = Vec::from([channel]);
+        guild.members = Vec::from([test::member(Id::new(3)), test::member(Id::new(4))]);
+        guild.roles = Vec::from([test::role(Id::new(5))]);
+
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&GuildCreate::Available(guild));
+
+        let json = cache.debug_json(DebugEntity::Guild(guild_id)).unwrap();
+        let value = serde_json::from_str::<Value>(&json).unwrap();
+
+        assert_eq!("guild", value["entity"]);
+        assert_eq!(guild_id.to_string(), value["value"]["id"]);
+        assert_eq!("test", value["value"]["name"]);
+        assert_eq!(
+            serde_json::json!({
+                "channels": 1,
+                "emojis": 0,
+                "integrations": 0,
+                "invites": 0,
+                "members": 2,
+                "presences": 0,
+                "roles": 1,
+                "scheduled_events": 0,
+                "stage_instances": 0,
+                "stickers": 0,
+                "voice_states": 0,
+            }),
+            value["relations"]
+        );
+
+        let json = cache.debug_json(DebugEntity::Channel(channel_id)).unwrap();
+        let value = serde_json::from_str::<Value>(&json).unwrap();
+        assert_eq!("channel", value["entity"]);
+        assert_eq!(
+            serde_json::json!({ "messages": 0, "voice_states": 0 }),
+            value["relations"]
+        );
+
+        let json = cache
+            .debug_json(DebugEntity::Member(guild_id, Id::new(3)))
+            .unwrap();
+        let value = serde_json::from_str::<Value>(&json).unwrap();
+        assert_eq!("member", value["entity"]);
+        assert_eq!(
+            serde_json::json!({ "guilds": 1, "presences": 0, "voice_states": 0 }),
+            value["relations"]
+        );
+
+        assert!(cache.debug_json(DebugEntity::Guild(Id::new(99))).is_none());
+        assert!(cache
+            .debug_json(DebugEntity::Message(Id::new(99)))
+            .is_none());
+    }
+}
@@ -1,8 +1,14 @@
-use crate::{traits::CacheableChannel, CacheableModels, InMemoryCache, ResourceType, UpdateCache};
+use crate::{
+    change::{self, CacheChange},
+    traits::CacheableChannel,
+    CacheableModels, InMemoryCache, ResourceType, UpdateCache,
+};
+use dashmap::mapref::entry::Entry;
 use twilight_model::{
     channel::Channel,
     gateway::payload::incoming::{ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate},
     id::{marker::ChannelMarker, Id},
+    util::Timestamp,
 };
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
@@ -12,7 +18,18 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         }
     }
 
-    pub(crate) fn cache_channel(&self, channel: Channel) {
+    pub(crate) fn cache_channel(&self, mut channel: Channel) {
+        // Apply a pins update received before the channel, unless the
+        // channel's own timestamp is more recent.
+        if let Some((_, (_, last_pin_timestamp))) = self.pending_pin_timestamps.remove(&channel.id)
+        {
+            if last_pin_timestamp.map(Timestamp::as_micros)
+                > channel.last_pin_timestamp.map(Timestamp::as_micros)
+            {
+                channel.last_pin_timestamp = last_pin_timestamp;
+            }
+        }
+
         if let Some(guild_id) = channel.guild_id {
             self.guild_channels
                 .entry(guild_id)
@@ -20,8 +37,51 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
                 .insert(channel.id);
         }
 
-        self.channels
-            .insert(channel.id, CacheModels::Channel::from(channel));
+        let channel_id = channel.id;
+        let channel = CacheModels::Channel::from(channel);
+
+        match self.channels.entry(channel_id) {
+            Entry::Occupied(entry) if *entry.get() == channel => {}
+            Entry::Occupied(mut entry) => {
+                entry.insert(channel);
+                change::report(CacheChange::ChannelUpdated(channel_id));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(channel);
+                change::report(CacheChange::ChannelAdded(channel_id));
+            }
+        }
+    }
+
+    /// Cache a partial channel, such as the one included in an interaction.
+    ///
+    /// A cached channel is updated with the fields present in the partial
+    /// channel instead of being replaced, so as not to lose fields the partial
+    /// channel lacks.
+    pub(crate) fn cache_partial_channel(&self, channel: Channel) {
+        if let Some(mut cached) = self.channels.get_mut(&channel.id) {
+            let channel_id = channel.id;
+            cached.merge_partial(channel);
+            change::report(CacheChange::ChannelUpdated(channel_id));
+
+            return;
+        }
+
+        self.cache_channel(channel);
+    }
+
+    /// Delete the cached messages of a channel.
+    ///
+    /// The channel's list of message IDs and the messages themselves will be
+    /// deleted.
+    pub(crate) fn delete_channel_messages(&self, channel_id: Id<ChannelMarker>) {
+        if let Some((_, message_ids)) = self.channel_messages.remove(&channel_id) {
+            for message_id in message_ids {
+                if self.messages.remove(&message_id).is_some() {
+                    change::report(CacheChange::MessageRemoved(channel_id, message_id));
+                }
+            }
+        }
     }
 
     /// Delete a guild channel from the cache.
@@ -29,7 +89,11 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     /// The guild channel data itself and the channel entry in its guild's list
     /// of channels will be deleted.
     pub(crate) fn delete_channel(&self, channel_id: Id<ChannelMarker>) {
+        self.pending_pin_timestamps.remove(&channel_id);
+
         if let Some((_, channel)) = self.channels.remove(&channel_id) {
+            change::report(CacheChange::ChannelRemoved(channel_id));
+
             if let Some(guild_id) = channel.guild_id() {
                 let maybe_channels = self.guild_channels.get_mut(&guild_id);
 
@@ -43,7 +107,7 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
+        if !cache.wants(ResourceType::CHANNEL) || cache.skips_dm(self.0.guild_id) {
             return;
         }
 
@@ -53,6 +117,16 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelCreate {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if cache.wants(ResourceType::MESSAGE) {
+            cache.delete_channel_messages(self.0.id);
+        }
+
+        if cache.wants(ResourceType::INVITE) {
+            if let Some(guild_id) = self.0.guild_id {
+                cache.delete_channel_invites(guild_id, self.0.id);
+            }
+        }
+
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -69,13 +143,27 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelPinsUpdat
 
         if let Some(mut channel) = cache.channels.get_mut(&self.channel_id) {
             channel.set_last_pin_timestamp(self.last_pin_timestamp);
+            change::report(CacheChange::ChannelUpdated(self.channel_id));
+
+            return;
+        }
+
+        // Guild channels, most commonly threads, may not have been cached
+        // yet. Buffer the timestamp so that it's applied once they are.
+        //
+        // Private channels aren't sent by the gateway, so buffering their
+        // timestamp would only grow the cache.
+        if let Some(guild_id) = self.guild_id {
+            cache
+                .pending_pin_timestamps
+                .insert(self.channel_id, (guild_id, self.last_pin_timestamp));
         }
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::CHANNEL) {
+        if !cache.wants(ResourceType::CHANNEL) || cache.skips_dm(self.0.guild_id) {
             return;
         }
 
@@ -86,9 +174,17 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelUpdate {
 #[cfg(test)]
 mod tests {
     use crate::{test, DefaultInMemoryCache};
-    use twilight_model::gateway::{
-        event::Event,
-        payload::incoming::{ChannelDelete, ChannelUpdate},
+    use twilight_model::{
+        channel::ChannelType,
+        gateway::{
+            event::Event,
+            payload::incoming::{
+                ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate, GuildDelete,
+                MessageCreate, ThreadCreate,
+            },
+        },
+        id::Id,
+        util::Timestamp,
     };
 
     #[test]
@@ -109,6 +205,22 @@ mod tests {
         assert!(cache.guild_channels.get(&guild_id).unwrap().is_empty());
     }
 
+    /// Test that deleting a channel deletes its cached messages.
+    #[test]
+    fn channel_delete_messages() {
+        let cache = DefaultInMemoryCache::new();
+        let (_, channel_id, channel) = test::guild_channel_text();
+
+        cache.cache_channel(channel.clone());
+        cache.update(&MessageCreate(test::message()));
+        assert_eq!(1, cache.stats().messages());
+        assert_eq!(Some(1), cache.stats().channel_messages(channel_id));
+
+        cache.update(&Event::ChannelDelete(Box::new(ChannelDelete(channel))));
+        assert_eq!(0, cache.stats().messages());
+        assert!(cache.stats().channel_messages(channel_id).is_none());
+    }
+
     #[test]
     fn channel_update_guild() {
         let cache = DefaultInMemoryCache::new();
@@ -122,4 +234,96 @@ mod tests {
             .unwrap()
             .contains(&channel_id));
     }
+
+    /// Test that a pins update received before its thread is applied once the
+    /// thread is cached.
+    #[test]
+    fn channel_pins_update_before_thread_create() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, _, mut thread) = test::guild_channel_text();
+        thread.id = Id::new(3);
+        thread.kind = ChannelType::PublicThread;
+        let last_pin_timestamp = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
+
+        cache.update(&ChannelPinsUpdate {
+            channel_id: thread.id,
+            guild_id: Some(guild_id),
+            last_pin_timestamp,
+        });
+        assert!(cache.channel(thread.id).is_none());
+
+        cache.update(&ThreadCreate(thread.clone()));
+        assert_eq!(
+            last_pin_timestamp,
+            cache.channel(thread.id).unwrap().last_pin_timestamp
+        );
+        assert!(cache.pending_pin_timestamps.is_empty());
+    }
+
+    /// Test that a buffered pins update doesn't override a more recent
+    /// timestamp of the channel itself.
+    #[test]
+    fn channel_pins_update_outdated() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, mut channel) = test::guild_channel_text();
+        let older = Timestamp::from_secs(1_632_072_645).expect("non zero");
+        let newer = Timestamp::from_secs(1_632_072_646).expect("non zero");
+        channel.last_pin_timestamp = Some(newer);
+
+        cache.update(&ChannelPinsUpdate {
+            channel_id,
+            guild_id: Some(guild_id),
+            last_pin_timestamp: Some(older),
+        });
+        cache.update(&ChannelCreate(channel));
+        assert_eq!(
+            Some(newer),
+            cache.channel(channel_id).unwrap().last_pin_timestamp
+        );
+    }
+
+    /// Test that buffered pins updates are dropped with their channel, and
+    /// that those of private channels aren't buffered.
+    #[test]
+    fn channel_pins_update_pending_removed() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, channel) = test::guild_channel_text();
+
+        cache.update(&ChannelPinsUpdate {
+            channel_id: Id::new(5),
+            guild_id: None,
+            last_pin_timestamp: None,
+        });
+        assert!(cache.pending_pin_timestamps.is_empty());
+
+        cache.update(&ChannelPinsUpdate {
+            channel_id,
+            guild_id: Some(guild_id),
+            last_pin_timestamp: None,
+        });
+        assert_eq!(1, cache.pending_pin_timestamps.len());
+
+        cache.update(&ChannelDelete(channel));
+        assert!(cache.pending_pin_timestamps.is_empty());
+    }
+
+    /// Test that buffered pins updates are dropped with their guild.
+    #[test]
+    fn channel_pins_update_pending_guild_removed() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, channel_id, _) = test::guild_channel_text();
+
+        cache.update(&ChannelPinsUpdate {
+            channel_id,
+            guild_id: Some(guild_id),
+            last_pin_timestamp: None,
+        });
+        assert_eq!(1, cache.pending_pin_timestamps.len());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: None,
+        });
+        assert!(cache.pending_pin_timestamps.is_empty());
+    }
 }
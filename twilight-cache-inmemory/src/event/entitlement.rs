@@ -0,0 +1,142 @@
+use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use dashmap::mapref::entry::Entry;
+use twilight_model::{
+    application::monetization::Entitlement,
+    gateway::payload::incoming::{EntitlementCreate, EntitlementDelete, EntitlementUpdate},
+    id::{
+        marker::{EntitlementMarker, UserMarker},
+        Id,
+    },
+};
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    fn cache_entitlement(&self, entitlement: &Entitlement) {
+        let Some(user_id) = entitlement.user_id else {
+            return;
+        };
+
+        let mut entitlements = self.entitlements.entry(user_id).or_default();
+
+        if let Some(cached) = entitlements
+            .iter_mut()
+            .find(|cached| cached.id == entitlement.id)
+        {
+            cached.clone_from(entitlement);
+        } else {
+            entitlements.push(entitlement.clone());
+        }
+    }
+
+    fn delete_entitlement(&self, user_id: Id<UserMarker>, entitlement_id: Id<EntitlementMarker>) {
+        if let Entry::Occupied(mut entry) = self.entitlements.entry(user_id) {
+            entry
+                .get_mut()
+                .retain(|entitlement| entitlement.id != entitlement_id);
+
+            if entry.get().is_empty() {
+                entry.remove();
+            }
+        }
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for EntitlementCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::ENTITLEMENT) {
+            return;
+        }
+
+        cache.cache_entitlement(&self.0);
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for EntitlementDelete {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::ENTITLEMENT) {
+            return;
+        }
+
+        if let Some(user_id) = self.user_id {
+            cache.delete_entitlement(user_id, self.id);
+        }
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for EntitlementUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::ENTITLEMENT) {
+            return;
+        }
+
+        if self.deleted {
+            if let Some(user_id) = self.user_id {
+                cache.delete_entitlement(user_id, self.id);
+            }
+        } else {
+            cache.cache_entitlement(&self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultInMemoryCache, ResourceType};
+    use twilight_model::{
+        application::monetization::{Entitlement, EntitlementType},
+        gateway::payload::incoming::{EntitlementCreate, EntitlementDelete, EntitlementUpdate},
+        id::Id,
+    };
+
+    fn entitlement(id: u64) -> Entitlement {
+        Entitlement {
+            application_id: Id::new(1),
+            consumed: None,
+            deleted: false,
+            ends_at: None,
+            guild_id: None,
+            id: Id::new(id),
+            kind: EntitlementType::ApplicationSubscription,
+            sku_id: Id::new(30),
+            starts_at: None,
+            user_id: Some(Id::new(6)),
+        }
+    }
+
+    #[test]
+    fn entitlement_events() {
+        let cache = DefaultInMemoryCache::new();
+
+        cache.update(&EntitlementCreate(entitlement(20)));
+        cache.update(&EntitlementCreate(entitlement(21)));
+        assert_eq!(
+            [entitlement(20), entitlement(21)].as_slice(),
+            cache.entitlements(Id::new(6)).unwrap().as_slice()
+        );
+
+        let mut consumed = entitlement(20);
+        consumed.consumed = Some(true);
+        cache.update(&EntitlementUpdate(consumed.clone()));
+        assert_eq!(
+            [consumed, entitlement(21)].as_slice(),
+            cache.entitlements(Id::new(6)).unwrap().as_slice()
+        );
+
+        let mut deleted = entitlement(21);
+        deleted.deleted = true;
+        cache.update(&EntitlementUpdate(deleted));
+        assert_eq!(1, cache.entitlements(Id::new(6)).unwrap().len());
+
+        cache.update(&EntitlementDelete(entitlement(20)));
+        assert!(cache.entitlements(Id::new(6)).is_none());
+    }
+
+    #[test]
+    fn entitlement_events_unwanted() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::ENTITLEMENT)
+            .build();
+
+        cache.update(&EntitlementCreate(entitlement(20)));
+        assert!(cache.entitlements(Id::new(6)).is_none());
+    }
+}
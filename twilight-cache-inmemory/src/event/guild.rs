@@ -1,15 +1,25 @@
-use crate::{config::ResourceType, CacheableGuild, CacheableModels, InMemoryCache, UpdateCache};
-use dashmap::DashMap;
+use crate::{
+    change::{self, CacheChange},
+    config::ResourceType,
+    CacheableGuild, CacheableMessage, CacheableModels, InMemoryCache, UpdateCache,
+};
+use dashmap::{mapref::entry::Entry, DashMap};
 use std::{collections::HashSet, hash::Hash, mem};
 use twilight_model::{
     gateway::payload::incoming::{GuildCreate, GuildDelete, GuildUpdate},
-    guild::Guild,
+    guild::GatewayGuild,
     id::{marker::GuildMarker, Id},
 };
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     #[allow(clippy::too_many_lines)]
-    fn cache_guild(&self, mut guild: Guild) {
+    fn cache_guild(&self, mut guild: GatewayGuild) {
+        // Resources of retained unavailable guilds may be stale, so purge them
+        // before caching the new ones.
+        if self.config.retain_unavailable_guilds() && self.unavailable_guilds.contains(&guild.id) {
+            self.delete_guild(guild.id, true);
+        }
+
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
         if self.wants(ResourceType::CHANNEL) {
@@ -74,21 +84,40 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         }
 
         if self.wants(ResourceType::GUILD) {
+            let guild_id = guild.id;
             let guild = CacheModels::Guild::from(guild);
-            self.unavailable_guilds.remove(&guild.id());
-            self.guilds.insert(guild.id(), guild);
+            self.unavailable_guilds.remove(&guild_id);
+
+            match self.guilds.entry(guild_id) {
+                Entry::Occupied(entry) if *entry.get() == guild => {}
+                Entry::Occupied(mut entry) => {
+                    entry.insert(guild);
+                    change::report(CacheChange::GuildUpdated(guild_id));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(guild);
+                    change::report(CacheChange::GuildAdded(guild_id));
+                }
+            }
         }
     }
 
+    /// Delete a guild and its resources from the cache.
+    ///
+    /// If the guild became unavailable it's kept and marked as such, but
+    /// reporting it as unavailable is left to the caller.
     pub(crate) fn delete_guild(&self, id: Id<GuildMarker>, unavailable: bool) {
         fn remove_ids<T: Eq + Hash, U>(
             guild_map: &DashMap<Id<GuildMarker>, HashSet<T>>,
             container: &DashMap<T, U>,
             guild_id: Id<GuildMarker>,
+            mut removed: impl FnMut(&T),
         ) {
             if let Some((_, ids)) = guild_map.remove(&guild_id) {
                 for id in ids {
-                    container.remove(&id);
+                    if container.remove(&id).is_some() {
+                        removed(&id);
+                    }
                 }
             }
         }
@@ -98,25 +127,61 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
                 if let Some(mut guild) = self.guilds.get_mut(&id) {
                     guild.set_unavailable(Some(true));
                 }
-            } else {
-                self.guilds.remove(&id);
+            } else if self.guilds.remove(&id).is_some() {
+                change::report(CacheChange::GuildRemoved(id));
+            }
+        }
+
+        if self.wants(ResourceType::MESSAGE) {
+            // Channels may not be cached, so also find the guild's channels by
+            // their messages.
+            let mut channel_ids = self
+                .guild_channels
+                .get(&id)
+                .map(|channel_ids| channel_ids.iter().copied().collect::<Vec<_>>())
+                .unwrap_or_default();
+            channel_ids.extend(
+                self.channel_messages
+                    .iter()
+                    .filter(|entry| {
+                        entry
+                            .value()
+                            .front()
+                            .and_then(|message_id| self.messages.get(message_id))
+                            .is_some_and(|message| message.guild_id() == Some(id))
+                    })
+                    .map(|entry| *entry.key()),
+            );
+
+            for channel_id in channel_ids {
+                self.delete_channel_messages(channel_id);
             }
         }
 
         if self.wants(ResourceType::CHANNEL) {
-            remove_ids(&self.guild_channels, &self.channels, id);
+            remove_ids(&self.guild_channels, &self.channels, id, |channel_id| {
+                change::report(CacheChange::ChannelRemoved(*channel_id));
+            });
+            self.pending_pin_timestamps
+                .retain(|_, (guild_id, _)| *guild_id != id);
         }
 
         if self.wants(ResourceType::EMOJI) {
-            remove_ids(&self.guild_emojis, &self.emojis, id);
+            remove_ids(&self.guild_emojis, &self.emojis, id, |_| {});
+        }
+
+        if self.wants(ResourceType::INVITE) {
+            remove_ids(&self.guild_invites, &self.invites, id, |_| {});
         }
 
         if self.wants(ResourceType::ROLE) {
-            remove_ids(&self.guild_roles, &self.roles, id);
+            remove_ids(&self.guild_roles, &self.roles, id, |role_id| {
+                change::report(CacheChange::RoleRemoved(id, *role_id));
+            });
         }
 
         if self.wants(ResourceType::STICKER) {
-            remove_ids(&self.guild_stickers, &self.stickers, id);
+            remove_ids(&self.guild_stickers, &self.stickers, id, |_| {});
         }
 
         if self.wants(ResourceType::VOICE_STATE) {
@@ -127,7 +192,9 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         if self.wants(ResourceType::MEMBER) {
             if let Some((_, ids)) = self.guild_members.remove(&id) {
                 for user_id in ids {
-                    self.members.remove(&(id, user_id));
+                    if self.members.remove(&(id, user_id)).is_some() {
+                        change::report(CacheChange::MemberRemoved(id, user_id));
+                    }
                 }
             }
         }
@@ -135,7 +202,9 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         if self.wants(ResourceType::PRESENCE) {
             if let Some((_, ids)) = self.guild_presences.remove(&id) {
                 for user_id in ids {
-                    self.presences.remove(&(id, user_id));
+                    if self.presences.remove(&(id, user_id)).is_some() {
+                        change::report(CacheChange::PresenceRemoved(id, user_id));
+                    }
                 }
             }
         }
@@ -155,7 +224,11 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildCreate {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        cache.delete_guild(self.id, false);
+        if self.unavailable == Some(true) && cache.config.retain_unavailable_guilds() {
+            cache.unavailable_guild(self.id);
+        } else {
+            cache.delete_guild(self.id, false);
+        }
     }
 }
 
@@ -167,6 +240,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildUpdate {
 
         if let Some(mut guild) = cache.guilds.get_mut(&self.0.id) {
             guild.update_with_guild_update(self);
+            change::report(CacheChange::GuildUpdated(self.0.id));
         };
     }
 }
@@ -181,11 +255,12 @@ mod tests {
             Channel, ChannelType,
         },
         gateway::payload::incoming::{
-            GuildCreate, GuildUpdate, MemberAdd, MemberRemove, UnavailableGuild,
+            GuildCreate, GuildDelete, GuildUpdate, MemberAdd, MemberRemove, MessageCreate,
+            UnavailableGuild,
         },
         guild::{
-            AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel,
-            NSFWLevel, PartialGuild, Permissions, PremiumTier, SystemChannelFlags,
+            AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, GatewayGuild,
+            MfaLevel, NSFWLevel, PartialGuild, Permissions, PremiumTier, SystemChannelFlags,
             VerificationLevel,
         },
         id::Id,
@@ -289,7 +364,7 @@ mod tests {
             video_quality_mode: None,
         }]);
 
-        let guild = Guild {
+        let guild = GatewayGuild {
             afk_channel_id: None,
             afk_timeout: AfkTimeout::FIFTEEN_MINUTES,
             application_id: None,
@@ -448,6 +523,70 @@ mod tests {
         assert_eq!(cache.guild(guild.id).unwrap().id, mutation.id);
     }
 
+    /// Fields only sent in guild creates must survive guild updates.
+    #[test]
+    fn guild_update_preserves_gateway_fields() {
+        let cache = DefaultInMemoryCache::new();
+        let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
+        let mut guild = test::guild(Id::new(1), Some(50));
+        guild.joined_at = joined_at;
+        guild.large = true;
+        guild.max_members = Some(250_000);
+        guild.premium_subscription_count = Some(14);
+
+        cache.update(&GuildCreate::Available(guild.clone()));
+
+        let mutation = PartialGuild {
+            id: guild.id,
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            application_id: guild.application_id,
+            banner: guild.banner,
+            default_message_notifications: guild.default_message_notifications,
+            description: guild.description,
+            discovery_splash: guild.discovery_splash,
+            emojis: guild.emojis,
+            explicit_content_filter: guild.explicit_content_filter,
+            features: guild.features,
+            icon: guild.icon,
+            max_members: None,
+            max_presences: guild.max_presences,
+            member_count: None,
+            mfa_level: guild.mfa_level,
+            name: "renamed".to_owned(),
+            nsfw_level: guild.nsfw_level,
+            owner_id: guild.owner_id,
+            owner: None,
+            permissions: None,
+            preferred_locale: guild.preferred_locale,
+            premium_progress_bar_enabled: true,
+            premium_subscription_count: None,
+            premium_tier: guild.premium_tier,
+            public_updates_channel_id: None,
+            roles: guild.roles,
+            rules_channel_id: Some(Id::new(5)),
+            splash: guild.splash,
+            system_channel_flags: guild.system_channel_flags,
+            system_channel_id: guild.system_channel_id,
+            verification_level: guild.verification_level,
+            vanity_url_code: guild.vanity_url_code,
+            widget_channel_id: guild.widget_channel_id,
+            widget_enabled: None,
+        };
+
+        cache.update(&GuildUpdate(mutation));
+
+        let cached = cache.guild(guild.id).unwrap();
+        assert_eq!("renamed", cached.name());
+        assert!(cached.premium_progress_bar_enabled());
+        assert_eq!(Some(Id::new(5)), cached.rules_channel_id());
+        assert_eq!(Some(50), cached.member_count());
+        assert_eq!(joined_at, cached.joined_at());
+        assert!(cached.large());
+        assert_eq!(Some(250_000), cached.max_members());
+        assert_eq!(Some(14), cached.premium_subscription_count());
+    }
+
     #[test]
     fn guild_member_count() {
         let user_id = Id::new(2);
@@ -508,4 +647,114 @@ mod tests {
         );
         assert!(!cache.guild(guild_id).unwrap().unavailable.unwrap_or(false));
     }
+
+    /// Guild with a channel and a member, the latter being either user 2 or 3.
+    fn guild_with_resources(user_id: u64) -> GatewayGuild {
+        let (guild_id, _, channel) = test::guild_channel_text();
+        let mut guild = test::guild(guild_id, Some(1));
+        guild.channels.push(channel);
+        guild.members.push(test::member(Id::new(user_id)));
+
+        guild
+    }
+
+    #[test]
+    fn guild_delete_unavailable_purges() {
+        let cache = DefaultInMemoryCache::new();
+        let guild = guild_with_resources(2);
+        let guild_id = guild.id;
+        cache.update(&GuildCreate::Available(guild));
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: Some(true),
+        });
+        assert!(cache.guild(guild_id).is_none());
+        assert!(cache.channel(Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+
+        cache.update(&GuildCreate::Available(guild_with_resources(3)));
+        assert!(cache.channel(Id::new(2)).is_some());
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+    }
+
+    #[test]
+    fn guild_delete_unavailable_retains() {
+        let cache = DefaultInMemoryCache::builder()
+            .retain_unavailable_guilds(true)
+            .build();
+        let guild = guild_with_resources(2);
+        let guild_id = guild.id;
+        cache.update(&GuildCreate::Available(guild));
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: Some(true),
+        });
+        assert!(cache.guild(guild_id).unwrap().unavailable.unwrap());
+        assert!(cache.unavailable_guilds.contains(&guild_id));
+        assert!(cache.channel(Id::new(2)).is_some());
+        assert!(cache.member(guild_id, Id::new(2)).is_some());
+
+        // Recreating the guild replaces the stale resources.
+        let mut guild = guild_with_resources(3);
+        guild.channels.clear();
+        cache.update(&GuildCreate::Available(guild));
+        assert!(!cache.unavailable_guilds.contains(&guild_id));
+        assert!(!cache.guild(guild_id).unwrap().unavailable.unwrap_or(false));
+        assert!(cache.channel(Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(2)).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_some());
+        assert_eq!(
+            1,
+            cache
+                .guild_members(guild_id)
+                .map(|members| members.len())
+                .unwrap_or_default()
+        );
+
+        // Removal from the guild always purges.
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: None,
+        });
+        assert!(cache.guild(guild_id).is_none());
+        assert!(cache.member(guild_id, Id::new(3)).is_none());
+    }
+
+    #[test]
+    fn guild_delete_messages() {
+        let cache = DefaultInMemoryCache::new();
+        let guild = guild_with_resources(2);
+        let guild_id = guild.id;
+        cache.update(&GuildCreate::Available(guild));
+        cache.update(&MessageCreate(test::message()));
+        assert_eq!(1, cache.stats().messages());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: None,
+        });
+        assert_eq!(0, cache.stats().messages());
+        assert!(cache.stats().channel_messages(Id::new(2)).is_none());
+    }
+
+    /// Test that messages of channels that aren't cached are removed when
+    /// their guild is deleted.
+    #[test]
+    fn guild_delete_messages_uncached_channel() {
+        let cache = DefaultInMemoryCache::new();
+        let message = test::message();
+        let guild_id = message.guild_id.expect("guild message");
+        cache.update(&MessageCreate(message));
+        assert_eq!(1, cache.stats().messages());
+
+        cache.update(&GuildDelete {
+            id: guild_id,
+            unavailable: None,
+        });
+        assert_eq!(0, cache.stats().messages());
+        assert!(cache.stats().channel_messages(Id::new(2)).is_none());
+    }
 }
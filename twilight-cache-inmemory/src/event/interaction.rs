@@ -24,6 +24,32 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for InteractionCreat
             }
         }
 
+        // Cache interaction channel
+        if cache.wants(ResourceType::CHANNEL) {
+            if let Some(channel) = &self.channel {
+                let mut channel = channel.clone();
+                // The partial channel may lack the guild ID.
+                channel.guild_id = channel.guild_id.or(self.guild_id);
+
+                if !cache.skips_dm(channel.guild_id) {
+                    cache.cache_partial_channel(channel);
+                }
+            }
+        }
+
+        // Cache the invoking user's entitlements
+        if cache.wants(ResourceType::ENTITLEMENT) {
+            if let Some(user_id) = self.author_id() {
+                if self.entitlements.is_empty() {
+                    cache.entitlements.remove(&user_id);
+                } else {
+                    cache
+                        .entitlements
+                        .insert(user_id, self.entitlements.clone());
+                }
+            }
+        }
+
         // Cache resolved interaction data
         if let Some(InteractionData::ApplicationCommand(data)) = &self.data {
             if let Some(resolved) = &data.resolved {
@@ -59,7 +85,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for InteractionCreat
 
 #[cfg(test)]
 mod tests {
-    use crate::DefaultInMemoryCache;
+    use crate::{test, DefaultInMemoryCache, ResourceType};
     use std::collections::HashMap;
     use twilight_model::{
         application::{
@@ -68,12 +94,14 @@ mod tests {
                 application_command::CommandData, Interaction, InteractionData,
                 InteractionDataResolved, InteractionMember, InteractionType,
             },
+            monetization::{Entitlement, EntitlementType},
         },
         channel::{
             message::{
                 sticker::{MessageSticker, StickerFormatType},
                 MessageFlags, MessageType,
             },
+            thread::{AutoArchiveDuration, ThreadMetadata},
             Channel, ChannelType, Message,
         },
         gateway::payload::incoming::InteractionCreate,
@@ -187,6 +215,7 @@ mod tests {
                                 mfa_enabled: None,
                                 name: "test".to_owned(),
                                 premium_type: None,
+                                primary_guild: None,
                                 public_flags: None,
                                 system: None,
                                 verified: None,
@@ -242,6 +271,7 @@ mod tests {
                         Id::new(8),
                         Role {
                             color: 0u32,
+                            colors: None,
                             hoist: false,
                             icon: None,
                             id: Id::new(8),
@@ -273,6 +303,7 @@ mod tests {
                             mfa_enabled: None,
                             name: "different name".into(),
                             premium_type: None,
+                            primary_guild: None,
                             public_flags: None,
                             system: None,
                             verified: None,
@@ -315,6 +346,7 @@ mod tests {
                     mfa_enabled: None,
                     name: "username".into(),
                     premium_type: None,
+                    primary_guild: None,
                     public_flags: None,
                     system: None,
                     verified: None,
@@ -349,4 +381,119 @@ mod tests {
 
         Ok(())
     }
+
+    /// Create an interaction invoked by user 6 in guild 1.
+    #[allow(deprecated)]
+    fn interaction(channel: Channel, entitlements: Vec<Entitlement>) -> InteractionCreate {
+        InteractionCreate(Interaction {
+            app_permissions: None,
+            application_id: Id::new(1),
+            authorizing_integration_owners: ApplicationIntegrationMap {
+                guild: None,
+                user: None,
+            },
+            channel_id: Some(channel.id),
+            channel: Some(channel),
+            context: None,
+            data: None,
+            entitlements,
+            guild: None,
+            guild_id: Some(Id::new(1)),
+            guild_locale: None,
+            id: Id::new(4),
+            kind: InteractionType::MessageComponent,
+            locale: None,
+            member: None,
+            message: None,
+            token: "token".into(),
+            user: Some(test::user(Id::new(6))),
+        })
+    }
+
+    /// Partial thread with a parent, as included in interactions.
+    fn partial_thread() -> Channel {
+        let (_, parent_id, mut channel) = test::guild_channel_text();
+        channel.guild_id = None;
+        channel.id = Id::new(10);
+        channel.kind = ChannelType::PublicThread;
+        channel.name = Some("thread".to_owned());
+        channel.nsfw = None;
+        channel.parent_id = Some(parent_id);
+        channel.permission_overwrites = None;
+        channel.position = None;
+        channel.thread_metadata = Some(ThreadMetadata {
+            archived: false,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            archive_timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            create_timestamp: None,
+            invitable: None,
+            locked: false,
+        });
+
+        channel
+    }
+
+    #[test]
+    fn interaction_channel() {
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&interaction(partial_thread(), Vec::new()));
+
+        {
+            let channel = cache.channel(Id::new(10)).unwrap();
+            assert_eq!(Some(Id::new(1)), channel.guild_id);
+            assert_eq!(ChannelType::PublicThread, channel.kind);
+            assert_eq!(Some("thread"), channel.name.as_deref());
+            assert_eq!(Some(Id::new(2)), channel.parent_id);
+            assert!(channel.thread_metadata.is_some());
+        }
+
+        assert!(cache
+            .guild_channels(Id::new(1))
+            .unwrap()
+            .contains(&Id::new(10)));
+
+        // A partial channel lacking fields doesn't erase the cached ones.
+        let mut partial = partial_thread();
+        partial.name = Some("renamed".to_owned());
+        partial.parent_id = None;
+        partial.thread_metadata = None;
+        cache.update(&interaction(partial, Vec::new()));
+
+        let channel = cache.channel(Id::new(10)).unwrap();
+        assert_eq!(Some("renamed"), channel.name.as_deref());
+        assert_eq!(Some(Id::new(2)), channel.parent_id);
+        assert!(channel.thread_metadata.is_some());
+    }
+
+    #[test]
+    fn interaction_entitlements() {
+        let cache = DefaultInMemoryCache::new();
+        let entitlement = Entitlement {
+            application_id: Id::new(1),
+            consumed: None,
+            deleted: false,
+            ends_at: None,
+            guild_id: None,
+            id: Id::new(20),
+            kind: EntitlementType::ApplicationSubscription,
+            sku_id: Id::new(30),
+            starts_at: None,
+            user_id: Some(Id::new(6)),
+        };
+
+        cache.update(&interaction(partial_thread(), vec![entitlement.clone()]));
+        assert_eq!(
+            [entitlement.clone()].as_slice(),
+            cache.entitlements(Id::new(6)).unwrap().as_slice()
+        );
+
+        cache.update(&interaction(partial_thread(), Vec::new()));
+        assert!(cache.entitlements(Id::new(6)).is_none());
+
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::ENTITLEMENT)
+            .build();
+        cache.update(&interaction(partial_thread(), vec![entitlement]));
+        assert!(cache.entitlements(Id::new(6)).is_none());
+    }
 }
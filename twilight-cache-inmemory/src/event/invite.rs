@@ -0,0 +1,160 @@
+use crate::{
+    config::ResourceType, model::CachedInvite, CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{
+    gateway::payload::incoming::{InviteCreate, InviteDelete},
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
+    fn cache_invite(&self, invite: InviteCreate) {
+        self.guild_invites
+            .entry(invite.guild_id)
+            .or_default()
+            .insert(invite.code.clone());
+
+        self.invites
+            .insert(invite.code.clone(), CachedInvite::from(invite));
+    }
+
+    fn delete_invite(&self, guild_id: Id<GuildMarker>, code: &str) {
+        self.invites.remove(code);
+
+        if let Some(mut codes) = self.guild_invites.get_mut(&guild_id) {
+            codes.remove(code);
+        }
+    }
+
+    /// Delete the cached invites to a guild channel.
+    pub(crate) fn delete_channel_invites(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) {
+        if let Some(mut codes) = self.guild_invites.get_mut(&guild_id) {
+            codes.retain(|code| {
+                let in_channel = self
+                    .invites
+                    .get(code)
+                    .is_some_and(|invite| invite.channel_id() == channel_id);
+
+                if in_channel {
+                    self.invites.remove(code);
+                }
+
+                !in_channel
+            });
+        }
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for InviteCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::INVITE) {
+            return;
+        }
+
+        cache.cache_invite(self.clone());
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for InviteDelete {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::INVITE) {
+            return;
+        }
+
+        cache.delete_invite(self.guild_id, &self.code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, DefaultInMemoryCache, ResourceType};
+    use twilight_model::{
+        gateway::payload::incoming::{ChannelDelete, GuildDelete, InviteCreate, InviteDelete},
+        id::Id,
+        util::Timestamp,
+    };
+
+    fn invite_create(channel_id: u64, code: &str) -> InviteCreate {
+        InviteCreate {
+            channel_id: Id::new(channel_id),
+            code: code.to_owned(),
+            created_at: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            expires_at: None,
+            guild_id: Id::new(1),
+            inviter: None,
+            max_age: 0,
+            max_uses: 0,
+            target_application: None,
+            target_user_type: None,
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        }
+    }
+
+    #[test]
+    fn invite_create_delete() {
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&invite_create(2, "a"));
+        cache.update(&invite_create(2, "b"));
+
+        assert_eq!(2, cache.stats().invites());
+        assert_eq!(2, cache.guild_invites(Id::new(1)).unwrap().len());
+        assert_eq!(Id::new(2), cache.invite("a").unwrap().channel_id());
+
+        cache.update(&InviteDelete {
+            channel_id: Id::new(2),
+            code: "a".to_owned(),
+            guild_id: Id::new(1),
+        });
+
+        assert!(cache.invite("a").is_none());
+        assert!(cache.invite("b").is_some());
+        assert!(!cache.guild_invites(Id::new(1)).unwrap().contains("a"));
+    }
+
+    #[test]
+    fn channel_delete() {
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&invite_create(2, "a"));
+        cache.update(&invite_create(3, "b"));
+
+        let (_, _, channel) = test::guild_channel_text();
+        cache.update(&ChannelDelete(channel));
+
+        assert!(cache.invite("a").is_none());
+        assert!(cache.invite("b").is_some());
+        assert_eq!(1, cache.guild_invites(Id::new(1)).unwrap().len());
+    }
+
+    #[test]
+    fn guild_delete() {
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&invite_create(2, "a"));
+
+        cache.update(&GuildDelete {
+            id: Id::new(1),
+            unavailable: None,
+        });
+
+        assert_eq!(0, cache.stats().invites());
+        assert!(cache.guild_invites(Id::new(1)).is_none());
+    }
+
+    #[test]
+    fn unwanted() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::all() - ResourceType::INVITE)
+            .build();
+        cache.update(&invite_create(2, "a"));
+
+        assert!(cache.invite("a").is_none());
+        assert!(cache.guild_invites(Id::new(1)).is_none());
+    }
+}
@@ -1,14 +1,16 @@
 use std::borrow::Cow;
 
 use crate::{
+    change::{self, CacheChange},
     config::ResourceType,
     model::member::ComputedInteractionMember,
     traits::{CacheableGuild, CacheableMember},
     CacheableModels, InMemoryCache, UpdateCache,
 };
+use dashmap::mapref::entry::Entry;
 use twilight_model::{
     application::interaction::InteractionMember,
-    gateway::payload::incoming::{MemberAdd, MemberChunk, MemberRemove, MemberUpdate},
+    gateway::payload::incoming::{MemberAdd, MemberChunk, MemberRemove, MemberUpdate, TypingStart},
     guild::{Member, PartialMember},
     id::{
         marker::{GuildMarker, UserMarker},
@@ -29,17 +31,24 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
 
     pub(crate) fn cache_member(&self, guild_id: Id<GuildMarker>, member: Member) {
         let member_id = member.user.id;
-        let id = (guild_id, member_id);
 
-        if let Some(m) = self.members.get(&id) {
-            if *m == member {
-                return;
+        let user = match self.members.entry((guild_id, member_id)) {
+            Entry::Occupied(entry) if *entry.get() == member => return,
+            entry => {
+                let change = if matches!(entry, Entry::Occupied(_)) {
+                    CacheChange::MemberUpdated(guild_id, member_id)
+                } else {
+                    CacheChange::MemberAdded(guild_id, member_id)
+                };
+                let user = member.user.clone();
+                entry.insert(CacheModels::Member::from(member));
+                change::report(change);
+
+                user
             }
-        }
+        };
 
-        self.cache_user(Cow::Borrowed(&member.user), Some(guild_id));
-        let cached = CacheModels::Member::from(member);
-        self.members.insert(id, cached);
+        self.cache_user(Cow::Owned(user), Some(guild_id));
         self.guild_members
             .entry(guild_id)
             .or_default()
@@ -52,11 +61,18 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         member: &PartialMember,
         user_id: Id<UserMarker>,
     ) {
-        let id = (guild_id, user_id);
-
-        if let Some(m) = self.members.get(&id) {
-            if &*m == member {
-                return;
+        match self.members.entry((guild_id, user_id)) {
+            Entry::Occupied(entry) if entry.get() == member => return,
+            // Partial members lack some fields, such as whether the member is
+            // pending, so merge them into the cached member instead of
+            // replacing it.
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().update_with_partial_member(member);
+                change::report(CacheChange::MemberUpdated(guild_id, user_id));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(CacheModels::Member::from((user_id, member.clone())));
+                change::report(CacheChange::MemberAdded(guild_id, user_id));
             }
         }
 
@@ -64,9 +80,6 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             .entry(guild_id)
             .or_default()
             .insert(user_id);
-
-        let cached = CacheModels::Member::from((user_id, member.clone()));
-        self.members.insert(id, cached);
     }
 
     pub(crate) fn cache_borrowed_interaction_member(
@@ -75,28 +88,36 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         member: &InteractionMember,
         user_id: Id<UserMarker>,
     ) {
-        let id = (guild_id, user_id);
-
-        let (avatar, deaf, mute) = match self.members.get(&id) {
-            Some(m) if &*m == member => return,
-            Some(m) => (m.avatar(), m.deaf(), m.mute()),
-            None => (None, None, None),
-        };
+        match self.members.entry((guild_id, user_id)) {
+            Entry::Occupied(entry) if entry.get() == member => return,
+            Entry::Occupied(mut entry) => {
+                let cached = CacheModels::Member::from(ComputedInteractionMember {
+                    avatar: entry.get().avatar(),
+                    deaf: entry.get().deaf(),
+                    interaction_member: member.clone(),
+                    mute: entry.get().mute(),
+                    user_id,
+                });
+
+                entry.insert(cached);
+                change::report(CacheChange::MemberUpdated(guild_id, user_id));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(CacheModels::Member::from(ComputedInteractionMember {
+                    avatar: None,
+                    deaf: None,
+                    interaction_member: member.clone(),
+                    mute: None,
+                    user_id,
+                }));
+                change::report(CacheChange::MemberAdded(guild_id, user_id));
+            }
+        }
 
         self.guild_members
             .entry(guild_id)
             .or_default()
             .insert(user_id);
-
-        let cached = CacheModels::Member::from(ComputedInteractionMember {
-            avatar,
-            deaf,
-            interaction_member: member.clone(),
-            mute,
-            user_id,
-        });
-
-        self.members.insert(id, cached);
     }
 }
 
@@ -105,6 +126,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberAdd {
         if cache.wants(ResourceType::GUILD) {
             if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
                 guild.increase_member_count(1);
+                change::report(CacheChange::GuildUpdated(self.guild_id));
             }
         }
 
@@ -118,15 +140,17 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberAdd {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberChunk {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if !cache.wants(ResourceType::MEMBER) {
+        if !cache.config.wants_chunk(self) {
             return;
         }
 
-        if self.members.is_empty() {
-            return;
+        if cache.wants(ResourceType::MEMBER) && !self.members.is_empty() {
+            cache.cache_members(self.guild_id, self.members.clone());
         }
 
-        cache.cache_members(self.guild_id, self.members.clone());
+        if cache.wants(ResourceType::PRESENCE) && !self.presences.is_empty() {
+            cache.cache_presences(self.guild_id, self.presences.clone());
+        }
     }
 }
 
@@ -135,6 +159,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberRemove {
         if cache.wants(ResourceType::GUILD) {
             if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
                 guild.decrease_member_count(1);
+                change::report(CacheChange::GuildUpdated(self.guild_id));
             }
         }
 
@@ -142,24 +167,27 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberRemove {
             return;
         }
 
-        cache.members.remove(&(self.guild_id, self.user.id));
+        if cache
+            .members
+            .remove(&(self.guild_id, self.user.id))
+            .is_some()
+        {
+            change::report(CacheChange::MemberRemoved(self.guild_id, self.user.id));
+        }
 
         if let Some(mut members) = cache.guild_members.get_mut(&self.guild_id) {
             members.remove(&self.user.id);
         }
 
-        // Avoid a deadlock by mutating the user, dropping the lock to the map,
-        // and then removing the user later if they are in no guilds.
-        let mut remove_user = false;
-
-        if let Some(mut user_guilds) = cache.user_guilds.get_mut(&self.user.id) {
-            user_guilds.remove(&self.guild_id);
+        // Remove the user while holding the entry of their set of guilds so
+        // that they can't be added to another guild in the meantime.
+        if let Entry::Occupied(mut user_guilds) = cache.user_guilds.entry(self.user.id) {
+            user_guilds.get_mut().remove(&self.guild_id);
 
-            remove_user = user_guilds.is_empty();
-        }
-
-        if remove_user {
-            cache.users.remove(&self.user.id);
+            if user_guilds.get().is_empty() {
+                cache.users.remove(&self.user.id);
+                user_guilds.remove();
+            }
         }
     }
 }
@@ -174,6 +202,19 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberUpdate {
 
         if let Some(mut member) = cache.members.get_mut(&key) {
             member.update_with_member_update(self);
+            change::report(CacheChange::MemberUpdated(self.guild_id, self.user.id));
+        }
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for TypingStart {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::MEMBER) {
+            return;
+        }
+
+        if let (Some(guild_id), Some(member)) = (self.guild_id, &self.member) {
+            cache.cache_member(guild_id, member.clone());
         }
     }
 }
@@ -182,7 +223,41 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberUpdate {
 mod tests {
     use crate::{test, DefaultInMemoryCache};
     use std::borrow::Cow;
-    use twilight_model::{gateway::payload::incoming::MemberRemove, id::Id};
+    use twilight_model::{
+        gateway::{
+            payload::incoming::{MemberChunk, MemberRemove, TypingStart},
+            presence::{ClientStatus, Presence, Status, UserOrId},
+        },
+        id::{
+            marker::{GuildMarker, UserMarker},
+            Id,
+        },
+    };
+
+    fn chunk(guild_id: Id<GuildMarker>, user_ids: &[Id<UserMarker>]) -> MemberChunk {
+        MemberChunk {
+            chunk_count: 1,
+            chunk_index: 0,
+            guild_id,
+            members: user_ids.iter().copied().map(test::member).collect(),
+            nonce: Some("scrape".to_owned()),
+            not_found: Vec::new(),
+            presences: user_ids
+                .iter()
+                .map(|user_id| Presence {
+                    activities: Vec::new(),
+                    client_status: ClientStatus {
+                        desktop: Some(Status::Online),
+                        mobile: None,
+                        web: None,
+                    },
+                    guild_id,
+                    status: Status::Online,
+                    user: UserOrId::UserId { id: *user_id },
+                })
+                .collect(),
+        }
+    }
 
     #[test]
     fn cache_guild_member() {
@@ -284,4 +359,62 @@ mod tests {
         });
         assert!(!cache.users.contains_key(&user_id));
     }
+
+    #[test]
+    fn member_chunk_presences() {
+        let guild_id = Id::new(1);
+        let user_ids = [Id::new(2), Id::new(3)];
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&chunk(guild_id, &user_ids));
+
+        assert_eq!(2, cache.guild_members(guild_id).unwrap().len());
+        assert_eq!(2, cache.guild_presences(guild_id).unwrap().len());
+
+        for user_id in user_ids {
+            assert!(cache.member(guild_id, user_id).is_some());
+            assert_eq!(
+                Status::Online,
+                cache.presence(guild_id, user_id).unwrap().status()
+            );
+        }
+    }
+
+    #[test]
+    fn member_chunk_filter() {
+        let guild_id = Id::new(1);
+        let user_ids = [Id::new(2), Id::new(3)];
+        let cache = DefaultInMemoryCache::builder()
+            .chunk_filter(|chunk| chunk.nonce.as_deref() != Some("scrape"))
+            .build();
+        cache.update(&chunk(guild_id, &user_ids));
+
+        assert!(cache.guild_members(guild_id).is_none());
+        assert!(cache.guild_presences(guild_id).is_none());
+        assert!(cache.members.is_empty());
+        assert!(cache.presences.is_empty());
+        assert!(cache.users.is_empty());
+    }
+
+    #[test]
+    fn typing_start_updates_member() {
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let cache = DefaultInMemoryCache::new();
+        cache.cache_member(guild_id, test::member(user_id));
+
+        let mut member = test::member(user_id);
+        member.pending = true;
+        member.roles = vec![Id::new(3)];
+        cache.update(&TypingStart {
+            channel_id: Id::new(4),
+            guild_id: Some(guild_id),
+            member: Some(member),
+            timestamp: 1_632_072_645,
+            user_id,
+        });
+
+        let cached = cache.member(guild_id, user_id).unwrap();
+        assert!(cached.pending());
+        assert_eq!(&[Id::new(3)], cached.roles());
+    }
 }
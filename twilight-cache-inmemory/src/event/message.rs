@@ -1,13 +1,22 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
+use crate::{
+    change::{self, CacheChange},
+    config::ResourceType,
+    traits::{CacheableChannel, CacheableMessage},
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use dashmap::mapref::entry::Entry;
 use twilight_model::gateway::payload::incoming::{
     MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
 };
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if cache.skips_dm(self.guild_id) {
+            return;
+        }
+
         if cache.wants(ResourceType::USER) {
-            cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
+            cache.cache_partial_user(&self.author, self.guild_id);
         }
 
         if let (Some(member), Some(guild_id), true) = (
@@ -30,14 +39,34 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
         // filling up with old messages that aren't in any channel cache.
         if channel_messages.len() >= cache.config.message_cache_size() {
             if let Some(popped_id) = channel_messages.pop_back() {
-                cache.messages.remove(&popped_id);
+                if cache.messages.remove(&popped_id).is_some() {
+                    change::report(CacheChange::MessageRemoved(self.0.channel_id, popped_id));
+                }
             }
         }
 
         channel_messages.push_front(self.0.id);
-        cache
+
+        let change = if cache
             .messages
-            .insert(self.0.id, CacheModels::Message::from(self.0.clone()));
+            .insert(self.0.id, CacheModels::Message::from(self.0.clone()))
+            .is_some()
+        {
+            CacheChange::MessageUpdated(self.0.channel_id, self.0.id)
+        } else {
+            CacheChange::MessageAdded(self.0.channel_id, self.0.id)
+        };
+        change::report(change);
+        drop(channel_messages);
+
+        // Deleted messages don't walk the last message ID back, matching
+        // Discord's behavior.
+        if cache.wants(ResourceType::CHANNEL) {
+            if let Some(mut channel) = cache.channels.get_mut(&self.0.channel_id) {
+                channel.record_message(self.0.id);
+                change::report(CacheChange::ChannelUpdated(self.0.channel_id));
+            }
+        }
     }
 }
 
@@ -47,12 +76,14 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDelete {
             return;
         }
 
-        cache.messages.remove(&self.id);
-
-        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
+        if cache.messages.remove(&self.id).is_some() {
+            change::report(CacheChange::MessageRemoved(self.channel_id, self.id));
+        }
 
-        if let Some(idx) = channel_messages.iter().position(|id| *id == self.id) {
-            channel_messages.remove(idx);
+        if let Some(mut channel_messages) = cache.channel_messages.get_mut(&self.channel_id) {
+            if let Some(idx) = channel_messages.iter().position(|id| *id == self.id) {
+                channel_messages.remove(idx);
+            }
         }
     }
 }
@@ -63,25 +94,28 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDeleteBul
             return;
         }
 
-        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
-
         for id in &self.ids {
-            cache.messages.remove(id);
-
-            if let Some(idx) = channel_messages
-                .iter()
-                .position(|message_id| message_id == id)
-            {
-                channel_messages.remove(idx);
+            if cache.messages.remove(id).is_some() {
+                change::report(CacheChange::MessageRemoved(self.channel_id, *id));
             }
         }
+
+        // Messages may have been evicted from the channel's queue already, so
+        // only remove the IDs that are present.
+        if let Some(mut channel_messages) = cache.channel_messages.get_mut(&self.channel_id) {
+            channel_messages.retain(|id| !self.ids.contains(id));
+        }
     }
 }
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if cache.skips_dm(self.guild_id) {
+            return;
+        }
+
         if cache.wants(ResourceType::USER) {
-            cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
+            cache.cache_partial_user(&self.author, self.guild_id);
         }
 
         if let (Some(member), Some(guild_id), true) = (
@@ -96,16 +130,32 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
             return;
         }
 
+        let message = CacheModels::Message::from(self.0.clone());
+
         // In special cases, this message was popped out due to the limitation
         // of the message cache capacity, or its Event::MessageCreate was missed.
         // If that is the case, we do not only add it to the message cache but
         // also add its ID to the channel messages cache.
-        if cache
-            .messages
-            .insert(self.id, CacheModels::Message::from(self.0.clone()))
-            .is_some()
-        {
-            return;
+        match cache.messages.entry(self.id) {
+            Entry::Occupied(mut entry) => {
+                let previous = entry.insert(message);
+
+                if previous != *entry.get() {
+                    change::report(CacheChange::MessageUpdated(self.channel_id, self.id));
+                }
+
+                let edit_history = cache.config.message_edit_history();
+
+                if edit_history > 0 {
+                    entry.get_mut().record_edit(previous, edit_history);
+                }
+
+                return;
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(message);
+                change::report(CacheChange::MessageAdded(self.channel_id, self.id));
+            }
         }
 
         let mut channel_messages = cache.channel_messages.entry(self.0.channel_id).or_default();
@@ -114,7 +164,9 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
         // the channel cache and also remove it from the message cache.
         if channel_messages.len() >= cache.config.message_cache_size() {
             if let Some(popped_id) = channel_messages.pop_back() {
-                cache.messages.remove(&popped_id);
+                if cache.messages.remove(&popped_id).is_some() {
+                    change::report(CacheChange::MessageRemoved(self.0.channel_id, popped_id));
+                }
             }
         }
 
@@ -124,13 +176,20 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DefaultInMemoryCache, ResourceType};
+    use crate::{test, DefaultInMemoryCache, ResourceType};
+    use std::borrow::Cow;
     use twilight_model::{
-        channel::message::{Message, MessageFlags, MessageType},
-        gateway::payload::incoming::MessageCreate,
+        channel::{
+            message::{Mention, Message, MessageFlags, MessageType},
+            ChannelType,
+        },
+        gateway::payload::incoming::{
+            ChannelCreate, MemberAdd, MessageCreate, MessageDelete, MessageDeleteBulk,
+            MessageUpdate, ThreadCreate,
+        },
         guild::{MemberFlags, PartialMember},
         id::Id,
-        user::User,
+        user::{AvatarDecorationData, PrimaryGuild, User, UserFlags},
         util::{image_hash::ImageHashParseError, ImageHash, Timestamp},
     };
 
@@ -166,6 +225,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "test".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -242,4 +302,249 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn message_delete_bulk_unknown_ids() {
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&MessageCreate(test::message()));
+
+        cache.update(&MessageDeleteBulk {
+            channel_id: Id::new(2),
+            guild_id: Some(Id::new(1)),
+            ids: vec![Id::new(4), Id::new(100)],
+        });
+        assert_eq!(0, cache.stats().messages());
+        assert_eq!(Some(0), cache.stats().channel_messages(Id::new(2)));
+
+        cache.update(&MessageDeleteBulk {
+            channel_id: Id::new(3),
+            guild_id: Some(Id::new(1)),
+            ids: vec![Id::new(5)],
+        });
+        assert!(cache.stats().channel_messages(Id::new(3)).is_none());
+    }
+
+    /// Test that created messages update the last message ID of their
+    /// channel and the message count of threads.
+    #[test]
+    fn message_create_updates_channel() {
+        let cache = DefaultInMemoryCache::new();
+        let (_, channel_id, channel) = test::guild_channel_text();
+        let thread_id = Id::new(3);
+        let mut thread = channel.clone();
+        thread.id = thread_id;
+        thread.kind = ChannelType::PublicThread;
+        thread.message_count = Some(0);
+        thread.parent_id = Some(channel_id);
+        cache.update(&ChannelCreate(channel));
+        cache.update(&ThreadCreate(thread));
+
+        let mut message = test::message();
+        for (message_channel_id, message_id) in [(channel_id, 4), (channel_id, 5), (thread_id, 6)] {
+            message.channel_id = message_channel_id;
+            message.id = Id::new(message_id);
+            cache.update(&MessageCreate(message.clone()));
+        }
+
+        // Deleting the latest message doesn't walk the last message ID back.
+        cache.update(&MessageDelete {
+            channel_id,
+            guild_id: Some(Id::new(1)),
+            id: Id::new(5),
+        });
+
+        let channel = cache.channel(channel_id).unwrap();
+        assert_eq!(Some(Id::new(5)), channel.last_message_id);
+        assert!(channel.message_count.is_none());
+
+        let thread = cache.channel(thread_id).unwrap();
+        assert_eq!(Some(Id::new(6)), thread.last_message_id);
+        assert_eq!(Some(1), thread.message_count);
+    }
+
+    /// Test that edits record the previous version of messages, evicting the
+    /// oldest versions beyond the limit.
+    #[test]
+    fn message_edit_history() {
+        let mut message = test::message();
+        message.content = "first".to_owned();
+
+        let process = |cache: &DefaultInMemoryCache| {
+            let mut message = message.clone();
+            cache.update(&MessageCreate(message.clone()));
+
+            for (content, secs) in [("second", 1_700_000_000), ("third", 1_700_000_060)] {
+                message.content = content.to_owned();
+                message.edited_timestamp = Some(Timestamp::from_secs(secs).unwrap());
+                cache.update(&MessageUpdate(message.clone()));
+            }
+        };
+
+        let cache = DefaultInMemoryCache::new();
+        process(&cache);
+        let cached = cache.message(message.id).unwrap();
+        assert_eq!("third", cached.content());
+        assert!(cached.edit_history().is_empty());
+        drop(cached);
+
+        let cache = DefaultInMemoryCache::builder()
+            .message_edit_history(1)
+            .build();
+        process(&cache);
+        let cached = cache.message(message.id).unwrap();
+        assert_eq!("third", cached.content());
+        assert_eq!(1, cached.edit_history().len());
+        let previous = &cached.edit_history()[0];
+        assert_eq!("second", previous.content());
+        assert_eq!(
+            Some(Timestamp::from_secs(1_700_000_000).unwrap()),
+            previous.edited_timestamp()
+        );
+        assert!(previous.embeds().is_empty());
+    }
+
+    /// Test that messages sharing an author store only its ID, resolving it
+    /// through the single cached user.
+    #[test]
+    fn messages_share_author() {
+        let cache = DefaultInMemoryCache::builder()
+            .message_cache_size(1000)
+            .build();
+        let mut message = test::message();
+        let author = message.author.clone();
+        message.member.as_mut().unwrap().user = Some(author.clone());
+        message.mentions = vec![Mention {
+            avatar: author.avatar,
+            bot: author.bot,
+            discriminator: author.discriminator,
+            id: author.id,
+            member: None,
+            name: author.name.clone(),
+            public_flags: UserFlags::empty(),
+        }];
+
+        for id in 1..=1000 {
+            message.id = Id::new(id);
+            cache.update(&MessageCreate(message.clone()));
+        }
+
+        assert_eq!(1000, cache.messages.len());
+        assert_eq!(1, cache.users.len());
+        assert_eq!(1, cache.members.len());
+        assert_eq!(
+            Some(author.id),
+            cache
+                .member(message.guild_id.unwrap(), author.id)
+                .map(|member| member.user_id())
+        );
+        // Messages don't own a copy of their author.
+        assert!(cache.messages.iter().all(|cached| {
+            cached.author() == author.id
+                && cached.member().is_some_and(|member| member.user.is_none())
+                && cached.mentions() == [author.id]
+        }));
+        assert!(*cache.message(Id::new(1000)).unwrap() == message);
+
+        assert_eq!(author, *cache.message_author(Id::new(1000)).unwrap());
+        let mentions = cache.message_mentions(Id::new(1)).unwrap();
+        assert_eq!(1, mentions.len());
+        assert_eq!(author.id, mentions[0].id);
+        drop(mentions);
+
+        assert!(cache.message_author(Id::new(1001)).is_none());
+        assert!(cache.message_mentions(Id::new(1001)).is_none());
+    }
+
+    /// Test that an author whose payload omits their avatar decoration and
+    /// primary guild doesn't erase those of the cached user.
+    #[test]
+    fn message_create_keeps_user_fields() -> Result<(), ImageHashParseError> {
+        let cache = DefaultInMemoryCache::new();
+        let mut message = test::message();
+
+        let mut user = message.author.clone();
+        user.avatar_decoration_data = Some(Box::new(AvatarDecorationData {
+            asset: ImageHash::parse(b"b2a6536641da91a0b59bd66557c56c36")?,
+            expires_at: None,
+            sku_id: Id::new(1),
+        }));
+        user.primary_guild = Some(Box::new(PrimaryGuild {
+            badge: Some(ImageHash::parse(b"c273213790e64f8230f7ea035817cbbf")?),
+            identity_enabled: Some(true),
+            identity_guild_id: message.guild_id,
+            tag: Some("TWI".to_owned()),
+        }));
+        cache.cache_user(Cow::Owned(user.clone()), message.guild_id);
+
+        message.author.name = "renamed".to_owned();
+        cache.update(&MessageCreate(message));
+
+        let cached = cache.user(user.id).unwrap();
+        assert_eq!("renamed", cached.name);
+        assert_eq!(user.avatar_decoration_data, cached.avatar_decoration_data);
+        assert_eq!(Some("TWI"), cached.guild_tag());
+
+        Ok(())
+    }
+
+    /// Test that a message's partial member updates the roles and nickname of
+    /// a cached member without discarding fields it doesn't include.
+    #[test]
+    fn message_create_merges_member() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::MEMBER)
+            .build();
+        let mut member = test::member(Id::new(3));
+        member.deaf = true;
+        member.pending = true;
+        member.roles = vec![Id::new(10)];
+        cache.update(&MemberAdd {
+            guild_id: Id::new(1),
+            member,
+        });
+
+        let mut message = test::message();
+        let partial = message.member.as_mut().unwrap();
+        partial.nick = Some("new nick".to_owned());
+        partial.roles = vec![Id::new(11), Id::new(12)];
+        cache.update(&MessageCreate(message));
+
+        let cached = cache.member(Id::new(1), Id::new(3)).unwrap();
+        assert_eq!(Some("new nick"), cached.nick());
+        assert_eq!(&[Id::new(11), Id::new(12)], cached.roles());
+        assert_eq!(Some(true), cached.deaf());
+        assert!(cached.pending());
+    }
+
+    /// Test that direct message channels and messages aren't cached when
+    /// disabled, but are by default.
+    #[test]
+    fn cache_dms() {
+        fn process(cache: &DefaultInMemoryCache) {
+            let (_, channel_id, mut channel) = test::guild_channel_text();
+            channel.guild_id = None;
+            channel.kind = ChannelType::Private;
+            cache.update(&ChannelCreate(channel));
+
+            let mut message = test::message();
+            message.channel_id = channel_id;
+            message.guild_id = None;
+            message.member = None;
+            cache.update(&MessageCreate(message));
+        }
+
+        let cache = DefaultInMemoryCache::builder().cache_dms(false).build();
+        process(&cache);
+        assert!(cache.channels.is_empty());
+        assert!(cache.channel_messages.is_empty());
+        assert!(cache.messages.is_empty());
+        assert!(cache.users.is_empty());
+
+        let cache = DefaultInMemoryCache::new();
+        process(&cache);
+        assert_eq!(1, cache.channels.len());
+        assert_eq!(1, cache.channel_messages.len());
+        assert_eq!(1, cache.messages.len());
+        assert_eq!(1, cache.users.len());
+    }
 }
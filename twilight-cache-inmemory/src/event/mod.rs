@@ -1,11 +1,36 @@
+//! Processing of gateway events into the cache.
+//!
+//! # Lock ordering
+//!
+//! The cache is made up of resource maps, such as `members` and `users`, and
+//! relation maps indexing them, such as `guild_members` and `user_guilds`.
+//! Each map is a [`DashMap`] whose shards are locked while a reference or
+//! entry into them is held, so handlers follow these rules to avoid
+//! contention and deadlocks:
+//!
+//! - Read-then-write sequences on a single map use one [`entry`] operation
+//!   instead of a lookup followed by an insertion.
+//! - A guard into a map is never held while calling a helper that accesses
+//!   the same map.
+//! - A guard into a relation map may be held while accessing the resource
+//!   map it indexes, such as evicting messages while holding a channel's
+//!   message queue or removing a user while holding their set of guilds. A
+//!   guard into a resource map is never held while accessing another map.
+//!
+//! [`DashMap`]: dashmap::DashMap
+//! [`entry`]: dashmap::DashMap::entry
+
 pub mod channel;
 pub mod emoji;
+pub mod entitlement;
 pub mod guild;
 pub mod guild_scheduled_events;
 pub mod integration;
 pub mod interaction;
+pub mod invite;
 pub mod member;
 pub mod message;
+pub mod poll;
 pub mod presence;
 pub mod reaction;
 pub mod role;
@@ -14,9 +39,15 @@ pub mod sticker;
 pub mod thread;
 pub mod voice_state;
 
-use std::{borrow::Cow, collections::HashSet};
+use std::borrow::Cow;
 
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    change::{self, CacheChange},
+    config::ResourceType,
+    traits::{CacheableGuild, CacheableUser},
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use dashmap::mapref::entry::Entry;
 use twilight_model::{
     gateway::payload::incoming::{Ready, UnavailableGuild, UserUpdate},
     id::{marker::GuildMarker, Id},
@@ -25,41 +56,79 @@ use twilight_model::{
 
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     fn cache_current_user(&self, current_user: CurrentUser) {
-        self.current_user
-            .lock()
-            .expect("current user poisoned")
-            .replace(CacheModels::CurrentUser::from(current_user));
+        let current_user = CacheModels::CurrentUser::from(current_user);
+        let mut cached = self.current_user.lock().expect("current user poisoned");
+
+        if cached.as_ref() != Some(&current_user) {
+            cached.replace(current_user);
+            change::report(CacheChange::CurrentUserUpdated);
+        }
     }
 
     pub(crate) fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<Id<GuildMarker>>) {
-        if let Some(cached_user) = self.users.get_mut(&user.id) {
-            if cached_user.value() == user.as_ref() {
-                if let Some(guild_id) = guild_id {
-                    self.user_guilds
-                        .entry(user.id)
-                        .or_default()
-                        .insert(guild_id);
-                }
+        let user_id = user.id;
 
-                return;
+        // Record the guild before caching the user, so that a concurrent
+        // member removal doesn't see an empty set of guilds and remove the
+        // user after it's been cached.
+        if let Some(guild_id) = guild_id {
+            self.user_guilds
+                .entry(user_id)
+                .or_default()
+                .insert(guild_id);
+        }
+
+        match self.users.entry(user_id) {
+            Entry::Occupied(entry) if entry.get() == user.as_ref() => {}
+            entry => {
+                entry.insert(CacheModels::User::from(user.into_owned()));
             }
         }
+    }
 
-        let user = user.into_owned();
-        let user_id = user.id;
+    /// Cache a user whose payload may omit some fields, such as a message's
+    /// author, keeping the cached values of those fields.
+    pub(crate) fn cache_partial_user(&self, user: &User, guild_id: Option<Id<GuildMarker>>) {
+        if let Some(guild_id) = guild_id {
+            self.user_guilds
+                .entry(user.id)
+                .or_default()
+                .insert(guild_id);
+        }
 
-        self.users.insert(user_id, CacheModels::User::from(user));
+        match self.users.entry(user.id) {
+            Entry::Occupied(mut entry) => {
+                let mut user = user.clone();
+                entry.get().fill_partial(&mut user);
 
-        if let Some(guild_id) = guild_id {
-            let mut guild_id_set = HashSet::new();
-            guild_id_set.insert(guild_id);
-            self.user_guilds.insert(user_id, guild_id_set);
+                if *entry.get() != user {
+                    entry.insert(CacheModels::User::from(user));
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(CacheModels::User::from(user.clone()));
+            }
         }
     }
 
+    /// Mark a guild as unavailable.
+    ///
+    /// No placeholder is recorded if guilds aren't cached, but the guild's
+    /// other resources are still removed unless they're retained.
     fn unavailable_guild(&self, guild_id: Id<GuildMarker>) {
-        self.unavailable_guilds.insert(guild_id);
-        self.delete_guild(guild_id, true);
+        let wants_guild = self.wants(ResourceType::GUILD);
+
+        if wants_guild && self.unavailable_guilds.insert(guild_id) {
+            change::report(CacheChange::GuildUnavailable(guild_id));
+        }
+
+        if !self.config.retain_unavailable_guilds() {
+            self.delete_guild(guild_id, true);
+        } else if wants_guild {
+            if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+                guild.set_unavailable(Some(true));
+            }
+        }
     }
 }
 
@@ -79,9 +148,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Ready {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for UnavailableGuild {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        if cache.wants(ResourceType::GUILD) {
-            cache.unavailable_guild(self.id);
-        }
+        cache.unavailable_guild(self.id);
     }
 }
 
@@ -97,7 +164,33 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for UserUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{test, DefaultInMemoryCache};
+    use crate::{test, DefaultInMemoryCache, ResourceType};
+    use twilight_model::{
+        gateway::payload::incoming::{Ready, UnavailableGuild as UnavailableGuildEvent},
+        guild::UnavailableGuild,
+        id::Id,
+        oauth::{ApplicationFlags, PartialApplication},
+    };
+
+    fn ready(guilds: u64) -> Ready {
+        Ready {
+            application: PartialApplication {
+                flags: ApplicationFlags::empty(),
+                id: Id::new(1),
+            },
+            guilds: (1..=guilds)
+                .map(|id| UnavailableGuild {
+                    id: Id::new(id),
+                    unavailable: true,
+                })
+                .collect(),
+            resume_gateway_url: "wss://gateway.discord.gg".into(),
+            session_id: String::new(),
+            shard: None,
+            user: test::current_user(1),
+            version: 10,
+        }
+    }
 
     /// Test retrieval of the current user, notably that it doesn't simply
     /// panic or do anything funny. This is the only synchronous mutex that we
@@ -109,4 +202,50 @@ mod tests {
         cache.cache_current_user(test::current_user(1));
         assert!(cache.current_user().is_some());
     }
+
+    /// Test that guild placeholders aren't created from a ready event when
+    /// guilds aren't cached.
+    #[test]
+    fn ready_guilds_unwanted() {
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::MESSAGE)
+            .build();
+        cache.update(&ready(1000));
+
+        assert_eq!(0, cache.stats().guilds());
+        assert_eq!(0, cache.stats().unavailable_guilds());
+        assert!(cache.current_user().is_none());
+
+        cache.update(&UnavailableGuildEvent { id: Id::new(1) });
+        assert_eq!(0, cache.stats().unavailable_guilds());
+    }
+
+    /// Test that guild placeholders are created from a ready event when guilds
+    /// are cached.
+    #[test]
+    fn ready_guilds_wanted() {
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&ready(1000));
+
+        assert_eq!(1000, cache.stats().unavailable_guilds());
+        assert!(cache.current_user().is_some());
+    }
+
+    /// Test that the resources of a guild that becomes unavailable are removed
+    /// even if guilds aren't cached.
+    #[test]
+    fn unavailable_guild_unwanted_removes_resources() {
+        let guild_id = Id::new(1);
+        let emoji_id = Id::new(2);
+        let cache = DefaultInMemoryCache::builder()
+            .resource_types(ResourceType::EMOJI)
+            .build();
+        cache.cache_emoji(guild_id, test::emoji(emoji_id, None));
+        assert!(cache.emoji(emoji_id).is_some());
+
+        cache.update(&UnavailableGuildEvent { id: guild_id });
+
+        assert!(cache.emoji(emoji_id).is_none());
+        assert_eq!(0, cache.stats().unavailable_guilds());
+    }
 }
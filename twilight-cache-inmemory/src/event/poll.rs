@@ -0,0 +1,186 @@
+use crate::{
+    change::{self, CacheChange},
+    config::ResourceType,
+    traits::{CacheableCurrentUser, CacheableMessage},
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use twilight_model::{
+    gateway::payload::incoming::{MessagePollVoteAdd, MessagePollVoteRemove},
+    poll::{AnswerCount, PollResults},
+};
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessagePollVoteAdd {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::MESSAGE) {
+            return;
+        }
+
+        let Some(mut message) = cache.messages.get_mut(&self.message_id) else {
+            return;
+        };
+
+        let Some(poll) = message.poll_mut() else {
+            return;
+        };
+
+        let me = cache
+            .current_user()
+            .is_some_and(|user| user.id() == self.user_id);
+
+        let results = poll.results.get_or_insert_with(|| PollResults {
+            answer_counts: Vec::new(),
+            is_finalized: false,
+        });
+
+        if let Some(answer_count) = results
+            .answer_counts
+            .iter_mut()
+            .find(|answer_count| answer_count.id == self.answer_id)
+        {
+            answer_count.count += 1;
+            answer_count.me_voted |= me;
+        } else {
+            results.answer_counts.push(AnswerCount {
+                id: self.answer_id,
+                count: 1,
+                me_voted: me,
+            });
+        }
+
+        change::report(CacheChange::MessageUpdated(
+            self.channel_id,
+            self.message_id,
+        ));
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessagePollVoteRemove {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::MESSAGE) {
+            return;
+        }
+
+        let Some(mut message) = cache.messages.get_mut(&self.message_id) else {
+            return;
+        };
+
+        let Some(answer_count) = message
+            .poll_mut()
+            .and_then(|poll| poll.results.as_mut())
+            .and_then(|results| {
+                results
+                    .answer_counts
+                    .iter_mut()
+                    .find(|answer_count| answer_count.id == self.answer_id)
+            })
+        else {
+            return;
+        };
+
+        answer_count.count = answer_count.count.saturating_sub(1);
+
+        if answer_count.me_voted
+            && cache
+                .current_user()
+                .is_some_and(|user| user.id() == self.user_id)
+        {
+            answer_count.me_voted = false;
+        }
+
+        change::report(CacheChange::MessageUpdated(
+            self.channel_id,
+            self.message_id,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test;
+    use twilight_model::{
+        gateway::payload::incoming::{MessagePollVoteAdd, MessagePollVoteRemove},
+        id::Id,
+        poll::{AnswerCount, Poll, PollLayoutType, PollMedia, PollResults},
+    };
+
+    fn poll(results: Option<PollResults>) -> Poll {
+        Poll {
+            answers: Vec::new(),
+            allow_multiselect: true,
+            expiry: None,
+            layout_type: PollLayoutType::Default,
+            question: PollMedia {
+                emoji: None,
+                text: Some("question".to_owned()),
+            },
+            results,
+        }
+    }
+
+    const fn vote_add(answer_id: u8) -> MessagePollVoteAdd {
+        MessagePollVoteAdd {
+            answer_id,
+            channel_id: Id::new(2),
+            guild_id: Some(Id::new(1)),
+            message_id: Id::new(4),
+            user_id: Id::new(3),
+        }
+    }
+
+    const fn vote_remove(answer_id: u8) -> MessagePollVoteRemove {
+        MessagePollVoteRemove {
+            answer_id,
+            channel_id: Id::new(2),
+            guild_id: Some(Id::new(1)),
+            message_id: Id::new(4),
+            user_id: Id::new(3),
+        }
+    }
+
+    #[test]
+    fn vote_add_creates_results() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.messages.get_mut(&Id::new(4)).unwrap().poll = Some(poll(None));
+
+        cache.update(&vote_add(1));
+        cache.update(&vote_add(1));
+        cache.update(&vote_add(2));
+
+        let message = cache.message(Id::new(4)).unwrap();
+        let poll = message.poll().unwrap();
+        assert_eq!(Some(2), poll.answer_count(1));
+        assert_eq!(Some(1), poll.answer_count(2));
+        assert!(!poll.results.as_ref().unwrap().is_finalized);
+    }
+
+    #[test]
+    fn vote_remove_never_below_zero() {
+        let cache = test::cache_with_message_and_reactions();
+        cache.messages.get_mut(&Id::new(4)).unwrap().poll = Some(poll(Some(PollResults {
+            answer_counts: vec![AnswerCount {
+                id: 1,
+                count: 1,
+                me_voted: false,
+            }],
+            is_finalized: false,
+        })));
+
+        cache.update(&vote_remove(1));
+        cache.update(&vote_remove(1));
+        cache.update(&vote_remove(2));
+        let message = cache.message(Id::new(4)).unwrap();
+        let poll = message.poll().unwrap();
+        assert_eq!(Some(0), poll.answer_count(1));
+        assert_eq!(None, poll.answer_count(2));
+    }
+
+    #[test]
+    fn vote_without_poll() {
+        let cache = test::cache_with_message_and_reactions();
+
+        cache.update(&vote_add(1));
+        cache.update(&vote_remove(1));
+
+        assert!(cache.message(Id::new(4)).unwrap().poll().is_none());
+    }
+}
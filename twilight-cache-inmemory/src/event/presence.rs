@@ -1,30 +1,90 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    change::{self, CacheChange},
+    config::{ActivityDetail, ResourceType},
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use dashmap::mapref::entry::Entry;
 use twilight_model::{
-    gateway::{payload::incoming::PresenceUpdate, presence::Presence},
-    id::{marker::GuildMarker, Id},
+    gateway::{
+        payload::incoming::PresenceUpdate,
+        presence::{Activity, Presence},
+    },
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 
+/// Trim an activity down to its type and name.
+fn activity_name_only(activity: Activity) -> Activity {
+    Activity {
+        application_id: None,
+        assets: None,
+        buttons: Vec::new(),
+        created_at: None,
+        details: None,
+        emoji: None,
+        flags: None,
+        id: None,
+        instance: None,
+        kind: activity.kind,
+        name: activity.name,
+        party: None,
+        secrets: None,
+        state: None,
+        timestamps: None,
+        url: None,
+    }
+}
+
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     pub(crate) fn cache_presences(
         &self,
         guild_id: Id<GuildMarker>,
         presences: impl IntoIterator<Item = Presence>,
     ) {
+        let mut guild_presences = self.guild_presences.entry(guild_id).or_default();
+
         for presence in presences {
-            self.cache_presence(guild_id, presence);
+            guild_presences.insert(self.upsert_presence(guild_id, presence));
         }
     }
 
     fn cache_presence(&self, guild_id: Id<GuildMarker>, presence: Presence) {
+        let user_id = self.upsert_presence(guild_id, presence);
+
         self.guild_presences
             .entry(guild_id)
             .or_default()
-            .insert(presence.user.id());
+            .insert(user_id);
+    }
 
-        self.presences.insert(
-            (guild_id, presence.user.id()),
-            CacheModels::Presence::from(presence),
-        );
+    /// Insert a presence into the resource map, returning the ID of its user.
+    fn upsert_presence(&self, guild_id: Id<GuildMarker>, mut presence: Presence) -> Id<UserMarker> {
+        match self.config.activity_detail() {
+            ActivityDetail::Full => {}
+            ActivityDetail::NameOnly => {
+                presence.activities = presence
+                    .activities
+                    .into_iter()
+                    .map(activity_name_only)
+                    .collect();
+            }
+            ActivityDetail::StatusOnly => presence.activities = Vec::new(),
+        }
+
+        let user_id = presence.user.id();
+        let presence = CacheModels::Presence::from(presence);
+
+        match self.presences.entry((guild_id, user_id)) {
+            Entry::Occupied(entry) if *entry.get() == presence => {}
+            entry => {
+                entry.insert(presence);
+                change::report(CacheChange::PresenceUpdated(guild_id, user_id));
+            }
+        }
+
+        user_id
     }
 }
 
@@ -40,16 +100,55 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for PresenceUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{test, DefaultInMemoryCache};
+    use crate::{test, ActivityDetail, DefaultInMemoryCache};
     use twilight_model::{
         gateway::{
             event::Event,
             payload::incoming::PresenceUpdate,
-            presence::{ClientStatus, Presence, Status, UserOrId},
+            presence::{
+                Activity, ActivityAssets, ActivityButton, ActivityType, ClientStatus, Presence,
+                Status, UserOrId,
+            },
         },
         id::Id,
     };
 
+    fn rich_presence_update() -> PresenceUpdate {
+        PresenceUpdate(Presence {
+            activities: vec![Activity {
+                application_id: Some(Id::new(2)),
+                assets: Some(ActivityAssets {
+                    large_image: Some("large".to_owned()),
+                    large_text: Some("large text".to_owned()),
+                    small_image: None,
+                    small_text: None,
+                }),
+                buttons: vec![ActivityButton::Unknown],
+                created_at: Some(1_700_000_000),
+                details: Some("details".to_owned()),
+                emoji: None,
+                flags: None,
+                id: Some("id".to_owned()),
+                instance: Some(false),
+                kind: ActivityType::Playing,
+                name: "game".to_owned(),
+                party: None,
+                secrets: None,
+                state: Some("state".to_owned()),
+                timestamps: None,
+                url: None,
+            }],
+            client_status: ClientStatus {
+                desktop: Some(Status::Online),
+                mobile: None,
+                web: None,
+            },
+            guild_id: Id::new(1),
+            status: Status::Online,
+            user: UserOrId::User(test::user(Id::new(1))),
+        })
+    }
+
     #[test]
     fn presence_update() {
         let cache = DefaultInMemoryCache::new();
@@ -78,4 +177,36 @@ mod tests {
             .unwrap()
             .contains(&user_id));
     }
+
+    #[test]
+    fn activity_detail() {
+        let payload = rich_presence_update();
+
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&payload);
+        let presence = cache.presence(Id::new(1), Id::new(1)).unwrap();
+        assert_eq!(payload.0.activities, presence.activities());
+
+        let cache = DefaultInMemoryCache::builder()
+            .activity_detail(ActivityDetail::NameOnly)
+            .build();
+        cache.update(&payload);
+        let presence = cache.presence(Id::new(1), Id::new(1)).unwrap();
+        let activity = &presence.activities()[0];
+        assert_eq!(ActivityType::Playing, activity.kind);
+        assert_eq!("game", activity.name);
+        assert!(activity.assets.is_none());
+        assert!(activity.buttons.is_empty());
+        assert!(activity.details.is_none());
+        assert!(activity.state.is_none());
+
+        let cache = DefaultInMemoryCache::builder()
+            .activity_detail(ActivityDetail::StatusOnly)
+            .build();
+        cache.update(&payload);
+        let presence = cache.presence(Id::new(1), Id::new(1)).unwrap();
+        assert!(presence.activities().is_empty());
+        assert_eq!(Status::Online, presence.status());
+        assert_eq!(payload.0.client_status, *presence.client_status());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    change::{self, CacheChange},
     config::ResourceType,
     traits::{CacheableCurrentUser, CacheableMessage},
     CacheableModels, InMemoryCache, UpdateCache,
@@ -22,37 +23,49 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionAdd {
             return;
         };
 
+        let is_current_user = cache
+            .current_user()
+            .is_some_and(|user| user.id() == self.0.user_id);
+
         if let Some(reaction) = message
             .reactions_mut()
             .iter_mut()
             .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
         {
-            if !reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id() == self.0.user_id {
-                        reaction.me = true;
-                    }
+            if self.0.burst {
+                reaction.count_details.burst += 1;
+                reaction.me_burst |= is_current_user;
+
+                if reaction.burst_colors.is_empty() {
+                    reaction.burst_colors.clone_from(&self.0.burst_colors);
                 }
+            } else {
+                reaction.count_details.normal += 1;
+                reaction.me |= is_current_user;
             }
 
             reaction.count += 1;
         } else {
-            let me = cache
-                .current_user()
-                .is_some_and(|user| user.id() == self.0.user_id);
+            let (burst, normal) = if self.0.burst { (1, 0) } else { (0, 1) };
 
             message.add_reaction(Reaction {
-                burst_colors: Vec::new(),
-                count: 1,
-                count_details: ReactionCountDetails {
-                    burst: 0,
-                    normal: 1,
+                burst_colors: if self.0.burst {
+                    self.0.burst_colors.clone()
+                } else {
+                    Vec::new()
                 },
+                count: 1,
+                count_details: ReactionCountDetails { burst, normal },
                 emoji: self.0.emoji.clone(),
-                me,
-                me_burst: false,
+                me: is_current_user && !self.0.burst,
+                me_burst: is_current_user && self.0.burst,
             });
         }
+
+        change::report(CacheChange::MessageUpdated(
+            self.0.channel_id,
+            self.0.message_id,
+        ));
     }
 }
 
@@ -66,16 +79,30 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemove {
             return;
         };
 
+        let is_current_user = cache
+            .current_user()
+            .is_some_and(|user| user.id() == self.0.user_id);
+
         if let Some(reaction) = message
             .reactions_mut()
             .iter_mut()
             .find(|r| reactions_eq(&r.emoji, &self.0.emoji))
         {
-            if reaction.me {
-                if let Some(current_user) = cache.current_user() {
-                    if current_user.id() == self.0.user_id {
-                        reaction.me = false;
-                    }
+            if self.0.burst {
+                reaction.count_details.burst = reaction.count_details.burst.saturating_sub(1);
+
+                if is_current_user {
+                    reaction.me_burst = false;
+                }
+
+                if reaction.count_details.burst == 0 {
+                    reaction.burst_colors.clear();
+                }
+            } else {
+                reaction.count_details.normal = reaction.count_details.normal.saturating_sub(1);
+
+                if is_current_user {
+                    reaction.me = false;
                 }
             }
 
@@ -84,6 +111,11 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemove {
             } else {
                 message.retain_reactions(|e| !(reactions_eq(&e.emoji, &self.0.emoji)));
             }
+
+            change::report(CacheChange::MessageUpdated(
+                self.0.channel_id,
+                self.0.message_id,
+            ));
         }
     }
 }
@@ -98,7 +130,13 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveAl
             return;
         };
 
-        message.clear_reactions();
+        if !message.reactions().is_empty() {
+            message.clear_reactions();
+            change::report(CacheChange::MessageUpdated(
+                self.channel_id,
+                self.message_id,
+            ));
+        }
     }
 }
 
@@ -119,6 +157,10 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ReactionRemoveEm
 
         if let Some(index) = maybe_index {
             message.remove_reaction(index);
+            change::report(CacheChange::MessageUpdated(
+                self.channel_id,
+                self.message_id,
+            ));
         }
     }
 }
@@ -142,12 +184,15 @@ mod tests {
     use super::reactions_eq;
     use crate::{model::CachedMessage, test};
     use twilight_model::{
-        channel::message::{EmojiReactionType, Reaction},
+        channel::message::{EmojiReactionType, Reaction, ReactionCountDetails},
         gateway::{
-            payload::incoming::{ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji},
+            payload::incoming::{
+                ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
+            },
             GatewayReaction,
         },
         id::Id,
+        util::HexColor,
     };
 
     fn find_custom_react(msg: &CachedMessage) -> Option<&Reaction> {
@@ -241,6 +286,56 @@ mod tests {
         assert!(custom_react.is_none());
     }
 
+    /// Test that normal and super reactions are counted separately.
+    #[test]
+    fn reaction_burst() {
+        let cache = test::cache_with_message_and_reactions();
+        let reaction = GatewayReaction {
+            burst: true,
+            burst_colors: vec![HexColor(255, 0, 255)],
+            channel_id: Id::new(2),
+            emoji: EmojiReactionType::Unicode {
+                name: "😀".to_owned(),
+            },
+            guild_id: Some(Id::new(1)),
+            member: None,
+            message_author_id: None,
+            message_id: Id::new(4),
+            user_id: Id::new(8),
+        };
+        let find_smiley = |reactions: &[Reaction]| {
+            reactions
+                .iter()
+                .find(|r| matches!(&r.emoji, EmojiReactionType::Unicode {name} if name == "😀"))
+                .cloned()
+                .unwrap()
+        };
+
+        cache.update(&ReactionAdd(reaction.clone()));
+        let smiley = find_smiley(&cache.message(Id::new(4)).unwrap().reactions);
+        assert_eq!(smiley.count, 3);
+        assert_eq!(
+            smiley.count_details,
+            ReactionCountDetails {
+                burst: 1,
+                normal: 2
+            }
+        );
+        assert_eq!(smiley.burst_colors, [HexColor(255, 0, 255)]);
+
+        cache.update(&ReactionRemove(reaction));
+        let smiley = find_smiley(&cache.message(Id::new(4)).unwrap().reactions);
+        assert_eq!(smiley.count, 2);
+        assert_eq!(
+            smiley.count_details,
+            ReactionCountDetails {
+                burst: 0,
+                normal: 2
+            }
+        );
+        assert!(smiley.burst_colors.is_empty());
+    }
+
     #[test]
     fn reaction_remove_all() {
         let cache = test::cache_with_message_and_reactions();
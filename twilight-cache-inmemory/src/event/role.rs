@@ -1,4 +1,10 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    change::{self, CacheChange},
+    config::ResourceType,
+    traits::CacheableMember,
+    CacheableModels, GuildResource, InMemoryCache, UpdateCache,
+};
+use dashmap::mapref::entry::Entry;
 use twilight_model::{
     gateway::payload::incoming::{RoleCreate, RoleDelete, RoleUpdate},
     guild::Role,
@@ -27,20 +33,49 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             .insert(role.id);
 
         // Insert the role into the all roles map
-        crate::upsert_guild_item(
-            &self.roles,
-            guild_id,
-            role.id,
-            CacheModels::Role::from(role),
-        );
+        let role_id = role.id;
+        let value = CacheModels::Role::from(role);
+
+        match self.roles.entry(role_id) {
+            Entry::Occupied(entry) if entry.get().value == value => {}
+            Entry::Occupied(mut entry) => {
+                entry.insert(GuildResource { guild_id, value });
+                change::report(CacheChange::RoleUpdated(guild_id, role_id));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(GuildResource { guild_id, value });
+                change::report(CacheChange::RoleAdded(guild_id, role_id));
+            }
+        }
     }
 
-    fn delete_role(&self, role_id: Id<RoleMarker>) {
+    fn delete_role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) {
         if let Some((_, role)) = self.roles.remove(&role_id) {
+            change::report(CacheChange::RoleRemoved(role.guild_id, role_id));
+
             if let Some(mut roles) = self.guild_roles.get_mut(&role.guild_id) {
                 roles.remove(&role_id);
             }
         }
+
+        if !self.config.remove_deleted_member_roles() {
+            return;
+        }
+
+        // Members aren't updated when a role is deleted, so remove the role
+        // from them to not reference it.
+        let Some(user_ids) = self.guild_members.get(&guild_id) else {
+            return;
+        };
+
+        for user_id in user_ids.iter() {
+            if let Some(mut member) = self.members.get_mut(&(guild_id, *user_id)) {
+                if member.roles().contains(&role_id) {
+                    member.remove_role(role_id);
+                    change::report(CacheChange::MemberUpdated(guild_id, *user_id));
+                }
+            }
+        }
     }
 }
 
@@ -60,7 +95,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for RoleDelete {
             return;
         }
 
-        cache.delete_role(self.role_id);
+        cache.delete_role(self.guild_id, self.role_id);
     }
 }
 
@@ -77,7 +112,11 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for RoleUpdate {
 #[cfg(test)]
 mod tests {
     use crate::{test, DefaultInMemoryCache};
-    use twilight_model::{gateway::payload::incoming::RoleCreate, id::Id};
+    use twilight_model::{
+        gateway::payload::incoming::{RoleCreate, RoleDelete, RoleUpdate},
+        guild::RoleColors,
+        id::Id,
+    };
 
     #[test]
     fn insert_role_on_event() {
@@ -96,6 +135,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn update_role_colors() {
+        let cache = DefaultInMemoryCache::new();
+        let colors = RoleColors {
+            primary_color: 0x00a9_c9ff,
+            secondary_color: Some(0x00ff_bbec),
+            tertiary_color: None,
+        };
+
+        cache.update(&RoleCreate {
+            guild_id: Id::new(1),
+            role: test::role(Id::new(2)),
+        });
+
+        let mut role = test::role(Id::new(2));
+        role.colors = Some(colors);
+        cache.update(&RoleUpdate {
+            guild_id: Id::new(1),
+            role,
+        });
+
+        assert_eq!(Some(colors), cache.role(Id::new(2)).unwrap().colors);
+    }
+
     #[test]
     fn cache_role() {
         let cache = DefaultInMemoryCache::new();
@@ -154,4 +217,64 @@ mod tests {
                 == &role));
         }
     }
+
+    /// Test that deleting a role removes it from the roles of cached members.
+    #[test]
+    fn delete_role_removes_member_role() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let (role_a, role_b) = (Id::new(2), Id::new(3));
+
+        let mut first = test::role(role_a);
+        first.position = 1;
+        let mut second = test::role(role_b);
+        second.position = 2;
+        cache.cache_roles(guild_id, [first, second]);
+
+        let mut member = test::member(Id::new(4));
+        member.roles = vec![role_a, role_b];
+        cache.cache_member(guild_id, member);
+        assert_eq!(
+            Some(role_b),
+            cache.member_highest_role(guild_id, Id::new(4))
+        );
+
+        cache.update(&RoleDelete {
+            guild_id,
+            role_id: role_b,
+        });
+
+        assert_eq!(
+            &[role_a],
+            cache.member(guild_id, Id::new(4)).unwrap().roles()
+        );
+        assert_eq!(
+            Some(role_a),
+            cache.member_highest_role(guild_id, Id::new(4))
+        );
+    }
+
+    #[test]
+    fn delete_role_retains_member_role() {
+        let cache = DefaultInMemoryCache::builder()
+            .remove_deleted_member_roles(false)
+            .build();
+        let guild_id = Id::new(1);
+
+        cache.cache_roles(guild_id, [test::role(Id::new(2))]);
+        let mut member = test::member(Id::new(3));
+        member.roles = vec![Id::new(2)];
+        cache.cache_member(guild_id, member);
+
+        cache.update(&RoleDelete {
+            guild_id,
+            role_id: Id::new(2),
+        });
+
+        assert_eq!(
+            &[Id::new(2)],
+            cache.member(guild_id, Id::new(3)).unwrap().roles()
+        );
+        assert!(cache.member_highest_role(guild_id, Id::new(3)).is_none());
+    }
 }
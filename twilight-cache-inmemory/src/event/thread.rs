@@ -15,6 +15,10 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadCreate {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if cache.wants(ResourceType::MESSAGE) {
+            cache.delete_channel_messages(self.id);
+        }
+
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
@@ -1,5 +1,10 @@
 use crate::CacheableVoiceState;
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    change::{self, CacheChange},
+    config::ResourceType,
+    CacheableModels, InMemoryCache, UpdateCache,
+};
+use dashmap::mapref::entry::Entry;
 use twilight_model::gateway::payload::incoming::VoiceStateUpdate;
 use twilight_model::voice::VoiceState;
 
@@ -17,30 +22,45 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         };
 
         let user_id = voice_state.user_id;
+        let key = (guild_id, user_id);
+        let channel_id = voice_state.channel_id;
 
-        // Check if the user is switching channels in the same guild (ie. they already have a voice state entry)
-        if let Some(voice_state) = self.voice_states.get(&(guild_id, user_id)) {
-            let remove_channel_mapping = self
-                .voice_state_channels
-                .get_mut(&voice_state.channel_id())
-                .is_some_and(|mut channel_voice_states| {
-                    channel_voice_states.remove(&(guild_id, user_id));
+        // Replace or remove the voice state in a single operation, taking the
+        // channel the user was previously connected to.
+        let previous_channel_id = if let Some(channel_id) = channel_id {
+            let cached_voice_state =
+                CacheModels::VoiceState::from((channel_id, guild_id, voice_state));
+            change::report(CacheChange::VoiceStateChanged(guild_id, user_id));
 
-                    channel_voice_states.is_empty()
-                });
+            self.voice_states
+                .insert(key, cached_voice_state)
+                .map(|previous| previous.channel_id())
+        } else {
+            let previous = self.voice_states.remove(&key);
 
-            if remove_channel_mapping {
-                self.voice_state_channels.remove(&voice_state.channel_id());
+            if previous.is_some() {
+                change::report(CacheChange::VoiceStateChanged(guild_id, user_id));
             }
-        }
 
-        if let Some(channel_id) = voice_state.channel_id {
-            let cached_voice_state =
-                CacheModels::VoiceState::from((channel_id, guild_id, voice_state));
+            previous.map(|(_, previous)| previous.channel_id())
+        };
 
-            self.voice_states
-                .insert((guild_id, user_id), cached_voice_state);
+        // Remove the user from the channel they were previously connected to
+        // if they've switched channels or left.
+        if let Some(previous_channel_id) =
+            previous_channel_id.filter(|previous| Some(*previous) != channel_id)
+        {
+            if let Entry::Occupied(mut entry) = self.voice_state_channels.entry(previous_channel_id)
+            {
+                entry.get_mut().remove(&key);
+
+                if entry.get().is_empty() {
+                    entry.remove();
+                }
+            }
+        }
 
+        if let Some(channel_id) = channel_id {
             self.voice_state_guilds
                 .entry(guild_id)
                 .or_default()
@@ -49,25 +69,14 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             self.voice_state_channels
                 .entry(channel_id)
                 .or_default()
-                .insert((guild_id, user_id));
-        } else {
+                .insert(key);
+        } else if let Entry::Occupied(mut entry) = self.voice_state_guilds.entry(guild_id) {
             // voice channel_id does not exist, signifying that the user has left
-            {
-                let remove_guild =
-                    self.voice_state_guilds
-                        .get_mut(&guild_id)
-                        .is_some_and(|mut guild_users| {
-                            guild_users.remove(&user_id);
-
-                            guild_users.is_empty()
-                        });
+            entry.get_mut().remove(&user_id);
 
-                if remove_guild {
-                    self.voice_state_guilds.remove(&guild_id);
-                }
+            if entry.get().is_empty() {
+                entry.remove();
             }
-
-            self.voice_states.remove(&(guild_id, user_id));
         }
     }
 }
@@ -317,6 +326,7 @@ mod tests {
                     mfa_enabled: None,
                     name: "test".to_owned(),
                     premium_type: None,
+                    primary_guild: None,
                     public_flags: None,
                     system: None,
                     verified: None,
@@ -11,16 +11,30 @@
 //! [`IterReference::value`], both returning immutable references to the
 //! underlying key and value. It also implements [`std::ops::Deref`] and
 //! dereferences to the value.
-
-use crate::{CacheableModels, GuildResource, InMemoryCache};
-use dashmap::{iter::Iter, mapref::multiple::RefMulti};
-use std::{hash::Hash, ops::Deref};
-use twilight_model::id::{
-    marker::{
-        ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
-        StageMarker, StickerMarker, UserMarker,
+//!
+//! Iterators over the resources of a single guild, such as
+//! [`InMemoryCacheIter::emojis_in`], return a [`GuildResourceIter`] instead,
+//! which yields [`Reference`]s.
+//!
+//! With the `rayon` feature enabled, methods such as
+//! [`InMemoryCacheIter::members_par`] create a [`ResourceParIter`] instead,
+//! which implements [`rayon::iter::ParallelIterator`] and also yields
+//! [`IterReference`]s.
+
+use crate::{traits::CacheableChannel, CacheableModels, GuildResource, InMemoryCache, Reference};
+use dashmap::{iter::Iter, mapref::multiple::RefMulti, DashMap};
+#[cfg(feature = "rayon")]
+use rayon::iter::{plumbing::UnindexedConsumer, IntoParallelIterator, ParallelIterator};
+use std::{hash::Hash, ops::Deref, vec::IntoIter};
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{
+            ChannelMarker, EmojiMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
+            StageMarker, StickerMarker, UserMarker,
+        },
+        Id,
     },
-    Id,
 };
 
 /// Reference to a resource value being iterated over in the cache.
@@ -141,11 +155,61 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
         ResourceIter::new(self.0.channels.iter())
     }
 
+    /// Create an iterator over the channels, including threads, in a guild.
+    ///
+    /// The iterator is empty if the guild's channels are not cached.
+    ///
+    /// Refer to [`GuildResourceIter`] for how the guild's channels are
+    /// collected.
+    pub fn channels_in(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GuildResourceIter<'a, Id<ChannelMarker>, CacheModels::Channel> {
+        GuildResourceIter::new(&self.0.guild_channels, guild_id, &self.0.channels)
+    }
+
+    /// Create an iterator over the channels of a type in a guild, such as
+    /// [`ChannelType::GuildVoice`] or [`ChannelType::PublicThread`].
+    ///
+    /// The iterator is empty if the guild's channels are not cached.
+    ///
+    /// The channels are filtered by their type when the iterator is created.
+    /// Refer to [`GuildResourceIter`] for how the guild's channels are
+    /// collected.
+    pub fn channels_in_kind(
+        &self,
+        guild_id: Id<GuildMarker>,
+        kind: ChannelType,
+    ) -> GuildResourceIter<'a, Id<ChannelMarker>, CacheModels::Channel> {
+        let mut iter = self.channels_in(guild_id);
+        let channels = &self.0.channels;
+        iter.retain_ids(|id| {
+            channels
+                .get(id)
+                .is_some_and(|channel| channel.kind() == kind)
+        });
+
+        iter
+    }
+
     /// Create an iterator over the emojis in the cache.
     pub fn emojis(&self) -> ResourceIter<'a, Id<EmojiMarker>, GuildResource<CacheModels::Emoji>> {
         ResourceIter::new(self.0.emojis.iter())
     }
 
+    /// Create an iterator over the emojis in a guild.
+    ///
+    /// The iterator is empty if the guild's emojis are not cached.
+    ///
+    /// Refer to [`GuildResourceIter`] for how the guild's emojis are
+    /// collected.
+    pub fn emojis_in(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GuildResourceIter<'a, Id<EmojiMarker>, GuildResource<CacheModels::Emoji>> {
+        GuildResourceIter::new(&self.0.guild_emojis, guild_id, &self.0.emojis)
+    }
+
     /// Create an iterator over the guilds in the cache.
     pub fn guilds(&self) -> ResourceIter<'a, Id<GuildMarker>, CacheModels::Guild> {
         ResourceIter::new(self.0.guilds.iter())
@@ -201,6 +265,19 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
         ResourceIter::new(self.0.stickers.iter())
     }
 
+    /// Create an iterator over the stickers in a guild.
+    ///
+    /// The iterator is empty if the guild's stickers are not cached.
+    ///
+    /// Refer to [`GuildResourceIter`] for how the guild's stickers are
+    /// collected.
+    pub fn stickers_in(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GuildResourceIter<'a, Id<StickerMarker>, GuildResource<CacheModels::Sticker>> {
+        GuildResourceIter::new(&self.0.guild_stickers, guild_id, &self.0.stickers)
+    }
+
     /// Create an iterator over the users in the cache.
     pub fn users(&self) -> ResourceIter<'a, Id<UserMarker>, CacheModels::User> {
         ResourceIter::new(self.0.users.iter())
@@ -214,6 +291,60 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<'a, CacheModels: CacheableModels> InMemoryCacheIter<'a, CacheModels> {
+    /// Create a parallel iterator over the guilds in the cache.
+    ///
+    /// Refer to the [deadlock caveats] before using.
+    ///
+    /// [deadlock caveats]: ResourceParIter#deadlocks
+    pub fn guilds_par(&self) -> ResourceParIter<'a, Id<GuildMarker>, CacheModels::Guild>
+    where
+        CacheModels::Guild: Send + Sync,
+    {
+        ResourceParIter::new((&self.0.guilds).into_par_iter())
+    }
+
+    /// Create a parallel iterator over the members across all guilds in the
+    /// cache.
+    ///
+    /// Refer to the [deadlock caveats] before using.
+    ///
+    /// [deadlock caveats]: ResourceParIter#deadlocks
+    pub fn members_par(
+        &self,
+    ) -> ResourceParIter<'a, (Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>
+    where
+        CacheModels::Member: Send + Sync,
+    {
+        ResourceParIter::new((&self.0.members).into_par_iter())
+    }
+
+    /// Create a parallel iterator over the messages in the cache.
+    ///
+    /// Refer to the [deadlock caveats] before using.
+    ///
+    /// [deadlock caveats]: ResourceParIter#deadlocks
+    pub fn messages_par(&self) -> ResourceParIter<'a, Id<MessageMarker>, CacheModels::Message>
+    where
+        CacheModels::Message: Send + Sync,
+    {
+        ResourceParIter::new((&self.0.messages).into_par_iter())
+    }
+
+    /// Create a parallel iterator over the users in the cache.
+    ///
+    /// Refer to the [deadlock caveats] before using.
+    ///
+    /// [deadlock caveats]: ResourceParIter#deadlocks
+    pub fn users_par(&self) -> ResourceParIter<'a, Id<UserMarker>, CacheModels::User>
+    where
+        CacheModels::User: Send + Sync,
+    {
+        ResourceParIter::new((&self.0.users).into_par_iter())
+    }
+}
+
 /// Generic iterator over key-value pairs of a resource.
 ///
 /// The iteration order is arbitrary.
@@ -255,13 +386,154 @@ impl<'a, K: Eq + Hash, V> Iterator for ResourceIter<'a, K, V> {
     }
 }
 
+/// Iterator over the resources of a single guild.
+///
+/// The IDs of the guild's resources are collected when the iterator is
+/// created, so no lock on the guild's set of IDs is held while iterating.
+/// Resources removed from the cache in the meantime are skipped and resources
+/// added in the meantime are not yielded.
+///
+/// Each yielded [`Reference`] holds a read lock on a shard of the underlying
+/// map for as long as it is alive, so it should be dropped before updating
+/// the cache.
+///
+/// # Examples
+///
+/// Collect the names of a guild's animated emojis:
+///
+/// ```no_run
+/// use twilight_cache_inmemory::DefaultInMemoryCache;
+/// use twilight_model::id::Id;
+///
+/// let cache = DefaultInMemoryCache::new();
+///
+/// // later in the application...
+/// let names = cache
+///     .iter()
+///     .emojis_in(Id::new(1))
+///     .filter(|emoji| emoji.animated())
+///     .map(|emoji| emoji.name().to_owned())
+///     .collect::<Vec<_>>();
+/// ```
+pub struct GuildResourceIter<'a, K, V> {
+    ids: IntoIter<K>,
+    map: &'a DashMap<K, V>,
+}
+
+impl<'a, K: Copy + Eq + Hash, V> GuildResourceIter<'a, K, V> {
+    /// Create a new iterator over a guild's resources, snapshotting the IDs
+    /// of the resources.
+    fn new<S>(
+        guild_ids: &DashMap<Id<GuildMarker>, S>,
+        guild_id: Id<GuildMarker>,
+        map: &'a DashMap<K, V>,
+    ) -> Self
+    where
+        for<'b> &'b S: IntoIterator<Item = &'b K>,
+    {
+        // The guard must be dropped before any resource is yielded.
+        let ids = guild_ids
+            .get(&guild_id)
+            .map(|ids| ids.value().into_iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        Self {
+            ids: ids.into_iter(),
+            map,
+        }
+    }
+
+    /// Retain only the snapshotted IDs matching a predicate.
+    fn retain_ids(&mut self, mut f: impl FnMut(&K) -> bool) {
+        let ids = self.ids.by_ref().filter(|id| f(id)).collect::<Vec<_>>();
+        self.ids = ids.into_iter();
+    }
+}
+
+impl<'a, K: Eq + Hash, V> Iterator for GuildResourceIter<'a, K, V> {
+    type Item = Reference<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ids
+            .by_ref()
+            .find_map(|id| self.map.get(&id).map(Reference::new))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.ids.size_hint().1)
+    }
+}
+
+/// Generic parallel iterator over key-value pairs of a resource.
+///
+/// The iteration order is arbitrary.
+///
+/// # Deadlocks
+///
+/// Parallel iterators hold read locks on shards of the underlying maps for as
+/// long as any of their [`IterReference`]s are alive, potentially across
+/// multiple threads. Updating the cache, such as by calling
+/// [`InMemoryCache::update`], while iterating may deadlock, both from within
+/// the iterator's closures and from other threads that are waiting on an
+/// iteration to finish.
+///
+/// # Examples
+///
+/// Count the number of members across all guilds that have a given role:
+///
+/// ```no_run
+/// use rayon::iter::ParallelIterator;
+/// use twilight_cache_inmemory::DefaultInMemoryCache;
+/// use twilight_model::id::Id;
+///
+/// let cache = DefaultInMemoryCache::new();
+///
+/// // later in the application...
+/// let role_id = Id::new(1);
+/// let count = cache
+///     .iter()
+///     .members_par()
+///     .filter(|member| member.roles().contains(&role_id))
+///     .count();
+///
+/// println!("members with the role: {count}");
+/// ```
+#[cfg(feature = "rayon")]
+pub struct ResourceParIter<'a, K, V> {
+    iter: dashmap::rayon::map::Iter<'a, K, V>,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, K, V> ResourceParIter<'a, K, V> {
+    /// Create a new parallel iterator over a resource.
+    pub(super) const fn new(iter: dashmap::rayon::map::Iter<'a, K, V>) -> Self {
+        Self { iter }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, K: Eq + Hash + Send + Sync, V: Send + Sync> ParallelIterator
+    for ResourceParIter<'a, K, V>
+{
+    type Item = IterReference<'a, K, V>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.iter.map(IterReference::new).drive_unindexed(consumer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{InMemoryCacheIter, IterReference, ResourceIter};
+    use super::{GuildResourceIter, InMemoryCacheIter, IterReference, ResourceIter};
     use crate::{test, DefaultCacheModels, DefaultInMemoryCache};
     use static_assertions::assert_impl_all;
-    use std::{borrow::Cow, fmt::Debug};
+    use std::{borrow::Cow, collections::HashMap, fmt::Debug};
     use twilight_model::{
+        channel::ChannelType,
+        gateway::payload::incoming::GuildCreate,
         id::{marker::UserMarker, Id},
         user::User,
     };
@@ -269,6 +541,9 @@ mod tests {
     assert_impl_all!(InMemoryCacheIter<'_, DefaultCacheModels>: Debug, Send, Sync);
     assert_impl_all!(IterReference<'_, Id<UserMarker>, User>: Send, Sync);
     assert_impl_all!(ResourceIter<'_, Id<UserMarker>, User>: Iterator, Send, Sync);
+    assert_impl_all!(GuildResourceIter<'_, Id<UserMarker>, User>: Iterator, Send, Sync);
+    #[cfg(feature = "rayon")]
+    assert_impl_all!(super::ResourceParIter<'_, Id<UserMarker>, User>: rayon::iter::ParallelIterator, Send);
 
     #[test]
     fn iter() {
@@ -291,4 +566,156 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn guild_resources() {
+        let guild_id = Id::new(1);
+        let mut guild = test::guild(guild_id, None);
+        guild.emojis = (2..=4).map(|id| test::emoji(Id::new(id), None)).collect();
+        guild.stickers = (5..=6)
+            .map(|id| test::sticker(Id::new(id), guild_id))
+            .collect();
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&GuildCreate::Available(guild));
+        cache.update(&GuildCreate::Available(test::guild(Id::new(7), None)));
+
+        assert_eq!(Some(3), cache.guild_emoji_count(guild_id));
+        assert_eq!(Some(0), cache.guild_emoji_count(Id::new(7)));
+        assert!(cache.guild_emoji_count(Id::new(8)).is_none());
+
+        // Holding another reference to an emoji must not deadlock.
+        let held = cache.emoji(Id::new(3)).unwrap();
+        let mut emojis = cache
+            .iter()
+            .emojis_in(guild_id)
+            .map(|emoji| {
+                assert_eq!(guild_id, emoji.guild_id());
+                // Nor may retrieving the guild's emoji IDs during iteration.
+                assert!(cache.guild_emojis(guild_id).is_some());
+
+                emoji.id()
+            })
+            .collect::<Vec<_>>();
+        drop(held);
+        emojis.sort_unstable();
+        assert_eq!(vec![Id::new(2), Id::new(3), Id::new(4)], emojis);
+
+        let mut stickers = cache
+            .iter()
+            .stickers_in(guild_id)
+            .map(|sticker| sticker.id())
+            .collect::<Vec<_>>();
+        stickers.sort_unstable();
+        assert_eq!(vec![Id::new(5), Id::new(6)], stickers);
+
+        assert_eq!(0, cache.iter().emojis_in(Id::new(7)).count());
+        assert_eq!(0, cache.iter().stickers_in(Id::new(8)).count());
+    }
+
+    #[test]
+    fn guild_channels() {
+        let (guild_id, _, channel) = test::guild_channel_text();
+        let mut guild = test::guild(guild_id, None);
+        guild.channels = [
+            (2, ChannelType::GuildText),
+            (3, ChannelType::GuildVoice),
+            (4, ChannelType::GuildVoice),
+            (5, ChannelType::GuildCategory),
+        ]
+        .into_iter()
+        .map(|(id, kind)| {
+            let mut channel = channel.clone();
+            channel.id = Id::new(id);
+            channel.kind = kind;
+
+            channel
+        })
+        .collect();
+        let mut thread = channel.clone();
+        thread.id = Id::new(6);
+        thread.kind = ChannelType::PublicThread;
+        thread.parent_id = Some(Id::new(2));
+        guild.threads = vec![thread];
+
+        let cache = DefaultInMemoryCache::new();
+        cache.update(&GuildCreate::Available(guild));
+        cache.update(&GuildCreate::Available(test::guild(Id::new(7), None)));
+
+        let ids = |kind: Option<ChannelType>| {
+            let mut ids = match kind {
+                Some(kind) => cache
+                    .iter()
+                    .channels_in_kind(guild_id, kind)
+                    .map(|channel| channel.id)
+                    .collect::<Vec<_>>(),
+                None => cache
+                    .iter()
+                    .channels_in(guild_id)
+                    .map(|channel| channel.id)
+                    .collect(),
+            };
+            ids.sort_unstable();
+
+            ids.into_iter().map(Id::get).collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![2, 3, 4, 5, 6], ids(None));
+        assert_eq!(vec![2], ids(Some(ChannelType::GuildText)));
+        assert_eq!(vec![3, 4], ids(Some(ChannelType::GuildVoice)));
+        assert_eq!(vec![5], ids(Some(ChannelType::GuildCategory)));
+        assert_eq!(vec![6], ids(Some(ChannelType::PublicThread)));
+        assert!(ids(Some(ChannelType::GuildForum)).is_empty());
+
+        assert_eq!(0, cache.iter().channels_in(Id::new(7)).count());
+        assert_eq!(0, cache.iter().channels_in(Id::new(8)).count());
+
+        let counts = cache.stats().guild_channels_by_kind(guild_id).unwrap();
+        assert_eq!(
+            HashMap::from([
+                (ChannelType::GuildText, 1),
+                (ChannelType::GuildVoice, 2),
+                (ChannelType::GuildCategory, 1),
+                (ChannelType::PublicThread, 1),
+            ]),
+            counts
+        );
+        assert_eq!(
+            Some(HashMap::new()),
+            cache.stats().guild_channels_by_kind(Id::new(7))
+        );
+        assert!(cache.stats().guild_channels_by_kind(Id::new(8)).is_none());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::iter::ParallelIterator;
+
+        let cache = DefaultInMemoryCache::new();
+
+        for guild_id in 1..=4 {
+            for user_id in 1..=100 {
+                cache.cache_member(Id::new(guild_id), test::member(Id::new(user_id)));
+            }
+        }
+
+        let predicate = |user_id: Id<UserMarker>| user_id.get() % 3 == 0;
+        let sequential = cache
+            .iter()
+            .members()
+            .filter(|member| predicate(member.user_id()))
+            .count();
+        let parallel = cache
+            .iter()
+            .members_par()
+            .filter(|member| predicate(member.user_id()))
+            .count();
+
+        assert_eq!(132, sequential);
+        assert_eq!(sequential, parallel);
+        assert_eq!(
+            cache.iter().users().count(),
+            cache.iter().users_par().count(),
+        );
+    }
 }
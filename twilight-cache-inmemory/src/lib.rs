@@ -19,7 +19,11 @@ pub mod traits;
 #[cfg(feature = "permission-calculator")]
 pub mod permission;
 
+#[cfg(feature = "debug-json")]
+mod debug;
+
 mod builder;
+mod change;
 mod config;
 mod event;
 mod stats;
@@ -29,7 +33,8 @@ mod test;
 
 pub use self::{
     builder::InMemoryCacheBuilder,
-    config::{Config, ResourceType},
+    change::CacheChange,
+    config::{ActivityDetail, Config, ResourceType},
     stats::InMemoryCacheStats,
     traits::{
         CacheableChannel, CacheableCurrentUser, CacheableEmoji, CacheableGuild,
@@ -39,6 +44,8 @@ pub use self::{
     },
 };
 
+#[cfg(feature = "debug-json")]
+pub use self::debug::DebugEntity;
 #[cfg(feature = "permission-calculator")]
 pub use self::permission::InMemoryCachePermissions;
 
@@ -48,13 +55,16 @@ use dashmap::{
     DashMap, DashSet,
 };
 use std::{
+    cmp::Reverse,
     collections::{HashSet, VecDeque},
     fmt::{Debug, Formatter, Result as FmtResult},
     hash::Hash,
     ops::Deref,
     sync::Mutex,
+    vec::IntoIter,
 };
 use twilight_model::{
+    application::monetization::Entitlement,
     channel::{Channel, StageInstance},
     gateway::event::Event,
     guild::{scheduled_event::GuildScheduledEvent, GuildIntegration, Role},
@@ -66,6 +76,7 @@ use twilight_model::{
         Id,
     },
     user::{CurrentUser, User},
+    util::Timestamp,
 };
 
 /// Resource associated with a guild.
@@ -197,10 +208,12 @@ pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
     // So long as the lock isn't held across await or panic points this is fine.
     current_user: Mutex<Option<CacheModels::CurrentUser>>,
     emojis: DashMap<Id<EmojiMarker>, GuildResource<CacheModels::Emoji>>,
+    entitlements: DashMap<Id<UserMarker>, Vec<Entitlement>>,
     guilds: DashMap<Id<GuildMarker>, CacheModels::Guild>,
     guild_channels: DashMap<Id<GuildMarker>, HashSet<Id<ChannelMarker>>>,
     guild_emojis: DashMap<Id<GuildMarker>, HashSet<Id<EmojiMarker>>>,
     guild_integrations: DashMap<Id<GuildMarker>, HashSet<Id<IntegrationMarker>>>,
+    guild_invites: DashMap<Id<GuildMarker>, HashSet<String>>,
     guild_members: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
     guild_presences: DashMap<Id<GuildMarker>, HashSet<Id<UserMarker>>>,
     guild_roles: DashMap<Id<GuildMarker>, HashSet<Id<RoleMarker>>>,
@@ -211,8 +224,12 @@ pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
         (Id<GuildMarker>, Id<IntegrationMarker>),
         GuildResource<CacheModels::GuildIntegration>,
     >,
+    invites: DashMap<String, model::CachedInvite>,
     members: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Member>,
     messages: DashMap<Id<MessageMarker>, CacheModels::Message>,
+    /// Last pin timestamps of guild channels received before the channel
+    /// itself, applied once the channel is cached, along with their guild.
+    pending_pin_timestamps: DashMap<Id<ChannelMarker>, (Id<GuildMarker>, Option<Timestamp>)>,
     presences: DashMap<(Id<GuildMarker>, Id<UserMarker>), CacheModels::Presence>,
     roles: DashMap<Id<RoleMarker>, GuildResource<CacheModels::Role>>,
     scheduled_events:
@@ -294,18 +311,22 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             .expect("current user poisoned")
             .take();
         self.emojis.clear();
+        self.entitlements.clear();
         self.guilds.clear();
         self.guild_channels.clear();
         self.guild_emojis.clear();
         self.guild_integrations.clear();
+        self.guild_invites.clear();
         self.guild_members.clear();
         self.guild_presences.clear();
         self.guild_roles.clear();
         self.guild_stage_instances.clear();
         self.guild_stickers.clear();
         self.integrations.clear();
+        self.invites.clear();
         self.members.clear();
         self.messages.clear();
+        self.pending_pin_timestamps.clear();
         self.presences.clear();
         self.roles.clear();
         self.stickers.clear();
@@ -406,6 +427,32 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         value.update(self);
     }
 
+    /// Update the cache with an event from the gateway, returning a summary of
+    /// the resources that changed.
+    ///
+    /// Refer to [`CacheChange`] for which resources are reported.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::{CacheChange, DefaultInMemoryCache};
+    /// # use twilight_model::gateway::{event::Event, payload::incoming::GuildDelete};
+    /// # use twilight_model::id::Id;
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    /// # let event = Event::GuildDelete(GuildDelete { id: Id::new(1), unavailable: None });
+    ///
+    /// // later in the application...
+    /// for change in cache.update_with_changes(&event) {
+    ///     if let CacheChange::GuildRemoved(guild_id) = change {
+    ///         println!("guild {guild_id} was removed");
+    ///     }
+    /// }
+    /// ```
+    pub fn update_with_changes(&self, event: &Event) -> Vec<CacheChange> {
+        change::collect(|| self.update(event))
+    }
+
     /// Gets the current user.
     #[allow(clippy::missing_panics_doc)]
     pub fn current_user(&self) -> Option<CacheModels::CurrentUser> {
@@ -477,6 +524,9 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     /// This requires both the [`GUILDS`] and [`GUILD_EMOJIS_AND_STICKERS`]
     /// intents.
     ///
+    /// Use [`InMemoryCacheIter::emojis_in`] to iterate over the emojis
+    /// themselves.
+    ///
     /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
     /// [`GUILD_EMOJIS_AND_STICKERS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS_AND_STICKERS
     pub fn guild_emojis(
@@ -486,6 +536,17 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.guild_emojis.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the number of emojis in a guild.
+    ///
+    /// This requires both the [`GUILDS`] and [`GUILD_EMOJIS_AND_STICKERS`]
+    /// intents.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_EMOJIS_AND_STICKERS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS_AND_STICKERS
+    pub fn guild_emoji_count(&self, guild_id: Id<GuildMarker>) -> Option<usize> {
+        self.guild_emojis.get(&guild_id).map(|emojis| emojis.len())
+    }
+
     /// Gets the set of integrations in a guild.
     ///
     /// This requires the [`GUILD_INTEGRATIONS`] intent. The
@@ -499,6 +560,37 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.guild_integrations.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the entitlements of a user.
+    ///
+    /// Entitlements are cached from the interactions the user invokes, which
+    /// include all of the user's active entitlements at that time, and kept
+    /// up to date by entitlement events.
+    ///
+    /// This requires the [`ResourceType::ENTITLEMENT`] resource type to be
+    /// enabled.
+    pub fn entitlements(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Option<Reference<'_, Id<UserMarker>, Vec<Entitlement>>> {
+        self.entitlements.get(&user_id).map(Reference::new)
+    }
+
+    /// Gets the set of invite codes in a guild.
+    ///
+    /// Only invites created while the cache was running are included, as the
+    /// gateway doesn't send existing invites.
+    ///
+    /// This requires the [`GUILD_INVITES`] intent. The
+    /// [`ResourceType::INVITE`] resource type must be enabled.
+    ///
+    /// [`GUILD_INVITES`]: twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn guild_invites(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<Reference<'_, Id<GuildMarker>, HashSet<String>>> {
+        self.guild_invites.get(&guild_id).map(Reference::new)
+    }
+
     /// Gets the set of members in a guild.
     ///
     /// This list may be incomplete if not all members have been cached.
@@ -573,6 +665,9 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     /// guild. This requires the [`GUILDS`] and [`GUILD_EMOJIS_AND_STICKERS`]
     /// intents and the [`STICKER`] resource type.
     ///
+    /// Use [`InMemoryCacheIter::stickers_in`] to iterate over the stickers
+    /// themselves.
+    ///
     /// [`GUILDS`]: twilight_model::gateway::Intents::GUILDS
     /// [`GUILD_EMOJIS_AND_STICKERS`]: ::twilight_model::gateway::Intents::GUILD_EMOJIS_AND_STICKERS
     /// [`STICKER`]: crate::config::ResourceType::STICKER
@@ -619,6 +714,16 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
             .map(Reference::new)
     }
 
+    /// Gets an invite by its code.
+    ///
+    /// This requires the [`GUILD_INVITES`] intent. The
+    /// [`ResourceType::INVITE`] resource type must be enabled.
+    ///
+    /// [`GUILD_INVITES`]: twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn invite(&self, code: &str) -> Option<Reference<'_, String, model::CachedInvite>> {
+        self.invites.get(code).map(Reference::new)
+    }
+
     /// Gets a member by guild ID and user ID.
     ///
     /// This requires the [`GUILD_MEMBERS`] intent.
@@ -647,6 +752,54 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.messages.get(&message_id).map(Reference::new)
     }
 
+    /// Gets the author of a message by message ID.
+    ///
+    /// Messages only store the ID of their author, which is resolved from the
+    /// cached users. This requires one or both of the [`GUILD_MESSAGES`] or
+    /// [`DIRECT_MESSAGES`] intents and the [`MESSAGE`] and [`USER`] resource
+    /// types.
+    ///
+    /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
+    /// [`GUILD_MESSAGES`]: ::twilight_model::gateway::Intents::GUILD_MESSAGES
+    /// [`MESSAGE`]: crate::config::ResourceType::MESSAGE
+    /// [`USER`]: crate::config::ResourceType::USER
+    pub fn message_author(
+        &self,
+        message_id: Id<MessageMarker>,
+    ) -> Option<Reference<'_, Id<UserMarker>, CacheModels::User>> {
+        let author_id = self.messages.get(&message_id)?.author();
+
+        self.users.get(&author_id).map(Reference::new)
+    }
+
+    /// Gets the cached users mentioned in a message by message ID.
+    ///
+    /// Messages only store the IDs of mentioned users, which are resolved from
+    /// the cached users. Mentioned users that aren't cached are skipped.
+    /// Returns [`None`] if the message isn't cached.
+    ///
+    /// This requires one or both of the [`GUILD_MESSAGES`] or
+    /// [`DIRECT_MESSAGES`] intents and the [`MESSAGE`] and [`USER`] resource
+    /// types.
+    ///
+    /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
+    /// [`GUILD_MESSAGES`]: ::twilight_model::gateway::Intents::GUILD_MESSAGES
+    /// [`MESSAGE`]: crate::config::ResourceType::MESSAGE
+    /// [`USER`]: crate::config::ResourceType::USER
+    pub fn message_mentions(
+        &self,
+        message_id: Id<MessageMarker>,
+    ) -> Option<Vec<Reference<'_, Id<UserMarker>, CacheModels::User>>> {
+        let mention_ids = self.messages.get(&message_id)?.mentions().to_vec();
+
+        Some(
+            mention_ids
+                .iter()
+                .filter_map(|user_id| self.users.get(user_id).map(Reference::new))
+                .collect(),
+        )
+    }
+
     /// Gets a presence by, optionally, guild ID, and user ID.
     ///
     /// This requires the [`GUILD_PRESENCES`] intent.
@@ -744,6 +897,66 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         self.user_guilds.get(&user_id).map(Reference::new)
     }
 
+    /// Get the IDs of the cached guilds a user shares with the current user.
+    ///
+    /// Unlike [`user_guilds`], the user's set of guilds is copied and its lock
+    /// released before anything else is read from the cache, so the cache may
+    /// be freely accessed while handling the returned IDs. Guilds that are in
+    /// the user's set but are no longer cached are skipped.
+    ///
+    /// IDs are sorted by their guild's [member count] in descending order,
+    /// followed by guilds with an unknown member count. Guilds with the same
+    /// member count are sorted by ID in ascending order.
+    ///
+    /// Requires the [`GUILD`] and [`USER`] resource types.
+    ///
+    /// [`GUILD`]: crate::config::ResourceType::GUILD
+    /// [`USER`]: crate::config::ResourceType::USER
+    /// [`user_guilds`]: Self::user_guilds
+    /// [member count]: CacheableGuild::member_count
+    pub fn mutual_guilds(&self, user_id: Id<UserMarker>) -> Vec<Id<GuildMarker>> {
+        let guild_ids = self
+            .user_guilds
+            .get(&user_id)
+            .map(|guild_ids| guild_ids.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut guilds = guild_ids
+            .into_iter()
+            .filter_map(|guild_id| {
+                let member_count = self.guilds.get(&guild_id)?.member_count();
+
+                Some((Reverse(member_count), guild_id))
+            })
+            .collect::<Vec<_>>();
+        guilds.sort_unstable();
+
+        guilds.into_iter().map(|(_, guild_id)| guild_id).collect()
+    }
+
+    /// Iterate over the cached guilds a user shares with the current user.
+    ///
+    /// The user's set of guilds is copied before iterating, so no lock on it
+    /// is held while guilds are yielded. Only the lock of the yielded guild is
+    /// held for as long as its [`Reference`] is alive. Guilds are yielded in
+    /// the order of [`mutual_guilds`], skipping guilds that are no longer
+    /// cached.
+    ///
+    /// Requires the [`GUILD`] and [`USER`] resource types.
+    ///
+    /// [`GUILD`]: crate::config::ResourceType::GUILD
+    /// [`USER`]: crate::config::ResourceType::USER
+    /// [`mutual_guilds`]: Self::mutual_guilds
+    pub fn mutual_guilds_iter(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> MutualGuilds<'_, CacheModels::Guild> {
+        MutualGuilds {
+            guild_ids: self.mutual_guilds(user_id).into_iter(),
+            guilds: &self.guilds,
+        }
+    }
+
     /// Gets the voice states within a voice channel.
     ///
     /// This requires both the [`GUILDS`] and [`GUILD_VOICE_STATES`] intents.
@@ -811,72 +1024,90 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         highest_role.map(|(_, id)| id)
     }
 
+    /// Create a new cache with a configuration.
+    ///
+    /// Maps are pre-allocated according to the config's expected number of
+    /// guilds, members, and users. Their shard amount is left to [`DashMap`],
+    /// which picks one proportional to the available parallelism.
     fn new_with_config(config: Config) -> Self {
-        Self {
-            config,
-            ..Self::default()
-        }
-    }
+        let guilds = config.expected_guilds();
+        let members = config.expected_members();
+        let users = config.expected_users();
 
-    /// Determine whether the configured cache wants a specific resource to be
-    /// processed.
-    const fn wants(&self, resource_type: ResourceType) -> bool {
-        self.config.resource_types().contains(resource_type)
-    }
-}
-
-// This needs to be implemented manually because the compiler apparently
-// can't derive Default for a struct with generics.
-impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
-    fn default() -> Self {
         Self {
             channel_messages: DashMap::new(),
             channels: DashMap::new(),
-            config: Config::default(),
             current_user: Mutex::new(None),
             emojis: DashMap::new(),
-            guild_channels: DashMap::new(),
-            guild_emojis: DashMap::new(),
-            guild_integrations: DashMap::new(),
-            guild_members: DashMap::new(),
-            guild_presences: DashMap::new(),
-            guild_roles: DashMap::new(),
-            guild_scheduled_events: DashMap::new(),
-            guild_stage_instances: DashMap::new(),
-            guild_stickers: DashMap::new(),
-            guilds: DashMap::new(),
+            entitlements: DashMap::new(),
+            guild_channels: DashMap::with_capacity(guilds),
+            guild_emojis: DashMap::with_capacity(guilds),
+            guild_integrations: DashMap::with_capacity(guilds),
+            guild_invites: DashMap::with_capacity(guilds),
+            guild_members: DashMap::with_capacity(guilds),
+            guild_presences: DashMap::with_capacity(guilds),
+            guild_roles: DashMap::with_capacity(guilds),
+            guild_scheduled_events: DashMap::with_capacity(guilds),
+            guild_stage_instances: DashMap::with_capacity(guilds),
+            guild_stickers: DashMap::with_capacity(guilds),
+            guilds: DashMap::with_capacity(guilds),
             integrations: DashMap::new(),
-            members: DashMap::new(),
+            invites: DashMap::new(),
+            members: DashMap::with_capacity(members),
             messages: DashMap::new(),
+            pending_pin_timestamps: DashMap::new(),
             presences: DashMap::new(),
             roles: DashMap::new(),
             scheduled_events: DashMap::new(),
             stage_instances: DashMap::new(),
             stickers: DashMap::new(),
             unavailable_guilds: DashSet::new(),
-            user_guilds: DashMap::new(),
-            users: DashMap::new(),
+            user_guilds: DashMap::with_capacity(users),
+            users: DashMap::with_capacity(users),
             voice_state_channels: DashMap::new(),
-            voice_state_guilds: DashMap::new(),
+            voice_state_guilds: DashMap::with_capacity(guilds),
             voice_states: DashMap::new(),
+            config,
         }
     }
+
+    /// Determine whether the configured cache wants a specific resource to be
+    /// processed.
+    const fn wants(&self, resource_type: ResourceType) -> bool {
+        self.config.resource_types().contains(resource_type)
+    }
+
+    /// Determine whether a resource not belonging to a guild, and thereby part
+    /// of a direct message, is to be skipped.
+    const fn skips_dm(&self, guild_id: Option<Id<GuildMarker>>) -> bool {
+        guild_id.is_none() && !self.config.cache_dms()
+    }
+}
+
+// This needs to be implemented manually because the compiler apparently
+// can't derive Default for a struct with generics.
+impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
+    fn default() -> Self {
+        Self::new_with_config(Config::default())
+    }
 }
 
 mod private {
     use twilight_model::gateway::{
         event::Event,
         payload::incoming::{
-            ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate, GuildCreate,
-            GuildDelete, GuildEmojisUpdate, GuildScheduledEventCreate, GuildScheduledEventDelete,
-            GuildScheduledEventUpdate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
-            GuildStickersUpdate, GuildUpdate, IntegrationCreate, IntegrationDelete,
-            IntegrationUpdate, InteractionCreate, MemberAdd, MemberChunk, MemberRemove,
-            MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
-            PresenceUpdate, ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
-            Ready, RoleCreate, RoleDelete, RoleUpdate, StageInstanceCreate, StageInstanceDelete,
-            StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate,
-            UnavailableGuild, UserUpdate, VoiceStateUpdate,
+            ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate, EntitlementCreate,
+            EntitlementDelete, EntitlementUpdate, GuildCreate, GuildDelete, GuildEmojisUpdate,
+            GuildScheduledEventCreate, GuildScheduledEventDelete, GuildScheduledEventUpdate,
+            GuildScheduledEventUserAdd, GuildScheduledEventUserRemove, GuildStickersUpdate,
+            GuildUpdate, IntegrationCreate, IntegrationDelete, IntegrationUpdate,
+            InteractionCreate, InviteCreate, InviteDelete, MemberAdd, MemberChunk, MemberRemove,
+            MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessagePollVoteAdd,
+            MessagePollVoteRemove, MessageUpdate, PresenceUpdate, ReactionAdd, ReactionRemove,
+            ReactionRemoveAll, ReactionRemoveEmoji, Ready, RoleCreate, RoleDelete, RoleUpdate,
+            StageInstanceCreate, StageInstanceDelete, StageInstanceUpdate, ThreadCreate,
+            ThreadDelete, ThreadListSync, ThreadUpdate, TypingStart, UnavailableGuild, UserUpdate,
+            VoiceStateUpdate,
         },
     };
 
@@ -887,6 +1118,9 @@ mod private {
     impl Sealed for ChannelDelete {}
     impl Sealed for ChannelPinsUpdate {}
     impl Sealed for ChannelUpdate {}
+    impl Sealed for EntitlementCreate {}
+    impl Sealed for EntitlementDelete {}
+    impl Sealed for EntitlementUpdate {}
     impl Sealed for GuildCreate {}
     impl Sealed for GuildEmojisUpdate {}
     impl Sealed for GuildDelete {}
@@ -896,6 +1130,8 @@ mod private {
     impl Sealed for IntegrationDelete {}
     impl Sealed for IntegrationUpdate {}
     impl Sealed for InteractionCreate {}
+    impl Sealed for InviteCreate {}
+    impl Sealed for InviteDelete {}
     impl Sealed for MemberAdd {}
     impl Sealed for MemberChunk {}
     impl Sealed for MemberRemove {}
@@ -903,6 +1139,8 @@ mod private {
     impl Sealed for MessageCreate {}
     impl Sealed for MessageDelete {}
     impl Sealed for MessageDeleteBulk {}
+    impl Sealed for MessagePollVoteAdd {}
+    impl Sealed for MessagePollVoteRemove {}
     impl Sealed for MessageUpdate {}
     impl Sealed for PresenceUpdate {}
     impl Sealed for ReactionAdd {}
@@ -920,6 +1158,7 @@ mod private {
     impl Sealed for ThreadDelete {}
     impl Sealed for ThreadListSync {}
     impl Sealed for ThreadUpdate {}
+    impl Sealed for TypingStart {}
     impl Sealed for UnavailableGuild {}
     impl Sealed for UserUpdate {}
     impl Sealed for VoiceStateUpdate {}
@@ -964,6 +1203,29 @@ impl<'a, CachedVoiceState> Iterator for VoiceChannelStates<'a, CachedVoiceState>
     }
 }
 
+/// Iterator over the cached guilds a user shares with the current user.
+///
+/// Created via [`InMemoryCache::mutual_guilds_iter`].
+pub struct MutualGuilds<'a, CachedGuild> {
+    guild_ids: IntoIter<Id<GuildMarker>>,
+    guilds: &'a DashMap<Id<GuildMarker>, CachedGuild>,
+}
+
+impl<'a, CachedGuild> Iterator for MutualGuilds<'a, CachedGuild> {
+    type Item = Reference<'a, Id<GuildMarker>, CachedGuild>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let guilds = self.guilds;
+
+        self.guild_ids
+            .find_map(|guild_id| guilds.get(&guild_id).map(Reference::new))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.guild_ids.size_hint().1)
+    }
+}
+
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
     // clippy: using `.deref()` is cleaner
     #[allow(clippy::explicit_deref_methods)]
@@ -973,6 +1235,9 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::ChannelDelete(v) => cache.update(v.deref()),
             Event::ChannelPinsUpdate(v) => cache.update(v),
             Event::ChannelUpdate(v) => cache.update(v.deref()),
+            Event::EntitlementCreate(v) => cache.update(v),
+            Event::EntitlementDelete(v) => cache.update(v),
+            Event::EntitlementUpdate(v) => cache.update(v),
             Event::GuildCreate(v) => cache.update(v.deref()),
             Event::GuildDelete(v) => cache.update(v),
             Event::GuildEmojisUpdate(v) => cache.update(v),
@@ -987,6 +1252,8 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::IntegrationDelete(v) => cache.update(v),
             Event::IntegrationUpdate(v) => cache.update(v.deref()),
             Event::InteractionCreate(v) => cache.update(v.deref()),
+            Event::InviteCreate(v) => cache.update(v.deref()),
+            Event::InviteDelete(v) => cache.update(v),
             Event::MemberAdd(v) => cache.update(v.deref()),
             Event::MemberChunk(v) => cache.update(v),
             Event::MemberRemove(v) => cache.update(v),
@@ -994,6 +1261,8 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::MessageCreate(v) => cache.update(v.deref()),
             Event::MessageDelete(v) => cache.update(v),
             Event::MessageDeleteBulk(v) => cache.update(v),
+            Event::MessagePollVoteAdd(v) => cache.update(v),
+            Event::MessagePollVoteRemove(v) => cache.update(v),
             Event::MessageUpdate(v) => cache.update(v.deref()),
             Event::PresenceUpdate(v) => cache.update(v.deref()),
             Event::ReactionAdd(v) => cache.update(v.deref()),
@@ -1011,6 +1280,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::ThreadDelete(v) => cache.update(v),
             Event::ThreadListSync(v) => cache.update(v),
             Event::ThreadUpdate(v) => cache.update(v.deref()),
+            Event::TypingStart(v) => cache.update(v.deref()),
             Event::UnavailableGuild(v) => cache.update(v),
             Event::UserUpdate(v) => cache.update(v),
             Event::VoiceStateUpdate(v) => cache.update(v.deref()),
@@ -1023,9 +1293,6 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             | Event::BanAdd(_)
             | Event::BanRemove(_)
             | Event::CommandPermissionsUpdate(_)
-            | Event::EntitlementCreate(_)
-            | Event::EntitlementDelete(_)
-            | Event::EntitlementUpdate(_)
             | Event::GatewayClose(_)
             | Event::GatewayHeartbeat(_)
             | Event::GatewayHeartbeatAck
@@ -1034,14 +1301,10 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             | Event::GatewayReconnect
             | Event::GuildAuditLogEntryCreate(_)
             | Event::GuildIntegrationsUpdate(_)
-            | Event::InviteCreate(_)
-            | Event::InviteDelete(_)
-            | Event::MessagePollVoteAdd(_)
-            | Event::MessagePollVoteRemove(_)
+            | Event::InteractionCreatePartial(_)
             | Event::Resumed
             | Event::ThreadMembersUpdate(_)
             | Event::ThreadMemberUpdate(_)
-            | Event::TypingStart(_)
             | Event::VoiceServerUpdate(_)
             | Event::WebhooksUpdate(_) => {}
         }
@@ -1051,8 +1314,14 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
 #[cfg(test)]
 mod tests {
     use crate::{test, DefaultInMemoryCache};
+    use std::thread;
     use twilight_model::{
-        gateway::payload::incoming::RoleDelete,
+        gateway::{
+            payload::incoming::{
+                GuildCreate, MemberAdd, MessageCreate, PresenceUpdate, RoleDelete, VoiceStateUpdate,
+            },
+            presence::{ClientStatus, Presence, Status, UserOrId},
+        },
         guild::{Member, MemberFlags, Permissions, Role, RoleFlags},
         id::Id,
         util::Timestamp,
@@ -1077,6 +1346,82 @@ mod tests {
         assert!(cache.members.is_empty());
     }
 
+    /// Test that maps are pre-allocated according to the expected sizes and
+    /// retain their capacity when cleared.
+    #[test]
+    fn expected_capacity() {
+        let cache = DefaultInMemoryCache::builder()
+            .expected_guilds(1_000)
+            .expected_members(50_000)
+            .expected_users(20_000)
+            .build();
+
+        let capacities = || {
+            [
+                (cache.guilds.capacity(), 1_000),
+                (cache.guild_channels.capacity(), 1_000),
+                (cache.guild_members.capacity(), 1_000),
+                (cache.guild_roles.capacity(), 1_000),
+                (cache.members.capacity(), 50_000),
+                (cache.user_guilds.capacity(), 20_000),
+                (cache.users.capacity(), 20_000),
+            ]
+        };
+
+        for (capacity, expected) in capacities() {
+            assert!(capacity >= expected, "{capacity} < {expected}");
+        }
+
+        cache.cache_member(Id::new(2), test::member(Id::new(2)));
+        cache.clear();
+
+        for (capacity, expected) in capacities() {
+            assert!(capacity >= expected, "{capacity} < {expected}");
+        }
+    }
+
+    #[test]
+    fn mutual_guilds() {
+        let cache = DefaultInMemoryCache::new();
+        let user_id = Id::new(5);
+
+        for (guild_id, member_count) in [
+            (Id::new(1), Some(10)),
+            (Id::new(3), Some(20)),
+            (Id::new(4), None),
+            (Id::new(6), Some(10)),
+        ] {
+            cache.update(&GuildCreate::Available(test::guild(guild_id, member_count)));
+            cache.cache_member(guild_id, test::member(user_id));
+        }
+
+        // Guild 2 is in the user's set but isn't cached.
+        cache
+            .user_guilds
+            .get_mut(&user_id)
+            .unwrap()
+            .insert(Id::new(2));
+
+        assert_eq!(
+            vec![Id::new(3), Id::new(1), Id::new(6), Id::new(4)],
+            cache.mutual_guilds(user_id)
+        );
+
+        let mut guilds = cache.mutual_guilds_iter(user_id);
+        let guild = guilds.next().unwrap();
+        assert_eq!(Id::new(3), guild.id());
+        // The user's set isn't locked while a guild is held.
+        assert_eq!(5, cache.user_guilds(user_id).unwrap().len());
+        drop(guild);
+        assert_eq!(Id::new(1), guilds.next().unwrap().id());
+        assert_eq!(Id::new(6), guilds.next().unwrap().id());
+        assert_eq!(Id::new(4), guilds.next().unwrap().id());
+        assert!(guilds.next().is_none());
+
+        assert!(cache.mutual_guilds(Id::new(7)).is_empty());
+        assert!(cache.mutual_guilds_iter(Id::new(7)).next().is_none());
+    }
+
     #[test]
     fn highest_role() {
         let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
@@ -1106,6 +1451,7 @@ mod tests {
             vec![
                 Role {
                     color: 0,
+                    colors: None,
                     hoist: false,
                     icon: None,
                     id: Id::new(1),
@@ -1120,6 +1466,7 @@ mod tests {
                 },
                 Role {
                     color: 0,
+                    colors: None,
                     hoist: false,
                     icon: None,
                     id: Id::new(2),
@@ -1140,4 +1487,111 @@ mod tests {
             Some(Id::new(2))
         );
     }
+
+    /// Test that processing many overlapping events from multiple threads
+    /// neither deadlocks nor leaves resource and relation maps inconsistent.
+    #[test]
+    fn concurrent_events() {
+        const GUILDS: u64 = 4;
+        const ROUNDS: u64 = 20;
+        const THREADS: u64 = 8;
+        const USERS: u64 = 64;
+
+        let cache = DefaultInMemoryCache::new();
+
+        thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let cache = &cache;
+
+                scope.spawn(move || {
+                    for round in 0..ROUNDS {
+                        for user in 0..USERS {
+                            let guild_id = Id::new(1 + (user + round + thread) % GUILDS);
+                            let user_id = Id::new(1 + user);
+
+                            cache.update(&MemberAdd {
+                                guild_id,
+                                member: test::member(user_id),
+                            });
+                            cache.update(&PresenceUpdate(Presence {
+                                activities: Vec::new(),
+                                client_status: ClientStatus {
+                                    desktop: Some(Status::Online),
+                                    mobile: None,
+                                    web: None,
+                                },
+                                guild_id,
+                                status: Status::Online,
+                                user: UserOrId::UserId { id: user_id },
+                            }));
+
+                            let mut message = test::message();
+                            message.author = test::user(user_id);
+                            message.channel_id = Id::new(100 + user % 4);
+                            message.guild_id = Some(guild_id);
+                            message.id = Id::new(1 + (thread * ROUNDS + round) * USERS + user);
+                            cache.update(&MessageCreate(message));
+
+                            // Each user's voice state is only updated by one
+                            // thread so that its final state is known. Even
+                            // users leave in the last round.
+                            if user % THREADS == thread {
+                                let channel_id = (round + 1 < ROUNDS || user % 2 == 1)
+                                    .then(|| Id::new(10 + round % 3));
+                                cache.update(&VoiceStateUpdate(test::voice_state(
+                                    Id::new(1),
+                                    channel_id,
+                                    user_id,
+                                )));
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let stats = cache.stats();
+        let users = usize::try_from(USERS).unwrap();
+        let guilds = usize::try_from(GUILDS).unwrap();
+
+        assert_eq!(users, stats.users());
+        assert_eq!(users * guilds, stats.members());
+        assert_eq!(users * guilds, stats.presences());
+
+        for guild_id in (1..=GUILDS).map(Id::new) {
+            assert_eq!(Some(users), stats.guild_members(guild_id));
+            assert_eq!(Some(users), stats.guild_presences(guild_id));
+        }
+
+        for user_id in (1..=USERS).map(Id::new) {
+            assert_eq!(guilds, cache.user_guilds(user_id).unwrap().len());
+
+            let voice_state = cache.voice_state(user_id, Id::new(1));
+            if user_id.get() % 2 == 0 {
+                assert!(voice_state.is_some());
+            } else {
+                assert!(voice_state.is_none());
+            }
+        }
+
+        let connected = users / 2;
+        let last_channel_id = Id::new(10 + (ROUNDS - 1) % 3);
+        assert_eq!(connected, stats.voice_states());
+        assert_eq!(Some(connected), stats.guild_voice_states(Id::new(1)));
+        assert_eq!(Some(connected), stats.channel_voice_states(last_channel_id));
+
+        for channel_id in (10..13).map(Id::new) {
+            if channel_id != last_channel_id {
+                assert!(stats.channel_voice_states(channel_id).is_none());
+            }
+        }
+
+        let mut channel_messages = 0;
+        for channel_id in (100..104).map(Id::new) {
+            let len = stats.channel_messages(channel_id).unwrap();
+            assert!(len <= cache.config().message_cache_size());
+            channel_messages += len;
+        }
+        assert_eq!(channel_messages, stats.messages());
+    }
 }
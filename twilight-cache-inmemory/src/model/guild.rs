@@ -5,8 +5,8 @@ use twilight_model::{
     gateway::payload::incoming::GuildUpdate,
     guild::{
         scheduled_event::GuildScheduledEvent, AfkTimeout, DefaultMessageNotificationLevel,
-        ExplicitContentFilter, Guild, GuildFeature, MfaLevel, NSFWLevel, Permissions, PremiumTier,
-        SystemChannelFlags, VerificationLevel,
+        ExplicitContentFilter, GatewayGuild, GuildFeature, MfaLevel, NSFWLevel, Permissions,
+        PremiumTier, SystemChannelFlags, VerificationLevel,
     },
     id::{
         marker::{ApplicationMarker, ChannelMarker, GuildMarker, UserMarker},
@@ -291,9 +291,9 @@ impl CachedGuild {
     }
 }
 
-impl From<Guild> for CachedGuild {
-    fn from(guild: Guild) -> Self {
-        let Guild {
+impl From<GatewayGuild> for CachedGuild {
+    fn from(guild: GatewayGuild) -> Self {
+        let GatewayGuild {
             afk_channel_id,
             afk_timeout,
             application_id,
@@ -384,8 +384,8 @@ impl From<Guild> for CachedGuild {
     }
 }
 
-impl PartialEq<Guild> for CachedGuild {
-    fn eq(&self, other: &Guild) -> bool {
+impl PartialEq<GatewayGuild> for CachedGuild {
+    fn eq(&self, other: &GatewayGuild) -> bool {
         self.afk_channel_id == other.afk_channel_id
             && self.afk_timeout == other.afk_timeout
             && self.application_id == other.application_id
@@ -443,31 +443,58 @@ impl CacheableGuild for CachedGuild {
     fn update_with_guild_update(&mut self, guild_update: &GuildUpdate) {
         self.afk_channel_id = guild_update.afk_channel_id;
         self.afk_timeout = guild_update.afk_timeout;
+        self.application_id = guild_update.application_id;
         self.banner = guild_update.banner;
         self.default_message_notifications = guild_update.default_message_notifications;
         self.description.clone_from(&guild_update.description);
+        self.discovery_splash = guild_update.discovery_splash;
+        self.explicit_content_filter = guild_update.explicit_content_filter;
         self.features.clone_from(&guild_update.features);
         self.icon = guild_update.icon;
-        self.max_members = guild_update.max_members;
         self.max_presences = Some(guild_update.max_presences.unwrap_or(25000));
         self.mfa_level = guild_update.mfa_level;
         self.name.clone_from(&guild_update.name);
         self.nsfw_level = guild_update.nsfw_level;
-        self.owner = guild_update.owner;
         self.owner_id = guild_update.owner_id;
-        self.permissions = guild_update.permissions;
         self.preferred_locale
             .clone_from(&guild_update.preferred_locale);
+        self.premium_progress_bar_enabled = guild_update.premium_progress_bar_enabled;
         self.premium_tier = guild_update.premium_tier;
-        self.premium_subscription_count
-            .replace(guild_update.premium_subscription_count.unwrap_or_default());
+        self.public_updates_channel_id = guild_update.public_updates_channel_id;
+        self.rules_channel_id = guild_update.rules_channel_id;
         self.splash = guild_update.splash;
+        self.system_channel_flags = guild_update.system_channel_flags;
         self.system_channel_id = guild_update.system_channel_id;
         self.verification_level = guild_update.verification_level;
         self.vanity_url_code
             .clone_from(&guild_update.vanity_url_code);
         self.widget_channel_id = guild_update.widget_channel_id;
-        self.widget_enabled = guild_update.widget_enabled;
+
+        // Optional fields missing from the update are kept rather than cleared,
+        // since they may have been populated by a guild create.
+        if guild_update.max_members.is_some() {
+            self.max_members = guild_update.max_members;
+        }
+
+        if guild_update.owner.is_some() {
+            self.owner = guild_update.owner;
+        }
+
+        if guild_update.permissions.is_some() {
+            self.permissions = guild_update.permissions;
+        }
+
+        if guild_update.premium_subscription_count.is_some() {
+            self.premium_subscription_count = guild_update.premium_subscription_count;
+        }
+
+        if guild_update.widget_enabled.is_some() {
+            self.widget_enabled = guild_update.widget_enabled;
+        }
+    }
+
+    fn member_count(&self) -> Option<u64> {
+        self.member_count
     }
 
     fn increase_member_count(&mut self, amount: u64) {
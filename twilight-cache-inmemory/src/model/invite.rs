@@ -0,0 +1,169 @@
+use serde::Serialize;
+use twilight_model::{
+    gateway::payload::incoming::InviteCreate,
+    guild::invite::{InviteApplication, TargetType},
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker},
+        Id,
+    },
+    util::Timestamp,
+};
+
+/// Represents a cached [`InviteCreate`].
+///
+/// [`InviteCreate`]: twilight_model::gateway::payload::incoming::InviteCreate
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedInvite {
+    channel_id: Id<ChannelMarker>,
+    code: String,
+    created_at: Timestamp,
+    expires_at: Option<Timestamp>,
+    guild_id: Id<GuildMarker>,
+    inviter_id: Option<Id<UserMarker>>,
+    max_age: u64,
+    max_uses: u64,
+    target_application: Option<InviteApplication>,
+    target_type: Option<TargetType>,
+    target_user_id: Option<Id<UserMarker>>,
+    temporary: bool,
+}
+
+impl CachedInvite {
+    /// ID of the channel invited users will first see.
+    pub const fn channel_id(&self) -> Id<ChannelMarker> {
+        self.channel_id
+    }
+
+    /// Unique code.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// When the invite was created.
+    pub const fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// When the invite expires, if it ever does.
+    pub const fn expires_at(&self) -> Option<Timestamp> {
+        self.expires_at
+    }
+
+    /// ID of the guild being invited to.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// ID of the user who created the invite.
+    pub const fn inviter_id(&self) -> Option<Id<UserMarker>> {
+        self.inviter_id
+    }
+
+    /// Maximum age before the invite expires.
+    ///
+    /// This is in seconds.
+    pub const fn max_age(&self) -> u64 {
+        self.max_age
+    }
+
+    /// Maximum number of uses before the invite expires.
+    pub const fn max_uses(&self) -> u64 {
+        self.max_uses
+    }
+
+    /// Embedded application to open for this embedded application invite.
+    pub const fn target_application(&self) -> Option<&InviteApplication> {
+        self.target_application.as_ref()
+    }
+
+    /// Target of the invite.
+    pub const fn target_type(&self) -> Option<TargetType> {
+        self.target_type
+    }
+
+    /// ID of the user whose stream to display for this voice channel stream
+    /// invite.
+    pub const fn target_user_id(&self) -> Option<Id<UserMarker>> {
+        self.target_user_id
+    }
+
+    /// Whether the invite is temporary.
+    ///
+    /// Invited users will be kicked when they are disconnected from an audio
+    /// channel unless they're assigned a role.
+    pub const fn temporary(&self) -> bool {
+        self.temporary
+    }
+}
+
+impl From<InviteCreate> for CachedInvite {
+    fn from(invite: InviteCreate) -> Self {
+        // Reasons for dropping fields:
+        //
+        // - `inviter`: we only need the user's ID
+        // - `target_user`: we only need the user's ID
+        // - `uses`: always zero, and the gateway doesn't send updates
+        let InviteCreate {
+            channel_id,
+            code,
+            created_at,
+            expires_at,
+            guild_id,
+            inviter,
+            max_age,
+            max_uses,
+            target_application,
+            target_user_type,
+            target_user,
+            temporary,
+            uses: _,
+        } = invite;
+
+        Self {
+            channel_id,
+            code,
+            created_at,
+            expires_at,
+            guild_id,
+            inviter_id: inviter.map(|user| user.id),
+            max_age,
+            max_uses,
+            target_application,
+            target_type: target_user_type,
+            target_user_id: target_user.map(|user| user.id),
+            temporary,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedInvite;
+    use serde::Serialize;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+
+    assert_fields!(
+        CachedInvite: channel_id,
+        code,
+        created_at,
+        expires_at,
+        guild_id,
+        inviter_id,
+        max_age,
+        max_uses,
+        target_application,
+        target_type,
+        target_user_id,
+        temporary
+    );
+    assert_impl_all!(
+        CachedInvite: Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+}
@@ -9,6 +9,7 @@ use twilight_model::{
         marker::{RoleMarker, UserMarker},
         Id,
     },
+    user::User,
     util::{ImageHash, Timestamp},
 };
 
@@ -96,6 +97,20 @@ impl CachedMember {
         self.mute
     }
 
+    /// Name of the member as displayed in the client, given its user.
+    ///
+    /// This is the member's [nickname] if set, otherwise the
+    /// [user's display name]. The user can be retrieved from the cache with
+    /// [`InMemoryCache::user`] using the member's [user ID].
+    ///
+    /// [`InMemoryCache::user`]: crate::InMemoryCache::user
+    /// [nickname]: Self::nick
+    /// [user ID]: Self::user_id
+    /// [user's display name]: User::display_name
+    pub fn display_name<'a>(&'a self, user: &'a User) -> &'a str {
+        self.nick().unwrap_or_else(|| user.display_name())
+    }
+
     /// Nickname of the member.
     pub fn nick(&self) -> Option<&str> {
         self.nick.as_deref()
@@ -241,7 +256,8 @@ impl PartialEq<Member> for CachedMember {
 
 impl PartialEq<PartialMember> for CachedMember {
     fn eq(&self, other: &PartialMember) -> bool {
-        self.communication_disabled_until == other.communication_disabled_until
+        self.avatar == other.avatar
+            && self.communication_disabled_until == other.communication_disabled_until
             && self.deaf == Some(other.deaf)
             && self.joined_at == other.joined_at
             && self.mute == Some(other.mute)
@@ -292,6 +308,17 @@ impl CacheableMember for CachedMember {
         self.pending = member_update.pending;
         self.communication_disabled_until = member_update.communication_disabled_until;
     }
+
+    fn update_with_partial_member(&mut self, member: &PartialMember) {
+        self.avatar = member.avatar;
+        self.communication_disabled_until = member.communication_disabled_until;
+        self.nick.clone_from(&member.nick);
+        self.roles.clone_from(&member.roles);
+    }
+
+    fn remove_role(&mut self, role_id: Id<RoleMarker>) {
+        self.roles.retain(|id| *id != role_id);
+    }
 }
 
 #[cfg(test)]
@@ -351,12 +378,26 @@ mod tests {
             mfa_enabled: None,
             name: "bar".to_owned(),
             premium_type: None,
+            primary_guild: None,
             public_flags: None,
             system: None,
             verified: None,
         }
     }
 
+    #[test]
+    fn display_name() {
+        let mut member = cached_member();
+        let mut user = user();
+        assert_eq!("member nick", member.display_name(&user));
+
+        member.nick = None;
+        assert_eq!("test", member.display_name(&user));
+
+        user.global_name = None;
+        assert_eq!("bar", member.display_name(&user));
+    }
+
     #[test]
     fn eq_member() {
         let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
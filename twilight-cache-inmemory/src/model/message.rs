@@ -88,6 +88,40 @@ impl PartialEq<MessageInteraction> for CachedMessageInteraction {
     }
 }
 
+/// Version of a [`CachedMessage`] prior to an edit.
+///
+/// Retained in a message's [edit history] if enabled via
+/// [`InMemoryCacheBuilder::message_edit_history`].
+///
+/// [edit history]: CachedMessage::edit_history
+/// [`InMemoryCacheBuilder::message_edit_history`]: crate::InMemoryCacheBuilder::message_edit_history
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CachedMessageEdit {
+    content: String,
+    edited_timestamp: Option<Timestamp>,
+    embeds: Vec<Embed>,
+}
+
+impl CachedMessageEdit {
+    /// Content of the message prior to the edit.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// [`Timestamp`] of the date the message was last edited prior to the
+    /// edit.
+    ///
+    /// [`None`] if this is the original version of the message.
+    pub const fn edited_timestamp(&self) -> Option<Timestamp> {
+        self.edited_timestamp
+    }
+
+    /// Embeds of the message prior to the edit.
+    pub fn embeds(&self) -> &[Embed] {
+        &self.embeds
+    }
+}
+
 /// Represents a cached [`Message`].
 ///
 /// [`Message`]: twilight_model::channel::Message
@@ -102,6 +136,8 @@ pub struct CachedMessage {
     channel_id: Id<ChannelMarker>,
     components: Vec<Component>,
     pub(crate) content: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    edit_history: Vec<CachedMessageEdit>,
     pub(crate) edited_timestamp: Option<Timestamp>,
     pub(crate) embeds: Vec<Embed>,
     flags: Option<MessageFlags>,
@@ -187,6 +223,16 @@ impl CachedMessage {
         &self.content
     }
 
+    /// Previous versions of the message, from oldest to newest.
+    ///
+    /// Empty unless enabled via
+    /// [`InMemoryCacheBuilder::message_edit_history`].
+    ///
+    /// [`InMemoryCacheBuilder::message_edit_history`]: crate::InMemoryCacheBuilder::message_edit_history
+    pub fn edit_history(&self) -> &[CachedMessageEdit] {
+        &self.edit_history
+    }
+
     /// [`Timestamp`] of the date the message was last edited.
     pub const fn edited_timestamp(&self) -> Option<Timestamp> {
         self.edited_timestamp
@@ -228,6 +274,12 @@ impl CachedMessage {
     }
 
     /// Member data for the author, if there is any.
+    ///
+    /// The member's user is not stored as it is the [author], which may be
+    /// retrieved via [`InMemoryCache::message_author`].
+    ///
+    /// [author]: Self::author
+    /// [`InMemoryCache::message_author`]: crate::InMemoryCache::message_author
     pub const fn member(&self) -> Option<&PartialMember> {
         self.member.as_ref()
     }
@@ -247,7 +299,12 @@ impl CachedMessage {
         &self.mention_roles
     }
 
-    /// Users mentioned in the content.
+    /// IDs of the users mentioned in the content.
+    ///
+    /// The cached mentioned users may be retrieved via
+    /// [`InMemoryCache::message_mentions`].
+    ///
+    /// [`InMemoryCache::message_mentions`]: crate::InMemoryCache::message_mentions
     pub fn mentions(&self) -> &[Id<UserMarker>] {
         &self.mentions
     }
@@ -257,6 +314,11 @@ impl CachedMessage {
         self.pinned
     }
 
+    /// Poll attached to the message.
+    pub const fn poll(&self) -> Option<&Poll> {
+        self.poll.as_ref()
+    }
+
     /// Reactions to the message.
     pub fn reactions(&self) -> &[Reaction] {
         &self.reactions
@@ -350,13 +412,18 @@ impl From<Message> for CachedMessage {
             channel_id,
             components,
             content,
+            edit_history: Vec::new(),
             edited_timestamp,
             embeds,
             flags,
             guild_id,
             interaction: interaction.map(CachedMessageInteraction::from_model),
             kind,
-            member,
+            // The member's user is the author, which is cached separately.
+            member: member.map(|member| PartialMember {
+                user: None,
+                ..member
+            }),
             mention_channels,
             mention_everyone,
             mention_roles,
@@ -403,7 +470,7 @@ impl PartialEq<Message> for CachedMessage {
                         .map_or(false, |other_interaction| interaction == other_interaction)
                 })
             && self.kind == other.kind
-            && self.member == other.member
+            && member_eq(self.member.as_ref(), other.member.as_ref())
             && self.mention_channels == other.mention_channels
             && self.mention_everyone == other.mention_everyone
             && self.mention_roles == other.mention_roles
@@ -425,7 +492,51 @@ impl PartialEq<Message> for CachedMessage {
     }
 }
 
+/// Whether a cached member, which doesn't store its user, equals a member.
+fn member_eq(cached: Option<&PartialMember>, other: Option<&PartialMember>) -> bool {
+    let (Some(cached), Some(other)) = (cached, other) else {
+        return cached.is_none() && other.is_none();
+    };
+
+    let PartialMember {
+        avatar,
+        communication_disabled_until,
+        deaf,
+        flags,
+        joined_at,
+        mute,
+        nick,
+        permissions,
+        premium_since,
+        roles,
+        user: _,
+    } = other;
+
+    cached.avatar == *avatar
+        && cached.communication_disabled_until == *communication_disabled_until
+        && cached.deaf == *deaf
+        && cached.flags == *flags
+        && cached.joined_at == *joined_at
+        && cached.mute == *mute
+        && cached.nick == *nick
+        && cached.permissions == *permissions
+        && cached.premium_since == *premium_since
+        && cached.roles == *roles
+}
+
 impl CacheableMessage for CachedMessage {
+    fn author(&self) -> Id<UserMarker> {
+        self.author
+    }
+
+    fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        self.guild_id
+    }
+
+    fn mentions(&self) -> &[Id<UserMarker>] {
+        &self.mentions
+    }
+
     fn reactions(&self) -> &[Reaction] {
         &self.reactions
     }
@@ -449,11 +560,42 @@ impl CacheableMessage for CachedMessage {
     fn remove_reaction(&mut self, idx: usize) {
         self.reactions.remove(idx);
     }
+
+    fn poll_mut(&mut self) -> Option<&mut Poll> {
+        self.poll.as_mut()
+    }
+
+    fn record_edit(&mut self, previous: Self, limit: usize) {
+        let Self {
+            content,
+            mut edit_history,
+            edited_timestamp,
+            embeds,
+            ..
+        } = previous;
+
+        // Updates such as embeds being resolved or the message being pinned
+        // don't change the message's content.
+        if content != self.content
+            || edited_timestamp != self.edited_timestamp
+            || embeds != self.embeds
+        {
+            edit_history.push(CachedMessageEdit {
+                content,
+                edited_timestamp,
+                embeds,
+            });
+        }
+
+        let excess = edit_history.len().saturating_sub(limit);
+        edit_history.drain(..excess);
+        self.edit_history = edit_history;
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{CachedMessage, CachedMessageInteraction};
+    use super::{CachedMessage, CachedMessageEdit, CachedMessageInteraction};
     use serde::Serialize;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::fmt::Debug;
@@ -468,6 +610,7 @@ mod tests {
         channel_id,
         components,
         content,
+        edit_history,
         edited_timestamp,
         embeds,
         flags,
@@ -498,6 +641,15 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(CachedMessageEdit: content, edited_timestamp, embeds);
+    assert_impl_all!(
+        CachedMessageEdit: Clone,
+        Debug,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
     assert_fields!(CachedMessageInteraction: id, kind, name, user_id);
     assert_impl_all!(
         CachedMessageInteraction: Clone,
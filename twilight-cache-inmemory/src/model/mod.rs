@@ -4,6 +4,7 @@ pub mod message;
 
 mod emoji;
 mod guild;
+mod invite;
 pub(crate) mod member;
 mod presence;
 mod sticker;
@@ -12,6 +13,7 @@ mod voice_state;
 pub use self::{
     emoji::CachedEmoji,
     guild::CachedGuild,
+    invite::CachedInvite,
     member::{CachedMember, ComputedInteractionMember},
     message::CachedMessage,
     presence::CachedPresence,
@@ -21,39 +21,59 @@
 //!     .build();
 //! ```
 //!
+//! If members aren't cached, such as when the [`GUILD_MEMBERS`] intent isn't
+//! enabled, [`InMemoryCachePermissions::in_channel_with_member`] and
+//! [`InMemoryCachePermissions::root_with_member`] accept member data from
+//! elsewhere, such as an interaction, and don't require
+//! [`ResourceType::MEMBER`].
+//!
+//! [`InMemoryCachePermissions::from_interaction`] calculates the current
+//! user's permissions where an interaction was invoked, falling back to the
+//! permissions provided by the interaction when calculating them from the
+//! cache isn't possible, such as in direct messages.
+//!
 //! # Disabled Member Communication Caveats
 //!
-//! The permission calculator checks the [current system time] against when a
+//! The permission calculator checks the [current time] against when a
 //! given member had their [communication disabled until]. If a member's
 //! communication is disabled, then they are restricted to
 //! [read-only permissions]. If the system time is incorrect then this may
-//! result in invalid behavior. This behavior can be opted out of via
+//! result in invalid behavior. The source of the current time can be
+//! configured via [`InMemoryCacheBuilder::clock`]. This behavior can be opted out of via
 //! [`InMemoryCachePermissions::check_member_communication_disabled`].
 //!
+//! [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
 //! [`ResourceType`]: crate::ResourceType
+//! [`ResourceType::MEMBER`]: crate::ResourceType::MEMBER
 //! [communication timed out until]: CachedMember::communication_disabled_until
-//! [current system time]: SystemTime::now
+//! [`InMemoryCacheBuilder::clock`]: crate::InMemoryCacheBuilder::clock
+//! [current time]: SystemTime::now
 //! [read-only permissions]: MEMBER_COMMUNICATION_DISABLED_ALLOWLIST
 
 use super::InMemoryCache;
 use crate::{
-    traits::{CacheableChannel, CacheableGuild, CacheableMember, CacheableRole},
+    traits::{
+        CacheableChannel, CacheableCurrentUser, CacheableGuild, CacheableMember, CacheableRole,
+    },
     CacheableModels,
 };
+use dashmap::mapref::one::Ref;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     time::{Duration, SystemTime},
 };
 use twilight_model::{
+    application::interaction::Interaction,
     channel::{permission_overwrite::PermissionOverwrite, ChannelType},
     guild::Permissions,
     id::{
         marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
         Id,
     },
+    util::Timestamp,
 };
-use twilight_util::permission_calculator::PermissionCalculator;
+use twilight_util::permission_calculator::{IterPermissionCalculator, PermissionCalculator};
 
 /// Permissions a member is allowed to have when their
 /// [communication has been disabled].
@@ -67,6 +87,32 @@ pub const MEMBER_COMMUNICATION_DISABLED_ALLOWLIST: Permissions = Permissions::fr
     Permissions::READ_MESSAGE_HISTORY.bits() | Permissions::VIEW_CHANNEL.bits(),
 );
 
+/// Permissions assumed for the current user in interactions invoked outside of
+/// a guild that don't provide [`app_permissions`].
+///
+/// Refer to [`InMemoryCachePermissions::from_interaction`] for more
+/// information.
+///
+/// [`app_permissions`]: Interaction::app_permissions
+pub const DIRECT_MESSAGE_PERMISSIONS: Permissions = Permissions::from_bits_truncate(
+    Permissions::ADD_REACTIONS.bits()
+        | Permissions::ATTACH_FILES.bits()
+        | Permissions::EMBED_LINKS.bits()
+        | Permissions::READ_MESSAGE_HISTORY.bits()
+        | Permissions::SEND_MESSAGES.bits()
+        | Permissions::USE_EXTERNAL_EMOJIS.bits()
+        | Permissions::VIEW_CHANNEL.bits(),
+);
+
+/// Cached guild channel and the ID of its guild.
+type GuildChannel<'a, CacheModels> = (
+    Ref<'a, Id<ChannelMarker>, <CacheModels as CacheableModels>::Channel>,
+    Id<GuildMarker>,
+);
+
+/// Type and permission overwrites of a channel in a [`PermissionSnapshot`].
+type SnapshotChannel = (ChannelType, Vec<PermissionOverwrite>);
+
 /// Error calculating permissions with the information in a cache.
 #[derive(Debug)]
 pub struct ChannelError {
@@ -289,14 +335,6 @@ enum MemberRolesErrorType {
     RoleMissing { role_id: Id<RoleMarker> },
 }
 
-/// Member's roles' permissions and the guild's `@everyone` role's permissions.
-struct MemberRoles {
-    /// User's roles and their permissions.
-    assigned: Vec<(Id<RoleMarker>, Permissions)>,
-    /// Permissions of the guild's `@everyone` role.
-    everyone: Permissions,
-}
-
 /// Calculate the permissions of a member with information from the cache.
 #[allow(clippy::type_complexity)]
 #[derive(Clone, Debug)]
@@ -402,15 +440,7 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
         user_id: Id<UserMarker>,
         channel_id: Id<ChannelMarker>,
     ) -> Result<Permissions, ChannelError> {
-        let channel = self.cache.channels.get(&channel_id).ok_or(ChannelError {
-            kind: ChannelErrorType::ChannelUnavailable { channel_id },
-            source: None,
-        })?;
-
-        let guild_id = channel.guild_id().ok_or(ChannelError {
-            kind: ChannelErrorType::ChannelNotInGuild { channel_id },
-            source: None,
-        })?;
+        let (channel, guild_id) = self.guild_channel(channel_id)?;
 
         if self.is_owner(user_id, guild_id) {
             return Ok(Permissions::all());
@@ -421,23 +451,162 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
             source: None,
         })?;
 
-        let MemberRoles { assigned, everyone } = self
-            .member_roles(guild_id, &member)
-            .map_err(ChannelError::from_member_roles)?;
+        self.channel_permissions(
+            user_id,
+            &channel,
+            guild_id,
+            member.roles(),
+            member.communication_disabled_until(),
+        )
+    }
 
-        let overwrites = match channel.kind() {
-            ChannelType::AnnouncementThread
-            | ChannelType::PrivateThread
-            | ChannelType::PublicThread => self.parent_overwrites(&channel)?,
-            _ => channel.permission_overwrites().unwrap_or_default().to_vec(),
-        };
+    /// Calculate the permissions of a member in a guild channel, using member
+    /// data provided by the caller rather than from the cache.
+    ///
+    /// This is useful when members aren't cached, such as when the
+    /// [`GUILD_MEMBERS`] intent isn't enabled, but their data is available
+    /// elsewhere, such as from an interaction's [`member`]. The channel, the
+    /// guild's owner, and the guild's roles are still retrieved from the cache.
+    ///
+    /// `roles` are the IDs of the member's roles, excluding the `@everyone`
+    /// role, and `communication_disabled_until` is when the member's
+    /// [communication is disabled until], if it is.
+    ///
+    /// Returns [`Permissions::all`] if the user is the owner of the guild.
+    ///
+    /// The following [`ResourceType`]s must be enabled:
+    ///
+    /// - [`ResourceType::CHANNEL`]
+    /// - [`ResourceType::ROLE`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    /// use twilight_model::application::interaction::Interaction;
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    /// # let interaction: Interaction = unimplemented!();
+    ///
+    /// // later on, when receiving an interaction...
+    ///
+    /// if let (Some(user_id), Some(channel), Some(member)) = (
+    ///     interaction.author_id(),
+    ///     &interaction.channel,
+    ///     &interaction.member,
+    /// ) {
+    ///     let permissions = cache.permissions().in_channel_with_member(
+    ///         user_id,
+    ///         channel.id,
+    ///         &member.roles,
+    ///         member.communication_disabled_until,
+    ///     )?;
+    ///     println!("User {user_id} has permissions {permissions:?}");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ChannelErrorType::ChannelUnavailable`] error type if the
+    /// guild channel is not in the cache.
+    ///
+    /// Returns a [`ChannelErrorType::RoleUnavailable`] error type if one of the
+    /// member's roles is not in the cache.
+    ///
+    /// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
+    /// [`Permissions::all`]: twilight_model::guild::Permissions::all
+    /// [`ResourceType::CHANNEL`]: crate::ResourceType::CHANNEL
+    /// [`ResourceType::ROLE`]: crate::ResourceType::ROLE
+    /// [`ResourceType`]: crate::ResourceType
+    /// [`member`]: twilight_model::application::interaction::Interaction::member
+    /// [communication is disabled until]: crate::model::CachedMember::communication_disabled_until
+    pub fn in_channel_with_member(
+        &self,
+        user_id: Id<UserMarker>,
+        channel_id: Id<ChannelMarker>,
+        roles: &[Id<RoleMarker>],
+        communication_disabled_until: Option<Timestamp>,
+    ) -> Result<Permissions, ChannelError> {
+        let (channel, guild_id) = self.guild_channel(channel_id)?;
 
-        let calculator =
-            PermissionCalculator::new(guild_id, user_id, everyone, assigned.as_slice());
+        if self.is_owner(user_id, guild_id) {
+            return Ok(Permissions::all());
+        }
 
-        let permissions = calculator.in_channel(channel.kind(), overwrites.as_slice());
+        self.channel_permissions(
+            user_id,
+            &channel,
+            guild_id,
+            roles,
+            communication_disabled_until,
+        )
+    }
 
-        Ok(self.disable_member_communication(&member, permissions))
+    /// Calculate the permissions of the current user where an interaction was
+    /// invoked.
+    ///
+    /// Permissions are calculated from the cache when the interaction was
+    /// invoked in a guild whose channel, roles, and current user's member are
+    /// cached, like with [`in_channel`].
+    ///
+    /// If calculating from the cache isn't possible, such as when the cache
+    /// is missing information or when an app is user-installed and the bot
+    /// isn't a member of the guild, then the interaction's
+    /// [`app_permissions`] are returned. If those are absent then
+    /// [`DIRECT_MESSAGE_PERMISSIONS`] are returned for interactions invoked
+    /// outside of a guild and no permissions otherwise.
+    ///
+    /// The following [`ResourceType`]s must be enabled to calculate
+    /// permissions from the cache:
+    ///
+    /// - [`ResourceType::CHANNEL`]
+    /// - [`ResourceType::MEMBER`]
+    /// - [`ResourceType::ROLE`]
+    /// - [`ResourceType::USER_CURRENT`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    /// use twilight_model::{application::interaction::Interaction, guild::Permissions};
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    /// # let interaction: Interaction = unimplemented!();
+    ///
+    /// // later on, when receiving an interaction...
+    ///
+    /// let permissions = cache.permissions().from_interaction(&interaction);
+    ///
+    /// if permissions.contains(Permissions::EMBED_LINKS) {
+    ///     println!("can respond with embeds");
+    /// }
+    /// ```
+    ///
+    /// [`ResourceType::CHANNEL`]: crate::ResourceType::CHANNEL
+    /// [`ResourceType::MEMBER`]: crate::ResourceType::MEMBER
+    /// [`ResourceType::ROLE`]: crate::ResourceType::ROLE
+    /// [`ResourceType::USER_CURRENT`]: crate::ResourceType::USER_CURRENT
+    /// [`ResourceType`]: crate::ResourceType
+    /// [`app_permissions`]: Interaction::app_permissions
+    /// [`in_channel`]: Self::in_channel
+    pub fn from_interaction(&self, interaction: &Interaction) -> Permissions {
+        if let (Some(_), Some(channel), Some(current_user)) = (
+            interaction.guild_id,
+            &interaction.channel,
+            self.cache.current_user(),
+        ) {
+            if let Ok(permissions) = self.in_channel(current_user.id(), channel.id) {
+                return permissions;
+            }
+        }
+
+        match (interaction.app_permissions, interaction.guild_id) {
+            (Some(permissions), _) => permissions,
+            (None, None) => DIRECT_MESSAGE_PERMISSIONS,
+            (None, Some(_)) => Permissions::empty(),
+        }
     }
 
     /// Calculate the guild-level permissions of a member.
@@ -501,57 +670,392 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
             source: None,
         })?;
 
-        let MemberRoles { assigned, everyone } = self
-            .member_roles(guild_id, &member)
+        self.root_permissions(
+            user_id,
+            guild_id,
+            member.roles(),
+            member.communication_disabled_until(),
+        )
+    }
+
+    /// Calculate the guild-level permissions of a member, using member data
+    /// provided by the caller rather than from the cache.
+    ///
+    /// Refer to [`in_channel_with_member`] for more information on when this
+    /// is useful and what the member data is.
+    ///
+    /// Returns [`Permissions::all`] if the user is the owner of the guild.
+    ///
+    /// The following [`ResourceType`]s must be enabled:
+    ///
+    /// - [`ResourceType::ROLE`]
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RootErrorType::RoleUnavailable`] error type if one of the
+    /// member's roles is not in the cache.
+    ///
+    /// [`Permissions::all`]: twilight_model::guild::Permissions::all
+    /// [`ResourceType::ROLE`]: crate::ResourceType::ROLE
+    /// [`ResourceType`]: crate::ResourceType
+    /// [`in_channel_with_member`]: Self::in_channel_with_member
+    pub fn root_with_member(
+        &self,
+        user_id: Id<UserMarker>,
+        guild_id: Id<GuildMarker>,
+        roles: &[Id<RoleMarker>],
+        communication_disabled_until: Option<Timestamp>,
+    ) -> Result<Permissions, RootError> {
+        if self.is_owner(user_id, guild_id) {
+            return Ok(Permissions::all());
+        }
+
+        self.root_permissions(user_id, guild_id, roles, communication_disabled_until)
+    }
+
+    /// Calculate the permissions of a member who isn't the owner in a guild
+    /// channel.
+    fn channel_permissions(
+        &self,
+        user_id: Id<UserMarker>,
+        channel: &CacheModels::Channel,
+        guild_id: Id<GuildMarker>,
+        roles: &[Id<RoleMarker>],
+        communication_disabled_until: Option<Timestamp>,
+    ) -> Result<Permissions, ChannelError> {
+        let everyone = self
+            .role_permissions(guild_id.cast())
+            .map_err(ChannelError::from_member_roles)?;
+
+        let overwrites = match channel.kind() {
+            ChannelType::AnnouncementThread
+            | ChannelType::PrivateThread
+            | ChannelType::PublicThread => self.parent_overwrites(channel)?,
+            _ => channel.permission_overwrites().unwrap_or_default().to_vec(),
+        };
+
+        let mut missing = None;
+        let calculator = IterPermissionCalculator::new(
+            guild_id,
+            user_id,
+            everyone,
+            self.member_roles(roles, &mut missing),
+        );
+
+        let permissions = calculator.in_channel(channel.kind(), overwrites.as_slice());
+
+        if let Some(role_id) = missing {
+            return Err(ChannelError::from_member_roles(
+                MemberRolesErrorType::RoleMissing { role_id },
+            ));
+        }
+
+        Ok(self.disable_member_communication(communication_disabled_until, permissions))
+    }
+
+    /// Calculate the guild-level permissions of a member who isn't the owner.
+    fn root_permissions(
+        &self,
+        user_id: Id<UserMarker>,
+        guild_id: Id<GuildMarker>,
+        roles: &[Id<RoleMarker>],
+        communication_disabled_until: Option<Timestamp>,
+    ) -> Result<Permissions, RootError> {
+        let everyone = self
+            .role_permissions(guild_id.cast())
             .map_err(RootError::from_member_roles)?;
-        let calculator =
-            PermissionCalculator::new(guild_id, user_id, everyone, assigned.as_slice());
+
+        let mut missing = None;
+        let calculator = IterPermissionCalculator::new(
+            guild_id,
+            user_id,
+            everyone,
+            self.member_roles(roles, &mut missing),
+        );
 
         let permissions = calculator.root();
 
-        Ok(self.disable_member_communication(&member, permissions))
+        if let Some(role_id) = missing {
+            return Err(RootError::from_member_roles(
+                MemberRolesErrorType::RoleMissing { role_id },
+            ));
+        }
+
+        Ok(self.disable_member_communication(communication_disabled_until, permissions))
     }
 
-    /// Determine whether the provided member is disabled and restrict them to
+    /// Determine whether a member is disabled and restrict them to
     /// [read-only permissions] if they are.
     ///
-    /// Only members whose [`communication_disabled_until`] values is in the
-    /// future count as being currently disabled. Members with the
-    /// [administrator permission] are never disabled.
+    /// Members with the [administrator permission] are never disabled.
     ///
-    /// [`communication_disabled_until`]: CachedMember::communication_disabled_until
     /// [administrator permission]: Permissions::ADMINISTRATOR
     /// [read-only permissions]: MEMBER_COMMUNICATION_DISABLED_ALLOWLIST
     fn disable_member_communication(
         &self,
-        member: &CacheModels::Member,
+        communication_disabled_until: Option<Timestamp>,
         permissions: Permissions,
     ) -> Permissions {
         // Administrators are never disabled.
-        if !self.check_member_communication_disabled
-            || permissions.contains(Permissions::ADMINISTRATOR)
+        if permissions.contains(Permissions::ADMINISTRATOR)
+            || !self.is_communication_disabled(communication_disabled_until)
         {
             return permissions;
         }
 
-        let micros = if let Some(until) = member.communication_disabled_until() {
+        permissions.intersection(MEMBER_COMMUNICATION_DISABLED_ALLOWLIST)
+    }
+
+    /// Take a snapshot of the data needed to calculate the permissions of a
+    /// member in a guild channel.
+    ///
+    /// Useful for custom checks that would otherwise need to look up several
+    /// resources from the cache, such as checking the member's roles
+    /// alongside their permissions. Refer to [`PermissionSnapshot`] for more
+    /// information.
+    ///
+    /// The same [`ResourceType`]s as for [`in_channel`] must be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    /// use twilight_model::{guild::Permissions, id::Id};
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    ///
+    /// // later on...
+    ///
+    /// let moderator_role_id = Id::new(3);
+    /// let snapshot = cache
+    ///     .permissions()
+    ///     .snapshot_in_channel(Id::new(5), Id::new(4))?;
+    ///
+    /// let has_role = snapshot.roles().iter().any(|(id, _)| *id == moderator_role_id);
+    /// let can_moderate = snapshot.permissions().contains(Permissions::MANAGE_MESSAGES);
+    /// println!("moderator: {}", has_role && can_moderate);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`in_channel`].
+    ///
+    /// [`ResourceType`]: crate::ResourceType
+    /// [`in_channel`]: Self::in_channel
+    pub fn snapshot_in_channel(
+        &self,
+        user_id: Id<UserMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<PermissionSnapshot, ChannelError> {
+        let (guild_id, channel) = self.channel(channel_id)?;
+
+        if self.is_owner(user_id, guild_id) {
+            return Ok(PermissionSnapshot::owner(guild_id, user_id, Some(channel)));
+        }
+
+        let (roles, communication_disabled_until) =
+            self.member(guild_id, user_id).ok_or(ChannelError {
+                kind: ChannelErrorType::MemberUnavailable { guild_id, user_id },
+                source: None,
+            })?;
+
+        self.snapshot(
+            user_id,
+            guild_id,
+            Some(channel),
+            &roles,
+            communication_disabled_until,
+        )
+        .map_err(ChannelError::from_member_roles)
+    }
+
+    /// Take a snapshot of the data needed to calculate the guild-level
+    /// permissions of a member.
+    ///
+    /// Refer to [`snapshot_in_channel`] and [`PermissionSnapshot`] for more
+    /// information.
+    ///
+    /// The same [`ResourceType`]s as for [`root`] must be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`root`].
+    ///
+    /// [`ResourceType`]: crate::ResourceType
+    /// [`root`]: Self::root
+    /// [`snapshot_in_channel`]: Self::snapshot_in_channel
+    pub fn snapshot_root(
+        &self,
+        user_id: Id<UserMarker>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<PermissionSnapshot, RootError> {
+        if self.is_owner(user_id, guild_id) {
+            return Ok(PermissionSnapshot::owner(guild_id, user_id, None));
+        }
+
+        let (roles, communication_disabled_until) =
+            self.member(guild_id, user_id).ok_or(RootError {
+                kind: RootErrorType::MemberUnavailable { guild_id, user_id },
+                source: None,
+            })?;
+
+        self.snapshot(
+            user_id,
+            guild_id,
+            None,
+            &roles,
+            communication_disabled_until,
+        )
+        .map_err(RootError::from_member_roles)
+    }
+
+    /// Take a snapshot of a member who isn't the owner, retrieving the
+    /// permissions of the `@everyone` role and the member's roles from the
+    /// cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MemberRolesErrorType::RoleMissing`] if one of the roles is
+    /// missing from the cache.
+    fn snapshot(
+        &self,
+        user_id: Id<UserMarker>,
+        guild_id: Id<GuildMarker>,
+        channel: Option<SnapshotChannel>,
+        roles: &[Id<RoleMarker>],
+        communication_disabled_until: Option<Timestamp>,
+    ) -> Result<PermissionSnapshot, MemberRolesErrorType> {
+        let everyone = self.role_permissions(guild_id.cast())?;
+        let roles = roles
+            .iter()
+            .map(|role_id| Ok((*role_id, self.role_permissions(*role_id)?)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(PermissionSnapshot {
+            channel,
+            communication_disabled: self.is_communication_disabled(communication_disabled_until),
+            everyone,
+            guild_id,
+            is_owner: false,
+            roles,
+            user_id,
+        })
+    }
+
+    /// Determine whether a member's communication is currently disabled.
+    ///
+    /// Only members whose [`communication_disabled_until`] values is in the
+    /// future count as being currently disabled.
+    ///
+    /// [`communication_disabled_until`]: CachedMember::communication_disabled_until
+    fn is_communication_disabled(&self, communication_disabled_until: Option<Timestamp>) -> bool {
+        if !self.check_member_communication_disabled {
+            return false;
+        }
+
+        let micros = if let Some(until) = communication_disabled_until {
             until.as_micros()
         } else {
-            return permissions;
+            return false;
         };
 
         let Ok(absolute) = micros.try_into() else {
-            return permissions;
+            return false;
         };
 
         let ends = SystemTime::UNIX_EPOCH + Duration::from_micros(absolute);
-        let now = SystemTime::now();
 
-        if now > ends {
-            return permissions;
+        self.cache.config.now() < ends
+    }
+
+    /// Retrieve a guild channel and the ID of its guild from the cache.
+    fn guild_channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<GuildChannel<'_, CacheModels>, ChannelError> {
+        let channel = self.cache.channels.get(&channel_id).ok_or(ChannelError {
+            kind: ChannelErrorType::ChannelUnavailable { channel_id },
+            source: None,
+        })?;
+
+        let guild_id = channel.guild_id().ok_or(ChannelError {
+            kind: ChannelErrorType::ChannelNotInGuild { channel_id },
+            source: None,
+        })?;
+
+        Ok((channel, guild_id))
+    }
+
+    /// Retrieve a guild channel's type and permission overwrites and the ID of
+    /// its guild from the cache.
+    ///
+    /// Threads are combined with the permission overwrites of their parent.
+    fn channel(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<(Id<GuildMarker>, SnapshotChannel), ChannelError> {
+        let (guild_id, kind, parent_id, overwrites) = {
+            let channel = self.cache.channels.get(&channel_id).ok_or(ChannelError {
+                kind: ChannelErrorType::ChannelUnavailable { channel_id },
+                source: None,
+            })?;
+
+            let guild_id = channel.guild_id().ok_or(ChannelError {
+                kind: ChannelErrorType::ChannelNotInGuild { channel_id },
+                source: None,
+            })?;
+
+            (
+                guild_id,
+                channel.kind(),
+                channel.parent_id(),
+                channel.permission_overwrites().unwrap_or_default().to_vec(),
+            )
+        };
+
+        if !matches!(
+            kind,
+            ChannelType::AnnouncementThread
+                | ChannelType::PrivateThread
+                | ChannelType::PublicThread
+        ) {
+            return Ok((guild_id, (kind, overwrites)));
         }
 
-        permissions.intersection(MEMBER_COMMUNICATION_DISABLED_ALLOWLIST)
+        let parent_id = parent_id.ok_or(ChannelError {
+            kind: ChannelErrorType::ParentChannelNotPresent {
+                thread_id: channel_id,
+            },
+            source: None,
+        })?;
+
+        // The thread's guard is released before retrieving its parent, which
+        // may be in the same shard of the map.
+        let parent = self.cache.channels.get(&parent_id).ok_or(ChannelError {
+            kind: ChannelErrorType::ChannelUnavailable {
+                channel_id: parent_id,
+            },
+            source: None,
+        })?;
+
+        if parent.guild_id().is_none() {
+            return Err(ChannelError {
+                kind: ChannelErrorType::ChannelNotInGuild {
+                    channel_id: parent.id(),
+                },
+                source: None,
+            });
+        }
+
+        let parent_overwrites = parent.permission_overwrites().unwrap_or_default();
+
+        let mut combined = Vec::with_capacity(parent_overwrites.len() + overwrites.len());
+        combined.extend_from_slice(parent_overwrites);
+        combined.extend(overwrites);
+
+        Ok((guild_id, (kind, combined)))
     }
 
     /// Determine whether a given user is the owner of a guild.
@@ -565,40 +1069,59 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
             .is_some_and(|r| r.owner_id() == user_id)
     }
 
-    /// Retrieve a member's roles' permissions and the guild's `@everyone`
-    /// role's permissions.
+    /// Retrieve the role IDs and when the communication of a member is
+    /// disabled until from the cache.
+    fn member(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Option<(Vec<Id<RoleMarker>>, Option<Timestamp>)> {
+        self.cache.member(guild_id, user_id).map(|member| {
+            (
+                member.roles().to_vec(),
+                member.communication_disabled_until(),
+            )
+        })
+    }
+
+    /// Retrieve the permissions of a role from the cache.
     ///
     /// # Errors
     ///
-    /// Returns [`MemberRolesErrorType::RoleMissing`] if a role is missing from
-    /// the cache.
-    fn member_roles(
+    /// Returns [`MemberRolesErrorType::RoleMissing`] if the role is missing
+    /// from the cache.
+    fn role_permissions(
         &self,
-        guild_id: Id<GuildMarker>,
-        member: &'a CacheModels::Member,
-    ) -> Result<MemberRoles, MemberRolesErrorType> {
-        let mut member_roles = Vec::with_capacity(member.roles().len());
-
-        for role_id in member.roles() {
-            let Some(role) = self.cache.roles.get(role_id) else {
-                return Err(MemberRolesErrorType::RoleMissing { role_id: *role_id });
-            };
-
-            member_roles.push((*role_id, role.permissions()));
-        }
+        role_id: Id<RoleMarker>,
+    ) -> Result<Permissions, MemberRolesErrorType> {
+        self.cache
+            .roles
+            .get(&role_id)
+            .map(|role| role.permissions())
+            .ok_or(MemberRolesErrorType::RoleMissing { role_id })
+    }
 
-        let everyone_role_id = guild_id.cast();
+    /// Iterate over a member's roles and their permissions, looking them up
+    /// from the cache as they're iterated over.
+    ///
+    /// Iteration stops at the first role missing from the cache, whose ID is
+    /// recorded in `missing`.
+    fn member_roles<'b>(
+        &'b self,
+        roles: &'b [Id<RoleMarker>],
+        missing: &'b mut Option<Id<RoleMarker>>,
+    ) -> impl Iterator<Item = (Id<RoleMarker>, Permissions)> + 'b {
+        let cache: &'b InMemoryCache<CacheModels> = self.cache;
+
+        roles.iter().map_while(move |role_id| {
+            if let Some(role) = cache.roles.get(role_id) {
+                Some((*role_id, role.permissions()))
+            } else {
+                *missing = Some(*role_id);
 
-        if let Some(everyone_role) = self.cache.roles.get(&everyone_role_id) {
-            Ok(MemberRoles {
-                assigned: member_roles,
-                everyone: everyone_role.permissions(),
-            })
-        } else {
-            Err(MemberRolesErrorType::RoleMissing {
-                role_id: everyone_role_id,
-            })
-        }
+                None
+            }
+        })
     }
 
     /// Given a thread channel, retrieve its parent from the cache, and combine
@@ -643,10 +1166,151 @@ impl<'a, CacheModels: CacheableModels> InMemoryCachePermissions<'a, CacheModels>
     }
 }
 
+/// Data needed to calculate the permissions of a member, copied out of the
+/// cache.
+///
+/// Taken via [`InMemoryCachePermissions::snapshot_in_channel`] or
+/// [`InMemoryCachePermissions::snapshot_root`]. The channel, guild, member and
+/// role maps are each accessed in that order and no guard is held while
+/// accessing another map, so taking a snapshot doesn't hold up cache updates
+/// and custom checks against it can't deadlock with them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionSnapshot {
+    /// Type and permission overwrites of the channel, if taken for one.
+    channel: Option<SnapshotChannel>,
+    /// Whether the member's communication is disabled.
+    communication_disabled: bool,
+    /// Permissions of the `@everyone` role.
+    everyone: Permissions,
+    /// ID of the guild.
+    guild_id: Id<GuildMarker>,
+    /// Whether the user is the owner of the guild.
+    is_owner: bool,
+    /// IDs and permissions of the member's roles.
+    roles: Vec<(Id<RoleMarker>, Permissions)>,
+    /// ID of the user.
+    user_id: Id<UserMarker>,
+}
+
+impl PermissionSnapshot {
+    /// Create a snapshot of the owner of a guild, who has all permissions.
+    const fn owner(
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        channel: Option<SnapshotChannel>,
+    ) -> Self {
+        Self {
+            channel,
+            communication_disabled: false,
+            everyone: Permissions::empty(),
+            guild_id,
+            is_owner: true,
+            roles: Vec::new(),
+            user_id,
+        }
+    }
+
+    /// Type of the channel, if the snapshot was taken for one.
+    pub const fn channel_kind(&self) -> Option<ChannelType> {
+        match &self.channel {
+            Some((kind, _)) => Some(*kind),
+            None => None,
+        }
+    }
+
+    /// Permission overwrites of the channel, including those of a thread's
+    /// parent.
+    ///
+    /// Empty if the snapshot wasn't taken for a channel.
+    pub fn channel_overwrites(&self) -> &[PermissionOverwrite] {
+        self.channel
+            .as_ref()
+            .map_or(&[], |(_, overwrites)| overwrites.as_slice())
+    }
+
+    /// Permissions of the guild's `@everyone` role.
+    ///
+    /// Empty if the user is the owner of the guild, in which case it isn't
+    /// retrieved.
+    pub const fn everyone(&self) -> Permissions {
+        self.everyone
+    }
+
+    /// ID of the guild.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// Whether the member's [communication is disabled], restricting them to
+    /// [read-only permissions].
+    ///
+    /// [communication is disabled]: crate::model::CachedMember::communication_disabled_until
+    /// [read-only permissions]: MEMBER_COMMUNICATION_DISABLED_ALLOWLIST
+    pub const fn is_communication_disabled(&self) -> bool {
+        self.communication_disabled
+    }
+
+    /// Whether the user is the owner of the guild.
+    pub const fn is_owner(&self) -> bool {
+        self.is_owner
+    }
+
+    /// IDs and permissions of the member's roles, excluding the `@everyone`
+    /// role.
+    ///
+    /// Empty if the user is the owner of the guild, in which case they aren't
+    /// retrieved.
+    pub fn roles(&self) -> &[(Id<RoleMarker>, Permissions)] {
+        &self.roles
+    }
+
+    /// ID of the user.
+    pub const fn user_id(&self) -> Id<UserMarker> {
+        self.user_id
+    }
+
+    /// Calculate the permissions of the member.
+    ///
+    /// These are the permissions in the channel if the snapshot was taken for
+    /// one, or otherwise the guild-level permissions.
+    pub fn permissions(&self) -> Permissions {
+        self.calculate(self.channel.as_ref())
+    }
+
+    /// Calculate the guild-level permissions of the member.
+    pub fn root(&self) -> Permissions {
+        self.calculate(None)
+    }
+
+    /// Calculate the permissions of the member in a channel, or the
+    /// guild-level permissions if none is provided.
+    fn calculate(&self, channel: Option<&SnapshotChannel>) -> Permissions {
+        if self.is_owner {
+            return Permissions::all();
+        }
+
+        let calculator =
+            PermissionCalculator::new(self.guild_id, self.user_id, self.everyone, &self.roles);
+
+        let permissions = match channel {
+            Some((kind, overwrites)) => calculator.in_channel(*kind, overwrites),
+            None => calculator.root(),
+        };
+
+        // Administrators are never disabled.
+        if self.communication_disabled && !permissions.contains(Permissions::ADMINISTRATOR) {
+            permissions.intersection(MEMBER_COMMUNICATION_DISABLED_ALLOWLIST)
+        } else {
+            permissions
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        ChannelError, ChannelErrorType, InMemoryCachePermissions, RootError, RootErrorType,
+        ChannelError, ChannelErrorType, InMemoryCachePermissions, PermissionSnapshot, RootError,
+        RootErrorType, DIRECT_MESSAGE_PERMISSIONS,
     };
     use crate::{test, DefaultCacheModels, DefaultInMemoryCache};
     use static_assertions::{assert_fields, assert_impl_all};
@@ -654,24 +1318,30 @@ mod tests {
         error::Error,
         fmt::Debug,
         str::FromStr,
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
         time::{Duration, SystemTime},
     };
     use twilight_model::{
+        application::interaction::{Interaction, InteractionType},
         channel::{
             permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
             Channel, ChannelType,
         },
         gateway::payload::incoming::{
             ChannelCreate, GuildCreate, MemberAdd, MemberUpdate, RoleCreate, ThreadCreate,
+            UserUpdate,
         },
         guild::{
-            AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel,
-            NSFWLevel, Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
+            AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, GatewayGuild,
+            MfaLevel, NSFWLevel, Permissions, PremiumTier, Role, SystemChannelFlags,
+            VerificationLevel,
         },
         id::{
             marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
             Id,
         },
+        oauth::ApplicationIntegrationMap,
         util::Timestamp,
     };
 
@@ -681,6 +1351,7 @@ mod tests {
     assert_impl_all!(ChannelErrorType: Debug, Send, Sync);
     assert_impl_all!(ChannelError: Debug, Send, Sync);
     assert_impl_all!(InMemoryCachePermissions<'_, DefaultCacheModels>: Clone, Debug, Send, Sync);
+    assert_impl_all!(PermissionSnapshot: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_fields!(RootErrorType::MemberUnavailable: guild_id, user_id);
     assert_fields!(RootErrorType::RoleUnavailable: role_id);
     assert_impl_all!(RootErrorType: Debug, Send, Sync);
@@ -712,8 +1383,8 @@ mod tests {
     /// ID of the safety alerts channel.
     const SAFETY_ALERTS_CHANNEL_ID: Id<ChannelMarker> = Id::new(6);
 
-    fn base_guild() -> Guild {
-        Guild {
+    fn base_guild() -> GatewayGuild {
+        GatewayGuild {
             id: GUILD_ID,
             afk_channel_id: None,
             afk_timeout: AfkTimeout::FIVE_MINUTES,
@@ -884,6 +1555,36 @@ mod tests {
         RoleCreate { guild_id, role }
     }
 
+    #[allow(deprecated)]
+    fn interaction(
+        guild_id: Option<Id<GuildMarker>>,
+        app_permissions: Option<Permissions>,
+    ) -> Interaction {
+        Interaction {
+            app_permissions,
+            application_id: Id::new(1),
+            authorizing_integration_owners: ApplicationIntegrationMap {
+                guild: None,
+                user: None,
+            },
+            channel: Some(channel()),
+            channel_id: None,
+            context: None,
+            data: None,
+            entitlements: Vec::new(),
+            guild: None,
+            guild_id,
+            guild_locale: None,
+            id: Id::new(2),
+            kind: InteractionType::ApplicationCommand,
+            locale: None,
+            member: None,
+            message: None,
+            token: "token".to_owned(),
+            user: None,
+        }
+    }
+
     /// Test that the permissions interface returns the correct errors depending
     /// on what information is unavailable during [`root`] operations.
     ///
@@ -1020,6 +1721,341 @@ mod tests {
         Ok(())
     }
 
+    /// Test that snapshots calculate the same permissions as the permissions
+    /// interface and contain the data they were calculated from.
+    #[test]
+    fn snapshot() -> Result<(), Box<dyn Error>> {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate::Available(base_guild()));
+        cache.update(&ChannelCreate(channel()));
+        cache.update(&ThreadCreate(thread()));
+        let mut member = test::member(USER_ID);
+        member.roles.push(OTHER_ROLE_ID);
+        cache.update(&MemberAdd {
+            guild_id: GUILD_ID,
+            member,
+        });
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(
+                OTHER_ROLE_ID,
+                Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS,
+            ),
+        ));
+
+        let snapshot = permissions.snapshot_in_channel(USER_ID, THREAD_ID)?;
+        assert_eq!(
+            permissions.in_channel(USER_ID, THREAD_ID)?,
+            snapshot.permissions()
+        );
+        assert_eq!(permissions.root(USER_ID, GUILD_ID)?, snapshot.root());
+        assert_eq!(Some(ChannelType::PublicThread), snapshot.channel_kind());
+        assert_eq!(
+            channel().permission_overwrites.unwrap().len()
+                + thread().permission_overwrites.unwrap().len(),
+            snapshot.channel_overwrites().len()
+        );
+        assert_eq!(
+            [(
+                OTHER_ROLE_ID,
+                Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS
+            )],
+            snapshot.roles()
+        );
+        assert!(!snapshot.is_owner());
+
+        let snapshot = permissions.snapshot_root(USER_ID, GUILD_ID)?;
+        assert_eq!(permissions.root(USER_ID, GUILD_ID)?, snapshot.permissions());
+        assert!(snapshot.channel_kind().is_none());
+        assert!(snapshot.channel_overwrites().is_empty());
+
+        let snapshot = permissions.snapshot_in_channel(OWNER_ID, CHANNEL_ID)?;
+        assert!(snapshot.is_owner());
+        assert_eq!(Permissions::all(), snapshot.permissions());
+
+        Ok(())
+    }
+
+    /// Test that permissions, both with and without snapshots, are consistently
+    /// calculated while another thread updates the resources they're
+    /// calculated from.
+    #[test]
+    fn concurrent_updates() -> Result<(), Box<dyn Error>> {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate::Available(base_guild()));
+        cache.update(&ChannelCreate(channel()));
+        cache.update(&ThreadCreate(thread()));
+        cache.update(&MemberAdd {
+            guild_id: GUILD_ID,
+            member: test::member(USER_ID),
+        });
+        let role = role_with_permissions(
+            OTHER_ROLE_ID,
+            Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS,
+        );
+        cache.update(&role_create(GUILD_ID, role.clone()));
+
+        let member_update = |roles| MemberUpdate {
+            avatar: None,
+            communication_disabled_until: None,
+            guild_id: GUILD_ID,
+            deaf: None,
+            flags: None,
+            joined_at: None,
+            mute: None,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles,
+            user: test::user(USER_ID),
+        };
+
+        let without_role = permissions.in_channel(USER_ID, THREAD_ID)?;
+        cache.update(&member_update(Vec::from([OTHER_ROLE_ID])));
+        let with_role = permissions.in_channel(USER_ID, THREAD_ID)?;
+        assert_ne!(without_role, with_role);
+
+        let done = AtomicBool::new(false);
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for round in 0..1000 {
+                    let roles = if round % 2 == 0 {
+                        Vec::new()
+                    } else {
+                        Vec::from([OTHER_ROLE_ID])
+                    };
+                    cache.update(&member_update(roles));
+                    cache.update(&role_create(GUILD_ID, role.clone()));
+                    cache.update(&ChannelCreate(channel()));
+                    cache.update(&ThreadCreate(thread()));
+                }
+
+                done.store(true, Ordering::Relaxed);
+            });
+
+            while !done.load(Ordering::Relaxed) {
+                let calculated = permissions.in_channel(USER_ID, THREAD_ID).unwrap();
+                assert!(calculated == without_role || calculated == with_role);
+
+                let snapshot = permissions.snapshot_in_channel(USER_ID, THREAD_ID).unwrap();
+                let calculated = snapshot.permissions();
+                assert!(calculated == without_role || calculated == with_role);
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Test that [`root_with_member`] calculates permissions with member data
+    /// that isn't in the cache.
+    ///
+    /// [`root_with_member`]: super::InMemoryCachePermissions::root_with_member
+    #[test]
+    fn root_with_member() -> Result<(), Box<dyn Error>> {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate::Available(base_guild()));
+        assert!(matches!(
+            permissions.root(USER_ID, GUILD_ID).unwrap_err().kind(),
+            RootErrorType::MemberUnavailable { .. }
+        ));
+        assert!(matches!(
+            permissions
+                .root_with_member(USER_ID, GUILD_ID, &[OTHER_ROLE_ID], None)
+                .unwrap_err()
+                .kind(),
+            &RootErrorType::RoleUnavailable { role_id }
+            if role_id == OTHER_ROLE_ID
+        ));
+
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(
+                OTHER_ROLE_ID,
+                Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS,
+            ),
+        ));
+
+        let expected = Permissions::CREATE_INVITE
+            | Permissions::BAN_MEMBERS
+            | Permissions::VIEW_AUDIT_LOG
+            | Permissions::SEND_MESSAGES;
+
+        assert_eq!(
+            expected,
+            permissions.root_with_member(USER_ID, GUILD_ID, &[OTHER_ROLE_ID], None)?
+        );
+        assert!(permissions
+            .root_with_member(OWNER_ID, GUILD_ID, &[], None)?
+            .is_all());
+
+        Ok(())
+    }
+
+    /// Test that [`in_channel_with_member`] calculates permissions with member
+    /// data that isn't in the cache, while still using the cached channel and
+    /// roles.
+    ///
+    /// [`in_channel_with_member`]: super::InMemoryCachePermissions::in_channel_with_member
+    #[test]
+    fn in_channel_with_member() -> Result<(), Box<dyn Error>> {
+        let clock =
+            test::ManualClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(1_632_072_645));
+        let cache = test::cache_with_clock(&clock);
+        let permissions = cache.permissions();
+        let roles = [OTHER_ROLE_ID];
+
+        cache.update(&GuildCreate::Available(base_guild()));
+        assert!(matches!(
+            permissions
+                .in_channel_with_member(USER_ID, CHANNEL_ID, &roles, None)
+                .unwrap_err()
+                .kind(),
+            ChannelErrorType::ChannelUnavailable { channel_id: c_id }
+            if *c_id == CHANNEL_ID
+        ));
+
+        cache.update(&ChannelCreate(channel()));
+        assert!(matches!(
+            permissions
+                .in_channel_with_member(USER_ID, CHANNEL_ID, &roles, None)
+                .unwrap_err()
+                .kind(),
+            &ChannelErrorType::RoleUnavailable { role_id }
+            if role_id == OTHER_ROLE_ID
+        ));
+
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(
+                OTHER_ROLE_ID,
+                Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS,
+            ),
+        ));
+
+        assert!(matches!(
+            permissions
+                .in_channel(USER_ID, CHANNEL_ID)
+                .unwrap_err()
+                .kind(),
+            ChannelErrorType::MemberUnavailable { .. }
+        ));
+        assert_eq!(
+            Permissions::EMBED_LINKS | Permissions::SEND_MESSAGES,
+            permissions.in_channel_with_member(USER_ID, CHANNEL_ID, &roles, None)?,
+        );
+
+        cache.update(&ThreadCreate(thread()));
+
+        assert_eq!(
+            Permissions::EMBED_LINKS | Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES,
+            permissions.in_channel_with_member(USER_ID, THREAD_ID, &roles, None)?
+        );
+
+        let in_future = Timestamp::from_secs(
+            (clock.now() + Duration::from_secs(60))
+                .duration_since(SystemTime::UNIX_EPOCH)?
+                .as_secs()
+                .try_into()?,
+        )?;
+        assert_eq!(
+            Permissions::empty(),
+            permissions.in_channel_with_member(USER_ID, CHANNEL_ID, &roles, Some(in_future))?
+        );
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(
+            Permissions::EMBED_LINKS | Permissions::SEND_MESSAGES,
+            permissions.in_channel_with_member(USER_ID, CHANNEL_ID, &roles, Some(in_future))?
+        );
+
+        Ok(())
+    }
+
+    /// Test that [`from_interaction`] calculates the current user's
+    /// permissions from the cache when the guild is cached, preferring them
+    /// over the interaction's permissions.
+    ///
+    /// [`from_interaction`]: super::InMemoryCachePermissions::from_interaction
+    #[test]
+    fn from_interaction_cached_guild() {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+        let mut member = test::member(USER_ID);
+        member.roles.push(OTHER_ROLE_ID);
+
+        cache.update(&UserUpdate(test::current_user(USER_ID.get())));
+        cache.update(&GuildCreate::Available(base_guild()));
+        cache.update(&ChannelCreate(channel()));
+        cache.update(&MemberAdd {
+            guild_id: GUILD_ID,
+            member,
+        });
+        cache.update(&role_create(
+            GUILD_ID,
+            role_with_permissions(
+                OTHER_ROLE_ID,
+                Permissions::SEND_MESSAGES | Permissions::BAN_MEMBERS,
+            ),
+        ));
+
+        assert_eq!(
+            Permissions::EMBED_LINKS | Permissions::SEND_MESSAGES,
+            permissions.from_interaction(&interaction(
+                Some(GUILD_ID),
+                Some(Permissions::ADMINISTRATOR)
+            )),
+        );
+    }
+
+    /// Test that [`from_interaction`] falls back to the interaction's
+    /// permissions when the guild isn't cached.
+    ///
+    /// [`from_interaction`]: super::InMemoryCachePermissions::from_interaction
+    #[test]
+    fn from_interaction_uncached_guild() {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&UserUpdate(test::current_user(USER_ID.get())));
+
+        assert_eq!(
+            Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            permissions.from_interaction(&interaction(
+                Some(GUILD_ID),
+                Some(Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL)
+            )),
+        );
+        assert!(permissions
+            .from_interaction(&interaction(Some(GUILD_ID), None))
+            .is_empty());
+    }
+
+    /// Test that [`from_interaction`] uses the interaction's permissions in
+    /// direct messages, defaulting to [`DIRECT_MESSAGE_PERMISSIONS`] when
+    /// they're absent.
+    ///
+    /// [`from_interaction`]: super::InMemoryCachePermissions::from_interaction
+    #[test]
+    fn from_interaction_direct_message() {
+        let cache = DefaultInMemoryCache::new();
+        let permissions = cache.permissions();
+
+        assert_eq!(
+            Permissions::ATTACH_FILES,
+            permissions.from_interaction(&interaction(None, Some(Permissions::ATTACH_FILES))),
+        );
+        assert_eq!(
+            DIRECT_MESSAGE_PERMISSIONS,
+            permissions.from_interaction(&interaction(None, None)),
+        );
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
@@ -1051,16 +2087,20 @@ mod tests {
     ///   [`Permissions::VIEW_CHANNEL`] permissions on a [guild level][`root`]
     /// - the same is true on a [channel level][`in_channel`]
     /// - administrators are never timed out
+    /// - the timeout no longer applies once it has ended
     /// - checking whether the member's communication is disabled is configurable
     ///
     /// [`in_channel`]: super::InMemoryCachePermissions::in_channel
     /// [`root`]: super::InMemoryCachePermissions::root
     #[test]
     fn member_communication_disabled() -> Result<(), Box<dyn Error>> {
-        fn acceptable_time(in_future: bool) -> Result<Timestamp, Box<dyn Error>> {
+        fn acceptable_time(
+            clock: &test::ManualClock,
+            in_future: bool,
+        ) -> Result<Timestamp, Box<dyn Error>> {
             const TIME_RANGE: Duration = Duration::from_secs(60);
 
-            let now = SystemTime::now();
+            let now = clock.now();
 
             let system_time = if in_future {
                 now + TIME_RANGE
@@ -1074,11 +2114,13 @@ mod tests {
             Timestamp::from_micros(micros).map_err(From::from)
         }
 
-        let cache = DefaultInMemoryCache::new();
+        let clock =
+            test::ManualClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(1_632_072_645));
+        let cache = test::cache_with_clock(&clock);
         let mut permissions = cache.permissions();
 
-        let in_past = acceptable_time(false)?;
-        let in_future = acceptable_time(true)?;
+        let in_past = acceptable_time(&clock, false)?;
+        let in_future = acceptable_time(&clock, true)?;
 
         let mut guild = base_guild();
         let everyone_permissions = Permissions::CREATE_INVITE
@@ -1113,6 +2155,10 @@ mod tests {
         assert_eq!(everyone_permissions, permissions.root(USER_ID, GUILD_ID)?);
         permissions = permissions.check_member_communication_disabled(true);
 
+        // check that the timeout ends once the clock passes it
+        clock.advance(Duration::from_secs(120));
+        assert_eq!(everyone_permissions, permissions.root(USER_ID, GUILD_ID)?);
+
         // check administrators are never disabled
         cache.update(&role_create(
             GUILD_ID,
@@ -1,9 +1,13 @@
-use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker},
-    Id,
+use std::collections::HashMap;
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
 };
 
-use crate::{CacheableModels, DefaultCacheModels};
+use crate::{traits::CacheableChannel, CacheableModels, DefaultCacheModels};
 
 use super::InMemoryCache;
 
@@ -100,6 +104,45 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         Some(guild.len())
     }
 
+    /// Number of channels of each type in a given guild in the cache.
+    ///
+    /// Types without any channels are omitted.
+    ///
+    /// Returns `None` if the guild hasn't yet been cached.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve the number of voice channels in a guild:
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::DefaultInMemoryCache;
+    /// use twilight_model::{channel::ChannelType, id::Id};
+    ///
+    /// let cache = DefaultInMemoryCache::new();
+    ///
+    /// // later on...
+    /// if let Some(counts) = cache.stats().guild_channels_by_kind(Id::new(1)) {
+    ///     let voice = counts.get(&ChannelType::GuildVoice).copied().unwrap_or(0);
+    ///     println!("voice channel count: {voice}");
+    /// }
+    /// ```
+    pub fn guild_channels_by_kind(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Option<HashMap<ChannelType, usize>> {
+        if !self.0.guild_channels.contains_key(&guild_id) {
+            return None;
+        }
+
+        let mut counts = HashMap::new();
+
+        for channel in self.0.iter().channels_in(guild_id) {
+            *counts.entry(channel.kind()).or_default() += 1;
+        }
+
+        Some(counts)
+    }
+
     /// Number of emojis in a given guild in the cache.
     ///
     /// Returns `None` if the guild hasn't yet been cached.
@@ -145,11 +188,21 @@ impl<'a, CacheModels: CacheableModels> InMemoryCacheStats<'a, CacheModels> {
         Some(guild.len())
     }
 
+    /// Number of invites in the cache.
+    pub fn invites(&self) -> usize {
+        self.0.invites.len()
+    }
+
     /// Number of members in the cache.
     pub fn members(&self) -> usize {
         self.0.members.len()
     }
 
+    /// Number of messages in the cache.
+    pub fn messages(&self) -> usize {
+        self.0.messages.len()
+    }
+
     /// Number of presences in the cache.
     pub fn presences(&self) -> usize {
         self.0.presences.len()
@@ -1,4 +1,8 @@
 use crate::DefaultInMemoryCache;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
 use twilight_model::{
     channel::{
         message::{
@@ -13,9 +17,9 @@ use twilight_model::{
     },
     guild::{
         scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, Status},
-        AfkTimeout, DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member,
-        MemberFlags, MfaLevel, NSFWLevel, PartialMember, Permissions, PremiumTier, Role, RoleFlags,
-        SystemChannelFlags, VerificationLevel,
+        AfkTimeout, DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, GatewayGuild,
+        Member, MemberFlags, MfaLevel, NSFWLevel, PartialMember, Permissions, PremiumTier, Role,
+        RoleFlags, SystemChannelFlags, VerificationLevel,
     },
     id::{
         marker::{
@@ -33,6 +37,41 @@ pub fn cache() -> DefaultInMemoryCache {
     DefaultInMemoryCache::new()
 }
 
+/// Clock that only moves when told to, for deterministic time-sensitive tests.
+#[derive(Clone, Debug)]
+pub struct ManualClock(Arc<Mutex<SystemTime>>);
+
+impl ManualClock {
+    /// Create a clock frozen at the given time.
+    pub fn new(now: SystemTime) -> Self {
+        Self(Arc::new(Mutex::new(now)))
+    }
+
+    /// Move the clock forward.
+    pub fn advance(&self, duration: Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+
+    /// Function to provide to [`InMemoryCacheBuilder::clock`].
+    ///
+    /// [`InMemoryCacheBuilder::clock`]: crate::InMemoryCacheBuilder::clock
+    pub fn clock(&self) -> Arc<dyn Fn() -> SystemTime + Send + Sync> {
+        let now = Arc::clone(&self.0);
+
+        Arc::new(move || *now.lock().unwrap())
+    }
+
+    /// Current time of the clock.
+    pub fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// Create a cache which reads the current time from a manual clock.
+pub fn cache_with_clock(clock: &ManualClock) -> DefaultInMemoryCache {
+    DefaultInMemoryCache::builder().clock(clock.clock()).build()
+}
+
 #[allow(clippy::too_many_lines, deprecated)]
 pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
     let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
@@ -40,75 +79,7 @@ pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
     let avatar = ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap();
     let flags = MemberFlags::BYPASSES_VERIFICATION | MemberFlags::DID_REJOIN;
 
-    let msg = Message {
-        activity: None,
-        application: None,
-        application_id: None,
-        attachments: Vec::new(),
-        author: User {
-            accent_color: None,
-            avatar: Some(avatar),
-            avatar_decoration: None,
-            avatar_decoration_data: None,
-            banner: None,
-            bot: false,
-            discriminator: 1,
-            email: None,
-            flags: None,
-            global_name: Some("test".to_owned()),
-            id: Id::new(3),
-            locale: None,
-            mfa_enabled: None,
-            name: "test".to_owned(),
-            premium_type: None,
-            public_flags: None,
-            system: None,
-            verified: None,
-        },
-        call: None,
-        channel_id: Id::new(2),
-        components: Vec::new(),
-        content: "ping".to_owned(),
-        edited_timestamp: None,
-        embeds: Vec::new(),
-        flags: Some(MessageFlags::empty()),
-        guild_id: Some(Id::new(1)),
-        id: Id::new(4),
-        interaction: None,
-        interaction_metadata: None,
-        kind: MessageType::Regular,
-        member: Some(PartialMember {
-            avatar: None,
-            communication_disabled_until: None,
-            deaf: false,
-            flags,
-            joined_at,
-            mute: false,
-            nick: Some("member nick".to_owned()),
-            permissions: None,
-            premium_since: None,
-            roles: Vec::new(),
-            user: None,
-        }),
-        mention_channels: Vec::new(),
-        mention_everyone: false,
-        mention_roles: Vec::new(),
-        mentions: Vec::new(),
-        message_snapshots: Vec::new(),
-        pinned: false,
-        poll: None,
-        reactions: Vec::new(),
-        reference: None,
-        referenced_message: None,
-        role_subscription_data: None,
-        sticker_items: Vec::new(),
-        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
-        thread: None,
-        tts: false,
-        webhook_id: None,
-    };
-
-    cache.update(&MessageCreate(msg));
+    cache.update(&MessageCreate(message()));
 
     let mut reaction = ReactionAdd(GatewayReaction {
         burst: false,
@@ -145,6 +116,7 @@ pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
                 mfa_enabled: None,
                 name: "test".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -187,6 +159,7 @@ pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
             mfa_enabled: None,
             name: "test".to_owned(),
             premium_type: None,
+            primary_guild: None,
             public_flags: None,
             system: None,
             verified: None,
@@ -213,10 +186,87 @@ pub fn cache_with_message_and_reactions() -> DefaultInMemoryCache {
     cache
 }
 
+#[allow(deprecated)]
+pub fn message() -> Message {
+    let joined_at = Some(Timestamp::from_secs(1_632_072_645).expect("non zero"));
+    let avatar = ImageHash::parse(b"6961d9f1fdb5880bf4a3ec6348d3bbcf").unwrap();
+    let flags = MemberFlags::BYPASSES_VERIFICATION | MemberFlags::DID_REJOIN;
+
+    Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author: User {
+            accent_color: None,
+            avatar: Some(avatar),
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: Some("test".to_owned()),
+            id: Id::new(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            primary_guild: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        },
+        call: None,
+        channel_id: Id::new(2),
+        components: Vec::new(),
+        content: "ping".to_owned(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: Some(MessageFlags::empty()),
+        guild_id: Some(Id::new(1)),
+        id: Id::new(4),
+        interaction: None,
+        interaction_metadata: None,
+        kind: MessageType::Regular,
+        member: Some(PartialMember {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            flags,
+            joined_at,
+            mute: false,
+            nick: Some("member nick".to_owned()),
+            permissions: None,
+            premium_since: None,
+            roles: Vec::new(),
+            user: None,
+        }),
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        message_snapshots: Vec::new(),
+        pinned: false,
+        poll: None,
+        reactions: Vec::new(),
+        reference: None,
+        referenced_message: None,
+        role_subscription_data: None,
+        sticker_items: Vec::new(),
+        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+        thread: None,
+        tts: false,
+        webhook_id: None,
+    }
+}
+
 pub fn current_user(id: u64) -> CurrentUser {
     CurrentUser {
         accent_color: Some(0xFF_00_00),
         avatar: None,
+        avatar_decoration_data: None,
         banner: None,
         bot: true,
         discriminator: 9876,
@@ -226,6 +276,7 @@ pub fn current_user(id: u64) -> CurrentUser {
         name: "test".to_owned(),
         verified: Some(true),
         premium_type: None,
+        primary_guild: None,
         public_flags: None,
         flags: None,
         locale: None,
@@ -311,6 +362,7 @@ pub fn member(id: Id<UserMarker>) -> Member {
 pub fn role(id: Id<RoleMarker>) -> Role {
     Role {
         color: 0,
+        colors: None,
         hoist: false,
         icon: None,
         id,
@@ -383,14 +435,15 @@ pub fn user(id: Id<UserMarker>) -> User {
         mfa_enabled: None,
         name: "user".to_owned(),
         premium_type: None,
+        primary_guild: None,
         public_flags: None,
         system: None,
         verified: None,
     }
 }
 
-pub fn guild(id: Id<GuildMarker>, member_count: Option<u64>) -> Guild {
-    Guild {
+pub fn guild(id: Id<GuildMarker>, member_count: Option<u64>) -> GatewayGuild {
+    GatewayGuild {
         afk_channel_id: None,
         afk_timeout: AfkTimeout::FIFTEEN_MINUTES,
         application_id: None,
@@ -463,6 +516,7 @@ pub fn guild_scheduled_event(
         image: None,
         name: "test".to_owned(),
         privacy_level: PrivacyLevel::GuildOnly,
+        recurrence_rule: None,
         scheduled_end_time: None,
         scheduled_start_time: Timestamp::from_secs(789).unwrap(),
         status: Status::Completed,
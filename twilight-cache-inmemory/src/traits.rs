@@ -35,15 +35,17 @@ use twilight_model::{
         presence::Presence,
     },
     guild::{
-        scheduled_event::GuildScheduledEvent, Emoji, Guild, GuildIntegration, Member,
+        scheduled_event::GuildScheduledEvent, Emoji, GatewayGuild, GuildIntegration, Member,
         PartialMember, Role,
     },
     id::{
         marker::{
-            ChannelMarker, GuildMarker, RoleMarker, ScheduledEventMarker, StickerMarker, UserMarker,
+            ChannelMarker, GuildMarker, MessageMarker, RoleMarker, ScheduledEventMarker,
+            StickerMarker, UserMarker,
         },
         Id,
     },
+    poll::Poll,
     user::{CurrentUser, User},
     util::{ImageHash, Timestamp},
     voice::VoiceState,
@@ -59,7 +61,7 @@ pub trait CacheableModels: Clone + Debug {
     type CurrentUser: CacheableCurrentUser;
     /// The cached [`Emoji`] model representation.
     type Emoji: CacheableEmoji;
-    /// The cached [`Guild`] model representation.
+    /// The cached [`GatewayGuild`] model representation.
     type Guild: CacheableGuild;
     /// The cached [`GuildIntegration`] model representation.
     type GuildIntegration: CacheableGuildIntegration;
@@ -113,6 +115,21 @@ pub trait CacheableMember:
 
     /// Update the cached data with a [`MemberUpdate`] event.
     fn update_with_member_update(&mut self, member_update: &MemberUpdate);
+
+    /// Update the cached data with partial member data, such as from a
+    /// [`MessageCreate`] or [`InteractionCreate`] event.
+    ///
+    /// Only fields that partial member data is authoritative for, such as the
+    /// member's roles and nickname, should be updated.
+    ///
+    /// [`InteractionCreate`]: twilight_model::gateway::payload::incoming::InteractionCreate
+    /// [`MessageCreate`]: twilight_model::gateway::payload::incoming::MessageCreate
+    fn update_with_partial_member(&mut self, member: &PartialMember);
+
+    /// Remove a role from this member, such as when the role is deleted.
+    ///
+    /// By default no role is removed.
+    fn remove_role(&mut self, _role_id: Id<RoleMarker>) {}
 }
 
 /// Trait for a generic cached representation of a [`Role`].
@@ -166,6 +183,21 @@ pub trait CacheableChannel:
 
     /// Set the last pin timestamp to a new timestamp.
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>);
+
+    /// Record that a message was sent in the channel.
+    ///
+    /// Implementations should update the ID of the last message and, for
+    /// threads, the number of messages in the thread.
+    ///
+    /// By default nothing is recorded.
+    fn record_message(&mut self, _message_id: Id<MessageMarker>) {}
+
+    /// Update the channel with the fields present in a partial channel, such
+    /// as the one included in an interaction.
+    ///
+    /// Implementations should retain the values of fields the partial channel
+    /// lacks rather than clearing them.
+    fn merge_partial(&mut self, partial: Channel);
 }
 
 impl CacheableChannel for Channel {
@@ -194,10 +226,81 @@ impl CacheableChannel for Channel {
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>) {
         self.last_pin_timestamp = timestamp;
     }
+
+    fn record_message(&mut self, message_id: Id<MessageMarker>) {
+        // Messages may be received out of order, so don't move the last
+        // message ID backwards.
+        if self
+            .last_message_id
+            .is_some_and(|last_message_id| last_message_id.get() >= message_id.get())
+        {
+            return;
+        }
+
+        self.last_message_id = Some(message_id.cast());
+
+        if self.kind.is_thread() {
+            if let Some(message_count) = self.message_count.as_mut() {
+                *message_count = message_count.saturating_add(1);
+            }
+        }
+    }
+
+    fn merge_partial(&mut self, partial: Channel) {
+        macro_rules! merge {
+            ($($field:ident),+ $(,)?) => {
+                $(
+                    if partial.$field.is_some() {
+                        self.$field = partial.$field;
+                    }
+                )+
+            };
+        }
+
+        self.kind = partial.kind;
+
+        merge!(
+            application_id,
+            applied_tags,
+            available_tags,
+            bitrate,
+            default_auto_archive_duration,
+            default_forum_layout,
+            default_reaction_emoji,
+            default_sort_order,
+            default_thread_rate_limit_per_user,
+            flags,
+            guild_id,
+            icon,
+            invitable,
+            last_message_id,
+            last_pin_timestamp,
+            managed,
+            member,
+            member_count,
+            message_count,
+            name,
+            newly_created,
+            nsfw,
+            owner_id,
+            parent_id,
+            permission_overwrites,
+            position,
+            rate_limit_per_user,
+            recipients,
+            rtc_region,
+            thread_metadata,
+            topic,
+            user_limit,
+            video_quality_mode,
+        );
+    }
 }
 
-/// Trait for a generic cached representation of a [`Guild`].
-pub trait CacheableGuild: From<Guild> + PartialEq<Guild> + PartialEq<Self> + Clone + Debug {
+/// Trait for a generic cached representation of a [`GatewayGuild`].
+pub trait CacheableGuild:
+    From<GatewayGuild> + PartialEq<GatewayGuild> + PartialEq<Self> + Clone + Debug
+{
     /// ID of the guild.
     fn id(&self) -> Id<GuildMarker>;
 
@@ -212,6 +315,16 @@ pub trait CacheableGuild: From<Guild> + PartialEq<Guild> + PartialEq<Self> + Clo
     /// cached structures such as channels are cleared prior.
     fn update_with_guild_update(&mut self, guild_update: &GuildUpdate);
 
+    /// Number of members in the guild.
+    ///
+    /// By default the member count isn't known, so guilds are only ordered by
+    /// their ID in [`InMemoryCache::mutual_guilds`].
+    ///
+    /// [`InMemoryCache::mutual_guilds`]: crate::InMemoryCache::mutual_guilds
+    fn member_count(&self) -> Option<u64> {
+        None
+    }
+
     /// Increase the guild member count.
     fn increase_member_count(&mut self, amount: u64);
 
@@ -235,6 +348,24 @@ pub trait CacheableVoiceState:
 pub trait CacheableMessage:
     From<Message> + PartialEq<Message> + PartialEq<Self> + Clone + Debug
 {
+    /// ID of the message author.
+    fn author(&self) -> Id<UserMarker>;
+
+    /// ID of the guild the message was sent in.
+    ///
+    /// By default the guild isn't known, so messages in channels that aren't
+    /// cached are kept when their guild is deleted.
+    fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        None
+    }
+
+    /// IDs of the users mentioned in this message.
+    ///
+    /// By default no mentions are known.
+    fn mentions(&self) -> &[Id<UserMarker>] {
+        &[]
+    }
+
     /// Reactions added to this message.
     fn reactions(&self) -> &[Reaction];
 
@@ -252,6 +383,23 @@ pub trait CacheableMessage:
 
     /// Remove a reaction from this message.
     fn remove_reaction(&mut self, idx: usize);
+
+    /// Mutable getter for the poll attached to this message.
+    ///
+    /// By default no poll is stored, so poll votes aren't tracked.
+    fn poll_mut(&mut self) -> Option<&mut Poll> {
+        None
+    }
+
+    /// Record the previous version of this message, which this message
+    /// replaced due to an edit.
+    ///
+    /// Implementations retaining an edit history should carry over the
+    /// history of `previous` and retain at most `limit` versions, evicting
+    /// the oldest first.
+    ///
+    /// By default no edit history is retained.
+    fn record_edit(&mut self, _previous: Self, _limit: usize) {}
 }
 
 /// Trait for a generic cached representation of a [`CurrentUser`].
@@ -302,9 +450,30 @@ pub trait CacheableStageInstance:
 impl CacheableStageInstance for StageInstance {}
 
 /// Trait for a generic cached representation of a [`User`].
-pub trait CacheableUser: From<User> + PartialEq<User> + PartialEq<Self> + Clone + Debug {}
+pub trait CacheableUser: From<User> + PartialEq<User> + PartialEq<Self> + Clone + Debug {
+    /// Fill in the fields of a user, such as a message's author, that its
+    /// payload may omit with their cached values.
+    ///
+    /// By default no fields are filled in.
+    fn fill_partial(&self, _user: &mut User) {}
+}
+
+impl CacheableUser for User {
+    fn fill_partial(&self, user: &mut User) {
+        if user.avatar_decoration.is_none() {
+            user.avatar_decoration = self.avatar_decoration;
+        }
+
+        if user.avatar_decoration_data.is_none() {
+            user.avatar_decoration_data
+                .clone_from(&self.avatar_decoration_data);
+        }
 
-impl CacheableUser for User {}
+        if user.primary_guild.is_none() {
+            user.primary_guild.clone_from(&self.primary_guild);
+        }
+    }
+}
 
 /// Trait for a generic cached representation of a [`GuildScheduledEvent`].
 pub trait CacheableGuildScheduledEvent:
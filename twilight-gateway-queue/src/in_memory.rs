@@ -1,7 +1,15 @@
 //! Memory based [`Queue`] implementation and supporting items.
 
 use super::{Queue, IDENTIFY_DELAY, LIMIT_PERIOD};
-use std::{collections::VecDeque, fmt::Debug, iter};
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    iter,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
 use tokio::{
     sync::{mpsc, oneshot},
     task::yield_now,
@@ -20,6 +28,10 @@ enum Message {
     },
     /// Update the runner's settings.
     Update(Settings),
+    /// Update the number of daily permits to hold back.
+    SafetyMargin(u32),
+    /// Ignore the safety margin until the daily permits reset.
+    OverrideSafety,
 }
 
 /// [`runner`]'s settings.
@@ -41,6 +53,7 @@ struct Settings {
 /// Buckets requests such that only one timer is necessary.
 async fn runner(
     mut rx: mpsc::UnboundedReceiver<Message>,
+    shared_remaining: Arc<AtomicU32>,
     Settings {
         max_concurrency,
         mut remaining,
@@ -58,12 +71,20 @@ async fn runner(
         .take(max_concurrency.into())
         .collect::<Box<_>>();
 
+    let mut safety_margin = 0;
+    let mut overridden = false;
+
     #[allow(clippy::ignored_unit_patterns)]
     loop {
+        // Permits at or below this floor are held back.
+        let floor = if overridden { 0 } else { safety_margin };
+
         tokio::select! {
             biased;
             _ = &mut reset_at, if remaining != total => {
                 remaining = total;
+                overridden = false;
+                shared_remaining.store(remaining, Ordering::Relaxed);
             }
             message = rx.recv() => {
                 match message {
@@ -71,6 +92,12 @@ async fn runner(
                         if queues.is_empty() {
                             _ = tx.send(());
                         } else {
+                            if remaining <= floor {
+                                let refill_delay =
+                                    reset_at.deadline().saturating_duration_since(Instant::now());
+                                log_parked(refill_delay, remaining, floor, shard);
+                            }
+
                             let key = shard as usize % queues.len();
                             queues[key].push_back((shard, tx));
                         }
@@ -83,6 +110,7 @@ async fn runner(
                             reset_after,
                             total,
                         } = update;
+                        shared_remaining.store(remaining, Ordering::Relaxed);
 
                         if remaining != total {
                             reset_at.as_mut().reset(Instant::now() + reset_after);
@@ -99,10 +127,12 @@ async fn runner(
                             }
                         }
                     }
+                    Some(Message::SafetyMargin(margin)) => safety_margin = margin,
+                    Some(Message::OverrideSafety) => overridden = true,
                     None => break,
                 }
             }
-            _ = &mut interval, if queues.iter().any(|queue| !queue.is_empty()) => {
+            _ = &mut interval, if remaining > floor && queues.iter().any(|queue| !queue.is_empty()) => {
                 let now = Instant::now();
                 let span = tracing::info_span!("bucket", moment = ?now);
 
@@ -113,13 +143,13 @@ async fn runner(
                 }
 
                 for (key, queue) in queues.iter_mut().enumerate() {
-                    if remaining == 0 {
+                    if remaining <= floor {
                         tracing::debug!(
+                            parent: &span,
                             refill_delay = ?reset_at.deadline().saturating_duration_since(now),
-                            "exhausted available permits"
+                            remaining,
+                            "parking remaining requests until permits refill"
                         );
-                        (&mut reset_at).await;
-                        remaining = total;
 
                         break;
                     }
@@ -131,6 +161,7 @@ async fn runner(
 
                         tracing::debug!(parent: &span, key, shard);
                         remaining -= 1;
+                        shared_remaining.store(remaining, Ordering::Relaxed);
                         // Reschedule behind shard for ordering correctness.
                         yield_now().await;
 
@@ -142,6 +173,21 @@ async fn runner(
     }
 }
 
+/// Log that `shard`'s request is parked until permits are available.
+fn log_parked(refill_delay: Duration, remaining: u32, safety_margin: u32, shard: u32) {
+    if safety_margin == 0 {
+        tracing::debug!(?refill_delay, "exhausted available permits");
+    } else {
+        tracing::warn!(
+            ?refill_delay,
+            remaining,
+            safety_margin,
+            shard,
+            "parking request: remaining permits within safety margin"
+        );
+    }
+}
+
 /// Memory based [`Queue`] implementation backed by an efficient background task.
 ///
 /// [`InMemoryQueue::update`] allows for dynamically changing the queue's
@@ -149,10 +195,21 @@ async fn runner(
 ///
 /// Cloning the queue is cheap and just increments a reference counter.
 ///
+/// Requests are parked once the remaining daily permits reach the configured
+/// [safety margin], protecting the token from being reset by Discord for
+/// exceeding its daily identify limit. Parked requests are released once the
+/// permits refill or an operator calls [`InMemoryQueue::override_safety`].
+///
 /// **Note:** A `max_concurrency` of `0` processes all requests instantly,
 /// effectively disabling the queue.
+///
+/// [safety margin]: InMemoryQueue::set_safety_margin
 #[derive(Clone, Debug)]
 pub struct InMemoryQueue {
+    /// Remaining daily permits, as last seen by the background [task runner].
+    ///
+    /// [task runner]: runner
+    remaining: Arc<AtomicU32>,
     /// Sender to communicate with the background [task runner].
     ///
     /// [task runner]: runner
@@ -168,9 +225,11 @@ impl InMemoryQueue {
     pub fn new(max_concurrency: u16, remaining: u32, reset_after: Duration, total: u32) -> Self {
         assert!(total >= remaining);
         let (tx, rx) = mpsc::unbounded_channel();
+        let shared_remaining = Arc::new(AtomicU32::new(remaining));
 
         tokio::spawn(runner(
             rx,
+            Arc::clone(&shared_remaining),
             Settings {
                 max_concurrency,
                 remaining,
@@ -179,7 +238,53 @@ impl InMemoryQueue {
             },
         ));
 
-        Self { tx }
+        Self {
+            remaining: shared_remaining,
+            tx,
+        }
+    }
+
+    /// Number of remaining daily permits.
+    ///
+    /// This is updated by the background task and may briefly lag behind
+    /// requests that were just enqueued.
+    pub fn remaining(&self) -> u32 {
+        self.remaining.load(Ordering::Relaxed)
+    }
+
+    /// Set the number of daily permits to hold back.
+    ///
+    /// Once the remaining permits are at or below the margin requests are
+    /// parked, and a warning is emitted, until the permits refill after
+    /// [`LIMIT_PERIOD`] or [`override_safety`] is called.
+    ///
+    /// Defaults to `0`, parking requests only once the permits are exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the background task runner has stopped, such as when the
+    /// runtime shut down.
+    ///
+    /// [`override_safety`]: Self::override_safety
+    pub fn set_safety_margin(&self, safety_margin: u32) {
+        self.tx
+            .send(Message::SafetyMargin(safety_margin))
+            .expect("receiver dropped after sender");
+    }
+
+    /// Release parked requests, ignoring the safety margin until the daily
+    /// permits refill.
+    ///
+    /// Requests are still parked once the permits are exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the background task runner has stopped, such as when the
+    /// runtime shut down.
+    pub fn override_safety(&self) {
+        self.tx
+            .send(Message::OverrideSafety)
+            .expect("receiver dropped after sender");
     }
 
     /// Update the queue with new info from the [Get Gateway Bot] endpoint.
@@ -1,8 +1,8 @@
 mod common;
 
 use common::*;
-use tokio::time::{Duration, Instant};
-use twilight_gateway_queue::{InMemoryQueue, Queue, IDENTIFY_DELAY};
+use tokio::time::{timeout, Duration, Instant};
+use twilight_gateway_queue::{InMemoryQueue, Queue, IDENTIFY_DELAY, LIMIT_PERIOD};
 
 #[tokio::test]
 async fn disabled_is_instant() {
@@ -34,6 +34,53 @@ async fn update_fills_bucket() {
     assert!(now.elapsed() < IDENTIFY_DELAY, "ran serially");
 }
 
+#[tokio::test(start_paused = true)]
+async fn safety_margin_parks() {
+    let queue = InMemoryQueue::new(1, 3, LIMIT_PERIOD, 10);
+    queue.set_safety_margin(1);
+    let now = Instant::now();
+
+    _ = queue.enqueue(0).await;
+    _ = queue.enqueue(0).await;
+    assert_eq!(queue.remaining(), 1);
+
+    let mut t3 = queue.enqueue(0);
+    assert!(
+        timeout(IDENTIFY_DELAY * 2, &mut t3).await.is_err(),
+        "did not park within safety margin"
+    );
+    assert_eq!(queue.remaining(), 1);
+
+    _ = t3.await;
+
+    assert!(now.elapsed() >= LIMIT_PERIOD, "released before refill");
+    assert_eq!(queue.remaining(), 9);
+}
+
+#[tokio::test(start_paused = true)]
+async fn override_safety_releases() {
+    let queue = InMemoryQueue::new(1, 2, LIMIT_PERIOD, 10);
+    queue.set_safety_margin(1);
+
+    _ = queue.enqueue(0).await;
+
+    let mut t2 = queue.enqueue(0);
+    assert!(
+        timeout(IDENTIFY_DELAY * 2, &mut t2).await.is_err(),
+        "did not park within safety margin"
+    );
+
+    let now = Instant::now();
+    queue.override_safety();
+    _ = t2.await;
+
+    assert!(
+        now.elapsed() < IDENTIFY_DELAY,
+        "did not release on override"
+    );
+    assert_eq!(queue.remaining(), 0);
+}
+
 #[tokio::test(start_paused = true)]
 async fn integration() {
     let mut queue = InMemoryQueue::new(1, 1000, Duration::ZERO, 1000);
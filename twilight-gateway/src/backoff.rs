@@ -0,0 +1,200 @@
+//! Delays before connecting to the gateway, increasing with each failed
+//! attempt.
+
+use std::time::Duration;
+
+/// Policy of how long a [`Shard`] waits before connecting to the gateway.
+///
+/// The shard waits before every connection attempt, including its first one,
+/// reconnects after close frames, and retries after network errors. The delay
+/// starts at the [base] and is multiplied by the [multiplier] for every
+/// consecutive failed attempt, up to the [maximum]. A random fraction of at
+/// most the [jitter] is subtracted from each delay so that many shards
+/// disconnected at once, such as during a Discord incident, don't reconnect
+/// in lockstep.
+///
+/// The default policy starts at 1 second, doubles with every attempt, is
+/// capped at 255 seconds, and has no jitter.
+///
+/// # Examples
+///
+/// Back off slower with a lower cap and jitter the delays:
+///
+/// ```no_run
+/// use std::{env, time::Duration};
+/// use twilight_gateway::{BackoffPolicy, ConfigBuilder, Intents};
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let policy =
+///     BackoffPolicy::new(Duration::from_secs(2), 1.5, Duration::from_secs(120)).jitter(0.5);
+///
+/// let config = ConfigBuilder::new(token, Intents::empty())
+///     .reconnect_backoff(policy)
+///     .build();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`Shard`]: crate::Shard
+/// [base]: Self::base
+/// [jitter]: Self::jitter_fraction
+/// [maximum]: Self::max
+/// [multiplier]: Self::multiplier
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackoffPolicy {
+    /// Delay before the first attempt.
+    base: Duration,
+    /// Maximum fraction of each delay that is randomly subtracted from it.
+    jitter: f64,
+    /// Maximum delay.
+    max: Duration,
+    /// Factor each delay is multiplied by after a failed attempt.
+    multiplier: f64,
+}
+
+impl BackoffPolicy {
+    /// Policy without any delay, mostly useful in tests.
+    pub const ZERO: Self = Self::new(Duration::ZERO, 1.0, Duration::ZERO);
+
+    /// Create a new policy without jitter.
+    ///
+    /// Refer to the type's documentation for how the delays are calculated.
+    pub const fn new(base: Duration, multiplier: f64, max: Duration) -> Self {
+        Self {
+            base,
+            jitter: 0.0,
+            max,
+            multiplier,
+        }
+    }
+
+    /// Set the maximum fraction of each delay that is randomly subtracted
+    /// from it.
+    ///
+    /// Default value is `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fraction isn't between `0.0` and `1.0`.
+    #[must_use = "setting the jitter has no effect if left unused"]
+    #[track_caller]
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&jitter),
+            "jitter must be between 0 and 1"
+        );
+
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Delay before the first attempt.
+    pub const fn base(&self) -> Duration {
+        self.base
+    }
+
+    /// Maximum fraction of each delay that is randomly subtracted from it.
+    pub const fn jitter_fraction(&self) -> f64 {
+        self.jitter
+    }
+
+    /// Maximum delay.
+    pub const fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Factor each delay is multiplied by after a failed attempt.
+    pub const fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// Delay before an attempt, given the number of preceding consecutive
+    /// failed attempts.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let secs = self.base.as_secs_f64() * self.multiplier.powi(exponent);
+        let delay = Duration::try_from_secs_f64(secs)
+            .unwrap_or(self.max)
+            .min(self.max);
+
+        if self.jitter > 0.0 {
+            delay.mul_f64(1.0 - self.jitter * fastrand::f64())
+        } else {
+            delay
+        }
+    }
+
+    /// Iterator over the delays before each consecutive attempt.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let policy = *self;
+
+        (0..).map(move |attempt| policy.delay(attempt))
+    }
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), 2.0, Duration::from_secs(255))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BackoffPolicy;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(BackoffPolicy: Clone, Copy, Debug, Default, PartialEq, Send, Sync);
+
+    /// Test that the default policy doubles from 1 second and is capped at
+    /// 255 seconds.
+    #[test]
+    fn default_delays() {
+        let delays = BackoffPolicy::default()
+            .delays()
+            .take(10)
+            .map(|delay| delay.as_secs())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 4, 8, 16, 32, 64, 128, 255, 255], delays);
+        assert_eq!(
+            Duration::from_secs(255),
+            BackoffPolicy::default().delay(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn delays() {
+        let policy =
+            BackoffPolicy::new(Duration::from_millis(100), 2.0, Duration::from_millis(500));
+        let delays = policy
+            .delays()
+            .take(5)
+            .map(|delay| delay.as_millis())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![100, 200, 400, 500, 500], delays);
+        assert!(BackoffPolicy::ZERO
+            .delays()
+            .take(10)
+            .all(|delay| delay.is_zero()));
+    }
+
+    #[test]
+    fn jitter() {
+        let policy = BackoffPolicy::default().jitter(0.5);
+
+        for (attempt, delay) in policy.delays().take(10).enumerate() {
+            let max = BackoffPolicy::default().delay(attempt.try_into().unwrap());
+
+            assert!(delay <= max);
+            assert!(delay >= max / 2);
+        }
+    }
+
+    #[should_panic(expected = "jitter must be between 0 and 1")]
+    #[test]
+    fn jitter_invalid() {
+        _ = BackoffPolicy::default().jitter(1.5);
+    }
+}
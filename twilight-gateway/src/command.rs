@@ -33,14 +33,16 @@ mod private {
 /// This is deliberately not implemented for [`Heartbeat`], [`Identify`], and
 /// [`Resume`] due to shards automatically sending them as necessary.
 ///
-/// To send an arbitrary command to the Discord Gateway API then [`Shard::send`]
-/// may be used.
+/// To send an arbitrary command to the Discord Gateway API then
+/// [`Shard::send_raw`] may be used, which rejects these opcodes, or
+/// [`Shard::send`], which does not validate the command at all.
 ///
 /// [`Heartbeat`]: twilight_model::gateway::payload::outgoing::Heartbeat
 /// [`Identify`]: twilight_model::gateway::payload::outgoing::Identify
 /// [`Resume`]: twilight_model::gateway::payload::outgoing::Resume
 /// [`Shard::command`]: crate::Shard::command
 /// [`Shard::send`]: crate::Shard::send
+/// [`Shard::send_raw`]: crate::Shard::send_raw
 pub trait Command: private::Sealed {}
 
 impl Command for RequestGuildMembers {}
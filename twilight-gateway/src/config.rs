@@ -1,10 +1,19 @@
 //! User configuration for shards.
 
-use crate::{queue::InMemoryQueue, Session};
+use crate::{queue::InMemoryQueue, BackoffPolicy, Session, ShardId};
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     sync::Arc,
 };
+#[cfg(any(
+    feature = "rustls-native-roots",
+    feature = "rustls-platform-verifier",
+    feature = "rustls-webpki-roots"
+))]
+use tokio_rustls::{
+    rustls::{client::Resumption, ClientConfig},
+    TlsConnector,
+};
 use tokio_websockets::Connector;
 use twilight_model::gateway::{
     payload::outgoing::{identify::IdentifyProperties, update_presence::UpdatePresencePayload},
@@ -32,12 +41,29 @@ impl Debug for Token {
     }
 }
 
+/// Function returning the presence of a shard to set when identifying.
+#[derive(Clone)]
+struct PresenceFn(Arc<dyn Fn(ShardId) -> UpdatePresencePayload + Send + Sync>);
+
+impl Debug for PresenceFn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("PresenceFn").finish_non_exhaustive()
+    }
+}
+
 /// Configuration used by the shard to identify with the gateway and operate.
 ///
 /// May be reused by cloning, also reusing the hidden TLS context---reducing
 /// memory usage. The TLS context may still be reused with an otherwise
 /// different config by turning it into to a [`ConfigBuilder`] through the
 /// [`From<Config>`] implementation and then rebuilding it into a rew config.
+///
+/// When using rustls, sharing the TLS context also shares its session cache,
+/// allowing shards to resume TLS sessions established by one another when
+/// (re)connecting, which skips part of the TLS handshake. See
+/// [`ConnectTimings::tls`] for how long the handshake took.
+///
+/// [`ConnectTimings::tls`]: crate::ConnectTimings::tls
 #[derive(Clone, Debug)]
 pub struct Config<Q = InMemoryQueue> {
     /// Identification properties the shard will use.
@@ -47,8 +73,16 @@ pub struct Config<Q = InMemoryQueue> {
     /// When the gateway will stop sending a guild's member list in
     /// Guild Create events.
     large_threshold: u64,
+    /// Number of consecutive heartbeat intervals without a received event
+    /// after which the connection is considered failed or "zombied".
+    missed_heartbeats_threshold: u8,
     /// Presence to set when identifying with the gateway.
     presence: Option<UpdatePresencePayload>,
+    /// Function returning the presence to set when identifying with the
+    /// gateway, taking precedence over [`presence`].
+    ///
+    /// [`presence`]: Self::presence
+    presence_fn: Option<PresenceFn>,
     /// Gateway proxy URL.
     proxy_url: Option<Box<str>>,
     /// Queue in use by the shard.
@@ -57,11 +91,14 @@ pub struct Config<Q = InMemoryQueue> {
     ///
     /// [outgoing message]: crate::Shard::send
     ratelimit_messages: bool,
+    /// Policy of how long to wait before connecting to the gateway.
+    reconnect_backoff: BackoffPolicy,
     /// URL to connect to if the shard resumes on initialization.
     resume_url: Option<Box<str>>,
     /// Session information to resume a shard on initialization.
     session: Option<Session>,
-    /// TLS connector for Websocket connections.
+    /// TLS connector for Websocket connections, including its TLS session
+    /// cache.
     // We need this to be public so [`stream`] can reuse TLS on multiple shards
     // if unconfigured.
     pub(crate) tls: Arc<Connector>,
@@ -100,6 +137,12 @@ impl<Q> Config<Q> {
         self.large_threshold
     }
 
+    /// Number of consecutive heartbeat intervals without a received event
+    /// after which the connection is considered failed or "zombied".
+    pub const fn missed_heartbeats_threshold(&self) -> u8 {
+        self.missed_heartbeats_threshold
+    }
+
     /// Immutable reference to the presence to set when identifying
     /// with the gateway.
     ///
@@ -109,6 +152,24 @@ impl<Q> Config<Q> {
         self.presence.as_ref()
     }
 
+    /// Immutable reference to the function returning the presence of a shard
+    /// to set when identifying with the gateway.
+    ///
+    /// Takes precedence over the static [`presence`].
+    ///
+    /// [`presence`]: Self::presence
+    pub fn presence_fn(&self) -> Option<&(dyn Fn(ShardId) -> UpdatePresencePayload + Send + Sync)> {
+        self.presence_fn.as_ref().map(|presence_fn| &*presence_fn.0)
+    }
+
+    /// Presence of a shard to set when identifying with the gateway.
+    pub(crate) fn identify_presence(&self, shard_id: ShardId) -> Option<UpdatePresencePayload> {
+        self.presence_fn
+            .as_ref()
+            .map(|presence_fn| (presence_fn.0)(shard_id))
+            .or_else(|| self.presence.clone())
+    }
+
     /// Immutable reference to the gateway proxy URL.
     pub fn proxy_url(&self) -> Option<&str> {
         self.proxy_url.as_deref()
@@ -126,6 +187,11 @@ impl<Q> Config<Q> {
         self.ratelimit_messages
     }
 
+    /// Policy of how long to wait before connecting to the gateway.
+    pub const fn reconnect_backoff(&self) -> BackoffPolicy {
+        self.reconnect_backoff
+    }
+
     /// Immutable reference to the token used to authenticate when identifying
     /// with the gateway.
     pub const fn token(&self) -> &str {
@@ -169,19 +235,56 @@ impl ConfigBuilder {
                 identify_properties: None,
                 intents,
                 large_threshold: 50,
+                missed_heartbeats_threshold: 1,
                 presence: None,
+                presence_fn: None,
                 proxy_url: None,
                 queue: InMemoryQueue::default(),
                 ratelimit_messages: true,
+                reconnect_backoff: BackoffPolicy::default(),
                 resume_url: None,
                 session: None,
-                tls: Arc::new(Connector::new().unwrap()),
+                tls: Arc::new(tls_connector()),
                 token: Token::new(token.into_boxed_str()),
             },
         }
     }
 }
 
+/// Number of TLS sessions cached per TLS context.
+///
+/// Every shard connects to one of a few gateway hosts, so the sessions of
+/// many shards fit in a small cache.
+#[cfg(any(
+    feature = "rustls-native-roots",
+    feature = "rustls-platform-verifier",
+    feature = "rustls-webpki-roots"
+))]
+const TLS_SESSIONS: usize = 32;
+
+/// Create the TLS connector shared by shards created from the same config.
+///
+/// Rustls connectors are given an in-memory session cache so that a
+/// reconnecting shard may resume a TLS session instead of performing a full
+/// handshake. native-tls doesn't support client session caches.
+fn tls_connector() -> Connector {
+    let connector = Connector::new().unwrap();
+
+    #[cfg(any(
+        feature = "rustls-native-roots",
+        feature = "rustls-platform-verifier",
+        feature = "rustls-webpki-roots"
+    ))]
+    if let Connector::Rustls(connector) = &connector {
+        let mut config = ClientConfig::clone(connector.config());
+        config.resumption = Resumption::in_memory_sessions(TLS_SESSIONS);
+
+        return Connector::Rustls(TlsConnector::from(Arc::new(config)));
+    }
+
+    connector
+}
+
 impl<Q> ConfigBuilder<Q> {
     /// Consume the builder, constructing a shard.
     #[allow(clippy::missing_const_for_fn)]
@@ -251,6 +354,35 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the number of consecutive missed heartbeats after which the
+    /// connection is considered failed or "zombied" and is reconnected.
+    ///
+    /// A heartbeat is missed when no event, heartbeat ACK or otherwise, is
+    /// received within a heartbeat interval after sending one. Raising the
+    /// threshold tolerates brief network stalls at the cost of detecting dead
+    /// connections later.
+    ///
+    /// Default value is `1`, reconnecting after the first missed heartbeat.
+    ///
+    /// Zombied reconnects are counted by [`Shard::zombied_reconnects`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided value is `0`.
+    ///
+    /// [`Shard::zombied_reconnects`]: crate::Shard::zombied_reconnects
+    #[track_caller]
+    pub const fn missed_heartbeats_threshold(mut self, missed_heartbeats_threshold: u8) -> Self {
+        assert!(
+            missed_heartbeats_threshold > 0,
+            "missed heartbeats threshold must be at least 1"
+        );
+
+        self.inner.missed_heartbeats_threshold = missed_heartbeats_threshold;
+
+        self
+    }
+
     /// Set the presence to use automatically when starting a new session.
     ///
     /// The active presence of a session is maintained across re-connections
@@ -302,6 +434,60 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set a function returning the presence of a shard to use when starting a
+    /// new session, taking precedence over the static [`presence`].
+    ///
+    /// The function is called each time the shard identifies, allowing the
+    /// presence to include the shard's ID or to change across sessions.
+    ///
+    /// Default is no function.
+    ///
+    /// # Examples
+    ///
+    /// Include the shard's ID in its presence:
+    ///
+    /// ```no_run
+    /// use std::{env, sync::Arc};
+    /// use twilight_gateway::{ConfigBuilder, Intents, Shard, ShardId};
+    /// use twilight_model::gateway::{
+    ///     payload::outgoing::update_presence::UpdatePresencePayload,
+    ///     presence::{ActivityType, MinimalActivity, Status},
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ConfigBuilder::new(env::var("DISCORD_TOKEN")?, Intents::empty())
+    ///     .presence_fn(Arc::new(|shard_id: ShardId| {
+    ///         UpdatePresencePayload::new(
+    ///             vec![MinimalActivity {
+    ///                 kind: ActivityType::Playing,
+    ///                 name: format!("shard {shard_id} | !help"),
+    ///                 url: None,
+    ///             }
+    ///             .into()],
+    ///             false,
+    ///             None,
+    ///             Status::Online,
+    ///         )
+    ///         .expect("activities are not empty")
+    ///     }))
+    ///     .build();
+    ///
+    /// let shard = Shard::with_config(ShardId::ONE, config);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`presence`]: Self::presence
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn presence_fn(
+        mut self,
+        presence_fn: Arc<dyn Fn(ShardId) -> UpdatePresencePayload + Send + Sync>,
+    ) -> Self {
+        self.inner.presence_fn = Some(PresenceFn(presence_fn));
+
+        self
+    }
+
     /// Set the proxy URL for connecting to the gateway.
     ///
     /// Resumes are always done to the URL specified in [`resume_gateway_url`].
@@ -325,10 +511,13 @@ impl<Q> ConfigBuilder<Q> {
             identify_properties,
             intents,
             large_threshold,
+            missed_heartbeats_threshold,
             presence,
+            presence_fn,
             proxy_url,
             queue: _,
             ratelimit_messages,
+            reconnect_backoff,
             resume_url,
             session,
             tls,
@@ -340,10 +529,13 @@ impl<Q> ConfigBuilder<Q> {
                 identify_properties,
                 intents,
                 large_threshold,
+                missed_heartbeats_threshold,
                 presence,
+                presence_fn,
                 proxy_url,
                 queue,
                 ratelimit_messages,
+                reconnect_backoff,
                 resume_url,
                 session,
                 tls,
@@ -364,6 +556,19 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the policy of how long to wait before connecting to the gateway.
+    ///
+    /// The policy applies to every connection attempt, whether it follows a
+    /// close frame or a network error. Refer to [`BackoffPolicy`] for how the
+    /// delays are calculated.
+    ///
+    /// Defaults to [`BackoffPolicy::default`].
+    pub const fn reconnect_backoff(mut self, reconnect_backoff: BackoffPolicy) -> Self {
+        self.inner.reconnect_backoff = reconnect_backoff;
+
+        self
+    }
+
     /// Set the resume URL to use when the initial shard connection resumes an old session.
     ///
     /// This is only used if the initial shard connection resumes instead of identifying and only affects the first session.
@@ -401,6 +606,7 @@ impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
 #[cfg(test)]
 mod tests {
     use super::{Config, ConfigBuilder};
+    use crate::BackoffPolicy;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
     use twilight_model::gateway::Intents;
@@ -412,6 +618,47 @@ mod tests {
         ConfigBuilder::new("test".to_owned(), Intents::empty())
     }
 
+    /// Test that shards created from the same config, including rebuilt ones,
+    /// share the TLS session cache.
+    #[cfg(any(
+        feature = "rustls-native-roots",
+        feature = "rustls-platform-verifier",
+        feature = "rustls-webpki-roots"
+    ))]
+    #[tokio::test]
+    async fn tls_session_cache() {
+        use crate::{Shard, ShardId};
+        use std::sync::Arc;
+        use tokio_rustls::rustls::ClientConfig;
+        use tokio_websockets::Connector;
+
+        fn client_config<Q>(config: &Config<Q>) -> &Arc<ClientConfig> {
+            let Connector::Rustls(connector) = &*config.tls else {
+                panic!("expected a rustls connector");
+            };
+
+            connector.config()
+        }
+
+        let config = builder().build();
+        let client = client_config(&config);
+        assert!(format!("{:?}", client.resumption).contains("ClientSessionMemoryCache"));
+
+        let shards =
+            [ShardId::ONE, ShardId::new(1, 2)].map(|id| Shard::with_config(id, config.clone()));
+        assert!(shards
+            .iter()
+            .all(|shard| Arc::ptr_eq(client, client_config(shard.config()))));
+
+        let rebuilt = ConfigBuilder::from(config.clone())
+            .large_threshold(100)
+            .build();
+        assert!(Arc::ptr_eq(client, client_config(&rebuilt)));
+
+        let other = builder().build();
+        assert!(!Arc::ptr_eq(client, client_config(&other)));
+    }
+
     #[tokio::test]
     async fn large_threshold() {
         const INPUTS: &[u64] = &[50, 100, 150, 200, 250];
@@ -436,6 +683,39 @@ mod tests {
         drop(builder().large_threshold(251));
     }
 
+    #[tokio::test]
+    async fn missed_heartbeats_threshold() {
+        assert_eq!(builder().build().missed_heartbeats_threshold(), 1);
+        assert_eq!(
+            builder()
+                .missed_heartbeats_threshold(3)
+                .build()
+                .missed_heartbeats_threshold(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn reconnect_backoff() {
+        assert_eq!(
+            builder().build().reconnect_backoff(),
+            BackoffPolicy::default()
+        );
+        assert_eq!(
+            builder()
+                .reconnect_backoff(BackoffPolicy::ZERO)
+                .build()
+                .reconnect_backoff(),
+            BackoffPolicy::ZERO
+        );
+    }
+
+    #[should_panic(expected = "missed heartbeats threshold must be at least 1")]
+    #[tokio::test]
+    async fn missed_heartbeats_threshold_minimum() {
+        drop(builder().missed_heartbeats_threshold(0));
+    }
+
     #[tokio::test]
     async fn config_prefixes_bot_to_token() {
         const WITHOUT: &str = "test";
@@ -61,6 +61,72 @@ pub enum ChannelErrorType {
     Closed,
 }
 
+/// Queueing a raw gateway command failed.
+#[derive(Debug)]
+pub struct SendRawError {
+    /// Type of error.
+    pub(crate) kind: SendRawErrorType,
+    /// Source error if available.
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SendRawError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SendRawErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SendRawErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for SendRawError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            SendRawErrorType::ForbiddenOpcode { opcode } => {
+                f.write_str("gateway command has a forbidden opcode: ")?;
+
+                Display::fmt(opcode, f)
+            }
+            SendRawErrorType::InvalidPayload => {
+                f.write_str("gateway command is not a JSON object with an opcode")
+            }
+        }
+    }
+}
+
+impl Error for SendRawError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SendRawError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SendRawErrorType {
+    /// Command's opcode is not one users may send, such as one managed by the
+    /// shard or an unknown one.
+    ForbiddenOpcode {
+        /// Opcode of the rejected command.
+        opcode: u8,
+    },
+    /// Command is not a JSON object with an opcode.
+    InvalidPayload,
+}
+
 /// Failure when fetching the recommended number of shards to use from Discord's
 /// REST API.
 #[cfg(feature = "twilight-http")]
@@ -223,12 +289,14 @@ pub enum ReceiveMessageErrorType {
 
 #[cfg(test)]
 mod tests {
-    use super::{ReceiveMessageError, ReceiveMessageErrorType};
+    use super::{ReceiveMessageError, ReceiveMessageErrorType, SendRawError, SendRawErrorType};
     use static_assertions::assert_impl_all;
     use std::{error::Error, fmt::Debug};
 
     assert_impl_all!(ReceiveMessageErrorType: Debug, Send, Sync);
     assert_impl_all!(ReceiveMessageError: Error, Send, Sync);
+    assert_impl_all!(SendRawErrorType: Debug, Send, Sync);
+    assert_impl_all!(SendRawError: Error, Send, Sync);
 
     #[test]
     fn receive_message_error_display() {
@@ -255,4 +323,24 @@ mod tests {
             assert_eq!(error.to_string(), message);
         }
     }
+
+    #[test]
+    fn send_raw_error_display() {
+        let messages: [(SendRawErrorType, &str); 2] = [
+            (
+                SendRawErrorType::ForbiddenOpcode { opcode: 2 },
+                "gateway command has a forbidden opcode: 2",
+            ),
+            (
+                SendRawErrorType::InvalidPayload,
+                "gateway command is not a JSON object with an opcode",
+            ),
+        ];
+
+        for (kind, message) in messages {
+            let error = SendRawError { kind, source: None };
+
+            assert_eq!(error.to_string(), message);
+        }
+    }
 }
@@ -0,0 +1,128 @@
+//! Counters of received gateway events, useful for lightweight observability
+//! without processing every event.
+
+use crate::EventTypeFlags;
+use twilight_model::gateway::event::EventType;
+
+/// Number of counters, one per possible [`EventTypeFlags`] bit.
+const LEN: usize = u128::BITS as usize;
+
+/// Number of events a [`Shard`] received, by event type.
+///
+/// Counts are incremented as the shard processes messages, regardless of
+/// whether they are later deserialized. Both dispatch events and gateway
+/// events, such as [`EventType::GatewayHello`], are counted.
+///
+/// May be obtained via [`Shard::event_counts`] and reset via
+/// [`Shard::reset_event_counts`].
+///
+/// [`Shard`]: crate::Shard
+/// [`Shard::event_counts`]: crate::Shard::event_counts
+/// [`Shard::reset_event_counts`]: crate::Shard::reset_event_counts
+#[derive(Clone, Copy, Debug)]
+pub struct EventCounts {
+    /// Number of received WebSocket close frames.
+    close_frames: u64,
+    /// Number of received events, indexed by their [`EventTypeFlags`] bit.
+    counts: [u64; LEN],
+    /// Number of connections established after the first.
+    reconnects: u64,
+}
+
+impl EventCounts {
+    /// Create a new instance with all counts zeroed.
+    pub(crate) const fn new() -> Self {
+        Self {
+            close_frames: 0,
+            counts: [0; LEN],
+            reconnects: 0,
+        }
+    }
+
+    /// Number of received events of the given type.
+    ///
+    /// [`EventType::GatewayClose`] returns the number of [close frames].
+    ///
+    /// [close frames]: Self::close_frames
+    pub fn get(&self, event_type: EventType) -> u64 {
+        if event_type == EventType::GatewayClose {
+            return self.close_frames;
+        }
+
+        self.counts[index(EventTypeFlags::from(event_type))]
+    }
+
+    /// Total number of received events of any type.
+    ///
+    /// Does not include [close frames] or [reconnects].
+    ///
+    /// [close frames]: Self::close_frames
+    /// [reconnects]: Self::reconnects
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Number of received WebSocket close frames.
+    pub const fn close_frames(&self) -> u64 {
+        self.close_frames
+    }
+
+    /// Number of times the shard reconnected to the gateway, for any reason.
+    pub const fn reconnects(&self) -> u64 {
+        self.reconnects
+    }
+
+    /// Record a received event.
+    ///
+    /// `flag` must contain a single event type.
+    pub(crate) fn record(&mut self, flag: EventTypeFlags) {
+        self.counts[index(flag)] += 1;
+    }
+
+    /// Record a received WebSocket close frame.
+    pub(crate) fn record_close_frame(&mut self) {
+        self.close_frames += 1;
+    }
+
+    /// Record a reconnect.
+    pub(crate) fn record_reconnect(&mut self) {
+        self.reconnects += 1;
+    }
+}
+
+/// Index of the counter for an event type flag.
+const fn index(flag: EventTypeFlags) -> usize {
+    flag.bits().trailing_zeros() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventCounts;
+    use crate::EventTypeFlags;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::gateway::event::EventType;
+
+    assert_impl_all!(EventCounts: Clone, Copy, Debug, Send, Sync);
+
+    #[test]
+    fn record() {
+        let mut counts = EventCounts::new();
+        assert_eq!(0, counts.total());
+
+        counts.record(EventTypeFlags::GATEWAY_HELLO);
+        counts.record(EventTypeFlags::MESSAGE_CREATE);
+        counts.record(EventTypeFlags::MESSAGE_CREATE);
+        counts.record(EventTypeFlags::WEBHOOKS_UPDATE);
+        counts.record_close_frame();
+
+        assert_eq!(1, counts.get(EventType::GatewayHello));
+        assert_eq!(2, counts.get(EventType::MessageCreate));
+        assert_eq!(1, counts.get(EventType::WebhooksUpdate));
+        assert_eq!(0, counts.get(EventType::Ready));
+        assert_eq!(4, counts.total());
+        assert_eq!(1, counts.close_frames());
+        assert_eq!(1, counts.get(EventType::GatewayClose));
+        assert_eq!(0, counts.reconnects());
+    }
+}
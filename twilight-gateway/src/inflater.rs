@@ -5,12 +5,15 @@
 //!
 //! A compressed message buffer is used to store incomplete messages and gets,
 //! if used, shrank every minute to the size of the most recent completed
-//! message.
+//! message. The decompressed message buffer grows to the size of the largest
+//! message and is likewise shrank every minute, though never below 32
+//! kibibytes.
 
 use flate2::{Decompress, FlushDecompress};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    mem,
     time::Instant,
 };
 
@@ -85,8 +88,8 @@ fn is_incomplete_message(message: &[u8]) -> bool {
 
 /// Gateway event decompressor.
 ///
-/// Each received compressed event gets inflated into a [`String`] who's input
-/// and output size is recorded.
+/// Each received compressed event gets inflated into a buffer reused across
+/// events, and its input and output size is recorded.
 ///
 /// # Example
 ///
@@ -103,37 +106,44 @@ fn is_incomplete_message(message: &[u8]) -> bool {
 /// ```
 #[derive(Debug)]
 pub struct Inflater {
-    /// Common decompressed message buffer.
-    buffer: Box<[u8]>,
     /// Per event compressed message buffer.
     compressed: Vec<u8>,
     /// Zlib decompressor with a dictionary of past data.
     decompress: Decompress,
-    /// When the compression buffer last shrank.
+    /// Common decompressed message buffer.
+    decompressed: Vec<u8>,
+    /// When the buffers last shrank.
     last_shrank: Instant,
 }
 
 impl Inflater {
-    /// [`Self::buffer`]'s size.
+    /// Amount [`Self::decompressed`] grows by when full, and the capacity it
+    /// never shrinks below.
     const BUFFER_SIZE: usize = 32 * 1024;
 
     /// Create a new inflator for a shard.
     pub(crate) fn new() -> Self {
         Self {
-            buffer: vec![0; Self::BUFFER_SIZE].into_boxed_slice(),
             compressed: Vec::new(),
             decompress: Decompress::new(true),
+            decompressed: Vec::with_capacity(Self::BUFFER_SIZE),
             last_shrank: Instant::now(),
         }
     }
 
-    /// Clear the compressed buffer and periodically shrink its capacity.
+    /// Clear the compressed buffer and periodically shrink the capacity of
+    /// both buffers.
+    ///
+    /// The decompressed buffer is shrank without losing its contents.
     fn clear(&mut self) {
-        if self.compressed.capacity() != 0 && self.last_shrank.elapsed().as_secs() > 60 {
+        if self.last_shrank.elapsed().as_secs() > 60 {
             self.compressed.shrink_to_fit();
+            self.decompressed
+                .shrink_to(self.decompressed.len().max(Self::BUFFER_SIZE));
 
             tracing::trace!(
                 compressed.capacity = self.compressed.capacity(),
+                decompressed.capacity = self.decompressed.capacity(),
                 "shrank capacity to the size of the last message"
             );
 
@@ -146,7 +156,9 @@ impl Inflater {
     /// Decompress message.
     ///
     /// Returns `None` if the message is incomplete, saving its content to be
-    /// combined with the next one.
+    /// combined with the next one. The returned message is the decompressed
+    /// buffer itself, which should be [reclaimed] once done with so that the
+    /// next message reuses it.
     ///
     /// # Errors
     ///
@@ -155,6 +167,8 @@ impl Inflater {
     ///
     /// Returns a [`CompressionErrorType::NotUtf8`] error type if the
     /// decompressed message is not UTF-8.
+    ///
+    /// [reclaimed]: Self::reclaim
     pub(crate) fn inflate(&mut self, message: &[u8]) -> Result<Option<String>, CompressionError> {
         // Complete message. Tries to bypass the `self.compressed` buffer if the
         // message is incomplete.
@@ -178,18 +192,19 @@ impl Inflater {
 
         let mut processed = 0;
 
-        // Decompressed message. `Vec::extend_from_slice` efficiently allocates
-        // only what's necessary.
-        let mut decompressed = Vec::new();
+        self.decompressed.clear();
 
         loop {
-            let produced_pre = self.produced();
+            // Only grow the buffer if the last message didn't already.
+            if self.decompressed.len() == self.decompressed.capacity() {
+                self.decompressed.reserve(Self::BUFFER_SIZE);
+            }
 
             // Use Sync to ensure data is flushed to the buffer.
             self.decompress
-                .decompress(
+                .decompress_vec(
                     &message[processed..],
-                    &mut self.buffer,
+                    &mut self.decompressed,
                     FlushDecompress::Sync,
                 )
                 .map_err(|source| CompressionError {
@@ -198,12 +213,11 @@ impl Inflater {
                 })?;
 
             processed = (self.processed() - processed_pre).try_into().unwrap();
-            let produced = (self.produced() - produced_pre).try_into().unwrap();
 
-            decompressed.extend_from_slice(&self.buffer[..produced]);
-
-            // Break when message has been fully decompressed.
-            if processed == message.len() {
+            // Break when message has been fully decompressed. A full buffer
+            // may mean there's more output pending.
+            if processed == message.len() && self.decompressed.len() < self.decompressed.capacity()
+            {
                 break;
             }
 
@@ -219,7 +233,7 @@ impl Inflater {
 
             tracing::trace!(
                 bytes.compressed = message.len(),
-                bytes.decompressed = decompressed.len(),
+                bytes.decompressed = self.decompressed.len(),
                 total_percentage_saved,
                 "{total_kib_saved} KiB saved in total",
             );
@@ -227,18 +241,32 @@ impl Inflater {
 
         self.clear();
 
-        String::from_utf8(decompressed)
+        String::from_utf8(mem::take(&mut self.decompressed))
             .map(Some)
-            .map_err(|source| CompressionError {
-                kind: CompressionErrorType::NotUtf8,
-                source: Some(Box::new(source)),
+            .map_err(|source| {
+                let error = source.utf8_error();
+                self.decompressed = source.into_bytes();
+
+                CompressionError {
+                    kind: CompressionErrorType::NotUtf8,
+                    source: Some(Box::new(error)),
+                }
             })
     }
 
+    /// Reclaim the decompressed buffer returned by [`inflate`] to reuse it for
+    /// the next message.
+    ///
+    /// [`inflate`]: Self::inflate
+    pub(crate) fn reclaim(&mut self, buffer: String) {
+        self.decompressed = buffer.into_bytes();
+    }
+
     /// Reset the inflater's state.
     pub(crate) fn reset(&mut self) {
         self.compressed = Vec::new();
         self.decompress.reset(true);
+        self.decompressed.clear();
     }
 
     /// Total number of bytes processed.
@@ -255,7 +283,6 @@ impl Inflater {
 #[cfg(test)]
 mod tests {
     use super::Inflater;
-
     const MESSAGE: &[u8] = &[
         120, 156, 52, 201, 65, 10, 131, 48, 16, 5, 208, 187, 252, 117, 82, 98, 169, 32, 115, 21,
         35, 50, 53, 67, 27, 136, 81, 226, 216, 82, 66, 238, 222, 110, 186, 123, 240, 42, 20, 148,
@@ -270,7 +297,7 @@ mod tests {
     fn decompress_single_segment() {
         let mut inflator = Inflater::new();
         assert!(inflator.compressed.is_empty());
-        assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
+        assert_eq!(inflator.inflate(MESSAGE).unwrap().as_deref(), Some(OUTPUT));
 
         assert!(inflator.compressed.is_empty());
     }
@@ -286,8 +313,11 @@ mod tests {
         assert!(!inflator.compressed.is_empty());
 
         assert_eq!(
-            inflator.inflate(&MESSAGE[MESSAGE.len() / 2..]).unwrap(),
-            Some(OUTPUT.to_owned()),
+            inflator
+                .inflate(&MESSAGE[MESSAGE.len() / 2..])
+                .unwrap()
+                .as_deref(),
+            Some(OUTPUT),
         );
         assert!(inflator.compressed.is_empty());
     }
@@ -312,6 +342,6 @@ mod tests {
         );
 
         inflator.reset();
-        assert_eq!(inflator.inflate(MESSAGE).unwrap(), Some(OUTPUT.to_owned()));
+        assert_eq!(inflator.inflate(MESSAGE).unwrap().as_deref(), Some(OUTPUT));
     }
 }
@@ -11,44 +11,121 @@ use crate::{
     EventTypeFlags,
 };
 use serde::de::DeserializeSeed;
+#[cfg(feature = "partial-interactions")]
+use serde::Deserialize;
+use std::borrow::Cow;
 use twilight_model::gateway::{
     event::{GatewayEvent, GatewayEventDeserializer},
     OpCode,
 };
+#[cfg(feature = "partial-interactions")]
+use twilight_model::{application::interaction::PartialInteraction, gateway::event::DispatchEvent};
+
+/// Dispatch payload of an interaction that only deserializes its envelope.
+#[cfg(feature = "partial-interactions")]
+#[derive(Deserialize)]
+struct PartialInteractionDispatch {
+    /// Partial interaction.
+    d: PartialInteraction,
+    /// Sequence number of the dispatch.
+    s: u64,
+}
 
 /// Parse a JSON encoded gateway event into a `GatewayEvent` if
 /// `wanted_event_types` contains its type.
 ///
+/// The event may be borrowed, such as from a buffer reused across events, in
+/// which case only the parts of it that become owned fields of the
+/// `GatewayEvent` are copied. The `simd-json` feature requires an owned event
+/// and copies borrowed events.
+///
 /// # Errors
 ///
 /// Returns a [`ReceiveMessageErrorType::Deserializing`] error if the *known*
 /// event could not be deserialized.
-pub fn parse(
-    event: String,
+///
+/// With the `partial-interactions` feature enabled, an `INTERACTION_CREATE`
+/// event that fails to deserialize is instead returned as an
+/// [`InteractionCreatePartial`] if its envelope can be deserialized.
+///
+/// [`InteractionCreatePartial`]: twilight_model::gateway::event::DispatchEvent::InteractionCreatePartial
+pub fn parse<'a>(
+    event: impl Into<Cow<'a, str>>,
+    wanted_event_types: EventTypeFlags,
+) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
+    parse_event(event.into(), None, wanted_event_types)
+}
+
+/// Parse a JSON encoded gateway event whose type is already known into a
+/// `GatewayEvent` if `wanted_event_types` contains its type.
+///
+/// Unwanted events are skipped without reading them.
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub(crate) fn parse_as<'a>(
+    event: impl Into<Cow<'a, str>>,
+    event_type: EventTypeFlags,
+    wanted_event_types: EventTypeFlags,
+) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
+    if !wanted_event_types.contains(event_type) {
+        return Ok(None);
+    }
+
+    parse_event(event.into(), Some(event_type), wanted_event_types)
+}
+
+/// Parse a JSON encoded gateway event, determining its type unless it's
+/// already known.
+fn parse_event(
+    event: Cow<'_, str>,
+    event_type: Option<EventTypeFlags>,
     wanted_event_types: EventTypeFlags,
 ) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
     let Some(gateway_deserializer) = GatewayEventDeserializer::from_json(&event) else {
         return Err(ReceiveMessageError {
-            kind: ReceiveMessageErrorType::Deserializing { event },
+            kind: ReceiveMessageErrorType::Deserializing {
+                event: event.into_owned(),
+            },
             source: None,
         });
     };
 
-    let Some(opcode) = OpCode::from(gateway_deserializer.op()) else {
-        return Ok(None);
-    };
+    let event_type = if let Some(event_type) = event_type {
+        event_type
+    } else {
+        let Some(opcode) = OpCode::from(gateway_deserializer.op()) else {
+            return Ok(None);
+        };
 
-    let event_type = gateway_deserializer.event_type();
+        let event_type = gateway_deserializer.event_type();
 
-    let Ok(event_type) = EventTypeFlags::try_from((opcode, event_type)) else {
-        return Ok(None);
+        let Ok(event_type) = EventTypeFlags::try_from((opcode, event_type)) else {
+            return Ok(None);
+        };
+
+        event_type
     };
 
     if wanted_event_types.contains(event_type) {
+        // simd-json deserializes events in place, so interactions are
+        // deserialized with serde_json to keep the payload intact in case it
+        // needs to be deserialized again as a partial interaction.
+        #[cfg(feature = "partial-interactions")]
+        if event_type == EventTypeFlags::INTERACTION_CREATE {
+            let mut json_deserializer = serde_json::Deserializer::from_str(&event);
+
+            return match gateway_deserializer.deserialize(&mut json_deserializer) {
+                Ok(event) => Ok(Some(event)),
+                Err(source) => partial_interaction(event, source),
+            };
+        }
+
         #[cfg(feature = "simd-json")]
         let gateway_deserializer = gateway_deserializer.into_owned();
         #[cfg(feature = "simd-json")]
-        let mut bytes = event.into_bytes();
+        let mut bytes = event.into_owned().into_bytes();
 
         #[cfg(feature = "simd-json")]
         let mut json_deserializer = match simd_json::Deserializer::from_slice(&mut bytes) {
@@ -74,7 +151,7 @@ pub fn parse(
                     #[cfg(feature = "simd-json")]
                     event: String::from_utf8_lossy(&bytes).into_owned(),
                     #[cfg(not(feature = "simd-json"))]
-                    event,
+                    event: event.into_owned(),
                 },
                 source: Some(Box::new(source)),
             })
@@ -82,3 +159,96 @@ pub fn parse(
         Ok(None)
     }
 }
+
+/// Deserialize an `INTERACTION_CREATE` event that failed to deserialize as a
+/// partial interaction, returning the original error if its envelope can't be
+/// deserialized either.
+#[cfg(feature = "partial-interactions")]
+fn partial_interaction(
+    event: Cow<'_, str>,
+    source: serde_json::Error,
+) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
+    if let Ok(dispatch) = serde_json::from_str::<PartialInteractionDispatch>(&event) {
+        tracing::debug!(?source, "falling back to partial interaction");
+
+        return Ok(Some(GatewayEvent::Dispatch(
+            dispatch.s,
+            DispatchEvent::InteractionCreatePartial(Box::new(dispatch.d)),
+        )));
+    }
+
+    Err(ReceiveMessageError {
+        kind: ReceiveMessageErrorType::Deserializing {
+            event: event.into_owned(),
+        },
+        source: Some(Box::new(source)),
+    })
+}
+
+#[cfg(all(test, feature = "partial-interactions"))]
+mod tests {
+    use super::parse;
+    use crate::EventTypeFlags;
+    use twilight_model::{
+        application::interaction::InteractionType,
+        gateway::event::{DispatchEvent, GatewayEvent},
+        id::Id,
+    };
+
+    #[test]
+    fn partial_interaction() {
+        let json = r#"{
+            "op": 0,
+            "s": 7,
+            "t": "INTERACTION_CREATE",
+            "d": {
+                "application_id": "1",
+                "channel_id": "2",
+                "data": {
+                    "id": "5",
+                    "name": "command",
+                    "options": [
+                        {"focused": true, "name": "a", "type": 3, "value": "b"},
+                        {"focused": "maybe", "name": "c", "type": 4, "value": {}}
+                    ],
+                    "type": 1
+                },
+                "guild_id": "3",
+                "id": "4",
+                "token": "token",
+                "type": 4,
+                "version": 1
+            }
+        }"#;
+
+        let event = parse(json.to_owned(), EventTypeFlags::INTERACTION_CREATE)
+            .unwrap()
+            .unwrap();
+
+        let interaction = match event {
+            GatewayEvent::Dispatch(7, DispatchEvent::InteractionCreatePartial(interaction)) => {
+                interaction
+            }
+            other => panic!("expected a partial interaction, got {other:?}"),
+        };
+
+        assert_eq!(Id::new(1), interaction.application_id);
+        assert_eq!(Some(Id::new(2)), interaction.channel_id);
+        assert_eq!(Some(Id::new(3)), interaction.guild_id);
+        assert_eq!(Id::new(4), interaction.id);
+        assert_eq!(
+            InteractionType::ApplicationCommandAutocomplete,
+            interaction.kind
+        );
+        assert_eq!("token", interaction.token);
+    }
+
+    #[test]
+    fn partial_interaction_unwanted() {
+        let json = r#"{"op":0,"s":1,"t":"INTERACTION_CREATE","d":{"id":"4"}}"#;
+
+        assert!(parse(json.to_owned(), EventTypeFlags::MESSAGE_CREATE)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -5,8 +5,10 @@ use std::time::{Duration, Instant};
 /// [`Shard`]'s gateway connection latency.
 ///
 /// Measures the difference between sending a heartbeat and receiving an
-/// acknowledgement, also known as a heartbeat period. Spurious heartbeat
-/// acknowledgements are ignored.
+/// acknowledgement, also known as a heartbeat period. Acknowledgements are
+/// timestamped when read from the WebSocket connection, so time spent by the
+/// event loop before polling the shard again is not counted. Spurious
+/// heartbeat acknowledgements are ignored.
 ///
 /// May be obtained via [`Shard::latency`].
 ///
@@ -82,8 +84,9 @@ impl Latency {
     /// Record that a heartbeat acknowledgement was received, completing the
     /// period.
     ///
-    /// The current time is subtracted against when the last heartbeat
-    /// [was sent] to calculate the heartbeat period's latency.
+    /// When the acknowledgement was read from the connection is subtracted
+    /// against when the last heartbeat [was sent] to calculate the heartbeat
+    /// period's latency.
     ///
     /// # Panics
     ///
@@ -91,12 +94,12 @@ impl Latency {
     ///
     /// [was sent]: Self::record_sent
     #[track_caller]
-    pub(crate) fn record_received(&mut self) {
+    pub(crate) fn record_received(&mut self, received: Instant) {
         debug_assert!(self.received.is_none(), "period completed multiple times");
 
-        let now = Instant::now();
-        let period_latency = now - self.sent.expect("period has not begun");
-        self.received = Some(now);
+        let period_latency =
+            received.saturating_duration_since(self.sent.expect("period has not begun"));
+        self.received = Some(received);
         self.periods += 1;
 
         self.latency_sum += period_latency;
@@ -119,7 +122,10 @@ impl Latency {
 mod tests {
     use super::Latency;
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, time::Duration};
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
 
     assert_impl_all!(Latency: Clone, Debug, Send, Sync);
 
@@ -227,7 +233,7 @@ mod tests {
         assert!(latency.received().is_none());
         assert!(latency.sent().is_some());
 
-        latency.record_received();
+        latency.record_received(Instant::now());
         assert_eq!(latency.periods(), 1);
         assert!(latency.received().is_some());
         assert!(latency.sent().is_some());
@@ -245,14 +251,14 @@ mod tests {
     fn record_completed_period() {
         let mut latency = Latency::new();
         latency.record_sent();
-        latency.record_received();
-        latency.record_received();
+        latency.record_received(Instant::now());
+        latency.record_received(Instant::now());
     }
 
     #[test]
     #[should_panic(expected = "period has not begun")]
     fn record_not_begun_period() {
         let mut latency = Latency::new();
-        latency.record_received();
+        latency.record_received(Instant::now());
     }
 }
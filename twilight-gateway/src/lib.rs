@@ -15,10 +15,12 @@
 
 pub mod error;
 
+mod backoff;
 mod channel;
 mod command;
 mod config;
 mod event;
+mod event_counts;
 #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 mod inflater;
 mod json;
@@ -28,14 +30,19 @@ mod ratelimiter;
 mod session;
 mod shard;
 mod stream;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod timings;
 
 #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 pub use self::inflater::Inflater;
 pub use self::{
+    backoff::BackoffPolicy,
     channel::MessageSender,
     command::Command,
     config::{Config, ConfigBuilder},
     event::EventTypeFlags,
+    event_counts::EventCounts,
     json::parse,
     latency::Latency,
     message::Message,
@@ -43,6 +50,7 @@ pub use self::{
     session::Session,
     shard::{Shard, ShardState},
     stream::StreamExt,
+    timings::ConnectTimings,
 };
 pub use twilight_model::gateway::{CloseFrame, Intents, ShardId};
 
@@ -55,6 +63,8 @@ pub use twilight_model::gateway::event::{Event, EventType};
 use self::error::{StartRecommendedError, StartRecommendedErrorType};
 #[cfg(feature = "twilight-http")]
 use twilight_http::Client;
+#[cfg(feature = "twilight-http")]
+use twilight_model::gateway::connection_info::BotConnectionInfo;
 
 /// Discord Gateway API version used by this crate.
 pub const API_VERSION: u8 = 10;
@@ -150,6 +160,21 @@ where
     })
 }
 
+/// Shards created from Discord's recommendation along with the information
+/// it's based on.
+///
+/// Returned by [`create_recommended_with_info`].
+#[cfg(feature = "twilight-http")]
+#[derive(Debug)]
+pub struct RecommendedShards<I> {
+    /// Gateway information, including the current [`SessionStartLimit`].
+    ///
+    /// [`SessionStartLimit`]: twilight_model::gateway::SessionStartLimit
+    pub info: BotConnectionInfo,
+    /// Iterator of the recommended shards.
+    pub shards: I,
+}
+
 /// Create a range of shards from Discord's recommendation.
 ///
 /// Passing a primary config is required. Further customization of this config
@@ -157,6 +182,9 @@ where
 ///
 /// Internally calls [`create_iterator`] with the values from [`GetGatewayAuthed`].
 ///
+/// Use [`create_recommended_with_info`] to also retrieve the session start
+/// limit, such as to check whether starting all shards is safe.
+///
 /// # Errors
 ///
 /// Returns a [`StartRecommendedErrorType::Deserializing`] error type if the
@@ -176,6 +204,66 @@ pub async fn create_recommended<F, Q>(
     config: Config<Q>,
     per_shard_config: F,
 ) -> Result<impl ExactSizeIterator<Item = Shard<Q>>, StartRecommendedError>
+where
+    F: Fn(ShardId, ConfigBuilder<Q>) -> Config<Q>,
+    Q: Clone,
+{
+    create_recommended_with_info(client, config, per_shard_config)
+        .await
+        .map(|recommended| recommended.shards)
+}
+
+/// Create a range of shards from Discord's recommendation, returning them
+/// with the gateway information they're based on.
+///
+/// Refer to [`create_recommended`] for details.
+///
+/// # Examples
+///
+/// Only start the shards if enough sessions remain:
+///
+/// ```no_run
+/// use std::{env, time::SystemTime};
+/// use twilight_gateway::{Config, Intents};
+/// use twilight_http::Client;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let client = Client::new(token.clone());
+///
+/// let config = Config::new(token, Intents::GUILDS);
+/// let retrieved_at = SystemTime::now();
+/// let recommended =
+///     twilight_gateway::create_recommended_with_info(&client, config, |_, builder| {
+///         builder.build()
+///     })
+///     .await?;
+///
+/// let limit = &recommended.info.session_start_limit;
+/// if !limit.can_start(recommended.info.shards) {
+///     let resets_at = limit.resets_at(retrieved_at);
+///     println!("not enough sessions remaining until {resets_at:?}");
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`StartRecommendedErrorType::Deserializing`] error type if the
+/// response body failed to deserialize.
+///
+/// Returns a [`StartRecommendedErrorType::Request`] error type if the request
+/// failed to complete.
+///
+/// # Panics
+///
+/// Panics if loading TLS certificates fails.
+#[cfg(feature = "twilight-http")]
+pub async fn create_recommended_with_info<F, Q>(
+    client: &Client,
+    config: Config<Q>,
+    per_shard_config: F,
+) -> Result<RecommendedShards<impl ExactSizeIterator<Item = Shard<Q>>>, StartRecommendedError>
 where
     F: Fn(ShardId, ConfigBuilder<Q>) -> Config<Q>,
     Q: Clone,
@@ -193,10 +281,7 @@ where
             source: Some(Box::new(source)),
         })?;
 
-    Ok(create_iterator(
-        0..info.shards,
-        info.shards,
-        config,
-        per_shard_config,
-    ))
+    let shards = create_iterator(0..info.shards, info.shards, config, per_shard_config);
+
+    Ok(RecommendedShards { info, shards })
 }
@@ -10,16 +10,19 @@
 use crate::inflater::Inflater;
 use crate::{
     channel::{MessageChannel, MessageSender},
-    error::{ReceiveMessageError, ReceiveMessageErrorType},
+    error::{ReceiveMessageError, ReceiveMessageErrorType, SendRawError, SendRawErrorType},
+    event_counts::EventCounts,
     json,
     latency::Latency,
     queue::{InMemoryQueue, Queue},
     ratelimiter::CommandRatelimiter,
     session::Session,
-    Command, Config, Message, ShardId, API_VERSION,
+    timings::ConnectTimings,
+    Command, Config, EventTypeFlags, Message, ShardId, API_VERSION,
 };
 use futures_core::Stream;
 use futures_sink::Sink;
+use http::Uri;
 use serde::{de::DeserializeOwned, Deserialize};
 #[cfg(any(
     feature = "native-tls",
@@ -31,19 +34,24 @@ use std::io::ErrorKind as IoErrorKind;
 use std::{
     env::consts::OS,
     fmt,
-    future::Future,
+    future::{self, Future},
     pin::Pin,
     str,
-    task::{ready, Context, Poll},
+    sync::{Arc, Mutex},
+    task::{ready, Context, Poll, Wake, Waker},
 };
 use tokio::{
     net::TcpStream,
     sync::oneshot,
     time::{self, Duration, Instant, Interval, MissedTickBehavior},
 };
-use tokio_websockets::{ClientBuilder, Error as WebsocketError, Limits, MaybeTlsStream};
+use tokio_websockets::{
+    ClientBuilder, Connector, Error as WebsocketError, Limits, MaybeTlsStream,
+    Message as WebsocketMessage,
+};
+use tracing::Instrument;
 use twilight_model::gateway::{
-    event::GatewayEventDeserializer,
+    event::{Event, GatewayEventDeserializer},
     payload::{
         incoming::Hello,
         outgoing::{
@@ -68,8 +76,11 @@ const COMPRESSION_FEATURES: &str = "";
 /// [`tokio_websockets`] library Websocket connection.
 type Connection = tokio_websockets::WebSocketStream<MaybeTlsStream<TcpStream>>;
 
+/// Established [`Connection`] along with how long establishing it took.
+type ConnectionResult = Result<(Connection, ConnectTimings), WebsocketError>;
+
 /// Wrapper struct around an `async fn` with a `Debug` implementation.
-struct ConnectionFuture(Pin<Box<dyn Future<Output = Result<Connection, WebsocketError>> + Send>>);
+struct ConnectionFuture(Pin<Box<dyn Future<Output = ConnectionResult> + Send>>);
 
 impl fmt::Debug for ConnectionFuture {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -79,6 +90,93 @@ impl fmt::Debug for ConnectionFuture {
     }
 }
 
+/// Message read from the [`Connection`].
+///
+/// Text messages own the buffer they were read into, so that they can be
+/// parsed without copying them first.
+enum ReadMessage {
+    /// Close message with an optional frame.
+    Close(Option<CloseFrame<'static>>),
+    /// Text message decompressed into the [`Inflater`]'s buffer, which should
+    /// be [reclaimed] once done with.
+    ///
+    /// [reclaimed]: Shard::reclaim
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    Decompressed(String),
+    /// Uncompressed text message.
+    Text(WebsocketMessage),
+}
+
+impl ReadMessage {
+    /// Close message indicating the connection was closed abnormally.
+    const ABNORMAL_CLOSE: Self = Self::Close(Some(CloseFrame::new(1006, "")));
+
+    /// Text of the message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message is a close message.
+    fn as_text(&self) -> &str {
+        match self {
+            Self::Close(_) => unreachable!("close messages have no text"),
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            Self::Decompressed(text) => text,
+            Self::Text(message) => message.as_text().expect("text message"),
+        }
+    }
+}
+
+/// Processed [`ReadMessage`] along with the type of its event, if known.
+type ReadResult = Result<(ReadMessage, Option<EventTypeFlags>), ReceiveMessageError>;
+
+/// Waker recording when the [`Connection`] was last woken to be read.
+///
+/// Frames may be read well after they arrived when the shard isn't polled in
+/// the meantime, such as while the user is processing an event. The time the
+/// connection was woken is close to when the frame arrived, so it's used as
+/// the frame's received time instead.
+#[derive(Debug, Default)]
+struct ReadWaker {
+    /// Waker of the task polling the shard.
+    waker: Mutex<Option<Waker>>,
+    /// When the waker was last woken.
+    woken_at: Mutex<Option<std::time::Instant>>,
+}
+
+impl ReadWaker {
+    /// Set the task to wake, returning a waker recording when it's woken.
+    fn register(self: &Arc<Self>, waker: &Waker) -> Waker {
+        let mut current = self.waker.lock().expect("waker poisoned");
+        if !current
+            .as_ref()
+            .is_some_and(|current| current.will_wake(waker))
+        {
+            *current = Some(waker.clone());
+        }
+
+        Waker::from(Arc::clone(self))
+    }
+
+    /// Take when the waker was last woken.
+    fn take_woken_at(&self) -> Option<std::time::Instant> {
+        self.woken_at.lock().expect("woken at poisoned").take()
+    }
+}
+
+impl Wake for ReadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        *self.woken_at.lock().expect("woken at poisoned") = Some(std::time::Instant::now());
+
+        if let Some(waker) = self.waker.lock().expect("waker poisoned").as_ref() {
+            waker.wake_by_ref();
+        }
+    }
+}
+
 /// Close initiator of a websocket connection.
 #[derive(Clone, Debug)]
 enum CloseInitiator {
@@ -112,9 +210,16 @@ pub enum ShardState {
     /// [invalid intents], or other reasons. Refer to the documentation for
     /// [`CloseCode`] for possible reasons.
     ///
+    /// The shard won't reconnect, as doing so would fail again. Human
+    /// intervention is typically required, so consider alerting on this
+    /// state.
+    ///
     /// [failed authentication]: CloseCode::AuthenticationFailed
     /// [invalid intents]: CloseCode::InvalidIntents
-    FatallyClosed,
+    FatallyClosed {
+        /// Fatal close code the gateway closed the connection with.
+        code: CloseCode,
+    },
     /// Shard is waiting to establish or resume a session.
     Identifying,
     /// Shard is replaying missed dispatch events.
@@ -126,12 +231,12 @@ pub enum ShardState {
 impl ShardState {
     /// Determine the connection status from the close code.
     ///
-    /// Defers to [`CloseCode::can_reconnect`] to determine whether the
-    /// connection can be reconnected, defaulting to [`Self::Disconnected`] if
-    /// the close code is unknown.
+    /// Defers to [`CloseCode::is_fatal`] to determine whether the connection
+    /// can be reconnected, defaulting to [`Self::Disconnected`] if the close
+    /// code is unknown.
     fn from_close_code(close_code: Option<u16>) -> Self {
         match close_code.map(CloseCode::try_from) {
-            Some(Ok(close_code)) if !close_code.can_reconnect() => Self::FatallyClosed,
+            Some(Ok(code)) if code.is_fatal() => Self::FatallyClosed { code },
             _ => Self::Disconnected {
                 reconnect_attempts: 0,
             },
@@ -222,7 +327,7 @@ impl Pending {
 ///
 /// ```no_run
 /// use std::env;
-/// use twilight_gateway::{Event, EventTypeFlags, Intents, Shard, ShardId, StreamExt as _};
+/// use twilight_gateway::{Event, EventTypeFlags, Intents, Shard, ShardId};
 ///
 /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// // Use the value of the "DISCORD_TOKEN" environment variable as the bot's
@@ -271,6 +376,12 @@ pub struct Shard<Q = InMemoryQueue> {
     /// The connection should only be dropped after it has returned `Ok(None)`
     /// to comply with the WebSocket protocol.
     connection: Option<Connection>,
+    /// Number of WebSocket connections established with the Gateway.
+    connection_generation: u64,
+    /// Number of received events by event type.
+    ///
+    /// Boxed as it holds a counter for every event type.
+    event_counts: Box<EventCounts>,
     /// Interval of how often the gateway would like the shard to send
     /// heartbeats.
     ///
@@ -291,6 +402,8 @@ pub struct Shard<Q = InMemoryQueue> {
     inflater: Inflater,
     /// Potentially pending outgoing message.
     pending: Option<Pending>,
+    /// Phase durations of the most recently established connection.
+    last_connect_timings: Option<ConnectTimings>,
     /// Recent heartbeat latency statistics.
     ///
     /// The latency is reset on receiving [`GatewayEvent::Hello`] as the host
@@ -298,9 +411,15 @@ pub struct Shard<Q = InMemoryQueue> {
     ///
     /// [`GatewayEvent::Hello`]: twilight_model::gateway::event::GatewayEvent::Hello
     latency: Latency,
+    /// Number of consecutive heartbeat intervals without a received event.
+    missed_heartbeats: u8,
     /// Command ratelimiter, if it was enabled via
     /// [`Config::ratelimit_messages`].
     ratelimiter: Option<CommandRatelimiter>,
+    /// Waker used to read from the [connection].
+    ///
+    /// [connection]: Self::connection
+    read_waker: Arc<ReadWaker>,
     /// Used for resuming connections.
     resume_url: Option<Box<str>>,
     /// Active session of the shard.
@@ -313,6 +432,8 @@ pub struct Shard<Q = InMemoryQueue> {
     /// Messages from the user to be relayed and sent over the Websocket
     /// connection.
     user_channel: MessageChannel,
+    /// Number of reconnects caused by failed or "zombied" connections.
+    zombied_reconnects: u64,
 }
 
 impl Shard {
@@ -336,6 +457,8 @@ impl<Q> Shard<Q> {
             config,
             connection_future: None,
             connection: None,
+            connection_generation: 0,
+            event_counts: Box::new(EventCounts::new()),
             heartbeat_interval: None,
             heartbeat_interval_event: false,
             id: shard_id,
@@ -343,14 +466,18 @@ impl<Q> Shard<Q> {
             #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
             inflater: Inflater::new(),
             pending: None,
+            last_connect_timings: None,
             latency: Latency::new(),
+            missed_heartbeats: 0,
             ratelimiter: None,
+            read_waker: Arc::default(),
             resume_url,
             session,
             state: ShardState::Disconnected {
                 reconnect_attempts: 0,
             },
             user_channel: MessageChannel::new(),
+            zombied_reconnects: 0,
         }
     }
 
@@ -377,6 +504,59 @@ impl<Q> Shard<Q> {
         self.state
     }
 
+    /// Whether the most recently returned message is a replayed dispatch event.
+    ///
+    /// Discord replays the dispatch events missed whilst disconnected after a
+    /// session is resumed, ending with a `RESUMED` dispatch event which is
+    /// itself not replayed. Replayed events are otherwise indistinguishable
+    /// from live ones.
+    ///
+    /// The sequence of the most recently returned dispatch event is available
+    /// via [`Session::sequence`].
+    ///
+    /// # Examples
+    ///
+    /// Skip replayed message creations:
+    ///
+    /// ```no_run
+    /// # use twilight_gateway::{Intents, Shard, ShardId};
+    /// # #[tokio::main] async fn main() {
+    /// # let mut shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+    /// use twilight_gateway::{Event, EventTypeFlags};
+    ///
+    /// while let Some(item) = shard.next_event(EventTypeFlags::MESSAGE_CREATE).await {
+    ///     let Ok(Event::MessageCreate(message)) = item else {
+    ///         continue;
+    ///     };
+    ///
+    ///     if shard.is_replaying() {
+    ///         continue;
+    ///     }
+    ///
+    ///     println!("message received with content: {}", message.content);
+    /// }
+    /// # }
+    /// ```
+    pub const fn is_replaying(&self) -> bool {
+        matches!(self.state, ShardState::Resuming)
+    }
+
+    /// Number of WebSocket connections the shard has established with the
+    /// Gateway.
+    ///
+    /// Incremented every time a new connection is established, whether the
+    /// session is then resumed or newly identified. Voice connections and
+    /// other state bound to the previous connection may need to be
+    /// re-established when this changes, as Discord doesn't necessarily
+    /// restore them. A successful resume is additionally signaled by an
+    /// [`Event::Resumed`], which is received after all missed events were
+    /// replayed if [`EventTypeFlags::RESUMED`] is wanted.
+    ///
+    /// [`Event::Resumed`]: crate::Event::Resumed
+    pub const fn connection_generation(&self) -> u64 {
+        self.connection_generation
+    }
+
     /// Shard latency statistics, including average latency and recent heartbeat
     /// latency times.
     ///
@@ -385,6 +565,43 @@ impl<Q> Shard<Q> {
         &self.latency
     }
 
+    /// Number of events received by the shard, by event type.
+    ///
+    /// Counts accumulate across reconnects until reset via
+    /// [`reset_event_counts`].
+    ///
+    /// [`reset_event_counts`]: Self::reset_event_counts
+    pub fn event_counts(&self) -> EventCounts {
+        *self.event_counts
+    }
+
+    /// Reset all [event counts] to zero.
+    ///
+    /// [event counts]: Self::event_counts
+    pub fn reset_event_counts(&mut self) {
+        *self.event_counts = EventCounts::new();
+    }
+
+    /// Phase durations of the most recently established connection, useful
+    /// for diagnosing slow reconnects.
+    ///
+    /// Returns [`None`] if no connection has been established yet.
+    pub const fn last_connect_timings(&self) -> Option<&ConnectTimings> {
+        self.last_connect_timings.as_ref()
+    }
+
+    /// Number of times the shard reconnected because its connection was failed
+    /// or "zombied".
+    ///
+    /// A connection is zombied once [`Config::missed_heartbeats_threshold`]
+    /// consecutive heartbeats went unanswered. Unlike [`latency`], this is not
+    /// reset when reconnecting, making it suitable for metrics.
+    ///
+    /// [`latency`]: Self::latency
+    pub const fn zombied_reconnects(&self) -> u64 {
+        self.zombied_reconnects
+    }
+
     /// Statistics about the number of available commands and when the command
     /// ratelimiter will refresh.
     ///
@@ -431,6 +648,30 @@ impl<Q> Shard<Q> {
             .expect("channel open");
     }
 
+    /// Queue a raw JSON encoded gateway command to be sent to the gateway.
+    ///
+    /// Unlike [`send`], the payload is checked to be a JSON object whose
+    /// opcode may be sent by users: presence update, voice state update, and
+    /// request guild members commands. All other opcodes are rejected, such as
+    /// heartbeat, identify, and resume commands, which are managed by the
+    /// shard. The payload is otherwise sent as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendRawErrorType::ForbiddenOpcode`] error type if the
+    /// command's opcode is rejected.
+    ///
+    /// Returns a [`SendRawErrorType::InvalidPayload`] error type if the
+    /// command is not a JSON object with an opcode.
+    ///
+    /// [`send`]: Self::send
+    pub fn send_raw(&self, json: String) -> Result<(), SendRawError> {
+        validate_raw_command(&json)?;
+        self.send(json);
+
+        Ok(())
+    }
+
     /// Queue a websocket close frame.
     ///
     /// Invalidates the session and shows the application's bot as offline if
@@ -505,6 +746,7 @@ impl<Q> Shard<Q> {
     fn disconnect(&mut self, initiator: CloseInitiator) {
         // May not send any additional WebSocket messages.
         self.heartbeat_interval = None;
+        self.missed_heartbeats = 0;
         self.ratelimiter = None;
         // Abort identify.
         self.identify_rx = None;
@@ -514,6 +756,21 @@ impl<Q> Shard<Q> {
                 reconnect_attempts: 0,
             },
         };
+        if let ShardState::FatallyClosed { code } = self.state {
+            tracing::error!(%code, "fatal close code received, not reconnecting");
+        }
+        if let CloseInitiator::Gateway(Some(close_code)) = initiator {
+            // Session is invalid, drop it so that the shard identifies (through
+            // the queue) instead of resuming again.
+            // https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-close-event-codes
+            if matches!(
+                CloseCode::try_from(close_code),
+                Ok(CloseCode::InvalidSequence | CloseCode::SessionTimedOut)
+            ) {
+                self.resume_url = None;
+                self.session = None;
+            }
+        }
         if let CloseInitiator::Shard(frame) = initiator {
             // Not resuming, drop session and resume URL.
             // https://discord.com/developers/docs/topics/gateway#initiating-a-disconnect
@@ -528,6 +785,48 @@ impl<Q> Shard<Q> {
         }
     }
 
+    /// Either send a heartbeat or, if too many heartbeats went unanswered,
+    /// disconnect the failed or "zombied" connection.
+    fn heartbeat_tick(&mut self) {
+        // Discord never responded after the last heartbeat, connection may be
+        // failed or "zombied", see
+        // https://discord.com/developers/docs/topics/gateway#heartbeat-interval-example-heartbeat-ack
+        // Note that unlike documented *any* event is okay; it does not have to
+        // be a heartbeat ACK.
+        if self.latency.sent().is_some() && !self.heartbeat_interval_event {
+            self.missed_heartbeats = self.missed_heartbeats.saturating_add(1);
+        } else {
+            self.missed_heartbeats = 0;
+        }
+
+        let threshold = self.config.missed_heartbeats_threshold();
+        if self.missed_heartbeats >= threshold {
+            self.zombied_reconnects += 1;
+            tracing::info!(
+                missed_heartbeats = self.missed_heartbeats,
+                threshold,
+                zombied_reconnects = self.zombied_reconnects,
+                "connection is failed or \"zombied\"",
+            );
+            self.disconnect(CloseInitiator::Shard(CloseFrame::RESUME));
+        } else {
+            if self.missed_heartbeats > 0 {
+                tracing::debug!(
+                    missed_heartbeats = self.missed_heartbeats,
+                    threshold,
+                    "heartbeat went unanswered"
+                );
+            }
+            tracing::debug!("sending heartbeat");
+            self.pending = Pending::text(
+                json::to_string(&Heartbeat::new(self.session().map(Session::sequence)))
+                    .expect("serialization cannot fail"),
+                true,
+            );
+            self.heartbeat_interval_event = false;
+        }
+    }
+
     /// Parse a JSON message into an event with minimal data for [processing].
     ///
     /// # Errors
@@ -550,6 +849,60 @@ impl<Q> Shard<Q> {
 }
 
 impl<Q: Queue> Shard<Q> {
+    /// Enqueue the shard in the identify queue.
+    ///
+    /// Every identify, including those after an invalidated session or a
+    /// failed resume, must go through here so that the [`Queue`] can ratelimit
+    /// it.
+    fn queue_identify(&mut self) {
+        tracing::debug!("queueing identify");
+        self.identify_rx = Some(self.config.queue().enqueue(self.id.number()));
+    }
+
+    /// Attempts to schedule an identify once the queue allows it.
+    ///
+    /// Requeues if the queue closed the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Poll::Pending` if no identify is queued or the queue is not ready
+    /// * `Poll::Ready` if an identify command is now pending
+    fn poll_identify(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            let Some(rx) = self.identify_rx.as_mut() else {
+                return Poll::Pending;
+            };
+
+            if ready!(Pin::new(rx).poll(cx)).is_err() {
+                self.queue_identify();
+                continue;
+            }
+
+            tracing::debug!("sending identify");
+
+            self.pending = Pending::text(
+                json::to_string(&Identify::new(IdentifyInfo {
+                    compress: false,
+                    intents: self.config.intents(),
+                    large_threshold: self.config.large_threshold(),
+                    presence: self.config.identify_presence(self.id),
+                    properties: self
+                        .config
+                        .identify_properties()
+                        .cloned()
+                        .unwrap_or_else(default_identify_properties),
+                    shard: Some(self.id),
+                    token: self.config.token().to_owned(),
+                }))
+                .expect("serialization cannot fail"),
+                false,
+            );
+            self.identify_rx = None;
+
+            return Poll::Ready(());
+        }
+    }
+
     /// Attempts to send due commands to the gateway.
     ///
     /// # Returns
@@ -597,23 +950,7 @@ impl<Q: Queue> Shard<Q> {
                 .as_mut()
                 .map_or(false, |heartbeater| heartbeater.poll_tick(cx).is_ready())
             {
-                // Discord never responded after the last heartbeat, connection
-                // is failed or "zombied", see
-                // https://discord.com/developers/docs/topics/gateway#heartbeat-interval-example-heartbeat-ack
-                // Note that unlike documented *any* event is okay; it does not
-                // have to be a heartbeat ACK.
-                if self.latency.sent().is_some() && !self.heartbeat_interval_event {
-                    tracing::info!("connection is failed or \"zombied\"");
-                    self.disconnect(CloseInitiator::Shard(CloseFrame::RESUME));
-                } else {
-                    tracing::debug!("sending heartbeat");
-                    self.pending = Pending::text(
-                        json::to_string(&Heartbeat::new(self.session().map(Session::sequence)))
-                            .expect("serialization cannot fail"),
-                        true,
-                    );
-                    self.heartbeat_interval_event = false;
-                }
+                self.heartbeat_tick();
 
                 continue;
             }
@@ -622,40 +959,8 @@ impl<Q: Queue> Shard<Q> {
                 ratelimiter.poll_available(cx).is_ready()
             });
 
-            if not_ratelimited {
-                if let Some(Poll::Ready(canceled)) = self
-                    .identify_rx
-                    .as_mut()
-                    .map(|rx| Pin::new(rx).poll(cx).map(|r| r.is_err()))
-                {
-                    if canceled {
-                        self.identify_rx = Some(self.config.queue().enqueue(self.id.number()));
-                        continue;
-                    }
-
-                    tracing::debug!("sending identify");
-
-                    self.pending = Pending::text(
-                        json::to_string(&Identify::new(IdentifyInfo {
-                            compress: false,
-                            intents: self.config.intents(),
-                            large_threshold: self.config.large_threshold(),
-                            presence: self.config.presence().cloned(),
-                            properties: self
-                                .config
-                                .identify_properties()
-                                .cloned()
-                                .unwrap_or_else(default_identify_properties),
-                            shard: Some(self.id),
-                            token: self.config.token().to_owned(),
-                        }))
-                        .expect("serialization cannot fail"),
-                        false,
-                    );
-                    self.identify_rx = None;
-
-                    continue;
-                }
+            if not_ratelimited && self.poll_identify(cx).is_ready() {
+                continue;
             }
 
             if not_ratelimited && self.state.is_identified() {
@@ -679,12 +984,21 @@ impl<Q: Queue> Shard<Q> {
     /// Updates the shard's internal state from a gateway event by recording
     /// and/or responding to certain Discord events.
     ///
+    /// `received` is when the event was read from the connection.
+    ///
+    /// Returns the type of the event, if it's known, so that it doesn't need to
+    /// be determined again when parsing the event.
+    ///
     /// # Errors
     ///
     /// Returns a [`ReceiveMessageErrorType::Deserializing`] error type if the
     /// gateway event isn't a recognized structure.
     #[allow(clippy::too_many_lines)]
-    fn process(&mut self, event: &str) -> Result<(), ReceiveMessageError> {
+    fn process(
+        &mut self,
+        event: &str,
+        received: std::time::Instant,
+    ) -> Result<Option<EventTypeFlags>, ReceiveMessageError> {
         let (raw_opcode, maybe_sequence, maybe_event_type) =
             GatewayEventDeserializer::from_json(event)
                 .ok_or_else(|| ReceiveMessageError {
@@ -699,7 +1013,15 @@ impl<Q: Queue> Shard<Q> {
             self.heartbeat_interval_event = true;
         }
 
-        match OpCode::from(raw_opcode) {
+        let opcode = OpCode::from(raw_opcode);
+        let event_type = opcode.and_then(|opcode| {
+            EventTypeFlags::try_from((opcode, maybe_event_type.as_deref())).ok()
+        });
+        if let Some(event_type) = event_type {
+            self.event_counts.record(event_type);
+        }
+
+        match opcode {
             Some(OpCode::Dispatch) => {
                 let event_type = maybe_event_type.ok_or_else(|| ReceiveMessageError {
                     kind: ReceiveMessageErrorType::Deserializing {
@@ -743,7 +1065,7 @@ impl<Q: Queue> Shard<Q> {
                 let requested = self.latency.received().is_none() && self.latency.sent().is_some();
                 if requested {
                     tracing::debug!("received heartbeat ack");
-                    self.latency.record_received();
+                    self.latency.record_received(received);
                 } else {
                     tracing::info!("received unrequested heartbeat ack");
                 }
@@ -756,6 +1078,10 @@ impl<Q: Queue> Shard<Q> {
                 let jitter = heartbeat_interval.mul_f64(fastrand::f64());
                 tracing::debug!(?heartbeat_interval, ?jitter, "received hello");
 
+                if let Some(timings) = &mut self.last_connect_timings {
+                    timings.record_hello(received);
+                }
+
                 if self.config().ratelimit_messages() {
                     self.ratelimiter = Some(CommandRatelimiter::new(heartbeat_interval));
                 }
@@ -767,6 +1093,7 @@ impl<Q: Queue> Shard<Q> {
                 // Reset `Latency` since the shard might have connected to a new
                 // remote which invalidates the recorded latencies.
                 self.latency = Latency::new();
+                self.missed_heartbeats = 0;
 
                 if let Some(session) = &self.session {
                     self.pending = Pending::text(
@@ -780,7 +1107,7 @@ impl<Q: Queue> Shard<Q> {
                     );
                     self.state = ShardState::Resuming;
                 } else {
-                    self.identify_rx = Some(self.config.queue().enqueue(self.id.number()));
+                    self.queue_identify();
                 }
             }
             Some(OpCode::InvalidSession) => {
@@ -799,18 +1126,19 @@ impl<Q: Queue> Shard<Q> {
             _ => tracing::info!("received an unknown opcode: {raw_opcode}"),
         }
 
-        Ok(())
+        Ok(event_type)
     }
 }
 
-impl<Q: Queue + Unpin> Stream for Shard<Q> {
-    type Item = Result<Message, ReceiveMessageError>;
-
+impl<Q: Queue> Shard<Q> {
+    /// Poll the connection for the next message, processing it before it's
+    /// returned along with the type of its event, if known.
+    #[allow(clippy::too_many_lines)]
     #[tracing::instrument(fields(id = %self.id), name = "shard", skip_all)]
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let message = loop {
+    fn poll_read(&mut self, cx: &mut Context<'_>) -> Poll<Option<ReadResult>> {
+        let (message, received) = loop {
             match self.state {
-                ShardState::FatallyClosed => {
+                ShardState::FatallyClosed { .. } => {
                     _ = ready!(Pin::new(
                         self.connection
                             .as_mut()
@@ -834,18 +1162,14 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                         tracing::debug!(url = base_url, "connecting to gateway");
 
                         let tls = self.config.tls.clone();
+                        let delay = self
+                            .config
+                            .reconnect_backoff()
+                            .delay(reconnect_attempts.into());
                         self.connection_future = Some(ConnectionFuture(Box::pin(async move {
-                            let secs = 2u8.saturating_pow(reconnect_attempts.into());
-                            time::sleep(Duration::from_secs(secs.into())).await;
-
-                            Ok(ClientBuilder::new()
-                                .uri(&uri)
-                                .expect("URL should be valid")
-                                .limits(Limits::unlimited())
-                                .connector(&tls)
-                                .connect()
-                                .await?
-                                .0)
+                            time::sleep(delay).await;
+
+                            connect(&uri, &tls).await
                         })));
                     }
 
@@ -853,8 +1177,15 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                         ready!(Pin::new(&mut self.connection_future.as_mut().unwrap().0).poll(cx));
                     self.connection_future = None;
                     match res {
-                        Ok(connection) => {
+                        Ok((connection, timings)) => {
+                            tracing::debug!(?timings, "connected to gateway");
                             self.connection = Some(connection);
+                            self.last_connect_timings = Some(timings);
+                            self.read_waker.take_woken_at();
+                            if self.connection_generation > 0 {
+                                self.event_counts.record_reconnect();
+                            }
+                            self.connection_generation += 1;
                             self.state = ShardState::Identifying;
                             #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
                             self.inflater.reset();
@@ -862,7 +1193,7 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                         Err(source) => {
                             self.resume_url = None;
                             self.state = ShardState::Disconnected {
-                                reconnect_attempts: reconnect_attempts + 1,
+                                reconnect_attempts: reconnect_attempts.saturating_add(1),
                             };
 
                             return Poll::Ready(Some(Err(ReceiveMessageError {
@@ -879,11 +1210,22 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                 self.disconnect(CloseInitiator::Transport);
                 self.connection = None;
 
-                return Poll::Ready(Some(Ok(Message::ABNORMAL_CLOSE)));
+                return Poll::Ready(Some(Ok((ReadMessage::ABNORMAL_CLOSE, None))));
             }
 
-            match ready!(Pin::new(self.connection.as_mut().unwrap()).poll_next(cx)) {
+            let read_waker = self.read_waker.register(cx.waker());
+            let mut read_cx = Context::from_waker(&read_waker);
+            match ready!(Pin::new(self.connection.as_mut().unwrap()).poll_next(&mut read_cx)) {
                 Some(Ok(message)) => {
+                    // Timestamp the message before decompressing and processing
+                    // it so that heartbeat latency excludes that work, as well
+                    // as the time until the shard was polled if it was woken
+                    // to read the message.
+                    let received = self
+                        .read_waker
+                        .take_woken_at()
+                        .unwrap_or_else(|| Instant::now().into_std());
+
                     #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
                     if message.is_binary() {
                         if let Some(decompressed) = self
@@ -891,11 +1233,14 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                             .inflate(message.as_payload())
                             .map_err(ReceiveMessageError::from_compression)?
                         {
-                            break Message::Text(decompressed);
+                            break (ReadMessage::Decompressed(decompressed), received);
                         };
                     }
-                    if let Some(message) = Message::from_websocket_msg(&message) {
-                        break message;
+                    if message.is_text() {
+                        break (ReadMessage::Text(message), received);
+                    }
+                    if let Some(Message::Close(frame)) = Message::from_websocket_msg(&message) {
+                        break (ReadMessage::Close(frame), received);
                     }
                 }
                 // Discord, against recommendations from the WebSocket spec,
@@ -918,7 +1263,7 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                 }
                 Some(Err(_)) => {
                     self.disconnect(CloseInitiator::Transport);
-                    return Poll::Ready(Some(Ok(Message::ABNORMAL_CLOSE)));
+                    return Poll::Ready(Some(Ok((ReadMessage::ABNORMAL_CLOSE, None))));
                 }
                 None => {
                     _ = ready!(Pin::new(self.connection.as_mut().unwrap()).poll_close(cx));
@@ -932,24 +1277,167 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
             }
         };
 
-        match &message {
-            Message::Close(frame) => {
-                // tokio-websockets automatically replies to the close message.
-                tracing::debug!(?frame, "received WebSocket close message");
-                // Don't run `disconnect` if we initiated the close.
-                if !self.state.is_disconnected() {
-                    self.disconnect(CloseInitiator::Gateway(frame.as_ref().map(|f| f.code)));
-                }
+        let event_type = if let ReadMessage::Close(frame) = &message {
+            // tokio-websockets automatically replies to the close message.
+            tracing::debug!(?frame, "received WebSocket close message");
+            self.event_counts.record_close_frame();
+            // Don't run `disconnect` if we initiated the close.
+            if !self.state.is_disconnected() {
+                self.disconnect(CloseInitiator::Gateway(frame.as_ref().map(|f| f.code)));
             }
-            Message::Text(event) => {
-                self.process(event)?;
+
+            None
+        } else {
+            self.process(message.as_text(), received)?
+        };
+
+        Poll::Ready(Some(Ok((message, event_type))))
+    }
+
+    /// Poll for the next wanted event, parsing it directly from the message
+    /// read from the connection.
+    fn poll_next_event(
+        &mut self,
+        cx: &mut Context<'_>,
+        wanted_event_types: EventTypeFlags,
+    ) -> Poll<Option<Result<Event, ReceiveMessageError>>> {
+        loop {
+            let (message, event_type) = match ready!(self.poll_read(cx)) {
+                Some(Ok((ReadMessage::Close(frame), _))) => {
+                    return Poll::Ready(Some(Ok(Event::GatewayClose(frame))))
+                }
+                Some(Ok(read)) => read,
+                Some(Err(source)) => return Poll::Ready(Some(Err(source))),
+                None => return Poll::Ready(None),
+            };
+
+            let event = match event_type {
+                Some(event_type) => {
+                    json::parse_as(message.as_text(), event_type, wanted_event_types)
+                }
+                None => Ok(None),
+            };
+            self.reclaim(message);
+
+            if let Some(event) = event.transpose() {
+                return Poll::Ready(Some(event.map(Into::into)));
             }
         }
+    }
+
+    /// Reclaim the buffer of a message read from the connection, if any, to
+    /// reuse it for the next message.
+    #[cfg_attr(
+        not(any(feature = "zlib-stock", feature = "zlib-simd")),
+        allow(unused_variables, clippy::unused_self, clippy::needless_pass_by_value)
+    )]
+    fn reclaim(&mut self, message: ReadMessage) {
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        if let ReadMessage::Decompressed(buffer) = message {
+            self.inflater.reclaim(buffer);
+        }
+    }
+
+    /// Consumes and returns the next wanted [`Event`] or `None` if the shard
+    /// has fatally closed.
+    ///
+    /// Equivalent to [`StreamExt::next_event`], except that events are parsed
+    /// directly from the shard's buffers instead of from a copied [`Message`].
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe. The returned future only holds onto a
+    /// reference to the shard, so dropping it will never lose a value.
+    ///
+    /// [`StreamExt::next_event`]: crate::StreamExt::next_event
+    pub async fn next_event(
+        &mut self,
+        wanted_event_types: EventTypeFlags,
+    ) -> Option<Result<Event, ReceiveMessageError>> {
+        future::poll_fn(|cx| self.poll_next_event(cx, wanted_event_types)).await
+    }
+}
+
+impl<Q: Queue + Unpin> Stream for Shard<Q> {
+    type Item = Result<Message, ReceiveMessageError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let message = match ready!(self.poll_read(cx)) {
+            Some(Ok((message, _))) => message,
+            Some(Err(source)) => return Poll::Ready(Some(Err(source))),
+            None => return Poll::Ready(None),
+        };
+
+        let message = if let ReadMessage::Close(frame) = message {
+            Message::Close(frame)
+        } else {
+            let text = Message::Text(message.as_text().to_owned());
+            self.reclaim(message);
+
+            text
+        };
 
         Poll::Ready(Some(Ok(message)))
     }
 }
 
+/// Establish a WebSocket connection with the Gateway, timing each phase.
+///
+/// The phases are run separately instead of via [`ClientBuilder::connect`] so
+/// that slow reconnects can be attributed to name resolution, the network, the
+/// TLS handshake or the WebSocket upgrade.
+async fn connect(uri: &str, tls: &Connector) -> ConnectionResult {
+    let parsed = uri.parse::<Uri>().expect("URL should be valid");
+    // IPv6 hosts are enclosed in brackets, which neither name resolution nor
+    // TLS server names accept.
+    let host = parsed
+        .host()
+        .ok_or(WebsocketError::CannotResolveHost)?
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let secure = parsed.scheme_str() != Some("ws");
+    let port = parsed.port_u16().unwrap_or(if secure { 443 } else { 80 });
+
+    let start = Instant::now();
+    let addrs = tokio::net::lookup_host((host, port))
+        .instrument(tracing::debug_span!("dns", host, port))
+        .await?
+        .collect::<Vec<_>>();
+    let dns = start.elapsed();
+
+    let start = Instant::now();
+    let stream = TcpStream::connect(addrs.as_slice())
+        .instrument(tracing::debug_span!("tcp"))
+        .await?;
+    let tcp = start.elapsed();
+
+    let start = Instant::now();
+    let connector = if secure { tls } else { &Connector::Plain };
+    let stream = connector
+        .wrap(host, stream)
+        .instrument(tracing::debug_span!("tls", secure))
+        .await?;
+    let tls = if secure {
+        start.elapsed()
+    } else {
+        Duration::ZERO
+    };
+
+    let start = Instant::now();
+    let (connection, _) = ClientBuilder::new()
+        .uri(uri)
+        .expect("URL should be valid")
+        .limits(Limits::unlimited())
+        .connect_on(stream)
+        .instrument(tracing::debug_span!("websocket_upgrade"))
+        .await?;
+    let upgrade = start.elapsed();
+
+    tracing::debug!(?dns, ?tcp, ?tls, ?upgrade, "established connection");
+
+    Ok((connection, ConnectTimings::new(dns, tcp, tls, upgrade)))
+}
+
 /// Default identify properties to use when the user hasn't customized it in
 /// [`Config::identify_properties`].
 ///
@@ -958,12 +1446,754 @@ fn default_identify_properties() -> IdentifyProperties {
     IdentifyProperties::new("twilight.rs", "twilight.rs", OS)
 }
 
+/// Ensure a raw gateway command is a JSON object with an opcode users may send.
+fn validate_raw_command(json: &str) -> Result<(), SendRawError> {
+    let opcode = json
+        .trim_start()
+        .starts_with('{')
+        .then(|| GatewayEventDeserializer::from_json(json))
+        .flatten()
+        .map(|deserializer| deserializer.op())
+        .ok_or(SendRawError {
+            kind: SendRawErrorType::InvalidPayload,
+            source: None,
+        })?;
+
+    match OpCode::from(opcode) {
+        Some(OpCode::PresenceUpdate | OpCode::RequestGuildMembers | OpCode::VoiceStateUpdate) => {
+            Ok(())
+        }
+        _ => Err(SendRawError {
+            kind: SendRawErrorType::ForbiddenOpcode { opcode },
+            source: None,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Shard;
+    use super::{connect, CloseCode, CloseInitiator, Message, Queue, Shard, ShardState};
+    use crate::error::{ReceiveMessageErrorType, SendRawErrorType};
+    use crate::CloseFrame;
+    use crate::{
+        BackoffPolicy, ConfigBuilder, Event, EventType, EventTypeFlags, Intents, Session, ShardId,
+    };
+    use futures_core::Stream;
+    use futures_sink::Sink;
     use static_assertions::{assert_impl_all, assert_not_impl_any};
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        future,
+        pin::Pin,
+        sync::{Arc, Mutex},
+        task::Poll,
+        time::{Duration, Instant},
+    };
+    use tokio::{
+        io::{AsyncRead, AsyncWrite},
+        net::TcpListener,
+        sync::oneshot,
+        time,
+    };
+    use tokio_stream::StreamExt;
+    use tokio_websockets::{
+        Connector, Message as WebsocketMessage, ServerBuilder, WebSocketStream,
+    };
+    use twilight_model::gateway::{
+        payload::outgoing::update_presence::UpdatePresencePayload,
+        presence::{ActivityType, MinimalActivity, Status},
+    };
 
     assert_impl_all!(Shard: Debug, Send);
     assert_not_impl_any!(Shard: Sync);
+
+    const HELLO: &str = r#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+
+    fn dispatch(event_type: &str, sequence: u64) -> String {
+        format!(r#"{{"op":0,"s":{sequence},"t":"{event_type}","d":{{}}}}"#)
+    }
+
+    /// Queue recording enqueued shards, which only proceed once allowed.
+    #[derive(Debug, Default)]
+    struct RecordingQueue(Mutex<Vec<(u32, oneshot::Sender<()>)>>);
+
+    impl RecordingQueue {
+        /// IDs of the shards that have been enqueued.
+        fn enqueued(&self) -> Vec<u32> {
+            self.0.lock().unwrap().iter().map(|(id, _)| *id).collect()
+        }
+
+        /// Allow all enqueued shards to proceed.
+        fn allow(&self) {
+            for (_, tx) in self.0.lock().unwrap().drain(..) {
+                _ = tx.send(());
+            }
+        }
+    }
+
+    impl Queue for RecordingQueue {
+        fn enqueue(&self, id: u32) -> oneshot::Receiver<()> {
+            let (tx, rx) = oneshot::channel();
+            self.0.lock().unwrap().push((id, tx));
+
+            rx
+        }
+    }
+
+    /// Poll the shard's identify queue once, returning the pending identify
+    /// payload, if any.
+    async fn poll_identify(shard: &mut Shard<RecordingQueue>) -> Option<String> {
+        _ = future::poll_fn(|cx| Poll::Ready(shard.poll_identify(cx))).await;
+
+        match shard.pending.take()?.gateway_event? {
+            Message::Text(json) => Some(json),
+            Message::Close(_) => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn identify_after_failed_resume() {
+        let config = ConfigBuilder::new("token".to_owned(), Intents::empty())
+            .session(Session::new(1, "session".to_owned()))
+            .queue(RecordingQueue::default())
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        shard.process(HELLO, Instant::now()).unwrap();
+        assert_eq!(ShardState::Resuming, shard.state());
+        assert!(shard.config().queue().enqueued().is_empty());
+        shard.pending = None;
+
+        // Resume failed.
+        shard
+            .process(r#"{"op":9,"d":false}"#, Instant::now())
+            .unwrap();
+        assert!(shard.session().is_none());
+        assert!(shard.state().is_disconnected());
+        shard.pending = None;
+
+        shard.process(HELLO, Instant::now()).unwrap();
+        assert_eq!(vec![0], shard.config().queue().enqueued());
+        assert!(poll_identify(&mut shard).await.is_none());
+
+        shard.config().queue().allow();
+        let identify = poll_identify(&mut shard).await.unwrap();
+        assert!(identify.contains(r#""op":2"#));
+        assert!(shard.identify_rx.is_none());
+    }
+
+    /// Test that the presence function is called with the identifying shard's
+    /// ID, taking precedence over the static presence.
+    #[tokio::test]
+    async fn identify_presence_fn() {
+        fn presence(name: String) -> UpdatePresencePayload {
+            UpdatePresencePayload::new(
+                vec![MinimalActivity {
+                    kind: ActivityType::Playing,
+                    name,
+                    url: None,
+                }
+                .into()],
+                false,
+                None,
+                Status::Online,
+            )
+            .unwrap()
+        }
+
+        let presence_fn = Arc::new(|shard_id: ShardId| presence(format!("shard {shard_id}")));
+
+        for shard_id in [ShardId::new(0, 2), ShardId::new(1, 2)] {
+            let config = ConfigBuilder::new("token".to_owned(), Intents::empty())
+                .presence(presence("static".to_owned()))
+                .presence_fn(presence_fn.clone())
+                .queue(RecordingQueue::default())
+                .build();
+            let mut shard = Shard::with_config(shard_id, config);
+
+            shard.process(HELLO, Instant::now()).unwrap();
+            shard.config().queue().allow();
+            let identify = poll_identify(&mut shard).await.unwrap();
+
+            let name = format!(r#""name":"shard {shard_id}""#);
+            assert!(identify.contains(&name), "{identify}");
+            assert!(!identify.contains("static"));
+        }
+    }
+
+    #[tokio::test]
+    async fn identify_after_session_timed_out() {
+        let config = ConfigBuilder::new("token".to_owned(), Intents::empty())
+            .session(Session::new(1, "session".to_owned()))
+            .queue(RecordingQueue::default())
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        shard.process(HELLO, Instant::now()).unwrap();
+        assert_eq!(ShardState::Resuming, shard.state());
+        shard.pending = None;
+
+        shard.disconnect(CloseInitiator::Gateway(Some(4009)));
+        assert!(shard.session().is_none());
+        assert!(shard.state().is_disconnected());
+
+        shard.process(HELLO, Instant::now()).unwrap();
+        assert_eq!(vec![0], shard.config().queue().enqueued());
+        assert!(poll_identify(&mut shard).await.is_none());
+
+        shard.config().queue().allow();
+        assert!(poll_identify(&mut shard).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn requeue_identify_when_canceled() {
+        let config = ConfigBuilder::new("token".to_owned(), Intents::empty())
+            .queue(RecordingQueue::default())
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        shard.process(HELLO, Instant::now()).unwrap();
+        assert_eq!(vec![0], shard.config().queue().enqueued());
+
+        // Drop the sender, closing the channel.
+        shard.config().queue().0.lock().unwrap().clear();
+        assert!(poll_identify(&mut shard).await.is_none());
+        assert_eq!(vec![0], shard.config().queue().enqueued());
+
+        shard.config().queue().allow();
+        assert!(poll_identify(&mut shard).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn replaying() {
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        assert!(!shard.is_replaying());
+
+        shard
+            .process(
+                r#"{"op":10,"d":{"heartbeat_interval":41250}}"#,
+                Instant::now(),
+            )
+            .unwrap();
+        assert_eq!(ShardState::Resuming, shard.state());
+
+        shard
+            .process(&dispatch("TYPING_START", 2), Instant::now())
+            .unwrap();
+        assert!(shard.is_replaying());
+        assert_eq!(Some(2), shard.session().map(Session::sequence));
+
+        shard
+            .process(&dispatch("MESSAGE_DELETE", 3), Instant::now())
+            .unwrap();
+        assert!(shard.is_replaying());
+        assert_eq!(Some(3), shard.session().map(Session::sequence));
+
+        shard
+            .process(&dispatch("RESUMED", 4), Instant::now())
+            .unwrap();
+        assert!(!shard.is_replaying());
+
+        shard
+            .process(&dispatch("MESSAGE_DELETE", 5), Instant::now())
+            .unwrap();
+        assert!(!shard.is_replaying());
+        assert_eq!(Some(5), shard.session().map(Session::sequence));
+    }
+
+    /// Send a text message over a server-side WebSocket connection.
+    async fn send<S: AsyncRead + AsyncWrite + Unpin>(
+        ws: &mut WebSocketStream<S>,
+        message: WebsocketMessage,
+    ) {
+        let mut ws = Pin::new(ws);
+        future::poll_fn(|cx| ws.as_mut().poll_ready(cx))
+            .await
+            .unwrap();
+        ws.as_mut().start_send(message).unwrap();
+        future::poll_fn(|cx| ws.as_mut().poll_flush(cx))
+            .await
+            .unwrap();
+    }
+
+    async fn send_text<S: AsyncRead + AsyncWrite + Unpin>(ws: &mut WebSocketStream<S>, text: &str) {
+        send(ws, WebsocketMessage::text(text.to_owned())).await;
+    }
+
+    async fn send_close<S: AsyncRead + AsyncWrite + Unpin>(ws: &mut WebSocketStream<S>, code: u16) {
+        send(
+            ws,
+            Message::Close(Some(CloseFrame::new(code, ""))).into_websocket_msg(),
+        )
+        .await;
+    }
+
+    /// Test that the connection generation is incremented on every new
+    /// connection, here caused by the Gateway requesting a reconnect.
+    #[tokio::test]
+    async fn connection_generation() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for reconnect in [true, false] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+                send_text(&mut ws, HELLO).await;
+                if reconnect {
+                    send_text(&mut ws, r#"{"op":7,"d":null}"#).await;
+                }
+
+                // Read until the shard closes the connection.
+                while let Some(Ok(_)) = ws.next().await {}
+            }
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        assert_eq!(0, shard.connection_generation());
+
+        let mut hellos = 0;
+        time::timeout(Duration::from_secs(10), async {
+            while hellos < 2 {
+                if let Message::Text(json) = shard.next().await.unwrap().unwrap() {
+                    if json == HELLO {
+                        hellos += 1;
+                        assert_eq!(hellos, shard.connection_generation());
+                    }
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        shard.close(CloseFrame::NORMAL);
+        while !matches!(shard.next().await, Some(Ok(Message::Close(_)))) {}
+        server.await.unwrap();
+        assert_eq!(2, shard.connection_generation());
+    }
+
+    /// Test that resuming on a new connection emits [`Event::Resumed`] once the
+    /// Gateway confirms the resume.
+    #[tokio::test]
+    async fn resumed_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for reconnect in [true, false] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+                send_text(&mut ws, HELLO).await;
+                if reconnect {
+                    send_text(&mut ws, r#"{"op":7,"d":null}"#).await;
+                } else {
+                    while let Some(Ok(message)) = ws.next().await {
+                        if message
+                            .as_text()
+                            .is_some_and(|text| text.contains(r#""op":6"#))
+                        {
+                            break;
+                        }
+                    }
+                    send_text(&mut ws, &dispatch("RESUMED", 2)).await;
+                }
+
+                // Read until the shard closes the connection.
+                while let Some(Ok(_)) = ws.next().await {}
+            }
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        let event = time::timeout(Duration::from_secs(10), async {
+            loop {
+                // Close events, such as of the requested reconnect, are
+                // always returned.
+                match shard.next_event(EventTypeFlags::RESUMED).await {
+                    Some(Ok(Event::GatewayClose(_))) => {}
+                    event => break event,
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert!(matches!(event, Some(Ok(Event::Resumed))));
+        assert_eq!(2, shard.connection_generation());
+        assert!(!shard.is_replaying());
+        assert_eq!(Some(2), shard.session().map(Session::sequence));
+
+        shard.close(CloseFrame::NORMAL);
+        while !matches!(shard.next().await, Some(Ok(Message::Close(_)))) {}
+        server.await.unwrap();
+    }
+
+    /// Test that the shard reconnects after a transient close code, but
+    /// becomes fatally closed after a fatal one.
+    #[tokio::test]
+    async fn fatal_close_code() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for code in [CloseCode::UnknownError, CloseCode::AuthenticationFailed] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+                send_text(&mut ws, HELLO).await;
+                send_close(&mut ws, code as u16).await;
+
+                // Read until the shard closes the connection.
+                while let Some(Ok(_)) = ws.next().await {}
+            }
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        let mut close_codes = Vec::new();
+        time::timeout(Duration::from_secs(10), async {
+            while let Some(message) = shard.next().await {
+                if let Message::Close(frame) = message.unwrap() {
+                    close_codes.push(frame.unwrap().code);
+                }
+            }
+        })
+        .await
+        .unwrap();
+        server.await.unwrap();
+
+        assert_eq!(vec![4000, 4004], close_codes);
+        assert_eq!(2, shard.connection_generation());
+        assert_eq!(
+            ShardState::FatallyClosed {
+                code: CloseCode::AuthenticationFailed
+            },
+            shard.state()
+        );
+    }
+
+    /// Test that the backoff policy applies to reconnects after close frames
+    /// and after network errors.
+    #[tokio::test]
+    async fn reconnect_backoff() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for code in [
+                CloseCode::UnknownError,
+                CloseCode::UnknownError,
+                CloseCode::AuthenticationFailed,
+            ] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+                send_text(&mut ws, HELLO).await;
+                send_close(&mut ws, code as u16).await;
+
+                // Read until the shard closes the connection.
+                while let Some(Ok(_)) = ws.next().await {}
+            }
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .reconnect_backoff(BackoffPolicy::ZERO)
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        // The default policy would wait 3 seconds for these connections.
+        let started = Instant::now();
+        time::timeout(Duration::from_secs(10), async {
+            while shard.next().await.is_some() {}
+        })
+        .await
+        .unwrap();
+        server.await.unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(3, shard.connection_generation());
+
+        // Nothing is listening on the address anymore, so connecting fails.
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .reconnect_backoff(BackoffPolicy::ZERO)
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            let error = shard.next().await.unwrap().unwrap_err();
+            assert!(matches!(error.kind(), ReceiveMessageErrorType::Reconnect));
+        }
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(
+            ShardState::Disconnected {
+                reconnect_attempts: 3
+            },
+            shard.state()
+        );
+    }
+
+    /// Test that received events, close frames and reconnects are counted.
+    #[tokio::test]
+    async fn event_counts() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for reconnect in [true, false] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+                send_text(&mut ws, HELLO).await;
+                send_text(&mut ws, &dispatch("MESSAGE_CREATE", 2)).await;
+                if reconnect {
+                    send_text(&mut ws, &dispatch("MESSAGE_CREATE", 3)).await;
+                    send_text(&mut ws, r#"{"op":7,"d":null}"#).await;
+                }
+
+                // Read until the shard closes the connection.
+                while let Some(Ok(_)) = ws.next().await {}
+            }
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        assert_eq!(0, shard.event_counts().total());
+
+        time::timeout(Duration::from_secs(10), async {
+            while shard.event_counts().get(EventType::MessageCreate) < 3 {
+                shard.next().await.unwrap().unwrap();
+            }
+        })
+        .await
+        .unwrap();
+
+        let counts = shard.event_counts();
+        assert_eq!(2, counts.get(EventType::GatewayHello));
+        assert_eq!(1, counts.get(EventType::GatewayReconnect));
+        assert_eq!(3, counts.get(EventType::MessageCreate));
+        assert_eq!(6, counts.total());
+        assert_eq!(1, counts.reconnects());
+
+        shard.reset_event_counts();
+        assert_eq!(0, shard.event_counts().total());
+        assert_eq!(0, shard.event_counts().reconnects());
+
+        shard.close(CloseFrame::NORMAL);
+        while !matches!(shard.next().await, Some(Ok(Message::Close(_)))) {}
+        server.await.unwrap();
+    }
+
+    /// Test that connection timings are recorded once connected and completed
+    /// by the Hello.
+    #[tokio::test]
+    async fn last_connect_timings() {
+        const HELLO_DELAY: Duration = Duration::from_millis(50);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+            time::sleep(HELLO_DELAY).await;
+            send_text(&mut ws, HELLO).await;
+
+            // Read until the shard closes the connection.
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        assert!(shard.last_connect_timings().is_none());
+
+        let started = Instant::now();
+        time::timeout(Duration::from_secs(10), async {
+            loop {
+                if let Message::Text(json) = shard.next().await.unwrap().unwrap() {
+                    if json == HELLO {
+                        break;
+                    }
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        let elapsed = started.elapsed();
+
+        let timings = shard.last_connect_timings().unwrap();
+        assert_eq!(Duration::ZERO, timings.tls());
+        assert!(timings.hello().unwrap() >= HELLO_DELAY);
+        assert!(timings.total() <= elapsed);
+
+        shard.close(CloseFrame::NORMAL);
+        while !matches!(shard.next().await, Some(Ok(Message::Close(_)))) {}
+        server.await.unwrap();
+    }
+
+    /// Test that connecting to an IPv6 address strips the brackets enclosing
+    /// it before resolving it.
+    #[tokio::test]
+    async fn connect_ipv6() {
+        let listener = TcpListener::bind("[::1]:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            ServerBuilder::new().accept(stream).await.unwrap();
+        });
+
+        connect(&format!("ws://{addr}"), &Connector::Plain)
+            .await
+            .unwrap();
+
+        server.await.unwrap();
+    }
+
+    /// Send a heartbeat, simulating it being flushed.
+    fn tick(shard: &mut Shard) {
+        shard.heartbeat_tick();
+        if shard
+            .pending
+            .take()
+            .map_or(false, |pending| pending.is_heartbeat)
+        {
+            shard.latency.record_sent();
+        }
+    }
+
+    #[tokio::test]
+    async fn zombied() {
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .missed_heartbeats_threshold(3)
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        shard
+            .process(
+                r#"{"op":10,"d":{"heartbeat_interval":41250}}"#,
+                Instant::now(),
+            )
+            .unwrap();
+        shard
+            .process(&dispatch("RESUMED", 2), Instant::now())
+            .unwrap();
+        assert_eq!(ShardState::Active, shard.state());
+
+        tick(&mut shard);
+        shard.process(r#"{"op":11}"#, Instant::now()).unwrap();
+        tick(&mut shard);
+        assert_eq!(0, shard.missed_heartbeats);
+
+        // Withhold ACKs.
+        tick(&mut shard);
+        tick(&mut shard);
+        assert_eq!(2, shard.missed_heartbeats);
+        assert_eq!(ShardState::Active, shard.state());
+        assert_eq!(0, shard.zombied_reconnects());
+
+        shard.heartbeat_tick();
+        assert!(shard.state().is_disconnected());
+        assert_eq!(1, shard.zombied_reconnects());
+        assert!(shard.heartbeat_interval.is_none());
+        assert!(shard.session().is_some());
+    }
+
+    /// Test that heartbeat latency is measured until the acknowledgement
+    /// arrived rather than until the shard was next polled, so a slow event
+    /// loop doesn't inflate it.
+    #[tokio::test]
+    async fn latency_excludes_consumer_delay() {
+        const CONSUMER_DELAY: Duration = Duration::from_millis(200);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+            send_text(&mut ws, HELLO).await;
+            ack_rx.await.unwrap();
+            send_text(&mut ws, r#"{"op":11}"#).await;
+
+            // Read until the shard closes the connection.
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .proxy_url(format!("ws://{addr}"))
+            .session(Session::new(1, "session".to_owned()))
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+        while !matches!(shard.next().await, Some(Ok(Message::Text(json))) if json == HELLO) {}
+
+        tick(&mut shard);
+        // Poll the shard once so that it waits to read the acknowledgement.
+        let pending =
+            future::poll_fn(|cx| Poll::Ready(Pin::new(&mut shard).poll_next(cx).is_pending()))
+                .await;
+        assert!(pending);
+
+        ack_tx.send(()).unwrap();
+        // Simulate a slow consumer only polling the shard again later.
+        time::sleep(CONSUMER_DELAY).await;
+        assert!(matches!(
+            shard.next().await,
+            Some(Ok(Message::Text(json))) if json == r#"{"op":11}"#
+        ));
+
+        assert_eq!(1, shard.latency().periods());
+        assert!(shard.latency().recent()[0] < CONSUMER_DELAY);
+
+        shard.close(CloseFrame::NORMAL);
+        while !matches!(shard.next().await, Some(Ok(Message::Close(_)))) {}
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn send_raw() {
+        let mut shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+
+        let presence =
+            r#"{"op":3,"d":{"since":null,"activities":[],"status":"online","afk":false}}"#;
+        shard.send_raw(presence.to_owned()).unwrap();
+        assert_eq!(
+            Some(presence),
+            shard.user_channel.command_rx.try_recv().ok().as_deref()
+        );
+
+        let identify = r#"{"op":2,"d":{"token":"token","intents":0,"properties":{}}}"#;
+        let error = shard.send_raw(identify.to_owned()).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            SendRawErrorType::ForbiddenOpcode { opcode: 2 }
+        ));
+
+        let error = shard
+            .send_raw(r#"{"op":42,"d":null}"#.to_owned())
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            SendRawErrorType::ForbiddenOpcode { opcode: 42 }
+        ));
+
+        let error = shard.send_raw("[3]".to_owned()).unwrap_err();
+        assert!(matches!(error.kind(), SendRawErrorType::InvalidPayload));
+        assert!(shard.user_channel.command_rx.try_recv().is_err());
+    }
 }
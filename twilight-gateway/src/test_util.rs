@@ -0,0 +1,209 @@
+//! Utilities for testing event handlers without connecting to the gateway.
+//!
+//! Event loops written against a [`Stream`] of [`Message`]s, rather than a
+//! [`Shard`] itself, may be fed canned events by a [`FakeShard`] instead. The
+//! [`StreamExt`] extension trait is implemented for both, so consuming events
+//! through [`next_event`] works the same.
+//!
+//! # Examples
+//!
+//! Exercise a handler counting role deletions:
+//!
+//! ```
+//! use futures_core::Stream;
+//! use twilight_gateway::{
+//!     error::ReceiveMessageError, test_util, Event, EventTypeFlags, Message, StreamExt as _,
+//! };
+//! use twilight_model::{gateway::payload::incoming::RoleDelete, id::Id};
+//!
+//! async fn count_role_deletes(
+//!     mut shard: impl Stream<Item = Result<Message, ReceiveMessageError>> + Unpin,
+//! ) -> usize {
+//!     let mut count = 0;
+//!
+//!     while let Some(item) = shard.next_event(EventTypeFlags::ROLE_DELETE).await {
+//!         if let Ok(Event::RoleDelete(_)) = item {
+//!             count += 1;
+//!         }
+//!     }
+//!
+//!     count
+//! }
+//!
+//! # #[tokio::main(flavor = "current_thread")] async fn main() {
+//! let (injector, shard) = test_util::fake_shard();
+//!
+//! for role_id in 1..=3 {
+//!     injector.send(Event::RoleDelete(RoleDelete {
+//!         guild_id: Id::new(1),
+//!         role_id: Id::new(role_id),
+//!     }));
+//! }
+//! drop(injector);
+//!
+//! assert_eq!(3, count_role_deletes(shard).await);
+//! # }
+//! ```
+//!
+//! [`next_event`]: StreamExt::next_event
+//! [`Shard`]: crate::Shard
+//! [`StreamExt`]: crate::StreamExt
+
+use crate::{error::ReceiveMessageError, json, Message};
+use futures_core::Stream;
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+use twilight_model::gateway::event::{DispatchEvent, Event, GatewayEvent};
+
+/// Create a connected [`EventInjector`] and [`FakeShard`] pair.
+///
+/// The fake shard's stream ends once every injector has been dropped.
+pub fn fake_shard() -> (EventInjector, FakeShard) {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    (
+        EventInjector {
+            sequence: Arc::new(AtomicU64::new(0)),
+            tx,
+        },
+        FakeShard { rx },
+    )
+}
+
+/// Sending half injecting events into a [`FakeShard`].
+///
+/// Cloning the injector is cheap and shares its sequence number.
+#[derive(Clone, Debug)]
+pub struct EventInjector {
+    /// Sequence number of the last dispatch event sent.
+    sequence: Arc<AtomicU64>,
+    /// Sender to the fake shard.
+    tx: mpsc::UnboundedSender<Message>,
+}
+
+impl EventInjector {
+    /// Send an event to the fake shard.
+    ///
+    /// The event is serialized into the same payload the gateway sends, with
+    /// dispatch events receiving increasing sequence numbers, and
+    /// [`Event::GatewayClose`] is sent as a close message.
+    ///
+    /// Events sent after the fake shard has been dropped are discarded.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn send(&self, event: Event) {
+        let message = match event {
+            Event::GatewayClose(frame) => Message::Close(frame),
+            event => {
+                let gateway_event = match DispatchEvent::try_from(event) {
+                    Ok(dispatch) => {
+                        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) + 1;
+
+                        GatewayEvent::Dispatch(sequence, dispatch)
+                    }
+                    Err(source) => GatewayEvent::try_from(source.into_event())
+                        .expect("events are either close, dispatch, or gateway events"),
+                };
+
+                Message::Text(
+                    json::to_string(&gateway_event).expect("serializing events is infallible"),
+                )
+            }
+        };
+
+        self.send_message(message);
+    }
+
+    /// Send a raw message to the fake shard.
+    ///
+    /// Useful for testing how handlers deal with payloads that fail to
+    /// deserialize.
+    ///
+    /// Messages sent after the fake shard has been dropped are discarded.
+    pub fn send_message(&self, message: Message) {
+        _ = self.tx.send(message);
+    }
+}
+
+/// Stand-in for a [`Shard`] yielding injected events.
+///
+/// Never yields errors and doesn't reconnect; close messages are passed
+/// through as-is.
+///
+/// [`Shard`]: crate::Shard
+#[derive(Debug)]
+pub struct FakeShard {
+    /// Receiver of injected messages.
+    rx: mpsc::UnboundedReceiver<Message>,
+}
+
+impl Stream for FakeShard {
+    type Item = Result<Message, ReceiveMessageError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx).map(|message| message.map(Ok))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fake_shard, EventInjector, FakeShard};
+    use crate::{EventTypeFlags, Message, StreamExt};
+    use futures_core::Stream;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::{event::Event, payload::incoming::RoleDelete, CloseFrame},
+        id::Id,
+    };
+
+    assert_impl_all!(EventInjector: Clone, Debug, Send, Sync);
+    assert_impl_all!(FakeShard: Debug, Send, Stream, Sync, Unpin);
+
+    #[tokio::test]
+    async fn round_trip() {
+        let (injector, mut shard) = fake_shard();
+        let role_delete = Event::RoleDelete(RoleDelete {
+            guild_id: Id::new(1),
+            role_id: Id::new(2),
+        });
+
+        injector.send(role_delete.clone());
+        injector.send(Event::GatewayReconnect);
+        injector.send_message(Message::Text("{".to_owned()));
+        injector.send(Event::GatewayClose(Some(CloseFrame::NORMAL)));
+        drop(injector);
+
+        let flags = EventTypeFlags::ROLE_DELETE | EventTypeFlags::GATEWAY_RECONNECT;
+        assert_eq!(role_delete, shard.next_event(flags).await.unwrap().unwrap());
+        assert_eq!(
+            Event::GatewayReconnect,
+            shard.next_event(flags).await.unwrap().unwrap()
+        );
+        assert!(shard.next_event(flags).await.unwrap().is_err());
+        assert_eq!(
+            Event::GatewayClose(Some(CloseFrame::NORMAL)),
+            shard.next_event(flags).await.unwrap().unwrap()
+        );
+        assert!(shard.next_event(flags).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn unwanted_skipped() {
+        let (injector, mut shard) = fake_shard();
+
+        injector.send(Event::GatewayReconnect);
+        drop(injector);
+
+        assert!(shard
+            .next_event(EventTypeFlags::ROLE_DELETE)
+            .await
+            .is_none());
+    }
+}
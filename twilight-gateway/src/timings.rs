@@ -0,0 +1,129 @@
+//! Statistics about how long establishing a connection took, useful for
+//! debugging slow reconnects.
+
+use std::time::{Duration, Instant};
+
+/// Durations of the phases of the [`Shard`]'s most recent gateway connection.
+///
+/// Each phase is timed separately so that a slow reconnect may be attributed
+/// to name resolution, the network, the TLS handshake, the WebSocket upgrade,
+/// or Discord itself being slow to send a [`GatewayEvent::Hello`].
+///
+/// May be obtained via [`Shard::last_connect_timings`].
+///
+/// [`GatewayEvent::Hello`]: twilight_model::gateway::event::GatewayEvent::Hello
+/// [`Shard`]: crate::Shard
+/// [`Shard::last_connect_timings`]: crate::Shard::last_connect_timings
+#[derive(Clone, Debug)]
+pub struct ConnectTimings {
+    /// When the connection was established.
+    connected: Instant,
+    /// Duration of resolving the gateway's host name.
+    dns: Duration,
+    /// Duration from the connection being established to receiving a
+    /// [`GatewayEvent::Hello`].
+    ///
+    /// [`GatewayEvent::Hello`]: twilight_model::gateway::event::GatewayEvent::Hello
+    hello: Option<Duration>,
+    /// Duration of opening the TCP connection.
+    tcp: Duration,
+    /// Duration of the TLS handshake.
+    tls: Duration,
+    /// Duration of the WebSocket upgrade request.
+    upgrade: Duration,
+}
+
+impl ConnectTimings {
+    /// Create a new instance from the durations of each connection phase.
+    pub(crate) fn new(dns: Duration, tcp: Duration, tls: Duration, upgrade: Duration) -> Self {
+        Self {
+            connected: Instant::now(),
+            dns,
+            hello: None,
+            tcp,
+            tls,
+            upgrade,
+        }
+    }
+
+    /// Duration of resolving the gateway's host name.
+    pub const fn dns(&self) -> Duration {
+        self.dns
+    }
+
+    /// Duration of opening the TCP connection.
+    pub const fn tcp(&self) -> Duration {
+        self.tcp
+    }
+
+    /// Duration of the TLS handshake.
+    ///
+    /// Zero if the connection is unencrypted, such as when connecting to a
+    /// `ws://` [proxy URL].
+    ///
+    /// [proxy URL]: crate::Config::proxy_url
+    pub const fn tls(&self) -> Duration {
+        self.tls
+    }
+
+    /// Duration of the WebSocket upgrade request.
+    pub const fn websocket_upgrade(&self) -> Duration {
+        self.upgrade
+    }
+
+    /// Duration from the connection being established to receiving a
+    /// [`GatewayEvent::Hello`].
+    ///
+    /// Returns [`None`] if no hello has been received yet.
+    ///
+    /// [`GatewayEvent::Hello`]: twilight_model::gateway::event::GatewayEvent::Hello
+    pub const fn hello(&self) -> Option<Duration> {
+        self.hello
+    }
+
+    /// Total duration of establishing the connection, including waiting for
+    /// a [`GatewayEvent::Hello`] if one has been received.
+    ///
+    /// [`GatewayEvent::Hello`]: twilight_model::gateway::event::GatewayEvent::Hello
+    pub fn total(&self) -> Duration {
+        self.dns + self.tcp + self.tls + self.upgrade + self.hello.unwrap_or_default()
+    }
+
+    /// Record that a hello was received at the given time.
+    ///
+    /// Only the first hello of a connection is recorded.
+    pub(crate) fn record_hello(&mut self, received: Instant) {
+        if self.hello.is_none() {
+            self.hello = Some(received.saturating_duration_since(self.connected));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConnectTimings;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(ConnectTimings: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn record_hello_once() {
+        let mut timings = ConnectTimings::new(
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+            Duration::from_millis(3),
+            Duration::from_millis(4),
+        );
+        assert!(timings.hello().is_none());
+        assert_eq!(Duration::from_millis(10), timings.total());
+
+        let received = timings.connected + Duration::from_millis(5);
+        timings.record_hello(received);
+        assert_eq!(Some(Duration::from_millis(5)), timings.hello());
+        assert_eq!(Duration::from_millis(15), timings.total());
+
+        timings.record_hello(received + Duration::from_secs(1));
+        assert_eq!(Some(Duration::from_millis(5)), timings.hello());
+    }
+}
@@ -0,0 +1,156 @@
+//! Count the allocations made when receiving compressed events.
+//!
+//! Messages are decompressed into a buffer that is reused across messages and
+//! events are parsed directly from it, so receiving an event must allocate
+//! less than receiving a message and parsing it.
+
+#![cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+
+use flate2::{Compress, Compression, FlushCompress};
+use futures_sink::Sink;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    future,
+    pin::Pin,
+    thread,
+};
+use tokio::{net::TcpListener, runtime::Builder, sync::oneshot};
+use tokio_stream::StreamExt as _;
+use tokio_websockets::{Message, ServerBuilder};
+use twilight_gateway::{
+    CloseFrame, ConfigBuilder, Event, EventTypeFlags, Intents, Session, Shard, ShardId, StreamExt,
+};
+
+/// Allocator counting the allocations made on the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: all allocations are delegated to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Number of allocations made on the current thread so far.
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+const BATCH: u64 = 100;
+
+const HELLO: &str = r#"{"t":null,"s":null,"op":10,"d":{"heartbeat_interval":41250,"_trace":["[\"gateway-prd-us-east1-b-0568\",{\"micros\":0.0}]"]}}"#;
+
+/// `MESSAGE_CREATE` payload with a sequence number.
+fn message_create(sequence: u64) -> String {
+    format!(
+        r#"{{"t":"MESSAGE_CREATE","s":{sequence},"op":0,"d":{{"type":0,"tts":false,"timestamp":"2024-08-16T18:00:00.000000+00:00","pinned":false,"mentions":[],"mention_roles":[],"mention_everyone":false,"id":"1274029377318715433","flags":0,"embeds":[],"edited_timestamp":null,"content":"hello world","components":[],"channel_id":"2","author":{{"username":"twilight","public_flags":0,"id":"3","global_name":null,"discriminator":"0","avatar":null}},"attachments":[],"guild_id":"1"}}}}"#
+    )
+}
+
+/// Run a gateway on its own thread, so that its allocations aren't counted,
+/// sending a hello followed by `count` compressed `MESSAGE_CREATE` events.
+fn gateway(listener: std::net::TcpListener, count: u64) -> oneshot::Receiver<()> {
+    let (done_tx, done_rx) = oneshot::channel();
+
+    thread::spawn(move || {
+        let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async move {
+            let listener = TcpListener::from_std(listener).unwrap();
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = ServerBuilder::new().accept(stream).await.unwrap();
+            let mut compress = Compress::new(Compression::default(), true);
+
+            let payloads = [HELLO.to_owned()]
+                .into_iter()
+                .chain((1..=count).map(message_create));
+            for payload in payloads {
+                let mut compressed = Vec::with_capacity(payload.len() * 2);
+                compress
+                    .compress_vec(payload.as_bytes(), &mut compressed, FlushCompress::Sync)
+                    .unwrap();
+
+                let mut ws = Pin::new(&mut ws);
+                future::poll_fn(|cx| ws.as_mut().poll_ready(cx))
+                    .await
+                    .unwrap();
+                ws.as_mut().start_send(Message::binary(compressed)).unwrap();
+                future::poll_fn(|cx| ws.as_mut().poll_flush(cx))
+                    .await
+                    .unwrap();
+            }
+
+            // Read until the shard closes the connection.
+            while let Some(Ok(_)) = ws.next().await {}
+            _ = done_tx.send(());
+        });
+    });
+
+    done_rx
+}
+
+#[tokio::test]
+async fn next_event() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let addr = listener.local_addr().unwrap();
+    let done = gateway(listener, BATCH * 3);
+
+    let config = ConfigBuilder::new(String::new(), Intents::empty())
+        .proxy_url(format!("ws://{addr}"))
+        .session(Session::new(0, "session".to_owned()))
+        .build();
+    let mut shard = Shard::with_config(ShardId::ONE, config);
+
+    // Let the shard connect and grow its buffers before counting.
+    for _ in 0..BATCH {
+        let event = shard.next_event(EventTypeFlags::MESSAGE_CREATE).await;
+        assert!(matches!(event, Some(Ok(Event::MessageCreate(_)))));
+    }
+
+    let before = allocations();
+    for _ in 0..BATCH {
+        let event = shard.next_event(EventTypeFlags::MESSAGE_CREATE).await;
+        assert!(matches!(event, Some(Ok(Event::MessageCreate(_)))));
+    }
+    let borrowed = allocations() - before;
+
+    let before = allocations();
+    for _ in 0..BATCH {
+        let event = StreamExt::next_event(&mut shard, EventTypeFlags::MESSAGE_CREATE).await;
+        assert!(matches!(event, Some(Ok(Event::MessageCreate(_)))));
+    }
+    let owned = allocations() - before;
+
+    // Receiving a message copies it out of the shard's buffer.
+    assert!(
+        borrowed + BATCH as usize <= owned,
+        "{borrowed} + {BATCH} > {owned}"
+    );
+
+    shard.close(CloseFrame::NORMAL);
+    while !matches!(
+        shard.next_event(EventTypeFlags::empty()).await,
+        Some(Ok(Event::GatewayClose(_)))
+    ) {}
+    done.await.unwrap();
+}
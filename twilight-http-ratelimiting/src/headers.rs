@@ -401,6 +401,18 @@ impl RatelimitHeaders {
         matches!(self, Self::Present(_))
     }
 
+    /// Scope of the ratelimit, if the headers contain one.
+    ///
+    /// Discord only sends the scope alongside a 429 response.
+    #[must_use]
+    pub const fn scope(&self) -> Option<RatelimitScope> {
+        match self {
+            Self::Global(global) => global.scope(),
+            Self::None => None,
+            Self::Present(present) => present.scope(),
+        }
+    }
+
     /// Parse headers from an iterator of tuples containing the header name and
     /// value.
     ///
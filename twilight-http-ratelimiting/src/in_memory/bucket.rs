@@ -4,7 +4,11 @@
 //! and respects the global ratelimit.
 
 use super::GlobalLockPair;
-use crate::{headers::RatelimitHeaders, request::Path, ticket::TicketNotifier};
+use crate::{
+    headers::{RatelimitHeaders, RatelimitScope},
+    request::Path,
+    ticket::TicketNotifier,
+};
 use std::{
     collections::HashMap,
     sync::{
@@ -251,6 +255,12 @@ impl BucketQueueTask {
     }
 
     /// Update the bucket's ratelimit state.
+    ///
+    /// Headers of a [shared] ratelimit describe the resource rather than the
+    /// bucket and are therefore only advisory: the bucket is left untouched so
+    /// that its limit isn't overwritten by the resource's.
+    ///
+    /// [shared]: RatelimitScope::Shared
     async fn handle_headers(&self, headers: &RatelimitHeaders) {
         let ratelimits = match headers {
             RatelimitHeaders::Global(global) => {
@@ -260,6 +270,11 @@ impl BucketQueueTask {
                 None
             }
             RatelimitHeaders::None => return,
+            RatelimitHeaders::Present(_) if headers.scope() == Some(RatelimitScope::Shared) => {
+                tracing::debug!(path=?self.path, "shared resource ratelimited, not updating bucket");
+
+                return;
+            }
             RatelimitHeaders::Present(present) => {
                 Some((present.limit(), present.remaining(), present.reset_after()))
             }
@@ -159,3 +159,56 @@ impl Ratelimiter for InMemoryRatelimiter {
         Box::pin(future::ready(Ok(rx)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryRatelimiter;
+    use crate::{request::Path, RatelimitHeaders, Ratelimiter};
+    use std::error::Error;
+
+    /// Headers of a request to a bucket with a limit of 10.
+    const BUCKET: [(&str, &[u8]); 4] = [
+        ("x-ratelimit-limit", b"10"),
+        ("x-ratelimit-remaining", b"9"),
+        ("x-ratelimit-reset", b"1573795260.333"),
+        ("x-ratelimit-reset-after", b"10.000"),
+    ];
+
+    /// Headers of a 429 response for a shared resource with a limit of 1.
+    const SHARED: [(&str, &[u8]); 5] = [
+        ("x-ratelimit-limit", b"1"),
+        ("x-ratelimit-remaining", b"0"),
+        ("x-ratelimit-reset", b"1573795260.333"),
+        ("x-ratelimit-reset-after", b"60.000"),
+        ("x-ratelimit-scope", b"shared"),
+    ];
+
+    /// Test that shared ratelimits don't affect the bucket, while a following
+    /// request's headers do.
+    #[tokio::test]
+    async fn shared_scope() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsId(1);
+
+        let sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+        let headers = RatelimitHeaders::from_pairs(SHARED.into_iter())?;
+        assert!(sender.headers(Some(headers)).is_ok());
+
+        // The next ticket is only granted once the previous headers were
+        // processed.
+        let sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+        let bucket = ratelimiter.bucket(&path).await?.unwrap();
+        assert_eq!(u64::MAX, bucket.limit());
+        assert_eq!(u64::MAX, bucket.remaining());
+
+        let headers = RatelimitHeaders::from_pairs(BUCKET.into_iter())?;
+        assert!(sender.headers(Some(headers)).is_ok());
+
+        let _sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+        let bucket = ratelimiter.bucket(&path).await?.unwrap();
+        assert_eq!(10, bucket.limit());
+        assert_eq!(9, bucket.remaining());
+
+        Ok(())
+    }
+}
@@ -174,6 +174,8 @@ pub enum Path {
     ChannelsIdWebhooks(u64),
     /// Operating on an application's entitlements.
     ApplicationIdEntitlements(u64),
+    /// Operating on an application's role connection metadata records.
+    ApplicationIdRoleConnectionsMetadata(u64),
     /// Operating on an application's SKUs.
     ApplicationIdSKUs(u64),
     /// Operating with the gateway information.
@@ -234,6 +236,9 @@ pub enum Path {
     GuildsIdRoles(u64),
     /// Operating on a role of one of the user's guilds.
     GuildsIdRolesId(u64),
+    /// Operating on the member counts of the roles of one of the user's
+    /// guilds.
+    GuildsIdRolesMemberCounts(u64),
     /// Operating on the guild's scheduled events.
     GuildsIdScheduledEvents(u64),
     /// Operating on a particular guild's scheduled events.
@@ -260,6 +265,8 @@ pub enum Path {
     GuildsIdWidget(u64),
     /// Operating on one of the user's guild's widget.
     GuildsIdWidgetJson(u64),
+    /// Operating on one of the user's guild's widget image.
+    GuildsIdWidgetPng(u64),
     /// Operating on a guild template.
     GuildsTemplatesCode(String),
     /// Operating on an interaction's callback.
@@ -280,6 +287,11 @@ pub enum Path {
     Stickers,
     /// Operating on a sticker.
     UsersId,
+    /// Operating on the user's role connection to an application.
+    ///
+    /// The path only refers to the current user, whose OAuth2 access token is
+    /// a secret, so the bucket is keyed by the application's ID.
+    UsersIdApplicationsIdRoleConnection(u64),
     /// Operating on the user's private channels.
     UsersIdChannels,
     /// Operating on the user's connections.
@@ -357,6 +369,9 @@ impl FromStr for Path {
             | ["applications", id, "guilds", _, "commands", _, "permissions"] => {
                 ApplicationGuildCommandId(parse_id(id)?)
             }
+            ["applications", id, "role-connections", "metadata"] => {
+                ApplicationIdRoleConnectionsMetadata(parse_id(id)?)
+            }
             ["applications", id, "skus"] => ApplicationIdSKUs(parse_id(id)?),
             ["channels", id] => ChannelsId(parse_id(id)?),
             ["channels", id, "followers"] => ChannelsIdFollowers(parse_id(id)?),
@@ -431,6 +446,7 @@ impl FromStr for Path {
             ["guilds", id, "prune"] => GuildsIdPrune(parse_id(id)?),
             ["guilds", id, "regions"] => GuildsIdRegions(parse_id(id)?),
             ["guilds", id, "roles"] => GuildsIdRoles(parse_id(id)?),
+            ["guilds", id, "roles", "member-counts"] => GuildsIdRolesMemberCounts(parse_id(id)?),
             ["guilds", id, "roles", _] => GuildsIdRolesId(parse_id(id)?),
             ["guilds", id, "scheduled-events"] => GuildsIdScheduledEvents(parse_id(id)?),
             ["guilds", id, "scheduled-events", _] => GuildsIdScheduledEventsId(parse_id(id)?),
@@ -451,6 +467,7 @@ impl FromStr for Path {
             ["guilds", id, "webhooks"] => GuildsIdWebhooks(parse_id(id)?),
             ["guilds", id, "widget"] => GuildsIdWidget(parse_id(id)?),
             ["guilds", id, "widget.json"] => GuildsIdWidgetJson(parse_id(id)?),
+            ["guilds", id, "widget.png"] => GuildsIdWidgetPng(parse_id(id)?),
             ["invites", _] => InvitesCode,
             ["interactions", id, _, "callback"] => InteractionCallback(parse_id(id)?),
             ["stage-instances", _] => StageInstances,
@@ -459,6 +476,9 @@ impl FromStr for Path {
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
             ["oauth2", "@me"] => OauthMe,
             ["users", _] => UsersId,
+            ["users", _, "applications", id, "role-connection"] => {
+                UsersIdApplicationsIdRoleConnection(parse_id(id)?)
+            }
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
             ["users", _, "guilds"] => UsersIdGuilds,
@@ -522,6 +542,18 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::GuildsIdWidgetPng(123),
+            Path::from_str("/guilds/123/widget.png")?
+        );
+        assert_eq!(
+            Path::ApplicationIdRoleConnectionsMetadata(123),
+            Path::from_str("/applications/123/role-connections/metadata")?
+        );
+        assert_eq!(
+            Path::UsersIdApplicationsIdRoleConnection(123),
+            Path::from_str("/users/@me/applications/123/role-connection")?
+        );
 
         Ok(())
     }
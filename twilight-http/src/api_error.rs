@@ -12,6 +12,18 @@ pub enum ApiError {
     Message(MessageApiError),
 }
 
+impl ApiError {
+    /// Known JSON error code of the error, if it is a [general] error.
+    ///
+    /// [general]: Self::General
+    pub const fn code(&self) -> Option<ApiErrorCode> {
+        match self {
+            Self::General(inner) => Some(ApiErrorCode::new(inner.code)),
+            Self::Message(_) | Self::Ratelimited(_) => None,
+        }
+    }
+}
+
 impl Display for ApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -22,6 +34,66 @@ impl Display for ApiError {
     }
 }
 
+/// JSON error code of a [`GeneralApiError`].
+///
+/// Only codes that callers commonly need to distinguish are named; others are
+/// available via [`Unknown`].
+///
+/// Refer to [Discord Docs/JSON Error Codes].
+///
+/// [`Unknown`]: Self::Unknown
+/// [Discord Docs/JSON Error Codes]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ApiErrorCode {
+    /// Unknown message.
+    UnknownMessage,
+    /// Unknown webhook.
+    UnknownWebhook,
+    /// Invalid webhook token.
+    ///
+    /// Returned when using the token of an interaction after it expired,
+    /// such as when editing or deleting a followup message more than 15
+    /// minutes after the interaction was received.
+    InvalidWebhookToken,
+    /// Variant value is unknown to the library.
+    Unknown(u64),
+}
+
+impl ApiErrorCode {
+    /// Create an error code from its raw value.
+    pub const fn new(code: u64) -> Self {
+        match code {
+            10008 => Self::UnknownMessage,
+            10015 => Self::UnknownWebhook,
+            50027 => Self::InvalidWebhookToken,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+
+    /// Raw value of the error code.
+    pub const fn get(self) -> u64 {
+        match self {
+            Self::UnknownMessage => 10008,
+            Self::UnknownWebhook => 10015,
+            Self::InvalidWebhookToken => 50027,
+            Self::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+impl From<u64> for ApiErrorCode {
+    fn from(code: u64) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<ApiErrorCode> for u64 {
+    fn from(code: ApiErrorCode) -> Self {
+        code.get()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 pub struct GeneralApiError {
@@ -131,7 +203,8 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, GeneralApiError, MessageApiError, MessageApiErrorEmbedField, RatelimitedApiError,
+        ApiError, ApiErrorCode, GeneralApiError, MessageApiError, MessageApiErrorEmbedField,
+        RatelimitedApiError,
     };
     use serde_test::Token;
 
@@ -158,6 +231,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn api_error_code() {
+        const MAP: &[(ApiErrorCode, u64)] = &[
+            (ApiErrorCode::UnknownMessage, 10008),
+            (ApiErrorCode::UnknownWebhook, 10015),
+            (ApiErrorCode::InvalidWebhookToken, 50027),
+            (ApiErrorCode::Unknown(50035), 50035),
+        ];
+
+        for (kind, code) in MAP {
+            assert_eq!(*kind, ApiErrorCode::from(*code));
+            assert_eq!(*code, u64::from(*kind));
+        }
+
+        let error = ApiError::General(GeneralApiError {
+            code: 10008,
+            message: "Unknown Message".to_owned(),
+        });
+        assert_eq!(Some(ApiErrorCode::UnknownMessage), error.code());
+
+        let error = ApiError::Message(MessageApiError { embed: None });
+        assert!(error.code().is_none());
+    }
+
     #[test]
     fn api_error_message() {
         let expected = ApiError::Message(MessageApiError {
@@ -0,0 +1,184 @@
+use twilight_model::{
+    channel::message::{AllowedMentions, MentionType},
+    id::Id,
+};
+
+/// How a request's allowed mentions are combined with the client's
+/// [default allowed mentions].
+///
+/// Requests that don't specify allowed mentions always use the default.
+///
+/// [default allowed mentions]: super::ClientBuilder::default_allowed_mentions
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MergeStrategy {
+    /// Intersect the request's allowed mentions with the default, which acts
+    /// as a ceiling on what requests may mention.
+    ///
+    /// The merge is performed per mention kind:
+    ///
+    /// - `@everyone` and `@here` are allowed only if both parse
+    ///   [`MentionType::Everyone`].
+    /// - Users are allowed as follows, and roles likewise:
+    ///   - if both parse [`MentionType::Users`] then all users are allowed;
+    ///   - if one parses [`MentionType::Users`] then the other's list of users
+    ///     is allowed;
+    ///   - if both list users then only users in both lists are allowed;
+    ///   - otherwise no users are allowed.
+    /// - The replied user is mentioned only if both allow it.
+    ///
+    /// Requests clearing their allowed mentions use the default.
+    Intersect,
+    /// Replace the default with the request's allowed mentions.
+    ///
+    /// This is the default.
+    #[default]
+    Replace,
+}
+
+/// Intersect a request's allowed mentions with the client's default.
+///
+/// Refer to [`MergeStrategy::Intersect`] for the semantics.
+pub(super) fn intersect(default: &AllowedMentions, request: &AllowedMentions) -> AllowedMentions {
+    let mut parse = Vec::new();
+
+    if default.parse.contains(&MentionType::Everyone)
+        && request.parse.contains(&MentionType::Everyone)
+    {
+        parse.push(MentionType::Everyone);
+    }
+
+    let roles = intersect_ids(
+        (default.parse.contains(&MentionType::Roles), &default.roles),
+        (request.parse.contains(&MentionType::Roles), &request.roles),
+    );
+
+    if roles.is_none() {
+        parse.push(MentionType::Roles);
+    }
+
+    let users = intersect_ids(
+        (default.parse.contains(&MentionType::Users), &default.users),
+        (request.parse.contains(&MentionType::Users), &request.users),
+    );
+
+    if users.is_none() {
+        parse.push(MentionType::Users);
+    }
+
+    AllowedMentions {
+        parse,
+        replied_user: default.replied_user && request.replied_user,
+        roles: roles.unwrap_or_default(),
+        users: users.unwrap_or_default(),
+    }
+}
+
+/// Intersect the IDs allowed by two allowed mentions, given whether each
+/// parses the mention kind and its explicit list of IDs.
+///
+/// Returns [`None`] if all IDs are allowed.
+fn intersect_ids<T>(
+    (default_all, default_ids): (bool, &[Id<T>]),
+    (request_all, request_ids): (bool, &[Id<T>]),
+) -> Option<Vec<Id<T>>> {
+    match (default_all, request_all) {
+        (true, true) => None,
+        (true, false) => Some(request_ids.to_vec()),
+        (false, true) => Some(default_ids.to_vec()),
+        (false, false) => Some(
+            request_ids
+                .iter()
+                .filter(|id| default_ids.contains(id))
+                .copied()
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{intersect, MergeStrategy};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{
+        channel::message::{AllowedMentions, MentionType},
+        id::Id,
+    };
+
+    assert_impl_all!(
+        MergeStrategy: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn everyone() {
+        let everyone = AllowedMentions {
+            parse: vec![MentionType::Everyone],
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(everyone, intersect(&everyone, &everyone));
+        assert_eq!(
+            AllowedMentions::default(),
+            intersect(&AllowedMentions::default(), &everyone)
+        );
+        assert_eq!(
+            AllowedMentions::default(),
+            intersect(&everyone, &AllowedMentions::default())
+        );
+    }
+
+    #[test]
+    fn parse_and_ids() {
+        let parse_all = AllowedMentions {
+            parse: vec![MentionType::Roles, MentionType::Users],
+            ..AllowedMentions::default()
+        };
+        let ids = AllowedMentions {
+            roles: vec![Id::new(1)],
+            users: vec![Id::new(2), Id::new(3)],
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(parse_all, intersect(&parse_all, &parse_all));
+        assert_eq!(ids, intersect(&parse_all, &ids));
+        assert_eq!(ids, intersect(&ids, &parse_all));
+        assert_eq!(
+            AllowedMentions::default(),
+            intersect(&AllowedMentions::default(), &parse_all)
+        );
+
+        let other_ids = AllowedMentions {
+            roles: vec![Id::new(4)],
+            users: vec![Id::new(3), Id::new(5)],
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(
+            AllowedMentions {
+                users: vec![Id::new(3)],
+                ..AllowedMentions::default()
+            },
+            intersect(&ids, &other_ids)
+        );
+    }
+
+    #[test]
+    fn replied_user() {
+        let replied = AllowedMentions {
+            replied_user: true,
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(replied, intersect(&replied, &replied));
+        assert!(!intersect(&AllowedMentions::default(), &replied).replied_user);
+    }
+}
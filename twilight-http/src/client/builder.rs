@@ -1,9 +1,15 @@
 use super::Token;
-use crate::{client::connector, Client};
+use crate::{
+    client::{connector, MergeStrategy},
+    Client,
+};
 use http::header::HeaderMap;
 use hyper_util::rt::TokioExecutor;
 use std::{
-    sync::{atomic::AtomicBool, Arc},
+    sync::{
+        atomic::{AtomicBool, AtomicU64},
+        Arc,
+    },
     time::Duration,
 };
 use twilight_http_ratelimiting::{InMemoryRatelimiter, Ratelimiter};
@@ -14,6 +20,8 @@ use twilight_model::channel::message::AllowedMentions;
 #[must_use = "has no effect if not built into a Client"]
 pub struct ClientBuilder {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    default_allowed_mentions_merge: MergeStrategy,
+    include_request_body_in_errors: bool,
     pub(crate) proxy: Option<Box<str>>,
     pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
@@ -43,26 +51,90 @@ impl ClientBuilder {
         };
 
         Client {
+            application_id: AtomicU64::new(0),
             http,
             default_headers: self.default_headers,
+            include_request_body_in_errors: self.include_request_body_in_errors,
             proxy: self.proxy,
             ratelimiter: self.ratelimiter,
             timeout: self.timeout,
             token_invalidated,
             token: self.token,
             default_allowed_mentions: self.default_allowed_mentions,
+            default_allowed_mentions_merge: self.default_allowed_mentions_merge,
             use_http: self.use_http,
+            webhook_only: false,
         }
     }
 
+    /// Build a [`Client`] that only executes requests carrying their own
+    /// credentials.
+    ///
+    /// Such requests, like executing a webhook by its token or responding to
+    /// an interaction, are sent without an `Authorization` header. Any
+    /// configured token is discarded and all other requests return an
+    /// [`ErrorType::TokenRequired`] error type without being sent.
+    ///
+    /// Useful for services that only deliver messages via webhooks and
+    /// shouldn't hold a bot token.
+    ///
+    /// [`ErrorType::TokenRequired`]: crate::error::ErrorType::TokenRequired
+    pub fn build_webhook_only(mut self) -> Client {
+        self.token = None;
+
+        let mut client = self.build();
+        client.webhook_only = true;
+
+        client
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
+    ///
+    /// Refer to [`default_allowed_mentions_merge`] for how requests that set
+    /// their own allowed mentions are handled.
+    ///
+    /// [`default_allowed_mentions_merge`]: Self::default_allowed_mentions_merge
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
         self.default_allowed_mentions.replace(allowed_mentions);
 
         self
     }
 
+    /// Set how the [default allowed mentions] are merged with the allowed
+    /// mentions of requests that set their own.
+    ///
+    /// Use [`MergeStrategy::Intersect`] to make the default a ceiling on what
+    /// requests may mention, such as to never allow `@everyone`.
+    ///
+    /// Defaults to [`MergeStrategy::Replace`].
+    ///
+    /// [default allowed mentions]: Self::default_allowed_mentions
+    pub const fn default_allowed_mentions_merge(mut self, strategy: MergeStrategy) -> Self {
+        self.default_allowed_mentions_merge = strategy;
+
+        self
+    }
+
+    /// Whether to include the beginning of a request's JSON body in the
+    /// errors of failed requests.
+    ///
+    /// This can help pinpoint which field of a request caused an error. Up to
+    /// [`RequestContext::BODY_LIMIT`] bytes are included, retrievable via
+    /// [`Error::request`]. Multipart form bodies, such as those of requests
+    /// uploading attachments, and headers, including the authorization token,
+    /// are never included.
+    ///
+    /// Defaults to false.
+    ///
+    /// [`Error::request`]: crate::Error::request
+    /// [`RequestContext::BODY_LIMIT`]: crate::error::RequestContext::BODY_LIMIT
+    pub const fn include_request_body_in_errors(mut self, include: bool) -> Self {
+        self.include_request_body_in_errors = include;
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -155,7 +227,9 @@ impl Default for ClientBuilder {
         #[allow(clippy::box_default)]
         Self {
             default_allowed_mentions: None,
+            default_allowed_mentions_merge: MergeStrategy::Replace,
             default_headers: None,
+            include_request_body_in_errors: false,
             proxy: None,
             ratelimiter: Some(Box::new(InMemoryRatelimiter::default())),
             remember_invalid_token: true,
@@ -1,8 +1,11 @@
+mod allowed_mentions;
 mod builder;
 mod connector;
 mod interaction;
 
-pub use self::{builder::ClientBuilder, interaction::InteractionClient};
+pub use self::{
+    allowed_mentions::MergeStrategy, builder::ClientBuilder, interaction::InteractionClient,
+};
 
 use crate::request::application::{
     emoji::{
@@ -12,11 +15,15 @@ use crate::request::application::{
         CreateTestEntitlement, CreateTestEntitlementOwner, DeleteTestEntitlement, GetEntitlements,
         GetSKUs,
     },
+    role_connection::{
+        GetApplicationRoleConnectionMetadataRecords, GetUserApplicationRoleConnection,
+        UpdateApplicationRoleConnectionMetadataRecords, UpdateUserApplicationRoleConnection,
+    },
 };
 #[allow(deprecated)]
 use crate::{
     client::connector::Connector,
-    error::{Error, ErrorType},
+    error::{Error, ErrorType, RequestParts},
     request::{
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
@@ -58,7 +65,8 @@ use crate::{
                 RemoveRoleFromMember, SearchGuildMembers, UpdateGuildMember,
             },
             role::{
-                CreateRole, DeleteRole, GetGuildRoles, GetRole, UpdateRole, UpdateRolePositions,
+                CreateRole, DeleteRole, GetGuildRoleMemberCounts, GetGuildRoles, GetRole,
+                UpdateRole, UpdateRolePositions,
             },
             sticker::{
                 CreateGuildSticker, DeleteGuildSticker, GetGuildSticker, GetGuildStickers,
@@ -69,9 +77,9 @@ use crate::{
             CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
             GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildOnboarding,
             GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions,
-            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetSettings,
-            UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfa,
-            UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
+            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetImage,
+            GetGuildWidgetSettings, UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions,
+            UpdateGuildMfa, UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
         },
         poll::{EndPoll, GetAnswerVoters},
         scheduled_event::{
@@ -88,8 +96,8 @@ use crate::{
             GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
             UpdateCurrentUser,
         },
-        GetCurrentAuthorizationInformation, GetGateway, GetUserApplicationInfo, GetVoiceRegions,
-        Method, Request, UpdateCurrentUserApplication,
+        Form, GetCurrentAuthorizationInformation, GetGateway, GetUserApplicationInfo,
+        GetVoiceRegions, Method, Nullable, Request, UpdateCurrentUserApplication,
     },
     response::ResponseFuture,
     API_VERSION,
@@ -99,12 +107,14 @@ use http::header::{
 };
 use http_body_util::Full;
 use hyper::body::Bytes;
+#[cfg(feature = "image-data")]
+use hyper::body::Incoming;
 use hyper_util::client::legacy::Client as HyperClient;
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     ops::Deref,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
     time::Duration,
@@ -112,6 +122,7 @@ use std::{
 use tokio::time;
 use twilight_http_ratelimiting::Ratelimiter;
 use twilight_model::{
+    application::role_connection::ApplicationRoleConnectionMetadata,
     channel::{message::AllowedMentions, ChannelType},
     guild::{
         auto_moderation::AutoModerationEventType, scheduled_event::PrivacyLevel, MfaLevel,
@@ -238,9 +249,15 @@ impl Deref for Token {
 /// [here]: https://discord.com/developers/applications
 #[derive(Debug)]
 pub struct Client {
+    /// Application ID used by [`Client::interaction_client`].
+    ///
+    /// Zero if unset.
+    application_id: AtomicU64,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    default_allowed_mentions_merge: MergeStrategy,
     default_headers: Option<HeaderMap>,
     http: HyperClient<Connector, Full<Bytes>>,
+    include_request_body_in_errors: bool,
     proxy: Option<Box<str>>,
     ratelimiter: Option<Box<dyn Ratelimiter>>,
     timeout: Duration,
@@ -251,6 +268,10 @@ pub struct Client {
     token_invalidated: Option<Arc<AtomicBool>>,
     token: Option<Token>,
     use_http: bool,
+    /// Whether only requests carrying their own credentials may be executed.
+    ///
+    /// Configured via [`ClientBuilder::build_webhook_only`].
+    webhook_only: bool,
 }
 
 impl Client {
@@ -319,12 +340,143 @@ impl Client {
         InteractionClient::new(self, application_id)
     }
 
+    /// Application ID stored via [`set_application_id`] or
+    /// [`fetch_and_cache_application_id`], if any.
+    ///
+    /// [`fetch_and_cache_application_id`]: Self::fetch_and_cache_application_id
+    /// [`set_application_id`]: Self::set_application_id
+    pub fn application_id(&self) -> Option<Id<ApplicationMarker>> {
+        Id::new_checked(self.application_id.load(Ordering::Relaxed))
+    }
+
+    /// Store the application ID to use with [`interaction_client`].
+    ///
+    /// [`interaction_client`]: Self::interaction_client
+    pub fn set_application_id(&self, application_id: Id<ApplicationMarker>) {
+        self.application_id
+            .store(application_id.get(), Ordering::Relaxed);
+    }
+
+    /// Retrieve the current bot application's ID and store it for use with
+    /// [`interaction_client`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// client.fetch_and_cache_application_id().await?;
+    ///
+    /// // Later in the process...
+    /// let commands = client
+    ///     .interaction_client()?
+    ///     .global_commands()
+    ///     .await?
+    ///     .models()
+    ///     .await?;
+    ///
+    /// println!("there are {} global commands", commands.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the [`current_user_application`] request if it
+    /// failed.
+    ///
+    /// Returns an error of type [`RequestError`] if the response body could
+    /// not be received or deserialized.
+    ///
+    /// [`current_user_application`]: Self::current_user_application
+    /// [`interaction_client`]: Self::interaction_client
+    /// [`RequestError`]: ErrorType::RequestError
+    pub async fn fetch_and_cache_application_id(&self) -> Result<Id<ApplicationMarker>, Error> {
+        let application = self
+            .current_user_application()
+            .await?
+            .model()
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::RequestError,
+                request: None,
+                source: Some(Box::new(source)),
+            })?;
+        self.set_application_id(application.id);
+
+        Ok(application.id)
+    }
+
+    /// Create an interface for using interactions with the stored application
+    /// ID.
+    ///
+    /// The application ID must first be stored via [`set_application_id`] or
+    /// [`fetch_and_cache_application_id`]. Use [`interaction`] to pass the
+    /// application ID explicitly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ApplicationIdUnset`] if no application ID is
+    /// stored.
+    ///
+    /// [`ApplicationIdUnset`]: ErrorType::ApplicationIdUnset
+    /// [`fetch_and_cache_application_id`]: Self::fetch_and_cache_application_id
+    /// [`interaction`]: Self::interaction
+    /// [`set_application_id`]: Self::set_application_id
+    pub fn interaction_client(&self) -> Result<InteractionClient<'_>, Error> {
+        let application_id = self.application_id().ok_or(Error {
+            kind: ErrorType::ApplicationIdUnset,
+            request: None,
+            source: None,
+        })?;
+
+        Ok(self.interaction(application_id))
+    }
+
     /// Get an immutable reference to the default [`AllowedMentions`] for sent
     /// messages.
     pub const fn default_allowed_mentions(&self) -> Option<&AllowedMentions> {
         self.default_allowed_mentions.as_ref()
     }
 
+    /// Get how the default [`AllowedMentions`] are merged with those of a
+    /// request.
+    pub const fn default_allowed_mentions_merge(&self) -> MergeStrategy {
+        self.default_allowed_mentions_merge
+    }
+
+    /// Resolve a request's allowed mentions against the default allowed
+    /// mentions.
+    ///
+    /// The default allowed mentions are used if the request has none or, when
+    /// intersecting, clears them, and are otherwise merged with the request's per the configured
+    /// [`MergeStrategy`]. Merged allowed mentions are stored in `merged`, from
+    /// which the returned value borrows.
+    pub(crate) fn resolve_allowed_mentions<'a>(
+        &'a self,
+        allowed_mentions: Option<Nullable<&'a AllowedMentions>>,
+        merged: &'a mut Option<AllowedMentions>,
+    ) -> Option<Nullable<&'a AllowedMentions>> {
+        let Some(default) = self.default_allowed_mentions.as_ref() else {
+            return allowed_mentions;
+        };
+
+        match (allowed_mentions, self.default_allowed_mentions_merge) {
+            // Clearing the allowed mentions would let the request mention
+            // everyone, so the default remains the ceiling.
+            (None, _) | (Some(Nullable(None)), MergeStrategy::Intersect) => {
+                Some(Nullable(Some(default)))
+            }
+            (Some(Nullable(Some(allowed_mentions))), MergeStrategy::Intersect) => Some(Nullable(
+                Some(merged.insert(allowed_mentions::intersect(default, allowed_mentions))),
+            )),
+            (allowed_mentions, _) => allowed_mentions,
+        }
+    }
+
     /// Get the Ratelimiter used by the client internally.
     ///
     /// This will return `None` only if ratelimit handling
@@ -1014,6 +1166,15 @@ impl Client {
         GetGuildWidget::new(self, guild_id)
     }
 
+    /// Get a guild's widget as a PNG image.
+    ///
+    /// See [Discord Docs/Get Guild Widget Image].
+    ///
+    /// [Discord Docs/Get Guild Widget Image]: https://discord.com/developers/docs/resources/guild#get-guild-widget-image
+    pub const fn guild_widget_image(&self, guild_id: Id<GuildMarker>) -> GetGuildWidgetImage<'_> {
+        GetGuildWidgetImage::new(self, guild_id)
+    }
+
     /// Get a guild's widget settings.
     ///
     /// See [Discord Docs/Get Guild Widget Settings].
@@ -1671,6 +1832,16 @@ impl Client {
         GetGuildRoles::new(self, guild_id)
     }
 
+    /// Get the number of members that have each role of a guild.
+    ///
+    /// The `@everyone` role is not included.
+    pub const fn role_member_counts(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> GetGuildRoleMemberCounts<'_> {
+        GetGuildRoleMemberCounts::new(self, guild_id)
+    }
+
     /// Get a role of a guild.
     pub const fn role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> GetRole<'_> {
         GetRole::new(self, guild_id, role_id)
@@ -1881,6 +2052,13 @@ impl Client {
     }
 
     /// Start a thread in a forum channel.
+    ///
+    /// # Errors
+    ///
+    /// Executing the request returns an error of type [`NameInvalid`] if the
+    /// thread's name's length is incorrect.
+    ///
+    /// [`NameInvalid`]: twilight_validate::channel::ChannelValidationErrorType::NameInvalid
     pub const fn create_forum_thread<'a>(
         &'a self,
         channel_id: Id<ChannelMarker>,
@@ -2494,6 +2672,8 @@ impl Client {
 
     /// Creates a sticker in a guild, and returns the created sticker.
     ///
+    /// The file must be a PNG, APNG, GIF, or Lottie file.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -2505,13 +2685,14 @@ impl Client {
     /// let client = Client::new("my token".to_owned());
     ///
     /// let guild_id = Id::new(1);
+    /// let file = std::fs::read("sticker.png")?;
     /// let sticker = client
     ///     .create_guild_sticker(
     ///         guild_id,
-    ///         &"sticker name",
-    ///         &"sticker description",
-    ///         &"sticker,tags",
-    ///         &[23, 23, 23, 23],
+    ///         "sticker name",
+    ///         "sticker description",
+    ///         "sticker,tags",
+    ///         &file,
     ///     )
     ///     .await?
     ///     .model()
@@ -2525,11 +2706,19 @@ impl Client {
     ///
     /// Returns an error of type [`DescriptionInvalid`] if the length is invalid.
     ///
+    /// Returns an error of type [`FileSizeInvalid`] if the file is empty or
+    /// too large.
+    ///
+    /// Returns an error of type [`FileTypeInvalid`] if the file's format isn't
+    /// supported.
+    ///
     /// Returns an error of type [`NameInvalid`] if the length is invalid.
     ///
     /// Returns an error of type [`TagsInvalid`] if the length is invalid.
     ///
     /// [`DescriptionInvalid`]: twilight_validate::sticker::StickerValidationErrorType::DescriptionInvalid
+    /// [`FileSizeInvalid`]: twilight_validate::sticker::StickerValidationErrorType::FileSizeInvalid
+    /// [`FileTypeInvalid`]: twilight_validate::sticker::StickerValidationErrorType::FileTypeInvalid
     /// [`NameInvalid`]: twilight_validate::sticker::StickerValidationErrorType::NameInvalid
     /// [`TagsInvalid`]: twilight_validate::sticker::StickerValidationErrorType::TagsInvalid
     pub fn create_guild_sticker<'a>(
@@ -2740,6 +2929,100 @@ impl Client {
         GetSKUs::new(self, application_id)
     }
 
+    /// Get an application's role connection metadata records.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let application_id = Id::new(1);
+    ///
+    /// let records = client
+    ///     .get_application_role_connection_metadata_records(application_id)
+    ///     .await?
+    ///     .models()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub const fn get_application_role_connection_metadata_records(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> GetApplicationRoleConnectionMetadataRecords<'_> {
+        GetApplicationRoleConnectionMetadataRecords::new(self, application_id)
+    }
+
+    /// Overwrite an application's role connection metadata records.
+    ///
+    /// Records not included are deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Validation`] if more than
+    /// [`ROLE_CONNECTION_METADATA_COUNT_MAX`] records are provided or a record
+    /// is invalid.
+    ///
+    /// [`ROLE_CONNECTION_METADATA_COUNT_MAX`]: twilight_validate::role_connection::ROLE_CONNECTION_METADATA_COUNT_MAX
+    /// [`Validation`]: crate::error::ErrorType::Validation
+    pub fn update_application_role_connection_metadata_records<'a>(
+        &'a self,
+        application_id: Id<ApplicationMarker>,
+        records: &'a [ApplicationRoleConnectionMetadata],
+    ) -> UpdateApplicationRoleConnectionMetadataRecords<'a> {
+        UpdateApplicationRoleConnectionMetadataRecords::new(self, application_id, records)
+    }
+
+    /// Get the current user's role connection for an application.
+    ///
+    /// The user's OAuth2 access token with the `role_connections.write` scope
+    /// is used to authorize the request instead of the client's token.
+    pub const fn get_user_application_role_connection<'a>(
+        &'a self,
+        application_id: Id<ApplicationMarker>,
+        access_token: &'a str,
+    ) -> GetUserApplicationRoleConnection<'a> {
+        GetUserApplicationRoleConnection::new(self, application_id, access_token)
+    }
+
+    /// Update the current user's role connection for an application.
+    ///
+    /// The user's OAuth2 access token with the `role_connections.write` scope
+    /// is used to authorize the request instead of the client's token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let application_id = Id::new(1);
+    /// let metadata = HashMap::from([("verified".to_owned(), "1".to_owned())]);
+    ///
+    /// client
+    ///     .update_user_application_role_connection(application_id, "access token")
+    ///     .metadata(&metadata)
+    ///     .platform_name("Twilight")
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub const fn update_user_application_role_connection<'a>(
+        &'a self,
+        application_id: Id<ApplicationMarker>,
+        access_token: &'a str,
+    ) -> UpdateUserApplicationRoleConnection<'a> {
+        UpdateUserApplicationRoleConnection::new(self, application_id, access_token)
+    }
+
     /// Gets all emojis associated with an application
     ///
     /// # Examples
@@ -2860,6 +3143,10 @@ impl Client {
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
     /// token has become invalid due to expiration, revocation, etc.
     ///
+    /// Returns an [`ErrorType::TokenRequired`] error type if the client was
+    /// built via [`ClientBuilder::build_webhook_only`] and the request
+    /// requires a token.
+    ///
     /// [`Response`]: super::response::Response
     pub fn request<T>(&self, request: Request) -> ResponseFuture<T> {
         match self.try_request::<T>(request) {
@@ -2873,11 +3160,20 @@ impl Client {
             if token_invalidated.load(Ordering::Relaxed) {
                 return Err(Error {
                     kind: ErrorType::Unauthorized,
+                    request: None,
                     source: None,
                 });
             }
         }
 
+        if self.webhook_only && request.use_authorization_token() {
+            return Err(Error {
+                kind: ErrorType::TokenRequired,
+                request: None,
+                source: None,
+            });
+        }
+
         let Request {
             body,
             form,
@@ -2894,6 +3190,13 @@ impl Client {
         let url = format!("{protocol}://{host}/api/v{API_VERSION}/{path}");
         tracing::debug!(?url);
 
+        let body = body.map(Bytes::from);
+        let parts = RequestParts {
+            body: body.clone().filter(|_| self.include_request_body_in_errors),
+            method,
+            path,
+        };
+
         let mut builder = hyper::Request::builder().method(method.name()).uri(&url);
 
         if use_authorization_token {
@@ -2903,6 +3206,7 @@ impl Client {
 
                     Error {
                         kind: ErrorType::CreatingHeader { name },
+                        request: None,
                         source: Some(Box::new(source)),
                     }
                 })?;
@@ -2914,51 +3218,20 @@ impl Client {
         }
 
         if let Some(headers) = builder.headers_mut() {
-            if let Some(form) = &form {
-                headers.insert(CONTENT_LENGTH, HeaderValue::from(form.len()));
-                if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
-                    headers.insert(CONTENT_TYPE, content_type);
-                }
-            } else if let Some(bytes) = &body {
-                headers.insert(CONTENT_LENGTH, HeaderValue::from(bytes.len()));
-                headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-            } else if matches!(method, Method::Put | Method::Post | Method::Patch) {
-                headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
-            }
-
-            #[cfg(feature = "decompression")]
-            headers.insert(
-                hyper::header::ACCEPT_ENCODING,
-                HeaderValue::from_static("br"),
-            );
-
-            headers.insert(USER_AGENT, HeaderValue::from_static(TWILIGHT_USER_AGENT));
-
-            if let Some(req_headers) = req_headers {
-                for (maybe_name, value) in req_headers {
-                    if let Some(name) = maybe_name {
-                        headers.insert(name, value);
-                    }
-                }
-            }
-
-            if let Some(default_headers) = &self.default_headers {
-                for (name, value) in default_headers {
-                    headers.insert(name, value.clone());
-                }
-            }
+            self.insert_headers(headers, method, body.as_ref(), form.as_ref(), req_headers);
         }
 
         let try_req = if let Some(form) = form {
             builder.body(Full::from(form.build()))
         } else if let Some(bytes) = body {
-            builder.body(Full::from(bytes))
+            builder.body(Full::new(bytes))
         } else {
             builder.body(Full::default())
         };
 
         let inner = self.http.request(try_req.map_err(|source| Error {
             kind: ErrorType::BuildingRequest,
+            request: None,
             source: Some(Box::new(source)),
         })?);
 
@@ -2972,16 +3245,93 @@ impl Client {
         Ok(if let Some(ratelimiter) = &self.ratelimiter {
             let tx_future = ratelimiter.wait_for_ticket(ratelimit_path);
 
-            ResponseFuture::ratelimit(invalid_token, inner, self.timeout, tx_future)
+            ResponseFuture::ratelimit(invalid_token, parts, inner, self.timeout, tx_future)
         } else {
-            ResponseFuture::new(Box::pin(time::timeout(self.timeout, inner)), invalid_token)
+            ResponseFuture::new(
+                Box::pin(time::timeout(self.timeout, inner)),
+                invalid_token,
+                parts,
+            )
         })
     }
+
+    /// Download a file from outside of Discord's API with the client's
+    /// connector and timeout.
+    #[cfg(feature = "image-data")]
+    pub(crate) async fn download(&self, url: &str) -> Result<hyper::Response<Incoming>, Error> {
+        let request = hyper::Request::get(url)
+            .body(Full::default())
+            .map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                request: None,
+                source: Some(Box::new(source)),
+            })?;
+
+        time::timeout(self.timeout, self.http.request(request))
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::RequestTimedOut,
+                request: None,
+                source: Some(Box::new(source)),
+            })?
+            .map_err(|source| Error {
+                kind: ErrorType::RequestError,
+                request: None,
+                source: Some(Box::new(source)),
+            })
+    }
+
+    /// Insert the content, encoding, user agent, and additional headers of a
+    /// request.
+    fn insert_headers(
+        &self,
+        headers: &mut HeaderMap,
+        method: Method,
+        body: Option<&Bytes>,
+        form: Option<&Form>,
+        req_headers: Option<HeaderMap>,
+    ) {
+        if let Some(form) = form {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(form.len()));
+            if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
+                headers.insert(CONTENT_TYPE, content_type);
+            }
+        } else if let Some(bytes) = body {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(bytes.len()));
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        } else if matches!(method, Method::Put | Method::Post | Method::Patch) {
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
+        }
+
+        #[cfg(feature = "decompression")]
+        headers.insert(
+            hyper::header::ACCEPT_ENCODING,
+            HeaderValue::from_static("br"),
+        );
+
+        headers.insert(USER_AGENT, HeaderValue::from_static(TWILIGHT_USER_AGENT));
+
+        if let Some(req_headers) = req_headers {
+            for (maybe_name, value) in req_headers {
+                if let Some(name) = maybe_name {
+                    headers.insert(name, value);
+                }
+            }
+        }
+
+        if let Some(default_headers) = &self.default_headers {
+            for (name, value) in default_headers {
+                headers.insert(name, value.clone());
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Client;
+    use crate::{error::ErrorType, request::TryIntoRequest};
+    use twilight_model::id::Id;
 
     #[test]
     fn client_debug_with_token() {
@@ -2990,4 +3340,62 @@ mod tests {
         );
         assert!(format!("{:?}", Client::builder().build()).contains("token: None"));
     }
+
+    #[test]
+    fn interaction_client_unset() {
+        let client = Client::new(String::new());
+        assert!(client.application_id().is_none());
+
+        let error = client.interaction_client().unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::ApplicationIdUnset));
+    }
+
+    #[test]
+    fn interaction_client_stored_id() {
+        let client = Client::new(String::new());
+        client.set_application_id(Id::new(123));
+        assert_eq!(Some(Id::new(123)), client.application_id());
+
+        let request = client
+            .interaction_client()
+            .unwrap()
+            .global_commands()
+            .try_into_request()
+            .unwrap();
+        assert!(request.path().starts_with("applications/123/commands"));
+    }
+
+    #[test]
+    fn webhook_only_omits_token() {
+        let client = Client::builder()
+            .token("Bot foo".to_owned())
+            .build_webhook_only();
+        assert!(client.token().is_none());
+
+        let request = client
+            .execute_webhook(Id::new(1), "token")
+            .content("test")
+            .try_into_request()
+            .unwrap();
+        assert!(!request.use_authorization_token());
+
+        let request = client
+            .delete_webhook(Id::new(1))
+            .token("token")
+            .try_into_request()
+            .unwrap();
+        assert!(!request.use_authorization_token());
+    }
+
+    #[tokio::test]
+    async fn webhook_only_token_required() {
+        let client = Client::builder().build_webhook_only();
+
+        let error = client
+            .create_message(Id::new(1))
+            .content("test")
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::TokenRequired));
+    }
 }
@@ -1,16 +1,23 @@
-use crate::{api_error::ApiError, json::JsonError, response::StatusCode};
+use crate::{
+    api_error::{ApiError, ApiErrorCode},
+    json::JsonError,
+    request::Method,
+    response::StatusCode,
+};
 use http::Response;
-use hyper::body::Incoming;
+use hyper::body::{Bytes, Incoming};
 use std::{
     error::Error as StdError,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     str,
+    time::Duration,
 };
 
 #[derive(Debug)]
 pub struct Error {
     pub(super) source: Option<Box<dyn StdError + Send + Sync>>,
     pub(super) kind: ErrorType,
+    pub(super) request: Option<Box<RequestContext>>,
 }
 
 impl Error {
@@ -20,6 +27,12 @@ impl Error {
         &self.kind
     }
 
+    /// Information about the request that failed, if the error is an
+    /// [`ErrorType::Response`].
+    pub fn request(&self) -> Option<&RequestContext> {
+        self.request.as_deref()
+    }
+
     /// Consume the error, returning the source error if there is any.
     #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
     pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
@@ -32,9 +45,32 @@ impl Error {
         (self.kind, self.source)
     }
 
+    /// JSON error code of the API's response, if the error is an
+    /// [`ErrorType::Response`] with a general error.
+    pub const fn api_error_code(&self) -> Option<ApiErrorCode> {
+        match &self.kind {
+            ErrorType::Response { error, .. } => error.code(),
+            _ => None,
+        }
+    }
+
+    /// Whether the error was caused by using an expired interaction token.
+    ///
+    /// Interaction tokens are valid for 15 minutes, after which editing or
+    /// deleting the interaction's response and followup messages fails with
+    /// [`ApiErrorCode::InvalidWebhookToken`]. Unlike a response of
+    /// [`ApiErrorCode::UnknownMessage`], the message may still exist.
+    pub const fn is_interaction_token_expired(&self) -> bool {
+        matches!(
+            self.api_error_code(),
+            Some(ApiErrorCode::InvalidWebhookToken)
+        )
+    }
+
     pub(super) fn json(source: JsonError) -> Self {
         Self {
             kind: ErrorType::Json,
+            request: None,
             source: Some(Box::new(source)),
         }
     }
@@ -42,6 +78,7 @@ impl Error {
     pub(super) fn validation(source: impl StdError + Send + Sync + 'static) -> Self {
         Self {
             kind: ErrorType::Validation,
+            request: None,
             source: Some(Box::new(source)),
         }
     }
@@ -50,6 +87,7 @@ impl Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ErrorType::ApplicationIdUnset => f.write_str("no application id is stored"),
             ErrorType::BuildingRequest => f.write_str("failed to build the request"),
             ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
             ErrorType::CreatingHeader { name, .. } => {
@@ -69,6 +107,20 @@ impl Display for Error {
                 }
             }
             ErrorType::RatelimiterTicket => f.write_str("Failed to get ratelimiter ticket"),
+            ErrorType::RatelimitedByProxy {
+                global,
+                retry_after,
+            } => {
+                f.write_str("request was ratelimited")?;
+
+                if *global {
+                    f.write_str(" globally")?;
+                }
+
+                f.write_str(", retry after ")?;
+
+                Debug::fmt(retry_after, f)
+            }
             ErrorType::RequestCanceled => {
                 f.write_str("Request was canceled either before or while being sent")
             }
@@ -78,12 +130,28 @@ impl Display for Error {
                 f.write_str("Response error: status code ")?;
                 Display::fmt(status, f)?;
                 f.write_str(", error: ")?;
+                f.write_str(&String::from_utf8_lossy(body))?;
 
-                f.write_str(&String::from_utf8_lossy(body))
+                if let Some(request) = &self.request {
+                    f.write_str(", request: ")?;
+                    f.write_str(request.method.name())?;
+                    f.write_str(" ")?;
+                    f.write_str(&request.path)?;
+
+                    if let Some(body) = &request.body {
+                        f.write_str(", request body: ")?;
+                        f.write_str(&String::from_utf8_lossy(body))?;
+                    }
+                }
+
+                Ok(())
             }
             ErrorType::ServiceUnavailable { .. } => {
                 f.write_str("api may be temporarily unavailable (received a 503)")
             }
+            ErrorType::TokenRequired => {
+                f.write_str("request requires a token but the client is webhook only")
+            }
             ErrorType::Unauthorized => {
                 f.write_str("token in use is invalid, expired, or is revoked")
             }
@@ -100,9 +168,113 @@ impl StdError for Error {
     }
 }
 
+/// Information about the request that resulted in an [`ErrorType::Response`].
+///
+/// Retrieved via [`Error::request`]. Tokens in the path, such as those of
+/// webhooks and interactions, are redacted. Headers are never included.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RequestContext {
+    pub(crate) body: Option<Vec<u8>>,
+    pub(crate) method: Method,
+    pub(crate) path: String,
+}
+
+impl RequestContext {
+    /// Maximum number of bytes of the request body that are included.
+    pub const BODY_LIMIT: usize = 512;
+
+    /// Create the context of a request.
+    ///
+    /// The body is truncated to at most [`BODY_LIMIT`] bytes.
+    ///
+    /// [`BODY_LIMIT`]: Self::BODY_LIMIT
+    pub(crate) fn new(method: Method, path: &str, body: Option<&[u8]>) -> Self {
+        Self {
+            body: body.map(|body| body[..body.len().min(Self::BODY_LIMIT)].to_vec()),
+            method,
+            path: redact_path(path),
+        }
+    }
+
+    /// Beginning of the JSON body of the request.
+    ///
+    /// Only present if enabled via
+    /// [`ClientBuilder::include_request_body_in_errors`] and the request had a
+    /// JSON body. Multipart form bodies, such as those of requests uploading
+    /// attachments, are never included.
+    ///
+    /// [`ClientBuilder::include_request_body_in_errors`]: crate::client::ClientBuilder::include_request_body_in_errors
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Path of the request, including the query string.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Debug for RequestContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut debug = f.debug_struct("RequestContext");
+
+        if let Some(body) = &self.body {
+            debug.field("body", &String::from_utf8_lossy(body));
+        } else {
+            debug.field("body", &None::<()>);
+        }
+
+        debug
+            .field("method", &self.method)
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+/// Parts of an in-flight request, kept to build its [`RequestContext`] if
+/// the request fails.
+///
+/// Redacting the path and copying the body are deferred until an error
+/// occurs, so successful requests don't pay for them.
+pub(crate) struct RequestParts {
+    pub(crate) body: Option<Bytes>,
+    pub(crate) method: Method,
+    pub(crate) path: String,
+}
+
+impl RequestParts {
+    /// Build the context of the failed request.
+    pub(crate) fn into_context(self) -> RequestContext {
+        RequestContext::new(self.method, &self.path, self.body.as_deref())
+    }
+}
+
+/// Replace the token segment of webhook and interaction paths.
+fn redact_path(path: &str) -> String {
+    let mut segments = path.splitn(3, '/');
+
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some(resource @ ("interactions" | "webhooks")), Some(id), Some(rest)) => {
+            let (_, rest) = rest.split_at(rest.find(['/', '?']).unwrap_or(rest.len()));
+
+            format!("{resource}/{id}/<redacted>{rest}")
+        }
+        _ => path.to_owned(),
+    }
+}
+
 /// Type of [`Error`] that occurred.
 #[non_exhaustive]
 pub enum ErrorType {
+    /// No application ID is stored for [`Client::interaction_client`].
+    ///
+    /// [`Client::interaction_client`]: crate::Client::interaction_client
+    ApplicationIdUnset,
     BuildingRequest,
     ChunkingResponse,
     CreatingHeader {
@@ -113,9 +285,23 @@ pub enum ErrorType {
         body: Vec<u8>,
     },
     RatelimiterTicket,
+    /// Request was ratelimited while the client's ratelimiter is disabled,
+    /// such as when a proxy handles ratelimits.
+    ///
+    /// The retry information is parsed from the response body, falling back
+    /// to the `Retry-After` and `X-RateLimit-Global` headers.
+    RatelimitedByProxy {
+        /// Whether the ratelimit is a global ratelimit.
+        global: bool,
+        /// Amount of time to wait before retrying.
+        retry_after: Duration,
+    },
     RequestCanceled,
     RequestError,
     RequestTimedOut,
+    /// Response had an unsuccessful status code.
+    ///
+    /// Information about the request is available via [`Error::request`].
     Response {
         body: Vec<u8>,
         error: ApiError,
@@ -128,6 +314,13 @@ pub enum ErrorType {
     ServiceUnavailable {
         response: Response<Incoming>,
     },
+    /// Request requires a token but the client only executes requests
+    /// carrying their own credentials.
+    ///
+    /// Refer to [`ClientBuilder::build_webhook_only`] for more information.
+    ///
+    /// [`ClientBuilder::build_webhook_only`]: crate::client::ClientBuilder::build_webhook_only
+    TokenRequired,
     /// Token in use has become revoked or is otherwise invalid.
     ///
     /// This can occur if a bot token is invalidated or an access token expires
@@ -195,6 +388,7 @@ pub enum ErrorType {
 impl Debug for ErrorType {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            Self::ApplicationIdUnset => f.write_str("ApplicationIdUnset"),
             Self::BuildingRequest => f.write_str("BuildingRequest"),
             Self::ChunkingResponse => f.write_str("ChunkingResponse"),
             Self::CreatingHeader { name } => f
@@ -212,6 +406,14 @@ impl Debug for ErrorType {
                 debug.field("body", body).finish()
             }
             Self::RatelimiterTicket => f.write_str("RatelimiterTicket"),
+            Self::RatelimitedByProxy {
+                global,
+                retry_after,
+            } => f
+                .debug_struct("RatelimitedByProxy")
+                .field("global", global)
+                .field("retry_after", retry_after)
+                .finish(),
             Self::RequestCanceled => f.write_str("RequestCanceled"),
             Self::RequestError => f.write_str("RequestError"),
             Self::RequestTimedOut => f.write_str("RequestTimedOut"),
@@ -236,6 +438,7 @@ impl Debug for ErrorType {
                 .debug_struct("ServiceUnavailable")
                 .field("response", response)
                 .finish(),
+            Self::TokenRequired => f.write_str("TokenRequired"),
             Self::Unauthorized => f.write_str("Unauthorized"),
             Self::Validation => f.write_str("Validation"),
         }
@@ -244,11 +447,19 @@ impl Debug for ErrorType {
 
 #[cfg(test)]
 mod tests {
-    use super::ErrorType;
+    use super::{redact_path, Error, ErrorType, RequestContext, RequestParts};
     use crate::{
-        api_error::{ApiError, GeneralApiError},
+        api_error::{ApiError, ApiErrorCode, GeneralApiError},
+        request::Method,
         response::StatusCode,
+        Client,
+    };
+    use hyper::body::Bytes;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
     };
+    use twilight_model::id::Id;
 
     /// Ensure
     #[test]
@@ -332,4 +543,186 @@ mod tests {
             format!("{error:#?}"),
         );
     }
+
+    fn response_error(body: Option<&[u8]>) -> Error {
+        Error {
+            kind: ErrorType::Response {
+                body: br#"{"code": 50035, "message": "Invalid Form Body"}"#.to_vec(),
+                error: ApiError::General(GeneralApiError {
+                    code: 50035,
+                    message: "Invalid Form Body".to_owned(),
+                }),
+                status: StatusCode::new(400),
+            },
+            request: Some(Box::new(
+                RequestParts {
+                    body: body.map(Bytes::copy_from_slice),
+                    method: Method::Post,
+                    path: "channels/1/messages".to_owned(),
+                }
+                .into_context(),
+            )),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn response_display() {
+        let error = response_error(None);
+
+        assert_eq!(
+            r#"Response error: status code 400, error: {"code": 50035, "message": "Invalid Form Body"}, request: POST channels/1/messages"#,
+            error.to_string(),
+        );
+        assert_eq!(
+            Some("channels/1/messages"),
+            error.request().map(RequestContext::path)
+        );
+    }
+
+    #[test]
+    fn response_display_request_body() {
+        let error = response_error(Some(br#"{"content":"ping"}"#));
+
+        assert!(error
+            .to_string()
+            .ends_with(r#"request: POST channels/1/messages, request body: {"content":"ping"}"#));
+    }
+
+    #[test]
+    fn request_body_truncated() {
+        let body = vec![b'a'; RequestContext::BODY_LIMIT * 2];
+        let request = RequestContext::new(Method::Post, "channels/1/messages", Some(&body));
+
+        assert_eq!(Some(&body[..RequestContext::BODY_LIMIT]), request.body());
+    }
+
+    #[test]
+    fn path_redacted() {
+        assert_eq!("channels/1/messages", redact_path("channels/1/messages"));
+        assert_eq!("webhooks/1", redact_path("webhooks/1"));
+        assert_eq!(
+            "webhooks/1/<redacted>?wait=true",
+            redact_path("webhooks/1/token?wait=true")
+        );
+        assert_eq!(
+            "webhooks/1/<redacted>/messages/2",
+            redact_path("webhooks/1/token/messages/2")
+        );
+        assert_eq!(
+            "interactions/1/<redacted>/callback",
+            redact_path("interactions/1/token/callback")
+        );
+    }
+
+    /// Serve a single error response, returning the address of the server.
+    async fn serve_error(status: &'static str, code: u64, message: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            // Read the whole request, including the body of `PATCH` requests,
+            // so that closing the connection doesn't reset it.
+            let body_start = loop {
+                let read = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+
+                if let Some(idx) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+                    break idx + 4;
+                }
+            };
+            let content_length = String::from_utf8_lossy(&request[..body_start])
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or_default();
+
+            while request.len() < body_start + content_length {
+                let read = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            let body = format!(r#"{{"code":{code},"message":"{message}"}}"#);
+            let response = format!(
+                "HTTP/1.1 {status}\r\n\
+                 connection: close\r\n\
+                 content-length: {}\r\n\
+                 content-type: application/json\r\n\
+                 \r\n\
+                 {body}",
+                body.len(),
+            );
+
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        address
+    }
+
+    fn client(address: String) -> Client {
+        Client::builder()
+            .proxy(address, true)
+            .ratelimiter(None)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn interaction_token_expired() {
+        let address = serve_error("401 Unauthorized", 50027, "Invalid Webhook Token").await;
+        let error = client(address)
+            .update_webhook_message(Id::new(1), "token", Id::new(2))
+            .content(Some("edited"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            Some(ApiErrorCode::InvalidWebhookToken),
+            error.api_error_code()
+        );
+        assert!(error.is_interaction_token_expired());
+    }
+
+    #[tokio::test]
+    async fn unknown_message() {
+        let address = serve_error("404 Not Found", 10008, "Unknown Message").await;
+        let error = client(address)
+            .delete_webhook_message(Id::new(1), "token", Id::new(2))
+            .await
+            .unwrap_err();
+
+        assert_eq!(Some(ApiErrorCode::UnknownMessage), error.api_error_code());
+        assert!(!error.is_interaction_token_expired());
+    }
+
+    #[tokio::test]
+    async fn unknown_webhook() {
+        let address = serve_error("404 Not Found", 10015, "Unknown Webhook").await;
+        let error = client(address)
+            .delete_webhook_message(Id::new(1), "token", Id::new(2))
+            .await
+            .unwrap_err();
+
+        assert_eq!(Some(ApiErrorCode::UnknownWebhook), error.api_error_code());
+        assert!(!error.is_interaction_token_expired());
+    }
+
+    #[test]
+    fn api_error_code_other_kind() {
+        let error = Error {
+            kind: ErrorType::Unauthorized,
+            request: None,
+            source: None,
+        };
+
+        assert!(error.api_error_code().is_none());
+        assert!(!error.is_interaction_token_expired());
+    }
 }
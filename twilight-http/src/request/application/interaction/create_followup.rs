@@ -9,7 +9,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
-use std::future::IntoFuture;
+use std::{borrow::Cow, future::IntoFuture};
 use twilight_model::{
     channel::message::{AllowedMentions, Component, Embed, Message, MessageFlags},
     http::attachment::Attachment,
@@ -29,7 +29,7 @@ struct CreateFollowupFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     components: Option<&'a [Component]>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content: Option<&'a str>,
+    content: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<&'a [Embed]>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -173,9 +173,11 @@ impl<'a> CreateFollowup<'a> {
     /// long.
     ///
     /// [`ContentInvalid`]: twilight_validate::message::MessageValidationErrorType::ContentInvalid
-    pub fn content(mut self, content: &'a str) -> Self {
+    pub fn content(mut self, content: impl Into<Cow<'a, str>>) -> Self {
+        let content = content.into();
+
         self.fields = self.fields.and_then(|mut fields| {
-            validate_content(content)?;
+            validate_content(&content)?;
             fields.content = Some(content);
 
             Ok(fields)
@@ -288,12 +290,12 @@ impl TryIntoRequest for CreateFollowup<'_> {
         // interaction token.
         request = request.use_authorization_token(false);
 
-        // Set the default allowed mentions if required.
-        if fields.allowed_mentions.is_none() {
-            if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
-                fields.allowed_mentions = Some(Nullable(Some(allowed_mentions)));
-            }
-        }
+        // Set the default allowed mentions if required, otherwise merge them
+        // with the request's allowed mentions.
+        let mut merged_allowed_mentions = None;
+        fields.allowed_mentions = self
+            .http
+            .resolve_allowed_mentions(fields.allowed_mentions, &mut merged_allowed_mentions);
 
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
@@ -14,6 +14,13 @@ use twilight_model::{
 /// Respond to an interaction, by its ID and token.
 ///
 /// This endpoint is not bound to the application's global rate limit.
+///
+/// If the response's [`data`] contains [`attachments`] then the request is
+/// sent as a multipart form, with the files named by their attachment IDs,
+/// instead of a JSON body.
+///
+/// [`attachments`]: twilight_model::http::interaction::InteractionResponseData::attachments
+/// [`data`]: InteractionResponse::data
 #[must_use = "requests must be configured and executed"]
 pub struct CreateResponse<'a> {
     interaction_id: Id<InteractionMarker>,
@@ -90,10 +97,13 @@ impl TryIntoRequest for CreateResponse<'_> {
 #[cfg(test)]
 mod tests {
     use crate::{client::Client, request::TryIntoRequest};
-    use std::error::Error;
+    use std::{error::Error, str};
     use twilight_http_ratelimiting::Path;
     use twilight_model::{
-        http::interaction::{InteractionResponse, InteractionResponseType},
+        http::{
+            attachment::Attachment,
+            interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+        },
         id::Id,
     };
 
@@ -123,4 +133,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn interaction_callback_attachments() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(InteractionResponseData {
+                attachments: Some(Vec::from([Attachment::from_bytes(
+                    "twilight.png".to_owned(),
+                    b"sparkle".to_vec(),
+                    3,
+                )])),
+                content: Some("look".to_owned()),
+                ..InteractionResponseData::default()
+            }),
+        };
+
+        let req = client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request()?;
+
+        assert!(req.body().is_none());
+        let body = req.form().ok_or("request has no form")?.clone().build();
+        let body = str::from_utf8(&body)?;
+
+        let payload = body
+            .find("Content-Disposition: form-data; name=\"payload_json\"\r\n")
+            .ok_or("form has no payload part")?;
+        let file = body
+            .find(
+                "Content-Disposition: form-data; name=\"files[3]\"; filename=\"twilight.png\"\r\n",
+            )
+            .ok_or("form has no file part")?;
+        assert!(payload < file);
+
+        let json = crate::json::to_vec(&response)?;
+        assert!(body.contains(str::from_utf8(&json)?));
+        assert!(body.contains(r#""attachments":[{"filename":"twilight.png","id":3}]"#));
+        assert!(body.contains("\r\n\r\nsparkle\r\n"));
+
+        Ok(())
+    }
 }
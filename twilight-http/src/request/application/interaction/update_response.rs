@@ -22,7 +22,8 @@ use twilight_model::{
 };
 use twilight_validate::message::{
     attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, MessageValidationError,
+    content as validate_content, embeds as validate_embeds,
+    keep_attachment_ids as validate_keep_attachment_ids, MessageValidationError,
 };
 
 #[derive(Serialize)]
@@ -152,6 +153,33 @@ impl<'a> UpdateResponse<'a> {
         self
     }
 
+    /// Remove all attachments already present in the target message.
+    ///
+    /// Attachments added with [`new_attachments`] are still uploaded. This is
+    /// impossible if it would leave the message empty of `attachments`,
+    /// `content`, or `embeds`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AttachmentsClearedAndKept`] if attachments to
+    /// keep were specified with [`keep_attachment_ids`].
+    ///
+    /// [`AttachmentsClearedAndKept`]: twilight_validate::message::MessageValidationErrorType::AttachmentsClearedAndKept
+    /// [`keep_attachment_ids`]: Self::keep_attachment_ids
+    /// [`new_attachments`]: Self::new_attachments
+    pub fn clear_attachments(mut self) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_keep_attachment_ids(true, self.attachment_manager.ids())?;
+
+            fields.attachments = Some(Nullable(Some(Vec::new())));
+
+            Ok(fields)
+        });
+        self.attachment_manager = self.attachment_manager.set_cleared();
+
+        self
+    }
+
     /// Set the message's list of [`Component`]s.
     ///
     /// Calling this method will clear previous calls.
@@ -287,23 +315,47 @@ impl<'a> UpdateResponse<'a> {
     /// message to keep.
     ///
     /// If called, all unspecified attachments (except ones added with
-    /// [`attachments`]) will be removed from the message. This is impossible if
-    /// it would leave the message empty of `attachments`, `content`, or
-    /// `embeds`. If not called, all attachments will be kept.
+    /// [`new_attachments`]) will be removed from the message. This is
+    /// impossible if it would leave the message empty of `attachments`,
+    /// `content`, or `embeds`. If not called, all attachments will be kept.
     ///
-    /// [`attachments`]: Self::attachments
+    /// # Errors
+    ///
+    /// Returns an error of type [`AttachmentsClearedAndKept`] if
+    /// [`clear_attachments`] was called and `attachment_ids` isn't empty.
+    ///
+    /// [`AttachmentsClearedAndKept`]: twilight_validate::message::MessageValidationErrorType::AttachmentsClearedAndKept
+    /// [`clear_attachments`]: Self::clear_attachments
+    /// [`new_attachments`]: Self::new_attachments
     pub fn keep_attachment_ids(mut self, attachment_ids: &'a [Id<AttachmentMarker>]) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
-            self.attachment_manager = self.attachment_manager.set_ids(attachment_ids.to_vec());
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_keep_attachment_ids(self.attachment_manager.is_cleared(), attachment_ids)?;
 
             // Set an empty list. This will be overwritten in `TryIntoRequest` if
             // the actual list is not empty.
             fields.attachments = Some(Nullable(Some(Vec::new())));
-        }
+
+            Ok(fields)
+        });
+        self.attachment_manager = self.attachment_manager.set_ids(attachment_ids.to_vec());
 
         self
     }
 
+    /// Attach multiple new files to the message, in addition to the existing
+    /// attachments that are kept.
+    ///
+    /// Equivalent to [`attachments`].
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`attachments`].
+    ///
+    /// [`attachments`]: Self::attachments
+    pub fn new_attachments(self, attachments: &'a [Attachment]) -> Self {
+        self.attachments(attachments)
+    }
+
     /// JSON encoded body of any additional request fields.
     ///
     /// If this method is called, all other fields are ignored, except for
@@ -352,12 +404,12 @@ impl TryIntoRequest for UpdateResponse<'_> {
         // interaction token.
         request = request.use_authorization_token(false);
 
-        // Set the default allowed mentions if required.
-        if fields.allowed_mentions.is_none() {
-            if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
-                fields.allowed_mentions = Some(Nullable(Some(allowed_mentions)));
-            }
-        }
+        // Set the default allowed mentions if required, otherwise merge them
+        // with the request's allowed mentions.
+        let mut merged_allowed_mentions = None;
+        fields.allowed_mentions = self
+            .http
+            .resolve_allowed_mentions(fields.allowed_mentions, &mut merged_allowed_mentions);
 
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
@@ -2,3 +2,4 @@ pub mod command;
 pub mod emoji;
 pub mod interaction;
 pub mod monetization;
+pub mod role_connection;
@@ -0,0 +1,84 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::ListBody, Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnectionMetadata,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Get an application's role connection metadata records.
+#[must_use = "requests must be configured and executed"]
+pub struct GetApplicationRoleConnectionMetadataRecords<'a> {
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+}
+
+impl<'a> GetApplicationRoleConnectionMetadataRecords<'a> {
+    pub(crate) const fn new(http: &'a Client, application_id: Id<ApplicationMarker>) -> Self {
+        Self {
+            application_id,
+            http,
+        }
+    }
+}
+
+impl IntoFuture for GetApplicationRoleConnectionMetadataRecords<'_> {
+    type Output = Result<Response<ListBody<ApplicationRoleConnectionMetadata>>, Error>;
+
+    type IntoFuture = ResponseFuture<ListBody<ApplicationRoleConnectionMetadata>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetApplicationRoleConnectionMetadataRecords<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            &Route::GetApplicationRoleConnectionMetadataRecords {
+                application_id: self.application_id.get(),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetApplicationRoleConnectionMetadataRecords;
+    use crate::{client::Client, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(GetApplicationRoleConnectionMetadataRecords<'_>: IntoFuture, Send, Sync, TryIntoRequest);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let req = client
+            .get_application_role_connection_metadata_records(Id::new(1))
+            .try_into_request()?;
+
+        assert!(req.use_authorization_token());
+        assert!(req.body().is_none());
+        assert_eq!(Method::Get, req.method());
+        assert_eq!(
+            &Path::ApplicationIdRoleConnectionsMetadata(1),
+            req.ratelimit_path()
+        );
+        assert_eq!("applications/1/role-connections/metadata", req.path());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{bearer_header, Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnection,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Get the current user's role connection for an application.
+///
+/// An OAuth2 access token for the user with the `role_connections.write`
+/// scope is required, and is used in place of the client's token.
+#[must_use = "requests must be configured and executed"]
+pub struct GetUserApplicationRoleConnection<'a> {
+    access_token: &'a str,
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+}
+
+impl<'a> GetUserApplicationRoleConnection<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        access_token: &'a str,
+    ) -> Self {
+        Self {
+            access_token,
+            application_id,
+            http,
+        }
+    }
+}
+
+impl IntoFuture for GetUserApplicationRoleConnection<'_> {
+    type Output = Result<Response<ApplicationRoleConnection>, Error>;
+
+    type IntoFuture = ResponseFuture<ApplicationRoleConnection>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetUserApplicationRoleConnection<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Request::builder(&Route::GetUserApplicationRoleConnection {
+            application_id: self.application_id.get(),
+        })
+        .use_authorization_token(false)
+        .headers(bearer_header(self.access_token)?)
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetUserApplicationRoleConnection;
+    use crate::{client::Client, request::TryIntoRequest};
+    use http::header::AUTHORIZATION;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(GetUserApplicationRoleConnection<'_>: IntoFuture, Send, Sync, TryIntoRequest);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("bot token".to_owned());
+        let req = client
+            .get_user_application_role_connection(Id::new(1), "access token")
+            .try_into_request()?;
+
+        assert!(!req.use_authorization_token());
+        assert_eq!(
+            Some("Bearer access token"),
+            req.headers()
+                .and_then(|headers| headers.get(AUTHORIZATION))
+                .and_then(|value| value.to_str().ok())
+        );
+        assert_eq!(Method::Get, req.method());
+        assert_eq!(
+            &Path::UsersIdApplicationsIdRoleConnection(1),
+            req.ratelimit_path()
+        );
+        assert!(!format!("{:?}", req.ratelimit_path()).contains("access token"));
+        assert_eq!("users/@me/applications/1/role-connection", req.path());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod get_metadata_records;
+pub mod get_user_role_connection;
+pub mod update_metadata_records;
+pub mod update_user_role_connection;
+
+pub use self::get_metadata_records::GetApplicationRoleConnectionMetadataRecords;
+pub use self::get_user_role_connection::GetUserApplicationRoleConnection;
+pub use self::update_metadata_records::UpdateApplicationRoleConnectionMetadataRecords;
+pub use self::update_user_role_connection::UpdateUserApplicationRoleConnection;
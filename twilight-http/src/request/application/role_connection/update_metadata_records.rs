@@ -0,0 +1,142 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::ListBody, Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnectionMetadata,
+    id::{marker::ApplicationMarker, Id},
+};
+use twilight_validate::role_connection::{
+    metadata as validate_metadata, RoleConnectionValidationError,
+};
+
+/// Overwrite an application's role connection metadata records.
+///
+/// An application may have at most 5 records. Records not present are
+/// deleted.
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateApplicationRoleConnectionMetadataRecords<'a> {
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+    records: Result<&'a [ApplicationRoleConnectionMetadata], RoleConnectionValidationError>,
+}
+
+impl<'a> UpdateApplicationRoleConnectionMetadataRecords<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        records: &'a [ApplicationRoleConnectionMetadata],
+    ) -> Self {
+        let records = validate_metadata(records).map(|()| records);
+
+        Self {
+            application_id,
+            http,
+            records,
+        }
+    }
+}
+
+impl IntoFuture for UpdateApplicationRoleConnectionMetadataRecords<'_> {
+    type Output = Result<Response<ListBody<ApplicationRoleConnectionMetadata>>, Error>;
+
+    type IntoFuture = ResponseFuture<ListBody<ApplicationRoleConnectionMetadata>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateApplicationRoleConnectionMetadataRecords<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let records = self.records.map_err(Error::validation)?;
+
+        Request::builder(&Route::UpdateApplicationRoleConnectionMetadataRecords {
+            application_id: self.application_id.get(),
+        })
+        .json(&records)
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateApplicationRoleConnectionMetadataRecords;
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::{
+        application::role_connection::{
+            ApplicationRoleConnectionMetadata, ApplicationRoleConnectionMetadataType,
+        },
+        id::Id,
+    };
+
+    assert_impl_all!(UpdateApplicationRoleConnectionMetadataRecords<'_>: IntoFuture, Send, Sync, TryIntoRequest);
+
+    fn record(key: &str) -> ApplicationRoleConnectionMetadata {
+        ApplicationRoleConnectionMetadata {
+            description: "description".to_owned(),
+            description_localizations: None,
+            key: key.to_owned(),
+            kind: ApplicationRoleConnectionMetadataType::BooleanEqual,
+            name: "name".to_owned(),
+            name_localizations: None,
+        }
+    }
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let records = [record("verified")];
+        let req = client
+            .update_application_role_connection_metadata_records(Id::new(1), &records)
+            .try_into_request()?;
+
+        assert!(req.use_authorization_token());
+        assert_eq!(Some(crate::json::to_vec(&records)?.as_slice()), req.body());
+        assert_eq!(Method::Put, req.method());
+        assert_eq!(
+            &Path::ApplicationIdRoleConnectionsMetadata(1),
+            req.ratelimit_path()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new(String::new());
+
+        let records = [record("Invalid Key")];
+        let error = client
+            .update_application_role_connection_metadata_records(Id::new(1), &records)
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+
+        let records = [
+            record("a"),
+            record("b"),
+            record("c"),
+            record("d"),
+            record("e"),
+            record("f"),
+        ];
+        let error = client
+            .update_application_role_connection_metadata_records(Id::new(1), &records)
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{bearer_header, Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{collections::HashMap, future::IntoFuture};
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnection,
+    id::{marker::ApplicationMarker, Id},
+};
+use twilight_validate::role_connection::{
+    key as validate_key, metadata_value as validate_metadata_value,
+    platform_name as validate_platform_name, platform_username as validate_platform_username,
+    RoleConnectionValidationError,
+};
+
+#[derive(Serialize)]
+struct UpdateUserApplicationRoleConnectionFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_username: Option<&'a str>,
+}
+
+/// Update the current user's role connection for an application.
+///
+/// An OAuth2 access token for the user with the `role_connections.write`
+/// scope is required, and is used in place of the client's token.
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateUserApplicationRoleConnection<'a> {
+    access_token: &'a str,
+    application_id: Id<ApplicationMarker>,
+    fields: Result<UpdateUserApplicationRoleConnectionFields<'a>, RoleConnectionValidationError>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateUserApplicationRoleConnection<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        access_token: &'a str,
+    ) -> Self {
+        Self {
+            access_token,
+            application_id,
+            fields: Ok(UpdateUserApplicationRoleConnectionFields {
+                metadata: None,
+                platform_name: None,
+                platform_username: None,
+            }),
+            http,
+        }
+    }
+
+    /// Set the user's metadata values, keyed by the application's metadata
+    /// record keys.
+    ///
+    /// Values are stringified and must be at most 100 characters long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Key`] if a key is invalid.
+    ///
+    /// Returns an error of type [`Value`] if a value is too long.
+    ///
+    /// [`Key`]: twilight_validate::role_connection::RoleConnectionValidationErrorType::Key
+    /// [`Value`]: twilight_validate::role_connection::RoleConnectionValidationErrorType::Value
+    pub fn metadata(mut self, metadata: &'a HashMap<String, String>) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            for (key, value) in metadata {
+                validate_key(key)?;
+                validate_metadata_value(key, value)?;
+            }
+
+            fields.metadata = Some(metadata);
+
+            Ok(fields)
+        });
+
+        self
+    }
+
+    /// Set the vanity name of the platform the application has connected.
+    ///
+    /// The name must be at most 50 characters long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`PlatformName`] if the name is too long.
+    ///
+    /// [`PlatformName`]: twilight_validate::role_connection::RoleConnectionValidationErrorType::PlatformName
+    pub fn platform_name(mut self, platform_name: &'a str) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_platform_name(platform_name)?;
+
+            fields.platform_name = Some(platform_name);
+
+            Ok(fields)
+        });
+
+        self
+    }
+
+    /// Set the username on the platform the application has connected.
+    ///
+    /// The username must be at most 100 characters long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`PlatformUsername`] if the username is too
+    /// long.
+    ///
+    /// [`PlatformUsername`]: twilight_validate::role_connection::RoleConnectionValidationErrorType::PlatformUsername
+    pub fn platform_username(mut self, platform_username: &'a str) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_platform_username(platform_username)?;
+
+            fields.platform_username = Some(platform_username);
+
+            Ok(fields)
+        });
+
+        self
+    }
+}
+
+impl IntoFuture for UpdateUserApplicationRoleConnection<'_> {
+    type Output = Result<Response<ApplicationRoleConnection>, Error>;
+
+    type IntoFuture = ResponseFuture<ApplicationRoleConnection>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateUserApplicationRoleConnection<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let fields = self.fields.map_err(Error::validation)?;
+
+        Request::builder(&Route::UpdateUserApplicationRoleConnection {
+            application_id: self.application_id.get(),
+        })
+        .use_authorization_token(false)
+        .headers(bearer_header(self.access_token)?)
+        .json(&fields)
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateUserApplicationRoleConnection;
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
+    use http::header::AUTHORIZATION;
+    use static_assertions::assert_impl_all;
+    use std::{collections::HashMap, error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(UpdateUserApplicationRoleConnection<'_>: IntoFuture, Send, Sync, TryIntoRequest);
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("bot token".to_owned());
+        let metadata = HashMap::from([("verified".to_owned(), "1".to_owned())]);
+        let req = client
+            .update_user_application_role_connection(Id::new(1), "access token")
+            .metadata(&metadata)
+            .platform_name("Twilight")
+            .platform_username("twilight-rs")
+            .try_into_request()?;
+
+        assert!(!req.use_authorization_token());
+        assert_eq!(
+            Some("Bearer access token"),
+            req.headers()
+                .and_then(|headers| headers.get(AUTHORIZATION))
+                .and_then(|value| value.to_str().ok())
+        );
+        assert_eq!(
+            Some(
+                br#"{"metadata":{"verified":"1"},"platform_name":"Twilight","platform_username":"twilight-rs"}"#
+                    .as_ref()
+            ),
+            req.body()
+        );
+        assert_eq!(Method::Put, req.method());
+        assert_eq!(
+            &Path::UsersIdApplicationsIdRoleConnection(1),
+            req.ratelimit_path()
+        );
+        assert!(!format!("{:?}", req.ratelimit_path()).contains("access token"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new(String::new());
+
+        let metadata = HashMap::from([("verified".to_owned(), "1".repeat(101))]);
+        let error = client
+            .update_user_application_role_connection(Id::new(1), "access token")
+            .metadata(&metadata)
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+
+        let error = client
+            .update_user_application_role_connection(Id::new(1), "access token")
+            .platform_name(&"a".repeat(51))
+            .try_into_request()
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Validation));
+    }
+}
@@ -6,6 +6,7 @@ use twilight_model::{
 };
 
 pub struct AttachmentManager<'a> {
+    cleared: bool,
     files: Vec<&'a Attachment>,
     ids: Vec<Id<AttachmentMarker>>,
 }
@@ -13,6 +14,7 @@ pub struct AttachmentManager<'a> {
 impl<'a> AttachmentManager<'a> {
     pub const fn new() -> Self {
         Self {
+            cleared: false,
             files: Vec::new(),
             ids: Vec::new(),
         }
@@ -49,10 +51,25 @@ impl<'a> AttachmentManager<'a> {
             .collect()
     }
 
+    pub fn ids(&self) -> &[Id<AttachmentMarker>] {
+        &self.ids
+    }
+
+    pub const fn is_cleared(&self) -> bool {
+        self.cleared
+    }
+
     pub fn is_empty(&self) -> bool {
         self.files.is_empty() && self.ids.is_empty()
     }
 
+    #[must_use = "has no effect if not built into a Form"]
+    pub const fn set_cleared(mut self) -> Self {
+        self.cleared = true;
+
+        self
+    }
+
     #[must_use = "has no effect if not built into a Form"]
     pub fn set_files(mut self, files: Vec<&'a Attachment>) -> Self {
         self.files = files;
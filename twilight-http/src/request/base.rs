@@ -132,7 +132,8 @@ impl RequestBuilder {
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
-    /// This is primarily useful for executing webhooks.
+    /// This is primarily useful for executing webhooks and for requests
+    /// authorized with a user's OAuth2 bearer token.
     pub fn use_authorization_token(mut self, use_authorization_token: bool) -> Self {
         if let Ok(request) = self.0.as_mut() {
             request.use_authorization_token = use_authorization_token;
@@ -9,7 +9,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
-use std::future::IntoFuture;
+use std::{borrow::Cow, future::IntoFuture};
 use twilight_model::{
     channel::message::{
         AllowedMentions, Component, Embed, Message, MessageFlags, MessageReference,
@@ -37,7 +37,7 @@ pub(crate) struct CreateMessageFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     components: Option<&'a [Component]>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content: Option<&'a str>,
+    content: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<&'a [Embed]>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -185,9 +185,11 @@ impl<'a> CreateMessage<'a> {
     /// long.
     ///
     /// [`ContentInvalid`]: twilight_validate::message::MessageValidationErrorType::ContentInvalid
-    pub fn content(mut self, content: &'a str) -> Self {
+    pub fn content(mut self, content: impl Into<Cow<'a, str>>) -> Self {
+        let content = content.into();
+
         self.fields = self.fields.and_then(|mut fields| {
-            validate_content(content)?;
+            validate_content(&content)?;
             fields.content.replace(content);
 
             Ok(fields)
@@ -409,12 +411,12 @@ impl TryIntoRequest for CreateMessage<'_> {
             channel_id: self.channel_id.get(),
         });
 
-        // Set the default allowed mentions if required.
-        if fields.allowed_mentions.is_none() {
-            if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
-                fields.allowed_mentions = Some(Nullable(Some(allowed_mentions)));
-            }
-        }
+        // Set the default allowed mentions if required, otherwise merge them
+        // with the request's allowed mentions.
+        let mut merged_allowed_mentions = None;
+        fields.allowed_mentions = self
+            .http
+            .resolve_allowed_mentions(fields.allowed_mentions, &mut merged_allowed_mentions);
 
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
@@ -439,3 +441,110 @@ impl TryIntoRequest for CreateMessage<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::CreateMessage;
+    use crate::{
+        client::{Client, MergeStrategy},
+        request::TryIntoRequest,
+    };
+    use std::error::Error;
+    use twilight_model::{
+        channel::message::{AllowedMentions, MentionType},
+        id::{marker::ChannelMarker, Id},
+    };
+
+    const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+
+    fn client_with_default(strategy: MergeStrategy) -> Client {
+        Client::builder()
+            .token("token".to_owned())
+            .default_allowed_mentions(AllowedMentions {
+                parse: vec![MentionType::Users],
+                ..AllowedMentions::default()
+            })
+            .default_allowed_mentions_merge(strategy)
+            .build()
+    }
+
+    #[test]
+    fn allowed_mentions_request_only() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let allowed_mentions = AllowedMentions {
+            parse: vec![MentionType::Everyone],
+            ..AllowedMentions::default()
+        };
+
+        let actual = CreateMessage::new(&client, CHANNEL_ID)
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request()?;
+
+        let expected = br#"{"allowed_mentions":{"parse":["everyone"]}}"#;
+        assert_eq!(Some(&expected[..]), actual.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_mentions_default_only() -> Result<(), Box<dyn Error>> {
+        let expected = br#"{"allowed_mentions":{"parse":["users"]}}"#;
+
+        for strategy in [MergeStrategy::Intersect, MergeStrategy::Replace] {
+            let client = client_with_default(strategy);
+            let actual = CreateMessage::new(&client, CHANNEL_ID).try_into_request()?;
+
+            assert_eq!(Some(&expected[..]), actual.body());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_mentions_both() -> Result<(), Box<dyn Error>> {
+        let allowed_mentions = AllowedMentions {
+            parse: vec![MentionType::Everyone],
+            users: vec![Id::new(2)],
+            ..AllowedMentions::default()
+        };
+
+        let client = client_with_default(MergeStrategy::Replace);
+        let actual = CreateMessage::new(&client, CHANNEL_ID)
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request()?;
+
+        let expected = br#"{"allowed_mentions":{"parse":["everyone"],"users":["2"]}}"#;
+        assert_eq!(Some(&expected[..]), actual.body());
+
+        let client = client_with_default(MergeStrategy::Intersect);
+        let actual = CreateMessage::new(&client, CHANNEL_ID)
+            .allowed_mentions(Some(&allowed_mentions))
+            .try_into_request()?;
+
+        let expected = br#"{"allowed_mentions":{"parse":[],"users":["2"]}}"#;
+        assert_eq!(Some(&expected[..]), actual.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowed_mentions_cleared() -> Result<(), Box<dyn Error>> {
+        let client = client_with_default(MergeStrategy::Replace);
+        let actual = CreateMessage::new(&client, CHANNEL_ID)
+            .allowed_mentions(None)
+            .try_into_request()?;
+
+        let expected = br#"{"allowed_mentions":null}"#;
+        assert_eq!(Some(&expected[..]), actual.body());
+
+        let client = client_with_default(MergeStrategy::Intersect);
+        let actual = CreateMessage::new(&client, CHANNEL_ID)
+            .allowed_mentions(None)
+            .try_into_request()?;
+
+        let expected = br#"{"allowed_mentions":{"parse":["users"]}}"#;
+        assert_eq!(Some(&expected[..]), actual.body());
+
+        Ok(())
+    }
+}
@@ -20,7 +20,8 @@ use twilight_model::{
 };
 use twilight_validate::message::{
     attachment as validate_attachment, components as validate_components,
-    content as validate_content, embeds as validate_embeds, MessageValidationError,
+    content as validate_content, embeds as validate_embeds,
+    keep_attachment_ids as validate_keep_attachment_ids, MessageValidationError,
 };
 
 #[derive(Serialize)]
@@ -157,6 +158,33 @@ impl<'a> UpdateMessage<'a> {
         self
     }
 
+    /// Remove all attachments already present in the target message.
+    ///
+    /// Attachments added with [`new_attachments`] are still uploaded. This is
+    /// impossible if it would leave the message empty of `attachments`,
+    /// `content`, `embeds`, or `sticker_ids`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AttachmentsClearedAndKept`] if attachments to
+    /// keep were specified with [`keep_attachment_ids`].
+    ///
+    /// [`AttachmentsClearedAndKept`]: twilight_validate::message::MessageValidationErrorType::AttachmentsClearedAndKept
+    /// [`keep_attachment_ids`]: Self::keep_attachment_ids
+    /// [`new_attachments`]: Self::new_attachments
+    pub fn clear_attachments(mut self) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_keep_attachment_ids(true, self.attachment_manager.ids())?;
+
+            fields.attachments = Some(Nullable(Some(Vec::new())));
+
+            Ok(fields)
+        });
+        self.attachment_manager = self.attachment_manager.set_cleared();
+
+        self
+    }
+
     /// Set the message's list of [`Component`]s.
     ///
     /// Calling this method will clear previous calls.
@@ -272,23 +300,48 @@ impl<'a> UpdateMessage<'a> {
     /// message to keep.
     ///
     /// If called, all unspecified attachments (except ones added with
-    /// [`attachments`]) will be removed from the message. This is impossible if
-    /// it would leave the message empty of `attachments`, `content`, `embeds`,
-    /// or `sticker_ids`. If not called, all attachments will be kept.
+    /// [`new_attachments`]) will be removed from the message. This is
+    /// impossible if it would leave the message empty of `attachments`,
+    /// `content`, `embeds`, or `sticker_ids`. If not called, all attachments
+    /// will be kept.
     ///
-    /// [`attachments`]: Self::attachments
+    /// # Errors
+    ///
+    /// Returns an error of type [`AttachmentsClearedAndKept`] if
+    /// [`clear_attachments`] was called and `attachment_ids` isn't empty.
+    ///
+    /// [`AttachmentsClearedAndKept`]: twilight_validate::message::MessageValidationErrorType::AttachmentsClearedAndKept
+    /// [`clear_attachments`]: Self::clear_attachments
+    /// [`new_attachments`]: Self::new_attachments
     pub fn keep_attachment_ids(mut self, attachment_ids: &'a [Id<AttachmentMarker>]) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
-            self.attachment_manager = self.attachment_manager.set_ids(attachment_ids.to_vec());
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_keep_attachment_ids(self.attachment_manager.is_cleared(), attachment_ids)?;
 
             // Set an empty list. This will be overwritten in `TryIntoRequest` if
             // the actual list is not empty.
             fields.attachments = Some(Nullable(Some(Vec::new())));
-        }
+
+            Ok(fields)
+        });
+        self.attachment_manager = self.attachment_manager.set_ids(attachment_ids.to_vec());
 
         self
     }
 
+    /// Attach multiple new files to the message, in addition to the existing
+    /// attachments that are kept.
+    ///
+    /// Equivalent to [`attachments`].
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`attachments`].
+    ///
+    /// [`attachments`]: Self::attachments
+    pub fn new_attachments(self, attachments: &'a [Attachment]) -> Self {
+        self.attachments(attachments)
+    }
+
     /// JSON encoded body of any additional request fields.
     ///
     /// If this method is called, all other fields are ignored, except for
@@ -333,12 +386,12 @@ impl TryIntoRequest for UpdateMessage<'_> {
             message_id: self.message_id.get(),
         });
 
-        // Set the default allowed mentions if required.
-        if fields.allowed_mentions.is_none() {
-            if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
-                fields.allowed_mentions = Some(Nullable(Some(allowed_mentions)));
-            }
-        }
+        // Set the default allowed mentions if required, otherwise merge them
+        // with the request's allowed mentions.
+        let mut merged_allowed_mentions = None;
+        fields.allowed_mentions = self
+            .http
+            .resolve_allowed_mentions(fields.allowed_mentions, &mut merged_allowed_mentions);
 
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
@@ -369,7 +422,8 @@ impl TryIntoRequest for UpdateMessage<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::error::Error;
+    use std::{error::Error, str};
+    use twilight_validate::message::MessageValidationErrorType;
 
     #[test]
     fn clear_attachment() -> Result<(), Box<dyn Error>> {
@@ -392,4 +446,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn keep_and_new_attachments() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+        let attachments = [Attachment::from_bytes(
+            "twilight.png".to_owned(),
+            b"sparkle".to_vec(),
+            0,
+        )];
+
+        let request = UpdateMessage::new(&client, Id::new(1), Id::new(2))
+            .keep_attachment_ids(&[Id::new(3), Id::new(4)])
+            .new_attachments(&attachments)
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        let body = request.form().ok_or("request has no form")?.clone().build();
+        let body = str::from_utf8(&body)?;
+        assert!(body
+            .contains(r#""attachments":[{"filename":"twilight.png","id":0},{"id":3},{"id":4}]"#));
+        assert!(body.contains(r#"name="files[0]"; filename="twilight.png""#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_and_new_attachments() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".into());
+        let attachments = [Attachment::from_bytes(
+            "twilight.png".to_owned(),
+            b"sparkle".to_vec(),
+            0,
+        )];
+
+        let request = UpdateMessage::new(&client, Id::new(1), Id::new(2))
+            .clear_attachments()
+            .try_into_request()?;
+        assert_eq!(Some(&br#"{"attachments":[]}"#[..]), request.body());
+
+        let request = UpdateMessage::new(&client, Id::new(1), Id::new(2))
+            .clear_attachments()
+            .new_attachments(&attachments)
+            .try_into_request()?;
+        let body = request.form().ok_or("request has no form")?.clone().build();
+        assert!(str::from_utf8(&body)?
+            .contains(r#""attachments":[{"filename":"twilight.png","id":0}]"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_and_keep_attachments() {
+        let client = Client::new("token".into());
+
+        for builder in [
+            UpdateMessage::new(&client, Id::new(1), Id::new(2))
+                .clear_attachments()
+                .keep_attachment_ids(&[Id::new(3)]),
+            UpdateMessage::new(&client, Id::new(1), Id::new(2))
+                .keep_attachment_ids(&[Id::new(3)])
+                .clear_attachments(),
+        ] {
+            assert!(matches!(
+                builder.fields.err().map(|source| source.into_parts().0),
+                Some(MessageValidationErrorType::AttachmentsClearedAndKept)
+            ));
+        }
+
+        assert!(UpdateMessage::new(&client, Id::new(1), Id::new(2))
+            .clear_attachments()
+            .keep_attachment_ids(&[])
+            .fields
+            .is_ok());
+    }
 }
@@ -2,11 +2,19 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{
+        pagination::{self, Paginate, Paginator},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, Response, ResponseFuture},
     routing::Route,
 };
-use std::future::IntoFuture;
+use futures_core::Stream;
+use std::{
+    future::IntoFuture,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use twilight_model::{
     channel::message::ReactionType,
     id::{
@@ -25,10 +33,16 @@ struct GetReactionsFields {
     kind: Option<ReactionType>,
 }
 
+/// Maximum number of users that can be retrieved in a single page.
+const REACTIONS_PAGE_LIMIT: u16 = 100;
+
 /// Get a list of users that reacted to a message with an `emoji`.
 ///
 /// This endpoint is limited to 100 users maximum, so if a message has more than 100 reactions,
-/// requests must be chained until all reactions are retrieved.
+/// requests must be chained until all reactions are retrieved. Use [`paginate`]
+/// to do so automatically.
+///
+/// [`paginate`]: Self::paginate
 #[must_use = "requests must be configured and executed"]
 pub struct GetReactions<'a> {
     channel_id: Id<ChannelMarker>,
@@ -99,6 +113,86 @@ impl<'a> GetReactions<'a> {
 
         self
     }
+
+    /// Walk through the users that reacted, page by page.
+    ///
+    /// Pages are requested using the [`after`] cursor, starting from the
+    /// configured value or the first user if none was set, with the highest
+    /// user ID of each page as the cursor of the next. The configured
+    /// [`limit`] is used as the page size and defaults to 100, the maximum.
+    /// The stream ends once a page has fewer users than the page size.
+    ///
+    /// # Examples
+    ///
+    /// Count the users who super reacted with an emoji:
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use twilight_http::{request::channel::reaction::RequestReactionType, Client};
+    /// use twilight_model::{channel::message::ReactionType, id::Id};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("token".to_owned());
+    /// let emoji = RequestReactionType::Unicode { name: "🎉" };
+    ///
+    /// let mut pages = client
+    ///     .reactions(Id::new(1), Id::new(2), &emoji)
+    ///     .kind(ReactionType::Burst)
+    ///     .paginate();
+    /// let mut count = 0;
+    ///
+    /// while let Some(page) = pages.next().await {
+    ///     count += page?.len();
+    /// }
+    ///
+    /// println!("{count} users super reacted");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Validation`] as the first and only item if a
+    /// field failed validation.
+    ///
+    /// Returns an error of type [`ChunkingResponse`] if a page's response
+    /// body could not be read.
+    ///
+    /// Returns an error of type [`Parsing`] if a page's response body could
+    /// not be deserialized.
+    ///
+    /// Errors from sending a request are returned as documented on
+    /// [`ResponseFuture`]. The stream ends after any error.
+    ///
+    /// [`ChunkingResponse`]: crate::error::ErrorType::ChunkingResponse
+    /// [`Parsing`]: crate::error::ErrorType::Parsing
+    /// [`Validation`]: crate::error::ErrorType::Validation
+    /// [`after`]: Self::after
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> ReactionPages<'a> {
+        let (fields, error) = match self.fields {
+            Ok(fields) => (Some(fields), None),
+            Err(source) => (None, Some(Error::validation(source))),
+        };
+
+        let after = fields.as_ref().and_then(|fields| fields.after);
+        let pagination = ReactionsPagination {
+            channel_id: self.channel_id,
+            emoji: self.emoji,
+            kind: fields.as_ref().and_then(|fields| fields.kind),
+            limit: fields
+                .and_then(|fields| fields.limit)
+                .unwrap_or(REACTIONS_PAGE_LIMIT),
+            message_id: self.message_id,
+        };
+
+        let inner = match error {
+            Some(source) => Paginator::failed(self.http, pagination, source),
+            None => Paginator::new(self.http, pagination, after),
+        };
+
+        ReactionPages { inner }
+    }
 }
 
 impl IntoFuture for GetReactions<'_> {
@@ -130,3 +224,153 @@ impl TryIntoRequest for GetReactions<'_> {
         }))
     }
 }
+
+/// Paginated endpoint of the users that reacted to a message with an emoji.
+struct ReactionsPagination<'a> {
+    channel_id: Id<ChannelMarker>,
+    emoji: &'a RequestReactionType<'a>,
+    kind: Option<ReactionType>,
+    limit: u16,
+    message_id: Id<MessageMarker>,
+}
+
+impl Paginate for ReactionsPagination<'_> {
+    type Cursor = Id<UserMarker>;
+    type Page = Vec<User>;
+
+    fn request(&self, after: Option<Id<UserMarker>>) -> Request {
+        Request::from_route(&Route::GetReactionUsers {
+            after: after.map(Id::get),
+            channel_id: self.channel_id.get(),
+            emoji: self.emoji,
+            limit: Some(self.limit),
+            message_id: self.message_id.get(),
+            kind: self.kind.map(Into::into),
+        })
+    }
+
+    fn advance(&self, page: &mut Vec<User>) -> Option<Id<UserMarker>> {
+        advance(page, self.limit)
+    }
+
+    fn is_empty(page: &Vec<User>) -> bool {
+        page.is_empty()
+    }
+}
+
+/// Stream of pages of users that reacted to a message with an emoji.
+///
+/// Obtained via [`GetReactions::paginate`]; refer to its documentation for
+/// more information.
+#[must_use = "streams do nothing unless polled"]
+pub struct ReactionPages<'a> {
+    inner: Paginator<'a, ReactionsPagination<'a>>,
+}
+
+impl Stream for ReactionPages<'_> {
+    type Item = Result<Vec<User>, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+/// Cursor to request the page after this one with, if there may be more users.
+fn advance(page: &[User], limit: u16) -> Option<Id<UserMarker>> {
+    pagination::after_cursor(page, limit, |user| user.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{advance, GetReactions, ReactionPages};
+    use crate::{
+        request::{channel::reaction::RequestReactionType, TryIntoRequest},
+        Client,
+    };
+    use futures_core::Stream;
+    use static_assertions::assert_impl_all;
+    use twilight_model::{channel::message::ReactionType, id::Id, user::User};
+
+    assert_impl_all!(ReactionPages<'_>: Stream, Unpin);
+
+    fn user(id: u64) -> User {
+        User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(id),
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_owned(),
+            premium_type: None,
+            primary_guild: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn query() {
+        let client = Client::new(String::new());
+        let emoji = RequestReactionType::Custom {
+            id: Id::new(3),
+            name: Some("foo"),
+        };
+
+        let request = GetReactions::new(&client, Id::new(1), Id::new(2), &emoji)
+            .after(Id::new(4))
+            .kind(ReactionType::Burst)
+            .limit(50)
+            .try_into_request()
+            .unwrap();
+
+        assert_eq!(
+            "channels/1/messages/2/reactions/foo:3?after=4&limit=50&type=1",
+            request.path()
+        );
+    }
+
+    #[test]
+    fn pages_query() {
+        let client = Client::new(String::new());
+        let emoji = RequestReactionType::Custom {
+            id: Id::new(3),
+            name: Some("foo"),
+        };
+
+        let mut pages = GetReactions::new(&client, Id::new(1), Id::new(2), &emoji)
+            .kind(ReactionType::Burst)
+            .paginate();
+
+        assert_eq!(
+            "channels/1/messages/2/reactions/foo:3?limit=100&type=1",
+            pages.inner.request().path()
+        );
+
+        pages.inner.set_cursor(Id::new(200));
+
+        assert_eq!(
+            "channels/1/messages/2/reactions/foo:3?after=200&limit=100&type=1",
+            pages.inner.request().path()
+        );
+    }
+
+    #[test]
+    fn advance_full_page() {
+        assert_eq!(Some(Id::new(5)), advance(&[user(3), user(5), user(4)], 3));
+    }
+
+    #[test]
+    fn advance_exhausted() {
+        assert_eq!(None, advance(&[user(3), user(4)], 3));
+        assert_eq!(None, advance(&[], 3));
+    }
+}
@@ -6,13 +6,18 @@ mod delete_all_reactions;
 mod get_reactions;
 
 pub use self::{
-    create_reaction::CreateReaction, delete_all_reaction::DeleteAllReaction,
-    delete_all_reactions::DeleteAllReactions, delete_reaction::DeleteReaction,
-    get_reactions::GetReactions,
+    create_reaction::CreateReaction,
+    delete_all_reaction::DeleteAllReaction,
+    delete_all_reactions::DeleteAllReactions,
+    delete_reaction::DeleteReaction,
+    get_reactions::{GetReactions, ReactionPages},
 };
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use twilight_model::id::{marker::EmojiMarker, Id};
+use twilight_model::{
+    channel::message::EmojiReactionType,
+    id::{marker::EmojiMarker, Id},
+};
 
 /// Handle a reaction of either a custom or unicode emoji.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -34,6 +39,35 @@ pub enum RequestReactionType<'a> {
     },
 }
 
+/// Borrow a reaction emoji, such as one received from the gateway or cache,
+/// for use in a request.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_http::request::channel::reaction::RequestReactionType;
+/// use twilight_model::{channel::message::EmojiReactionType, id::Id};
+///
+/// let emoji = EmojiReactionType::Custom {
+///     animated: false,
+///     id: Id::new(123),
+///     name: Some("rarity".to_owned()),
+/// };
+///
+/// assert_eq!("rarity:123", RequestReactionType::from(&emoji).to_string());
+/// ```
+impl<'a> From<&'a EmojiReactionType> for RequestReactionType<'a> {
+    fn from(emoji: &'a EmojiReactionType) -> Self {
+        match emoji {
+            EmojiReactionType::Custom { id, name, .. } => Self::Custom {
+                id: *id,
+                name: name.as_deref(),
+            },
+            EmojiReactionType::Unicode { name } => Self::Unicode { name },
+        }
+    }
+}
+
 /// Format a [`RequestReactionType`] into a format acceptable for use in URLs.
 ///
 /// # Examples
@@ -99,12 +133,36 @@ mod tests {
         fmt::{Debug, Display},
         hash::Hash,
     };
-    use twilight_model::id::Id;
+    use twilight_model::{channel::message::EmojiReactionType, id::Id};
 
     assert_fields!(RequestReactionType::Custom: id, name);
     assert_fields!(RequestReactionType::Unicode: name);
     assert_impl_all!(RequestReactionType<'_>: Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
 
+    #[test]
+    fn from_emoji_reaction_type() {
+        let custom = EmojiReactionType::Custom {
+            animated: true,
+            id: Id::new(123),
+            name: Some("foo".to_owned()),
+        };
+        let unicode = EmojiReactionType::Unicode {
+            name: "🌈".to_owned(),
+        };
+
+        assert_eq!(
+            RequestReactionType::Custom {
+                id: Id::new(123),
+                name: Some("foo"),
+            },
+            RequestReactionType::from(&custom)
+        );
+        assert_eq!(
+            RequestReactionType::Unicode { name: "🌈" },
+            RequestReactionType::from(&unicode)
+        );
+    }
+
     #[test]
     fn display_custom_with_name() {
         let reaction = RequestReactionType::Custom {
@@ -41,6 +41,7 @@ pub(super) struct CreateForumThreadMessageFields<'a> {
     pub(super) sticker_ids: Option<&'a [Id<StickerMarker>]>,
 }
 
+/// Configure the first message of a thread created via [`CreateForumThread`].
 #[must_use = "requests must be configured and executed"]
 pub struct CreateForumThreadMessage<'a>(Result<CreateForumThread<'a>, MessageValidationError>);
 
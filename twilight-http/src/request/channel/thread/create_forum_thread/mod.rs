@@ -6,7 +6,7 @@ use self::message::CreateForumThreadMessageFields;
 use crate::{
     client::Client,
     error::Error,
-    request::{attachment::AttachmentManager, Nullable, Request},
+    request::{attachment::AttachmentManager, Request},
     response::ResponseFuture,
     routing::Route,
 };
@@ -18,11 +18,18 @@ use twilight_model::{
         Id,
     },
 };
+use twilight_validate::channel::{
+    forum_thread_applied_tags as validate_forum_thread_applied_tags, name as validate_name,
+    rate_limit_per_user as validate_rate_limit_per_user,
+};
 
-#[derive(Deserialize, Serialize)]
+/// Thread created in a forum or media channel, along with its first message.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ForumThread {
+    /// Created thread channel.
     #[serde(flatten)]
     pub channel: Channel,
+    /// First message in the thread.
     pub message: Message,
 }
 
@@ -38,11 +45,17 @@ struct CreateForumThreadFields<'a> {
     rate_limit_per_user: Option<u16>,
 }
 
-/// Creates a new thread in a forum channel.
+/// Creates a new thread in a forum or media channel.
+///
+/// The thread is created along with its first message, which is configured
+/// via [`message`]. Forum and media threads don't have a channel type or an
+/// invitable setting, so only fields supported by forum and media channels
+/// are exposed.
 ///
 /// Requires the [`SEND_MESSAGES`] permission.
 ///
 /// [`SEND_MESSAGES`]: twilight_model::guild::Permissions::SEND_MESSAGES
+/// [`message`]: Self::message
 #[must_use = "requests must be configured and executed"]
 pub struct CreateForumThread<'a> {
     attachment_manager: AttachmentManager<'a>,
@@ -81,6 +94,16 @@ impl<'a> CreateForumThread<'a> {
     }
 
     /// Set the forum thread's applied tags.
+    ///
+    /// At most [`CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX`] tags may be applied.
+    ///
+    /// # Errors
+    ///
+    /// Executing the request returns an error of type
+    /// [`ForumThreadAppliedTagsInvalid`] if too many tags are applied.
+    ///
+    /// [`CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX`]: twilight_validate::channel::CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX
+    /// [`ForumThreadAppliedTagsInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumThreadAppliedTagsInvalid
     pub const fn applied_tags(mut self, applied_tags: &'a [Id<TagMarker>]) -> Self {
         self.fields.applied_tags = Some(applied_tags);
 
@@ -101,10 +124,30 @@ impl<'a> CreateForumThread<'a> {
         self
     }
 
+    /// Configure the first message in the thread.
     pub const fn message(self) -> CreateForumThreadMessage<'a> {
         CreateForumThreadMessage::new(self)
     }
 
+    /// Set the number of seconds that a user must wait before they are able
+    /// to to send another message.
+    ///
+    /// The minimum is 0 and the maximum is 21600. This is also known as "Slow
+    /// Mode". See [Discord Docs/Channel Object].
+    ///
+    /// # Errors
+    ///
+    /// Executing the request returns an error of type
+    /// [`RateLimitPerUserInvalid`] if the limit is invalid.
+    ///
+    /// [`RateLimitPerUserInvalid`]: twilight_validate::channel::ChannelValidationErrorType::RateLimitPerUserInvalid
+    /// [Discord Docs/Channel Object]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
+    pub const fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
+        self.fields.rate_limit_per_user = Some(rate_limit_per_user);
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -117,39 +160,214 @@ impl<'a> CreateForumThread<'a> {
         }
     }
 
-    fn try_into_request(mut self) -> Result<Request, Error> {
+    fn try_into_request(self) -> Result<Request, Error> {
         let mut request = Request::builder(&Route::CreateForumThread {
             channel_id: self.channel_id.get(),
         });
+        let mut fields = self.fields;
 
-        // Set the default allowed mentions if required.
-        if self.fields.message.allowed_mentions.is_none() {
-            if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
-                self.fields.message.allowed_mentions = Some(Nullable(Some(allowed_mentions)));
-            }
+        validate_name(fields.name).map_err(Error::validation)?;
+
+        if let Some(applied_tags) = fields.applied_tags {
+            validate_forum_thread_applied_tags(applied_tags).map_err(Error::validation)?;
+        }
+
+        if let Some(rate_limit_per_user) = fields.rate_limit_per_user {
+            validate_rate_limit_per_user(rate_limit_per_user).map_err(Error::validation)?;
         }
 
+        // Set the default allowed mentions if required, otherwise merge them
+        // with the request's allowed mentions.
+        let mut merged_allowed_mentions = None;
+        fields.message.allowed_mentions = self.http.resolve_allowed_mentions(
+            fields.message.allowed_mentions,
+            &mut merged_allowed_mentions,
+        );
+
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
         if !self.attachment_manager.is_empty() {
-            let form = if let Some(payload_json) = self.fields.message.payload_json {
+            let form = if let Some(payload_json) = fields.message.payload_json {
                 self.attachment_manager.build_form(payload_json)
             } else {
-                self.fields.message.attachments =
+                fields.message.attachments =
                     Some(self.attachment_manager.get_partial_attachments());
 
-                let fields = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                let fields = crate::json::to_vec(&fields).map_err(Error::json)?;
 
                 self.attachment_manager.build_form(fields.as_ref())
             };
 
             request = request.form(form);
-        } else if let Some(payload_json) = self.fields.message.payload_json {
+        } else if let Some(payload_json) = fields.message.payload_json {
             request = request.body(payload_json.to_vec());
         } else {
-            request = request.json(&self.fields);
+            request = request.json(&fields);
         }
 
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ForumThread;
+    use crate::{client::Client, error::ErrorType, request::TryIntoRequest};
+    use std::{error::Error, str};
+    use twilight_http_ratelimiting::Path;
+    use twilight_model::{
+        channel::{thread::AutoArchiveDuration, ChannelType},
+        http::attachment::Attachment,
+        id::Id,
+    };
+
+    #[test]
+    fn json() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let tags = [Id::new(3), Id::new(4)];
+
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .applied_tags(&tags)
+            .auto_archive_duration(AutoArchiveDuration::Day)
+            .rate_limit_per_user(10)
+            .message()
+            .content("first message")
+            .try_into_request()?;
+
+        assert!(request.form().is_none());
+        assert_eq!(&Path::ChannelsIdThreads(1), request.ratelimit_path());
+        assert_eq!(
+            Some(
+                br#"{"applied_tags":["3","4"],"auto_archive_duration":1440,"message":{"content":"first message"},"name":"thread","rate_limit_per_user":10}"#
+                    .as_slice()
+            ),
+            request.body()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn multipart() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let attachments = [Attachment::from_bytes(
+            "twilight.png".to_owned(),
+            b"sparkle".to_vec(),
+            5,
+        )];
+        let tags = [Id::new(3)];
+
+        let request = client
+            .create_forum_thread(Id::new(1), "thread")
+            .applied_tags(&tags)
+            .message()
+            .attachments(&attachments)
+            .content("look")
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        let body = request.form().ok_or("request has no form")?.clone().build();
+        let body = str::from_utf8(&body)?;
+
+        let payload = body
+            .find("Content-Disposition: form-data; name=\"payload_json\"\r\n")
+            .ok_or("form has no payload part")?;
+        let file = body
+            .find(
+                "Content-Disposition: form-data; name=\"files[5]\"; filename=\"twilight.png\"\r\n",
+            )
+            .ok_or("form has no file part")?;
+        assert!(payload < file);
+
+        assert!(body.contains(
+            r#"{"applied_tags":["3"],"message":{"attachments":[{"filename":"twilight.png","id":5}],"content":"look"},"name":"thread"}"#
+        ));
+        assert!(body.contains("\r\n\r\nsparkle\r\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new(String::new());
+        let tags = [
+            Id::new(1),
+            Id::new(2),
+            Id::new(3),
+            Id::new(4),
+            Id::new(5),
+            Id::new(6),
+        ];
+        let content = "a".repeat(2001);
+
+        let requests = [
+            client
+                .create_forum_thread(Id::new(1), "")
+                .message()
+                .content("content"),
+            client
+                .create_forum_thread(Id::new(1), "thread")
+                .applied_tags(&tags)
+                .message()
+                .content("content"),
+            client
+                .create_forum_thread(Id::new(1), "thread")
+                .rate_limit_per_user(21_601)
+                .message()
+                .content("content"),
+            client
+                .create_forum_thread(Id::new(1), "thread")
+                .message()
+                .content(&content),
+        ];
+
+        for request in requests {
+            let error = request.try_into_request().unwrap_err();
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+    }
+
+    #[test]
+    fn forum_thread() -> Result<(), Box<dyn Error>> {
+        const INPUT: &str = r#"{
+            "applied_tags": ["3"],
+            "id": "2",
+            "name": "thread",
+            "parent_id": "1",
+            "type": 11,
+            "message": {
+                "attachments": [],
+                "author": {
+                    "avatar": null,
+                    "discriminator": "0",
+                    "id": "4",
+                    "username": "twilight"
+                },
+                "channel_id": "2",
+                "content": "first message",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "2",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2024-01-01T00:00:00.000000+00:00",
+                "tts": false,
+                "type": 0
+            }
+        }"#;
+
+        let thread = crate::json::from_bytes::<ForumThread>(INPUT.as_bytes())?;
+
+        assert_eq!(Id::new(2), thread.channel.id);
+        assert_eq!(ChannelType::PublicThread, thread.channel.kind);
+        assert_eq!(Some(Id::new(1)), thread.channel.parent_id);
+        assert_eq!(Some(Vec::from([Id::new(3)])), thread.channel.applied_tags);
+        assert_eq!(thread.channel.id, thread.message.channel_id);
+        assert_eq!("first message", thread.message.content);
+
+        Ok(())
+    }
+}
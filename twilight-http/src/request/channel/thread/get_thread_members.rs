@@ -1,11 +1,19 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{
+        pagination::{self, Paginate, Paginator},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, Response, ResponseFuture},
     routing::Route,
 };
-use std::future::IntoFuture;
+use futures_core::Stream;
+use std::{
+    future::IntoFuture,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use twilight_model::{
     channel::thread::ThreadMember,
     id::{
@@ -21,9 +29,19 @@ struct GetThreadMembersFields {
     with_member: Option<bool>,
 }
 
+/// Maximum number of thread members that can be retrieved in a single page.
+const THREAD_MEMBERS_PAGE_LIMIT: u16 = 100;
+
 /// Returns the [`ThreadMember`]s of the thread.
 ///
+/// Thread members only include their guild [`member`] if requested via
+/// [`with_member`]. Use [`paginate`] to retrieve all of the members of a
+/// thread.
+///
 /// [`ThreadMember`]: twilight_model::channel::thread::ThreadMember
+/// [`member`]: twilight_model::channel::thread::ThreadMember::member
+/// [`paginate`]: Self::paginate
+/// [`with_member`]: Self::with_member
 #[must_use = "requests must be configured and executed"]
 pub struct GetThreadMembers<'a> {
     channel_id: Id<ChannelMarker>,
@@ -75,6 +93,10 @@ impl<'a> GetThreadMembers<'a> {
     }
 
     /// Include the associated guild members for each thread member.
+    ///
+    /// Requires the [`GUILD_MEMBERS`] privileged intent.
+    ///
+    /// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
     pub fn with_member(mut self, with_member: bool) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
             fields.with_member = Some(with_member);
@@ -82,6 +104,89 @@ impl<'a> GetThreadMembers<'a> {
 
         self
     }
+
+    /// Walk through the members of the thread, page by page.
+    ///
+    /// Pages are requested using the [`after`] cursor, starting from the
+    /// configured value or the first member if none was set, with the highest
+    /// user ID of each page as the cursor of the next. The configured
+    /// [`limit`] is used as the page size and defaults to 100, the maximum.
+    /// [`with_member`] applies to every page. The stream ends once a page has
+    /// fewer members than the page size.
+    ///
+    /// # Examples
+    ///
+    /// Print the nicknames of the members of a thread:
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("token".to_owned());
+    ///
+    /// let mut pages = client
+    ///     .thread_members(Id::new(1))
+    ///     .with_member(true)
+    ///     .paginate();
+    ///
+    /// while let Some(page) = pages.next().await {
+    ///     for thread_member in page? {
+    ///         if let Some(member) = thread_member.member {
+    ///             println!("{}", member.display_name());
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Validation`] as the first and only item if a
+    /// field failed validation.
+    ///
+    /// Returns an error of type [`ChunkingResponse`] if a page's response
+    /// body could not be read.
+    ///
+    /// Returns an error of type [`Parsing`] if a page's response body could
+    /// not be deserialized.
+    ///
+    /// Errors from sending a request are returned as documented on
+    /// [`ResponseFuture`]. The stream ends after any error.
+    ///
+    /// [`ChunkingResponse`]: crate::error::ErrorType::ChunkingResponse
+    /// [`Parsing`]: crate::error::ErrorType::Parsing
+    /// [`Validation`]: crate::error::ErrorType::Validation
+    /// [`after`]: Self::after
+    /// [`limit`]: Self::limit
+    /// [`with_member`]: Self::with_member
+    pub fn paginate(self) -> ThreadMemberPages<'a> {
+        let (fields, error) = match self.fields {
+            Ok(fields) => (Some(fields), None),
+            Err(source) => (None, Some(Error::validation(source))),
+        };
+
+        let after = fields.as_ref().and_then(|fields| fields.after);
+        let pagination = ThreadMembersPagination {
+            channel_id: self.channel_id,
+            // The limit is validated to be at most 100.
+            limit: fields
+                .as_ref()
+                .and_then(|fields| fields.limit)
+                .and_then(|limit| u16::try_from(limit).ok())
+                .unwrap_or(THREAD_MEMBERS_PAGE_LIMIT),
+            with_member: fields.and_then(|fields| fields.with_member),
+        };
+
+        let inner = match error {
+            Some(source) => Paginator::failed(self.http, pagination, source),
+            None => Paginator::new(self.http, pagination, after),
+        };
+
+        ThreadMemberPages { inner }
+    }
 }
 
 impl IntoFuture for GetThreadMembers<'_> {
@@ -111,3 +216,191 @@ impl TryIntoRequest for GetThreadMembers<'_> {
         }))
     }
 }
+
+/// Paginated endpoint of the members of a thread.
+struct ThreadMembersPagination {
+    channel_id: Id<ChannelMarker>,
+    limit: u16,
+    with_member: Option<bool>,
+}
+
+impl Paginate for ThreadMembersPagination {
+    type Cursor = Id<UserMarker>;
+    type Page = Vec<ThreadMember>;
+
+    fn request(&self, after: Option<Id<UserMarker>>) -> Request {
+        Request::from_route(&Route::GetThreadMembers {
+            after: after.map(Id::get),
+            channel_id: self.channel_id.get(),
+            limit: Some(u32::from(self.limit)),
+            with_member: self.with_member,
+        })
+    }
+
+    fn advance(&self, page: &mut Vec<ThreadMember>) -> Option<Id<UserMarker>> {
+        pagination::after_cursor(page, self.limit, |member| member.user_id).flatten()
+    }
+
+    fn is_empty(page: &Vec<ThreadMember>) -> bool {
+        page.is_empty()
+    }
+}
+
+/// Stream of pages of the members of a thread.
+///
+/// Obtained via [`GetThreadMembers::paginate`]; refer to its documentation for
+/// more information.
+#[must_use = "streams do nothing unless polled"]
+pub struct ThreadMemberPages<'a> {
+    inner: Paginator<'a, ThreadMembersPagination>,
+}
+
+impl Stream for ThreadMemberPages<'_> {
+    type Item = Result<Vec<ThreadMember>, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GetThreadMembers, ThreadMemberPages, ThreadMembersPagination};
+    use crate::{
+        request::{pagination::Paginate, TryIntoRequest},
+        Client,
+    };
+    use futures_core::Stream;
+    use static_assertions::assert_impl_all;
+    use std::error::Error;
+    use twilight_model::{channel::thread::ThreadMember, id::Id};
+
+    assert_impl_all!(ThreadMemberPages<'_>: Stream, Unpin);
+
+    /// Thread member without a guild member, as returned by default.
+    const WITHOUT_MEMBER: &str = r#"{
+        "flags": 1,
+        "id": "1",
+        "join_timestamp": "2024-01-01T00:00:00.000000+00:00",
+        "user_id": "2"
+    }"#;
+
+    /// Thread member with a guild member, as returned when requested.
+    const WITH_MEMBER: &str = r#"{
+        "flags": 1,
+        "id": "1",
+        "join_timestamp": "2024-01-01T00:00:00.000000+00:00",
+        "member": {
+            "communication_disabled_until": null,
+            "deaf": false,
+            "flags": 0,
+            "joined_at": "2023-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "nick": "nick",
+            "roles": ["3"],
+            "user": {
+                "avatar": null,
+                "discriminator": "0",
+                "global_name": null,
+                "id": "2",
+                "username": "twilight"
+            }
+        },
+        "user_id": "2"
+    }"#;
+
+    #[test]
+    fn query() {
+        let client = Client::new(String::new());
+
+        let request = GetThreadMembers::new(&client, Id::new(1))
+            .after(Id::new(2))
+            .limit(50)
+            .with_member(true)
+            .try_into_request()
+            .unwrap();
+
+        assert_eq!(
+            "channels/1/thread-members?after=2&limit=50&with_member=true",
+            request.path()
+        );
+    }
+
+    #[test]
+    fn pages_query() {
+        let client = Client::new(String::new());
+
+        let mut pages = GetThreadMembers::new(&client, Id::new(1))
+            .with_member(true)
+            .paginate();
+
+        assert_eq!(
+            "channels/1/thread-members?limit=100&with_member=true",
+            pages.inner.request().path()
+        );
+
+        pages.inner.set_cursor(Id::new(200));
+
+        assert_eq!(
+            "channels/1/thread-members?after=200&limit=100&with_member=true",
+            pages.inner.request().path()
+        );
+    }
+
+    #[test]
+    fn pages_limit() {
+        let client = Client::new(String::new());
+
+        let pages = GetThreadMembers::new(&client, Id::new(1))
+            .after(Id::new(2))
+            .limit(2)
+            .paginate();
+
+        assert_eq!(
+            "channels/1/thread-members?after=2&limit=2",
+            pages.inner.request().path()
+        );
+    }
+
+    #[test]
+    fn advance() -> Result<(), Box<dyn Error>> {
+        let pagination = ThreadMembersPagination {
+            channel_id: Id::new(1),
+            limit: 2,
+            with_member: None,
+        };
+        let body = format!("[{WITHOUT_MEMBER}, {WITH_MEMBER}]");
+        let mut page = crate::json::from_bytes::<Vec<ThreadMember>>(body.as_bytes())?;
+
+        assert_eq!(Some(Id::new(2)), pagination.advance(&mut page));
+
+        page.pop();
+        assert!(pagination.advance(&mut page).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_without_member() -> Result<(), Box<dyn Error>> {
+        let member = crate::json::from_bytes::<ThreadMember>(WITHOUT_MEMBER.as_bytes())?;
+
+        assert_eq!(Some(Id::new(1)), member.id);
+        assert_eq!(Some(Id::new(2)), member.user_id);
+        assert!(member.member.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_with_member() -> Result<(), Box<dyn Error>> {
+        let member = crate::json::from_bytes::<ThreadMember>(WITH_MEMBER.as_bytes())?;
+        let guild_member = member.member.expect("member was requested");
+
+        assert_eq!(Some(Id::new(2)), member.user_id);
+        assert_eq!(member.user_id, Some(guild_member.user.id));
+        assert_eq!(Some("nick"), guild_member.nick.as_deref());
+        assert_eq!(vec![Id::new(3)], guild_member.roles);
+
+        Ok(())
+    }
+}
@@ -17,8 +17,9 @@ use twilight_model::{
 };
 use twilight_validate::{
     channel::{
-        bitrate as validate_bitrate, forum_topic as validate_forum_topic, name as validate_name,
-        topic as validate_topic, user_limit as validate_user_limit, ChannelValidationError,
+        bitrate as validate_bitrate, forum_tag as validate_forum_tag,
+        forum_topic as validate_forum_topic, name as validate_name, topic as validate_topic,
+        user_limit as validate_user_limit, ChannelValidationError,
     },
     request::{audit_reason as validate_audit_reason, ValidationError},
 };
@@ -108,10 +109,27 @@ impl<'a> UpdateChannel<'a> {
     }
 
     /// Set the available tags for the forum.
+    ///
+    /// Each tag's name must be at most 20 characters long, and a tag may not
+    /// have both an emoji ID and an emoji name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ForumTagNameInvalid`] if a tag's name is
+    /// invalid.
+    ///
+    /// Returns an error of type [`ForumTagEmojiInvalid`] if a tag has both an
+    /// emoji ID and an emoji name.
+    ///
+    /// [`ForumTagEmojiInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagEmojiInvalid
+    /// [`ForumTagNameInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagNameInvalid
     pub fn available_tags(mut self, available_tags: &'a [ForumTag]) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
+        self.fields = self.fields.and_then(|mut fields| {
+            available_tags.iter().try_for_each(validate_forum_tag)?;
             fields.available_tags = Some(available_tags);
-        }
+
+            Ok(fields)
+        });
 
         self
     }
@@ -70,6 +70,14 @@ impl TryIntoRequest for DeleteWebhook<'_> {
             token: self.fields.token,
         });
 
+        let use_webhook_token = self.fields.token.is_some();
+
+        // If a webhook token has been configured, then we don't need to use
+        // the client's authorization token.
+        if use_webhook_token {
+            request = request.use_authorization_token(false);
+        }
+
         if let Some(reason) = self.reason.map_err(Error::validation)? {
             request = request.headers(request::audit_header(reason)?);
         }
@@ -135,4 +135,15 @@ mod tests {
         assert_eq!(expected.body, actual.body);
         assert_eq!(expected.path, actual.path);
     }
+
+    #[test]
+    fn thread_id() {
+        let client = Client::new("token".to_owned());
+        let actual = DeleteWebhookMessage::new(&client, Id::new(1), "token", Id::new(2))
+            .thread_id(Id::new(3))
+            .try_into_request()
+            .expect("failed to create request");
+
+        assert_eq!("webhooks/1/token/messages/2?thread_id=3", actual.path());
+    }
 }
@@ -10,7 +10,7 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
-use std::future::IntoFuture;
+use std::{borrow::Cow, future::IntoFuture};
 use twilight_model::{
     channel::message::{AllowedMentions, Component, Embed, MessageFlags},
     http::attachment::Attachment,
@@ -35,11 +35,11 @@ pub(crate) struct ExecuteWebhookFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     attachments: Option<Vec<PartialAttachment<'a>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    avatar_url: Option<&'a str>,
+    avatar_url: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     components: Option<&'a [Component]>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content: Option<&'a str>,
+    content: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<&'a [Embed]>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -47,11 +47,11 @@ pub(crate) struct ExecuteWebhookFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<&'a [u8]>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    thread_name: Option<&'a str>,
+    thread_name: Option<Cow<'a, str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    username: Option<&'a str>,
+    username: Option<Cow<'a, str>>,
 }
 
 /// Execute a webhook, sending a message to its channel.
@@ -161,9 +161,9 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     /// The URL of the avatar of the webhook.
-    pub fn avatar_url(mut self, avatar_url: &'a str) -> Self {
+    pub fn avatar_url(mut self, avatar_url: impl Into<Cow<'a, str>>) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
-            fields.avatar_url = Some(avatar_url);
+            fields.avatar_url = Some(avatar_url.into());
         }
 
         self
@@ -201,9 +201,11 @@ impl<'a> ExecuteWebhook<'a> {
     /// long.
     ///
     /// [`ContentInvalid`]: twilight_validate::message::MessageValidationErrorType::ContentInvalid
-    pub fn content(mut self, content: &'a str) -> Self {
+    pub fn content(mut self, content: impl Into<Cow<'a, str>>) -> Self {
+        let content = content.into();
+
         self.fields = self.fields.and_then(|mut fields| {
-            validate_content(content)?;
+            validate_content(&content)?;
             fields.content = Some(content);
 
             Ok(fields)
@@ -326,7 +328,9 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     /// Set the name of the created thread when used in a forum channel.
-    pub fn thread_name(mut self, thread_name: &'a str) -> Self {
+    pub fn thread_name(mut self, thread_name: impl Into<Cow<'a, str>>) -> Self {
+        let thread_name = thread_name.into();
+
         self.fields = self.fields.map(|mut fields| {
             fields.thread_name = Some(thread_name);
 
@@ -353,9 +357,11 @@ impl<'a> ExecuteWebhook<'a> {
     /// invalid.
     ///
     /// [`WebhookUsername`]: twilight_validate::request::ValidationErrorType::WebhookUsername
-    pub fn username(mut self, username: &'a str) -> Self {
+    pub fn username(mut self, username: impl Into<Cow<'a, str>>) -> Self {
+        let username = username.into();
+
         self.fields = self.fields.and_then(|mut fields| {
-            validate_webhook_username(username).map_err(|source| {
+            validate_webhook_username(&username).map_err(|source| {
                 MessageValidationError::from_validation_error(
                     MessageValidationErrorType::WebhookUsername,
                     source,
@@ -411,12 +417,12 @@ impl TryIntoRequest for ExecuteWebhook<'_> {
         // webhook token.
         request = request.use_authorization_token(false);
 
-        // Set the default allowed mentions if required.
-        if fields.allowed_mentions.is_none() {
-            if let Some(allowed_mentions) = self.http.default_allowed_mentions() {
-                fields.allowed_mentions = Some(Nullable(Some(allowed_mentions)));
-            }
-        }
+        // Set the default allowed mentions if required, otherwise merge them
+        // with the request's allowed mentions.
+        let mut merged_allowed_mentions = None;
+        fields.allowed_mentions = self
+            .http
+            .resolve_allowed_mentions(fields.allowed_mentions, &mut merged_allowed_mentions);
 
         // Determine whether we need to use a multipart/form-data body or a JSON
         // body.
@@ -76,3 +76,21 @@ impl TryIntoRequest for GetWebhookMessage<'_> {
         .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GetWebhookMessage;
+    use crate::{client::Client, request::TryIntoRequest};
+    use twilight_model::id::Id;
+
+    #[test]
+    fn thread_id() {
+        let client = Client::new("token".to_owned());
+        let actual = GetWebhookMessage::new(&client, Id::new(1), "token", Id::new(2))
+            .thread_id(Id::new(3))
+            .try_into_request()
+            .expect("failed to create request");
+
+        assert_eq!("webhooks/1/token/messages/2?thread_id=3", actual.path());
+    }
+}
@@ -2,10 +2,11 @@ use crate::{
     client::Client,
     error::Error as HttpError,
     request::{self, AuditLogReason, Request, TryIntoRequest},
-    response::ResponseFuture,
+    response::{Response, ResponseFuture},
     routing::Route,
 };
 use serde::Serialize;
+use std::future::IntoFuture;
 use twilight_model::{
     guild::auto_moderation::{
         AutoModerationActionType, AutoModerationEventType, AutoModerationKeywordPresetType,
@@ -344,7 +345,7 @@ impl<'a> CreateAutoModerationRule<'a> {
             Ok(fields)
         });
 
-        self.exec()
+        self.into_future()
     }
 
     /// Create the request with the trigger type [`Spam`], then execute it.
@@ -357,7 +358,7 @@ impl<'a> CreateAutoModerationRule<'a> {
             fields
         });
 
-        self.exec()
+        self.into_future()
     }
 
     /// Create the request with the trigger type [`KeywordPreset`], then execute
@@ -398,7 +399,7 @@ impl<'a> CreateAutoModerationRule<'a> {
             Ok(fields)
         });
 
-        self.exec()
+        self.into_future()
     }
 
     /// Create the request with the trigger type [`MentionSpam`], then execute
@@ -433,19 +434,7 @@ impl<'a> CreateAutoModerationRule<'a> {
             Ok(fields)
         });
 
-        self.exec()
-    }
-
-    /// Execute the request, returning a future resolving to a [`Response`].
-    ///
-    /// [`Response`]: crate::response::Response
-    fn exec(self) -> ResponseFuture<AutoModerationRule> {
-        let http = self.http;
-
-        match self.try_into_request() {
-            Ok(request) => http.request(request),
-            Err(source) => ResponseFuture::error(source),
-        }
+        self.into_future()
     }
 }
 
@@ -457,6 +446,25 @@ impl<'a> AuditLogReason<'a> for CreateAutoModerationRule<'a> {
     }
 }
 
+/// Execute the request without setting a trigger type.
+///
+/// Discord requires a trigger type when creating a rule, so prefer one of the
+/// `with_*` methods, such as [`CreateAutoModerationRule::with_spam`].
+impl IntoFuture for CreateAutoModerationRule<'_> {
+    type Output = Result<Response<AutoModerationRule>, HttpError>;
+
+    type IntoFuture = ResponseFuture<AutoModerationRule>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
 impl TryIntoRequest for CreateAutoModerationRule<'_> {
     fn try_into_request(self) -> Result<Request, HttpError> {
         let fields = self.fields.map_err(HttpError::validation)?;
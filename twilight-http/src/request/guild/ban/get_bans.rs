@@ -1,11 +1,19 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{
+        pagination::{self, Paginate, Paginator},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, Response, ResponseFuture},
     routing::Route,
 };
-use std::future::IntoFuture;
+use futures_core::Stream;
+use std::{
+    future::IntoFuture,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use twilight_model::{
     guild::Ban,
     id::{
@@ -23,6 +31,9 @@ struct GetBansFields {
     limit: Option<u16>,
 }
 
+/// Maximum number of bans that can be retrieved in a single page.
+const BANS_PAGE_LIMIT: u16 = 1000;
+
 /// Retrieve the bans for a guild.
 ///
 /// # Examples
@@ -49,6 +60,10 @@ struct GetBansFields {
 /// }
 /// # Ok(()) }
 /// ```
+///
+/// Use [`paginate`] to retrieve all of the bans of a guild.
+///
+/// [`paginate`]: Self::paginate
 #[must_use = "requests must be configured and executed"]
 pub struct GetBans<'a> {
     fields: Result<GetBansFields, ValidationError>,
@@ -119,6 +134,81 @@ impl<'a> GetBans<'a> {
 
         self
     }
+
+    /// Walk through the bans of the guild, page by page.
+    ///
+    /// Pages are requested using the [`after`] cursor, starting from the
+    /// configured value or the first ban if none was set, with the highest
+    /// user ID of each page as the cursor of the next. [`before`] is ignored.
+    /// The configured [`limit`] is used as the page size and defaults to 1000,
+    /// the maximum. The stream ends once a page has fewer bans than the page
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// Count the bans of a guild:
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("token".to_owned());
+    ///
+    /// let mut pages = client.bans(Id::new(1)).paginate();
+    /// let mut count = 0;
+    ///
+    /// while let Some(page) = pages.next().await {
+    ///     count += page?.len();
+    /// }
+    ///
+    /// println!("{count} users are banned");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Validation`] as the first and only item if a
+    /// field failed validation.
+    ///
+    /// Returns an error of type [`ChunkingResponse`] if a page's response
+    /// body could not be read.
+    ///
+    /// Returns an error of type [`Parsing`] if a page's response body could
+    /// not be deserialized.
+    ///
+    /// Errors from sending a request are returned as documented on
+    /// [`ResponseFuture`]. The stream ends after any error.
+    ///
+    /// [`ChunkingResponse`]: crate::error::ErrorType::ChunkingResponse
+    /// [`Parsing`]: crate::error::ErrorType::Parsing
+    /// [`Validation`]: crate::error::ErrorType::Validation
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> BanPages<'a> {
+        let (fields, error) = match self.fields {
+            Ok(fields) => (Some(fields), None),
+            Err(source) => (None, Some(Error::validation(source))),
+        };
+
+        let after = fields.as_ref().and_then(|fields| fields.after);
+        let pagination = BansPagination {
+            guild_id: self.guild_id,
+            limit: fields
+                .and_then(|fields| fields.limit)
+                .unwrap_or(BANS_PAGE_LIMIT),
+        };
+
+        let inner = match error {
+            Some(source) => Paginator::failed(self.http, pagination, source),
+            None => Paginator::new(self.http, pagination, after),
+        };
+
+        BanPages { inner }
+    }
 }
 
 impl IntoFuture for GetBans<'_> {
@@ -148,3 +238,94 @@ impl TryIntoRequest for GetBans<'_> {
         }))
     }
 }
+
+/// Paginated endpoint of the bans of a guild.
+struct BansPagination {
+    guild_id: Id<GuildMarker>,
+    limit: u16,
+}
+
+impl Paginate for BansPagination {
+    type Cursor = Id<UserMarker>;
+    type Page = Vec<Ban>;
+
+    fn request(&self, after: Option<Id<UserMarker>>) -> Request {
+        Request::from_route(&Route::GetBansWithParameters {
+            after: after.map(Id::get),
+            before: None,
+            limit: Some(self.limit),
+            guild_id: self.guild_id.get(),
+        })
+    }
+
+    fn advance(&self, page: &mut Vec<Ban>) -> Option<Id<UserMarker>> {
+        pagination::after_cursor(page, self.limit, |ban| ban.user.id)
+    }
+
+    fn is_empty(page: &Vec<Ban>) -> bool {
+        page.is_empty()
+    }
+}
+
+/// Stream of pages of the bans of a guild.
+///
+/// Obtained via [`GetBans::paginate`]; refer to its documentation for more
+/// information.
+#[must_use = "streams do nothing unless polled"]
+pub struct BanPages<'a> {
+    inner: Paginator<'a, BansPagination>,
+}
+
+impl Stream for BanPages<'_> {
+    type Item = Result<Vec<Ban>, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BanPages, GetBans};
+    use crate::{request::TryIntoRequest, Client};
+    use futures_core::Stream;
+    use static_assertions::assert_impl_all;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(BanPages<'_>: Stream, Unpin);
+
+    #[test]
+    fn pages_query() {
+        let client = Client::new(String::new());
+
+        let mut pages = GetBans::new(&client, Id::new(1))
+            .after(Id::new(2))
+            .before(Id::new(3))
+            .paginate();
+
+        assert_eq!(
+            "guilds/1/bans?after=2&limit=1000",
+            pages.inner.request().path()
+        );
+
+        pages.inner.set_cursor(Id::new(200));
+
+        assert_eq!(
+            "guilds/1/bans?after=200&limit=1000",
+            pages.inner.request().path()
+        );
+    }
+
+    #[test]
+    fn query() {
+        let client = Client::new(String::new());
+
+        let request = GetBans::new(&client, Id::new(1))
+            .before(Id::new(3))
+            .limit(50)
+            .try_into_request()
+            .unwrap();
+
+        assert_eq!("guilds/1/bans?before=3&limit=50", request.path());
+    }
+}
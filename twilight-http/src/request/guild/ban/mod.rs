@@ -3,4 +3,9 @@ mod delete_ban;
 mod get_ban;
 mod get_bans;
 
-pub use self::{create_ban::CreateBan, delete_ban::DeleteBan, get_ban::GetBan, get_bans::GetBans};
+pub use self::{
+    create_ban::CreateBan,
+    delete_ban::DeleteBan,
+    get_ban::GetBan,
+    get_bans::{BanPages, GetBans},
+};
@@ -21,7 +21,7 @@ use twilight_model::{
 };
 use twilight_validate::{
     channel::{
-        bitrate as validate_bitrate, name as validate_name,
+        bitrate as validate_bitrate, forum_tag as validate_forum_tag, name as validate_name,
         rate_limit_per_user as validate_rate_limit_per_user, topic as validate_topic,
         ChannelValidationError,
     },
@@ -120,10 +120,27 @@ impl<'a> CreateGuildChannel<'a> {
     }
 
     /// Set the available tags for the forum.
+    ///
+    /// Each tag's name must be at most 20 characters long, and a tag may not
+    /// have both an emoji ID and an emoji name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ForumTagNameInvalid`] if a tag's name is
+    /// invalid.
+    ///
+    /// Returns an error of type [`ForumTagEmojiInvalid`] if a tag has both an
+    /// emoji ID and an emoji name.
+    ///
+    /// [`ForumTagEmojiInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagEmojiInvalid
+    /// [`ForumTagNameInvalid`]: twilight_validate::channel::ChannelValidationErrorType::ForumTagNameInvalid
     pub fn available_tags(mut self, available_tags: &'a [ForumTag]) -> Self {
-        if let Ok(fields) = self.fields.as_mut() {
+        self.fields = self.fields.and_then(|mut fields| {
+            available_tags.iter().try_for_each(validate_forum_tag)?;
             fields.available_tags = Some(available_tags);
-        }
+
+            Ok(fields)
+        });
 
         self
     }
@@ -7,7 +7,7 @@ use crate::{
 };
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::GuildPrune,
+    guild::GuildPruneResult,
     id::{
         marker::{GuildMarker, RoleMarker},
         Id,
@@ -18,10 +18,10 @@ use twilight_validate::request::{
     ValidationError,
 };
 
-struct CreateGuildPruneFields<'a> {
+struct CreateGuildPruneFields {
     compute_prune_count: Option<bool>,
     days: Option<u16>,
-    include_roles: &'a [Id<RoleMarker>],
+    include_roles: Vec<Id<RoleMarker>>,
 }
 
 /// Begin a guild prune.
@@ -31,7 +31,7 @@ struct CreateGuildPruneFields<'a> {
 /// [Discord Docs/Begin Guild Prune]: https://discord.com/developers/docs/resources/guild#begin-guild-prune
 #[must_use = "requests must be configured and executed"]
 pub struct CreateGuildPrune<'a> {
-    fields: Result<CreateGuildPruneFields<'a>, ValidationError>,
+    fields: Result<CreateGuildPruneFields, ValidationError>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     reason: Result<Option<&'a str>, ValidationError>,
@@ -43,7 +43,7 @@ impl<'a> CreateGuildPrune<'a> {
             fields: Ok(CreateGuildPruneFields {
                 compute_prune_count: None,
                 days: None,
-                include_roles: &[],
+                include_roles: Vec::new(),
             }),
             guild_id,
             http,
@@ -51,16 +51,21 @@ impl<'a> CreateGuildPrune<'a> {
         }
     }
 
-    /// List of roles to include when pruning.
-    pub fn include_roles(mut self, roles: &'a [Id<RoleMarker>]) -> Self {
+    /// Set the roles to include when pruning.
+    ///
+    /// By default members with roles are not pruned. Members with any of the
+    /// provided roles are also pruned.
+    pub fn include_roles(mut self, roles: impl IntoIterator<Item = Id<RoleMarker>>) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
-            fields.include_roles = roles;
+            fields.include_roles = roles.into_iter().collect();
         }
 
         self
     }
 
     /// Return the amount of pruned members. Discouraged for large guilds.
+    ///
+    /// The response is [`GuildPruneResult::Suppressed`] when this is `false`.
     pub fn compute_prune_count(mut self, compute_prune_count: bool) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
             fields.compute_prune_count = Some(compute_prune_count);
@@ -71,7 +76,7 @@ impl<'a> CreateGuildPrune<'a> {
 
     /// Set the number of days that a user must be inactive before being pruned.
     ///
-    /// The number of days must be greater than 0.
+    /// The number of days must be greater than 0, and less than or equal to 30.
     ///
     /// # Errors
     ///
@@ -100,9 +105,9 @@ impl<'a> AuditLogReason<'a> for CreateGuildPrune<'a> {
 }
 
 impl IntoFuture for CreateGuildPrune<'_> {
-    type Output = Result<Response<GuildPrune>, Error>;
+    type Output = Result<Response<GuildPruneResult>, Error>;
 
-    type IntoFuture = ResponseFuture<GuildPrune>;
+    type IntoFuture = ResponseFuture<GuildPruneResult>;
 
     fn into_future(self) -> Self::IntoFuture {
         let http = self.http;
@@ -121,7 +126,7 @@ impl TryIntoRequest for CreateGuildPrune<'_> {
             compute_prune_count: fields.compute_prune_count,
             days: fields.days,
             guild_id: self.guild_id.get(),
-            include_roles: fields.include_roles,
+            include_roles: &fields.include_roles,
         });
 
         if let Some(reason) = self.reason.map_err(Error::validation)? {
@@ -131,3 +136,39 @@ impl TryIntoRequest for CreateGuildPrune<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::CreateGuildPrune;
+    use crate::{request::TryIntoRequest, Client};
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn query() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = CreateGuildPrune::new(&client, Id::new(1))
+            .compute_prune_count(false)
+            .days(30)
+            .include_roles([Id::new(2), Id::new(3)])
+            .try_into_request()?;
+
+        assert_eq!(
+            "guilds/1/prune?compute_prune_count=false&days=30&include_roles=2,3",
+            request.path()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn days() {
+        let client = Client::new(String::new());
+
+        assert!(CreateGuildPrune::new(&client, Id::new(1))
+            .days(31)
+            .try_into_request()
+            .is_err());
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "image-data")]
+use crate::request::image::ImageData;
 use crate::{
     client::Client,
     error::Error,
@@ -58,6 +60,18 @@ impl<'a> CreateEmoji<'a> {
         }
     }
 
+    /// Set the emoji's image from an image validated as an
+    /// [`ImageDataKind::Emoji`], replacing the Data URI passed when creating
+    /// the request.
+    ///
+    /// [`ImageDataKind::Emoji`]: crate::request::image::ImageDataKind::Emoji
+    #[cfg(feature = "image-data")]
+    pub fn image_data(mut self, image: &'a ImageData) -> Self {
+        self.fields.image = image.as_str();
+
+        self
+    }
+
     /// Whitelist roles for this emoji.
     ///
     /// See [Discord Docs/Emoji Object].
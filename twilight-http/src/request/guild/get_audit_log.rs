@@ -1,11 +1,19 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{
+        pagination::{Paginate, Paginator},
+        Request, TryIntoRequest,
+    },
     response::{Response, ResponseFuture},
     routing::Route,
 };
-use std::future::IntoFuture;
+use futures_core::Stream;
+use std::{
+    future::IntoFuture,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use twilight_model::{
     guild::audit_log::{AuditLog, AuditLogEventType},
     id::{
@@ -25,6 +33,9 @@ struct GetAuditLogFields {
     user_id: Option<Id<UserMarker>>,
 }
 
+/// Maximum number of entries that can be retrieved in a single page.
+const AUDIT_LOG_PAGE_LIMIT: u16 = 100;
+
 /// Get the audit log for a guild.
 ///
 /// # Examples
@@ -131,6 +142,92 @@ impl<'a> GetAuditLog<'a> {
 
         self
     }
+
+    /// Walk backwards through the audit log, page by page.
+    ///
+    /// Pages are requested using the [`before`] cursor, starting from the
+    /// configured value or the newest entry if none was set. The configured
+    /// [`limit`] is used as the page size and defaults to 100, the maximum.
+    ///
+    /// The [`after`] value is used as a cutoff rather than being sent to
+    /// Discord: entries with an ID equal to or lower than it are removed from
+    /// the final page and the stream ends. Otherwise the stream ends once a
+    /// page has fewer entries than the page size.
+    ///
+    /// Each page is yielded in full, including the users, integrations,
+    /// webhooks, and other information referenced by its entries.
+    ///
+    /// # Examples
+    ///
+    /// Print the reason of every ban in the audit log:
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::{guild::audit_log::AuditLogEventType, id::Id};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("token".to_owned());
+    ///
+    /// let mut pages = client
+    ///     .audit_log(Id::new(1))
+    ///     .action_type(AuditLogEventType::MemberBanAdd)
+    ///     .paginate();
+    ///
+    /// while let Some(page) = pages.next().await {
+    ///     for entry in page?.entries {
+    ///         println!("{}: {:?}", entry.id, entry.reason);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Validation`] as the first and only item if a
+    /// field failed validation.
+    ///
+    /// Returns an error of type [`ChunkingResponse`] if a page's response
+    /// body could not be read.
+    ///
+    /// Returns an error of type [`Parsing`] if a page's response body could
+    /// not be deserialized.
+    ///
+    /// Errors from sending a request are returned as documented on
+    /// [`ResponseFuture`]. The stream ends after any error.
+    ///
+    /// [`ChunkingResponse`]: crate::error::ErrorType::ChunkingResponse
+    /// [`Parsing`]: crate::error::ErrorType::Parsing
+    /// [`Validation`]: crate::error::ErrorType::Validation
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> AuditLogPages<'a> {
+        let (fields, error) = match self.fields {
+            Ok(fields) => (Some(fields), None),
+            Err(source) => (None, Some(Error::validation(source))),
+        };
+
+        let before = fields.as_ref().and_then(|fields| fields.before);
+        let pagination = AuditLogPagination {
+            action_type: fields.as_ref().and_then(|fields| fields.action_type),
+            cutoff: fields.as_ref().and_then(|fields| fields.after),
+            guild_id: self.guild_id,
+            limit: fields
+                .as_ref()
+                .and_then(|fields| fields.limit)
+                .unwrap_or(AUDIT_LOG_PAGE_LIMIT),
+            user_id: fields.and_then(|fields| fields.user_id),
+        };
+
+        let inner = match error {
+            Some(source) => Paginator::failed(self.http, pagination, source),
+            None => Paginator::new(self.http, pagination, before),
+        };
+
+        AuditLogPages { inner }
+    }
 }
 
 impl IntoFuture for GetAuditLog<'_> {
@@ -162,3 +259,180 @@ impl TryIntoRequest for GetAuditLog<'_> {
         }))
     }
 }
+
+/// Paginated endpoint of a guild's audit log, walking backwards from the
+/// newest entry.
+struct AuditLogPagination {
+    action_type: Option<AuditLogEventType>,
+    cutoff: Option<u64>,
+    guild_id: Id<GuildMarker>,
+    limit: u16,
+    user_id: Option<Id<UserMarker>>,
+}
+
+impl Paginate for AuditLogPagination {
+    type Cursor = u64;
+    type Page = AuditLog;
+
+    fn request(&self, before: Option<u64>) -> Request {
+        Request::from_route(&Route::GetAuditLogs {
+            action_type: self.action_type.map(|x| u64::from(u16::from(x))),
+            after: None,
+            before,
+            guild_id: self.guild_id.get(),
+            limit: Some(self.limit),
+            user_id: self.user_id.map(Id::get),
+        })
+    }
+
+    fn advance(&self, page: &mut AuditLog) -> Option<u64> {
+        advance(page, self.cutoff, self.limit)
+    }
+
+    fn is_empty(page: &AuditLog) -> bool {
+        page.entries.is_empty()
+    }
+}
+
+/// Stream of audit log pages, walking backwards from the newest entry.
+///
+/// Obtained via [`GetAuditLog::paginate`]; refer to its documentation for
+/// more information.
+#[must_use = "streams do nothing unless polled"]
+pub struct AuditLogPages<'a> {
+    inner: Paginator<'a, AuditLogPagination>,
+}
+
+impl Stream for AuditLogPages<'_> {
+    type Item = Result<AuditLog, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+/// Remove entries at or below the cutoff from a page, returning the cursor to
+/// request the next page with if there may be more entries.
+fn advance(page: &mut AuditLog, cutoff: Option<u64>, limit: u16) -> Option<u64> {
+    let received = page.entries.len();
+
+    if let Some(cutoff) = cutoff {
+        page.entries.retain(|entry| entry.id.get() > cutoff);
+    }
+
+    if received < usize::from(limit) || page.entries.len() < received {
+        return None;
+    }
+
+    page.entries.iter().map(|entry| entry.id.get()).min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{advance, AuditLogPages, GetAuditLog};
+    use crate::{request::TryIntoRequest, Client};
+    use futures_core::Stream;
+    use static_assertions::assert_impl_all;
+    use twilight_model::{
+        guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEventType},
+        id::Id,
+    };
+
+    assert_impl_all!(AuditLogPages<'_>: Stream, Unpin);
+
+    fn page(ids: &[u64]) -> AuditLog {
+        AuditLog {
+            application_commands: Vec::new(),
+            auto_moderation_rules: Vec::new(),
+            entries: ids
+                .iter()
+                .map(|id| AuditLogEntry {
+                    action_type: AuditLogEventType::MemberBanAdd,
+                    changes: Vec::new(),
+                    guild_id: None,
+                    id: Id::new(*id),
+                    options: None,
+                    reason: None,
+                    target_id: None,
+                    user_id: None,
+                })
+                .collect(),
+            guild_scheduled_events: Vec::new(),
+            integrations: Vec::new(),
+            threads: Vec::new(),
+            users: Vec::new(),
+            webhooks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn query() {
+        let client = Client::new(String::new());
+
+        let request = GetAuditLog::new(&client, Id::new(1))
+            .action_type(AuditLogEventType::MemberBanAdd)
+            .before(200)
+            .limit(50)
+            .user_id(Id::new(3))
+            .try_into_request()
+            .unwrap();
+
+        assert_eq!(
+            "guilds/1/audit-logs?action_type=22&before=200&limit=50&user_id=3",
+            request.path()
+        );
+    }
+
+    #[test]
+    fn pages_query() {
+        let client = Client::new(String::new());
+
+        let mut pages = GetAuditLog::new(&client, Id::new(1))
+            .action_type(AuditLogEventType::MemberBanAdd)
+            .after(100)
+            .user_id(Id::new(3))
+            .paginate();
+
+        assert_eq!(
+            "guilds/1/audit-logs?action_type=22&limit=100&user_id=3",
+            pages.inner.request().path()
+        );
+
+        pages.inner.set_cursor(200);
+
+        assert_eq!(
+            "guilds/1/audit-logs?action_type=22&before=200&limit=100&user_id=3",
+            pages.inner.request().path()
+        );
+    }
+
+    #[test]
+    fn advance_full_page() {
+        let mut audit_log = page(&[5, 4, 3]);
+
+        assert_eq!(Some(3), advance(&mut audit_log, None, 3));
+        assert_eq!(3, audit_log.entries.len());
+    }
+
+    #[test]
+    fn advance_exhausted() {
+        let mut audit_log = page(&[5, 4]);
+
+        assert_eq!(None, advance(&mut audit_log, None, 3));
+        assert_eq!(2, audit_log.entries.len());
+    }
+
+    #[test]
+    fn advance_cutoff() {
+        let mut audit_log = page(&[5, 4, 3]);
+        assert_eq!(None, advance(&mut audit_log, Some(3), 3));
+        assert_eq!(
+            [5, 4],
+            [audit_log.entries[0].id.get(), audit_log.entries[1].id.get()]
+        );
+
+        let mut audit_log = page(&[5, 4, 3]);
+        assert_eq!(Some(3), advance(&mut audit_log, Some(2), 3));
+        assert_eq!(3, audit_log.entries.len());
+    }
+}
@@ -15,15 +15,15 @@ use twilight_model::{
 };
 use twilight_validate::request::{guild_prune_days as validate_guild_prune_days, ValidationError};
 
-struct GetGuildPruneCountFields<'a> {
+struct GetGuildPruneCountFields {
     days: Option<u16>,
-    include_roles: &'a [Id<RoleMarker>],
+    include_roles: Vec<Id<RoleMarker>>,
 }
 
 /// Get the counts of guild members to be pruned.
 #[must_use = "requests must be configured and executed"]
 pub struct GetGuildPruneCount<'a> {
-    fields: Result<GetGuildPruneCountFields<'a>, ValidationError>,
+    fields: Result<GetGuildPruneCountFields, ValidationError>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
 }
@@ -33,7 +33,7 @@ impl<'a> GetGuildPruneCount<'a> {
         Self {
             fields: Ok(GetGuildPruneCountFields {
                 days: None,
-                include_roles: &[],
+                include_roles: Vec::new(),
             }),
             guild_id,
             http,
@@ -62,10 +62,13 @@ impl<'a> GetGuildPruneCount<'a> {
         self
     }
 
-    /// List of roles to include when calculating prune count
-    pub fn include_roles(mut self, roles: &'a [Id<RoleMarker>]) -> Self {
+    /// Set the roles to include when calculating the prune count.
+    ///
+    /// By default members with roles are not counted. Members with any of the
+    /// provided roles are also counted.
+    pub fn include_roles(mut self, roles: impl IntoIterator<Item = Id<RoleMarker>>) -> Self {
         if let Ok(fields) = self.fields.as_mut() {
-            fields.include_roles = roles;
+            fields.include_roles = roles.into_iter().collect();
         }
 
         self
@@ -94,7 +97,7 @@ impl TryIntoRequest for GetGuildPruneCount<'_> {
         Ok(Request::from_route(&Route::GetGuildPruneCount {
             days: fields.days,
             guild_id: self.guild_id.get(),
-            include_roles: fields.include_roles,
+            include_roles: &fields.include_roles,
         }))
     }
 }
@@ -103,6 +106,7 @@ impl TryIntoRequest for GetGuildPruneCount<'_> {
 mod tests {
     use super::GetGuildPruneCount;
     use crate::{request::TryIntoRequest, Client};
+    use std::error::Error;
     use twilight_model::id::Id;
 
     #[test]
@@ -120,4 +124,24 @@ mod tests {
         assert!(days_valid(1));
         assert!(!days_valid(u16::MAX));
     }
+
+    #[test]
+    fn include_roles() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = GetGuildPruneCount::new(&client, Id::new(1))
+            .days(7)
+            .include_roles([Id::new(2), Id::new(3)])
+            .try_into_request()?;
+
+        assert_eq!("guilds/1/prune?days=7&include_roles=2,3", request.path());
+
+        let request = GetGuildPruneCount::new(&client, Id::new(1))
+            .include_roles(Vec::new())
+            .try_into_request()?;
+
+        assert_eq!("guilds/1/prune", request.path());
+
+        Ok(())
+    }
 }
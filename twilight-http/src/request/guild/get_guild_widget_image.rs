@@ -0,0 +1,151 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+/// Style of a guild's widget image.
+///
+/// See [Discord Docs/Widget Style Options].
+///
+/// [Discord Docs/Widget Style Options]: https://discord.com/developers/docs/resources/guild#get-guild-widget-image-widget-style-options
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildWidgetImageStyle {
+    /// Large image with the guild icon, name, and online count, and a "JOIN
+    /// MY SERVER" label at the bottom.
+    Banner1,
+    /// Smaller image with the guild icon, name, and online count, and a
+    /// Discord logo at the bottom.
+    Banner2,
+    /// Large image with the guild icon, name, and online count, and a Discord
+    /// logo and "Chat Now" label at the bottom.
+    Banner3,
+    /// Large image with the guild icon, name, and online count, a large
+    /// Discord logo at the top, and a "JOIN MY SERVER" label at the bottom.
+    Banner4,
+    /// Shield style image with a Discord icon and the guild's online count.
+    ///
+    /// This is the default style.
+    Shield,
+}
+
+impl GuildWidgetImageStyle {
+    /// Name of the style as used in the query string.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Banner1 => "banner1",
+            Self::Banner2 => "banner2",
+            Self::Banner3 => "banner3",
+            Self::Banner4 => "banner4",
+            Self::Shield => "shield",
+        }
+    }
+}
+
+/// Get a guild's widget as a PNG image.
+///
+/// The response isn't JSON, so retrieve the image via [`Response::bytes`].
+/// The guild's widget must be enabled.
+///
+/// See [Discord Docs/Get Guild Widget Image].
+///
+/// # Examples
+///
+/// ```no_run
+/// use twilight_http::{request::guild::GuildWidgetImageStyle, Client};
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let image = client
+///     .guild_widget_image(Id::new(1))
+///     .style(GuildWidgetImageStyle::Banner2)
+///     .await?
+///     .bytes()
+///     .await?;
+///
+/// std::fs::write("widget.png", image)?;
+/// # Ok(()) }
+/// ```
+///
+/// [Discord Docs/Get Guild Widget Image]: https://discord.com/developers/docs/resources/guild#get-guild-widget-image
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildWidgetImage<'a> {
+    guild_id: Id<GuildMarker>,
+    http: &'a Client,
+    style: Option<GuildWidgetImageStyle>,
+}
+
+impl<'a> GetGuildWidgetImage<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            guild_id,
+            http,
+            style: None,
+        }
+    }
+
+    /// Set the style of the image.
+    ///
+    /// Defaults to [`GuildWidgetImageStyle::Shield`].
+    pub const fn style(mut self, style: GuildWidgetImageStyle) -> Self {
+        self.style = Some(style);
+
+        self
+    }
+}
+
+impl IntoFuture for GetGuildWidgetImage<'_> {
+    type Output = Result<Response<EmptyBody>, Error>;
+
+    type IntoFuture = ResponseFuture<EmptyBody>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetGuildWidgetImage<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::GetGuildWidgetImage {
+            guild_id: self.guild_id.get(),
+            style: self.style.map(GuildWidgetImageStyle::name),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildWidgetImageStyle;
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn style() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+
+        let request = client.guild_widget_image(Id::new(1)).try_into_request()?;
+        assert_eq!("guilds/1/widget.png", request.path());
+
+        let request = client
+            .guild_widget_image(Id::new(1))
+            .style(GuildWidgetImageStyle::Banner2)
+            .try_into_request()?;
+        assert_eq!("guilds/1/widget.png?style=banner2", request.path());
+
+        Ok(())
+    }
+}
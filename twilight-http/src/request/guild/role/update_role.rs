@@ -8,7 +8,7 @@ use crate::{
 use serde::Serialize;
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::{Permissions, Role},
+    guild::{Permissions, Role, RoleColors},
     id::{
         marker::{GuildMarker, RoleMarker},
         Id,
@@ -21,6 +21,8 @@ struct UpdateRoleFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     color: Option<Nullable<u32>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    colors: Option<RoleColors>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     hoist: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     icon: Option<Nullable<&'a str>>,
@@ -53,6 +55,7 @@ impl<'a> UpdateRole<'a> {
         Self {
             fields: UpdateRoleFields {
                 color: None,
+                colors: None,
                 hoist: None,
                 icon: None,
                 mentionable: None,
@@ -80,6 +83,19 @@ impl<'a> UpdateRole<'a> {
         self
     }
 
+    /// Set the colors of the role.
+    ///
+    /// Setting a secondary color displays the role with a gradient, which
+    /// requires the guild to have the `ENHANCED_ROLE_COLORS` feature. Takes
+    /// precedence over [`color`].
+    ///
+    /// [`color`]: Self::color
+    pub const fn colors(mut self, colors: RoleColors) -> Self {
+        self.fields.colors = Some(colors);
+
+        self
+    }
+
     /// If true, display the role in the members list.
     pub const fn hoist(mut self, hoist: bool) -> Self {
         self.fields.hoist = Some(hoist);
@@ -1,20 +1,20 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{multipart::Form, AuditLogReason, Request, TryIntoRequest},
+    request::{self, multipart::Form, AuditLogReason, Request, TryIntoRequest},
     response::{Response, ResponseFuture},
     routing::Route,
 };
 use std::future::IntoFuture;
 use twilight_model::{
-    channel::message::Sticker,
+    channel::message::{sticker::StickerFormatType, Sticker},
     id::{marker::GuildMarker, Id},
 };
 use twilight_validate::{
     request::{audit_reason as validate_audit_reason, ValidationError},
     sticker::{
-        description as validate_description, name as validate_name, tags as validate_tags,
-        StickerValidationError,
+        description as validate_description, file as validate_file, file_format,
+        name as validate_name, tags as validate_tags, StickerValidationError,
     },
 };
 
@@ -27,6 +27,11 @@ struct CreateGuildStickerFields<'a> {
 
 /// Creates a sticker in a guild, and returns the created sticker.
 ///
+/// The file is the raw PNG, APNG, GIF, or Lottie file, which is uploaded as
+/// part of a multipart form. Guilds may only have a limited number of stickers
+/// depending on their premium tier, see
+/// [`twilight_validate::sticker::guild_limit`].
+///
 /// # Examples
 ///
 /// ```no_run
@@ -38,13 +43,14 @@ struct CreateGuildStickerFields<'a> {
 /// let client = Client::new("my token".to_owned());
 ///
 /// let guild_id = Id::new(1);
+/// let file = std::fs::read("sticker.png")?;
 /// let sticker = client
 ///     .create_guild_sticker(
 ///         guild_id,
-///         &"sticker name",
-///         &"sticker description",
-///         &"sticker,tags",
-///         &[23, 23, 23, 23],
+///         "sticker name",
+///         "sticker description",
+///         "sticker,tags",
+///         &file,
 ///     )
 ///     .await?
 ///     .model()
@@ -53,6 +59,7 @@ struct CreateGuildStickerFields<'a> {
 /// println!("{sticker:#?}");
 /// # Ok(()) }
 /// ```
+#[must_use = "requests must be configured and executed"]
 pub struct CreateGuildSticker<'a> {
     fields: Result<CreateGuildStickerFields<'a>, StickerValidationError>,
     guild_id: Id<GuildMarker>,
@@ -77,6 +84,7 @@ impl<'a> CreateGuildSticker<'a> {
         })
         .and_then(|fields| {
             validate_description(description)?;
+            validate_file(file)?;
             validate_name(name)?;
             validate_tags(tags)?;
 
@@ -122,14 +130,92 @@ impl TryIntoRequest for CreateGuildSticker<'_> {
             guild_id: self.guild_id.get(),
         });
 
+        // Discord determines the format from the file name's extension.
+        let filename: &[u8] = match file_format(fields.file) {
+            Some(StickerFormatType::Gif) => b"sticker.gif",
+            Some(StickerFormatType::Lottie) => b"sticker.json",
+            _ => b"sticker.png",
+        };
+
         let form = Form::new()
             .part(b"description", fields.description.as_bytes())
-            .part(b"file", fields.file)
+            .file_part(b"file", filename, fields.file)
             .part(b"name", fields.name.as_bytes())
             .part(b"tags", fields.tags.as_bytes());
 
         request = request.form(form);
 
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use std::{error::Error, str};
+    use twilight_model::id::Id;
+
+    const GIF: &[u8] = b"GIF89a";
+
+    #[test]
+    fn form() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client
+            .create_guild_sticker(Id::new(1), "name", "description", "tags", GIF)
+            .reason("reason")
+            .try_into_request()?;
+
+        let form = request.form().unwrap().clone().build();
+        let form = str::from_utf8(&form)?;
+
+        for part in [
+            "name=\"description\"\r\n\r\ndescription\r\n",
+            "name=\"file\"; filename=\"sticker.gif\"\r\n\r\nGIF89a\r\n",
+            "name=\"name\"\r\n\r\nname\r\n",
+            "name=\"tags\"\r\n\r\ntags\r\n",
+        ] {
+            assert!(form.contains(part), "{part:?} not in {form:?}");
+        }
+
+        assert_eq!(
+            Some("reason"),
+            request
+                .headers()
+                .and_then(|headers| headers.get(REASON_HEADER_NAME))
+                .and_then(|value| value.to_str().ok())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new("token".to_owned());
+        let long = "a".repeat(201);
+        let reason = "a".repeat(513);
+
+        let requests = [
+            client.create_guild_sticker(Id::new(1), "a", "description", "tags", GIF),
+            client.create_guild_sticker(Id::new(1), "name", &long, "tags", GIF),
+            client.create_guild_sticker(Id::new(1), "name", "description", &long, GIF),
+            client.create_guild_sticker(Id::new(1), "name", "description", "tags", &[]),
+            client.create_guild_sticker(Id::new(1), "name", "description", "tags", b"\xff\xd8"),
+            client
+                .create_guild_sticker(Id::new(1), "name", "description", "tags", GIF)
+                .reason(&reason),
+        ];
+
+        for request in requests {
+            let error = request.try_into_request().unwrap_err();
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+    }
+}
@@ -24,8 +24,11 @@ use twilight_validate::{
 
 #[derive(Serialize)]
 struct UpdateGuildStickerFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tags: Option<&'a str>,
 }
 
@@ -169,10 +172,61 @@ impl TryIntoRequest for UpdateGuildSticker<'_> {
         })
         .json(&fields);
 
-        if let Ok(Some(reason)) = self.reason {
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
             request = request.headers(request::audit_header(reason)?);
         }
 
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::{AuditLogReason, TryIntoRequest},
+    };
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn body() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = client
+            .update_guild_sticker(Id::new(1), Id::new(2))
+            .name("name")
+            .try_into_request()?;
+
+        assert_eq!(Some(&br#"{"name":"name"}"#[..]), request.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() {
+        let client = Client::new("token".to_owned());
+        let long = "a".repeat(201);
+        let reason = "a".repeat(513);
+
+        let requests = [
+            client
+                .update_guild_sticker(Id::new(1), Id::new(2))
+                .name("a"),
+            client
+                .update_guild_sticker(Id::new(1), Id::new(2))
+                .description(&long),
+            client
+                .update_guild_sticker(Id::new(1), Id::new(2))
+                .tags(&long),
+            client
+                .update_guild_sticker(Id::new(1), Id::new(2))
+                .reason(&reason),
+        ];
+
+        for request in requests {
+            let error = request.try_into_request().unwrap_err();
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+    }
+}
@@ -0,0 +1,482 @@
+//! Image data for uploading avatars and emojis.
+//!
+//! Discord accepts images in a number of requests, such as [`CreateEmoji`] and
+//! [`UpdateCurrentUser::avatar`], as a [Data URI]. [`ImageData`] validates an
+//! image against the limits of what it's used for and encodes it in this form.
+//!
+//! [`CreateEmoji`]: super::guild::emoji::CreateEmoji
+//! [`UpdateCurrentUser::avatar`]: super::user::UpdateCurrentUser::avatar
+//! [Data URI]: https://discord.com/developers/docs/reference#image-data
+
+use crate::{client::Client, response::StatusCode};
+use http::header::CONTENT_TYPE;
+use http_body_util::BodyExt;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Maximum size of an avatar in bytes.
+pub const AVATAR_SIZE_MAX: usize = 10 * 1024 * 1024;
+
+/// Maximum size of an emoji in bytes.
+pub const EMOJI_SIZE_MAX: usize = 256 * 1024;
+
+/// Image MIME types accepted by Discord.
+pub const IMAGE_MIME_TYPES: &[&str] = &[
+    "image/avif",
+    "image/gif",
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+];
+
+/// Characters used for encoding in base64, indexed by their value.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Creating [`ImageData`] failed.
+#[derive(Debug)]
+pub struct ImageDataError {
+    kind: ImageDataErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ImageDataError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ImageDataErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ImageDataErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Create an error of type [`Downloading`] with a source error.
+    ///
+    /// [`Downloading`]: ImageDataErrorType::Downloading
+    fn downloading(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: ImageDataErrorType::Downloading,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ImageDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ImageDataErrorType::ContentTypeMissing => {
+                f.write_str("downloaded image has no valid content type")
+            }
+            ImageDataErrorType::Downloading => f.write_str("downloading the image failed"),
+            ImageDataErrorType::MimeInvalid { kind, mime } => {
+                f.write_str("image mime type ")?;
+                f.write_str(mime)?;
+                f.write_str(" isn't supported for ")?;
+
+                f.write_str(kind.name())
+            }
+            ImageDataErrorType::SizeInvalid { kind, size } => {
+                f.write_str("image is ")?;
+                Display::fmt(size, f)?;
+                f.write_str(" bytes, but ")?;
+                f.write_str(kind.name())?;
+                f.write_str(" must be at least 1 and at most ")?;
+
+                Display::fmt(&kind.size_max(), f)
+            }
+            ImageDataErrorType::StatusInvalid { status } => {
+                f.write_str("downloading the image returned status code ")?;
+
+                Display::fmt(status, f)
+            }
+        }
+    }
+}
+
+impl Error for ImageDataError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ImageDataError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageDataErrorType {
+    /// Downloaded image has no valid `Content-Type` header.
+    ContentTypeMissing,
+    /// Downloading the image failed.
+    ///
+    /// The source error is the HTTP error.
+    Downloading,
+    /// MIME type isn't one of the kind's [MIME types].
+    ///
+    /// [MIME types]: ImageDataKind::mime_types
+    MimeInvalid {
+        /// Kind of image.
+        kind: ImageDataKind,
+        /// Provided MIME type.
+        mime: String,
+    },
+    /// Image is empty or larger than the kind's [maximum size].
+    ///
+    /// When downloading, the size is the number of bytes received before the
+    /// download was stopped.
+    ///
+    /// [maximum size]: ImageDataKind::size_max
+    SizeInvalid {
+        /// Kind of image.
+        kind: ImageDataKind,
+        /// Provided size in bytes.
+        size: usize,
+    },
+    /// Downloading the image returned an unsuccessful status code.
+    StatusInvalid {
+        /// Status code of the response.
+        status: StatusCode,
+    },
+}
+
+/// What an image is uploaded as, determining its limits.
+///
+/// Sticker files aren't image data and are instead validated by
+/// [`twilight_validate::sticker::file`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageDataKind {
+    /// User avatar.
+    Avatar,
+    /// Guild emoji.
+    Emoji,
+}
+
+impl ImageDataKind {
+    /// MIME types accepted for the kind of image.
+    pub const fn mime_types(self) -> &'static [&'static str] {
+        match self {
+            Self::Avatar | Self::Emoji => IMAGE_MIME_TYPES,
+        }
+    }
+
+    /// Maximum size in bytes of the kind of image.
+    pub const fn size_max(self) -> usize {
+        match self {
+            Self::Avatar => AVATAR_SIZE_MAX,
+            Self::Emoji => EMOJI_SIZE_MAX,
+        }
+    }
+
+    /// Name of the kind of image, used in error messages.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Avatar => "avatars",
+            Self::Emoji => "emojis",
+        }
+    }
+}
+
+/// Image validated for what it's uploaded as and encoded as a [Data URI].
+///
+/// The image can be passed to [`CreateEmoji::image_data`] and
+/// [`UpdateCurrentUser::avatar_data`], or its encoded form to other requests
+/// accepting image data via [`as_str`].
+///
+/// # Examples
+///
+/// Create an emoji from an image on disk:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::{
+///     request::image::{ImageData, ImageDataKind},
+///     Client,
+/// };
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(std::env::var("DISCORD_TOKEN")?);
+/// let image = ImageData::from_bytes(
+///     ImageDataKind::Emoji,
+///     "image/png",
+///     std::fs::read("sparkle.png")?,
+/// )?;
+///
+/// client
+///     .create_emoji(Id::new(1), "sparkle", "")
+///     .image_data(&image)
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`CreateEmoji::image_data`]: super::guild::emoji::CreateEmoji::image_data
+/// [`UpdateCurrentUser::avatar_data`]: super::user::UpdateCurrentUser::avatar_data
+/// [`as_str`]: Self::as_str
+/// [Data URI]: https://discord.com/developers/docs/reference#image-data
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageData {
+    bytes: Vec<u8>,
+    kind: ImageDataKind,
+    uri: String,
+}
+
+impl ImageData {
+    /// Create image data from an image's MIME type and contents, validating it
+    /// for its kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`MimeInvalid`] if the MIME type isn't one of
+    /// the kind's [MIME types].
+    ///
+    /// Returns an error of type [`SizeInvalid`] if the image is empty or larger
+    /// than the kind's [maximum size].
+    ///
+    /// [`MimeInvalid`]: ImageDataErrorType::MimeInvalid
+    /// [`SizeInvalid`]: ImageDataErrorType::SizeInvalid
+    /// [MIME types]: ImageDataKind::mime_types
+    /// [maximum size]: ImageDataKind::size_max
+    pub fn from_bytes(
+        kind: ImageDataKind,
+        mime: &str,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Result<Self, ImageDataError> {
+        let bytes = bytes.into();
+
+        if !kind.mime_types().contains(&mime) {
+            return Err(ImageDataError {
+                kind: ImageDataErrorType::MimeInvalid {
+                    kind,
+                    mime: mime.to_owned(),
+                },
+                source: None,
+            });
+        }
+
+        if bytes.is_empty() || bytes.len() > kind.size_max() {
+            return Err(ImageDataError {
+                kind: ImageDataErrorType::SizeInvalid {
+                    kind,
+                    size: bytes.len(),
+                },
+                source: None,
+            });
+        }
+
+        let mut uri = String::with_capacity(mime.len() + 13 + bytes.len().div_ceil(3) * 4);
+        uri.push_str("data:");
+        uri.push_str(mime);
+        uri.push_str(";base64,");
+        encode_base64(&bytes, &mut uri);
+
+        Ok(Self { bytes, kind, uri })
+    }
+
+    /// Download an image and create image data from it.
+    ///
+    /// The image is downloaded with the client's HTTP connector and timeout.
+    /// The MIME type is taken from the response's `Content-Type` header, and
+    /// the download is stopped once the image is larger than the kind's
+    /// [maximum size].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Downloading`] if the request failed or timed
+    /// out.
+    ///
+    /// Returns an error of type [`StatusInvalid`] if the response's status code
+    /// isn't successful.
+    ///
+    /// Returns an error of type [`ContentTypeMissing`] if the response has no
+    /// valid `Content-Type` header.
+    ///
+    /// Refer to [`from_bytes`] for validation errors.
+    ///
+    /// [`ContentTypeMissing`]: ImageDataErrorType::ContentTypeMissing
+    /// [`Downloading`]: ImageDataErrorType::Downloading
+    /// [`StatusInvalid`]: ImageDataErrorType::StatusInvalid
+    /// [`from_bytes`]: Self::from_bytes
+    /// [maximum size]: ImageDataKind::size_max
+    pub async fn from_url(
+        client: &Client,
+        kind: ImageDataKind,
+        url: &str,
+    ) -> Result<Self, ImageDataError> {
+        let response = client
+            .download(url)
+            .await
+            .map_err(ImageDataError::downloading)?;
+
+        let status = StatusCode::new(response.status().as_u16());
+
+        if !status.is_success() {
+            return Err(ImageDataError {
+                kind: ImageDataErrorType::StatusInvalid { status },
+                source: None,
+            });
+        }
+
+        let mime = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|value| value.trim().to_ascii_lowercase())
+            .ok_or(ImageDataError {
+                kind: ImageDataErrorType::ContentTypeMissing,
+                source: None,
+            })?;
+
+        let mut body = response.into_body();
+        let mut bytes = Vec::new();
+
+        while let Some(frame) = body.frame().await {
+            let frame = frame.map_err(ImageDataError::downloading)?;
+
+            if let Ok(data) = frame.into_data() {
+                bytes.extend_from_slice(&data);
+            }
+
+            if bytes.len() > kind.size_max() {
+                return Err(ImageDataError {
+                    kind: ImageDataErrorType::SizeInvalid {
+                        kind,
+                        size: bytes.len(),
+                    },
+                    source: None,
+                });
+            }
+        }
+
+        Self::from_bytes(kind, &mime, bytes)
+    }
+
+    /// Encoded [Data URI] of the image.
+    ///
+    /// [Data URI]: https://discord.com/developers/docs/reference#image-data
+    pub fn as_str(&self) -> &str {
+        &self.uri
+    }
+
+    /// Raw contents of the image.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Kind of image the image was validated for.
+    pub const fn kind(&self) -> ImageDataKind {
+        self.kind
+    }
+}
+
+/// Encode bytes in standard, padded base64, appending them to the output.
+fn encode_base64(bytes: &[u8], output: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let mut group = [0; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+
+        let value = u32::from(group[0]) << 16 | u32::from(group[1]) << 8 | u32::from(group[2]);
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (value >> (18 - 6 * index)) & 0x3F;
+                output.push(char::from(BASE64_ALPHABET[sextet as usize]));
+            } else {
+                output.push('=');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_base64, ImageData, ImageDataError, ImageDataErrorType, ImageDataKind, EMOJI_SIZE_MAX,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(ImageData: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ImageDataError: Debug, Error, Send, Sync);
+    assert_impl_all!(ImageDataKind: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn base64() {
+        const CASES: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (b"foobar", "Zm9vYmFy"),
+            (&[0xFB, 0xFF, 0xBF], "+/+/"),
+        ];
+
+        for (input, expected) in CASES {
+            let mut output = String::new();
+            encode_base64(input, &mut output);
+
+            assert_eq!(*expected, output);
+        }
+    }
+
+    #[test]
+    fn from_bytes() {
+        let image =
+            ImageData::from_bytes(ImageDataKind::Emoji, "image/png", b"sparkle".to_vec()).unwrap();
+
+        assert_eq!("data:image/png;base64,c3BhcmtsZQ==", image.as_str());
+        assert_eq!(b"sparkle", image.bytes());
+        assert_eq!(ImageDataKind::Emoji, image.kind());
+    }
+
+    #[test]
+    fn mime_invalid() {
+        let error = ImageData::from_bytes(ImageDataKind::Emoji, "text/plain", b"sparkle".to_vec())
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ImageDataErrorType::MimeInvalid { mime, .. } if mime == "text/plain"
+        ));
+    }
+
+    #[test]
+    fn size_invalid() {
+        let emoji = |bytes| ImageData::from_bytes(ImageDataKind::Emoji, "image/gif", bytes);
+
+        assert!(emoji(vec![0; EMOJI_SIZE_MAX]).is_ok());
+
+        assert!(matches!(
+            emoji(Vec::new()).unwrap_err().kind(),
+            ImageDataErrorType::SizeInvalid { size: 0, .. }
+        ));
+        assert!(matches!(
+            emoji(vec![0; EMOJI_SIZE_MAX + 1]).unwrap_err().kind(),
+            ImageDataErrorType::SizeInvalid {
+                kind: ImageDataKind::Emoji,
+                size,
+            } if *size == EMOJI_SIZE_MAX + 1
+        ));
+
+        // Avatars may be larger than emojis.
+        assert!(ImageData::from_bytes(
+            ImageDataKind::Avatar,
+            "image/gif",
+            vec![0; EMOJI_SIZE_MAX + 1]
+        )
+        .is_ok());
+    }
+}
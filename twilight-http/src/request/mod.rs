@@ -44,6 +44,8 @@ pub mod application;
 pub mod attachment;
 pub mod channel;
 pub mod guild;
+#[cfg(feature = "image-data")]
+pub mod image;
 pub mod poll;
 pub mod scheduled_event;
 pub mod sticker;
@@ -58,6 +60,7 @@ mod get_gateway_authed;
 mod get_user_application;
 mod get_voice_regions;
 mod multipart;
+mod pagination;
 mod try_into_request;
 mod update_user_application;
 
@@ -76,7 +79,7 @@ pub use self::{
 pub use twilight_http_ratelimiting::request::Method;
 
 use crate::error::{Error, ErrorType};
-use http::header::{HeaderName, HeaderValue};
+use http::header::{HeaderName, HeaderValue, AUTHORIZATION};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use serde::Serialize;
 use std::iter;
@@ -93,7 +96,7 @@ const REASON_HEADER_NAME: &str = "x-audit-log-reason";
 /// `Nullable` is provided with `None` within it then it will serialize as
 /// null. This mechanism is primarily used in patch requests.
 #[derive(Serialize)]
-struct Nullable<T>(Option<T>);
+pub(crate) struct Nullable<T>(pub(crate) Option<T>);
 
 fn audit_header(reason: &str) -> Result<impl Iterator<Item = (HeaderName, HeaderValue)>, Error> {
     let header_name = HeaderName::from_static(REASON_HEADER_NAME);
@@ -102,8 +105,30 @@ fn audit_header(reason: &str) -> Result<impl Iterator<Item = (HeaderName, Header
         kind: ErrorType::CreatingHeader {
             name: encoded_reason,
         },
+        request: None,
         source: Some(Box::new(e)),
     })?;
 
     Ok(iter::once((header_name, header_value)))
 }
+
+/// Create an authorization header for a user's OAuth2 bearer token.
+///
+/// Requests using this header should not use the client's authorization
+/// token, so that an invalid bearer token isn't mistaken for an invalid bot
+/// token.
+fn bearer_header(
+    access_token: &str,
+) -> Result<impl Iterator<Item = (HeaderName, HeaderValue)>, Error> {
+    let value = format!("Bearer {access_token}");
+    let mut header_value = HeaderValue::from_str(&value).map_err(|e| Error {
+        kind: ErrorType::CreatingHeader {
+            name: AUTHORIZATION.to_string(),
+        },
+        request: None,
+        source: Some(Box::new(e)),
+    })?;
+    header_value.set_sensitive(true);
+
+    Ok(iter::once((AUTHORIZATION, header_value)))
+}
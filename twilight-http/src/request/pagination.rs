@@ -0,0 +1,336 @@
+//! Streaming the pages of endpoints paginated with a cursor.
+
+use crate::{
+    client::Client,
+    error::{Error, ErrorType},
+    request::Request,
+    response::{BytesFuture, ResponseFuture},
+};
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Endpoint whose pages are requested relative to a cursor.
+pub(crate) trait Paginate {
+    /// Cursor pages are requested relative to, such as an ID.
+    type Cursor: Copy;
+
+    /// Deserialized body of a page.
+    type Page: DeserializeOwned;
+
+    /// Request for the page relative to a cursor, or for the first page if
+    /// there is none.
+    fn request(&self, cursor: Option<Self::Cursor>) -> Request;
+
+    /// Cursor to request the page after this one with, if there may be more
+    /// pages.
+    ///
+    /// The page may be modified, such as to remove items past a cutoff.
+    fn advance(&self, page: &mut Self::Page) -> Option<Self::Cursor>;
+
+    /// Whether a page has no items, ending the stream without being yielded.
+    fn is_empty(page: &Self::Page) -> bool;
+}
+
+/// Stage of a [`Paginator`].
+enum PaginatorStage<T> {
+    /// Response body of a page is being read.
+    Chunking(BytesFuture),
+    /// Stream has ended.
+    Completed,
+    /// Stream failed before a request was sent and will yield the error.
+    Failed(Error),
+    /// Request for the next page has yet to be sent.
+    Idle,
+    /// Request for a page has been sent.
+    InFlight(ResponseFuture<T>),
+}
+
+/// Stream of the pages of an endpoint paginated with a cursor.
+///
+/// Yields pages until [`Paginate::advance`] returns no cursor or a page is
+/// empty. The stream ends after any error.
+pub(crate) struct Paginator<'a, P: Paginate> {
+    /// Cursor to request the next page with.
+    cursor: Option<P::Cursor>,
+    /// HTTP client to send requests with.
+    http: &'a Client,
+    /// Paginated endpoint.
+    pagination: P,
+    /// Current stage of the stream.
+    stage: PaginatorStage<P::Page>,
+}
+
+impl<'a, P: Paginate> Paginator<'a, P> {
+    /// Create a stream starting at a cursor, or at the first page if there is
+    /// none.
+    pub(crate) const fn new(http: &'a Client, pagination: P, cursor: Option<P::Cursor>) -> Self {
+        Self {
+            cursor,
+            http,
+            pagination,
+            stage: PaginatorStage::Idle,
+        }
+    }
+
+    /// Create a stream yielding an error as its first and only item.
+    pub(crate) const fn failed(http: &'a Client, pagination: P, source: Error) -> Self {
+        Self {
+            cursor: None,
+            http,
+            pagination,
+            stage: PaginatorStage::Failed(source),
+        }
+    }
+
+    /// Request for the next page.
+    pub(crate) fn request(&self) -> Request {
+        self.pagination.request(self.cursor)
+    }
+
+    /// Set the cursor to request the next page with.
+    #[cfg(test)]
+    pub(crate) fn set_cursor(&mut self, cursor: P::Cursor) {
+        self.cursor = Some(cursor);
+    }
+}
+
+impl<P> Stream for Paginator<'_, P>
+where
+    P: Paginate + Unpin,
+    P::Cursor: Unpin,
+    P::Page: Unpin,
+{
+    type Item = Result<P::Page, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.stage {
+                PaginatorStage::Chunking(future) => {
+                    let result = match Pin::new(future).poll(cx) {
+                        Poll::Ready(result) => result,
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    self.stage = PaginatorStage::Completed;
+
+                    let bytes = match result {
+                        Ok(bytes) => bytes,
+                        Err(source) => {
+                            return Poll::Ready(Some(Err(Error {
+                                kind: ErrorType::ChunkingResponse,
+                                request: None,
+                                source: Some(Box::new(source)),
+                            })));
+                        }
+                    };
+
+                    let mut page = match crate::json::from_bytes::<P::Page>(&bytes) {
+                        Ok(page) => page,
+                        Err(source) => {
+                            return Poll::Ready(Some(Err(Error {
+                                kind: ErrorType::Parsing { body: bytes },
+                                request: None,
+                                source: Some(Box::new(source)),
+                            })));
+                        }
+                    };
+
+                    if let Some(cursor) = self.pagination.advance(&mut page) {
+                        self.cursor = Some(cursor);
+                        self.stage = PaginatorStage::Idle;
+                    }
+
+                    if P::is_empty(&page) {
+                        return Poll::Ready(None);
+                    }
+
+                    return Poll::Ready(Some(Ok(page)));
+                }
+                PaginatorStage::Completed => return Poll::Ready(None),
+                PaginatorStage::Failed(_) => {
+                    let PaginatorStage::Failed(source) =
+                        std::mem::replace(&mut self.stage, PaginatorStage::Completed)
+                    else {
+                        unreachable!("stage is failed")
+                    };
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+                PaginatorStage::Idle => {
+                    let future = self.http.request(self.request());
+                    self.stage = PaginatorStage::InFlight(future);
+                }
+                PaginatorStage::InFlight(future) => match Pin::new(future).poll(cx) {
+                    Poll::Ready(Ok(response)) => {
+                        self.stage = PaginatorStage::Chunking(response.bytes());
+                    }
+                    Poll::Ready(Err(source)) => {
+                        self.stage = PaginatorStage::Completed;
+
+                        return Poll::Ready(Some(Err(source)));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+            }
+        }
+    }
+}
+
+/// Cursor to request the page after this one with when paginating in
+/// ascending order, if there may be more items.
+///
+/// This is the highest ID of the page if it's full.
+pub(crate) fn after_cursor<T, C: Ord>(page: &[T], limit: u16, id: impl Fn(&T) -> C) -> Option<C> {
+    if page.len() < usize::from(limit) {
+        return None;
+    }
+
+    page.iter().map(id).max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{after_cursor, Paginate, Paginator};
+    use crate::{
+        error::{Error, ErrorType},
+        request::Request,
+        routing::Route,
+        Client,
+    };
+    use futures_util::StreamExt;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Pages of IDs requested by the `after` query parameter of the bans
+    /// route.
+    struct Ids {
+        limit: u16,
+    }
+
+    impl Paginate for Ids {
+        type Cursor = u64;
+        type Page = Vec<u64>;
+
+        fn request(&self, cursor: Option<u64>) -> Request {
+            Request::from_route(&Route::GetBansWithParameters {
+                after: cursor,
+                before: None,
+                guild_id: 1,
+                limit: Some(self.limit),
+            })
+        }
+
+        fn advance(&self, page: &mut Vec<u64>) -> Option<u64> {
+            after_cursor(page, self.limit, |id| *id)
+        }
+
+        fn is_empty(page: &Vec<u64>) -> bool {
+            page.is_empty()
+        }
+    }
+
+    /// Serve one response body per request, in order, returning the address
+    /// of the server.
+    async fn serve(bodies: Vec<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        tokio::spawn(async move {
+            for body in bodies {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..read]);
+                }
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\n\
+                     connection: close\r\n\
+                     content-length: {}\r\n\
+                     content-type: application/json\r\n\
+                     \r\n\
+                     {body}",
+                    body.len(),
+                );
+
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        address
+    }
+
+    fn client(address: String) -> Client {
+        Client::builder()
+            .proxy(address, true)
+            .ratelimiter(None)
+            .build()
+    }
+
+    #[test]
+    fn after_cursor_full_page() {
+        assert_eq!(Some(5), after_cursor(&[3, 5, 4], 3, |id| *id));
+    }
+
+    #[test]
+    fn after_cursor_exhausted() {
+        assert_eq!(None, after_cursor(&[3, 4], 3, |id| *id));
+        assert_eq!(None, after_cursor::<u64, u64>(&[], 3, |id| *id));
+    }
+
+    #[tokio::test]
+    async fn short_page_ends() {
+        let client = client(serve(vec!["[1,2]", "[3]"]).await);
+        let pages = Paginator::new(&client, Ids { limit: 2 }, None)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(vec![vec![1, 2], vec![3]], pages);
+    }
+
+    #[tokio::test]
+    async fn empty_page_ends() {
+        let client = client(serve(vec!["[1,2]", "[]"]).await);
+        let pages = Paginator::new(&client, Ids { limit: 2 }, None)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(vec![vec![1, 2]], pages);
+    }
+
+    #[tokio::test]
+    async fn error_ends() {
+        let client = client(serve(vec!["[1,2]", "{"]).await);
+        let mut pages = Paginator::new(&client, Ids { limit: 2 }, None);
+
+        assert_eq!(vec![1, 2], pages.next().await.unwrap().unwrap());
+        assert!(pages.next().await.unwrap().is_err());
+        assert!(pages.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn failed() {
+        let client = Client::new(String::new());
+        let source = Error {
+            kind: ErrorType::Unauthorized,
+            request: None,
+            source: None,
+        };
+        let pages = Paginator::failed(&client, Ids { limit: 2 }, source)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(matches!(pages.as_slice(), [Err(_)]));
+    }
+}
@@ -17,16 +17,21 @@ use crate::{
 };
 use serde::Serialize;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel},
+    guild::scheduled_event::{
+        EntityType, GuildScheduledEvent, GuildScheduledEventRecurrenceRule, PrivacyLevel,
+    },
     id::{
         marker::{ChannelMarker, GuildMarker},
         Id,
     },
     util::Timestamp,
 };
-use twilight_validate::request::{
-    audit_reason as validate_audit_reason, scheduled_event_name as validate_scheduled_event_name,
-    ValidationError,
+use twilight_validate::{
+    request::{
+        audit_reason as validate_audit_reason,
+        scheduled_event_name as validate_scheduled_event_name, ValidationError,
+    },
+    scheduled_event::{end_after_start as validate_end_after_start, entity as validate_entity},
 };
 
 #[derive(Serialize)]
@@ -46,6 +51,8 @@ struct CreateGuildScheduledEventFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     privacy_level: Option<PrivacyLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<&'a GuildScheduledEventRecurrenceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_end_time: Option<&'a Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_start_time: Option<&'a Timestamp>,
@@ -60,6 +67,12 @@ struct CreateGuildScheduledEventFields<'a> {
 /// The name must be between 1 and 100 characters in length. For external
 /// events, the location must be between 1 and 100 characters in length.
 ///
+/// The scheduled end time, if any, must be after the scheduled start time.
+/// Refer to [`twilight_validate::scheduled_event`] for the validated
+/// requirements; failing them results in an error of type [`Validation`] when
+/// the request is built. Discord additionally requires the scheduled start
+/// time to be in the future.
+///
 /// # Examples
 ///
 /// Create an event in a stage instance:
@@ -115,6 +128,7 @@ struct CreateGuildScheduledEventFields<'a> {
 /// ```
 ///
 /// [Discord Docs/Create Guild Scheduled Event]: https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event
+/// [`Validation`]: crate::error::ErrorType::Validation
 pub struct CreateGuildScheduledEvent<'a> {
     guild_id: Id<GuildMarker>,
     http: &'a Client,
@@ -139,6 +153,7 @@ impl<'a> CreateGuildScheduledEvent<'a> {
                 image: None,
                 name: None,
                 privacy_level: Some(privacy_level),
+                recurrence_rule: None,
                 scheduled_end_time: None,
                 scheduled_start_time: None,
             }),
@@ -241,6 +256,25 @@ impl<'a> CreateGuildScheduledEvent<'a> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
 
+        if let (Some(entity_type), Some(scheduled_start_time)) =
+            (fields.entity_type, fields.scheduled_start_time)
+        {
+            validate_entity(
+                entity_type,
+                fields.channel_id,
+                fields
+                    .entity_metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.location),
+                fields.scheduled_end_time,
+            )
+            .map_err(Error::validation)?;
+            if let Some(scheduled_end_time) = fields.scheduled_end_time {
+                validate_end_after_start(scheduled_start_time, scheduled_end_time)
+                    .map_err(Error::validation)?;
+            }
+        }
+
         Request::builder(&Route::CreateGuildScheduledEvent {
             guild_id: self.guild_id.get(),
         })
@@ -256,3 +290,100 @@ impl<'a> AuditLogReason<'a> for CreateGuildScheduledEvent<'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::ErrorType, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_model::guild::scheduled_event::{
+        RecurrenceRuleFrequency, RecurrenceRuleMonth, RecurrenceRuleNWeekday, RecurrenceRuleWeekday,
+    };
+
+    #[test]
+    fn recurrence_rule() -> Result<(), Box<dyn Error>> {
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+        let start = Timestamp::from_secs(1_723_831_200)?;
+        let mut rule = GuildScheduledEventRecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(Vec::from([RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Friday,
+                n: 3,
+            }])),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Monthly,
+            interval: 1,
+            start,
+        };
+
+        {
+            let expected = r#"{"channel_id":"2","entity_type":2,"name":"book club","privacy_level":2,"recurrence_rule":{"by_month":null,"by_month_day":null,"by_n_weekday":[{"day":4,"n":3}],"by_weekday":null,"by_year_day":null,"count":null,"end":null,"frequency":1,"interval":1,"start":"2024-08-16T18:00:00.000000+00:00"},"scheduled_start_time":"2024-08-16T18:00:00.000000+00:00"}"#;
+            let actual = CreateGuildScheduledEvent::new(&client, GUILD_ID, PrivacyLevel::GuildOnly)
+                .voice(CHANNEL_ID, "book club", &start)
+                .recurrence_rule(&rule)
+                .try_into_request()?;
+
+            assert_eq!(Some(expected.as_bytes()), actual.body());
+        }
+
+        {
+            rule.by_month = Some(Vec::from([RecurrenceRuleMonth::August]));
+            let error = CreateGuildScheduledEvent::new(&client, GUILD_ID, PrivacyLevel::GuildOnly)
+                .voice(CHANNEL_ID, "book club", &start)
+                .recurrence_rule(&rule)
+                .try_into_request()
+                .unwrap_err();
+
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() -> Result<(), Box<dyn Error>> {
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+        let start = Timestamp::from_secs(1_723_831_200)?;
+        let end = Timestamp::from_secs(1_723_838_400)?;
+
+        assert!(
+            CreateGuildScheduledEvent::new(&client, GUILD_ID, PrivacyLevel::GuildOnly)
+                .external("garfield con", "baltimore", &start, &end)
+                .try_into_request()
+                .is_ok()
+        );
+        assert!(
+            CreateGuildScheduledEvent::new(&client, GUILD_ID, PrivacyLevel::GuildOnly)
+                .stage_instance(CHANNEL_ID, "garfield hour", &start)
+                .try_into_request()
+                .is_ok()
+        );
+
+        let errors = [
+            CreateGuildScheduledEvent::new(&client, GUILD_ID, PrivacyLevel::GuildOnly)
+                .external("garfield con", "", &start, &end)
+                .try_into_request()
+                .unwrap_err(),
+            CreateGuildScheduledEvent::new(&client, GUILD_ID, PrivacyLevel::GuildOnly)
+                .external("garfield con", "baltimore", &end, &start)
+                .try_into_request()
+                .unwrap_err(),
+        ];
+
+        for error in errors {
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+
+        Ok(())
+    }
+}
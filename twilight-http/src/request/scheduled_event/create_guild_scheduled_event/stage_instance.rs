@@ -6,13 +6,14 @@ use crate::{
 };
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent},
+    guild::scheduled_event::{EntityType, GuildScheduledEvent, GuildScheduledEventRecurrenceRule},
     id::{marker::ChannelMarker, Id},
     util::Timestamp,
 };
 use twilight_validate::request::{
     audit_reason as validate_audit_reason,
     scheduled_event_description as validate_scheduled_event_description,
+    scheduled_event_recurrence_rule as validate_scheduled_event_recurrence_rule,
 };
 
 /// Create a stage instance scheduled event in a guild.
@@ -75,6 +76,37 @@ impl<'a> CreateGuildStageInstanceScheduledEvent<'a> {
         self
     }
 
+    /// Set the rule defining how often the event recurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ScheduledEventRecurrenceRuleExclusive`],
+    /// [`ScheduledEventRecurrenceRuleFrequency`],
+    /// [`ScheduledEventRecurrenceRuleInterval`],
+    /// [`ScheduledEventRecurrenceRuleNWeekday`], or
+    /// [`ScheduledEventRecurrenceRuleSystemField`] if the rule is invalid. See
+    /// [`twilight_validate::request::scheduled_event_recurrence_rule`] for the
+    /// constraints.
+    ///
+    /// [`ScheduledEventRecurrenceRuleExclusive`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleExclusive
+    /// [`ScheduledEventRecurrenceRuleFrequency`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleFrequency
+    /// [`ScheduledEventRecurrenceRuleInterval`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleInterval
+    /// [`ScheduledEventRecurrenceRuleNWeekday`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleNWeekday
+    /// [`ScheduledEventRecurrenceRuleSystemField`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleSystemField
+    pub fn recurrence_rule(
+        mut self,
+        recurrence_rule: &'a GuildScheduledEventRecurrenceRule,
+    ) -> Self {
+        self.0.fields = self.0.fields.and_then(|mut fields| {
+            validate_scheduled_event_recurrence_rule(recurrence_rule)?;
+            fields.recurrence_rule = Some(recurrence_rule);
+
+            Ok(fields)
+        });
+
+        self
+    }
+
     /// Set the scheduled end time of the event.
     ///
     /// This is not a required field for stage instance events.
@@ -9,17 +9,27 @@ use crate::{
 use serde::Serialize;
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, Status},
+    guild::scheduled_event::{
+        EntityType, GuildScheduledEvent, GuildScheduledEventRecurrenceRule, PrivacyLevel, Status,
+    },
     id::{
         marker::{ChannelMarker, GuildMarker, ScheduledEventMarker},
         Id,
     },
     util::Timestamp,
 };
-use twilight_validate::request::{
-    audit_reason as validate_audit_reason,
-    scheduled_event_description as validate_scheduled_event_description,
-    scheduled_event_name as validate_scheduled_event_name, ValidationError,
+use twilight_validate::{
+    request::{
+        audit_reason as validate_audit_reason,
+        scheduled_event_description as validate_scheduled_event_description,
+        scheduled_event_name as validate_scheduled_event_name,
+        scheduled_event_recurrence_rule as validate_scheduled_event_recurrence_rule,
+        ValidationError,
+    },
+    scheduled_event::{
+        end_after_start as validate_end_after_start, end_time as validate_end_time,
+        location as validate_location,
+    },
 };
 
 #[derive(Serialize)]
@@ -39,6 +49,8 @@ struct UpdateGuildScheduledEventFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     privacy_level: Option<PrivacyLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<Nullable<&'a GuildScheduledEventRecurrenceRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_end_time: Option<Nullable<&'a Timestamp>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_start_time: Option<&'a Timestamp>,
@@ -54,12 +66,17 @@ struct UpdateGuildScheduledEventFields<'a> {
 ///
 /// When changing the entity type to [`EntityType::External`], the `channel_id`
 /// field is cleared and the [`channel_id`] method has no effect.  Additionally,
-/// you must set a location with [`location`].
+/// you must set a location with [`location`] and a scheduled end time with
+/// [`scheduled_end_time`].
+///
+/// If both are set, the scheduled end time must be after the scheduled start
+/// time. Failing these requirements results in an error of type
+/// [`Validation`] when the request is built.
 ///
 /// [`channel_id`]: UpdateGuildScheduledEvent::channel_id
 /// [`location`]: UpdateGuildScheduledEvent::location
-/// [`channel_id`]: UpdateGuildScheduledEvent::channel_id
-/// [`location`]: UpdateGuildScheduledEvent::location
+/// [`scheduled_end_time`]: UpdateGuildScheduledEvent::scheduled_end_time
+/// [`Validation`]: crate::error::ErrorType::Validation
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateGuildScheduledEvent<'a> {
     guild_id: Id<GuildMarker>,
@@ -86,6 +103,7 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
                 image: None,
                 name: None,
                 privacy_level: None,
+                recurrence_rule: None,
                 scheduled_end_time: None,
                 scheduled_start_time: None,
                 status: None,
@@ -206,6 +224,42 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
         self
     }
 
+    /// Set the rule defining how often the event recurs.
+    ///
+    /// Pass [`None`] to stop the event from recurring.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ScheduledEventRecurrenceRuleExclusive`],
+    /// [`ScheduledEventRecurrenceRuleFrequency`],
+    /// [`ScheduledEventRecurrenceRuleInterval`],
+    /// [`ScheduledEventRecurrenceRuleNWeekday`], or
+    /// [`ScheduledEventRecurrenceRuleSystemField`] if the rule is invalid. See
+    /// [`twilight_validate::request::scheduled_event_recurrence_rule`] for the
+    /// constraints.
+    ///
+    /// [`ScheduledEventRecurrenceRuleExclusive`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleExclusive
+    /// [`ScheduledEventRecurrenceRuleFrequency`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleFrequency
+    /// [`ScheduledEventRecurrenceRuleInterval`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleInterval
+    /// [`ScheduledEventRecurrenceRuleNWeekday`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleNWeekday
+    /// [`ScheduledEventRecurrenceRuleSystemField`]: twilight_validate::request::ValidationErrorType::ScheduledEventRecurrenceRuleSystemField
+    pub fn recurrence_rule(
+        mut self,
+        recurrence_rule: Option<&'a GuildScheduledEventRecurrenceRule>,
+    ) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            if let Some(recurrence_rule) = recurrence_rule {
+                validate_scheduled_event_recurrence_rule(recurrence_rule)?;
+            }
+
+            fields.recurrence_rule = Some(Nullable(recurrence_rule));
+
+            Ok(fields)
+        });
+
+        self
+    }
+
     /// Set the scheduled end time of the event.
     ///
     /// Required for external events.
@@ -277,6 +331,31 @@ impl IntoFuture for UpdateGuildScheduledEvent<'_> {
 impl TryIntoRequest for UpdateGuildScheduledEvent<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
+        let scheduled_end_time = fields
+            .scheduled_end_time
+            .as_ref()
+            .and_then(|scheduled_end_time| scheduled_end_time.0);
+
+        // The channel may already be set on the event, so it can't be
+        // validated here.
+        if let Some(entity_type) = fields.entity_type {
+            validate_location(
+                entity_type,
+                fields
+                    .entity_metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.location),
+            )
+            .map_err(Error::validation)?;
+            validate_end_time(entity_type, scheduled_end_time).map_err(Error::validation)?;
+        }
+
+        if let (Some(scheduled_start_time), Some(scheduled_end_time)) =
+            (fields.scheduled_start_time, scheduled_end_time)
+        {
+            validate_end_after_start(scheduled_start_time, scheduled_end_time)
+                .map_err(Error::validation)?;
+        }
 
         Request::builder(&Route::UpdateGuildScheduledEvent {
             guild_id: self.guild_id.get(),
@@ -286,3 +365,119 @@ impl TryIntoRequest for UpdateGuildScheduledEvent<'_> {
         .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorType;
+    use std::error::Error;
+    use twilight_model::guild::scheduled_event::{RecurrenceRuleFrequency, RecurrenceRuleWeekday};
+
+    #[test]
+    fn recurrence_rule() -> Result<(), Box<dyn Error>> {
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+        const SCHEDULED_EVENT_ID: Id<ScheduledEventMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+        let mut rule = GuildScheduledEventRecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: Some(Vec::from([RecurrenceRuleWeekday::Friday])),
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Weekly,
+            interval: 2,
+            start: Timestamp::from_secs(1_723_831_200)?,
+        };
+
+        {
+            let expected = r#"{"recurrence_rule":{"by_month":null,"by_month_day":null,"by_n_weekday":null,"by_weekday":[4],"by_year_day":null,"count":null,"end":null,"frequency":2,"interval":2,"start":"2024-08-16T18:00:00.000000+00:00"}}"#;
+            let actual = UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .recurrence_rule(Some(&rule))
+                .try_into_request()?;
+
+            assert_eq!(Some(expected.as_bytes()), actual.body());
+        }
+
+        {
+            let expected = r#"{"recurrence_rule":null}"#;
+            let actual = UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .recurrence_rule(None)
+                .try_into_request()?;
+
+            assert_eq!(Some(expected.as_bytes()), actual.body());
+        }
+
+        {
+            rule.by_weekday = Some(Vec::from([
+                RecurrenceRuleWeekday::Thursday,
+                RecurrenceRuleWeekday::Friday,
+            ]));
+            let error = UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .recurrence_rule(Some(&rule))
+                .try_into_request()
+                .unwrap_err();
+
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation() -> Result<(), Box<dyn Error>> {
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+        const SCHEDULED_EVENT_ID: Id<ScheduledEventMarker> = Id::new(2);
+
+        let client = Client::new("token".into());
+        let start = Timestamp::from_secs(1_723_831_200)?;
+        let end = Timestamp::from_secs(1_723_838_400)?;
+
+        assert!(
+            UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .entity_type(EntityType::External)
+                .location(Some("baltimore"))
+                .scheduled_end_time(Some(&end))
+                .try_into_request()
+                .is_ok()
+        );
+        assert!(
+            UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .entity_type(EntityType::Voice)
+                .scheduled_start_time(&start)
+                .try_into_request()
+                .is_ok()
+        );
+
+        let errors = [
+            UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .entity_type(EntityType::External)
+                .scheduled_end_time(Some(&end))
+                .try_into_request()
+                .unwrap_err(),
+            UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .entity_type(EntityType::External)
+                .location(Some("baltimore"))
+                .try_into_request()
+                .unwrap_err(),
+            UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .entity_type(EntityType::StageInstance)
+                .location(Some("baltimore"))
+                .try_into_request()
+                .unwrap_err(),
+            UpdateGuildScheduledEvent::new(&client, GUILD_ID, SCHEDULED_EVENT_ID)
+                .scheduled_start_time(&end)
+                .scheduled_end_time(Some(&start))
+                .try_into_request()
+                .unwrap_err(),
+        ];
+
+        for error in errors {
+            assert!(matches!(error.kind(), ErrorType::Validation));
+        }
+
+        Ok(())
+    }
+}
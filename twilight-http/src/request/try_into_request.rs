@@ -26,6 +26,11 @@ mod private {
                 create_test_entitlement::CreateTestEntitlement, get_entitlements::GetEntitlements,
                 DeleteTestEntitlement, GetSKUs,
             },
+            role_connection::{
+                GetApplicationRoleConnectionMetadataRecords, GetUserApplicationRoleConnection,
+                UpdateApplicationRoleConnectionMetadataRecords,
+                UpdateUserApplicationRoleConnection,
+            },
         },
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
@@ -67,7 +72,8 @@ mod private {
                 RemoveRoleFromMember, SearchGuildMembers, UpdateGuildMember,
             },
             role::{
-                CreateRole, DeleteRole, GetGuildRoles, GetRole, UpdateRole, UpdateRolePositions,
+                CreateRole, DeleteRole, GetGuildRoleMemberCounts, GetGuildRoles, GetRole,
+                UpdateRole, UpdateRolePositions,
             },
             sticker::{
                 CreateGuildSticker, DeleteGuildSticker, GetGuildSticker, GetGuildStickers,
@@ -78,9 +84,9 @@ mod private {
             CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
             GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildOnboarding,
             GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions,
-            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetSettings,
-            UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions, UpdateGuildMfa,
-            UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
+            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetImage,
+            GetGuildWidgetSettings, UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions,
+            UpdateGuildMfa, UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
         },
         poll::{EndPoll, GetAnswerVoters},
         scheduled_event::{
@@ -181,6 +187,7 @@ mod private {
     impl Sealed for GetActiveThreads<'_> {}
     impl Sealed for ListApplicationEmojis<'_> {}
     impl Sealed for GetAnswerVoters<'_> {}
+    impl Sealed for GetApplicationRoleConnectionMetadataRecords<'_> {}
     impl Sealed for GetAuditLog<'_> {}
     impl Sealed for GetAutoModerationRule<'_> {}
     impl Sealed for GetBan<'_> {}
@@ -216,6 +223,7 @@ mod private {
     impl Sealed for GetGuildOnboarding<'_> {}
     impl Sealed for GetGuildPreview<'_> {}
     impl Sealed for GetGuildPruneCount<'_> {}
+    impl Sealed for GetGuildRoleMemberCounts<'_> {}
     impl Sealed for GetGuildRoles<'_> {}
     impl Sealed for GetGuildScheduledEvent<'_> {}
     impl Sealed for GetGuildScheduledEventUsers<'_> {}
@@ -227,6 +235,7 @@ mod private {
     impl Sealed for GetGuildWebhooks<'_> {}
     impl Sealed for GetGuildWelcomeScreen<'_> {}
     impl Sealed for GetGuildWidget<'_> {}
+    impl Sealed for GetGuildWidgetImage<'_> {}
     impl Sealed for GetGuildWidgetSettings<'_> {}
     impl Sealed for GetInvite<'_> {}
     impl Sealed for GetJoinedPrivateArchivedThreads<'_> {}
@@ -248,6 +257,7 @@ mod private {
     impl Sealed for GetThreadMembers<'_> {}
     impl Sealed for GetUser<'_> {}
     impl Sealed for GetUserApplicationInfo<'_> {}
+    impl Sealed for GetUserApplicationRoleConnection<'_> {}
     impl Sealed for GetVoiceRegions<'_> {}
     impl Sealed for GetWebhook<'_> {}
     impl Sealed for GetWebhookMessage<'_> {}
@@ -261,6 +271,7 @@ mod private {
     impl Sealed for SetGlobalCommands<'_> {}
     impl Sealed for SetGuildCommands<'_> {}
     impl Sealed for SyncTemplate<'_> {}
+    impl Sealed for UpdateApplicationRoleConnectionMetadataRecords<'_> {}
     impl Sealed for UpdateAutoModerationRule<'_> {}
     impl Sealed for UpdateChannel<'_> {}
     impl Sealed for UpdateChannelPermission<'_> {}
@@ -288,6 +299,7 @@ mod private {
     impl Sealed for UpdateStageInstance<'_> {}
     impl Sealed for UpdateTemplate<'_> {}
     impl Sealed for UpdateThread<'_> {}
+    impl Sealed for UpdateUserApplicationRoleConnection<'_> {}
     impl Sealed for UpdateUserVoiceState<'_> {}
     impl Sealed for UpdateWebhook<'_> {}
     impl Sealed for UpdateWebhookMessage<'_> {}
@@ -363,13 +375,335 @@ pub trait TryIntoRequest: private::Sealed {
 #[cfg(test)]
 mod tests {
     use super::TryIntoRequest;
+    use crate::request::{
+        application::{
+            command::{
+                create_global_command::{
+                    CreateGlobalChatInputCommand, CreateGlobalMessageCommand,
+                    CreateGlobalUserCommand,
+                },
+                create_guild_command::{
+                    CreateGuildChatInputCommand, CreateGuildMessageCommand, CreateGuildUserCommand,
+                },
+                DeleteGlobalCommand, DeleteGuildCommand, GetCommandPermissions, GetGlobalCommand,
+                GetGlobalCommands, GetGuildCommand, GetGuildCommandPermissions, GetGuildCommands,
+                SetGlobalCommands, SetGuildCommands, UpdateCommandPermissions, UpdateGlobalCommand,
+                UpdateGuildCommand,
+            },
+            emoji::{
+                AddApplicationEmoji, DeleteApplicationEmoji, ListApplicationEmojis,
+                UpdateApplicationEmoji,
+            },
+            interaction::{
+                CreateFollowup, CreateResponse, DeleteFollowup, DeleteResponse, GetFollowup,
+                GetResponse, UpdateFollowup, UpdateResponse,
+            },
+            monetization::{
+                create_test_entitlement::CreateTestEntitlement, get_entitlements::GetEntitlements,
+                DeleteTestEntitlement, GetSKUs,
+            },
+            role_connection::{
+                GetApplicationRoleConnectionMetadataRecords, GetUserApplicationRoleConnection,
+                UpdateApplicationRoleConnectionMetadataRecords,
+                UpdateUserApplicationRoleConnection,
+            },
+        },
+        channel::{
+            invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
+            message::{
+                CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, GetChannelMessages,
+                GetChannelMessagesConfigured, GetMessage, UpdateMessage,
+            },
+            reaction::{
+                CreateReaction, DeleteAllReaction, DeleteAllReactions, DeleteReaction, GetReactions,
+            },
+            stage::{
+                CreateStageInstance, DeleteStageInstance, GetStageInstance, UpdateStageInstance,
+            },
+            thread::{
+                create_forum_thread::CreateForumThreadMessage, AddThreadMember, CreateThread,
+                CreateThreadFromMessage, GetJoinedPrivateArchivedThreads,
+                GetPrivateArchivedThreads, GetPublicArchivedThreads, GetThreadMember,
+                GetThreadMembers, JoinThread, LeaveThread, RemoveThreadMember, UpdateThread,
+            },
+            webhook::{
+                CreateWebhook, DeleteWebhook, DeleteWebhookMessage, ExecuteWebhook,
+                ExecuteWebhookAndWait, GetChannelWebhooks, GetWebhook, GetWebhookMessage,
+                UpdateWebhook, UpdateWebhookMessage, UpdateWebhookWithToken,
+            },
+            CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermissionConfigured,
+            DeletePin, FollowNewsChannel, GetChannel, GetPins, UpdateChannel,
+            UpdateChannelPermission,
+        },
+        guild::{
+            auto_moderation::{
+                CreateAutoModerationRule, DeleteAutoModerationRule, GetAutoModerationRule,
+                GetGuildAutoModerationRules, UpdateAutoModerationRule,
+            },
+            ban::{CreateBan, DeleteBan, GetBan, GetBans},
+            emoji::{CreateEmoji, DeleteEmoji, GetEmoji, GetEmojis, UpdateEmoji},
+            integration::{DeleteGuildIntegration, GetGuildIntegrations},
+            member::{
+                AddGuildMember, AddRoleToMember, GetGuildMembers, GetMember, RemoveMember,
+                RemoveRoleFromMember, SearchGuildMembers, UpdateGuildMember,
+            },
+            role::{
+                CreateRole, DeleteRole, GetGuildRoleMemberCounts, GetGuildRoles, GetRole,
+                UpdateRole, UpdateRolePositions,
+            },
+            sticker::{
+                CreateGuildSticker, DeleteGuildSticker, GetGuildSticker, GetGuildStickers,
+                UpdateGuildSticker,
+            },
+            update_guild_onboarding::UpdateGuildOnboarding,
+            user::{UpdateCurrentUserVoiceState, UpdateUserVoiceState},
+            CreateGuild, CreateGuildChannel, CreateGuildPrune, DeleteGuild, GetActiveThreads,
+            GetAuditLog, GetGuild, GetGuildChannels, GetGuildInvites, GetGuildOnboarding,
+            GetGuildPreview, GetGuildPruneCount, GetGuildVanityUrl, GetGuildVoiceRegions,
+            GetGuildWebhooks, GetGuildWelcomeScreen, GetGuildWidget, GetGuildWidgetImage,
+            GetGuildWidgetSettings, UpdateCurrentMember, UpdateGuild, UpdateGuildChannelPositions,
+            UpdateGuildMfa, UpdateGuildWelcomeScreen, UpdateGuildWidgetSettings,
+        },
+        poll::{EndPoll, GetAnswerVoters},
+        scheduled_event::{
+            CreateGuildExternalScheduledEvent, CreateGuildStageInstanceScheduledEvent,
+            CreateGuildVoiceScheduledEvent, DeleteGuildScheduledEvent, GetGuildScheduledEvent,
+            GetGuildScheduledEventUsers, GetGuildScheduledEvents, UpdateGuildScheduledEvent,
+        },
+        sticker::{GetNitroStickerPacks, GetSticker},
+        template::{
+            CreateGuildFromTemplate, CreateTemplate, DeleteTemplate, GetTemplate, GetTemplates,
+            SyncTemplate, UpdateTemplate,
+        },
+        update_user_application::UpdateCurrentUserApplication,
+        user::{
+            CreatePrivateChannel, GetCurrentUser, GetCurrentUserConnections,
+            GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
+            UpdateCurrentUser,
+        },
+        GetCurrentAuthorizationInformation, GetGateway, GetGatewayAuthed, GetUserApplicationInfo,
+        GetVoiceRegions,
+    };
     use crate::{client::Client, request::Method};
-    use static_assertions::assert_obj_safe;
-    use std::error::Error;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{error::Error, future::IntoFuture};
     use twilight_model::id::Id;
 
     assert_obj_safe!(TryIntoRequest);
 
+    /// Assert that request builders can be both awaited and converted into a
+    /// [`Request`].
+    ///
+    /// Builders that only select the kind of request to create, such as
+    /// [`CreateGlobalCommand`], [`CreateGuildCommand`], [`CreateForumThread`],
+    /// and [`DeleteChannelPermission`], are excluded because the requests they
+    /// produce are asserted instead.
+    ///
+    /// [`CreateForumThread`]: crate::request::channel::thread::CreateForumThread
+    /// [`CreateGlobalCommand`]: crate::request::application::command::CreateGlobalCommand
+    /// [`CreateGuildCommand`]: crate::request::application::command::CreateGuildCommand
+    /// [`DeleteChannelPermission`]: crate::request::channel::DeleteChannelPermission
+    /// [`Request`]: crate::request::Request
+    macro_rules! assert_requests {
+        ($($request:ident),* $(,)?) => {
+            $(assert_impl_all!($request<'static>: IntoFuture, TryIntoRequest);)*
+        };
+    }
+
+    assert_requests!(
+        AddApplicationEmoji,
+        AddGuildMember,
+        AddRoleToMember,
+        AddThreadMember,
+        CreateAutoModerationRule,
+        CreateBan,
+        CreateEmoji,
+        CreateFollowup,
+        CreateForumThreadMessage,
+        CreateGlobalChatInputCommand,
+        CreateGlobalMessageCommand,
+        CreateGlobalUserCommand,
+        CreateGuild,
+        CreateGuildChannel,
+        CreateGuildChatInputCommand,
+        CreateGuildExternalScheduledEvent,
+        CreateGuildFromTemplate,
+        CreateGuildMessageCommand,
+        CreateGuildPrune,
+        CreateGuildStageInstanceScheduledEvent,
+        CreateGuildSticker,
+        CreateGuildUserCommand,
+        CreateGuildVoiceScheduledEvent,
+        CreateInvite,
+        CreateMessage,
+        CreatePin,
+        CreatePrivateChannel,
+        CreateReaction,
+        CreateResponse,
+        CreateRole,
+        CreateStageInstance,
+        CreateTemplate,
+        CreateTestEntitlement,
+        CreateThread,
+        CreateThreadFromMessage,
+        CreateTypingTrigger,
+        CreateWebhook,
+        CrosspostMessage,
+        DeleteAllReaction,
+        DeleteAllReactions,
+        DeleteApplicationEmoji,
+        DeleteAutoModerationRule,
+        DeleteBan,
+        DeleteChannel,
+        DeleteChannelPermissionConfigured,
+        DeleteEmoji,
+        DeleteFollowup,
+        DeleteGlobalCommand,
+        DeleteGuild,
+        DeleteGuildCommand,
+        DeleteGuildIntegration,
+        DeleteGuildScheduledEvent,
+        DeleteGuildSticker,
+        DeleteInvite,
+        DeleteMessage,
+        DeleteMessages,
+        DeletePin,
+        DeleteReaction,
+        DeleteResponse,
+        DeleteRole,
+        DeleteStageInstance,
+        DeleteTemplate,
+        DeleteTestEntitlement,
+        DeleteWebhook,
+        DeleteWebhookMessage,
+        EndPoll,
+        ExecuteWebhook,
+        ExecuteWebhookAndWait,
+        FollowNewsChannel,
+        GetActiveThreads,
+        GetAnswerVoters,
+        GetApplicationRoleConnectionMetadataRecords,
+        GetAuditLog,
+        GetAutoModerationRule,
+        GetBan,
+        GetBans,
+        GetChannel,
+        GetChannelInvites,
+        GetChannelMessages,
+        GetChannelMessagesConfigured,
+        GetChannelWebhooks,
+        GetCommandPermissions,
+        GetCurrentAuthorizationInformation,
+        GetCurrentUser,
+        GetCurrentUserConnections,
+        GetCurrentUserGuildMember,
+        GetCurrentUserGuilds,
+        GetEmoji,
+        GetEmojis,
+        GetEntitlements,
+        GetFollowup,
+        GetGateway,
+        GetGatewayAuthed,
+        GetGlobalCommand,
+        GetGlobalCommands,
+        GetGuild,
+        GetGuildAutoModerationRules,
+        GetGuildChannels,
+        GetGuildCommand,
+        GetGuildCommandPermissions,
+        GetGuildCommands,
+        GetGuildIntegrations,
+        GetGuildInvites,
+        GetGuildMembers,
+        GetGuildOnboarding,
+        GetGuildPreview,
+        GetGuildPruneCount,
+        GetGuildRoleMemberCounts,
+        GetGuildRoles,
+        GetGuildScheduledEvent,
+        GetGuildScheduledEventUsers,
+        GetGuildScheduledEvents,
+        GetGuildSticker,
+        GetGuildStickers,
+        GetGuildVanityUrl,
+        GetGuildVoiceRegions,
+        GetGuildWebhooks,
+        GetGuildWelcomeScreen,
+        GetGuildWidget,
+        GetGuildWidgetImage,
+        GetGuildWidgetSettings,
+        GetInvite,
+        GetJoinedPrivateArchivedThreads,
+        GetMember,
+        GetMessage,
+        GetNitroStickerPacks,
+        GetPins,
+        GetPrivateArchivedThreads,
+        GetPublicArchivedThreads,
+        GetReactions,
+        GetResponse,
+        GetRole,
+        GetSKUs,
+        GetStageInstance,
+        GetSticker,
+        GetTemplate,
+        GetTemplates,
+        GetThreadMember,
+        GetThreadMembers,
+        GetUser,
+        GetUserApplicationInfo,
+        GetUserApplicationRoleConnection,
+        GetVoiceRegions,
+        GetWebhook,
+        GetWebhookMessage,
+        JoinThread,
+        LeaveGuild,
+        LeaveThread,
+        ListApplicationEmojis,
+        RemoveMember,
+        RemoveRoleFromMember,
+        RemoveThreadMember,
+        SearchGuildMembers,
+        SetGlobalCommands,
+        SetGuildCommands,
+        SyncTemplate,
+        UpdateApplicationEmoji,
+        UpdateApplicationRoleConnectionMetadataRecords,
+        UpdateAutoModerationRule,
+        UpdateChannel,
+        UpdateChannelPermission,
+        UpdateCommandPermissions,
+        UpdateCurrentMember,
+        UpdateCurrentUser,
+        UpdateCurrentUserApplication,
+        UpdateCurrentUserVoiceState,
+        UpdateEmoji,
+        UpdateFollowup,
+        UpdateGlobalCommand,
+        UpdateGuild,
+        UpdateGuildChannelPositions,
+        UpdateGuildCommand,
+        UpdateGuildMember,
+        UpdateGuildMfa,
+        UpdateGuildOnboarding,
+        UpdateGuildScheduledEvent,
+        UpdateGuildSticker,
+        UpdateGuildWelcomeScreen,
+        UpdateGuildWidgetSettings,
+        UpdateMessage,
+        UpdateResponse,
+        UpdateRole,
+        UpdateRolePositions,
+        UpdateStageInstance,
+        UpdateTemplate,
+        UpdateThread,
+        UpdateUserApplicationRoleConnection,
+        UpdateUserVoiceState,
+        UpdateWebhook,
+        UpdateWebhookMessage,
+        UpdateWebhookWithToken,
+    );
+
     #[test]
     fn conversion() -> Result<(), Box<dyn Error>> {
         let client = Client::new("token".to_owned());
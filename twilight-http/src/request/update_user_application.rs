@@ -135,6 +135,8 @@ impl<'a> UpdateCurrentUserApplication<'a> {
         self
     }
 
+    /// Sets the default install params of the application for guild and user
+    /// installations.
     pub fn integrations_types_config(
         mut self,
         guild: Option<InstallParams>,
@@ -202,3 +204,61 @@ impl TryIntoRequest for UpdateCurrentUserApplication<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateCurrentUserApplication;
+    use crate::{client::Client, request::TryIntoRequest};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, future::IntoFuture};
+    use twilight_http_ratelimiting::{Method, Path};
+    use twilight_model::{guild::Permissions, oauth::InstallParams};
+
+    assert_impl_all!(UpdateCurrentUserApplication<'_>: IntoFuture, Send, Sync, TryIntoRequest);
+
+    #[test]
+    fn update_current_user_application() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let install_params = InstallParams {
+            permissions: Permissions::SEND_MESSAGES,
+            scopes: Vec::from(["bot".to_owned()]),
+        };
+
+        let req = client
+            .update_current_user_application()
+            .description("a pretty cool application")
+            .install_params(install_params.clone())
+            .integrations_types_config(None, Some(install_params))
+            .interactions_endpoint_url("https://interactions")
+            .role_connections_verification_url("https://roleconnections")
+            .tags(Vec::from(["ponies", "magic"]))
+            .try_into_request()?;
+
+        assert_eq!(Method::Patch, req.method());
+        assert_eq!("applications/@me", req.path());
+        assert_eq!(&Path::ApplicationsMe, req.ratelimit_path());
+
+        let expected = r#"{"description":"a pretty cool application","install_params":{"permissions":"2048","scopes":["bot"]},"integration_types_config":{"1":{"oauth2_install_params":{"permissions":"2048","scopes":["bot"]}}},"interactions_endpoint_url":"https://interactions","role_connections_verification_url":"https://roleconnections","tags":["ponies","magic"]}"#;
+
+        assert_eq!(Some(expected.as_bytes()), req.body());
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_current_user_application_nullable() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let req = client
+            .update_current_user_application()
+            .cover_image(None)
+            .icon(None)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"cover_image":null,"icon":null}"#.as_ref()),
+            req.body()
+        );
+
+        Ok(())
+    }
+}
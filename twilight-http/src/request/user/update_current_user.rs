@@ -1,3 +1,5 @@
+#[cfg(feature = "image-data")]
+use crate::request::image::ImageData;
 use crate::{
     client::Client,
     error::Error,
@@ -61,6 +63,15 @@ impl<'a> UpdateCurrentUser<'a> {
         self
     }
 
+    /// Set the user's avatar from an image validated as an
+    /// [`ImageDataKind::Avatar`].
+    ///
+    /// [`ImageDataKind::Avatar`]: crate::request::image::ImageDataKind::Avatar
+    #[cfg(feature = "image-data")]
+    pub fn avatar_data(self, avatar: Option<&'a ImageData>) -> Self {
+        self.avatar(avatar.map(ImageData::as_str))
+    }
+
     /// Set the user's banner.
     ///
     /// This must be a Data URI, in the form of
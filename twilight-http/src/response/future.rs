@@ -1,9 +1,9 @@
 use super::{Response, StatusCode};
 use crate::{
-    api_error::ApiError,
-    error::{Error, ErrorType},
+    api_error::{ApiError, RatelimitedApiError},
+    error::{Error, ErrorType, RequestParts},
 };
-use http::StatusCode as HyperStatusCode;
+use http::{header::RETRY_AFTER, HeaderMap, StatusCode as HyperStatusCode};
 use hyper_util::client::legacy::ResponseFuture as HyperResponseFuture;
 use std::{
     future::Future,
@@ -18,7 +18,9 @@ use std::{
     time::Duration,
 };
 use tokio::time::{self, Timeout};
-use twilight_http_ratelimiting::{ticket::TicketSender, RatelimitHeaders, WaitForTicketFuture};
+use twilight_http_ratelimiting::{
+    headers::RatelimitScope, ticket::TicketSender, RatelimitHeaders, WaitForTicketFuture,
+};
 
 type Output<T> = Result<Response<T>, Error>;
 
@@ -28,8 +30,59 @@ enum InnerPoll<T> {
     Ready(Output<T>),
 }
 
+/// Ratelimit information from the headers of a 429 response received while
+/// the ratelimiter is disabled.
+struct ProxyRatelimit {
+    /// Whether the `X-RateLimit-Global` header is `true`.
+    global: bool,
+    /// Number of seconds in the `Retry-After` header.
+    retry_after: Option<f64>,
+}
+
+impl ProxyRatelimit {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let global = headers
+            .get("x-ratelimit-global")
+            .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"true"));
+        let retry_after = headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+
+        Self {
+            global,
+            retry_after,
+        }
+    }
+
+    /// Create the error, preferring the response body's information over the
+    /// headers'.
+    ///
+    /// Returns `None` if neither contains a valid retry after duration.
+    fn into_error(self, body: &[u8]) -> Option<Error> {
+        let body = crate::json::from_bytes::<RatelimitedApiError>(body).ok();
+        let retry_after = body
+            .as_ref()
+            .map(|body| body.retry_after)
+            .or(self.retry_after)
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())?;
+        let global = self.global || body.is_some_and(|body| body.global);
+
+        Some(Error {
+            kind: ErrorType::RatelimitedByProxy {
+                global,
+                retry_after,
+            },
+            request: None,
+            source: None,
+        })
+    }
+}
+
 struct Chunking {
     future: Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + Send + Sync + 'static>>,
+    proxy_ratelimit: Option<ProxyRatelimit>,
+    request: RequestParts,
     status: HyperStatusCode,
 }
 
@@ -41,11 +94,19 @@ impl Chunking {
             Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Chunking(self)),
         };
 
+        if let Some(error) = self
+            .proxy_ratelimit
+            .and_then(|proxy_ratelimit| proxy_ratelimit.into_error(&bytes))
+        {
+            return InnerPoll::Ready(Err(error));
+        }
+
         let error = match crate::json::from_bytes::<ApiError>(&bytes) {
             Ok(error) => error,
             Err(source) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::Parsing { body: bytes },
+                    request: None,
                     source: Some(Box::new(source)),
                 }));
             }
@@ -57,6 +118,7 @@ impl Chunking {
                 error,
                 status: StatusCode::new(self.status.as_u16()),
             },
+            request: Some(Box::new(self.request.into_context())),
             source: None,
         }))
     }
@@ -75,6 +137,7 @@ impl Failed {
 struct InFlight {
     future: Pin<Box<Timeout<HyperResponseFuture>>>,
     invalid_token: Option<Arc<AtomicBool>>,
+    request: RequestParts,
     tx: Option<TicketSender>,
 }
 
@@ -85,12 +148,14 @@ impl InFlight {
             Poll::Ready(Ok(Err(source))) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestError,
+                    request: None,
                     source: Some(Box::new(source)),
                 }))
             }
             Poll::Ready(Err(source)) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestTimedOut,
+                    request: None,
                     source: Some(Box::new(source)),
                 }))
             }
@@ -106,6 +171,15 @@ impl InFlight {
             }
         }
 
+        // Without a ratelimiter the ratelimit headers of 429 responses would
+        // otherwise go unused, so surface them to the caller.
+        let proxy_ratelimit = (self.tx.is_none()
+            && resp.status() == HyperStatusCode::TOO_MANY_REQUESTS)
+            .then(|| ProxyRatelimit::from_headers(resp.headers()));
+
+        // Scope of the ratelimit, only sent alongside 429 responses.
+        let mut scope = None;
+
         if let Some(tx) = self.tx {
             let headers = resp
                 .headers()
@@ -114,6 +188,8 @@ impl InFlight {
 
             match RatelimitHeaders::from_pairs(headers) {
                 Ok(v) => {
+                    scope = v.scope();
+
                     let _res = tx.headers(Some(v));
                 }
                 Err(source) => {
@@ -137,12 +213,18 @@ impl InFlight {
         }
 
         match status {
+            // Shared ratelimits apply to the resource for all users of it and
+            // don't count against the invalid request limit.
+            HyperStatusCode::TOO_MANY_REQUESTS if scope == Some(RatelimitScope::Shared) => {
+                tracing::debug!("shared resource 429 response: {resp:?}");
+            }
             HyperStatusCode::TOO_MANY_REQUESTS => {
                 tracing::warn!("429 response: {resp:?}");
             }
             HyperStatusCode::SERVICE_UNAVAILABLE => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::ServiceUnavailable { response: resp },
+                    request: None,
                     source: None,
                 }));
             }
@@ -155,12 +237,15 @@ impl InFlight {
                 .await
                 .map_err(|source| Error {
                     kind: ErrorType::ChunkingResponse,
+                    request: None,
                     source: Some(Box::new(source)),
                 })
         };
 
         InnerPoll::Advance(ResponseFutureStage::Chunking(Chunking {
             future: Box::pin(fut),
+            proxy_ratelimit,
+            request: self.request,
             status,
         }))
     }
@@ -168,6 +253,7 @@ impl InFlight {
 
 struct RatelimitQueue {
     invalid_token: Option<Arc<AtomicBool>>,
+    request: RequestParts,
     response_future: HyperResponseFuture,
     timeout: Duration,
     pre_flight_check: Option<Box<dyn FnOnce() -> bool + Send + 'static>>,
@@ -181,6 +267,7 @@ impl RatelimitQueue {
             Poll::Ready(Err(source)) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RatelimiterTicket,
+                    request: None,
                     source: Some(source),
                 }))
             }
@@ -191,6 +278,7 @@ impl RatelimitQueue {
             if !pre_flight_check() {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestCanceled,
+                    request: None,
                     source: None,
                 }));
             }
@@ -199,6 +287,7 @@ impl RatelimitQueue {
         InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
             future: Box::pin(time::timeout(self.timeout, self.response_future)),
             invalid_token: self.invalid_token,
+            request: self.request,
             tx: Some(tx),
         }))
     }
@@ -230,6 +319,9 @@ enum ResponseFutureStage {
 /// Returns an [`ErrorType::Parsing`] error type if the request failed and the
 /// error in the response body could not be deserialized.
 ///
+/// Returns an [`ErrorType::RatelimitedByProxy`] error type if the request was
+/// ratelimited while the client's ratelimiter is disabled.
+///
 /// Returns an [`ErrorType::RequestCanceled`] error type if the request was
 /// canceled by the user.
 ///
@@ -247,6 +339,7 @@ enum ResponseFutureStage {
 /// [`ClientBuilder::timeout`]: crate::client::ClientBuilder::timeout
 /// [`ErrorType::Json`]: crate::error::ErrorType::Json
 /// [`ErrorType::Parsing`]: crate::error::ErrorType::Parsing
+/// [`ErrorType::RatelimitedByProxy`]: crate::error::ErrorType::RatelimitedByProxy
 /// [`ErrorType::RequestCanceled`]: crate::error::ErrorType::RequestCanceled
 /// [`ErrorType::RequestError`]: crate::error::ErrorType::RequestError
 /// [`ErrorType::RequestTimedOut`]: crate::error::ErrorType::RequestTimedOut
@@ -263,12 +356,14 @@ impl<T> ResponseFuture<T> {
     pub(crate) const fn new(
         future: Pin<Box<Timeout<HyperResponseFuture>>>,
         invalid_token: Option<Arc<AtomicBool>>,
+        request: RequestParts,
     ) -> Self {
         Self {
             phantom: PhantomData,
             stage: ResponseFutureStage::InFlight(InFlight {
                 future,
                 invalid_token,
+                request,
                 tx: None,
             }),
         }
@@ -349,6 +444,7 @@ impl<T> ResponseFuture<T> {
 
     pub(crate) fn ratelimit(
         invalid_token: Option<Arc<AtomicBool>>,
+        request: RequestParts,
         response_future: HyperResponseFuture,
         timeout: Duration,
         wait_for_sender: WaitForTicketFuture,
@@ -357,6 +453,7 @@ impl<T> ResponseFuture<T> {
             phantom: PhantomData,
             stage: ResponseFutureStage::RatelimitQueue(RatelimitQueue {
                 invalid_token,
+                request,
                 response_future,
                 timeout,
                 pre_flight_check: None,
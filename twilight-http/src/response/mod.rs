@@ -69,6 +69,7 @@ use std::{
     pin::Pin,
     task::{Context, Poll},
 };
+use twilight_http_ratelimiting::RatelimitHeaders;
 
 /// Failure when processing a response body.
 #[derive(Debug)]
@@ -193,6 +194,25 @@ impl<T> Response<T> {
         HeaderIter(self.inner.headers().iter())
     }
 
+    /// Parse the ratelimit headers of the response.
+    ///
+    /// This is useful when the client's ratelimiter is disabled, such as when
+    /// a proxy handles ratelimits, to still be able to inspect the remaining
+    /// ratelimit of buckets.
+    ///
+    /// Returns [`None`] if the headers are missing required fields or contain
+    /// invalid values.
+    #[must_use = "parsing the ratelimit headers has no use on its own"]
+    pub fn ratelimit_headers(&self) -> Option<RatelimitHeaders> {
+        let headers = self
+            .inner
+            .headers()
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_bytes()));
+
+        RatelimitHeaders::from_pairs(headers).ok()
+    }
+
     /// Status code of the response.
     #[must_use = "retrieving the status code has no use on its own"]
     pub fn status(&self) -> StatusCode {
@@ -404,6 +404,12 @@ pub enum Route<'a> {
         /// The ID of the application.
         application_id: u64,
     },
+    /// Route information to get an application's role connection metadata
+    /// records.
+    GetApplicationRoleConnectionMetadataRecords {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information for fetching poll vote information.
     GetAnswerVoters {
         /// Get users after this user ID.
@@ -642,6 +648,11 @@ pub enum Route<'a> {
         /// pruned.
         include_roles: &'a [Id<RoleMarker>],
     },
+    /// Route information to get the member counts of a guild's roles.
+    GetGuildRoleMemberCounts {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get guild's roles.
     GetGuildRoles {
         /// The ID of the guild.
@@ -715,6 +726,13 @@ pub enum Route<'a> {
         /// ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a guild's widget image.
+    GetGuildWidgetImage {
+        /// ID of the guild.
+        guild_id: u64,
+        /// Style of the image.
+        style: Option<&'a str>,
+    },
     /// Route information to get a guild's widget settings.
     GetGuildWidgetSettings {
         /// ID of the guild.
@@ -886,6 +904,12 @@ pub enum Route<'a> {
         /// ID of the target user.
         user_id: u64,
     },
+    /// Route information to get the current user's role connection for an
+    /// application.
+    GetUserApplicationRoleConnection {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to get the current user's connections.
     GetUserConnections,
     /// Route information to get the current user's private channels and groups.
@@ -1005,6 +1029,12 @@ pub enum Route<'a> {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to update an application's role connection metadata
+    /// records.
+    UpdateApplicationRoleConnectionMetadataRecords {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to update an auto moderation rule for a guild.
     UpdateAutoModerationRule {
         /// ID of the auto moderation rule.
@@ -1169,6 +1199,12 @@ pub enum Route<'a> {
         /// The template code.
         template_code: &'a str,
     },
+    /// Route information to update the current user's role connection for an
+    /// application.
+    UpdateUserApplicationRoleConnection {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to update a user's voice state.
     UpdateUserVoiceState {
         /// ID of the guild.
@@ -1249,6 +1285,7 @@ impl Route<'_> {
             | Self::UnpinMessage { .. } => Method::Delete,
             Self::GetActiveThreads { .. }
             | Self::GetApplicationEmojis { .. }
+            | Self::GetApplicationRoleConnectionMetadataRecords { .. }
             | Self::GetAnswerVoters { .. }
             | Self::GetAuditLogs { .. }
             | Self::GetAutoModerationRule { .. }
@@ -1283,6 +1320,7 @@ impl Route<'_> {
             | Self::GetGuildOnboarding { .. }
             | Self::GetGuildPreview { .. }
             | Self::GetGuildPruneCount { .. }
+            | Self::GetGuildRoleMemberCounts { .. }
             | Self::GetGuildRoles { .. }
             | Self::GetGuildScheduledEvent { .. }
             | Self::GetGuildScheduledEventUsers { .. }
@@ -1294,6 +1332,7 @@ impl Route<'_> {
             | Self::GetGuildWelcomeScreen { .. }
             | Self::GetGuildWebhooks { .. }
             | Self::GetGuildWidget { .. }
+            | Self::GetGuildWidgetImage { .. }
             | Self::GetGuildWidgetSettings { .. }
             | Self::GetGuilds { .. }
             | Self::GetInteractionOriginal { .. }
@@ -1316,6 +1355,7 @@ impl Route<'_> {
             | Self::GetTemplates { .. }
             | Self::GetThreadMember { .. }
             | Self::GetThreadMembers { .. }
+            | Self::GetUserApplicationRoleConnection { .. }
             | Self::GetUserConnections
             | Self::GetUserPrivateChannels
             | Self::GetUser { .. }
@@ -1394,8 +1434,10 @@ impl Route<'_> {
             | Self::SetGuildCommands { .. }
             | Self::SyncTemplate { .. }
             | Self::UpdateCommandPermissions { .. }
+            | Self::UpdateApplicationRoleConnectionMetadataRecords { .. }
             | Self::UpdateGuildOnboarding { .. }
-            | Self::UpdatePermissionOverwrite { .. } => Method::Put,
+            | Self::UpdatePermissionOverwrite { .. }
+            | Self::UpdateUserApplicationRoleConnection { .. } => Method::Put,
         }
     }
 
@@ -1499,6 +1541,9 @@ impl Route<'_> {
             Self::CreateRole { guild_id } | Self::GetGuildRoles { guild_id } => {
                 Path::GuildsIdRoles(guild_id)
             }
+            Self::GetGuildRoleMemberCounts { guild_id } => {
+                Path::GuildsIdRolesMemberCounts(guild_id)
+            }
             Self::CreateStageInstance { .. }
             | Self::DeleteStageInstance { .. }
             | Self::GetStageInstance { .. }
@@ -1637,6 +1682,10 @@ impl Route<'_> {
             | Self::DeleteApplicationEmoji { application_id, .. } => {
                 Path::ApplicationEmojis(application_id)
             }
+            Self::GetApplicationRoleConnectionMetadataRecords { application_id }
+            | Self::UpdateApplicationRoleConnectionMetadataRecords { application_id } => {
+                Path::ApplicationIdRoleConnectionsMetadata(application_id)
+            }
             Self::GetAuditLogs { guild_id, .. } => Path::GuildsIdAuditLogs(guild_id),
             Self::GetBan { guild_id, .. } => Path::GuildsIdBansId(guild_id),
             Self::GetBans { guild_id } | Self::GetBansWithParameters { guild_id, .. } => {
@@ -1667,6 +1716,7 @@ impl Route<'_> {
                 Path::GuildsId(guild_id)
             }
             Self::GetGuildWidget { guild_id } => Path::GuildsIdWidgetJson(guild_id),
+            Self::GetGuildWidgetImage { guild_id, .. } => Path::GuildsIdWidgetPng(guild_id),
             Self::GetGuildWidgetSettings { guild_id }
             | Self::UpdateGuildWidgetSettings { guild_id } => Path::GuildsIdWidget(guild_id),
             Self::GetGuildIntegrations { guild_id } => Path::GuildsIdIntegrations(guild_id),
@@ -1705,6 +1755,10 @@ impl Route<'_> {
             }
             Self::GetSKUs { application_id } => Path::ApplicationIdSKUs(application_id),
             Self::GetSticker { .. } => Path::Stickers,
+            Self::GetUserApplicationRoleConnection { application_id }
+            | Self::UpdateUserApplicationRoleConnection { application_id } => {
+                Path::UsersIdApplicationsIdRoleConnection(application_id)
+            }
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
             Self::InteractionCallback { interaction_id, .. } => {
@@ -2020,6 +2074,12 @@ impl Display for Route<'_> {
 
                 f.write_str("/roles")
             }
+            Route::GetGuildRoleMemberCounts { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/roles/member-counts")
+            }
             Route::CreateStageInstance { .. } => f.write_str("stage-instances"),
             Route::CreateTemplate { guild_id } | Route::GetTemplates { guild_id } => {
                 f.write_str("guilds/")?;
@@ -2468,6 +2528,13 @@ impl Display for Route<'_> {
 
                 f.write_str("/emojis")
             }
+            Route::GetApplicationRoleConnectionMetadataRecords { application_id }
+            | Route::UpdateApplicationRoleConnectionMetadataRecords { application_id } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/role-connections/metadata")
+            }
             Route::GetAuditLogs {
                 action_type,
                 after,
@@ -2731,6 +2798,15 @@ impl Display for Route<'_> {
 
                 f.write_str("/widget.json")
             }
+            Route::GetGuildWidgetImage { guild_id, style } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/widget.png")?;
+
+                let mut query_formatter = QueryStringFormatter::new(f);
+
+                query_formatter.write_opt_param("style", style.as_ref())
+            }
             Route::GetGuildWidgetSettings { guild_id }
             | Route::UpdateGuildWidgetSettings { guild_id } => {
                 f.write_str("guilds/")?;
@@ -2892,6 +2968,13 @@ impl Display for Route<'_> {
                 query_formatter.write_opt_param("limit", limit.as_ref())?;
                 query_formatter.write_opt_param("with_member", with_member.as_ref())
             }
+            Route::GetUserApplicationRoleConnection { application_id }
+            | Route::UpdateUserApplicationRoleConnection { application_id } => {
+                f.write_str("users/@me/applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/role-connection")
+            }
             Route::GetUserConnections => f.write_str("users/@me/connections"),
             Route::GetUser { user_id } => {
                 f.write_str("users/")?;
@@ -3099,6 +3182,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delete_webhook_message_thread_id() {
+        let route = Route::DeleteWebhookMessage {
+            message_id: 1,
+            thread_id: Some(2),
+            token: "token",
+            webhook_id: 3,
+        };
+
+        assert_eq!("webhooks/3/token/messages/1?thread_id=2", route.to_string());
+    }
+
+    #[test]
+    fn get_webhook_message_thread_id() {
+        let route = Route::GetWebhookMessage {
+            message_id: 1,
+            thread_id: Some(2),
+            token: "token",
+            webhook_id: 3,
+        };
+
+        assert_eq!("webhooks/3/token/messages/1?thread_id=2", route.to_string());
+
+        let route = Route::GetWebhookMessage {
+            message_id: 1,
+            thread_id: None,
+            token: "token",
+            webhook_id: 3,
+        };
+
+        assert_eq!("webhooks/3/token/messages/1", route.to_string());
+    }
+
     #[test]
     fn update_webhook_message_thread_id() {
         let route = Route::UpdateWebhookMessage {
@@ -3473,6 +3589,15 @@ mod tests {
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/roles"));
     }
 
+    #[test]
+    fn get_guild_role_member_counts() {
+        let route = Route::GetGuildRoleMemberCounts { guild_id: GUILD_ID };
+        assert_eq!(
+            route.to_string(),
+            format!("guilds/{GUILD_ID}/roles/member-counts")
+        );
+    }
+
     #[test]
     fn update_role_positions() {
         let route = Route::UpdateRolePositions { guild_id: GUILD_ID };
@@ -4282,6 +4407,24 @@ mod tests {
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/widget.json"));
     }
 
+    #[test]
+    fn get_guild_widget_image() {
+        let route = Route::GetGuildWidgetImage {
+            guild_id: GUILD_ID,
+            style: None,
+        };
+        assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/widget.png"));
+
+        let route = Route::GetGuildWidgetImage {
+            guild_id: GUILD_ID,
+            style: Some("banner1"),
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("guilds/{GUILD_ID}/widget.png?style=banner1")
+        );
+    }
+
     #[test]
     fn get_guild_widget_settings() {
         let route = Route::GetGuildWidgetSettings { guild_id: GUILD_ID };
@@ -4825,4 +4968,40 @@ mod tests {
         let route = Route::GetSKUs { application_id: 1 };
         assert_eq!(route.to_string(), format!("applications/1/skus"));
     }
+
+    #[test]
+    fn get_application_role_connection_metadata_records() {
+        let route = Route::GetApplicationRoleConnectionMetadataRecords { application_id: 1 };
+        assert_eq!(
+            route.to_string(),
+            "applications/1/role-connections/metadata"
+        );
+    }
+
+    #[test]
+    fn update_application_role_connection_metadata_records() {
+        let route = Route::UpdateApplicationRoleConnectionMetadataRecords { application_id: 1 };
+        assert_eq!(
+            route.to_string(),
+            "applications/1/role-connections/metadata"
+        );
+    }
+
+    #[test]
+    fn get_user_application_role_connection() {
+        let route = Route::GetUserApplicationRoleConnection { application_id: 1 };
+        assert_eq!(
+            route.to_string(),
+            "users/@me/applications/1/role-connection"
+        );
+    }
+
+    #[test]
+    fn update_user_application_role_connection() {
+        let route = Route::UpdateUserApplicationRoleConnection { application_id: 1 };
+        assert_eq!(
+            route.to_string(),
+            "users/@me/applications/1/role-connection"
+        );
+    }
 }
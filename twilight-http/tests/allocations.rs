@@ -0,0 +1,131 @@
+//! Count the allocations made when building hot requests.
+//!
+//! Text fields of these requests accept both borrowed and owned strings.
+//! Owned strings are moved into the request rather than copied, so building a
+//! request from an owned string must allocate exactly as much as building it
+//! from a borrowed one.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+use twilight_http::{request::TryIntoRequest, Client};
+use twilight_model::id::Id;
+
+/// Allocator counting the allocations made on the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: all allocations are delegated to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Number of allocations made on the current thread while running `f`, and its
+/// return value.
+fn allocations<T>(f: impl FnOnce() -> T) -> (usize, T) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+
+    (ALLOCATIONS.with(Cell::get) - before, value)
+}
+
+#[test]
+fn create_message() {
+    let client = Client::new("token".to_owned());
+    let content = "a".repeat(2000);
+
+    let borrowed = || {
+        client
+            .create_message(Id::new(1))
+            .content(content.as_str())
+            .try_into_request()
+    };
+    // Warm up any lazily initialized state before counting.
+    _ = borrowed();
+    let (borrowed, _) = allocations(borrowed);
+
+    let owned_content = content.clone();
+    let (setter, request) =
+        allocations(|| client.create_message(Id::new(1)).content(owned_content));
+    let (owned, _) = allocations(|| request.try_into_request());
+
+    assert_eq!(0, setter);
+    assert_eq!(borrowed, setter + owned);
+}
+
+#[test]
+fn execute_webhook() {
+    let client = Client::new("token".to_owned());
+    let content = "a".repeat(2000);
+    let username = "twilight".to_owned();
+
+    let borrowed = || {
+        client
+            .execute_webhook(Id::new(1), "token")
+            .content(content.as_str())
+            .username(username.as_str())
+            .try_into_request()
+    };
+    // Warm up any lazily initialized state before counting.
+    _ = borrowed();
+    let (borrowed, _) = allocations(borrowed);
+
+    let (owned_content, owned_username) = (content.clone(), username.clone());
+    let (setter, request) = allocations(|| {
+        client
+            .execute_webhook(Id::new(1), "token")
+            .content(owned_content)
+            .username(owned_username)
+    });
+    let (owned, _) = allocations(|| request.try_into_request());
+
+    assert_eq!(0, setter);
+    assert_eq!(borrowed, setter + owned);
+}
+
+#[test]
+fn create_followup() {
+    let client = Client::new("token".to_owned());
+    let content = "a".repeat(2000);
+
+    let borrowed = || {
+        client
+            .interaction(Id::new(1))
+            .create_followup("token")
+            .content(content.as_str())
+            .try_into_request()
+    };
+    // Warm up any lazily initialized state before counting.
+    _ = borrowed();
+    let (borrowed, _) = allocations(borrowed);
+
+    let owned_content = content.clone();
+    let interaction = client.interaction(Id::new(1));
+    let (setter, request) =
+        allocations(|| interaction.create_followup("token").content(owned_content));
+    let (owned, _) = allocations(|| request.try_into_request());
+
+    assert_eq!(0, setter);
+    assert_eq!(borrowed, setter + owned);
+}
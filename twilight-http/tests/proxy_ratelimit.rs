@@ -0,0 +1,121 @@
+//! Ratelimited responses from a proxy while the client's ratelimiter is
+//! disabled.
+
+use std::time::Duration;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use twilight_http::{error::ErrorType, Client};
+
+/// Serve a single 429 response with the given headers and body, returning the
+/// address of the server.
+async fn serve_ratelimited(headers: &'static str, body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+
+        while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+            let read = stream.read(&mut buf).await.unwrap();
+
+            if read == 0 {
+                return;
+            }
+
+            request.extend_from_slice(&buf[..read]);
+        }
+
+        let response = format!(
+            "HTTP/1.1 429 Too Many Requests\r\n\
+             connection: close\r\n\
+             content-length: {}\r\n\
+             content-type: application/json\r\n\
+             {headers}\r\n\
+             {body}",
+            body.len(),
+        );
+
+        stream.write_all(response.as_bytes()).await.unwrap();
+    });
+
+    address
+}
+
+fn client(address: String) -> Client {
+    Client::builder()
+        .proxy(address, true)
+        .ratelimiter(None)
+        .build()
+}
+
+#[tokio::test]
+async fn body() {
+    let address = serve_ratelimited(
+        "retry-after: 1\r\n",
+        r#"{"global":true,"message":"You are being rate limited.","retry_after":0.5}"#,
+    )
+    .await;
+
+    let error = client(address).current_user().await.unwrap_err();
+
+    assert!(matches!(
+        error.kind(),
+        ErrorType::RatelimitedByProxy {
+            global: true,
+            retry_after,
+        } if *retry_after == Duration::from_millis(500),
+    ));
+}
+
+#[tokio::test]
+async fn headers() {
+    let address = serve_ratelimited(
+        "retry-after: 2\r\nx-ratelimit-global: true\r\n",
+        r#"{"message":"proxy ratelimit"}"#,
+    )
+    .await;
+
+    let error = client(address).current_user().await.unwrap_err();
+
+    assert!(matches!(
+        error.kind(),
+        ErrorType::RatelimitedByProxy {
+            global: true,
+            retry_after,
+        } if *retry_after == Duration::from_secs(2),
+    ));
+}
+
+#[tokio::test]
+async fn bucket() {
+    let address = serve_ratelimited(
+        "retry-after: 3\r\n",
+        r#"{"global":false,"message":"You are being rate limited.","retry_after":3.0}"#,
+    )
+    .await;
+
+    let error = client(address).current_user().await.unwrap_err();
+
+    assert!(matches!(
+        error.kind(),
+        ErrorType::RatelimitedByProxy {
+            global: false,
+            retry_after,
+        } if *retry_after == Duration::from_secs(3),
+    ));
+}
+
+/// Without any retry information the response is surfaced as before.
+#[tokio::test]
+async fn missing_retry_after() {
+    let address = serve_ratelimited("", r#"{"code":0,"message":"proxy ratelimit"}"#).await;
+
+    let error = client(address).current_user().await.unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorType::Response { .. }));
+}
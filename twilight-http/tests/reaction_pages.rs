@@ -0,0 +1,131 @@
+//! Paginating the users that reacted to a message.
+
+use futures_util::StreamExt;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+use twilight_http::{request::channel::reaction::RequestReactionType, Client};
+use twilight_model::{channel::message::ReactionType, id::Id};
+
+/// Serve one response per body, in order, returning the address of the server
+/// and a handle resolving to the request targets it received.
+async fn serve(bodies: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let handle = tokio::spawn(async move {
+        let mut targets = Vec::new();
+
+        for body in bodies {
+            let (mut stream, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await.unwrap();
+                assert_ne!(0, read, "connection closed before request was read");
+
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            let request = String::from_utf8(request).unwrap();
+            let target = request.split(' ').nth(1).unwrap().to_owned();
+            targets.push(target);
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\n\
+                 connection: close\r\n\
+                 content-length: {}\r\n\
+                 content-type: application/json\r\n\
+                 \r\n\
+                 {body}",
+                body.len(),
+            );
+
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+
+        targets
+    });
+
+    (address, handle)
+}
+
+fn users(ids: &[u64]) -> String {
+    let users = ids
+        .iter()
+        .map(|id| {
+            format!(r#"{{"id":"{id}","username":"user","discriminator":"0001","avatar":null}}"#)
+        })
+        .collect::<Vec<_>>();
+
+    format!("[{}]", users.join(","))
+}
+
+#[tokio::test]
+async fn cursor_propagation() {
+    let (address, handle) = serve(vec![users(&[1, 2]), users(&[3, 4]), users(&[5])]).await;
+    let client = Client::builder()
+        .proxy(address, true)
+        .ratelimiter(None)
+        .build();
+    let emoji = RequestReactionType::Custom {
+        id: Id::new(3),
+        name: Some("foo"),
+    };
+
+    let pages = client
+        .reactions(Id::new(1), Id::new(2), &emoji)
+        .kind(ReactionType::Burst)
+        .limit(2)
+        .paginate()
+        .map(|page| {
+            page.unwrap()
+                .into_iter()
+                .map(|user| user.id.get())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(vec![vec![1, 2], vec![3, 4], vec![5]], pages);
+    assert_eq!(
+        vec![
+            "/api/v10/channels/1/messages/2/reactions/foo:3?limit=2&type=1",
+            "/api/v10/channels/1/messages/2/reactions/foo:3?after=2&limit=2&type=1",
+            "/api/v10/channels/1/messages/2/reactions/foo:3?after=4&limit=2&type=1",
+        ],
+        handle.await.unwrap(),
+    );
+}
+
+/// An empty page ends the stream without being yielded.
+#[tokio::test]
+async fn empty_final_page() {
+    let (address, handle) = serve(vec![users(&[11, 12]), users(&[])]).await;
+    let client = Client::builder()
+        .proxy(address, true)
+        .ratelimiter(None)
+        .build();
+    let emoji = RequestReactionType::Unicode { name: "a" };
+
+    let pages = client
+        .reactions(Id::new(1), Id::new(2), &emoji)
+        .after(Id::new(10))
+        .limit(2)
+        .paginate()
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(1, pages.len());
+    assert_eq!(
+        vec![
+            "/api/v10/channels/1/messages/2/reactions/a?after=10&limit=2",
+            "/api/v10/channels/1/messages/2/reactions/a?after=12&limit=2",
+        ],
+        handle.await.unwrap(),
+    );
+}
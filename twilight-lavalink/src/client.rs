@@ -1,8 +1,8 @@
 //! Client to manage nodes and players.
 
 use crate::{
-    model::VoiceUpdate,
-    node::{IncomingEvents, Node, NodeConfig, NodeError, Resume},
+    model::{PlayerReconnected, VoiceUpdate},
+    node::{IncomingEvents, Node, NodeConfig, NodeError, Protocol, Resume},
     player::{Player, PlayerManager},
 };
 use dashmap::DashMap;
@@ -49,6 +49,9 @@ impl Display for ClientError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
             ClientErrorType::NodesUnconfigured => f.write_str("no node has been configured"),
+            ClientErrorType::SendingPlayerState => {
+                f.write_str("couldn't send player state to node")
+            }
             ClientErrorType::SendingVoiceUpdate => {
                 f.write_str("couldn't send voice update to node")
             }
@@ -70,6 +73,9 @@ impl Error for ClientError {
 pub enum ClientErrorType {
     /// A node isn't configured, so the operation isn't possible to fulfill.
     NodesUnconfigured,
+    /// Sending a player's last known state to the node after its voice server
+    /// changed failed because the node's connection was shutdown.
+    SendingPlayerState,
     /// Sending a voice update event to the node failed because the node's
     /// connection was shutdown.
     SendingVoiceUpdate,
@@ -99,6 +105,7 @@ pub enum ClientErrorType {
 pub struct Lavalink {
     nodes: DashMap<SocketAddr, Arc<Node>>,
     players: PlayerManager,
+    protocol: Protocol,
     resume: Option<Resume>,
     shard_count: u32,
     user_id: Id<UserMarker>,
@@ -141,6 +148,7 @@ impl Lavalink {
         Self {
             nodes: DashMap::new(),
             players: PlayerManager::new(),
+            protocol: Protocol::V3,
             resume,
             shard_count,
             user_id,
@@ -149,6 +157,19 @@ impl Lavalink {
         }
     }
 
+    /// Set the version of the Lavalink protocol spoken by nodes created via
+    /// [`add`].
+    ///
+    /// Defaults to [`Protocol::V3`].
+    ///
+    /// [`add`]: Self::add
+    #[must_use = "has no effect if unused"]
+    pub const fn with_protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = protocol;
+
+        self
+    }
+
     /// Process an event into the Lavalink client.
     ///
     /// **Note**: calling this method in your event loop is required. See the
@@ -163,16 +184,32 @@ impl Lavalink {
     /// stalled voice states that never received their voice server update half
     /// or vice versa. It is recommended that you process Ready events.
     ///
+    /// When the voice server endpoint of an existing player changes, such as
+    /// during a voice region migration, the player's last known state is
+    /// re-applied after the voice update: its track is restarted from the last
+    /// reported position, and its paused state, volume, and equalizer are
+    /// re-sent. A [`PlayerReconnected`] event is then emitted on the player's
+    /// node's [`IncomingEvents`].
+    ///
     /// # Errors
     ///
     /// Returns a [`ClientErrorType::NodesUnconfigured`] error type if no nodes
     /// have been added to the client when attempting to retrieve a guild's
     /// player.
     ///
+    /// Returns a [`ClientErrorType::SendingPlayerState`] error type if the
+    /// player's state couldn't be re-applied after its voice server changed.
+    ///
+    /// Returns a [`ClientErrorType::SendingVoiceUpdate`] error type if the
+    /// voice update couldn't be sent to the player's node.
+    ///
+    /// [`IncomingEvents`]: crate::node::IncomingEvents
     /// [crate documentation]: crate#examples
     pub async fn process(&self, event: &Event) -> Result<(), ClientError> {
         tracing::trace!("processing event: {event:?}");
 
+        let mut endpoint_changed = false;
+
         let guild_id = match event {
             Event::Ready(e) => {
                 let shard_id = e.shard.map_or(0, ShardId::number);
@@ -182,7 +219,10 @@ impl Lavalink {
                 return Ok(());
             }
             Event::VoiceServerUpdate(e) => {
-                self.server_updates.insert(e.guild_id, e.clone());
+                if let Some(previous) = self.server_updates.insert(e.guild_id, e.clone()) {
+                    endpoint_changed = previous.endpoint != e.endpoint;
+                }
+
                 e.guild_id
             }
             Event::VoiceStateUpdate(e) => {
@@ -246,9 +286,16 @@ impl Lavalink {
             }
         };
 
+        let existing_player = self.players().get(&guild_id);
+        let reconnecting = endpoint_changed && existing_player.is_some();
+
         tracing::debug!("getting player for guild {guild_id}");
 
-        let player = self.player(guild_id).await?;
+        let player = match existing_player {
+            Some(player) => player,
+            None => self.player(guild_id).await?,
+        };
+        let endpoint = update.event.endpoint.clone();
 
         tracing::debug!("sending voice update for guild {guild_id}: {update:?}");
 
@@ -259,6 +306,21 @@ impl Lavalink {
 
         tracing::debug!("sent voice update for guild {guild_id}");
 
+        if reconnecting {
+            tracing::debug!("voice server changed, re-applying state of player {guild_id}");
+
+            for event in player.state_events() {
+                player.node().send(event).map_err(|source| ClientError {
+                    kind: ClientErrorType::SendingPlayerState,
+                    source: Some(Box::new(source)),
+                })?;
+            }
+
+            player
+                .node()
+                .emit(PlayerReconnected { endpoint, guild_id }.into());
+        }
+
         Ok(())
     }
 
@@ -278,6 +340,7 @@ impl Lavalink {
         let config = NodeConfig {
             address,
             authorization: authorization.into(),
+            protocol: self.protocol,
             resume: self.resume.clone(),
             user_id: self.user_id,
         };
@@ -394,10 +457,137 @@ impl Lavalink {
 #[cfg(test)]
 mod tests {
     use super::{ClientError, ClientErrorType, Lavalink};
+    use crate::{
+        model::{
+            Equalizer, EqualizerBand, IncomingEvent, OutgoingEvent, Pause, Play, PlayerReconnected,
+            VoiceUpdate, Volume,
+        },
+        node::{Node, NodeConfig, Protocol},
+    };
+    use futures_util::{FutureExt, StreamExt};
     use static_assertions::assert_impl_all;
-    use std::{error::Error, fmt::Debug};
+    use std::{
+        error::Error,
+        fmt::Debug,
+        net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+        sync::Arc,
+    };
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{VoiceServerUpdate, VoiceStateUpdate},
+        },
+        id::Id,
+        voice::VoiceState,
+    };
 
     assert_impl_all!(ClientErrorType: Debug, Send, Sync);
     assert_impl_all!(ClientError: Error, Send, Sync);
     assert_impl_all!(Lavalink: Debug, Send, Sync);
+
+    fn server_update(endpoint: &str) -> VoiceServerUpdate {
+        VoiceServerUpdate {
+            endpoint: Some(endpoint.to_owned()),
+            guild_id: Id::new(1),
+            token: "token".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn endpoint_change_reapplies_player_state() -> Result<(), Box<dyn Error>> {
+        let guild_id = Id::new(1);
+        let address = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2333));
+        let lavalink = Lavalink::new(Id::new(2), 1);
+        let config = NodeConfig {
+            address,
+            authorization: "auth".to_owned(),
+            protocol: Protocol::V3,
+            resume: None,
+            user_id: Id::new(2),
+        };
+        let (node, mut outgoing, mut incoming) =
+            Node::disconnected(config, lavalink.players().clone());
+        lavalink.nodes.insert(address, Arc::new(node));
+
+        lavalink
+            .process(&Event::VoiceStateUpdate(Box::new(VoiceStateUpdate(
+                VoiceState {
+                    channel_id: Some(Id::new(3)),
+                    deaf: false,
+                    guild_id: Some(guild_id),
+                    member: None,
+                    mute: false,
+                    self_deaf: false,
+                    self_mute: false,
+                    self_stream: false,
+                    self_video: false,
+                    session_id: "session".to_owned(),
+                    suppress: false,
+                    user_id: Id::new(2),
+                    request_to_speak_timestamp: None,
+                },
+            ))))
+            .await?;
+        lavalink
+            .process(&Event::VoiceServerUpdate(server_update("a.discord.media")))
+            .await?;
+        assert_eq!(
+            Some(OutgoingEvent::from(VoiceUpdate::new(
+                guild_id,
+                "session",
+                server_update("a.discord.media"),
+            ))),
+            outgoing.try_recv().ok(),
+        );
+
+        let player = lavalink.players().get(&guild_id).unwrap();
+        let bands = vec![EqualizerBand::new(0, 0.25)];
+        player.send(Play::from((guild_id, "track")))?;
+        player.send(Volume::new(guild_id, 50))?;
+        player.send(Equalizer::new(guild_id, bands.clone()))?;
+        player.send(Pause::new(guild_id, true))?;
+        player.set_position(3000);
+        while outgoing.try_recv().is_ok() {}
+
+        // An update to the same endpoint only forwards the voice update.
+        lavalink
+            .process(&Event::VoiceServerUpdate(server_update("a.discord.media")))
+            .await?;
+        assert!(matches!(
+            outgoing.try_recv(),
+            Ok(OutgoingEvent::VoiceUpdate(_))
+        ));
+        assert!(outgoing.try_recv().is_err());
+
+        lavalink
+            .process(&Event::VoiceServerUpdate(server_update("b.discord.media")))
+            .await?;
+
+        let expected = [
+            OutgoingEvent::from(VoiceUpdate::new(
+                guild_id,
+                "session",
+                server_update("b.discord.media"),
+            )),
+            OutgoingEvent::from(Play::new(guild_id, "track", 3000, None, false)),
+            OutgoingEvent::from(Pause::new(guild_id, true)),
+            OutgoingEvent::from(Volume::new(guild_id, 50)),
+            OutgoingEvent::from(Equalizer::new(guild_id, bands)),
+        ];
+
+        for event in expected {
+            assert_eq!(Some(event), outgoing.try_recv().ok());
+        }
+
+        assert!(outgoing.try_recv().is_err());
+        assert_eq!(
+            Some(IncomingEvent::from(PlayerReconnected {
+                endpoint: Some("b.discord.media".to_owned()),
+                guild_id,
+            })),
+            incoming.next().now_or_never().flatten(),
+        );
+
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 //! Models to deserialize responses into and functions to create `http` crate
 //! requests.
 
+use crate::{model::UpdatePlayer, node::NodeSenderError};
 use http::{
     header::{HeaderValue, AUTHORIZATION},
     Error as HttpError, Request,
@@ -8,6 +9,7 @@ use http::{
 use percent_encoding::NON_ALPHANUMERIC;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::net::{IpAddr, SocketAddr};
+use twilight_model::id::{marker::GuildMarker, Id};
 
 /// The type of search result given.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -314,6 +316,43 @@ pub fn unmark_failed_address(
     )
 }
 
+/// Update a guild's player on a Lavalink v4 node.
+///
+/// Set `no_replace` to `true` to keep playing the current track if the update
+/// contains a new one. The session ID is sent by the node in its [`Ready`]
+/// event.
+///
+/// The response will include a body describing the updated player.
+///
+/// # Errors
+///
+/// Returns a [`NodeSenderErrorType::BuildingRequest`] error type if the
+/// request failed to build, such as if the authorization is invalid.
+///
+/// Returns a [`NodeSenderErrorType::Serializing`] error type if the update
+/// failed to serialize.
+///
+/// [`NodeSenderErrorType::BuildingRequest`]: crate::node::NodeSenderErrorType::BuildingRequest
+/// [`NodeSenderErrorType::Serializing`]: crate::node::NodeSenderErrorType::Serializing
+/// [`Ready`]: crate::model::Ready
+pub fn update_player(
+    address: SocketAddr,
+    authorization: impl AsRef<str>,
+    session_id: impl AsRef<str>,
+    guild_id: Id<GuildMarker>,
+    update: &UpdatePlayer,
+    no_replace: bool,
+) -> Result<Request<Vec<u8>>, NodeSenderError> {
+    crate::node::update_player_request(
+        address,
+        authorization.as_ref(),
+        session_id.as_ref(),
+        guild_id,
+        update,
+        no_replace,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
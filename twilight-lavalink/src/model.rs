@@ -20,6 +20,8 @@ pub enum Opcode {
     Play,
     /// An update about a player's current track.
     PlayerUpdate,
+    /// A Lavalink v4 session is ready.
+    Ready,
     /// Seek a player's active track to a new position.
     Seek,
     /// Updated statistics about a node.
@@ -37,6 +39,7 @@ pub mod outgoing {
 
     use super::Opcode;
     use serde::{Deserialize, Serialize};
+    use serde_json::{Map, Value};
     use twilight_model::{
         gateway::payload::incoming::VoiceServerUpdate,
         id::{marker::GuildMarker, Id},
@@ -63,6 +66,10 @@ pub mod outgoing {
         VoiceUpdate(VoiceUpdate),
         /// Set the volume of a player.
         Volume(Volume),
+        /// Raw JSON payload, such as a message defined by a Lavalink plugin.
+        ///
+        /// The payload is sent as-is and isn't validated.
+        Raw(Value),
     }
 
     impl From<Destroy> for OutgoingEvent {
@@ -197,6 +204,27 @@ pub mod outgoing {
         }
     }
 
+    /// Filters applied to the audio of a player.
+    ///
+    /// Filters that are `None` aren't applied.
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Filters {
+        /// The bands to use as part of the equalizer.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub equalizer: Option<Vec<EqualizerBand>>,
+        /// Other filters, such as `timescale` or filters defined by a Lavalink
+        /// plugin, keyed by their name.
+        ///
+        /// The filters are sent as-is and aren't validated.
+        #[serde(flatten)]
+        pub other: Map<String, Value>,
+        /// The volume multiplier, where `1.0` is 100%.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub volume: Option<f64>,
+    }
+
     /// Pause or unpause a player.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -369,6 +397,53 @@ pub mod outgoing {
         }
     }
 
+    /// Update a player through the REST API of a Lavalink v4 node.
+    ///
+    /// Fields that are `None` leave the player unchanged.
+    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct UpdatePlayer {
+        /// The position in milliseconds to end the track.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub end_time: Option<u64>,
+        /// The filters to apply, replacing any existing filters.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub filters: Option<Filters>,
+        /// Whether the player is paused.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub paused: Option<bool>,
+        /// The position in milliseconds to seek to.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub position: Option<i64>,
+        /// The track to play.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub track: Option<UpdatePlayerTrack>,
+        /// The volume of the player from 0 to 1000. 100 is the default.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub volume: Option<i64>,
+    }
+
+    /// Track to play as part of an [`UpdatePlayer`].
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct UpdatePlayerTrack {
+        /// The base64 track to play.
+        ///
+        /// Set to `None` to stop the playing track.
+        pub encoded: Option<String>,
+    }
+
+    impl UpdatePlayerTrack {
+        /// Create a new track to play, or `None` to stop the playing track.
+        pub fn new(encoded: impl Into<Option<String>>) -> Self {
+            Self {
+                encoded: encoded.into(),
+            }
+        }
+    }
+
     /// A combined voice server and voice state update.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -441,16 +516,35 @@ pub mod incoming {
     //! Events that Lavalink sends to clients.
 
     use super::Opcode;
-    use serde::{Deserialize, Serialize};
+    use serde::{
+        de::{Deserializer, Error as DeError},
+        Deserialize, Serialize, Serializer,
+    };
+    use serde_json::Value;
     use twilight_model::id::{marker::GuildMarker, Id};
 
     /// An incoming event from a Lavalink node.
-    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    ///
+    /// Events are deserialized based on their opcode and, for track events,
+    /// their type. Events with an unrecognized opcode or type deserialize into
+    /// [`Unknown`], while recognized events with an invalid payload fail to
+    /// deserialize.
+    ///
+    /// [`Unknown`]: Self::Unknown
+    #[derive(Clone, Debug, PartialEq, Serialize)]
     #[non_exhaustive]
     #[serde(untagged)]
     pub enum IncomingEvent {
+        /// A player's voice server changed and its state was re-applied.
+        ///
+        /// This is emitted by the [`Lavalink`] client rather than Lavalink.
+        ///
+        /// [`Lavalink`]: crate::Lavalink
+        PlayerReconnected(PlayerReconnected),
         /// An update about the information of a player.
         PlayerUpdate(PlayerUpdate),
+        /// A Lavalink v4 session is ready.
+        Ready(Ready),
         /// New statistics about a node and its host.
         Stats(Stats),
         /// A track ended.
@@ -459,6 +553,62 @@ pub mod incoming {
         TrackStart(TrackStart),
         /// The voice websocket connection was closed.
         WeboscketClosed(WebsocketClosed),
+        /// An event that isn't otherwise recognized, such as one sent by a
+        /// Lavalink plugin.
+        Unknown(UnknownEvent),
+    }
+
+    impl<'de> Deserialize<'de> for IncomingEvent {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            /// Recognized kind of an event.
+            enum Kind {
+                PlayerUpdate,
+                Ready,
+                Stats,
+                TrackEnd,
+                TrackStart,
+                WebsocketClosed,
+            }
+
+            let raw = Value::deserialize(deserializer)?;
+            let op = raw
+                .get("op")
+                .and_then(Value::as_str)
+                .ok_or_else(|| DeError::missing_field("op"))?;
+
+            let kind = match (op, raw.get("type").and_then(Value::as_str)) {
+                ("playerUpdate", _) => Kind::PlayerUpdate,
+                ("ready", _) => Kind::Ready,
+                ("stats", _) => Kind::Stats,
+                ("event", Some("TrackEndEvent")) => Kind::TrackEnd,
+                ("event", Some("TrackStartEvent")) => Kind::TrackStart,
+                ("event", Some("WebSocketClosedEvent")) => Kind::WebsocketClosed,
+                _ => {
+                    let op = op.to_owned();
+
+                    return Ok(Self::Unknown(UnknownEvent { op, raw }));
+                }
+            };
+
+            let event = match kind {
+                Kind::PlayerUpdate => PlayerUpdate::deserialize(raw).map(Self::PlayerUpdate),
+                Kind::Ready => Ready::deserialize(raw).map(Self::Ready),
+                Kind::Stats => Stats::deserialize(raw).map(Self::Stats),
+                Kind::TrackEnd => TrackEnd::deserialize(raw).map(Self::TrackEnd),
+                Kind::TrackStart => TrackStart::deserialize(raw).map(Self::TrackStart),
+                Kind::WebsocketClosed => {
+                    WebsocketClosed::deserialize(raw).map(Self::WeboscketClosed)
+                }
+            };
+
+            event.map_err(DeError::custom)
+        }
+    }
+
+    impl From<PlayerReconnected> for IncomingEvent {
+        fn from(event: PlayerReconnected) -> IncomingEvent {
+            Self::PlayerReconnected(event)
+        }
     }
 
     impl From<PlayerUpdate> for IncomingEvent {
@@ -467,12 +617,38 @@ pub mod incoming {
         }
     }
 
+    impl From<Ready> for IncomingEvent {
+        fn from(event: Ready) -> IncomingEvent {
+            Self::Ready(event)
+        }
+    }
+
     impl From<Stats> for IncomingEvent {
         fn from(event: Stats) -> IncomingEvent {
             Self::Stats(event)
         }
     }
 
+    impl From<UnknownEvent> for IncomingEvent {
+        fn from(event: UnknownEvent) -> IncomingEvent {
+            Self::Unknown(event)
+        }
+    }
+
+    /// A player's voice server changed while it was connected.
+    ///
+    /// The voice update and the player's last known state, such as its track,
+    /// position, and volume, have been re-sent to the node.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlayerReconnected {
+        /// The new voice server endpoint.
+        pub endpoint: Option<String>,
+        /// The guild ID of the player.
+        pub guild_id: Id<GuildMarker>,
+    }
+
     /// An update about the information of a player.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -493,12 +669,32 @@ pub mod incoming {
     pub struct PlayerUpdateState {
         /// True when the player is connected to the voice gateway.
         pub connected: bool,
+        /// Round trip latency to the voice gateway in milliseconds.
+        ///
+        /// -1 if not connected, and None if not sent by the node.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub ping: Option<i64>,
         /// Unix timestamp of the player in milliseconds.
         pub time: i64,
         /// Track position in milliseconds. None if not playing anything.
         pub position: Option<i64>,
     }
 
+    /// A Lavalink v4 session is ready.
+    ///
+    /// This is the first event sent by a Lavalink v4 node after connecting.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Ready {
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// Whether a previous session was resumed.
+        pub resumed: bool,
+        /// The ID of the session, used to control players over REST.
+        pub session_id: String,
+    }
+
     /// Statistics about a node and its host.
     #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -578,6 +774,38 @@ pub mod incoming {
         WebsocketClosed,
     }
 
+    /// An event that isn't otherwise recognized, such as one sent by a Lavalink
+    /// plugin.
+    ///
+    /// Serializes back into the raw payload.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[non_exhaustive]
+    pub struct UnknownEvent {
+        /// The opcode of the event.
+        pub op: String,
+        /// The entire raw payload of the event, including the opcode.
+        pub raw: Value,
+    }
+
+    impl<'de> Deserialize<'de> for UnknownEvent {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = Value::deserialize(deserializer)?;
+            let op = raw
+                .get("op")
+                .and_then(Value::as_str)
+                .ok_or_else(|| DeError::missing_field("op"))?
+                .to_owned();
+
+            Ok(Self { op, raw })
+        }
+    }
+
+    impl Serialize for UnknownEvent {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.raw.serialize(serializer)
+        }
+    }
+
     /// A track ended.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -637,12 +865,13 @@ pub mod incoming {
 
 pub use self::{
     incoming::{
-        IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames, StatsMemory,
-        TrackEnd, TrackEventType, TrackStart, WebsocketClosed,
+        IncomingEvent, PlayerReconnected, PlayerUpdate, PlayerUpdateState, Ready, Stats, StatsCpu,
+        StatsFrames, StatsMemory, TrackEnd, TrackEventType, TrackStart, UnknownEvent,
+        WebsocketClosed,
     },
     outgoing::{
-        Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, Stop, VoiceUpdate,
-        Volume,
+        Destroy, Equalizer, EqualizerBand, Filters, OutgoingEvent, Pause, Play, Seek, Stop,
+        UpdatePlayer, UpdatePlayerTrack, VoiceUpdate, Volume,
     },
 };
 
@@ -650,16 +879,18 @@ pub use self::{
 mod tests {
     use super::{
         incoming::{
-            IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames,
-            StatsMemory, TrackEnd, TrackEventType, TrackStart, WebsocketClosed,
+            IncomingEvent, PlayerReconnected, PlayerUpdate, PlayerUpdateState, Ready, Stats,
+            StatsCpu, StatsFrames, StatsMemory, TrackEnd, TrackEventType, TrackStart, UnknownEvent,
+            WebsocketClosed,
         },
         outgoing::{
-            Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, Stop, VoiceUpdate,
-            Volume,
+            Destroy, Equalizer, EqualizerBand, Filters, OutgoingEvent, Pause, Play, Seek, Stop,
+            UpdatePlayer, UpdatePlayerTrack, VoiceUpdate, Volume,
         },
         Opcode,
     };
     use serde::{Deserialize, Serialize};
+    use serde_json::{Map, Value};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::fmt::Debug;
@@ -706,8 +937,10 @@ mod tests {
         IncomingEvent: Clone,
         Debug,
         Deserialize<'static>,
+        From<PlayerReconnected>,
         From<PlayerUpdate>,
         From<Stats>,
+        From<UnknownEvent>,
         PartialEq,
         Send,
         Serialize,
@@ -742,7 +975,18 @@ mod tests {
         Serialize,
         Sync,
     );
-    assert_fields!(PlayerUpdateState: position, time);
+    assert_fields!(PlayerReconnected: endpoint, guild_id);
+    assert_impl_all!(
+        PlayerReconnected: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(PlayerUpdateState: connected, ping, position, time);
     assert_impl_all!(
         PlayerUpdateState: Clone,
         Debug,
@@ -898,6 +1142,68 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(UnknownEvent: op, raw);
+    assert_impl_all!(
+        UnknownEvent: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Filters: equalizer, other, volume);
+    assert_impl_all!(
+        Filters: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Ready: op, resumed, session_id);
+    assert_impl_all!(
+        Ready: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(
+        UpdatePlayer: end_time,
+        filters,
+        paused,
+        position,
+        track,
+        volume
+    );
+    assert_impl_all!(
+        UpdatePlayer: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(UpdatePlayerTrack: encoded);
+    assert_impl_all!(
+        UpdatePlayerTrack: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(VoiceUpdate: event, guild_id, op, session_id);
     assert_impl_all!(
         VoiceUpdate: Clone,
@@ -998,4 +1304,120 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn unknown_event() {
+        const PAYLOAD: &str = r#"{"op":"segmentsLoaded","guildId":"1","segments":[{"category":"sponsor","start":1.5,"end":20}],"nested":{"flag":true,"value":null}}"#;
+
+        let event = serde_json::from_str::<IncomingEvent>(PAYLOAD).unwrap();
+        let IncomingEvent::Unknown(unknown) = &event else {
+            panic!("expected unknown event, got {event:?}");
+        };
+        assert_eq!("segmentsLoaded", unknown.op);
+        assert_eq!(serde_json::from_str::<Value>(PAYLOAD).unwrap(), unknown.raw);
+        assert_eq!(
+            serde_json::from_str::<Value>(PAYLOAD).unwrap(),
+            serde_json::to_value(&event).unwrap()
+        );
+
+        // Known opcodes with unknown event types are preserved as well.
+        let event = serde_json::from_str::<IncomingEvent>(
+            r#"{"op":"event","type":"SegmentSkipped","guildId":"1"}"#,
+        )
+        .unwrap();
+        assert!(matches!(event, IncomingEvent::Unknown(UnknownEvent { op, .. }) if op == "event"));
+
+        assert!(serde_json::from_str::<IncomingEvent>(r#"{"guildId":"1"}"#).is_err());
+    }
+
+    #[test]
+    fn known_event_invalid() {
+        assert!(serde_json::from_str::<IncomingEvent>(
+            r#"{"op":"playerUpdate","guildId":"1","state":{"connected":"yes"}}"#,
+        )
+        .is_err());
+        assert!(serde_json::from_str::<IncomingEvent>(
+            r#"{"op":"event","type":"TrackEndEvent","guildId":"1"}"#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn ready() {
+        let event = serde_json::from_str::<IncomingEvent>(
+            r#"{"op":"ready","resumed":false,"sessionId":"la3kfsdf5eafe848"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            IncomingEvent::from(Ready {
+                op: Opcode::Ready,
+                resumed: false,
+                session_id: "la3kfsdf5eafe848".to_owned(),
+            }),
+            event
+        );
+    }
+
+    #[test]
+    fn player_update() {
+        let event = serde_json::from_str::<IncomingEvent>(
+            r#"{"op":"playerUpdate","guildId":"1","state":{"time":1500467109,"position":60000,"connected":true,"ping":50}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            IncomingEvent::from(PlayerUpdate {
+                guild_id: Id::new(1),
+                op: Opcode::PlayerUpdate,
+                state: PlayerUpdateState {
+                    connected: true,
+                    ping: Some(50),
+                    position: Some(60000),
+                    time: 1_500_467_109,
+                },
+            }),
+            event
+        );
+    }
+
+    #[test]
+    fn update_player() {
+        let filters = Filters {
+            equalizer: Some(Vec::from([EqualizerBand::new(0, 0.25)])),
+            other: Map::from_iter([("timescale".to_owned(), serde_json::json!({ "speed": 1.5 }))]),
+            volume: None,
+        };
+        let update = UpdatePlayer {
+            filters: Some(filters),
+            position: Some(5000),
+            track: Some(UpdatePlayerTrack { encoded: None }),
+            ..UpdatePlayer::default()
+        };
+
+        assert_eq!(
+            serde_json::json!({
+                "filters": {
+                    "equalizer": [{ "band": 0, "gain": 0.25 }],
+                    "timescale": { "speed": 1.5 },
+                },
+                "position": 5000,
+                "track": { "encoded": null },
+            }),
+            serde_json::to_value(&update).unwrap()
+        );
+    }
+
+    #[test]
+    fn outgoing_raw() {
+        let value = serde_json::json!({
+            "op": "lyrics",
+            "guildId": "1",
+        });
+
+        assert_eq!(
+            serde_json::to_value(OutgoingEvent::Raw(value.clone())).unwrap(),
+            value
+        );
+    }
 }
@@ -17,8 +17,13 @@
 //!
 //! [`Lavalink`]: crate::client::Lavalink
 
+#[cfg(any(feature = "http-support", feature = "v4"))]
+use crate::model::UpdatePlayer;
 use crate::{
-    model::{IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Stats, StatsCpu, StatsMemory},
+    model::{
+        IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Ready, Stats, StatsCpu, StatsMemory,
+        TrackEnd,
+    },
     player::PlayerManager,
 };
 use futures_util::{
@@ -26,14 +31,29 @@ use futures_util::{
     sink::SinkExt,
     stream::{Stream, StreamExt},
 };
-use http::header::{HeaderName, AUTHORIZATION};
+#[cfg(any(feature = "http-support", feature = "v4"))]
+use http::{header::CONTENT_TYPE, Error as HttpError, Request};
+use http::{
+    header::{HeaderName, HeaderValue, AUTHORIZATION},
+    StatusCode,
+};
+#[cfg(feature = "v4")]
+use http_body_util::Full;
+#[cfg(feature = "v4")]
+use hyper::body::Bytes;
+#[cfg(feature = "v4")]
+use hyper_util::{
+    client::legacy::{connect::HttpConnector, Client as HyperClient},
+    rt::TokioExecutor,
+};
 use std::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     net::SocketAddr,
     pin::Pin,
+    sync::{Arc, Mutex},
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{
     net::TcpStream,
@@ -43,6 +63,8 @@ use tokio::{
 use tokio_websockets::{
     upgrade, ClientBuilder, Error as WebsocketError, MaybeTlsStream, Message, WebSocketStream,
 };
+#[cfg(any(feature = "http-support", feature = "v4"))]
+use twilight_model::id::marker::GuildMarker;
 use twilight_model::id::{marker::UserMarker, Id};
 
 /// An error occurred while either initializing a connection or while running
@@ -126,8 +148,8 @@ pub enum NodeErrorType {
 /// An error that can occur while sending an event over a node.
 #[derive(Debug)]
 pub struct NodeSenderError {
-    kind: NodeSenderErrorType,
-    source: Option<Box<dyn Error + Send + Sync>>,
+    pub(crate) kind: NodeSenderErrorType,
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
 }
 
 impl NodeSenderError {
@@ -151,7 +173,24 @@ impl NodeSenderError {
 impl Display for NodeSenderError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            NodeSenderErrorType::BuildingRequest => f.write_str("failed to build http request"),
+            NodeSenderErrorType::Requesting => f.write_str("failed to send http request"),
+            NodeSenderErrorType::Response { status } => {
+                f.write_str("node responded with status code ")?;
+
+                Display::fmt(status, f)
+            }
             NodeSenderErrorType::Sending => f.write_str("failed to send over channel"),
+            NodeSenderErrorType::Serializing => f.write_str("failed to serialize player update"),
+            NodeSenderErrorType::SessionUnavailable => {
+                f.write_str("node hasn't sent its session id yet")
+            }
+            NodeSenderErrorType::StartTimeInvalid { start_time } => {
+                f.write_str("start time ")?;
+                Display::fmt(start_time, f)?;
+
+                f.write_str(" is too large")
+            }
         }
     }
 }
@@ -168,8 +207,30 @@ impl Error for NodeSenderError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum NodeSenderErrorType {
+    /// Building the HTTP request to update a player failed.
+    BuildingRequest,
+    /// Sending the HTTP request to update a player failed.
+    Requesting,
+    /// The node responded to the HTTP request with an unsuccessful status
+    /// code.
+    Response {
+        /// Status code of the response.
+        status: StatusCode,
+    },
     /// Error occurred while sending over the channel.
     Sending,
+    /// Serializing the player update into the HTTP request's body failed.
+    Serializing,
+    /// The node hasn't sent its [`Ready`] event, so its session ID required to
+    /// update players over HTTP isn't known yet.
+    ///
+    /// [`Ready`]: crate::model::Ready
+    SessionUnavailable,
+    /// The start time of a track to play is too large to be sent to the node.
+    StartTimeInvalid {
+        /// Provided start time in milliseconds.
+        start_time: u64,
+    },
 }
 
 /// Stream of incoming events from a node.
@@ -221,6 +282,24 @@ impl NodeSender {
     }
 }
 
+/// Version of the Lavalink protocol spoken by a node.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// Lavalink v3, where players are controlled by sending events over the
+    /// websocket.
+    #[default]
+    V3,
+    /// Lavalink v4, where players are controlled through the node's REST API
+    /// and the websocket is only used to receive events.
+    ///
+    /// Refer to [`Player`] for which methods use the REST API.
+    ///
+    /// [`Player`]: crate::player::Player
+    #[cfg(feature = "v4")]
+    V4,
+}
+
 /// The configuration that a [`Node`] uses to connect to a Lavalink server.
 #[derive(Clone, Eq, PartialEq)]
 #[non_exhaustive]
@@ -230,6 +309,10 @@ pub struct NodeConfig {
     pub address: SocketAddr,
     /// The password to use when authenticating.
     pub authorization: String,
+    /// The version of the Lavalink protocol spoken by the node.
+    ///
+    /// Defaults to [`Protocol::V3`].
+    pub protocol: Protocol,
     /// The details for resuming a Lavalink session, if any.
     ///
     /// Set this to `None` to disable resume capability.
@@ -254,6 +337,7 @@ impl Debug for NodeConfig {
         f.debug_struct("NodeConfig")
             .field("address", &self.address)
             .field("authorization", &Redacted)
+            .field("protocol", &self.protocol)
             .field("resume", &self.resume)
             .field("user_id", &self.user_id)
             .finish()
@@ -311,6 +395,7 @@ impl NodeConfig {
         Self {
             address,
             authorization,
+            protocol: Protocol::V3,
             resume,
             user_id,
         }
@@ -326,8 +411,12 @@ impl NodeConfig {
 #[derive(Debug)]
 pub struct Node {
     config: NodeConfig,
+    #[cfg(feature = "v4")]
+    http: HyperClient<HttpConnector, Full<Bytes>>,
+    incoming_tx: UnboundedSender<IncomingEvent>,
     lavalink_tx: UnboundedSender<OutgoingEvent>,
     players: PlayerManager,
+    session_id: Arc<Mutex<Option<Box<str>>>>,
     stats: BiLock<Stats>,
 }
 
@@ -360,39 +449,33 @@ impl Node {
         config: NodeConfig,
         players: PlayerManager,
     ) -> Result<(Self, IncomingEvents), NodeError> {
-        let (bilock_left, bilock_right) = BiLock::new(Stats {
-            cpu: StatsCpu {
-                cores: 0,
-                lavalink_load: 0f64,
-                system_load: 0f64,
-            },
-            frames: None,
-            memory: StatsMemory {
-                allocated: 0,
-                free: 0,
-                used: 0,
-                reservable: 0,
-            },
-            players: 0,
-            playing_players: 0,
-            op: Opcode::Stats,
-            uptime: 0,
-        });
+        let (bilock_left, bilock_right) = BiLock::new(initial_stats());
+        let session_id = Arc::new(Mutex::new(None));
 
         tracing::debug!("starting connection to {}", config.address);
 
-        let (conn_loop, lavalink_tx, lavalink_rx) =
-            Connection::connect(config.clone(), players.clone(), bilock_right).await?;
+        let (conn_loop, lavalink_tx, lavalink_rx) = Connection::connect(
+            config.clone(),
+            players.clone(),
+            Arc::clone(&session_id),
+            bilock_right,
+        )
+        .await?;
 
         tracing::debug!("started connection to {}", config.address);
 
+        let incoming_tx = conn_loop.node_to.clone();
         tokio::spawn(conn_loop.run());
 
         Ok((
             Self {
                 config,
+                #[cfg(feature = "v4")]
+                http: http_client(),
+                incoming_tx,
                 lavalink_tx,
                 players,
+                session_id,
                 stats: bilock_left,
             },
             IncomingEvents { inner: lavalink_rx },
@@ -409,6 +492,100 @@ impl Node {
         &self.players
     }
 
+    /// Retrieve the ID of the node's session.
+    ///
+    /// This is only known for [Lavalink v4] nodes once they've sent their
+    /// [`Ready`] event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the session ID lock is poisoned.
+    ///
+    /// [`Ready`]: crate::model::Ready
+    /// [Lavalink v4]: Protocol::V4
+    pub fn session_id(&self) -> Option<String> {
+        self.session_id
+            .lock()
+            .expect("session id poisoned")
+            .as_deref()
+            .map(ToOwned::to_owned)
+    }
+
+    /// Update a guild's player through the node's REST API.
+    ///
+    /// This is only supported by [Lavalink v4] nodes. Set `no_replace` to
+    /// `true` to keep playing the current track if the update contains a new
+    /// one.
+    ///
+    /// Note that updating a player through the node won't update player
+    /// states, such as whether it's paused. Prefer the methods on [`Player`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeSenderErrorType::SessionUnavailable`] error type if
+    /// the node hasn't sent its session ID yet.
+    ///
+    /// Returns a [`NodeSenderErrorType::BuildingRequest`] error type if the
+    /// request failed to build, such as if the authorization is invalid.
+    ///
+    /// Returns a [`NodeSenderErrorType::Serializing`] error type if the update
+    /// failed to serialize.
+    ///
+    /// Returns a [`NodeSenderErrorType::Requesting`] error type if the request
+    /// failed to be sent.
+    ///
+    /// Returns a [`NodeSenderErrorType::Response`] error type if the node
+    /// responded with an unsuccessful status code.
+    ///
+    /// [`Player`]: crate::player::Player
+    /// [Lavalink v4]: Protocol::V4
+    #[cfg(feature = "v4")]
+    pub async fn update_player(
+        &self,
+        guild_id: Id<GuildMarker>,
+        update: &UpdatePlayer,
+        no_replace: bool,
+    ) -> Result<(), NodeSenderError> {
+        let session_id = self.session_id().ok_or(NodeSenderError {
+            kind: NodeSenderErrorType::SessionUnavailable,
+            source: None,
+        })?;
+
+        let request = update_player_request(
+            self.config.address,
+            &self.config.authorization,
+            &session_id,
+            guild_id,
+            update,
+            no_replace,
+        )?;
+
+        tracing::debug!(
+            "updating player {guild_id} on {}: {update:?}",
+            self.config.address
+        );
+
+        let response = self
+            .http
+            .request(request.map(|body| Full::new(Bytes::from(body))))
+            .await
+            .map_err(|source| NodeSenderError {
+                kind: NodeSenderErrorType::Requesting,
+                source: Some(Box::new(source)),
+            })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(NodeSenderError {
+                kind: NodeSenderErrorType::Response { status },
+                source: None,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Retrieve an immutable reference to the node's configuration.
     ///
     /// Note that sending player events through the node's sender won't update
@@ -432,6 +609,15 @@ impl Node {
         }
     }
 
+    /// Emit an event generated by the client to the node's incoming events.
+    pub(crate) fn emit(&self, event: IncomingEvent) {
+        // It's fine if the rx end dropped, often users don't need to care about
+        // these events.
+        if !self.incoming_tx.is_closed() {
+            let _result = self.incoming_tx.send(event);
+        }
+    }
+
     /// Retrieve a copy of the node's stats.
     pub async fn stats(&self) -> Stats {
         (*self.stats.lock().await).clone()
@@ -462,12 +648,115 @@ impl Node {
     }
 }
 
+#[cfg(test)]
+impl Node {
+    /// Create a node without a connection to a Lavalink server, returning the
+    /// receiving halves of the events that would be sent to the server and to
+    /// the user.
+    pub(crate) fn disconnected(
+        config: NodeConfig,
+        players: PlayerManager,
+    ) -> (Self, UnboundedReceiver<OutgoingEvent>, IncomingEvents) {
+        let (incoming_tx, incoming_rx) = mpsc::unbounded_channel();
+        let (lavalink_tx, lavalink_rx) = mpsc::unbounded_channel();
+        let (stats, _) = BiLock::new(initial_stats());
+
+        (
+            Self {
+                config,
+                #[cfg(feature = "v4")]
+                http: http_client(),
+                incoming_tx,
+                lavalink_tx,
+                players,
+                session_id: Arc::new(Mutex::new(None)),
+                stats,
+            },
+            lavalink_rx,
+            IncomingEvents { inner: incoming_rx },
+        )
+    }
+
+    /// Set the session ID as if the node sent its [`Ready`] event.
+    #[cfg(feature = "v4")]
+    pub(crate) fn set_session_id(&self, session_id: &str) {
+        self.session_id
+            .lock()
+            .expect("session id poisoned")
+            .replace(session_id.into());
+    }
+}
+
+/// HTTP client used to control players of Lavalink v4 nodes.
+#[cfg(feature = "v4")]
+fn http_client() -> HyperClient<HttpConnector, Full<Bytes>> {
+    HyperClient::builder(TokioExecutor::new()).build_http()
+}
+
+/// Build a request to update a guild's player on a Lavalink v4 node.
+#[cfg(any(feature = "http-support", feature = "v4"))]
+pub(crate) fn update_player_request(
+    address: SocketAddr,
+    authorization: &str,
+    session_id: &str,
+    guild_id: Id<GuildMarker>,
+    update: &UpdatePlayer,
+    no_replace: bool,
+) -> Result<Request<Vec<u8>>, NodeSenderError> {
+    let mut url = format!("http://{address}/v4/sessions/{session_id}/players/{guild_id}");
+
+    if no_replace {
+        url.push_str("?noReplace=true");
+    }
+
+    let body = serde_json::to_vec(update).map_err(|source| NodeSenderError {
+        kind: NodeSenderErrorType::Serializing,
+        source: Some(Box::new(source)),
+    })?;
+
+    HeaderValue::from_str(authorization)
+        .map_err(HttpError::from)
+        .and_then(|authorization| {
+            Request::patch(url)
+                .header(AUTHORIZATION, authorization)
+                .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+                .body(body)
+        })
+        .map_err(|source| NodeSenderError {
+            kind: NodeSenderErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })
+}
+
+/// Stats of a node that hasn't reported any yet.
+const fn initial_stats() -> Stats {
+    Stats {
+        cpu: StatsCpu {
+            cores: 0,
+            lavalink_load: 0f64,
+            system_load: 0f64,
+        },
+        frames: None,
+        memory: StatsMemory {
+            allocated: 0,
+            free: 0,
+            used: 0,
+            reservable: 0,
+        },
+        players: 0,
+        playing_players: 0,
+        op: Opcode::Stats,
+        uptime: 0,
+    }
+}
+
 struct Connection {
     config: NodeConfig,
     stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
     node_from: UnboundedReceiver<OutgoingEvent>,
     node_to: UnboundedSender<IncomingEvent>,
     players: PlayerManager,
+    session_id: Arc<Mutex<Option<Box<str>>>>,
     stats: BiLock<Stats>,
 }
 
@@ -475,6 +764,7 @@ impl Connection {
     async fn connect(
         config: NodeConfig,
         players: PlayerManager,
+        session_id: Arc<Mutex<Option<Box<str>>>>,
         stats: BiLock<Stats>,
     ) -> Result<
         (
@@ -496,6 +786,7 @@ impl Connection {
                 node_from: from_node,
                 node_to: to_node,
                 players,
+                session_id,
                 stats,
             },
             to_lavalink,
@@ -557,15 +848,20 @@ impl Connection {
             return Ok(true);
         };
 
-        let Ok(event) = serde_json::from_str(text) else {
-            tracing::warn!("unknown message from lavalink node: {text}");
+        let event = match serde_json::from_str(text) {
+            Ok(event) => event,
+            Err(source) => {
+                tracing::warn!(?source, "invalid message from lavalink node: {text}");
 
-            return Ok(true);
+                return Ok(true);
+            }
         };
 
         match &event {
             IncomingEvent::PlayerUpdate(update) => self.player_update(update)?,
+            IncomingEvent::Ready(ready) => self.ready(ready),
             IncomingEvent::Stats(stats) => self.stats(stats).await?,
+            IncomingEvent::TrackEnd(end) => self.track_end(end),
             _ => {}
         }
 
@@ -588,12 +884,30 @@ impl Connection {
             return Ok(());
         };
 
-        player.set_position(update.state.position.unwrap_or(0));
-        player.set_time(update.state.time);
+        player.update_state(&update.state, Instant::now());
 
         Ok(())
     }
 
+    fn ready(&self, ready: &Ready) {
+        tracing::debug!(
+            "session {} of node {} is ready",
+            ready.session_id,
+            self.config.address,
+        );
+
+        self.session_id
+            .lock()
+            .expect("session id poisoned")
+            .replace(ready.session_id.as_str().into());
+    }
+
+    fn track_end(&self, end: &TrackEnd) {
+        if let Some(player) = self.players.get(&end.guild_id) {
+            player.end_track(&end.track);
+        }
+    }
+
     async fn stats(&self, stats: &Stats) -> Result<(), NodeError> {
         *self.stats.lock().await = stats.clone();
 
@@ -611,8 +925,14 @@ impl Drop for Connection {
 }
 
 fn connect_request(state: &NodeConfig) -> Result<ClientBuilder, NodeError> {
+    let uri = match state.protocol {
+        Protocol::V3 => format!("ws://{}", state.address),
+        #[cfg(feature = "v4")]
+        Protocol::V4 => format!("ws://{}/v4/websocket", state.address),
+    };
+
     let mut builder = ClientBuilder::new()
-        .uri(&format!("ws://{}", state.address))
+        .uri(&uri)
         .map_err(|source| NodeError {
             kind: NodeErrorType::BuildingConnectionRequest,
             source: Some(Box::new(source)),
@@ -621,6 +941,10 @@ fn connect_request(state: &NodeConfig) -> Result<ClientBuilder, NodeError> {
         .add_header(
             HeaderName::from_static("user-id"),
             state.user_id.get().into(),
+        )
+        .add_header(
+            HeaderName::from_static("client-name"),
+            HeaderValue::from_static(concat!("twilight-lavalink/", env!("CARGO_PKG_VERSION"))),
         );
 
     if state.resume.is_some() {
@@ -721,7 +1045,10 @@ async fn backoff(
 
 #[cfg(test)]
 mod tests {
-    use super::{Node, NodeConfig, NodeError, NodeErrorType, Resume};
+    use super::{
+        Node, NodeConfig, NodeError, NodeErrorType, NodeSenderError, NodeSenderErrorType, Protocol,
+        Resume,
+    };
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{
         error::Error,
@@ -730,13 +1057,27 @@ mod tests {
     };
     use twilight_model::id::Id;
 
-    assert_fields!(NodeConfig: address, authorization, resume, user_id);
+    assert_fields!(NodeConfig: address, authorization, protocol, resume, user_id);
     assert_impl_all!(NodeConfig: Clone, Debug, Send, Sync);
     assert_fields!(NodeErrorType::SerializingMessage: message);
     assert_fields!(NodeErrorType::Unauthorized: address, authorization);
     assert_impl_all!(NodeErrorType: Debug, Send, Sync);
     assert_impl_all!(NodeError: Error, Send, Sync);
+    assert_fields!(NodeSenderErrorType::Response: status);
+    assert_fields!(NodeSenderErrorType::StartTimeInvalid: start_time);
+    assert_impl_all!(NodeSenderErrorType: Debug, Send, Sync);
+    assert_impl_all!(NodeSenderError: Error, Send, Sync);
     assert_impl_all!(Node: Debug, Send, Sync);
+    assert_impl_all!(
+        Protocol: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
     assert_fields!(Resume: timeout);
     assert_impl_all!(Resume: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
 
@@ -745,10 +1086,38 @@ mod tests {
         let config = NodeConfig {
             address: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 1312)),
             authorization: "some auth".to_owned(),
+            protocol: Protocol::V3,
             resume: None,
             user_id: Id::new(123),
         };
 
         assert!(format!("{config:?}").contains("authorization: <redacted>"));
     }
+
+    #[cfg(any(feature = "http-support", feature = "v4"))]
+    #[test]
+    fn update_player_request_uri() -> Result<(), Box<dyn Error>> {
+        use super::update_player_request;
+        use crate::model::UpdatePlayer;
+
+        let address = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 2333));
+        let update = UpdatePlayer {
+            paused: Some(true),
+            ..UpdatePlayer::default()
+        };
+
+        let request =
+            update_player_request(address, "auth", "session", Id::new(1), &update, false)?;
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/sessions/session/players/1",
+            request.uri().to_string()
+        );
+        assert_eq!("auth", request.headers()["authorization"]);
+        assert_eq!(br#"{"paused":true}"#, request.body().as_slice());
+
+        let request = update_player_request(address, "auth", "session", Id::new(1), &update, true)?;
+        assert_eq!(Some("noReplace=true"), request.uri().query());
+
+        Ok(())
+    }
 }
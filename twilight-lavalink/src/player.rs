@@ -8,18 +8,27 @@
 //! [players]: Player
 //! [send events]: Player::send
 //! [read the position]: Player::position
+//!
+//! Nodes only report a player's position every few seconds, so
+//! [`Player::position_estimate`] extrapolates it from the last report for
+//! uses such as progress bars.
 
 use crate::{
-    model::{Destroy, OutgoingEvent},
-    node::{Node, NodeSenderError},
+    model::{
+        Destroy, Equalizer, Filters, OutgoingEvent, Pause, Play, PlayerUpdateState, Seek,
+        UpdatePlayer, UpdatePlayerTrack, Volume,
+    },
+    node::{Node, NodeSenderError, NodeSenderErrorType, Protocol},
 };
 use dashmap::DashMap;
+use serde_json::{Map, Value};
 use std::{
     fmt::Debug,
     sync::{
         atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex,
     },
+    time::{Duration, Instant},
 };
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
@@ -63,8 +72,6 @@ impl PlayerManager {
     ///
     /// Returns a [`NodeSenderErrorType::Sending`] error type if node is no
     /// longer connected.
-    ///
-    /// [`NodeSenderErrorType::Sending`]: crate::node::NodeSenderErrorType::Sending
     pub fn destroy(&self, guild_id: Id<GuildMarker>) -> Result<(), NodeSenderError> {
         if let Some(player) = self.get(&guild_id) {
             player
@@ -81,14 +88,33 @@ impl PlayerManager {
 ///
 /// This can be used to send events over a node and to read the details of a
 /// player for a guild.
+///
+/// The [`play`], [`pause`], [`seek`], [`set_volume`], and [`set_filters`]
+/// methods control the player through the node's REST API if the node speaks
+/// [Lavalink v4], and otherwise by sending events over the node's websocket.
+///
+/// [Lavalink v4]: Protocol::V4
+/// [`pause`]: Self::pause
+/// [`play`]: Self::play
+/// [`seek`]: Self::seek
+/// [`set_filters`]: Self::set_filters
+/// [`set_volume`]: Self::set_volume
 #[derive(Debug)]
 pub struct Player {
     channel_id: AtomicU64,
+    connected: AtomicBool,
+    equalizer: Mutex<Option<Equalizer>>,
     guild_id: Id<GuildMarker>,
+    length: Mutex<Option<Duration>>,
     node: Arc<Node>,
     paused: AtomicBool,
+    ping: AtomicI64,
+    play: Mutex<Option<Play>>,
     position: AtomicI64,
     time: AtomicI64,
+    /// When the position was last known, either from a player update or
+    /// from controlling the player.
+    updated_at: Mutex<Option<Instant>>,
     volume: AtomicI64,
 }
 
@@ -96,11 +122,17 @@ impl Player {
     pub(crate) const fn new(guild_id: Id<GuildMarker>, node: Arc<Node>) -> Self {
         Self {
             channel_id: AtomicU64::new(0),
+            connected: AtomicBool::new(false),
+            equalizer: Mutex::new(None),
             guild_id,
+            length: Mutex::new(None),
             node,
             paused: AtomicBool::new(false),
+            ping: AtomicI64::new(-1),
+            play: Mutex::new(None),
             position: AtomicI64::new(0),
             time: AtomicI64::new(0),
+            updated_at: Mutex::new(None),
             volume: AtomicI64::new(100),
         }
     }
@@ -138,7 +170,6 @@ impl Player {
     /// Returns a [`NodeSenderErrorType::Sending`] error type if node is no
     /// longer connected.
     ///
-    /// [`NodeSenderErrorType::Sending`]: crate::node::NodeSenderErrorType::Sending
     /// [`Pause`]: crate::model::outgoing::Pause
     /// [`Play`]: crate::model::outgoing::Play
     pub fn send(&self, event: impl Into<OutgoingEvent>) -> Result<(), NodeSenderError> {
@@ -148,16 +179,212 @@ impl Player {
     fn _send(&self, event: OutgoingEvent) -> Result<(), NodeSenderError> {
         tracing::debug!("sending event on guild player {}: {event:?}", self.guild_id);
 
-        match &event {
-            OutgoingEvent::Pause(event) => self.paused.store(event.pause, Ordering::Release),
+        self.apply(&event);
+
+        self.node.send(event)
+    }
+
+    /// Play a track, optionally starting from a position or not replacing the
+    /// playing track.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeSenderErrorType::Sending`] error type if node is no
+    /// longer connected.
+    ///
+    /// Returns a [`NodeSenderErrorType::StartTimeInvalid`] error type if the
+    /// start time doesn't fit in an [`i64`].
+    ///
+    /// Refer to [`Node::update_player`] for the errors that can occur for
+    /// [Lavalink v4] nodes.
+    ///
+    /// [Lavalink v4]: Protocol::V4
+    pub async fn play(&self, play: Play) -> Result<(), NodeSenderError> {
+        let position = play
+            .start_time
+            .map(|start_time| {
+                i64::try_from(start_time).map_err(|source| NodeSenderError {
+                    kind: NodeSenderErrorType::StartTimeInvalid { start_time },
+                    source: Some(Box::new(source)),
+                })
+            })
+            .transpose()?;
+        let update = UpdatePlayer {
+            end_time: play.end_time,
+            position,
+            track: Some(UpdatePlayerTrack::new(play.track.clone())),
+            ..UpdatePlayer::default()
+        };
+        let no_replace = play.no_replace;
+
+        self.update(OutgoingEvent::from(play), &update, no_replace)
+            .await
+    }
+
+    /// Pause or resume the player.
+    ///
+    /// Set to `true` to pause the player or `false` to resume it.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`play`].
+    ///
+    /// [`play`]: Self::play
+    pub async fn pause(&self, pause: bool) -> Result<(), NodeSenderError> {
+        let update = UpdatePlayer {
+            paused: Some(pause),
+            ..UpdatePlayer::default()
+        };
+
+        self.update(Pause::new(self.guild_id, pause).into(), &update, false)
+            .await
+    }
+
+    /// Seek the playing track to a position in milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`play`].
+    ///
+    /// [`play`]: Self::play
+    pub async fn seek(&self, position: i64) -> Result<(), NodeSenderError> {
+        let update = UpdatePlayer {
+            position: Some(position),
+            ..UpdatePlayer::default()
+        };
+
+        self.update(Seek::new(self.guild_id, position).into(), &update, false)
+            .await
+    }
+
+    /// Set the volume of the player from 0 to 1000. 100 is the default.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`play`].
+    ///
+    /// [`play`]: Self::play
+    pub async fn set_volume(&self, volume: i64) -> Result<(), NodeSenderError> {
+        let update = UpdatePlayer {
+            volume: Some(volume),
+            ..UpdatePlayer::default()
+        };
+
+        self.update(Volume::new(self.guild_id, volume).into(), &update, false)
+            .await
+    }
+
+    /// Set the filters of the player, replacing any existing filters.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`play`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the equalizer lock is poisoned.
+    ///
+    /// [`play`]: Self::play
+    pub async fn set_filters(&self, filters: Filters) -> Result<(), NodeSenderError> {
+        let mut payload = Map::from_iter([
+            ("op".to_owned(), Value::from("filters")),
+            ("guildId".to_owned(), Value::from(self.guild_id.to_string())),
+        ]);
+
+        if let Ok(Value::Object(filters)) = serde_json::to_value(&filters) {
+            payload.extend(filters);
+        }
+
+        let equalizer = filters
+            .equalizer
+            .clone()
+            .map(|bands| Equalizer::new(self.guild_id, bands));
+        let update = UpdatePlayer {
+            filters: Some(filters),
+            ..UpdatePlayer::default()
+        };
+
+        self.update(OutgoingEvent::Raw(Value::Object(payload)), &update, false)
+            .await?;
+        *self.equalizer.lock().expect("equalizer poisoned") = equalizer;
+
+        Ok(())
+    }
+
+    /// Update the player over REST for Lavalink v4 nodes, or send the event
+    /// over the websocket otherwise.
+    #[cfg_attr(not(feature = "v4"), allow(clippy::unused_async, unused_variables))]
+    async fn update(
+        &self,
+        event: OutgoingEvent,
+        update: &UpdatePlayer,
+        no_replace: bool,
+    ) -> Result<(), NodeSenderError> {
+        match self.node.config().protocol {
+            Protocol::V3 => self._send(event),
+            #[cfg(feature = "v4")]
+            Protocol::V4 => {
+                self.node
+                    .update_player(self.guild_id, update, no_replace)
+                    .await?;
+                self.apply(&event);
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Update the known state of the player from an event sent to its node.
+    fn apply(&self, event: &OutgoingEvent) {
+        match event {
+            OutgoingEvent::Destroy(_) | OutgoingEvent::Stop(_) => {
+                self.play.lock().expect("play poisoned").take();
+                self.set_track_length(None);
+                self.forget_position();
+            }
+            OutgoingEvent::Equalizer(event) => {
+                self.equalizer
+                    .lock()
+                    .expect("equalizer poisoned")
+                    .replace(event.clone());
+            }
+            OutgoingEvent::Pause(event) => {
+                // Freeze the estimated position when pausing and continue
+                // extrapolating from it when resuming.
+                let now = Instant::now();
+
+                if let Some(position) = self.position_estimate(now) {
+                    self.anchor(position, now);
+                }
+
+                self.paused.store(event.pause, Ordering::Release);
+            }
+            OutgoingEvent::Play(event) => {
+                let mut play = self.play.lock().expect("play poisoned");
+
+                // Lavalink ignores the track if one is already playing and
+                // replacing it isn't allowed.
+                if !event.no_replace || play.is_none() {
+                    play.replace(event.clone());
+                    self.set_track_length(None);
+                    self.anchor(
+                        Duration::from_millis(event.start_time.unwrap_or(0)),
+                        Instant::now(),
+                    );
+                }
+            }
+            OutgoingEvent::Seek(event) => {
+                self.anchor(
+                    Duration::from_millis(u64::try_from(event.position).unwrap_or(0)),
+                    Instant::now(),
+                );
+            }
             OutgoingEvent::Volume(event) => {
                 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
                 self.volume.store(event.volume, Ordering::Release);
             }
             _ => {}
         }
-
-        self.node.send(event)
     }
 
     /// Return an immutable reference to the node linked to the player.
@@ -187,6 +414,23 @@ impl Player {
         self.guild_id
     }
 
+    /// Return whether the player is connected to the voice gateway, as of the
+    /// last player update.
+    pub fn connected(&self) -> bool {
+        self.connected.load(Ordering::Acquire)
+    }
+
+    /// Return the round trip latency of the player to the voice gateway, as
+    /// of the last player update.
+    ///
+    /// Returns `None` if the player isn't connected or its node doesn't report
+    /// the latency.
+    pub fn ping(&self) -> Option<Duration> {
+        u64::try_from(self.ping.load(Ordering::Acquire))
+            .ok()
+            .map(Duration::from_millis)
+    }
+
     /// Return whether the player is paused.
     pub fn paused(&self) -> bool {
         self.paused.load(Ordering::Acquire)
@@ -212,18 +456,491 @@ impl Player {
         self.time.store(time, Ordering::Release);
     }
 
+    /// Return when the player's position was last known.
+    ///
+    /// This is when the last player update was received, or when the player
+    /// was last told to play, pause, or seek, whichever is later. Returns
+    /// `None` if neither has happened yet or if no track is playing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the updated at lock is poisoned.
+    pub fn updated_at(&self) -> Option<Instant> {
+        *self.updated_at.lock().expect("updated at poisoned")
+    }
+
+    /// Estimate the position of the playing track at an instant.
+    ///
+    /// The position is extrapolated from the last known position while the
+    /// player is playing and is frozen while paused. It's clamped to the
+    /// [track length] if known. Returns `None` if the position isn't known,
+    /// such as when no track is playing.
+    ///
+    /// # Examples
+    ///
+    /// Estimate the current position of a player:
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use twilight_lavalink::Lavalink;
+    /// # use twilight_model::id::Id;
+    /// # let (guild_id, user_id) = (Id::new(1), Id::new(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    ///
+    /// if let Some(player) = lavalink.players().get(&guild_id) {
+    ///     if let Some(position) = player.position_estimate(Instant::now()) {
+    ///         println!("{}s into the track", position.as_secs());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the updated at or track length lock is poisoned.
+    ///
+    /// [track length]: Self::set_track_length
+    pub fn position_estimate(&self, now: Instant) -> Option<Duration> {
+        let (position, updated_at) = {
+            let updated_at = self.updated_at.lock().expect("updated at poisoned");
+
+            (self.position(), (*updated_at)?)
+        };
+        let mut position = Duration::from_millis(u64::try_from(position).unwrap_or_default());
+
+        if !self.paused() {
+            position += now.saturating_duration_since(updated_at);
+        }
+
+        Some(match self.track_length() {
+            Some(length) => position.min(length),
+            None => position,
+        })
+    }
+
+    /// Record a known position of the player at an instant.
+    fn anchor(&self, position: Duration, at: Instant) {
+        let mut updated_at = self.updated_at.lock().expect("updated at poisoned");
+
+        self.set_position(i64::try_from(position.as_millis()).unwrap_or(i64::MAX));
+        updated_at.replace(at);
+    }
+
+    /// Forget the player's position, such as when its track ends.
+    fn forget_position(&self) {
+        let mut updated_at = self.updated_at.lock().expect("updated at poisoned");
+
+        self.set_position(0);
+        updated_at.take();
+    }
+
+    /// Update the known state of the player from a player update received at
+    /// an instant.
+    pub(crate) fn update_state(&self, state: &PlayerUpdateState, at: Instant) {
+        self.connected.store(state.connected, Ordering::Release);
+        self.ping.store(state.ping.unwrap_or(-1), Ordering::Release);
+        self.set_time(state.time);
+
+        // Nodes only include the position while a track is playing.
+        match state.position {
+            Some(position) => self.anchor(
+                Duration::from_millis(u64::try_from(position).unwrap_or(0)),
+                at,
+            ),
+            None => self.forget_position(),
+        }
+    }
+
+    /// Return the length of the playing track, if known.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the track length lock is poisoned.
+    pub fn track_length(&self) -> Option<Duration> {
+        *self.length.lock().expect("length poisoned")
+    }
+
+    /// Set the length of the playing track, such as from the track info
+    /// returned when loading it, to clamp the [position estimate] to.
+    ///
+    /// This is cleared when another track is played or the track ends, so it
+    /// should be set after playing a track.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the track length lock is poisoned.
+    ///
+    /// [position estimate]: Self::position_estimate
+    pub fn set_track_length(&self, length: Option<Duration>) {
+        *self.length.lock().expect("length poisoned") = length;
+    }
+
     /// Return the player's volume.
     pub fn volume(&self) -> i64 {
         self.volume.load(Ordering::Relaxed)
     }
+
+    /// Return the base64 track the player was last told to play.
+    ///
+    /// This is cleared when the track ends or the player is stopped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the play lock is poisoned.
+    pub fn track(&self) -> Option<String> {
+        self.play
+            .lock()
+            .expect("play poisoned")
+            .as_ref()
+            .map(|play| play.track.clone())
+    }
+
+    /// Forget the playing track if it is the one that ended.
+    pub(crate) fn end_track(&self, track: &str) {
+        let mut play = self.play.lock().expect("play poisoned");
+
+        if play.as_ref().is_some_and(|play| play.track == track) {
+            play.take();
+            self.set_track_length(None);
+            self.forget_position();
+        }
+    }
+
+    /// Events re-applying the last known state of the player.
+    ///
+    /// The playing track is restarted from its last reported position,
+    /// followed by the paused state, volume, and equalizer if they differ from
+    /// a new player's defaults.
+    pub(crate) fn state_events(&self) -> Vec<OutgoingEvent> {
+        let mut events = Vec::new();
+
+        if let Some(play) = self.play.lock().expect("play poisoned").as_ref() {
+            events.push(OutgoingEvent::from(Play::new(
+                self.guild_id,
+                play.track.clone(),
+                u64::try_from(self.position()).ok(),
+                play.end_time,
+                false,
+            )));
+        }
+
+        if self.paused() {
+            events.push(OutgoingEvent::from(Pause::new(self.guild_id, true)));
+        }
+
+        let volume = self.volume();
+
+        if volume != 100 {
+            events.push(OutgoingEvent::from(Volume::new(self.guild_id, volume)));
+        }
+
+        if let Some(equalizer) = self.equalizer.lock().expect("equalizer poisoned").clone() {
+            events.push(OutgoingEvent::from(equalizer));
+        }
+
+        events
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Player, PlayerManager};
+    #[cfg(feature = "v4")]
+    use crate::model::EqualizerBand;
+    use crate::{
+        model::{Filters, OutgoingEvent, Pause, Play, PlayerUpdateState},
+        node::{Node, NodeConfig, NodeSenderErrorType, Protocol},
+    };
+    #[cfg(feature = "v4")]
+    use serde_json::Value;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        net::{Ipv4Addr, SocketAddr},
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+    use tokio::sync::mpsc::UnboundedReceiver;
+    #[cfg(feature = "v4")]
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+        sync::mpsc,
+    };
+    use twilight_model::id::Id;
 
     assert_impl_all!(PlayerManager: Debug, Default, Send, Sync);
     assert_impl_all!(Player: Debug, Send, Sync);
+
+    fn player(
+        address: SocketAddr,
+        protocol: Protocol,
+    ) -> (Player, UnboundedReceiver<OutgoingEvent>) {
+        let config = NodeConfig {
+            address,
+            authorization: "auth".to_owned(),
+            protocol,
+            resume: None,
+            user_id: Id::new(2),
+        };
+        let (node, outgoing, _) = Node::disconnected(config, PlayerManager::new());
+
+        (Player::new(Id::new(1), Arc::new(node)), outgoing)
+    }
+
+    fn state(position: i64) -> PlayerUpdateState {
+        PlayerUpdateState {
+            connected: true,
+            ping: Some(50),
+            position: Some(position),
+            time: 1_500_467_109,
+        }
+    }
+
+    /// Accept HTTP requests, forwarding their request line and JSON body while
+    /// responding without content.
+    #[cfg(feature = "v4")]
+    async fn stub_server(
+    ) -> Result<(SocketAddr, UnboundedReceiver<(String, Value)>), Box<dyn Error>> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let address = listener.local_addr()?;
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let tx = tx.clone();
+
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    let mut line = String::new();
+
+                    while stream.read_line(&mut line).await.unwrap() != 0 {
+                        let mut content_length = 0;
+
+                        loop {
+                            let mut header = String::new();
+                            stream.read_line(&mut header).await.unwrap();
+
+                            let Some((name, value)) = header.trim_end().split_once(':') else {
+                                break;
+                            };
+
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+
+                        let mut body = vec![0; content_length];
+                        stream.read_exact(&mut body).await.unwrap();
+                        tx.send((
+                            line.trim_end().to_owned(),
+                            serde_json::from_slice(&body).unwrap(),
+                        ))
+                        .unwrap();
+
+                        stream
+                            .write_all(b"HTTP/1.1 204 No Content\r\n\r\n")
+                            .await
+                            .unwrap();
+                        line.clear();
+                    }
+                });
+            }
+        });
+
+        Ok((address, rx))
+    }
+
+    #[cfg(feature = "v4")]
+    #[tokio::test]
+    async fn update_over_http() -> Result<(), Box<dyn Error>> {
+        const PATH: &str = "PATCH /v4/sessions/session/players/1";
+
+        let (address, mut requests) = stub_server().await?;
+        let (player, mut outgoing) = player(address, Protocol::V4);
+
+        assert!(matches!(
+            player.pause(true).await.unwrap_err().kind(),
+            NodeSenderErrorType::SessionUnavailable
+        ));
+        assert!(!player.paused());
+
+        player.node().set_session_id("session");
+        player
+            .play(Play::new(player.guild_id(), "track", 5000, None, true))
+            .await?;
+        player.pause(true).await?;
+        player.seek(1000).await?;
+        player.set_volume(50).await?;
+        player
+            .set_filters(Filters {
+                equalizer: Some(Vec::from([EqualizerBand::new(0, 0.25)])),
+                ..Filters::default()
+            })
+            .await?;
+
+        let expected = [
+            (
+                format!("{PATH}?noReplace=true HTTP/1.1"),
+                serde_json::json!({ "position": 5000, "track": { "encoded": "track" } }),
+            ),
+            (
+                format!("{PATH} HTTP/1.1"),
+                serde_json::json!({ "paused": true }),
+            ),
+            (
+                format!("{PATH} HTTP/1.1"),
+                serde_json::json!({ "position": 1000 }),
+            ),
+            (
+                format!("{PATH} HTTP/1.1"),
+                serde_json::json!({ "volume": 50 }),
+            ),
+            (
+                format!("{PATH} HTTP/1.1"),
+                serde_json::json!({ "filters": { "equalizer": [{ "band": 0, "gain": 0.25 }] } }),
+            ),
+        ];
+
+        for request in expected {
+            assert_eq!(Some(request), requests.recv().await);
+        }
+
+        assert_eq!(Some("track".to_owned()), player.track());
+        assert!(player.paused());
+        assert_eq!(50, player.volume());
+        assert!(outgoing.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_over_websocket() -> Result<(), Box<dyn Error>> {
+        let (player, mut outgoing) =
+            player(SocketAddr::from((Ipv4Addr::LOCALHOST, 2333)), Protocol::V3);
+
+        player.pause(true).await?;
+        assert_eq!(
+            Some(OutgoingEvent::from(Pause::new(player.guild_id(), true))),
+            outgoing.try_recv().ok()
+        );
+
+        player
+            .set_filters(Filters {
+                volume: Some(0.5),
+                ..Filters::default()
+            })
+            .await?;
+        assert_eq!(
+            Some(OutgoingEvent::Raw(serde_json::json!({
+                "op": "filters",
+                "guildId": "1",
+                "volume": 0.5,
+            }))),
+            outgoing.try_recv().ok()
+        );
+
+        let start_time = u64::MAX;
+        assert!(matches!(
+            player
+                .play(Play::new(player.guild_id(), "track", start_time, None, false))
+                .await
+                .unwrap_err()
+                .kind(),
+            NodeSenderErrorType::StartTimeInvalid { start_time: invalid } if *invalid == start_time
+        ));
+        assert!(player.track().is_none());
+        assert!(outgoing.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn position_estimate_playing() {
+        let (player, _outgoing) =
+            player(SocketAddr::from((Ipv4Addr::LOCALHOST, 2333)), Protocol::V3);
+        let now = Instant::now();
+
+        assert!(player.position_estimate(now).is_none());
+        assert!(!player.connected());
+        assert!(player.ping().is_none());
+        assert!(player.updated_at().is_none());
+
+        player.update_state(&state(1000), now);
+        assert!(player.connected());
+        assert_eq!(Some(Duration::from_millis(50)), player.ping());
+        assert_eq!(Some(now), player.updated_at());
+
+        assert_eq!(Some(Duration::from_secs(1)), player.position_estimate(now));
+        assert_eq!(
+            Some(Duration::from_millis(3500)),
+            player.position_estimate(now + Duration::from_millis(2500))
+        );
+    }
+
+    #[test]
+    fn position_estimate_unknown() -> Result<(), Box<dyn Error>> {
+        let (player, _outgoing) =
+            player(SocketAddr::from((Ipv4Addr::LOCALHOST, 2333)), Protocol::V3);
+        let now = Instant::now();
+
+        player.update_state(
+            &PlayerUpdateState {
+                position: None,
+                ..state(0)
+            },
+            now,
+        );
+        assert!(player.position_estimate(now).is_none());
+
+        player.send(Play::from((player.guild_id(), "track")))?;
+        player.update_state(&state(1000), now);
+        assert_eq!(Some(Duration::from_secs(1)), player.position_estimate(now));
+
+        player.end_track("track");
+        assert!(player.position_estimate(now).is_none());
+        assert!(player.updated_at().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn position_estimate_paused() -> Result<(), Box<dyn Error>> {
+        let (player, _outgoing) =
+            player(SocketAddr::from((Ipv4Addr::LOCALHOST, 2333)), Protocol::V3);
+        player.send(Pause::new(player.guild_id(), true))?;
+
+        let now = Instant::now();
+        player.update_state(&state(5000), now);
+
+        assert_eq!(
+            Some(Duration::from_secs(5)),
+            player.position_estimate(now + Duration::from_secs(10))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn position_estimate_clamped() -> Result<(), Box<dyn Error>> {
+        let (player, _outgoing) =
+            player(SocketAddr::from((Ipv4Addr::LOCALHOST, 2333)), Protocol::V3);
+        player.send(Play::from((player.guild_id(), "track")))?;
+        player.set_track_length(Some(Duration::from_secs(4)));
+
+        let now = Instant::now();
+        player.update_state(&state(3000), now);
+
+        assert_eq!(
+            Some(Duration::from_secs(4)),
+            player.position_estimate(now + Duration::from_secs(5))
+        );
+
+        // Another track's length isn't known.
+        player.send(Play::new(player.guild_id(), "other", None, None, false))?;
+        assert!(player.track_length().is_none());
+
+        Ok(())
+    }
 }
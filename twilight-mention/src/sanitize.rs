@@ -0,0 +1,378 @@
+//! Neutralize mentions and markdown in untrusted content.
+//!
+//! Bots echoing content provided by users, such as in a "say" command, need to
+//! make sure that the content can't ping everyone in a guild or render
+//! differently than it was written.
+//!
+//! Every function in this module returns [`Cow::Borrowed`] if the content
+//! didn't need to be changed, only allocating when something was replaced.
+//!
+//! # Examples
+//!
+//! Neutralize the mentions and markdown of a user's input:
+//!
+//! ```
+//! use twilight_mention::sanitize::{escape_markdown, strip_mentions};
+//!
+//! let input = "@everyone look at **this**";
+//! let content = escape_markdown(&strip_mentions(input)).into_owned();
+//!
+//! assert_eq!("@\u{200B}everyone look at \\*\\*this\\*\\*", content);
+//! ```
+
+use std::borrow::Cow;
+
+/// Zero-width space inserted by [`strip_mentions`] to break up mentions.
+pub const ZERO_WIDTH_SPACE: &str = "\u{200B}";
+
+/// Mentions that ping every member of a channel, without the leading `@`.
+const GLOBAL_MENTIONS: [&str; 2] = ["everyone", "here"];
+
+/// Escape all Discord markdown in the content.
+///
+/// A backslash is inserted before every character that may start or end
+/// formatting: bold, italics, underlines, strikethroughs, spoilers, inline code
+/// and code blocks, masked links, and backslashes themselves. Block quotes,
+/// headers, subtext, and lists are escaped when they begin a line.
+///
+/// Already escaped content is escaped again, so that it renders exactly as it
+/// was written.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use twilight_mention::sanitize::escape_markdown;
+///
+/// assert_eq!(r"\|\|spoiler\|\|", escape_markdown("||spoiler||"));
+/// assert_eq!(r"\> quote", escape_markdown("> quote"));
+/// assert!(matches!(escape_markdown("plain text"), Cow::Borrowed(_)));
+/// ```
+pub fn escape_markdown(content: &str) -> Cow<'_, str> {
+    /// Position within the current line.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    enum Line {
+        /// Only whitespace has been seen since the start of the line.
+        Start,
+        /// Only digits have been seen since the start of the line, which may
+        /// be the number of an ordered list item.
+        Number,
+        /// Any other character has been seen since the start of the line.
+        Rest,
+    }
+
+    let mut escaped = Replacer::new(content);
+    let mut line = Line::Start;
+
+    for (index, character) in content.char_indices() {
+        let escape = match character {
+            '\\' | '*' | '_' | '~' | '`' | '|' | '[' | ']' => true,
+            '#' | '-' | '>' => line == Line::Start,
+            '.' => line == Line::Number && content[index + 1..].starts_with(' '),
+            _ => false,
+        };
+
+        if escape {
+            escaped.insert(index, "\\");
+        }
+
+        line = match character {
+            '\n' => Line::Start,
+            ' ' | '\t' if line == Line::Start => Line::Start,
+            '0'..='9' if line != Line::Rest => Line::Number,
+            _ => Line::Rest,
+        };
+    }
+
+    escaped.finish()
+}
+
+/// Neutralize user, role, `@everyone`, and `@here` mentions in the content by
+/// inserting a [`ZERO_WIDTH_SPACE`] after their `@`.
+///
+/// The content still looks the same when displayed, but will not mention
+/// anyone. Use [`strip_mentions_with`] to replace mentions with other text.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use twilight_mention::sanitize::strip_mentions;
+///
+/// assert_eq!("hi <@\u{200B}123>", strip_mentions("hi <@123>"));
+/// assert_eq!("@\u{200B}here", strip_mentions("@here"));
+/// assert!(matches!(strip_mentions("hi <#123>"), Cow::Borrowed(_)));
+/// ```
+pub fn strip_mentions(content: &str) -> Cow<'_, str> {
+    let mut stripped = Replacer::new(content);
+
+    for (start, _) in Mentions::new(content) {
+        // The `@` is either the first character or follows a `<`.
+        let at = if content.as_bytes()[start] == b'@' {
+            start
+        } else {
+            start + 1
+        };
+
+        stripped.insert(at + 1, ZERO_WIDTH_SPACE);
+    }
+
+    stripped.finish()
+}
+
+/// Replace user, role, `@everyone`, and `@here` mentions in the content with
+/// the given replacement.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_mention::sanitize::strip_mentions_with;
+///
+/// assert_eq!(
+///     "hi [mention] and [mention]",
+///     strip_mentions_with("hi <@&1> and @everyone", "[mention]"),
+/// );
+/// ```
+pub fn strip_mentions_with<'a>(content: &'a str, replacement: &str) -> Cow<'a, str> {
+    let mut stripped = Replacer::new(content);
+
+    for (start, end) in Mentions::new(content) {
+        stripped.replace(start, end, replacement);
+    }
+
+    stripped.finish()
+}
+
+/// Iterator over the byte ranges of mentions that ping in some content.
+struct Mentions<'a> {
+    /// Content being searched.
+    content: &'a str,
+    /// Byte index to continue searching from.
+    index: usize,
+}
+
+impl<'a> Mentions<'a> {
+    /// Create a new iterator over the mentions in the content.
+    const fn new(content: &'a str) -> Self {
+        Self { content, index: 0 }
+    }
+
+    /// Length of a user or role mention's remainder after `<@`, including the
+    /// closing `>`.
+    fn id_mention_len(rest: &[u8]) -> Option<usize> {
+        let prefix = usize::from(matches!(rest.first(), Some(b'!' | b'&')));
+        let digits = rest[prefix..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        (digits > 0 && rest.get(prefix + digits) == Some(&b'>')).then_some(prefix + digits + 1)
+    }
+}
+
+impl Iterator for Mentions<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.content.as_bytes();
+
+        while let Some(offset) = self.content[self.index..].find('@') {
+            let at = self.index + offset;
+            let rest = &bytes[at + 1..];
+            self.index = at + 1;
+
+            if at > 0 && bytes[at - 1] == b'<' {
+                if let Some(len) = Self::id_mention_len(rest) {
+                    self.index = at + 1 + len;
+
+                    return Some((at - 1, self.index));
+                }
+            }
+
+            if let Some(mention) = GLOBAL_MENTIONS
+                .iter()
+                .find(|mention| rest.starts_with(mention.as_bytes()))
+            {
+                self.index = at + 1 + mention.len();
+
+                return Some((at, self.index));
+            }
+        }
+
+        None
+    }
+}
+
+/// Lazily built copy of some content with ranges replaced.
+///
+/// Ranges must be replaced in order and must not overlap.
+struct Replacer<'a> {
+    /// Original content.
+    content: &'a str,
+    /// Byte index in the original content up to which has been copied.
+    copied: usize,
+    /// Modified content, if anything has been replaced.
+    output: Option<String>,
+}
+
+impl<'a> Replacer<'a> {
+    /// Create a new replacer over the content.
+    const fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            copied: 0,
+            output: None,
+        }
+    }
+
+    /// Insert text at the byte index in the original content.
+    fn insert(&mut self, index: usize, text: &str) {
+        self.replace(index, index, text);
+    }
+
+    /// Replace the byte range of the original content with the text.
+    fn replace(&mut self, start: usize, end: usize, text: &str) {
+        let output = self
+            .output
+            .get_or_insert_with(|| String::with_capacity(self.content.len() + text.len()));
+
+        output.push_str(&self.content[self.copied..start]);
+        output.push_str(text);
+        self.copied = end;
+    }
+
+    /// Finish replacing, borrowing the original content if nothing was
+    /// replaced.
+    fn finish(self) -> Cow<'a, str> {
+        match self.output {
+            Some(mut output) => {
+                output.push_str(&self.content[self.copied..]);
+
+                Cow::Owned(output)
+            }
+            None => Cow::Borrowed(self.content),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_markdown, strip_mentions, strip_mentions_with, ZERO_WIDTH_SPACE};
+    use std::borrow::Cow;
+
+    fn zwsp(content: &str) -> String {
+        content.replace('^', ZERO_WIDTH_SPACE)
+    }
+
+    #[test]
+    fn escape_markdown_borrows() {
+        for content in ["", "plain text", "a - b # c > d", "1.5 and 2.5", "<@123>"] {
+            assert!(matches!(escape_markdown(content), Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn escape_markdown_inline() {
+        assert_eq!(r"\*\*bold\*\*", escape_markdown("**bold**"));
+        assert_eq!(
+            r"\*italics\* \_italics\_",
+            escape_markdown("*italics* _italics_")
+        );
+        assert_eq!(r"\_\_underline\_\_", escape_markdown("__underline__"));
+        assert_eq!(r"\~\~strike\~\~", escape_markdown("~~strike~~"));
+        assert_eq!(r"\|\|spoiler\|\|", escape_markdown("||spoiler||"));
+        assert_eq!(r"\`code\`", escape_markdown("`code`"));
+        assert_eq!(
+            r"\[link\](https://twilight.rs)",
+            escape_markdown("[link](https://twilight.rs)")
+        );
+    }
+
+    #[test]
+    fn escape_markdown_nested() {
+        assert_eq!(r"\*\*\*both\*\*\*", escape_markdown("***both***"));
+        assert_eq!(
+            r"\_\_\*\_\~\~all\~\~\_\*\_\_",
+            escape_markdown("__*_~~all~~_*__"),
+        );
+        assert_eq!(r"\|\|\`a\|\|b\`\|\|", escape_markdown("||`a||b`||"));
+        assert_eq!(r"\*\*a \_\_b\*\* c\_\_", escape_markdown("**a __b** c__"));
+    }
+
+    #[test]
+    fn escape_markdown_code_fence() {
+        assert_eq!(
+            "\\`\\`\\`rust\nlet a = 1;\n\\`\\`\\`",
+            escape_markdown("```rust\nlet a = 1;\n```"),
+        );
+    }
+
+    #[test]
+    fn escape_markdown_line_start() {
+        assert_eq!(r"\> quote", escape_markdown("> quote"));
+        assert_eq!(r"\>>> quote", escape_markdown(">>> quote"));
+        assert_eq!(r"\# header", escape_markdown("# header"));
+        assert_eq!(r"\-# subtext", escape_markdown("-# subtext"));
+        assert_eq!(r"\- item", escape_markdown("- item"));
+        assert_eq!(r"  \- item", escape_markdown("  - item"));
+        assert_eq!(r"12\. item", escape_markdown("12. item"));
+        assert_eq!("a\n\\> b\n\\- c", escape_markdown("a\n> b\n- c"));
+        assert_eq!("a > b - c # d 1. e", escape_markdown("a > b - c # d 1. e"));
+    }
+
+    #[test]
+    fn escape_markdown_already_escaped() {
+        assert_eq!(r"\\\*not bold\\\*", escape_markdown(r"\*not bold\*"));
+        assert_eq!(r"\\\\", escape_markdown(r"\\"));
+    }
+
+    #[test]
+    fn escape_markdown_unicode() {
+        assert_eq!("✨ \\*sparkle\\* ✨", escape_markdown("✨ *sparkle* ✨"));
+    }
+
+    #[test]
+    fn strip_mentions_borrows() {
+        for content in [
+            "",
+            "no mentions",
+            "<#123> <:emoji:123> <t:123>",
+            "<@> <@abc> <@123 <@&> <@!>",
+            "email@example.com",
+            &zwsp("<@^123> @^everyone"),
+        ] {
+            assert!(matches!(strip_mentions(content), Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn strip_mentions_default() {
+        assert_eq!(zwsp("<@^123>"), strip_mentions("<@123>"));
+        assert_eq!(zwsp("<@^!123>"), strip_mentions("<@!123>"));
+        assert_eq!(zwsp("<@^&123>"), strip_mentions("<@&123>"));
+        assert_eq!(zwsp("@^everyone"), strip_mentions("@everyone"));
+        assert_eq!(zwsp("@^here"), strip_mentions("@here"));
+        assert_eq!(zwsp("hi @^everyone!"), strip_mentions("hi @everyone!"));
+    }
+
+    #[test]
+    fn strip_mentions_overlapping() {
+        assert_eq!(zwsp("<@<@^123>>"), strip_mentions("<@<@123>>"));
+        assert_eq!(zwsp("@@^everyone"), strip_mentions("@@everyone"));
+        assert_eq!(zwsp("<@^1><@^&2>@^here"), strip_mentions("<@1><@&2>@here"));
+        assert_eq!(zwsp("<@^everyone>"), strip_mentions("<@everyone>"));
+        assert_eq!(zwsp("@^everyone@^here"), strip_mentions("@everyone@here"));
+    }
+
+    #[test]
+    fn strip_mentions_replacement() {
+        assert_eq!("[m]", strip_mentions_with("<@123>", "[m]"));
+        assert_eq!("a [m] b [m]", strip_mentions_with("a <@&1> b @here", "[m]"));
+        assert_eq!("<@[m]>", strip_mentions_with("<@<@!123>>", "[m]"));
+        assert_eq!("", strip_mentions_with("@everyone", ""));
+        assert!(matches!(
+            strip_mentions_with("nothing", "[m]"),
+            Cow::Borrowed(_),
+        ));
+    }
+}
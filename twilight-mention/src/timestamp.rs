@@ -33,7 +33,60 @@ use std::{
     cmp::Ordering,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
+use twilight_model::util::Timestamp as ModelTimestamp;
+
+/// Converting a time into a [`Timestamp`] failed.
+#[derive(Debug)]
+pub struct TimestampConversionError {
+    kind: TimestampConversionErrorType,
+}
+
+impl TimestampConversionError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TimestampConversionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        TimestampConversionErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for TimestampConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TimestampConversionErrorType::BeforeEpoch => {
+                f.write_str("time is before the unix epoch")
+            }
+            TimestampConversionErrorType::OutOfRange => {
+                f.write_str("time does not fit in a signed 64-bit unix timestamp")
+            }
+        }
+    }
+}
+
+impl Error for TimestampConversionError {}
+
+/// Type of [`TimestampConversionError`] that occurred.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TimestampConversionErrorType {
+    /// Time is before the Unix epoch.
+    BeforeEpoch,
+    /// Time is too far in the future to be represented as a signed 64-bit
+    /// Unix timestamp in seconds.
+    OutOfRange,
+}
 
 /// Converting a [`TimestampStyle`] from a string slice failed.
 #[derive(Debug)]
@@ -131,6 +184,131 @@ impl Timestamp {
         Self { style, unix }
     }
 
+    /// Create a timestamp from a [`Timestamp`] from the model crate and
+    /// optionally a style.
+    ///
+    /// Sub-second precision is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::{
+    ///     timestamp::{Timestamp, TimestampStyle},
+    ///     Mention,
+    /// };
+    /// use twilight_model::util::Timestamp as ModelTimestamp;
+    ///
+    /// let joined_at = ModelTimestamp::from_secs(1_624_044_388)?;
+    /// let timestamp = Timestamp::from_timestamp(joined_at, Some(TimestampStyle::RelativeTime))?;
+    /// assert_eq!("<t:1624044388:R>", timestamp.mention().to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`BeforeEpoch`] if the timestamp is before the
+    /// Unix epoch.
+    ///
+    /// [`BeforeEpoch`]: TimestampConversionErrorType::BeforeEpoch
+    /// [`Timestamp`]: twilight_model::util::Timestamp
+    pub fn from_timestamp(
+        timestamp: ModelTimestamp,
+        style: Option<TimestampStyle>,
+    ) -> Result<Self, TimestampConversionError> {
+        let unix = u64::try_from(timestamp.as_secs()).map_err(|_| TimestampConversionError {
+            kind: TimestampConversionErrorType::BeforeEpoch,
+        })?;
+
+        Ok(Self::new(unix, style))
+    }
+
+    /// Create a timestamp a duration from now and optionally a style.
+    ///
+    /// Pairs well with [`TimestampStyle::RelativeTime`] to display times such
+    /// as `in 3 minutes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use twilight_mention::{
+    ///     timestamp::{Timestamp, TimestampStyle},
+    ///     Mention,
+    /// };
+    ///
+    /// let timestamp = Timestamp::in_future(
+    ///     Duration::from_secs(3 * 60),
+    ///     Some(TimestampStyle::RelativeTime),
+    /// )?;
+    /// println!("The giveaway ends {}", timestamp.mention());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`OutOfRange`] if the resulting time is too
+    /// far in the future.
+    ///
+    /// [`OutOfRange`]: TimestampConversionErrorType::OutOfRange
+    pub fn in_future(
+        duration: Duration,
+        style: Option<TimestampStyle>,
+    ) -> Result<Self, TimestampConversionError> {
+        let time = SystemTime::now()
+            .checked_add(duration)
+            .ok_or(TimestampConversionError {
+                kind: TimestampConversionErrorType::OutOfRange,
+            })?;
+
+        Self::at_system_time(time, style)
+    }
+
+    /// Create a timestamp from a [`SystemTime`] and optionally a style.
+    ///
+    /// Sub-second precision is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use twilight_mention::{timestamp::Timestamp, Mention};
+    ///
+    /// let time = UNIX_EPOCH + Duration::from_secs(1_624_044_388);
+    /// let timestamp = Timestamp::at_system_time(time, None)?;
+    /// assert_eq!("<t:1624044388>", timestamp.mention().to_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`BeforeEpoch`] if the time is before the Unix
+    /// epoch.
+    ///
+    /// Returns an error of type [`OutOfRange`] if the time does not fit in a
+    /// signed 64-bit Unix timestamp in seconds.
+    ///
+    /// [`BeforeEpoch`]: TimestampConversionErrorType::BeforeEpoch
+    /// [`OutOfRange`]: TimestampConversionErrorType::OutOfRange
+    pub fn at_system_time(
+        time: SystemTime,
+        style: Option<TimestampStyle>,
+    ) -> Result<Self, TimestampConversionError> {
+        let unix = time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TimestampConversionError {
+                kind: TimestampConversionErrorType::BeforeEpoch,
+            })?
+            .as_secs();
+
+        if i64::try_from(unix).is_err() {
+            return Err(TimestampConversionError {
+                kind: TimestampConversionErrorType::OutOfRange,
+            });
+        }
+
+        Ok(Self::new(unix, style))
+    }
+
     /// Style representing the display modifier.
     ///
     /// ```
@@ -276,11 +454,22 @@ impl TryFrom<&str> for TimestampStyle {
 #[cfg(test)]
 mod tests {
     use super::{
-        Timestamp, TimestampStyle, TimestampStyleConversionError, TimestampStyleConversionErrorType,
+        Timestamp, TimestampConversionError, TimestampConversionErrorType, TimestampStyle,
+        TimestampStyleConversionError, TimestampStyleConversionErrorType,
     };
+    use crate::Mention;
     use static_assertions::assert_impl_all;
-    use std::{cmp::Ordering, error::Error, fmt::Debug, hash::Hash};
+    use std::{
+        cmp::Ordering,
+        error::Error,
+        fmt::Debug,
+        hash::Hash,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+    use twilight_model::util::Timestamp as ModelTimestamp;
 
+    assert_impl_all!(TimestampConversionErrorType: Debug, Send, Sync);
+    assert_impl_all!(TimestampConversionError: Debug, Error, Send, Sync);
     assert_impl_all!(TimestampStyleConversionErrorType: Debug, Send, Sync);
     assert_impl_all!(TimestampStyleConversionError: Debug, Error, Send, Sync);
     assert_impl_all!(
@@ -379,4 +568,47 @@ mod tests {
         assert!(TIMESTAMP_OLD < TIMESTAMP_NEW_STYLED);
         assert!(TIMESTAMP_OLD_STYLED < TIMESTAMP_NEW_STYLED);
     }
+
+    /// Test creating timestamps from model timestamps and system times.
+    #[test]
+    fn timestamp_conversions() -> Result<(), Box<dyn Error>> {
+        let model = ModelTimestamp::from_secs(1_624_044_388)?;
+        let timestamp = Timestamp::from_timestamp(model, Some(TimestampStyle::RelativeTime))?;
+        assert_eq!("<t:1624044388:R>", timestamp.mention().to_string());
+
+        let time = UNIX_EPOCH + Duration::from_millis(1_624_044_388_500);
+        let timestamp = Timestamp::at_system_time(time, Some(TimestampStyle::LongDate))?;
+        assert_eq!("<t:1624044388:D>", timestamp.mention().to_string());
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let timestamp = Timestamp::in_future(Duration::from_secs(180), None)?;
+        assert!(timestamp.unix() >= now + 180);
+
+        Ok(())
+    }
+
+    /// Test that times outside of the valid range fail to convert.
+    #[test]
+    fn timestamp_conversion_errors() -> Result<(), Box<dyn Error>> {
+        let model = ModelTimestamp::from_secs(-1)?;
+        assert_eq!(
+            &TimestampConversionErrorType::BeforeEpoch,
+            Timestamp::from_timestamp(model, None).unwrap_err().kind()
+        );
+
+        let time = UNIX_EPOCH - Duration::from_secs(1);
+        assert_eq!(
+            &TimestampConversionErrorType::BeforeEpoch,
+            Timestamp::at_system_time(time, None).unwrap_err().kind()
+        );
+
+        assert_eq!(
+            &TimestampConversionErrorType::OutOfRange,
+            Timestamp::in_future(Duration::MAX, None)
+                .unwrap_err()
+                .kind()
+        );
+
+        Ok(())
+    }
 }
@@ -11,12 +11,14 @@ pub mod modal;
 mod context_type;
 mod interaction_type;
 mod metadata;
+mod partial_interaction;
 mod resolved;
 
 pub use self::{
     context_type::InteractionContextType,
     interaction_type::InteractionType,
     metadata::InteractionMetadata,
+    partial_interaction::PartialInteraction,
     resolved::{InteractionChannel, InteractionDataResolved, InteractionMember},
 };
 
@@ -614,6 +616,7 @@ mod tests {
                             mfa_enabled: None,
                             name: "username".into(),
                             premium_type: None,
+                            primary_guild: None,
                             public_flags: None,
                             system: None,
                             verified: None,
@@ -668,6 +671,7 @@ mod tests {
                     mfa_enabled: None,
                     name: "username".into(),
                     premium_type: None,
+                    primary_guild: None,
                     public_flags: None,
                     system: None,
                     verified: None,
@@ -0,0 +1,80 @@
+use super::InteractionType;
+use crate::id::{
+    marker::{ApplicationMarker, ChannelMarker, GuildMarker, InteractionMarker},
+    Id,
+};
+use serde::{Deserialize, Serialize};
+
+/// Minimal envelope of an [`Interaction`] containing only what is needed to
+/// respond to it.
+///
+/// Used as a fallback when the full interaction fails to deserialize, such as
+/// when Discord sends data that isn't yet supported.
+///
+/// [`Interaction`]: super::Interaction
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PartialInteraction {
+    /// ID of the associated application.
+    pub application_id: Id<ApplicationMarker>,
+    /// ID of the channel the interaction was invoked in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// ID of the guild the interaction was invoked in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the interaction.
+    pub id: Id<InteractionMarker>,
+    /// Type of interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Token for responding to the interaction.
+    pub token: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PartialInteraction;
+    use crate::{application::interaction::InteractionType, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn partial_interaction() {
+        let value = PartialInteraction {
+            application_id: Id::new(1),
+            channel_id: Some(Id::new(2)),
+            guild_id: Some(Id::new(3)),
+            id: Id::new(4),
+            kind: InteractionType::ApplicationCommand,
+            token: "token".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PartialInteraction",
+                    len: 6,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::Str("type"),
+                Token::U8(InteractionType::ApplicationCommand as u8),
+                Token::Str("token"),
+                Token::Str("token"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
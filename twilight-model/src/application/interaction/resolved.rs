@@ -193,6 +193,7 @@ mod tests {
                         mfa_enabled: None,
                         name: "test".to_owned(),
                         premium_type: None,
+                        primary_guild: None,
                         public_flags: None,
                         system: None,
                         verified: None,
@@ -249,6 +250,7 @@ mod tests {
                 Id::new(400),
                 Role {
                     color: 0,
+                    colors: None,
                     hoist: true,
                     icon: None,
                     id: Id::new(400),
@@ -281,6 +283,7 @@ mod tests {
                     mfa_enabled: Some(true),
                     name: "test".to_owned(),
                     premium_type: Some(PremiumType::Nitro),
+                    primary_guild: None,
                     public_flags: Some(
                         UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER,
                     ),
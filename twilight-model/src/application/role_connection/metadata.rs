@@ -0,0 +1,92 @@
+use super::ApplicationRoleConnectionMetadataType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Metadata record of an application, which guilds use to configure the
+/// requirements of linked roles.
+///
+/// An application may have at most 5 metadata records.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationRoleConnectionMetadata {
+    /// Description of the metadata field, between 1 and 200 characters.
+    pub description: String,
+    /// Localization dictionary for the `description` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+    /// Dictionary key of the metadata field.
+    ///
+    /// Must be between 1 and 50 characters consisting of lowercase letters,
+    /// digits and underscores.
+    pub key: String,
+    /// Type of the metadata value.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// Name of the metadata field, between 1 and 100 characters.
+    pub name: String,
+    /// Localization dictionary for the `name` field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApplicationRoleConnectionMetadata, ApplicationRoleConnectionMetadataType};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, fmt::Debug};
+
+    assert_fields!(
+        ApplicationRoleConnectionMetadata: description,
+        description_localizations,
+        key,
+        kind,
+        name,
+        name_localizations
+    );
+    assert_impl_all!(
+        ApplicationRoleConnectionMetadata: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn metadata() {
+        let value = ApplicationRoleConnectionMetadata {
+            description: "days since joining".to_owned(),
+            description_localizations: None,
+            key: "joined_at".to_owned(),
+            kind: ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual,
+            name: "Joined".to_owned(),
+            name_localizations: Some(HashMap::from([("fr".to_owned(), "Rejoint".to_owned())])),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnectionMetadata",
+                    len: 5,
+                },
+                Token::Str("description"),
+                Token::Str("days since joining"),
+                Token::Str("key"),
+                Token::Str("joined_at"),
+                Token::Str("type"),
+                Token::U8(6),
+                Token::Str("name"),
+                Token::Str("Joined"),
+                Token::Str("name_localizations"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str("fr"),
+                Token::Str("Rejoint"),
+                Token::MapEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+/// Comparison performed between a user's metadata value and a guild's
+/// configured value.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ApplicationRoleConnectionMetadataType {
+    /// Metadata value is less than or equal to the guild's configured value.
+    IntegerLessThanOrEqual,
+    /// Metadata value is greater than or equal to the guild's configured
+    /// value.
+    IntegerGreaterThanOrEqual,
+    /// Metadata value is equal to the guild's configured value.
+    IntegerEqual,
+    /// Metadata value is not equal to the guild's configured value.
+    IntegerNotEqual,
+    /// Metadata value, an ISO8601 string, is less than or equal to the
+    /// guild's configured value, in days before the current date.
+    DatetimeLessThanOrEqual,
+    /// Metadata value, an ISO8601 string, is greater than or equal to the
+    /// guild's configured value, in days before the current date.
+    DatetimeGreaterThanOrEqual,
+    /// Metadata value is equal to the guild's configured value, which is `1`.
+    BooleanEqual,
+    /// Metadata value is not equal to the guild's configured value, which is
+    /// `1`.
+    BooleanNotEqual,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for ApplicationRoleConnectionMetadataType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::IntegerLessThanOrEqual,
+            2 => Self::IntegerGreaterThanOrEqual,
+            3 => Self::IntegerEqual,
+            4 => Self::IntegerNotEqual,
+            5 => Self::DatetimeLessThanOrEqual,
+            6 => Self::DatetimeGreaterThanOrEqual,
+            7 => Self::BooleanEqual,
+            8 => Self::BooleanNotEqual,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ApplicationRoleConnectionMetadataType> for u8 {
+    fn from(value: ApplicationRoleConnectionMetadataType) -> Self {
+        match value {
+            ApplicationRoleConnectionMetadataType::IntegerLessThanOrEqual => 1,
+            ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual => 2,
+            ApplicationRoleConnectionMetadataType::IntegerEqual => 3,
+            ApplicationRoleConnectionMetadataType::IntegerNotEqual => 4,
+            ApplicationRoleConnectionMetadataType::DatetimeLessThanOrEqual => 5,
+            ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual => 6,
+            ApplicationRoleConnectionMetadataType::BooleanEqual => 7,
+            ApplicationRoleConnectionMetadataType::BooleanNotEqual => 8,
+            ApplicationRoleConnectionMetadataType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnectionMetadataType;
+    use serde_test::Token;
+
+    const MAP: &[(ApplicationRoleConnectionMetadataType, u8)] = &[
+        (
+            ApplicationRoleConnectionMetadataType::IntegerLessThanOrEqual,
+            1,
+        ),
+        (
+            ApplicationRoleConnectionMetadataType::IntegerGreaterThanOrEqual,
+            2,
+        ),
+        (ApplicationRoleConnectionMetadataType::IntegerEqual, 3),
+        (ApplicationRoleConnectionMetadataType::IntegerNotEqual, 4),
+        (
+            ApplicationRoleConnectionMetadataType::DatetimeLessThanOrEqual,
+            5,
+        ),
+        (
+            ApplicationRoleConnectionMetadataType::DatetimeGreaterThanOrEqual,
+            6,
+        ),
+        (ApplicationRoleConnectionMetadataType::BooleanEqual, 7),
+        (ApplicationRoleConnectionMetadataType::BooleanNotEqual, 8),
+        (ApplicationRoleConnectionMetadataType::Unknown(99), 99),
+    ];
+
+    #[test]
+    fn variants() {
+        for (kind, num) in MAP {
+            serde_test::assert_tokens(kind, &[Token::U8(*num)]);
+            assert_eq!(*kind, ApplicationRoleConnectionMetadataType::from(*num));
+            assert_eq!(*num, u8::from(*kind));
+        }
+    }
+}
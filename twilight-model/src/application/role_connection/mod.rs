@@ -0,0 +1,16 @@
+//! Linked roles, which require users to have connected an application's
+//! account and meet the application's metadata criteria.
+//!
+//! See [Discord Docs/Application Role Connection Metadata].
+//!
+//! [Discord Docs/Application Role Connection Metadata]: https://discord.com/developers/docs/resources/application-role-connection-metadata
+
+mod metadata;
+mod metadata_type;
+mod user_connection;
+
+pub use self::{
+    metadata::ApplicationRoleConnectionMetadata,
+    metadata_type::ApplicationRoleConnectionMetadataType,
+    user_connection::ApplicationRoleConnection,
+};
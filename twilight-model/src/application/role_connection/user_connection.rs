@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Role connection of a user to an application.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationRoleConnection {
+    /// Values of the user's metadata, keyed by the [metadata record] keys.
+    ///
+    /// Values are stringified, such as `"1"` for booleans.
+    ///
+    /// [metadata record]: super::ApplicationRoleConnectionMetadata::key
+    pub metadata: HashMap<String, String>,
+    /// Vanity name of the platform the application has connected, up to 50
+    /// characters.
+    pub platform_name: Option<String>,
+    /// Username on the platform the application has connected, up to 100
+    /// characters.
+    pub platform_username: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnection;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashMap, fmt::Debug};
+
+    assert_fields!(
+        ApplicationRoleConnection: metadata,
+        platform_name,
+        platform_username
+    );
+    assert_impl_all!(
+        ApplicationRoleConnection: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn connection() {
+        let value = ApplicationRoleConnection {
+            metadata: HashMap::from([("verified".to_owned(), "1".to_owned())]),
+            platform_name: Some("Twily Platform".to_owned()),
+            platform_username: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnection",
+                    len: 3,
+                },
+                Token::Str("metadata"),
+                Token::Map { len: Some(1) },
+                Token::Str("verified"),
+                Token::Str("1"),
+                Token::MapEnd,
+                Token::Str("platform_name"),
+                Token::Some,
+                Token::Str("Twily Platform"),
+                Token::Str("platform_username"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
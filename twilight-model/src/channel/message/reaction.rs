@@ -55,6 +55,46 @@ pub enum EmojiReactionType {
     },
 }
 
+impl EmojiReactionType {
+    /// Whether the emoji is the same emoji as another.
+    ///
+    /// Custom emojis are matched by their IDs, ignoring their names since they
+    /// may be renamed or unavailable. Unicode emojis are matched ignoring
+    /// [variation selector-16] characters (U+FE0F), which Discord may or may
+    /// not include for the same emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::channel::message::EmojiReactionType;
+    ///
+    /// let with_selector = EmojiReactionType::Unicode {
+    ///     name: "\u{2764}\u{fe0f}".to_owned(),
+    /// };
+    /// let without_selector = EmojiReactionType::Unicode {
+    ///     name: "\u{2764}".to_owned(),
+    /// };
+    ///
+    /// assert!(with_selector.matches(&without_selector));
+    /// ```
+    ///
+    /// [variation selector-16]: https://unicode.org/reports/tr51/#def_emoji_presentation_selector
+    pub fn matches(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom { id, .. }, Self::Custom { id: other_id, .. }) => id == other_id,
+            (Self::Unicode { name }, Self::Unicode { name: other_name }) => {
+                /// Emoji presentation selector.
+                const VARIATION_SELECTOR_16: char = '\u{fe0f}';
+
+                name.chars()
+                    .filter(|c| *c != VARIATION_SELECTOR_16)
+                    .eq(other_name.chars().filter(|c| *c != VARIATION_SELECTOR_16))
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Breakdown of normal and super reaction counts for the associated emoji.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct ReactionCountDetails {
@@ -70,6 +110,38 @@ mod tests {
     use crate::{id::Id, util::HexColor};
     use serde_test::Token;
 
+    #[test]
+    fn matches_custom() {
+        let custom = |id, name: Option<&str>| EmojiReactionType::Custom {
+            animated: false,
+            id: Id::new(id),
+            name: name.map(ToOwned::to_owned),
+        };
+
+        assert!(custom(1, Some("foo")).matches(&custom(1, Some("bar"))));
+        assert!(custom(1, Some("foo")).matches(&custom(1, None)));
+        assert!(!custom(1, Some("foo")).matches(&custom(2, Some("foo"))));
+        assert!(
+            !custom(1, Some("foo")).matches(&EmojiReactionType::Unicode {
+                name: "foo".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn matches_unicode() {
+        let unicode = |name: &str| EmojiReactionType::Unicode {
+            name: name.to_owned(),
+        };
+
+        assert!(unicode("\u{2764}\u{fe0f}").matches(&unicode("\u{2764}")));
+        assert!(unicode("\u{2764}").matches(&unicode("\u{2764}\u{fe0f}")));
+        // Rainbow flag with and without its variation selector.
+        assert!(unicode("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}")
+            .matches(&unicode("\u{1f3f3}\u{200d}\u{1f308}")));
+        assert!(!unicode("\u{2764}\u{fe0f}").matches(&unicode("\u{1f499}\u{fe0f}")));
+    }
+
     #[test]
     fn message_reaction_unicode() {
         let value = Reaction {
@@ -126,6 +198,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn message_reaction_burst() {
+        let value = Reaction {
+            burst_colors: Vec::from([HexColor(242, 0, 255), HexColor(0, 0, 0)]),
+            count: 3,
+            count_details: ReactionCountDetails {
+                burst: 2,
+                normal: 1,
+            },
+            emoji: EmojiReactionType::Custom {
+                animated: false,
+                id: Id::new(1337),
+                name: Some("foo".to_owned()),
+            },
+            me: false,
+            me_burst: true,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Reaction",
+                    len: 6,
+                },
+                Token::Str("burst_colors"),
+                Token::Seq { len: Some(2) },
+                Token::Str("#F200FF"),
+                Token::Str("#000000"),
+                Token::SeqEnd,
+                Token::Str("count"),
+                Token::U64(3),
+                Token::Str("count_details"),
+                Token::Struct {
+                    name: "ReactionCountDetails",
+                    len: 2,
+                },
+                Token::Str("burst"),
+                Token::U64(2),
+                Token::Str("normal"),
+                Token::U64(1),
+                Token::StructEnd,
+                Token::Str("emoji"),
+                Token::Struct {
+                    name: "EmojiReactionType",
+                    len: 3,
+                },
+                Token::Str("animated"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1337"),
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("foo"),
+                Token::StructEnd,
+                Token::Str("me"),
+                Token::Bool(false),
+                Token::Str("me_burst"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn custom() {
         let value = EmojiReactionType::Custom {
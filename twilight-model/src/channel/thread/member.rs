@@ -16,6 +16,10 @@ pub struct ThreadMember {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Id<ChannelMarker>>,
     pub join_timestamp: Timestamp,
+    /// Guild member of the thread member's user.
+    ///
+    /// Only present when requested, such as when listing the members of a
+    /// thread with members included.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<Member>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,7 +19,12 @@ pub struct Webhook {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
     pub avatar: Option<ImageHash>,
-    pub channel_id: Id<ChannelMarker>,
+    /// ID of the channel the webhook is for, if any.
+    ///
+    /// [Application] webhooks aren't bound to a channel.
+    ///
+    /// [Application]: WebhookType::Application
+    pub channel_id: Option<Id<ChannelMarker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<WebhookMarker>,
@@ -65,6 +70,7 @@ mod tests {
         source_channel,
         source_guild,
         token,
+        url,
         user
     );
 
@@ -83,7 +89,7 @@ mod tests {
         let value = Webhook {
             application_id: Some(Id::new(4)),
             avatar: Some(image_hash::AVATAR),
-            channel_id: Id::new(1),
+            channel_id: Some(Id::new(1)),
             guild_id: Some(Id::new(2)),
             id: Id::new(3),
             kind: WebhookType::Incoming,
@@ -110,6 +116,7 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::AVATAR_INPUT),
                 Token::Str("channel_id"),
+                Token::Some,
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("guild_id"),
@@ -138,7 +145,7 @@ mod tests {
         let value = Webhook {
             application_id: Some(Id::new(4)),
             avatar: Some(image_hash::AVATAR),
-            channel_id: Id::new(1),
+            channel_id: Some(Id::new(1)),
             guild_id: Some(Id::new(2)),
             id: Id::new(3),
             kind: WebhookType::Incoming,
@@ -170,6 +177,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "test".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -191,6 +199,7 @@ mod tests {
                 Token::Some,
                 Token::Str(image_hash::AVATAR_INPUT),
                 Token::Str("channel_id"),
+                Token::Some,
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
                 Token::Str("guild_id"),
@@ -271,4 +280,131 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn webhook_channel_follower() {
+        let value = Webhook {
+            application_id: None,
+            avatar: None,
+            channel_id: Some(Id::new(1)),
+            guild_id: Some(Id::new(2)),
+            id: Id::new(3),
+            kind: WebhookType::ChannelFollower,
+            name: Some("announcements".to_owned()),
+            source_channel: Some(WebhookChannel {
+                id: Id::new(4),
+                name: "news".into(),
+            }),
+            source_guild: Some(WebhookGuild {
+                icon: None,
+                id: Id::new(5),
+                name: "source guild".into(),
+            }),
+            token: None,
+            url: None,
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Webhook",
+                    len: 8,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("announcements"),
+                Token::Str("source_channel"),
+                Token::Some,
+                Token::Struct {
+                    name: "WebhookChannel",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("4"),
+                Token::Str("name"),
+                Token::Str("news"),
+                Token::StructEnd,
+                Token::Str("source_guild"),
+                Token::Some,
+                Token::Struct {
+                    name: "WebhookGuild",
+                    len: 3,
+                },
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::Str("name"),
+                Token::Str("source guild"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test that application webhooks, which aren't bound to a channel, are
+    /// deserialized.
+    #[test]
+    fn webhook_application() {
+        let value = Webhook {
+            application_id: Some(Id::new(1)),
+            avatar: None,
+            channel_id: None,
+            guild_id: None,
+            id: Id::new(1),
+            kind: WebhookType::Application,
+            name: Some("app".to_owned()),
+            source_channel: None,
+            source_guild: None,
+            token: None,
+            url: None,
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Webhook",
+                    len: 6,
+                },
+                Token::Str("application_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("name"),
+                Token::Some,
+                Token::Str("app"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -103,6 +103,18 @@ impl CloseCode {
                 | Self::SessionTimedOut
         )
     }
+
+    /// Whether the close code is fatal, meaning reconnecting with the same
+    /// configuration will fail again.
+    ///
+    /// This is the inverse of [`can_reconnect`]. Fatal close codes typically
+    /// require human intervention, such as replacing the token or enabling
+    /// privileged intents.
+    ///
+    /// [`can_reconnect`]: Self::can_reconnect
+    pub const fn is_fatal(self) -> bool {
+        !self.can_reconnect()
+    }
 }
 
 impl Display for CloseCode {
@@ -222,7 +234,8 @@ mod tests {
             serde_test::assert_tokens(kind, &[Token::U16(*num)]);
             assert_eq!(*kind, CloseCode::try_from(*num).unwrap());
             assert_eq!(*num, *kind as u16);
-            assert!(kind.can_reconnect() == *can_reconnect)
+            assert!(kind.can_reconnect() == *can_reconnect);
+            assert!(kind.is_fatal() != *can_reconnect);
         }
     }
 
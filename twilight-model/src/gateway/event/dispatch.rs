@@ -1,4 +1,5 @@
 use super::{super::payload::incoming::*, Event, EventConversionError, EventType};
+use crate::application::interaction::PartialInteraction;
 use serde::{
     de::{Deserialize, DeserializeSeed, Deserializer, Error as DeError, IgnoredAny},
     Serialize,
@@ -44,6 +45,9 @@ pub enum DispatchEvent {
     IntegrationDelete(IntegrationDelete),
     IntegrationUpdate(Box<IntegrationUpdate>),
     InteractionCreate(Box<InteractionCreate>),
+    /// Only produced as a fallback by the gateway, never by
+    /// [`DispatchEventWithTypeDeserializer`].
+    InteractionCreatePartial(Box<PartialInteraction>),
     InviteCreate(Box<InviteCreate>),
     InviteDelete(InviteDelete),
     MemberAdd(Box<MemberAdd>),
@@ -116,7 +120,9 @@ impl DispatchEvent {
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
             Self::IntegrationDelete(_) => EventType::IntegrationDelete,
             Self::IntegrationUpdate(_) => EventType::IntegrationUpdate,
-            Self::InteractionCreate(_) => EventType::InteractionCreate,
+            Self::InteractionCreate(_) | Self::InteractionCreatePartial(_) => {
+                EventType::InteractionCreate
+            }
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
             Self::MemberAdd(_) => EventType::MemberAdd,
@@ -189,6 +195,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::IntegrationDelete(v) => Self::IntegrationDelete(v),
             Event::IntegrationUpdate(v) => Self::IntegrationUpdate(v),
             Event::InteractionCreate(v) => Self::InteractionCreate(v),
+            Event::InteractionCreatePartial(v) => Self::InteractionCreatePartial(v),
             Event::InviteCreate(v) => Self::InviteCreate(v),
             Event::InviteDelete(v) => Self::InviteDelete(v),
             Event::MemberAdd(v) => Self::MemberAdd(v),
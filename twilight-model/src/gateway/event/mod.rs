@@ -12,7 +12,10 @@ pub use self::{
 };
 
 use super::{payload::incoming::*, CloseFrame};
-use crate::id::{marker::GuildMarker, Id};
+use crate::{
+    application::interaction::PartialInteraction,
+    id::{marker::GuildMarker, Id},
+};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
@@ -103,6 +106,13 @@ pub enum Event {
     IntegrationUpdate(Box<IntegrationUpdate>),
     /// An interaction was invoked by a user.
     InteractionCreate(Box<InteractionCreate>),
+    /// An interaction was invoked by a user, but only its envelope could be
+    /// deserialized.
+    ///
+    /// This is only emitted by the gateway as a fallback when the full
+    /// [`InteractionCreate`] could not be deserialized, allowing the
+    /// interaction to still be responded to.
+    InteractionCreatePartial(Box<PartialInteraction>),
     /// A invite was made.
     InviteCreate(Box<InviteCreate>),
     /// A invite was deleted.
@@ -212,6 +222,7 @@ impl Event {
             Event::IntegrationDelete(e) => Some(e.guild_id),
             Event::IntegrationUpdate(e) => e.0.guild_id,
             Event::InteractionCreate(e) => e.0.guild_id,
+            Event::InteractionCreatePartial(e) => e.guild_id,
             Event::InviteCreate(e) => Some(e.guild_id),
             Event::InviteDelete(e) => Some(e.guild_id),
             Event::MemberAdd(e) => Some(e.guild_id),
@@ -298,7 +309,9 @@ impl Event {
             Self::IntegrationCreate(_) => EventType::IntegrationCreate,
             Self::IntegrationDelete(_) => EventType::IntegrationDelete,
             Self::IntegrationUpdate(_) => EventType::IntegrationUpdate,
-            Self::InteractionCreate(_) => EventType::InteractionCreate,
+            Self::InteractionCreate(_) | Self::InteractionCreatePartial(_) => {
+                EventType::InteractionCreate
+            }
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
             Self::MemberAdd(_) => EventType::MemberAdd,
@@ -377,6 +390,7 @@ impl From<DispatchEvent> for Event {
             DispatchEvent::IntegrationDelete(v) => Self::IntegrationDelete(v),
             DispatchEvent::IntegrationUpdate(v) => Self::IntegrationUpdate(v),
             DispatchEvent::InteractionCreate(v) => Self::InteractionCreate(v),
+            DispatchEvent::InteractionCreatePartial(v) => Self::InteractionCreatePartial(v),
             DispatchEvent::InviteCreate(v) => Self::InviteCreate(v),
             DispatchEvent::InviteDelete(v) => Self::InviteDelete(v),
             DispatchEvent::MemberAdd(v) => Self::MemberAdd(v),
@@ -486,7 +500,7 @@ mod tests {
     // requires a variable to be used in a function, so this is a false
     // positive.
     #[allow(dead_code)]
-    const EVENT_THRESHOLD: usize = 256;
+    const EVENT_THRESHOLD: usize = 240;
 
     const_assert!(mem::size_of::<Event>() == EVENT_THRESHOLD);
 
@@ -3,10 +3,15 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     num::NonZeroU32,
+    ops::Range,
+    str::FromStr,
 };
 
+/// Parsing a [`ShardId`] failed.
+#[derive(Debug)]
 pub struct ShardIdParseError {
     kind: ShardIdParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
 }
 
 impl ShardIdParseError {
@@ -17,22 +22,24 @@ impl ShardIdParseError {
     }
 
     /// Consume the error, returning the source error if there is any.
-    #[allow(clippy::unused_self)]
     #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
     pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
-        None
+        self.source
     }
 
     /// Consume the error, returning the owned error type and the source error.
     #[must_use = "consuming the error into its parts has no effect if left unused"]
     pub fn into_parts(self) -> (ShardIdParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
-        (self.kind, None)
+        (self.kind, self.source)
     }
 }
 
 impl Display for ShardIdParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self.kind {
+            ShardIdParseErrorType::Format => {
+                f.write_str("ShardId isn't in the `number/total` format")
+            }
             ShardIdParseErrorType::NumberGreaterOrEqualTotal { number, total } => {
                 f.write_str("ShardId's number (")?;
                 Display::fmt(&number, f)?;
@@ -41,13 +48,26 @@ impl Display for ShardIdParseError {
 
                 f.write_str(")")
             }
+            ShardIdParseErrorType::Parsing => {
+                f.write_str("ShardId's number or total isn't a valid integer")
+            }
         }
     }
 }
 
+impl Error for ShardIdParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
 /// Type of [`ShardIdParseError`] that occurred.
 #[derive(Debug)]
 pub enum ShardIdParseErrorType {
+    /// String isn't in the `number/total` format.
+    Format,
     /// `ShardId`'s number was greater or equal to its total.
     NumberGreaterOrEqualTotal {
         /// Value of number.
@@ -55,6 +75,10 @@ pub enum ShardIdParseErrorType {
         /// Value of total.
         total: u32,
     },
+    /// `ShardId`'s number or total isn't a valid integer.
+    ///
+    /// The source error is the integer parsing error.
+    Parsing,
 }
 
 /// Shard identifier to calculate if it receivies a given event.
@@ -150,6 +174,47 @@ impl ShardId {
         }
     }
 
+    /// Range of shard numbers a cluster should run when splitting `total`
+    /// shards evenly across `clusters` clusters.
+    ///
+    /// Clusters are 0-indexed. When the shards can't be divided evenly, the
+    /// first clusters each run one additional shard. Clusters receive an empty
+    /// range if there are more clusters than shards.
+    ///
+    /// # Examples
+    ///
+    /// Split 10 shards across 3 clusters:
+    ///
+    /// ```
+    /// use twilight_model::gateway::ShardId;
+    ///
+    /// assert_eq!(0..4, ShardId::chunk(0, 3, 10));
+    /// assert_eq!(4..7, ShardId::chunk(1, 3, 10));
+    /// assert_eq!(7..10, ShardId::chunk(2, 3, 10));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cluster index is greater than or equal to the number of
+    /// clusters.
+    pub const fn chunk(cluster_index: u32, clusters: u32, total: u32) -> Range<u32> {
+        assert!(
+            cluster_index < clusters,
+            "cluster index must be less than clusters"
+        );
+
+        let size = total / clusters;
+        let remainder = total % clusters;
+
+        let (start, size) = if cluster_index < remainder {
+            (cluster_index * (size + 1), size + 1)
+        } else {
+            (cluster_index * size + remainder, size)
+        };
+
+        start..start + size
+    }
+
     /// Identifying number of the shard, 0-indexed.
     pub const fn number(self) -> u32 {
         self.number
@@ -163,12 +228,34 @@ impl ShardId {
 
 /// Display the shard ID.
 ///
-/// Formats as `[{number}, {total}]`.
+/// Formats as `{number}/{total}`.
 impl Display for ShardId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_list()
-            .entries(Into::<[u32; 2]>::into(*self))
-            .finish()
+        Display::fmt(&self.number, f)?;
+        f.write_str("/")?;
+
+        Display::fmt(&self.total, f)
+    }
+}
+
+/// Parse a shard ID in the `{number}/{total}` form, such as `17/64`.
+impl FromStr for ShardId {
+    type Err = ShardIdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, total) = s.split_once('/').ok_or(ShardIdParseError {
+            kind: ShardIdParseErrorType::Format,
+            source: None,
+        })?;
+
+        let parse = |value: &str| {
+            value.parse().map_err(|source| ShardIdParseError {
+                kind: ShardIdParseErrorType::Parsing,
+                source: Some(Box::new(source)),
+            })
+        };
+
+        Self::try_from([parse(number)?, parse(total)?])
     }
 }
 
@@ -178,6 +265,7 @@ impl TryFrom<[u32; 2]> for ShardId {
     fn try_from([number, total]: [u32; 2]) -> Result<Self, Self::Error> {
         Self::new_checked(number, total).ok_or(ShardIdParseError {
             kind: ShardIdParseErrorType::NumberGreaterOrEqualTotal { number, total },
+            source: None,
         })
     }
 }
@@ -188,13 +276,83 @@ impl From<ShardId> for [u32; 2] {
     }
 }
 
+/// Serialize and deserialize a [`ShardId`] in the `{number}/{total}` string
+/// form.
+///
+/// Deserialization also accepts the default `[number, total]` array form.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use twilight_model::gateway::ShardId;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct WorkerConfig {
+///     #[serde(with = "twilight_model::gateway::shard_id_string")]
+///     shard: ShardId,
+/// }
+/// ```
+pub mod shard_id_string {
+    use super::ShardId;
+    use serde::{
+        de::{Error as DeError, SeqAccess, Visitor},
+        Deserializer, Serializer,
+    };
+    use std::fmt::{Formatter, Result as FmtResult};
+
+    struct ShardIdVisitor;
+
+    impl<'de> Visitor<'de> for ShardIdVisitor {
+        type Value = ShardId;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("a shard id string or array")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let number = seq
+                .next_element()?
+                .ok_or_else(|| DeError::invalid_length(0, &self))?;
+            let total = seq
+                .next_element()?
+                .ok_or_else(|| DeError::invalid_length(1, &self))?;
+
+            ShardId::try_from([number, total]).map_err(DeError::custom)
+        }
+
+        fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(DeError::custom)
+        }
+    }
+
+    /// Deserialize a shard ID from either its string or array form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input isn't a valid shard ID.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ShardId, D::Error> {
+        deserializer.deserialize_any(ShardIdVisitor)
+    }
+
+    /// Serialize a shard ID in its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(value: &ShardId, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ShardId;
-    use serde::{de::DeserializeOwned, Serialize};
+    use super::{ShardId, ShardIdParseError, ShardIdParseErrorType};
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_impl_all, const_assert_eq};
-    use std::{fmt::Debug, hash::Hash};
+    use std::{error::Error, fmt::Debug, hash::Hash, str::FromStr};
 
     const_assert_eq!(ShardId::ONE.number(), 0);
     const_assert_eq!(ShardId::ONE.total(), 1);
@@ -210,6 +368,7 @@ mod tests {
         Serialize,
         Sync
     );
+    assert_impl_all!(ShardIdParseError: Debug, Error, Send, Sync);
 
     #[test]
     const fn checked_invalid() {
@@ -242,6 +401,119 @@ mod tests {
         )
     }
 
+    #[test]
+    fn serde_string() {
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Wrapper(#[serde(with = "super::shard_id_string")] ShardId);
+
+        let value = Wrapper(ShardId::new(17, 64));
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Str("17/64"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Seq { len: Some(2) },
+                Token::U32(17),
+                Token::U32(64),
+                Token::SeqEnd,
+            ],
+        );
+        serde_test::assert_de_tokens_error::<Wrapper>(
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Str("64/64"),
+            ],
+            "ShardId's number (64) was greater or equal to its total (64)",
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("0/1", ShardId::ONE.to_string());
+        assert_eq!("17/64", ShardId::new(17, 64).to_string());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(ShardId::new(17, 64), ShardId::from_str("17/64").unwrap());
+        assert_eq!(ShardId::ONE, "0/1".parse().unwrap());
+
+        for input in ["", "17", "17:64"] {
+            assert!(matches!(
+                ShardId::from_str(input).unwrap_err().kind(),
+                ShardIdParseErrorType::Format
+            ));
+        }
+
+        for input in ["/64", "17/", "a/64", "17/b", "-1/64", " 17/64", "17/64/1"] {
+            let error = ShardId::from_str(input).unwrap_err();
+            assert!(matches!(error.kind(), ShardIdParseErrorType::Parsing));
+            assert!(error.into_source().is_some());
+        }
+
+        assert!(matches!(
+            ShardId::from_str("64/64").unwrap_err().kind(),
+            ShardIdParseErrorType::NumberGreaterOrEqualTotal {
+                number: 64,
+                total: 64
+            }
+        ));
+        assert!(matches!(
+            ShardId::from_str("0/0").unwrap_err().kind(),
+            ShardIdParseErrorType::NumberGreaterOrEqualTotal {
+                number: 0,
+                total: 0
+            }
+        ));
+    }
+
+    #[test]
+    fn chunk() {
+        assert_eq!(0..64, ShardId::chunk(0, 1, 64));
+
+        assert_eq!(0..16, ShardId::chunk(0, 4, 64));
+        assert_eq!(48..64, ShardId::chunk(3, 4, 64));
+
+        let chunks = (0..3)
+            .map(|index| ShardId::chunk(index, 3, 10))
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from([0..4, 4..7, 7..10]), chunks);
+
+        let chunks = (0..4)
+            .map(|index| ShardId::chunk(index, 4, 2))
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from([0..1, 1..2, 2..2, 2..2]), chunks);
+
+        assert!(ShardId::chunk(0, 1, 0).is_empty());
+
+        for total in 0..50 {
+            for clusters in 1..10 {
+                let mut next = 0;
+
+                for index in 0..clusters {
+                    let range = ShardId::chunk(index, clusters, total);
+                    assert_eq!(next, range.start);
+                    next = range.end;
+                }
+
+                assert_eq!(total, next);
+            }
+        }
+    }
+
+    #[should_panic(expected = "cluster index must be less than clusters")]
+    #[test]
+    fn chunk_index_invalid() {
+        ShardId::chunk(3, 3, 10);
+    }
+
     #[should_panic(expected = "number must be less than total")]
     #[test]
     const fn number_equal() {
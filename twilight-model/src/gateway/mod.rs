@@ -14,7 +14,7 @@ mod session_start_limit;
 pub use self::{
     close_code::{CloseCode, CloseCodeConversionError},
     frame::CloseFrame,
-    id::{ShardId, ShardIdParseError, ShardIdParseErrorType},
+    id::{shard_id_string, ShardId, ShardIdParseError, ShardIdParseErrorType},
     intents::Intents,
     opcode::OpCode,
     reaction::GatewayReaction,
@@ -1,5 +1,5 @@
 use crate::{
-    guild::{Guild, UnavailableGuild},
+    guild::{GatewayGuild, UnavailableGuild},
     id::{marker::GuildMarker, Id},
 };
 use serde::{Deserialize, Serialize};
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 #[serde(untagged)]
 pub enum GuildCreate {
     Unavailable(UnavailableGuild),
-    Available(Guild),
+    Available(GatewayGuild),
 }
 
 impl GuildCreate {
@@ -25,11 +25,18 @@ impl GuildCreate {
 
 #[cfg(test)]
 mod tests {
-    use serde_test::Token;
-
-    use crate::{guild::UnavailableGuild, id::Id};
-
     use super::GuildCreate;
+    use crate::{
+        guild::{
+            AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, GatewayGuild,
+            MfaLevel, NSFWLevel, PremiumTier, SystemChannelFlags, UnavailableGuild,
+            VerificationLevel,
+        },
+        id::Id,
+        util::Timestamp,
+    };
+    use serde_test::Token;
+    use std::str::FromStr;
 
     #[test]
     fn unavailable_guild() {
@@ -56,4 +63,161 @@ mod tests {
             ],
         );
     }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn available_guild() {
+        let joined_at = Timestamp::from_str("2015-04-26T06:26:56.936000+00:00").unwrap();
+
+        let expected = GuildCreate::Available(GatewayGuild {
+            afk_channel_id: None,
+            afk_timeout: AfkTimeout::FIVE_MINUTES,
+            application_id: None,
+            approximate_member_count: None,
+            approximate_presence_count: None,
+            banner: None,
+            channels: Vec::new(),
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: None,
+            discovery_splash: None,
+            emojis: Vec::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: Vec::new(),
+            guild_scheduled_events: Vec::new(),
+            icon: None,
+            id: Id::new(1234),
+            joined_at: Some(joined_at),
+            large: false,
+            max_members: None,
+            max_presences: None,
+            max_stage_video_channel_users: None,
+            max_video_channel_users: None,
+            member_count: Some(2),
+            members: Vec::new(),
+            mfa_level: MfaLevel::None,
+            name: "guild".to_owned(),
+            nsfw_level: NSFWLevel::Default,
+            owner_id: Id::new(5),
+            owner: None,
+            permissions: None,
+            preferred_locale: "en-us".to_owned(),
+            premium_progress_bar_enabled: false,
+            premium_subscription_count: None,
+            premium_tier: PremiumTier::None,
+            presences: Vec::new(),
+            public_updates_channel_id: None,
+            roles: Vec::new(),
+            rules_channel_id: None,
+            safety_alerts_channel_id: None,
+            splash: None,
+            stage_instances: Vec::new(),
+            stickers: Vec::new(),
+            system_channel_flags: SystemChannelFlags::empty(),
+            system_channel_id: None,
+            threads: Vec::new(),
+            unavailable: Some(false),
+            vanity_url_code: None,
+            verification_level: VerificationLevel::None,
+            voice_states: Vec::new(),
+            widget_channel_id: None,
+            widget_enabled: None,
+        });
+
+        serde_test::assert_tokens(
+            &expected,
+            &[
+                Token::Struct {
+                    name: "GatewayGuild",
+                    len: 36,
+                },
+                Token::Str("afk_channel_id"),
+                Token::None,
+                Token::Str("afk_timeout"),
+                Token::NewtypeStruct { name: "AfkTimeout" },
+                Token::U16(300),
+                Token::Str("application_id"),
+                Token::None,
+                Token::Str("banner"),
+                Token::None,
+                Token::Str("channels"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("default_message_notifications"),
+                Token::U8(1),
+                Token::Str("description"),
+                Token::None,
+                Token::Str("discovery_splash"),
+                Token::None,
+                Token::Str("emojis"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("explicit_content_filter"),
+                Token::U8(0),
+                Token::Str("features"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1234"),
+                Token::Str("joined_at"),
+                Token::Some,
+                Token::Str("2015-04-26T06:26:56.936000+00:00"),
+                Token::Str("large"),
+                Token::Bool(false),
+                Token::Str("member_count"),
+                Token::Some,
+                Token::U64(2),
+                Token::Str("members"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("mfa_level"),
+                Token::U8(0),
+                Token::Str("name"),
+                Token::Str("guild"),
+                Token::Str("nsfw_level"),
+                Token::U8(0),
+                Token::Str("owner_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("5"),
+                Token::Str("preferred_locale"),
+                Token::Str("en-us"),
+                Token::Str("premium_progress_bar_enabled"),
+                Token::Bool(false),
+                Token::Str("premium_tier"),
+                Token::U8(0),
+                Token::Str("presences"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("public_updates_channel_id"),
+                Token::None,
+                Token::Str("roles"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("rules_channel_id"),
+                Token::None,
+                Token::Str("splash"),
+                Token::None,
+                Token::Str("system_channel_flags"),
+                Token::U64(0),
+                Token::Str("system_channel_id"),
+                Token::None,
+                Token::Str("threads"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("unavailable"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("vanity_url_code"),
+                Token::None,
+                Token::Str("verification_level"),
+                Token::U8(0),
+                Token::Str("voice_states"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -1,7 +1,7 @@
 //! Gateway event payload when an invite is created.
 
 use crate::{
-    guild::invite::TargetType,
+    guild::invite::{InviteApplication, TargetType},
     id::{
         marker::{ChannelMarker, GuildMarker, UserMarker},
         Id,
@@ -22,6 +22,9 @@ pub struct InviteCreate {
     pub code: String,
     /// When the invite was created.
     pub created_at: Timestamp,
+    /// When the invite expires, if it ever does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
     /// ID of the guild being invited to.
     pub guild_id: Id<GuildMarker>,
     /// Information about the user who created the invite.
@@ -33,8 +36,11 @@ pub struct InviteCreate {
     pub max_age: u64,
     /// Maximum number of uses before the invite expires.
     pub max_uses: u64,
-    /// Target of the invite.
+    /// Embedded application to open for this embedded application invite.
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_application: Option<InviteApplication>,
+    /// Target of the invite.
+    #[serde(alias = "target_type", skip_serializing_if = "Option::is_none")]
     pub target_user_type: Option<TargetType>,
     /// User whose stream to display for this voice channel stream invite.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,7 +91,12 @@ impl PartialUser {
 #[cfg(test)]
 mod tests {
     use super::{InviteCreate, PartialUser};
-    use crate::{id::Id, test::image_hash, util::Timestamp};
+    use crate::{
+        guild::invite::{InviteApplication, TargetType},
+        id::Id,
+        test::image_hash,
+        util::Timestamp,
+    };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
@@ -95,10 +106,12 @@ mod tests {
         InviteCreate: channel_id,
         code,
         created_at,
+        expires_at,
         guild_id,
         inviter,
         max_age,
         max_uses,
+        target_application,
         target_user_type,
         target_user,
         temporary,
@@ -136,10 +149,12 @@ mod tests {
             channel_id: Id::new(1),
             code: "a".repeat(7),
             created_at,
+            expires_at: None,
             guild_id: Id::new(2),
             inviter: None,
             max_age: 3600,
             max_uses: 5,
+            target_application: None,
             target_user_type: None,
             target_user: None,
             temporary: false,
@@ -176,6 +191,138 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invite_create_embedded_application() {
+        let created_at = Timestamp::from_secs(1_609_459_200).expect("non zero");
+        let expires_at = Timestamp::from_secs(1_609_462_800).expect("non zero");
+
+        let value = InviteCreate {
+            channel_id: Id::new(1),
+            code: "a".repeat(7),
+            created_at,
+            expires_at: Some(expires_at),
+            guild_id: Id::new(2),
+            inviter: None,
+            max_age: 3600,
+            max_uses: 5,
+            target_application: Some(InviteApplication {
+                description: "an activity".to_owned(),
+                icon: None,
+                id: Id::new(3),
+                name: "application name".to_owned(),
+            }),
+            target_user_type: Some(TargetType::EmbeddedApplication),
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InviteCreate",
+                    len: 11,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("code"),
+                Token::Str("aaaaaaa"),
+                Token::Str("created_at"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("expires_at"),
+                Token::Some,
+                Token::Str("2021-01-01T01:00:00.000000+00:00"),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("max_age"),
+                Token::U64(3600),
+                Token::Str("max_uses"),
+                Token::U64(5),
+                Token::Str("target_application"),
+                Token::Some,
+                Token::Struct {
+                    name: "InviteApplication",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::Str("an activity"),
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("application name"),
+                Token::StructEnd,
+                Token::Str("target_user_type"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("temporary"),
+                Token::Bool(false),
+                Token::Str("uses"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Discord sends the invite's target as `target_type`.
+    #[test]
+    fn invite_create_target_type() {
+        let created_at = Timestamp::from_secs(1_609_459_200).expect("non zero");
+
+        let value = InviteCreate {
+            channel_id: Id::new(1),
+            code: "a".repeat(7),
+            created_at,
+            expires_at: None,
+            guild_id: Id::new(2),
+            inviter: None,
+            max_age: 0,
+            max_uses: 0,
+            target_application: None,
+            target_user_type: Some(TargetType::Stream),
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        };
+
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InviteCreate",
+                    len: 9,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("code"),
+                Token::Str("aaaaaaa"),
+                Token::Str("created_at"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("max_age"),
+                Token::U64(0),
+                Token::Str("max_uses"),
+                Token::U64(0),
+                Token::Str("target_type"),
+                Token::Some,
+                Token::U8(1),
+                Token::Str("temporary"),
+                Token::Bool(false),
+                Token::Str("uses"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn partial_user() {
         let value = PartialUser {
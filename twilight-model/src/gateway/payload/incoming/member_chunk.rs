@@ -323,6 +323,7 @@ mod tests {
                         global_name: Some("test".to_owned()),
                         premium_type: None,
                         system: None,
+                        primary_guild: None,
                         public_flags: None,
                     },
                 },
@@ -355,6 +356,7 @@ mod tests {
                         global_name: Some("test".to_owned()),
                         premium_type: None,
                         system: None,
+                        primary_guild: None,
                         public_flags: None,
                     },
                 },
@@ -387,6 +389,7 @@ mod tests {
                         global_name: Some("test".to_owned()),
                         premium_type: None,
                         system: None,
+                        primary_guild: None,
                         public_flags: Some(UserFlags::VERIFIED_DEVELOPER),
                     },
                 },
@@ -419,6 +422,7 @@ mod tests {
                         global_name: Some("test".to_owned()),
                         premium_type: None,
                         system: None,
+                        primary_guild: None,
                         public_flags: None,
                     },
                 },
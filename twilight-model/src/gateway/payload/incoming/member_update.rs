@@ -68,6 +68,7 @@ mod tests {
                 avatar_decoration_data: None,
                 banner: None,
                 name: "Twilight Sparkle".to_string(),
+                primary_guild: None,
                 public_flags: None,
                 id: Id::new(424_242),
                 discriminator: 1234,
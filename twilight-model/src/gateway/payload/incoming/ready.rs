@@ -54,6 +54,7 @@ mod tests {
             user: CurrentUser {
                 accent_color: None,
                 avatar: None,
+                avatar_decoration_data: None,
                 banner: None,
                 bot: false,
                 discriminator: 1212,
@@ -64,6 +65,7 @@ mod tests {
                 mfa_enabled: false,
                 name: "bar".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 verified: None,
             },
@@ -25,6 +25,7 @@ mod tests {
             guild_id: Id::new(1),
             role: Role {
                 color: 0,
+                colors: None,
                 hoist: true,
                 icon: None,
                 id: Id::new(1),
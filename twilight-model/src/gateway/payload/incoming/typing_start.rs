@@ -69,6 +69,7 @@ mod tests {
                     flags: None,
                     premium_type: None,
                     system: None,
+                    primary_guild: None,
                     public_flags: None,
                 },
             }),
@@ -71,6 +71,7 @@ mod tests {
                     flags: None,
                     premium_type: None,
                     system: None,
+                    primary_guild: None,
                     public_flags: None,
                 },
             }),
@@ -222,6 +223,7 @@ mod tests {
                     flags: None,
                     premium_type: None,
                     system: None,
+                    primary_guild: None,
                     public_flags: None,
                 },
             }),
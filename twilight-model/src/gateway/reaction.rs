@@ -88,6 +88,7 @@ mod tests {
                     mfa_enabled: None,
                     name: "test".to_owned(),
                     premium_type: None,
+                    primary_guild: None,
                     public_flags: None,
                     system: None,
                     verified: None,
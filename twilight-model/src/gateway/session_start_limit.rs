@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
 
 /// Current gateway session utilization status.
 ///
@@ -20,10 +21,48 @@ pub struct SessionStartLimit {
     pub total: u32,
 }
 
+impl SessionStartLimit {
+    /// Whether the given number of sessions may be started before the limit
+    /// resets.
+    ///
+    /// # Examples
+    ///
+    /// Check whether all 16 shards of a bot may be restarted:
+    ///
+    /// ```
+    /// use twilight_model::gateway::SessionStartLimit;
+    ///
+    /// let limit = SessionStartLimit {
+    ///     max_concurrency: 1,
+    ///     remaining: 10,
+    ///     reset_after: 60_000,
+    ///     total: 1000,
+    /// };
+    ///
+    /// assert!(!limit.can_start(16));
+    /// ```
+    pub const fn can_start(&self, sessions: u32) -> bool {
+        sessions <= self.remaining
+    }
+
+    /// Time at which [`remaining`] resets back to [`total`].
+    ///
+    /// [`reset_after`] is relative to when the limit was retrieved, so
+    /// `retrieved_at` must be the time at which it was retrieved.
+    ///
+    /// [`remaining`]: Self::remaining
+    /// [`reset_after`]: Self::reset_after
+    /// [`total`]: Self::total
+    pub fn resets_at(&self, retrieved_at: SystemTime) -> SystemTime {
+        retrieved_at + Duration::from_millis(self.reset_after)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SessionStartLimit;
     use serde_test::Token;
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn connection_info() {
@@ -53,4 +92,31 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn can_start() {
+        let value: SessionStartLimit = serde_json::from_str(
+            r#"{"max_concurrency":1,"remaining":5,"reset_after":60000,"total":1000}"#,
+        )
+        .unwrap();
+
+        assert!(value.can_start(0));
+        assert!(value.can_start(5));
+        assert!(!value.can_start(6));
+    }
+
+    #[test]
+    fn resets_at() {
+        let value = SessionStartLimit {
+            max_concurrency: 1,
+            remaining: 5,
+            reset_after: 60_000,
+            total: 1000,
+        };
+
+        assert_eq!(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(60),
+            value.resets_at(SystemTime::UNIX_EPOCH)
+        );
+    }
 }
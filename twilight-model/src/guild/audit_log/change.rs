@@ -6,6 +6,7 @@ use crate::{
         stage_instance::PrivacyLevel, thread::AutoArchiveDuration,
     },
     guild::{
+        scheduled_event::{EntityType as ScheduledEventEntityType, Status as ScheduledEventStatus},
         DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, NSFWLevel, Permissions,
         VerificationLevel,
     },
@@ -18,6 +19,7 @@ use crate::{
     util::{ImageHash, Timestamp},
 };
 use serde::{Deserialize, Serialize};
+use serde_value::Value;
 
 /// Minimal amount of information about an affected [role].
 ///
@@ -262,10 +264,10 @@ pub enum AuditLogChange {
     EntityType {
         /// New entity type.
         #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
-        new: Option<u64>,
+        new: Option<ScheduledEventEntityType>,
         /// Previous state, if any.
         #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
-        old: Option<u64>,
+        old: Option<ScheduledEventEntityType>,
     },
     /// Behavior of the expiration of an integration.
     ExpireBehavior {
@@ -594,10 +596,10 @@ pub enum AuditLogChange {
     Status {
         /// New status.
         #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
-        new: Option<u64>,
+        new: Option<ScheduledEventStatus>,
         /// Previous state, if any.
         #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
-        old: Option<u64>,
+        old: Option<ScheduledEventStatus>,
     },
     /// ID of guild's system channel.
     SystemChannelId {
@@ -711,8 +713,20 @@ pub enum AuditLogChange {
         old: Option<bool>,
     },
     /// Other type of change not covered by other variants.
-    #[serde(other)]
-    Other,
+    ///
+    /// The key and values are retained so that changes Twilight does not yet
+    /// know about are not lost and serialize back to their original form.
+    #[serde(untagged)]
+    Other {
+        /// Key of the change.
+        key: String,
+        /// New value, if any.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Value>,
+        /// Previous value, if any.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Value>,
+    },
 }
 
 impl AuditLogChange {
@@ -818,7 +832,7 @@ impl AuditLogChange {
             Self::VerificationLevel { .. } => AuditLogChangeKey::VerificationLevel,
             Self::WidgetChannelId { .. } => AuditLogChangeKey::WidgetChannelId,
             Self::WidgetEnabled { .. } => AuditLogChangeKey::WidgetEnabled,
-            Self::Other => return None,
+            Self::Other { .. } => return None,
         })
     }
 }
@@ -826,9 +840,18 @@ impl AuditLogChange {
 #[cfg(test)]
 mod tests {
     use super::{super::AuditLogChangeKey, AffectedRole, AuditLogChange, AuditLogChangeTypeValue};
-    use crate::{channel::ChannelType, guild::Permissions, id::Id};
+    use crate::{
+        channel::ChannelType,
+        guild::{
+            scheduled_event::{EntityType, Status},
+            Permissions,
+        },
+        id::Id,
+        util::Timestamp,
+    };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
+    use serde_value::Value;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{fmt::Debug, hash::Hash};
 
@@ -851,6 +874,7 @@ mod tests {
     assert_fields!(AuditLogChange::Description: new, old);
     assert_fields!(AuditLogChange::DiscoverySplashHash: new, old);
     assert_fields!(AuditLogChange::EnableEmoticons: new, old);
+    assert_fields!(AuditLogChange::EntityType: new, old);
     assert_fields!(AuditLogChange::ExpireBehavior: new);
     assert_fields!(AuditLogChange::ExpireGracePeriod: new);
     assert_fields!(AuditLogChange::ExplicitContentFilter: new, old);
@@ -867,6 +891,7 @@ mod tests {
     assert_fields!(AuditLogChange::Mute: new, old);
     assert_fields!(AuditLogChange::Name: new, old);
     assert_fields!(AuditLogChange::Nick: new, old);
+    assert_fields!(AuditLogChange::Other: key, new, old);
     assert_fields!(AuditLogChange::NsfwLevel: new, old);
     assert_fields!(AuditLogChange::OwnerId: new, old);
     assert_fields!(AuditLogChange::Permissions: new, old);
@@ -880,6 +905,7 @@ mod tests {
     assert_fields!(AuditLogChange::RoleRemoved: new);
     assert_fields!(AuditLogChange::RulesChannelId: new, old);
     assert_fields!(AuditLogChange::SplashHash: new, old);
+    assert_fields!(AuditLogChange::Status: new, old);
     assert_fields!(AuditLogChange::SystemChannelId: new, old);
     assert_fields!(AuditLogChange::Temporary: new);
     assert_fields!(AuditLogChange::Topic: new);
@@ -1032,4 +1058,161 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn communication_disabled_until() {
+        let value = AuditLogChange::CommunicationDisabledUntil {
+            new: Some(Timestamp::from_secs(1_700_000_000).unwrap()),
+            old: None,
+        };
+
+        assert_eq!(
+            Some(AuditLogChangeKey::CommunicationDisabledUntil),
+            value.key()
+        );
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogChange",
+                    len: 2,
+                },
+                Token::String("key"),
+                Token::String("communication_disabled_until"),
+                Token::String("new_value"),
+                Token::Some,
+                Token::Str("2023-11-14T22:13:20.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_type() {
+        let value = AuditLogChange::EntityType {
+            new: Some(EntityType::Voice),
+            old: Some(EntityType::StageInstance),
+        };
+
+        assert_eq!(Some(AuditLogChangeKey::EntityType), value.key());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogChange",
+                    len: 3,
+                },
+                Token::String("key"),
+                Token::String("entity_type"),
+                Token::String("new_value"),
+                Token::Some,
+                Token::U8(2),
+                Token::String("old_value"),
+                Token::Some,
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn role_added() {
+        let value = AuditLogChange::RoleAdded {
+            new: Vec::from([AffectedRole {
+                id: Id::new(1),
+                name: "role".to_owned(),
+            }]),
+            old: Vec::new(),
+        };
+
+        assert_eq!(Some(AuditLogChangeKey::RoleAdded), value.key());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogChange",
+                    len: 2,
+                },
+                Token::String("key"),
+                Token::String("$add"),
+                Token::String("new_value"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "AffectedRole",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("role"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn status() {
+        let value = AuditLogChange::Status {
+            new: Some(Status::Completed),
+            old: Some(Status::Active),
+        };
+
+        assert_eq!(Some(AuditLogChangeKey::Status), value.key());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogChange",
+                    len: 3,
+                },
+                Token::String("key"),
+                Token::String("status"),
+                Token::String("new_value"),
+                Token::Some,
+                Token::U8(3),
+                Token::String("old_value"),
+                Token::Some,
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test that changes with unknown keys retain their key and values.
+    #[test]
+    fn other() {
+        let value = AuditLogChange::Other {
+            key: "unknown_key".to_owned(),
+            new: Some(Value::U64(2)),
+            old: Some(Value::Bool(true)),
+        };
+
+        assert!(value.key().is_none());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AuditLogChange",
+                    len: 3,
+                },
+                Token::String("key"),
+                Token::String("unknown_key"),
+                Token::String("new_value"),
+                Token::Some,
+                Token::U64(2),
+                Token::String("old_value"),
+                Token::Some,
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
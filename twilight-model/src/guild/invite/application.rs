@@ -0,0 +1,58 @@
+use crate::{
+    id::{marker::ApplicationMarker, Id},
+    util::ImageHash,
+};
+use serde::{Deserialize, Serialize};
+
+/// Embedded application opened by an [`EmbeddedApplication`] invite.
+///
+/// [`EmbeddedApplication`]: super::TargetType::EmbeddedApplication
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct InviteApplication {
+    /// Description of the application.
+    pub description: String,
+    /// Hash of the application's icon.
+    pub icon: Option<ImageHash>,
+    /// ID of the application.
+    pub id: Id<ApplicationMarker>,
+    /// Name of the application.
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InviteApplication;
+    use crate::{id::Id, test::image_hash};
+    use serde_test::Token;
+
+    #[test]
+    fn invite_application() {
+        let value = InviteApplication {
+            description: "an activity".to_owned(),
+            icon: Some(image_hash::ICON),
+            id: Id::new(1),
+            name: "application name".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InviteApplication",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::Str("an activity"),
+                Token::Str("icon"),
+                Token::Some,
+                Token::Str(image_hash::ICON_INPUT),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("application name"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
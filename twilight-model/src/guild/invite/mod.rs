@@ -1,3 +1,4 @@
+mod application;
 mod channel;
 mod guild;
 mod invite_type;
@@ -5,6 +6,7 @@ mod target_type;
 mod welcome_screen;
 
 pub use self::{
+    application::InviteApplication,
     channel::InviteChannel,
     guild::InviteGuild,
     invite_type::InviteType,
@@ -36,6 +38,8 @@ pub struct Invite {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_uses: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_application: Option<InviteApplication>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_type: Option<TargetType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_user: Option<User>,
@@ -50,8 +54,8 @@ pub struct Invite {
 #[cfg(test)]
 mod tests {
     use super::{
-        welcome_screen::WelcomeScreenChannel, Invite, InviteChannel, InviteGuild, InviteType,
-        TargetType, User, WelcomeScreen,
+        welcome_screen::WelcomeScreenChannel, Invite, InviteApplication, InviteChannel,
+        InviteGuild, InviteType, TargetType, User, WelcomeScreen,
     };
     use crate::{
         channel::ChannelType,
@@ -76,6 +80,7 @@ mod tests {
         inviter,
         max_age,
         max_uses,
+        target_application,
         target_type,
         target_user,
         temporary,
@@ -110,6 +115,7 @@ mod tests {
             inviter: None,
             max_age: None,
             max_uses: None,
+            target_application: None,
             target_type: Some(TargetType::Stream),
             target_user: None,
             temporary: None,
@@ -216,12 +222,14 @@ mod tests {
                 mfa_enabled: None,
                 name: "test".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
             }),
             max_age: Some(86_400),
             max_uses: Some(10),
+            target_application: None,
             target_type: Some(TargetType::Stream),
             target_user: Some(User {
                 accent_color: None,
@@ -239,6 +247,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "test".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -442,4 +451,81 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn invite_embedded_application() {
+        let value = Invite {
+            approximate_member_count: None,
+            approximate_presence_count: None,
+            channel: Some(InviteChannel {
+                id: Id::new(2),
+                kind: ChannelType::GuildVoice,
+                name: None,
+            }),
+            code: "uniquecode".to_owned(),
+            created_at: None,
+            expires_at: None,
+            guild: None,
+            inviter: None,
+            max_age: None,
+            max_uses: None,
+            target_application: Some(InviteApplication {
+                description: "an activity".to_owned(),
+                icon: None,
+                id: Id::new(3),
+                name: "application name".to_owned(),
+            }),
+            target_type: Some(TargetType::EmbeddedApplication),
+            target_user: None,
+            temporary: None,
+            kind: InviteType::Guild,
+            uses: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Invite",
+                    len: 5,
+                },
+                Token::Str("channel"),
+                Token::Some,
+                Token::Struct {
+                    name: "InviteChannel",
+                    len: 2,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::Str("code"),
+                Token::Str("uniquecode"),
+                Token::Str("target_application"),
+                Token::Some,
+                Token::Struct {
+                    name: "InviteApplication",
+                    len: 4,
+                },
+                Token::Str("description"),
+                Token::Str("an activity"),
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("application name"),
+                Token::StructEnd,
+                Token::Str("target_type"),
+                Token::Some,
+                Token::U8(2),
+                Token::Str("type"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
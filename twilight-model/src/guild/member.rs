@@ -36,6 +36,21 @@ pub struct Member {
     pub user: User,
 }
 
+impl Member {
+    /// Name of the member as displayed in the client.
+    ///
+    /// This is the member's [nickname] if set, otherwise the
+    /// [user's display name].
+    ///
+    /// [nickname]: Self::nick
+    /// [user's display name]: User::display_name
+    pub fn display_name(&self) -> &str {
+        self.nick
+            .as_deref()
+            .unwrap_or_else(|| self.user.display_name())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Member;
@@ -49,6 +64,50 @@ mod tests {
     use serde_test::Token;
     use std::str::FromStr;
 
+    fn member(nick: Option<&str>, global_name: Option<&str>) -> Member {
+        Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            flags: MemberFlags::empty(),
+            joined_at: None,
+            mute: false,
+            nick: nick.map(ToOwned::to_owned),
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user: User {
+                accent_color: None,
+                avatar: None,
+                avatar_decoration: None,
+                avatar_decoration_data: None,
+                banner: None,
+                bot: false,
+                discriminator: 0,
+                email: None,
+                flags: None,
+                global_name: global_name.map(ToOwned::to_owned),
+                id: Id::new(1),
+                locale: None,
+                mfa_enabled: None,
+                name: "name".to_owned(),
+                premium_type: None,
+                primary_guild: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+        }
+    }
+
+    #[test]
+    fn display_name() {
+        assert_eq!("nick", member(Some("nick"), Some("Global")).display_name());
+        assert_eq!("nick", member(Some("nick"), None).display_name());
+        assert_eq!("Global", member(None, Some("Global")).display_name());
+        assert_eq!("name", member(None, None).display_name());
+    }
+
     #[test]
     fn member_deserializer() -> Result<(), TimestampParseError> {
         let joined_at = Some(Timestamp::from_str("2015-04-26T06:26:56.936000+00:00")?);
@@ -82,6 +141,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "twilight".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -190,6 +250,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "twilight".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -32,17 +32,81 @@ pub struct PartialMember {
     pub user: Option<User>,
 }
 
+impl PartialMember {
+    /// Name of the member as displayed in the client.
+    ///
+    /// This is the member's [nickname] if set, otherwise the
+    /// [user's display name]. Returns [`None`] if neither is known, such as
+    /// when the member has no nickname and the user is not present.
+    ///
+    /// [nickname]: Self::nick
+    /// [user's display name]: User::display_name
+    pub fn display_name(&self) -> Option<&str> {
+        self.nick
+            .as_deref()
+            .or_else(|| self.user.as_ref().map(User::display_name))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PartialMember;
     use crate::{
         guild::MemberFlags,
         id::Id,
+        user::User,
         util::datetime::{Timestamp, TimestampParseError},
     };
     use serde_test::Token;
     use std::str::FromStr;
 
+    #[test]
+    fn display_name() {
+        let mut member = PartialMember {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            flags: MemberFlags::empty(),
+            joined_at: None,
+            mute: false,
+            nick: None,
+            permissions: None,
+            premium_since: None,
+            roles: Vec::new(),
+            user: None,
+        };
+        assert!(member.display_name().is_none());
+
+        member.user = Some(User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 0,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(1),
+            locale: None,
+            mfa_enabled: None,
+            name: "name".to_owned(),
+            premium_type: None,
+            primary_guild: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        });
+        assert_eq!(Some("name"), member.display_name());
+
+        member.user.as_mut().unwrap().global_name = Some("Global".to_owned());
+        assert_eq!(Some("Global"), member.display_name());
+
+        member.nick = Some("nick".to_owned());
+        assert_eq!(Some("nick"), member.display_name());
+    }
+
     #[test]
     fn partial_member() -> Result<(), TimestampParseError> {
         let joined_at = Some(Timestamp::from_str("2015-04-26T06:26:56.936000+00:00")?);
@@ -5,9 +5,53 @@ pub struct GuildPrune {
     pub pruned: u64,
 }
 
+/// Result of beginning a guild prune.
+///
+/// Discord only returns the number of pruned members if it was requested to
+/// compute it, which is discouraged for large guilds.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "GuildPruneResultRaw", into = "GuildPruneResultRaw")]
+pub enum GuildPruneResult {
+    /// Number of members that were pruned.
+    Count(u64),
+    /// Number of pruned members was not computed.
+    Suppressed,
+}
+
+impl GuildPruneResult {
+    /// Number of members that were pruned, if it was computed.
+    pub const fn count(self) -> Option<u64> {
+        match self {
+            Self::Count(count) => Some(count),
+            Self::Suppressed => None,
+        }
+    }
+}
+
+/// Wire representation of a [`GuildPruneResult`].
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "GuildPruneResult")]
+struct GuildPruneResultRaw {
+    pruned: Option<u64>,
+}
+
+impl From<GuildPruneResultRaw> for GuildPruneResult {
+    fn from(raw: GuildPruneResultRaw) -> Self {
+        raw.pruned.map_or(Self::Suppressed, Self::Count)
+    }
+}
+
+impl From<GuildPruneResult> for GuildPruneResultRaw {
+    fn from(result: GuildPruneResult) -> Self {
+        Self {
+            pruned: result.count(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GuildPrune;
+    use super::{GuildPrune, GuildPruneResult};
     use serde_test::Token;
 
     #[test]
@@ -27,4 +71,43 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn guild_prune_result_count() {
+        let result = GuildPruneResult::Count(31);
+        assert_eq!(Some(31), result.count());
+
+        serde_test::assert_tokens(
+            &result,
+            &[
+                Token::Struct {
+                    name: "GuildPruneResult",
+                    len: 1,
+                },
+                Token::Str("pruned"),
+                Token::Some,
+                Token::U64(31),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn guild_prune_result_suppressed() {
+        let result = GuildPruneResult::Suppressed;
+        assert!(result.count().is_none());
+
+        serde_test::assert_tokens(
+            &result,
+            &[
+                Token::Struct {
+                    name: "GuildPruneResult",
+                    len: 1,
+                },
+                Token::Str("pruned"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use super::{RoleFlags, RoleTags};
+use super::{RoleColors, RoleFlags, RoleTags};
 use crate::{
     guild::Permissions,
     id::{marker::RoleMarker, Id},
@@ -10,6 +10,13 @@ use std::cmp::Ordering;
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Role {
     pub color: u32,
+    /// Colors of the role.
+    ///
+    /// Unlike [`color`], may describe a gradient.
+    ///
+    /// [`color`]: Self::color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<RoleColors>,
     pub hoist: bool,
     /// Icon image hash.
     ///
@@ -63,6 +70,7 @@ impl Ord for Role {
     ///     id: Id::new(123),
     ///     position: 12,
     /// #   color: 0,
+    /// #   colors: None,
     /// #   hoist: true,
     /// #   icon: None,
     /// #   managed: false,
@@ -78,6 +86,7 @@ impl Ord for Role {
     ///     id: Id::new(456),
     ///     position: 13,
     /// #   color: 0,
+    /// #   colors: None,
     /// #   hoist: true,
     /// #   icon: None,
     /// #   managed: false,
@@ -104,6 +113,7 @@ impl Ord for Role {
     ///     id: Id::new(123),
     ///     position: 12,
     /// #   color: 0,
+    /// #   colors: None,
     /// #   hoist: true,
     /// #   icon: None,
     /// #   managed: false,
@@ -118,6 +128,7 @@ impl Ord for Role {
     ///     id: Id::new(456),
     ///     position: 12,
     /// #   color: 0,
+    /// #   colors: None,
     /// #   hoist: true,
     /// #   icon: None,
     /// #   managed: false,
@@ -148,7 +159,7 @@ impl PartialOrd for Role {
 
 #[cfg(test)]
 mod tests {
-    use super::{Permissions, Role};
+    use super::{Permissions, Role, RoleColors};
     use crate::{guild::RoleFlags, id::Id};
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
@@ -157,6 +168,7 @@ mod tests {
 
     assert_fields!(
         Role: color,
+        colors,
         hoist,
         icon,
         id,
@@ -183,6 +195,7 @@ mod tests {
     fn role() {
         let role = Role {
             color: 0,
+            colors: None,
             hoist: true,
             icon: None,
             id: Id::new(123),
@@ -226,4 +239,71 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn role_colors() {
+        let role = Role {
+            color: 0x00a9_c9ff,
+            colors: Some(RoleColors {
+                primary_color: 0x00a9_c9ff,
+                secondary_color: Some(0x00ff_bbec),
+                tertiary_color: None,
+            }),
+            hoist: false,
+            icon: None,
+            id: Id::new(123),
+            managed: false,
+            mentionable: false,
+            name: "gradient".to_owned(),
+            permissions: Permissions::empty(),
+            position: 1,
+            flags: RoleFlags::empty(),
+            tags: None,
+            unicode_emoji: None,
+        };
+
+        serde_test::assert_tokens(
+            &role,
+            &[
+                Token::Struct {
+                    name: "Role",
+                    len: 10,
+                },
+                Token::Str("color"),
+                Token::U32(0x00a9_c9ff),
+                Token::Str("colors"),
+                Token::Some,
+                Token::Struct {
+                    name: "RoleColors",
+                    len: 3,
+                },
+                Token::Str("primary_color"),
+                Token::U32(0x00a9_c9ff),
+                Token::Str("secondary_color"),
+                Token::Some,
+                Token::U32(0x00ff_bbec),
+                Token::Str("tertiary_color"),
+                Token::None,
+                Token::StructEnd,
+                Token::Str("hoist"),
+                Token::Bool(false),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("123"),
+                Token::Str("managed"),
+                Token::Bool(false),
+                Token::Str("mentionable"),
+                Token::Bool(false),
+                Token::Str("name"),
+                Token::Str("gradient"),
+                Token::Str("permissions"),
+                Token::Str("0"),
+                Token::Str("position"),
+                Token::I64(1),
+                Token::Str("flags"),
+                Token::U64(0),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
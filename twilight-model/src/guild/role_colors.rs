@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+/// Colors of a [`Role`].
+///
+/// Roles with only a primary color are displayed with a solid color, while a
+/// secondary color creates a gradient between the two.
+///
+/// [`Role`]: super::Role
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RoleColors {
+    /// Primary color of the role.
+    pub primary_color: u32,
+    /// Secondary color of the role, creating a gradient with the primary
+    /// color.
+    pub secondary_color: Option<u32>,
+    /// Tertiary color of the role, creating a holographic style.
+    ///
+    /// Only available to guilds with the `ENHANCED_ROLE_COLORS` feature.
+    pub tertiary_color: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoleColors;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(RoleColors: primary_color, secondary_color, tertiary_color);
+    assert_impl_all!(
+        RoleColors: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn solid() {
+        let colors = RoleColors {
+            primary_color: 0x00d9_0083,
+            secondary_color: None,
+            tertiary_color: None,
+        };
+
+        serde_test::assert_tokens(
+            &colors,
+            &[
+                Token::Struct {
+                    name: "RoleColors",
+                    len: 3,
+                },
+                Token::Str("primary_color"),
+                Token::U32(0x00d9_0083),
+                Token::Str("secondary_color"),
+                Token::None,
+                Token::Str("tertiary_color"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn gradient() {
+        let colors = RoleColors {
+            primary_color: 0x00a9_c9ff,
+            secondary_color: Some(0x00ff_bbec),
+            tertiary_color: Some(0x00ff_c3a0),
+        };
+
+        serde_test::assert_tokens(
+            &colors,
+            &[
+                Token::Struct {
+                    name: "RoleColors",
+                    len: 3,
+                },
+                Token::Str("primary_color"),
+                Token::U32(0x00a9_c9ff),
+                Token::Str("secondary_color"),
+                Token::Some,
+                Token::U32(0x00ff_bbec),
+                Token::Str("tertiary_color"),
+                Token::Some,
+                Token::U32(0x00ff_c3a0),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -1,8 +1,15 @@
 //! Types for interacting with scheduled events.
 
+mod recurrence_rule;
 mod user;
 
-pub use self::user::GuildScheduledEventUser;
+pub use self::{
+    recurrence_rule::{
+        GuildScheduledEventRecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth,
+        RecurrenceRuleNWeekday, RecurrenceRuleWeekday,
+    },
+    user::GuildScheduledEventUser,
+};
 
 use crate::{
     id::{
@@ -66,6 +73,9 @@ pub struct GuildScheduledEvent {
     pub name: String,
     /// Privacy level of the event.
     pub privacy_level: PrivacyLevel,
+    /// Rule defining how often the event recurs, if it does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence_rule: Option<GuildScheduledEventRecurrenceRule>,
     /// Scheduled end time of the event.
     ///
     /// Required on events of type [`EntityType::External`]. It also may be
@@ -223,6 +233,7 @@ mod tests {
             image: Some(COVER),
             name: "garfield dance party".into(),
             privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
             scheduled_end_time: None,
             scheduled_start_time,
             status: Status::Completed,
@@ -273,6 +284,80 @@ mod tests {
             ],
         );
 
+        Ok(())
+    }
+    #[test]
+    fn scheduled_event_recurring() -> Result<(), Box<dyn Error>> {
+        let payload = r#"{
+            "id": "4",
+            "guild_id": "3",
+            "channel_id": "1",
+            "creator_id": null,
+            "name": "weekday standup",
+            "description": null,
+            "scheduled_start_time": "2024-08-12T09:00:00+00:00",
+            "scheduled_end_time": null,
+            "privacy_level": 2,
+            "status": 1,
+            "entity_type": 2,
+            "entity_id": null,
+            "entity_metadata": null,
+            "user_count": 3,
+            "image": null,
+            "recurrence_rule": {
+                "start": "2024-08-12T09:00:00+00:00",
+                "end": null,
+                "frequency": 3,
+                "interval": 1,
+                "by_weekday": [0, 1, 2, 3, 4],
+                "by_n_weekday": null,
+                "by_month": null,
+                "by_month_day": null,
+                "by_year_day": null,
+                "count": null
+            }
+        }"#;
+
+        let scheduled_start_time = Timestamp::parse("2024-08-12T09:00:00+00:00")?;
+        let expected = GuildScheduledEvent {
+            channel_id: Some(Id::new(1)),
+            creator: None,
+            creator_id: None,
+            description: None,
+            entity_id: None,
+            entity_metadata: None,
+            entity_type: EntityType::Voice,
+            guild_id: Id::new(3),
+            id: Id::new(4),
+            image: None,
+            name: "weekday standup".into(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: Some(GuildScheduledEventRecurrenceRule {
+                by_month: None,
+                by_month_day: None,
+                by_n_weekday: None,
+                by_weekday: Some(Vec::from([
+                    RecurrenceRuleWeekday::Monday,
+                    RecurrenceRuleWeekday::Tuesday,
+                    RecurrenceRuleWeekday::Wednesday,
+                    RecurrenceRuleWeekday::Thursday,
+                    RecurrenceRuleWeekday::Friday,
+                ])),
+                by_year_day: None,
+                count: None,
+                end: None,
+                frequency: RecurrenceRuleFrequency::Daily,
+                interval: 1,
+                start: scheduled_start_time,
+            }),
+            scheduled_end_time: None,
+            scheduled_start_time,
+            status: Status::Scheduled,
+            user_count: Some(3),
+        };
+
+        assert_eq!(expected, serde_json::from_str(payload)?);
+
         Ok(())
     }
 }
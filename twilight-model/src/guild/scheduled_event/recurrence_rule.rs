@@ -0,0 +1,416 @@
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Rule defining how often a scheduled event recurs.
+///
+/// Discord supports a subset of the [iCalendar recurrence rule] behaviors, and
+/// restricts which fields may be combined depending on the [`frequency`].
+///
+/// [`frequency`]: Self::frequency
+/// [iCalendar recurrence rule]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEventRecurrenceRule {
+    /// Months to recur in.
+    ///
+    /// Only used alongside [`by_month_day`] by
+    /// [`RecurrenceRuleFrequency::Yearly`] rules.
+    ///
+    /// [`by_month_day`]: Self::by_month_day
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    /// Specific days of the month to recur on.
+    ///
+    /// Only used alongside [`by_month`] by [`RecurrenceRuleFrequency::Yearly`]
+    /// rules.
+    ///
+    /// [`by_month`]: Self::by_month
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific weekdays within a specific week to recur on.
+    ///
+    /// Only used by [`RecurrenceRuleFrequency::Monthly`] rules.
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    /// Days of the week to recur on.
+    ///
+    /// Only used by [`RecurrenceRuleFrequency::Daily`] and
+    /// [`RecurrenceRuleFrequency::Weekly`] rules.
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    /// Days of the year to recur on.
+    ///
+    /// Set by Discord and can't be set by users.
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total number of times the event is allowed to recur before stopping.
+    ///
+    /// Set by Discord and can't be set by users.
+    pub count: Option<u16>,
+    /// End time of the recurrence interval.
+    ///
+    /// Set by Discord and can't be set by users.
+    pub end: Option<Timestamp>,
+    /// How often the event occurs.
+    pub frequency: RecurrenceRuleFrequency,
+    /// Spacing between events, as a number of [`frequency`] units.
+    ///
+    /// For example, a [`RecurrenceRuleFrequency::Weekly`] rule with an
+    /// interval of 2 recurs every other week.
+    ///
+    /// [`frequency`]: Self::frequency
+    pub interval: u16,
+    /// Start time of the recurrence interval.
+    pub start: Timestamp,
+}
+
+/// How often a scheduled event occurs.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleFrequency {
+    /// Event recurs every year.
+    Yearly,
+    /// Event recurs every month.
+    Monthly,
+    /// Event recurs every week.
+    Weekly,
+    /// Event recurs every day.
+    Daily,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleFrequency {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => RecurrenceRuleFrequency::Yearly,
+            1 => RecurrenceRuleFrequency::Monthly,
+            2 => RecurrenceRuleFrequency::Weekly,
+            3 => RecurrenceRuleFrequency::Daily,
+            unknown => RecurrenceRuleFrequency::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleFrequency> for u8 {
+    fn from(value: RecurrenceRuleFrequency) -> Self {
+        match value {
+            RecurrenceRuleFrequency::Yearly => 0,
+            RecurrenceRuleFrequency::Monthly => 1,
+            RecurrenceRuleFrequency::Weekly => 2,
+            RecurrenceRuleFrequency::Daily => 3,
+            RecurrenceRuleFrequency::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Month of the year a scheduled event recurs in.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleMonth {
+    /// January.
+    January,
+    /// February.
+    February,
+    /// March.
+    March,
+    /// April.
+    April,
+    /// May.
+    May,
+    /// June.
+    June,
+    /// July.
+    July,
+    /// August.
+    August,
+    /// September.
+    September,
+    /// October.
+    October,
+    /// November.
+    November,
+    /// December.
+    December,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleMonth {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => RecurrenceRuleMonth::January,
+            2 => RecurrenceRuleMonth::February,
+            3 => RecurrenceRuleMonth::March,
+            4 => RecurrenceRuleMonth::April,
+            5 => RecurrenceRuleMonth::May,
+            6 => RecurrenceRuleMonth::June,
+            7 => RecurrenceRuleMonth::July,
+            8 => RecurrenceRuleMonth::August,
+            9 => RecurrenceRuleMonth::September,
+            10 => RecurrenceRuleMonth::October,
+            11 => RecurrenceRuleMonth::November,
+            12 => RecurrenceRuleMonth::December,
+            unknown => RecurrenceRuleMonth::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleMonth> for u8 {
+    fn from(value: RecurrenceRuleMonth) -> Self {
+        match value {
+            RecurrenceRuleMonth::January => 1,
+            RecurrenceRuleMonth::February => 2,
+            RecurrenceRuleMonth::March => 3,
+            RecurrenceRuleMonth::April => 4,
+            RecurrenceRuleMonth::May => 5,
+            RecurrenceRuleMonth::June => 6,
+            RecurrenceRuleMonth::July => 7,
+            RecurrenceRuleMonth::August => 8,
+            RecurrenceRuleMonth::September => 9,
+            RecurrenceRuleMonth::October => 10,
+            RecurrenceRuleMonth::November => 11,
+            RecurrenceRuleMonth::December => 12,
+            RecurrenceRuleMonth::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Specific weekday within a specific week of the month, such as the second
+/// Tuesday.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRuleNWeekday {
+    /// Day of the week.
+    pub day: RecurrenceRuleWeekday,
+    /// Week of the month, from 1 to 5.
+    pub n: u8,
+}
+
+/// Day of the week a scheduled event recurs on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleWeekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleWeekday {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => RecurrenceRuleWeekday::Monday,
+            1 => RecurrenceRuleWeekday::Tuesday,
+            2 => RecurrenceRuleWeekday::Wednesday,
+            3 => RecurrenceRuleWeekday::Thursday,
+            4 => RecurrenceRuleWeekday::Friday,
+            5 => RecurrenceRuleWeekday::Saturday,
+            6 => RecurrenceRuleWeekday::Sunday,
+            unknown => RecurrenceRuleWeekday::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleWeekday> for u8 {
+    fn from(value: RecurrenceRuleWeekday) -> Self {
+        match value {
+            RecurrenceRuleWeekday::Monday => 0,
+            RecurrenceRuleWeekday::Tuesday => 1,
+            RecurrenceRuleWeekday::Wednesday => 2,
+            RecurrenceRuleWeekday::Thursday => 3,
+            RecurrenceRuleWeekday::Friday => 4,
+            RecurrenceRuleWeekday::Saturday => 5,
+            RecurrenceRuleWeekday::Sunday => 6,
+            RecurrenceRuleWeekday::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        GuildScheduledEventRecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth,
+        RecurrenceRuleNWeekday, RecurrenceRuleWeekday,
+    };
+    use crate::util::Timestamp;
+    use serde_test::Token;
+    use std::error::Error;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&RecurrenceRuleFrequency::Yearly, &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceRuleFrequency::Daily, &[Token::U8(3)]);
+        serde_test::assert_tokens(&RecurrenceRuleFrequency::Unknown(99), &[Token::U8(99)]);
+        serde_test::assert_tokens(&RecurrenceRuleMonth::January, &[Token::U8(1)]);
+        serde_test::assert_tokens(&RecurrenceRuleMonth::December, &[Token::U8(12)]);
+        serde_test::assert_tokens(&RecurrenceRuleMonth::Unknown(0), &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceRuleWeekday::Monday, &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceRuleWeekday::Sunday, &[Token::U8(6)]);
+        serde_test::assert_tokens(&RecurrenceRuleWeekday::Unknown(7), &[Token::U8(7)]);
+    }
+
+    /// Every other week on Friday, as created in the client.
+    #[test]
+    fn weekly() -> Result<(), Box<dyn Error>> {
+        let value = GuildScheduledEventRecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: Some(Vec::from([RecurrenceRuleWeekday::Friday])),
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Weekly,
+            interval: 2,
+            start: Timestamp::parse("2024-08-16T18:00:00.000000+00:00")?,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEventRecurrenceRule",
+                    len: 10,
+                },
+                Token::Str("by_month"),
+                Token::None,
+                Token::Str("by_month_day"),
+                Token::None,
+                Token::Str("by_n_weekday"),
+                Token::None,
+                Token::Str("by_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::U8(4),
+                Token::SeqEnd,
+                Token::Str("by_year_day"),
+                Token::None,
+                Token::Str("count"),
+                Token::None,
+                Token::Str("end"),
+                Token::None,
+                Token::Str("frequency"),
+                Token::U8(2),
+                Token::Str("interval"),
+                Token::U16(2),
+                Token::Str("start"),
+                Token::Str("2024-08-16T18:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    /// Second Tuesday of every month, with the N-th weekday encoded as an
+    /// object.
+    #[test]
+    fn monthly() -> Result<(), Box<dyn Error>> {
+        let value = GuildScheduledEventRecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(Vec::from([RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Tuesday,
+                n: 2,
+            }])),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Monthly,
+            interval: 1,
+            start: Timestamp::parse("2024-08-13T18:00:00.000000+00:00")?,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEventRecurrenceRule",
+                    len: 10,
+                },
+                Token::Str("by_month"),
+                Token::None,
+                Token::Str("by_month_day"),
+                Token::None,
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "RecurrenceRuleNWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(1),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("by_weekday"),
+                Token::None,
+                Token::Str("by_year_day"),
+                Token::None,
+                Token::Str("count"),
+                Token::None,
+                Token::Str("end"),
+                Token::None,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2024-08-13T18:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    /// Yearly rule as returned by Discord, including the system-set day of the
+    /// year.
+    #[test]
+    fn yearly_payload() -> Result<(), Box<dyn Error>> {
+        let payload = r#"{
+            "start": "2024-12-25T09:00:00+00:00",
+            "end": null,
+            "frequency": 0,
+            "interval": 1,
+            "by_weekday": null,
+            "by_n_weekday": null,
+            "by_month": [12],
+            "by_month_day": [25],
+            "by_year_day": [360],
+            "count": null
+        }"#;
+
+        let expected = GuildScheduledEventRecurrenceRule {
+            by_month: Some(Vec::from([RecurrenceRuleMonth::December])),
+            by_month_day: Some(Vec::from([25])),
+            by_n_weekday: None,
+            by_weekday: None,
+            by_year_day: Some(Vec::from([360])),
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Yearly,
+            interval: 1,
+            start: Timestamp::parse("2024-12-25T09:00:00+00:00")?,
+        };
+
+        assert_eq!(
+            expected,
+            serde_json::from_str::<GuildScheduledEventRecurrenceRule>(payload)?
+        );
+
+        Ok(())
+    }
+}
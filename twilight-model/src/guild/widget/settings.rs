@@ -1,9 +1,16 @@
 use crate::id::{marker::ChannelMarker, Id};
 use serde::{Deserialize, Serialize};
 
+/// Settings of a guild's widget.
+///
+/// See [Discord Docs/Guild Widget Settings Object].
+///
+/// [Discord Docs/Guild Widget Settings Object]: https://discord.com/developers/docs/resources/guild#guild-widget-settings-object
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct GuildWidgetSettings {
-    pub channel_id: Id<ChannelMarker>,
+    /// ID of the channel invites generated by the widget lead to, if any.
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// Whether the widget is enabled.
     pub enabled: bool,
 }
 
@@ -32,7 +39,7 @@ mod tests {
     #[test]
     fn guild_widget_settings() {
         let value = GuildWidgetSettings {
-            channel_id: Id::new(111_111_111_111_111_111),
+            channel_id: Some(Id::new(111_111_111_111_111_111)),
             enabled: true,
         };
 
@@ -44,6 +51,7 @@ mod tests {
                     len: 2,
                 },
                 Token::Str("channel_id"),
+                Token::Some,
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("111111111111111111"),
                 Token::Str("enabled"),
@@ -52,4 +60,27 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn guild_widget_settings_no_channel() {
+        let value = GuildWidgetSettings {
+            channel_id: None,
+            enabled: false,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildWidgetSettings",
+                    len: 2,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("enabled"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -1,12 +1,12 @@
 use super::{
     application_integration_type::{ApplicationIntegrationMap, ApplicationIntegrationTypeConfig},
-    team::Team,
+    team::{Team, TeamMemberRole, TeamMembershipState},
     ApplicationFlags, InstallParams,
 };
 use crate::{
     guild::Guild,
     id::{
-        marker::{ApplicationMarker, GuildMarker, OauthSkuMarker},
+        marker::{ApplicationMarker, GuildMarker, OauthSkuMarker, UserMarker},
         Id,
     },
     user::User,
@@ -95,17 +95,47 @@ pub struct Application {
     pub verify_key: String,
 }
 
+impl Application {
+    /// Whether a user owns the application.
+    ///
+    /// If the application belongs to a [`team`], this is the team's owner and
+    /// its admins who have accepted their invite; the [`owner`] is then a
+    /// placeholder user for the team. Otherwise, this is the [`owner`].
+    ///
+    /// Returns `false` if the application's owner isn't known, such as when
+    /// the application was partially returned.
+    ///
+    /// [`owner`]: Self::owner
+    /// [`team`]: Self::team
+    pub fn is_owner(&self, user_id: Id<UserMarker>) -> bool {
+        if let Some(team) = &self.team {
+            return team.owner_user_id == user_id
+                || team.members.iter().any(|member| {
+                    member.user.id == user_id
+                        && member.membership_state == TeamMembershipState::Accepted
+                        && member.role == TeamMemberRole::Admin
+                });
+        }
+
+        self.owner.as_ref().is_some_and(|owner| owner.id == user_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Application, ApplicationFlags, Team, User};
-    use crate::{id::Id, test::image_hash};
+    use super::{
+        Application, ApplicationFlags, ApplicationIntegrationMap, ApplicationIntegrationTypeConfig,
+        InstallParams, Team, TeamMemberRole, TeamMembershipState, User,
+    };
+    use crate::{guild::Permissions, id::Id, test::image_hash};
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{fmt::Debug, hash::Hash};
 
     assert_fields!(
-        Application: bot_public,
+        Application: approximate_guild_count,
+        bot_public,
         bot_require_code_grant,
         cover_image,
         custom_install_url,
@@ -115,10 +145,13 @@ mod tests {
         icon,
         id,
         install_params,
+        integration_types_config,
+        interactions_endpoint_url,
         name,
         owner,
         primary_sku_id,
         privacy_policy_url,
+        role_connections_verification_url,
         rpc_origins,
         slug,
         tags,
@@ -174,6 +207,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "app dev".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -324,4 +358,127 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn install_params() -> Result<(), serde_json::Error> {
+        let input = r#"{
+            "bot_public": true,
+            "bot_require_code_grant": false,
+            "description": "",
+            "id": "1",
+            "install_params": {"permissions": "2048", "scopes": ["bot"]},
+            "integration_types_config": {
+                "0": {"oauth2_install_params": {"permissions": "2048", "scopes": ["bot"]}},
+                "1": {}
+            },
+            "name": "application",
+            "rpc_origins": [],
+            "verify_key": "key"
+        }"#;
+        let install_params = InstallParams {
+            permissions: Permissions::SEND_MESSAGES,
+            scopes: Vec::from(["bot".to_owned()]),
+        };
+
+        let application = serde_json::from_str::<Application>(input)?;
+
+        assert_eq!(Some(&install_params), application.install_params.as_ref());
+        assert_eq!(
+            Some(ApplicationIntegrationMap {
+                guild: Some(ApplicationIntegrationTypeConfig {
+                    oauth2_install_params: Some(install_params),
+                }),
+                user: Some(ApplicationIntegrationTypeConfig {
+                    oauth2_install_params: None,
+                }),
+            }),
+            application.integration_types_config,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_owner_individual() -> Result<(), serde_json::Error> {
+        let input = r#"{
+            "bot_public": true,
+            "bot_require_code_grant": false,
+            "description": "",
+            "id": "1",
+            "name": "application",
+            "owner": {"avatar": null, "discriminator": "0", "id": "2", "username": "owner"},
+            "rpc_origins": [],
+            "team": null,
+            "verify_key": "key"
+        }"#;
+
+        let application = serde_json::from_str::<Application>(input)?;
+
+        assert!(application.is_owner(Id::new(2)));
+        assert!(!application.is_owner(Id::new(3)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_owner_team() -> Result<(), serde_json::Error> {
+        fn member(id: u64, membership_state: u8, role: &str) -> String {
+            format!(
+                r#"{{
+                    "membership_state": {membership_state},
+                    "permissions": ["*"],
+                    "role": "{role}",
+                    "team_id": "10",
+                    "user": {{"avatar": null, "discriminator": "0", "id": "{id}", "username": "member"}}
+                }}"#
+            )
+        }
+
+        let members = [
+            member(2, 2, "admin"),
+            member(3, 2, "developer"),
+            member(4, 2, "read_only"),
+            member(5, 1, "admin"),
+        ]
+        .join(",");
+        let input = format!(
+            r#"{{
+                "bot_public": true,
+                "bot_require_code_grant": false,
+                "description": "",
+                "id": "1",
+                "name": "application",
+                "owner": {{"avatar": null, "discriminator": "0", "id": "10", "username": "team10", "flags": 1024}},
+                "rpc_origins": [],
+                "team": {{
+                    "icon": null,
+                    "id": "10",
+                    "members": [{members}],
+                    "name": "team",
+                    "owner_user_id": "6"
+                }},
+                "verify_key": "key"
+            }}"#
+        );
+
+        let application = serde_json::from_str::<Application>(&input)?;
+        let team = application.team.as_ref().unwrap();
+        assert_eq!(TeamMemberRole::Admin, team.members[0].role);
+        assert_eq!(TeamMemberRole::ReadOnly, team.members[2].role);
+        assert_eq!(
+            TeamMembershipState::Invited,
+            team.members[3].membership_state
+        );
+
+        // The team owner and accepted admins.
+        assert!(application.is_owner(Id::new(6)));
+        assert!(application.is_owner(Id::new(2)));
+        // Other roles, invited admins, and the team's placeholder user.
+        assert!(!application.is_owner(Id::new(3)));
+        assert!(!application.is_owner(Id::new(4)));
+        assert!(!application.is_owner(Id::new(5)));
+        assert!(!application.is_owner(Id::new(10)));
+
+        Ok(())
+    }
 }
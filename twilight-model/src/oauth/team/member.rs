@@ -1,21 +1,27 @@
 use crate::{
     id::{marker::OauthTeamMarker, Id},
-    oauth::team::TeamMembershipState,
+    oauth::team::{TeamMemberRole, TeamMembershipState},
     user::User,
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct TeamMember {
+    /// Whether the user has accepted the invite to the team.
     pub membership_state: TeamMembershipState,
+    /// Will always be `["*"]`.
     pub permissions: Vec<String>,
+    /// Role of the member in the team.
+    pub role: TeamMemberRole,
+    /// ID of the team the user is a member of.
     pub team_id: Id<OauthTeamMarker>,
+    /// Partial user object of the member.
     pub user: User,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{TeamMember, TeamMembershipState, User};
+    use super::{TeamMember, TeamMemberRole, TeamMembershipState, User};
     use crate::id::Id;
     use serde_test::Token;
 
@@ -24,6 +30,7 @@ mod tests {
         let value = TeamMember {
             membership_state: TeamMembershipState::Accepted,
             permissions: vec!["*".to_owned()],
+            role: TeamMemberRole::Developer,
             team_id: Id::new(1),
             user: User {
                 accent_color: None,
@@ -41,6 +48,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "test".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -52,7 +60,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "TeamMember",
-                    len: 4,
+                    len: 5,
                 },
                 Token::Str("membership_state"),
                 Token::U8(2),
@@ -60,6 +68,8 @@ mod tests {
                 Token::Seq { len: Some(1) },
                 Token::Str("*"),
                 Token::SeqEnd,
+                Token::Str("role"),
+                Token::Str("developer"),
                 Token::Str("team_id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("1"),
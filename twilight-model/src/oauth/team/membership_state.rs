@@ -4,7 +4,9 @@ use serde::{Deserialize, Serialize};
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum TeamMembershipState {
+    /// User has been invited to the team but hasn't accepted yet.
     Invited,
+    /// User has accepted the invite to the team.
     Accepted,
     /// Variant value is unknown to the library.
     Unknown(u8),
@@ -1,7 +1,8 @@
 mod member;
 mod membership_state;
+mod role;
 
-pub use self::{member::TeamMember, membership_state::TeamMembershipState};
+pub use self::{member::TeamMember, membership_state::TeamMembershipState, role::TeamMemberRole};
 
 use crate::{
     id::{
@@ -14,10 +15,15 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Team {
+    /// Hash of the team's icon.
     pub icon: Option<ImageHash>,
+    /// ID of the team.
     pub id: Id<OauthTeamMarker>,
+    /// Members of the team.
     pub members: Vec<TeamMember>,
+    /// Name of the team.
     pub name: String,
+    /// ID of the user who owns the team.
     pub owner_user_id: Id<UserMarker>,
 }
 
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Role of a member of a team, determining what they can access.
+///
+/// The owner of a team is identified by the team's [`owner_user_id`] rather
+/// than a role.
+///
+/// See [Discord Docs/Team Member Role Types].
+///
+/// [`owner_user_id`]: super::Team::owner_user_id
+/// [Discord Docs/Team Member Role Types]: https://discord.com/developers/docs/topics/teams#team-member-roles-team-member-role-types
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "String", into = "Cow<'static, str>")]
+pub enum TeamMemberRole {
+    /// Admins have similar access to owners, except they can't take
+    /// destructive actions on the team or its applications.
+    Admin,
+    /// Developers can access information about applications and configure
+    /// them, but can't manage the team or its members.
+    Developer,
+    /// Read-only members can access information about the team and its
+    /// applications.
+    ReadOnly,
+    /// Variant value is unknown to the library.
+    Unknown(String),
+}
+
+impl From<TeamMemberRole> for Cow<'static, str> {
+    fn from(value: TeamMemberRole) -> Self {
+        match value {
+            TeamMemberRole::Admin => "admin".into(),
+            TeamMemberRole::Developer => "developer".into(),
+            TeamMemberRole::ReadOnly => "read_only".into(),
+            TeamMemberRole::Unknown(unknown) => unknown.into(),
+        }
+    }
+}
+
+impl From<String> for TeamMemberRole {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "admin" => Self::Admin,
+            "developer" => Self::Developer,
+            "read_only" => Self::ReadOnly,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TeamMemberRole;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        const MAP: &[(TeamMemberRole, &str)] = &[
+            (TeamMemberRole::Admin, "admin"),
+            (TeamMemberRole::Developer, "developer"),
+            (TeamMemberRole::ReadOnly, "read_only"),
+        ];
+
+        for (role, value) in MAP {
+            serde_test::assert_tokens(role, &[Token::Str(value)]);
+        }
+
+        serde_test::assert_tokens(
+            &TeamMemberRole::Unknown("owner".to_owned()),
+            &[Token::Str("owner")],
+        );
+    }
+}
@@ -32,12 +32,90 @@ pub struct Poll {
     pub results: Option<PollResults>,
 }
 
+impl Poll {
+    /// Number of votes for an answer.
+    ///
+    /// Returns `None` if the poll has no results or the answer has no counted
+    /// votes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use twilight_model::poll::{AnswerCount, Poll, PollLayoutType, PollMedia, PollResults};
+    /// # let poll = Poll {
+    /// #     answers: Vec::new(),
+    /// #     allow_multiselect: false,
+    /// #     expiry: None,
+    /// #     layout_type: PollLayoutType::Default,
+    /// #     question: PollMedia {
+    /// #         emoji: None,
+    /// #         text: None,
+    /// #     },
+    /// #     results: Some(PollResults {
+    /// #         answer_counts: vec![AnswerCount {
+    /// #             id: 1,
+    /// #             count: 5,
+    /// #             me_voted: false,
+    /// #         }],
+    /// #         is_finalized: false,
+    /// #     }),
+    /// # };
+    /// assert_eq!(Some(5), poll.answer_count(1));
+    /// assert_eq!(None, poll.answer_count(2));
+    /// ```
+    pub fn answer_count(&self, answer_id: u8) -> Option<u64> {
+        self.results
+            .as_ref()?
+            .answer_counts
+            .iter()
+            .find(|answer_count| answer_count.id == answer_id)
+            .map(|answer_count| answer_count.count)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{AnswerCount, Poll, PollAnswer, PollLayoutType, PollMedia, PollResults};
     use crate::{id::Id, poll::media::PartialPollMediaEmoji};
     use serde_test::Token;
 
+    #[test]
+    fn answer_count() {
+        let mut value = Poll {
+            answers: Vec::new(),
+            allow_multiselect: false,
+            expiry: None,
+            layout_type: PollLayoutType::Default,
+            question: PollMedia {
+                emoji: None,
+                text: Some("a".to_owned()),
+            },
+            results: None,
+        };
+
+        assert_eq!(None, value.answer_count(1));
+
+        value.results = Some(PollResults {
+            answer_counts: vec![
+                AnswerCount {
+                    id: 1,
+                    count: 2,
+                    me_voted: true,
+                },
+                AnswerCount {
+                    id: 3,
+                    count: 0,
+                    me_voted: false,
+                },
+            ],
+            is_finalized: false,
+        });
+
+        assert_eq!(Some(2), value.answer_count(1));
+        assert_eq!(None, value.answer_count(2));
+        assert_eq!(Some(0), value.answer_count(3));
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn poll() {
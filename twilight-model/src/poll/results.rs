@@ -15,6 +15,45 @@ mod tests {
     use super::{AnswerCount, PollResults};
     use serde_test::Token;
 
+    #[test]
+    fn poll_results_not_finalized() {
+        let value = PollResults {
+            answer_counts: vec![AnswerCount {
+                id: 2,
+                count: 7,
+                me_voted: false,
+            }],
+            is_finalized: false,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PollResults",
+                    len: 2,
+                },
+                Token::Str("answer_counts"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "AnswerCount",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::U8(2),
+                Token::Str("count"),
+                Token::U64(7),
+                Token::Str("me_voted"),
+                Token::Bool(false),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("is_finalized"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn poll_results() {
         let value = PollResults {
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     id::{marker::AvatarDecorationDataSkuMarker, Id},
-    util::ImageHash,
+    util::{ImageHash, Timestamp},
 };
 
 /// The data for the user's avatar decoration.
@@ -10,20 +10,27 @@ use crate::{
 pub struct AvatarDecorationData {
     /// The avatar decoration hash.
     pub asset: ImageHash,
+    /// When the avatar decoration expires, if it's only available for a
+    /// limited time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
     /// ID of the avatar decoration's SKU.
     pub sku_id: Id<AvatarDecorationDataSkuMarker>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{AvatarDecorationData, Id, ImageHash};
+    use super::{AvatarDecorationData, Id, ImageHash, Timestamp};
+    use crate::util::datetime::TimestampParseError;
     use serde_test::Token;
+    use std::str::FromStr;
 
     #[test]
     fn test_avatar_decoration_data() {
         let hash = "b2a6536641da91a0b59bd66557c56c36";
         let value = AvatarDecorationData {
             asset: ImageHash::parse(hash.as_bytes()).unwrap(),
+            expires_at: None,
             sku_id: Id::new(1),
         };
 
@@ -43,4 +50,35 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn expires_at() -> Result<(), TimestampParseError> {
+        let hash = "b2a6536641da91a0b59bd66557c56c36";
+        let value = AvatarDecorationData {
+            asset: ImageHash::parse(hash.as_bytes()).unwrap(),
+            expires_at: Some(Timestamp::from_str("2025-07-01T00:00:00.000000+00:00")?),
+            sku_id: Id::new(1),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AvatarDecorationData",
+                    len: 3,
+                },
+                Token::Str("asset"),
+                Token::Str(hash),
+                Token::Str("expires_at"),
+                Token::Some,
+                Token::Str("2025-07-01T00:00:00.000000+00:00"),
+                Token::Str("sku_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use super::{DiscriminatorDisplay, PremiumType, UserFlags};
+use super::{AvatarDecorationData, DiscriminatorDisplay, PremiumType, PrimaryGuild, UserFlags};
 use crate::{
     id::{marker::UserMarker, Id},
     util::image_hash::ImageHash,
@@ -17,6 +17,9 @@ pub struct CurrentUser {
     ///
     /// [Discord Docs/Image Formatting]: https://discord.com/developers/docs/reference#image-formatting
     pub avatar: Option<ImageHash>,
+    /// Data for the user's avatar decoration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
     /// Hash of the user's banner image.
     pub banner: Option<ImageHash>,
     /// Whether the user belongs to an OAuth2 application.
@@ -63,6 +66,13 @@ pub struct CurrentUser {
     /// Type of Nitro subscription on a user's account.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_type: Option<PremiumType>,
+    /// Guild whose tag the user displays next to their name.
+    ///
+    /// Refer to [`guild_tag`] for the tag to display.
+    ///
+    /// [`guild_tag`]: Self::guild_tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_guild: Option<Box<PrimaryGuild>>,
     /// Public flags on a user's account.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_flags: Option<UserFlags>,
@@ -83,6 +93,16 @@ impl CurrentUser {
     pub const fn discriminator(&self) -> DiscriminatorDisplay {
         DiscriminatorDisplay::new(self.discriminator)
     }
+
+    /// Guild tag to display next to the user's name, also known as a clan
+    /// tag, if the user is displaying one.
+    ///
+    /// Refer to [`PrimaryGuild::displayed_tag`] for more information.
+    pub fn guild_tag(&self) -> Option<&str> {
+        self.primary_guild
+            .as_deref()
+            .and_then(PrimaryGuild::displayed_tag)
+    }
 }
 
 #[cfg(test)]
@@ -184,6 +204,7 @@ mod tests {
         let value = CurrentUser {
             accent_color: Some(16_711_680),
             avatar: Some(image_hash::AVATAR),
+            avatar_decoration_data: None,
             banner: None,
             bot: true,
             discriminator: 9999,
@@ -193,6 +214,7 @@ mod tests {
             name: "test name".to_owned(),
             verified: Some(true),
             premium_type: Some(PremiumType::NitroClassic),
+            primary_guild: None,
             public_flags: Some(UserFlags::STAFF),
             flags: None,
             locale: Some("test locale".to_owned()),
@@ -213,6 +235,7 @@ mod tests {
         let value = CurrentUser {
             accent_color: None,
             avatar: Some(image_hash::AVATAR),
+            avatar_decoration_data: None,
             banner: Some(image_hash::BANNER),
             bot: true,
             discriminator: 9999,
@@ -222,6 +245,7 @@ mod tests {
             name: "test name".to_owned(),
             verified: Some(true),
             premium_type: Some(PremiumType::NitroClassic),
+            primary_guild: None,
             public_flags: Some(UserFlags::STAFF),
             flags: Some(UserFlags::STAFF),
             locale: Some("test locale".to_owned()),
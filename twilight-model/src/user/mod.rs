@@ -5,11 +5,13 @@ mod current_user;
 mod current_user_guild;
 mod flags;
 mod premium_type;
+mod primary_guild;
 
 pub use self::{
     avatar_decoration_data::AvatarDecorationData, connection::Connection,
     connection_visibility::ConnectionVisibility, current_user::CurrentUser,
     current_user_guild::CurrentUserGuild, flags::UserFlags, premium_type::PremiumType,
+    primary_guild::PrimaryGuild,
 };
 
 use crate::{
@@ -128,7 +130,7 @@ pub struct User {
     /// Hash of the user's avatar decoration.
     pub avatar_decoration: Option<ImageHash>,
     /// Data for the user's avatar decoration.
-    pub avatar_decoration_data: Option<AvatarDecorationData>,
+    pub avatar_decoration_data: Option<Box<AvatarDecorationData>>,
     /// Hash of the user's banner image.
     pub banner: Option<ImageHash>,
     #[serde(default)]
@@ -170,6 +172,13 @@ pub struct User {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_type: Option<PremiumType>,
+    /// Guild whose tag the user displays next to their name.
+    ///
+    /// Refer to [`guild_tag`] for the tag to display.
+    ///
+    /// [`guild_tag`]: Self::guild_tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_guild: Option<Box<PrimaryGuild>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_flags: Option<UserFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,11 +195,49 @@ impl User {
     pub const fn discriminator(&self) -> DiscriminatorDisplay {
         DiscriminatorDisplay::new(self.discriminator)
     }
+
+    /// Name of the user as displayed in the client.
+    ///
+    /// This is the user's [global name] if set, otherwise their [username].
+    ///
+    /// Refer to [`Member::display_name`] to also take a guild nickname into
+    /// account.
+    ///
+    /// [`Member::display_name`]: crate::guild::Member::display_name
+    /// [global name]: Self::global_name
+    /// [username]: Self::name
+    pub fn display_name(&self) -> &str {
+        self.global_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Guild tag to display next to the user's name, also known as a clan
+    /// tag, if the user is displaying one.
+    ///
+    /// Refer to [`PrimaryGuild::displayed_tag`] for more information.
+    pub fn guild_tag(&self) -> Option<&str> {
+        self.primary_guild
+            .as_deref()
+            .and_then(PrimaryGuild::displayed_tag)
+    }
+
+    /// Tag of the user.
+    ///
+    /// Users that have yet to migrate to the new username system have a tag in
+    /// the legacy format of `name#discriminator`, such as `twilight#0001`.
+    /// Migrated users, which have a discriminator of `0`, are tagged by their
+    /// username only.
+    pub fn tag(&self) -> String {
+        if self.discriminator == 0 {
+            self.name.clone()
+        } else {
+            format!("{}#{}", self.name, self.discriminator())
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{DiscriminatorDisplay, PremiumType, User, UserFlags};
+    use super::{DiscriminatorDisplay, PremiumType, PrimaryGuild, User, UserFlags};
     use crate::{id::Id, test::image_hash};
     use serde_test::Token;
     use static_assertions::assert_impl_all;
@@ -322,6 +369,60 @@ mod tests {
         ]
     }
 
+    fn user_named(name: &str, global_name: Option<&str>, discriminator: u16) -> User {
+        User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator,
+            email: None,
+            flags: None,
+            global_name: global_name.map(ToOwned::to_owned),
+            id: Id::new(1),
+            locale: None,
+            mfa_enabled: None,
+            name: name.to_owned(),
+            premium_type: None,
+            primary_guild: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn display_name() {
+        assert_eq!(
+            "Global",
+            user_named("name", Some("Global"), 0).display_name()
+        );
+        assert_eq!("name", user_named("name", None, 0).display_name());
+    }
+
+    #[test]
+    fn guild_tag() {
+        let mut user = user_named("name", None, 0);
+        assert!(user.guild_tag().is_none());
+
+        user.primary_guild = Some(Box::new(PrimaryGuild {
+            badge: Some(image_hash::ICON),
+            identity_enabled: Some(true),
+            identity_guild_id: Some(Id::new(2)),
+            tag: Some("TWI".to_owned()),
+        }));
+        assert_eq!(Some("TWI"), user.guild_tag());
+    }
+
+    #[test]
+    fn tag() {
+        assert_eq!("name#0001", user_named("name", None, 1).tag());
+        assert_eq!("name#1234", user_named("name", Some("Global"), 1234).tag());
+        assert_eq!("name", user_named("name", Some("Global"), 0).tag());
+    }
+
     #[test]
     fn discriminator_display() {
         assert_eq!(3030, DiscriminatorDisplay::new(3030).get());
@@ -350,6 +451,7 @@ mod tests {
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
+            primary_guild: None,
             public_flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
             system: None,
             verified: Some(true),
@@ -383,6 +485,7 @@ mod tests {
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
+            primary_guild: None,
             public_flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
             system: None,
             verified: Some(true),
@@ -413,6 +516,7 @@ mod tests {
             mfa_enabled: Some(true),
             name: "test".to_owned(),
             premium_type: Some(PremiumType::Nitro),
+            primary_guild: None,
             public_flags: Some(UserFlags::PREMIUM_EARLY_SUPPORTER | UserFlags::VERIFIED_DEVELOPER),
             system: Some(true),
             verified: Some(true),
@@ -0,0 +1,137 @@
+use crate::{
+    id::{marker::GuildMarker, Id},
+    util::ImageHash,
+};
+use serde::{Deserialize, Serialize};
+
+/// Guild whose tag a user displays next to their name.
+///
+/// Guild tags are also known as clan tags.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PrimaryGuild {
+    /// Hash of the guild tag's badge.
+    pub badge: Option<ImageHash>,
+    /// Whether the user is displaying the guild's tag.
+    ///
+    /// This is `None` if the guild's tag was recently cleared.
+    pub identity_enabled: Option<bool>,
+    /// ID of the guild.
+    pub identity_guild_id: Option<Id<GuildMarker>>,
+    /// Text of the guild's tag, up to 4 characters long.
+    pub tag: Option<String>,
+}
+
+impl PrimaryGuild {
+    /// Tag to display next to the user's name, if the user is displaying one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{id::Id, user::PrimaryGuild};
+    ///
+    /// let mut primary_guild = PrimaryGuild {
+    ///     badge: None,
+    ///     identity_enabled: Some(true),
+    ///     identity_guild_id: Some(Id::new(1)),
+    ///     tag: Some("TWI".to_owned()),
+    /// };
+    /// assert_eq!(Some("TWI"), primary_guild.displayed_tag());
+    ///
+    /// primary_guild.identity_enabled = Some(false);
+    /// assert!(primary_guild.displayed_tag().is_none());
+    /// ```
+    pub fn displayed_tag(&self) -> Option<&str> {
+        if self.identity_enabled == Some(true) {
+            self.tag.as_deref()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrimaryGuild;
+    use crate::{id::Id, test::image_hash};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(PrimaryGuild: badge, identity_enabled, identity_guild_id, tag);
+    assert_impl_all!(
+        PrimaryGuild: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn primary_guild() {
+        let value = PrimaryGuild {
+            badge: Some(image_hash::ICON),
+            identity_enabled: Some(true),
+            identity_guild_id: Some(Id::new(1)),
+            tag: Some("TWI".to_owned()),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PrimaryGuild",
+                    len: 4,
+                },
+                Token::Str("badge"),
+                Token::Some,
+                Token::Str(image_hash::ICON_INPUT),
+                Token::Str("identity_enabled"),
+                Token::Some,
+                Token::Bool(true),
+                Token::Str("identity_guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("tag"),
+                Token::Some,
+                Token::Str("TWI"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn cleared() {
+        let value = PrimaryGuild {
+            badge: None,
+            identity_enabled: None,
+            identity_guild_id: None,
+            tag: None,
+        };
+
+        assert!(value.displayed_tag().is_none());
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PrimaryGuild",
+                    len: 4,
+                },
+                Token::Str("badge"),
+                Token::None,
+                Token::Str("identity_enabled"),
+                Token::None,
+                Token::Str("identity_guild_id"),
+                Token::None,
+                Token::Str("tag"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
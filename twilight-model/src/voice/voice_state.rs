@@ -164,6 +164,7 @@ mod tests {
                     mfa_enabled: None,
                     name: "twilight".to_owned(),
                     premium_type: None,
+                    primary_guild: None,
                     public_flags: None,
                     system: None,
                     verified: None,
@@ -0,0 +1,187 @@
+//! Blocking variants of [`Standby`] methods for synchronous call sites.
+//!
+//! [`Standby`]: super::Standby
+
+use crate::{Bystander, Sender, Standby};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{
+        mpsc::{self, RecvTimeoutError, SyncSender},
+        Arc, Weak,
+    },
+    time::Duration,
+};
+use twilight_model::{
+    gateway::payload::incoming::MessageCreate,
+    id::{marker::ChannelMarker, Id},
+};
+
+/// Blocking wait timed out or was canceled.
+#[derive(Debug)]
+pub struct TimeoutOrCanceled {
+    /// Type of error.
+    kind: TimeoutOrCanceledType,
+}
+
+impl TimeoutOrCanceled {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TimeoutOrCanceledType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (TimeoutOrCanceledType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for TimeoutOrCanceled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            TimeoutOrCanceledType::Canceled => {
+                f.write_str("bystander was dropped before an event matched")
+            }
+            TimeoutOrCanceledType::TimedOut => {
+                f.write_str("no event matched before the timeout elapsed")
+            }
+        }
+    }
+}
+
+impl Error for TimeoutOrCanceled {}
+
+/// Type of [`TimeoutOrCanceled`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TimeoutOrCanceledType {
+    /// Bystander was dropped before an event matched.
+    Canceled,
+    /// No event matched before the timeout elapsed.
+    TimedOut,
+}
+
+/// Sender half of a blocking bystander.
+#[derive(Debug)]
+pub(crate) struct BlockingSender<E> {
+    /// Sender half of the synchronous channel the waiting thread is parked on.
+    tx: SyncSender<E>,
+    /// Handle to the waiting thread's token, dropped once it stops waiting.
+    waiter: Weak<()>,
+}
+
+impl<E> BlockingSender<E> {
+    /// Whether the waiting thread has stopped waiting.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.waiter.strong_count() == 0
+    }
+
+    /// Send an event to the waiting thread, if it is still waiting.
+    pub(crate) fn send(self, event: E) {
+        // The waiting thread may have timed out between checking whether the
+        // sender is closed and sending, which is fine.
+        drop(self.tx.try_send(event));
+    }
+}
+
+impl Standby {
+    /// Wait for a message in a certain channel, blocking the current thread
+    /// for at most `timeout`.
+    ///
+    /// This is a blocking variant of [`wait_for_message`] for synchronous call
+    /// sites, such as embedded scripting engines. The thread is parked on a
+    /// standard library channel instead of a Tokio primitive, so no runtime is
+    /// required. Events must still be [processed] by another thread.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 5 seconds for a message in channel 123 by user 456:
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use twilight_model::{gateway::payload::incoming::MessageCreate, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let standby = Standby::new();
+    ///
+    /// let author_id = Id::new(456);
+    /// let message = standby.wait_for_message_blocking(
+    ///     Id::new(123),
+    ///     move |event: &MessageCreate| event.author.id == author_id,
+    ///     Duration::from_secs(5),
+    /// )?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TimedOut`] if no matching message was
+    /// processed before the timeout elapsed.
+    ///
+    /// Returns an error of type [`Canceled`] if the bystander was dropped
+    /// without being sent a message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within a Tokio runtime, as blocking a runtime
+    /// thread may prevent the event from ever being processed. Use
+    /// [`wait_for_message`] from asynchronous code instead.
+    ///
+    /// [`Canceled`]: TimeoutOrCanceledType::Canceled
+    /// [`TimedOut`]: TimeoutOrCanceledType::TimedOut
+    /// [`wait_for_message`]: Self::wait_for_message
+    /// [processed]: Self::process
+    pub fn wait_for_message_blocking<F: Fn(&MessageCreate) -> bool + Send + Sync + 'static>(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        check: impl Into<Box<F>>,
+        timeout: Duration,
+    ) -> Result<MessageCreate, TimeoutOrCanceled> {
+        assert!(
+            tokio::runtime::Handle::try_current().is_err(),
+            "wait_for_message_blocking must not be called from within a Tokio runtime"
+        );
+
+        tracing::trace!(%channel_id, ?timeout, "blocking on message in channel");
+
+        let (tx, rx) = mpsc::sync_channel(1);
+        let waiter = Arc::new(());
+
+        self.messages
+            .entry(channel_id)
+            .or_default()
+            .push(Bystander {
+                claims: false,
+                func: check.into(),
+                sender: Some(Sender::Blocking(BlockingSender {
+                    tx,
+                    waiter: Arc::downgrade(&waiter),
+                })),
+            });
+
+        let result = rx
+            .recv_timeout(timeout)
+            .map_err(|source| TimeoutOrCanceled {
+                kind: match source {
+                    RecvTimeoutError::Disconnected => TimeoutOrCanceledType::Canceled,
+                    RecvTimeoutError::Timeout => TimeoutOrCanceledType::TimedOut,
+                },
+            });
+
+        // Mark the bystander as closed so that it is removed when the next
+        // message in the channel is processed.
+        drop(waiter);
+
+        result
+    }
+}
@@ -2,12 +2,14 @@
 //!
 //! [`Standby`]: super::Standby
 
+use crate::Standby;
 use futures_core::Stream;
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 use tokio::sync::{
@@ -18,7 +20,7 @@ use twilight_model::{
     application::interaction::Interaction,
     gateway::{
         event::Event,
-        payload::incoming::{MessageCreate, ReactionAdd},
+        payload::incoming::{MessageCreate, ReactionAdd, ThreadCreate},
     },
 };
 
@@ -117,9 +119,11 @@ impl Stream for WaitForGuildEventStream {
     }
 }
 
-/// The future returned from [`Standby::wait_for_message`].
+/// The future returned from [`Standby::wait_for_message`] and
+/// [`Standby::wait_for_message_claiming`].
 ///
 /// [`Standby::wait_for_message`]: crate::Standby::wait_for_message
+/// [`Standby::wait_for_message_claiming`]: crate::Standby::wait_for_message_claiming
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForMessageFuture {
@@ -153,9 +157,11 @@ impl Stream for WaitForMessageStream {
     }
 }
 
-/// The future returned from [`Standby::wait_for_reaction`].
+/// The future returned from [`Standby::wait_for_reaction`] and
+/// [`Standby::wait_for_reaction_claiming`].
 ///
 /// [`Standby::wait_for_reaction`]: crate::Standby::wait_for_reaction
+/// [`Standby::wait_for_reaction_claiming`]: crate::Standby::wait_for_reaction_claiming
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForReactionFuture {
@@ -189,9 +195,83 @@ impl Stream for WaitForReactionStream {
     }
 }
 
-/// The future returned from [`Standby::wait_for_component`].
+/// The future returned from [`Standby::wait_for_thread`].
+///
+/// [`Standby::wait_for_thread`]: crate::Standby::wait_for_thread
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForThreadFuture {
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<ThreadCreate>,
+}
+
+impl Future for WaitForThreadFuture {
+    type Output = Result<ThreadCreate, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map_err(Canceled)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_thread_stream`].
+///
+/// [`Standby::wait_for_thread_stream`]: crate::Standby::wait_for_thread_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForThreadStream {
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<ThreadCreate>,
+}
+
+impl Stream for WaitForThreadStream {
+    type Item = ThreadCreate;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_raw`].
+///
+/// [`Standby::wait_for_raw`]: crate::Standby::wait_for_raw
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForRawFuture {
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<String>,
+}
+
+impl Future for WaitForRawFuture {
+    type Output = Result<String, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx).poll(cx).map_err(Canceled)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_raw_stream`].
+///
+/// [`Standby::wait_for_raw_stream`]: crate::Standby::wait_for_raw_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForRawStream {
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<String>,
+}
+
+impl Stream for WaitForRawStream {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_component`] and
+/// [`Standby::wait_for_component_claiming`].
 ///
 /// [`Standby::wait_for_component`]: crate::Standby::wait_for_component
+/// [`Standby::wait_for_component_claiming`]: crate::Standby::wait_for_component_claiming
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForComponentFuture {
@@ -225,22 +305,69 @@ impl Stream for WaitForComponentStream {
     }
 }
 
+/// The stream returned from [`Standby::process_stream`].
+///
+/// [`Standby::process_stream`]: crate::Standby::process_stream
+#[must_use = "streams do nothing unless you poll them"]
+pub struct ProcessStream<S> {
+    /// Standby instance to process events with.
+    pub(crate) standby: Arc<Standby>,
+    /// Wrapped stream of events.
+    pub(crate) stream: Pin<Box<S>>,
+}
+
+impl<S> Debug for ProcessStream<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ProcessStream")
+            .field("standby", &self.standby)
+            .field("stream", &"<S>")
+            .finish()
+    }
+}
+
+impl<S: Stream<Item = Event>> Stream for ProcessStream<S> {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.stream.as_mut().poll_next(cx);
+
+        if let Poll::Ready(Some(event)) = &poll {
+            self.standby.process(event);
+        }
+
+        poll
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
-        WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
+        ProcessStream, WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture,
+        WaitForGuildEventStream, WaitForMessageFuture, WaitForMessageStream, WaitForRawFuture,
+        WaitForRawStream, WaitForReactionFuture, WaitForReactionStream, WaitForThreadFuture,
+        WaitForThreadStream,
     };
     use futures_core::Stream;
     use static_assertions::assert_impl_all;
     use std::{fmt::Debug, future::Future};
+    use tokio_stream::Iter;
+    use twilight_model::gateway::event::Event;
 
     assert_impl_all!(WaitForEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForGuildEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForMessageFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForRawFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForReactionFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForThreadFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForGuildEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForMessageStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForRawStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForReactionStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForThreadStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(ProcessStream<Iter<std::vec::IntoIter<Event>>>: Debug, Stream, Send, Sync);
 }
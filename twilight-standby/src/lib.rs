@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(
     clippy::missing_const_for_fn,
     clippy::missing_docs_in_private_items,
@@ -12,18 +13,25 @@
     clippy::unnecessary_wraps
 )]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod future;
 
 use self::future::{
-    WaitForComponentFuture, WaitForComponentStream, WaitForEventFuture, WaitForEventStream,
-    WaitForGuildEventFuture, WaitForGuildEventStream, WaitForMessageFuture, WaitForMessageStream,
-    WaitForReactionFuture, WaitForReactionStream,
+    ProcessStream, WaitForComponentFuture, WaitForComponentStream, WaitForEventFuture,
+    WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream, WaitForMessageFuture,
+    WaitForMessageStream, WaitForRawFuture, WaitForRawStream, WaitForReactionFuture,
+    WaitForReactionStream, WaitForThreadFuture, WaitForThreadStream,
 };
 use dashmap::DashMap;
+use futures_core::Stream;
 use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     hash::Hash,
-    sync::atomic::{AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 use tokio::sync::{
     mpsc::{self, UnboundedReceiver, UnboundedSender as MpscSender},
@@ -32,11 +40,11 @@ use tokio::sync::{
 use twilight_model::{
     application::interaction::{Interaction, InteractionType},
     gateway::{
-        event::Event,
-        payload::incoming::{MessageCreate, ReactionAdd},
+        event::{Event, EventType},
+        payload::incoming::{InteractionCreate, MessageCreate, ReactionAdd, ThreadCreate},
     },
     id::{
-        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
         Id,
     },
 };
@@ -52,6 +60,9 @@ enum Sender<E> {
     Future(OneshotSender<E>),
     /// Bystander is a stream and the sender is an MPSC.
     Stream(MpscSender<E>),
+    /// Bystander is a blocked thread and the sender is a synchronous channel.
+    #[cfg(feature = "blocking")]
+    Blocking(blocking::BlockingSender<E>),
 }
 
 impl<E> Sender<E> {
@@ -60,6 +71,8 @@ impl<E> Sender<E> {
         match self {
             Self::Future(sender) => sender.is_closed(),
             Self::Stream(sender) => sender.is_closed(),
+            #[cfg(feature = "blocking")]
+            Self::Blocking(sender) => sender.is_closed(),
         }
     }
 }
@@ -67,6 +80,10 @@ impl<E> Sender<E> {
 /// Registration for a caller to wait for an event based on a predicate
 /// function.
 struct Bystander<T> {
+    /// Whether matching an event claims it.
+    ///
+    /// Only future bystanders may claim events.
+    claims: bool,
     /// Predicate check to perform on an event.
     func: Box<dyn Fn(&T) -> bool + Send + Sync>,
     /// [`Sender::Future`]s consume themselves once upon sending so the sender
@@ -77,6 +94,7 @@ struct Bystander<T> {
 impl<T: Debug> Debug for Bystander<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Bystander")
+            .field("claims", &self.claims)
             .field("func", &"<dyn Fn(&T) -> bool>")
             .field("sender", &self.sender)
             .finish()
@@ -118,7 +136,8 @@ pub struct Standby {
     /// List of component bystanders where the ID of the message is known
     /// beforehand.
     components: DashMap<Id<MessageMarker>, Vec<Bystander<Interaction>>>,
-    /// Bystanders for any event that may not be in any particular guild.
+    /// Bystanders for any event that may not be in any particular guild or of
+    /// any particular type.
     ///
     /// The key is generated via [`event_counter`].
     ///
@@ -128,14 +147,28 @@ pub struct Standby {
     ///
     /// [`events`]: Self::events
     event_counter: AtomicU64,
+    /// List of bystanders where the type of the event is known beforehand.
+    event_types: DashMap<EventType, Vec<Bystander<Event>>>,
     /// List of bystanders where the ID of the guild is known beforehand.
     guilds: DashMap<Id<GuildMarker>, Vec<Bystander<Event>>>,
     /// List of message bystanders where the ID of the channel is known
     /// beforehand.
     messages: DashMap<Id<ChannelMarker>, Vec<Bystander<MessageCreate>>>,
+    /// List of raw payload bystanders where the name of the event is known
+    /// beforehand.
+    ///
+    /// Kept separate from the typed maps so that processing deserialized
+    /// events never touches it.
+    raw: DashMap<Box<str>, Vec<Bystander<String>>>,
     /// List of reaction bystanders where the ID of the message is known
     /// beforehand.
     reactions: DashMap<Id<MessageMarker>, Vec<Bystander<ReactionAdd>>>,
+    /// List of thread bystanders where the ID of the parent channel is known
+    /// beforehand.
+    threads: DashMap<Id<ChannelMarker>, Vec<Bystander<ThreadCreate>>>,
+    /// List of component bystanders where the ID of the user is known
+    /// beforehand.
+    user_components: DashMap<Id<UserMarker>, Vec<Bystander<Interaction>>>,
 }
 
 impl Standby {
@@ -170,43 +203,201 @@ impl Standby {
         let mut completions = ProcessResults::new();
 
         match event {
-            Event::InteractionCreate(e) => {
-                if e.kind == InteractionType::MessageComponent {
-                    if let Some(message) = &e.message {
-                        completions.add_with(&Self::process_specific_event(
-                            &self.components,
-                            message.id,
-                            e,
-                        ));
-                    }
-                }
-            }
-            Event::MessageCreate(e) => {
-                completions.add_with(&Self::process_specific_event(
-                    &self.messages,
-                    e.0.channel_id,
-                    e,
-                ));
-            }
-            Event::ReactionAdd(e) => {
-                completions.add_with(&Self::process_specific_event(
-                    &self.reactions,
-                    e.0.message_id,
-                    e,
-                ));
-            }
+            Event::InteractionCreate(e) => completions.add_with(&self.process_interaction(e)),
+            Event::MessageCreate(e) => completions.add_with(&self.process_message(e)),
+            Event::ReactionAdd(e) => completions.add_with(&self.process_reaction_add(e)),
+            Event::ThreadCreate(e) => completions.add_with(&self.process_thread(e)),
             _ => {}
         }
 
         if let Some(guild_id) = event.guild_id() {
-            completions.add_with(&Self::process_specific_event(&self.guilds, guild_id, event));
+            completions.add_with(&Self::process_specific_event(
+                &self.guilds,
+                &guild_id,
+                event,
+            ));
         }
 
+        completions.add_with(&Self::process_specific_event(
+            &self.event_types,
+            &event.kind(),
+            event,
+        ));
+
         completions.add_with(&Self::process_event(&self.events, event));
 
         completions
     }
 
+    /// Process an interaction, calling any component bystanders that might be
+    /// waiting on it.
+    ///
+    /// This is useful when interactions are received without being wrapped in
+    /// an [`Event`], such as from a message broker. Only bystanders created
+    /// via [`wait_for_component`], [`wait_for_user_component`], and their
+    /// stream variants are processed; bystanders waiting on guild events or
+    /// any event, such as via [`wait_for`] or [`wait_for_event`], require
+    /// calling [`process`].
+    ///
+    /// Bystanders waiting on the interaction's message and those waiting on
+    /// its user are independent of each other, so an interaction matching
+    /// both is sent to both.
+    ///
+    /// Calling [`process`] with an [`Event::InteractionCreate`] already
+    /// processes the interaction with this method.
+    ///
+    /// [`process`]: Self::process
+    /// [`wait_for_component`]: Self::wait_for_component
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_user_component`]: Self::wait_for_user_component
+    /// [`wait_for`]: Self::wait_for
+    pub fn process_interaction(&self, event: &InteractionCreate) -> ProcessResults {
+        let mut completions = ProcessResults::new();
+
+        if event.kind != InteractionType::MessageComponent {
+            return completions;
+        }
+
+        if let Some(message) = &event.message {
+            completions.add_with(&Self::process_specific_event(
+                &self.components,
+                &message.id,
+                event,
+            ));
+        }
+
+        if let Some(user_id) = event.author_id() {
+            completions.add_with(&Self::process_specific_event(
+                &self.user_components,
+                &user_id,
+                event,
+            ));
+        }
+
+        completions
+    }
+
+    /// Process a message, calling any message bystanders that might be
+    /// waiting on it.
+    ///
+    /// This is useful when messages are received without being wrapped in an
+    /// [`Event`], such as from a message broker. Only bystanders created via
+    /// [`wait_for_message`] and [`wait_for_message_stream`] are processed;
+    /// bystanders waiting on guild events or any event, such as via
+    /// [`wait_for`] or [`wait_for_event`], require calling [`process`].
+    ///
+    /// Calling [`process`] with an [`Event::MessageCreate`] already processes
+    /// the message with this method.
+    ///
+    /// [`process`]: Self::process
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_message_stream`]: Self::wait_for_message_stream
+    /// [`wait_for_message`]: Self::wait_for_message
+    /// [`wait_for`]: Self::wait_for
+    pub fn process_message(&self, event: &MessageCreate) -> ProcessResults {
+        Self::process_specific_event(&self.messages, &event.channel_id, event)
+    }
+
+    /// Process a reaction addition, calling any reaction bystanders that
+    /// might be waiting on it.
+    ///
+    /// This is useful when reactions are received without being wrapped in an
+    /// [`Event`], such as from a message broker. Only bystanders created via
+    /// [`wait_for_reaction`] and [`wait_for_reaction_stream`] are processed;
+    /// bystanders waiting on guild events or any event, such as via
+    /// [`wait_for`] or [`wait_for_event`], require calling [`process`].
+    ///
+    /// Calling [`process`] with an [`Event::ReactionAdd`] already processes
+    /// the reaction with this method.
+    ///
+    /// [`process`]: Self::process
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_reaction_stream`]: Self::wait_for_reaction_stream
+    /// [`wait_for_reaction`]: Self::wait_for_reaction
+    /// [`wait_for`]: Self::wait_for
+    pub fn process_reaction_add(&self, event: &ReactionAdd) -> ProcessResults {
+        Self::process_specific_event(&self.reactions, &event.message_id, event)
+    }
+
+    /// Process a thread creation, calling any thread bystanders that might be
+    /// waiting on it.
+    ///
+    /// Only bystanders created via [`wait_for_thread`] and
+    /// [`wait_for_thread_stream`] are processed. Threads without a parent
+    /// channel are ignored. Refer to [`process_message`] for more information.
+    ///
+    /// [`process_message`]: Self::process_message
+    /// [`wait_for_thread_stream`]: Self::wait_for_thread_stream
+    /// [`wait_for_thread`]: Self::wait_for_thread
+    pub fn process_thread(&self, event: &ThreadCreate) -> ProcessResults {
+        // Threads are always created in a parent channel, but the field is
+        // optional on the model.
+        let Some(parent_id) = event.parent_id else {
+            return ProcessResults::new();
+        };
+
+        Self::process_specific_event(&self.threads, &parent_id, event)
+    }
+
+    /// Process a raw gateway payload, calling any raw bystanders that might be
+    /// waiting on it.
+    ///
+    /// Only bystanders created via [`wait_for_raw`] and [`wait_for_raw_stream`]
+    /// are processed. [`process`] never calls this method, so payloads must be
+    /// passed in separately, such as before deserializing them. The event name
+    /// is the `t` field of the payload, for example `MESSAGE_CREATE`.
+    ///
+    /// Payloads are only copied when a bystander is waiting on the event name.
+    ///
+    /// [`process`]: Self::process
+    /// [`wait_for_raw_stream`]: Self::wait_for_raw_stream
+    /// [`wait_for_raw`]: Self::wait_for_raw
+    pub fn process_raw(&self, event_name: &str, payload: &str) -> ProcessResults {
+        if !self.raw.contains_key(event_name) {
+            return ProcessResults::new();
+        }
+
+        Self::process_specific_event(&self.raw, &Box::from(event_name), &payload.to_owned())
+    }
+
+    /// Wrap a stream of events, [processing] each event before yielding it.
+    ///
+    /// This is an alternative to calling [`process`] at the top of every event
+    /// loop. Events are processed as they're received and are then yielded
+    /// unchanged, in the same order, without being buffered; dropping the
+    /// returned stream in between polls loses no events.
+    ///
+    /// # Examples
+    ///
+    /// Process events before handling them:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::sync::Arc;
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::gateway::event::Event;
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Arc::new(Standby::new());
+    /// # let events = tokio_stream::iter(vec![Event::Resumed]);
+    /// let mut events = standby.clone().process_stream(events);
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("received event: {:?}", event.kind());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [processing]: Self::process
+    /// [`process`]: Self::process
+    pub fn process_stream<S: Stream<Item = Event>>(self: Arc<Self>, stream: S) -> ProcessStream<S> {
+        ProcessStream {
+            standby: self,
+            stream: Box::pin(stream),
+        }
+    }
+
     /// Wait for an event in a certain guild.
     ///
     /// To wait for multiple guild events matching the given predicate use
@@ -357,6 +548,7 @@ impl Standby {
         self.events.insert(
             self.next_event_id(),
             Bystander {
+                claims: false,
                 func: check.into(),
                 sender: Some(Sender::Future(tx)),
             },
@@ -416,6 +608,7 @@ impl Standby {
         self.events.insert(
             self.next_event_id(),
             Bystander {
+                claims: false,
                 func: check.into(),
                 sender: Some(Sender::Stream(tx)),
             },
@@ -424,6 +617,109 @@ impl Standby {
         WaitForEventStream { rx }
     }
 
+    /// Wait for an event of a certain type.
+    ///
+    /// Unlike [`wait_for_event`], the predicate is only called for events of
+    /// the given type, which avoids calling every predicate for every event
+    /// when many bystanders are registered.
+    ///
+    /// To wait for multiple events matching the given predicate use
+    /// [`wait_for_event_typed_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a [`Ready`] event for shard 5:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::gateway::event::{Event, EventType};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let ready = standby
+    ///     .wait_for_event_typed(EventType::Ready, |event: &Event| {
+    ///         if let Event::Ready(ready) = event {
+    ///             ready.shard.map_or(false, |id| id.number() == 5)
+    ///         } else {
+    ///             false
+    ///         }
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    /// [`wait_for_event`]: Self::wait_for_event
+    /// [`wait_for_event_typed_stream`]: Self::wait_for_event_typed_stream
+    pub fn wait_for_event_typed<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        event_type: EventType,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventFuture {
+        tracing::trace!(?event_type, "waiting for event of type");
+
+        WaitForEventFuture {
+            rx: Self::insert_future(&self.event_types, event_type, check),
+        }
+    }
+
+    /// Wait for a stream of events of a certain type.
+    ///
+    /// Unlike [`wait_for_event_stream`], the predicate is only called for
+    /// events of the given type.
+    ///
+    /// To wait for only one event matching the given predicate use
+    /// [`wait_for_event_typed`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple [`BanAdd`] events:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::gateway::event::{Event, EventType};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut events = standby.wait_for_event_typed_stream(EventType::BanAdd, |_: &Event| true);
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("got event with guild id {:?}", event.guild_id());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`BanAdd`]: twilight_model::gateway::payload::incoming::BanAdd
+    /// [`wait_for_event_stream`]: Self::wait_for_event_stream
+    /// [`wait_for_event_typed`]: Self::wait_for_event_typed
+    pub fn wait_for_event_typed_stream<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        event_type: EventType,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventStream {
+        tracing::trace!(?event_type, "waiting for events of type");
+
+        WaitForEventStream {
+            rx: Self::insert_stream(&self.event_types, event_type, check),
+        }
+    }
+
     /// Wait for a message in a certain channel.
     ///
     /// To wait for multiple messages matching the given predicate use
@@ -471,6 +767,60 @@ impl Standby {
         }
     }
 
+    /// Wait for a message in a certain channel, claiming it once matched.
+    ///
+    /// This is equivalent to [`wait_for_message`], except that a matched
+    /// message is reported as claimed via [`ProcessResults::claimed`]. This
+    /// lets the event loop skip further handling of messages that the caller
+    /// considers handled, such as a reply to a prompt.
+    ///
+    /// # Examples
+    ///
+    /// Wait for a reply in channel 123 by user 456, and skip command parsing
+    /// for it:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     gateway::{event::Event, payload::incoming::MessageCreate},
+    ///     id::Id,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let author_id = Id::new(456);
+    /// let reply = standby.wait_for_message_claiming(Id::new(123), move |event: &MessageCreate| {
+    ///     event.author.id == author_id
+    /// });
+    ///
+    /// # let event = Event::Resumed;
+    /// if !standby.process(&event).claimed() {
+    ///     // Parse commands.
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_message`]: Self::wait_for_message
+    pub fn wait_for_message_claiming<F: Fn(&MessageCreate) -> bool + Send + Sync + 'static>(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForMessageFuture {
+        tracing::trace!(%channel_id, "waiting for message in channel to claim");
+
+        WaitForMessageFuture {
+            rx: Self::insert_future_with_claim(&self.messages, channel_id, check, true),
+        }
+    }
+
     /// Wait for a stream of message in a certain channel.
     ///
     /// To wait for only one message matching the given predicate use
@@ -568,6 +918,32 @@ impl Standby {
         }
     }
 
+    /// Wait for a reaction on a certain message, claiming it once matched.
+    ///
+    /// This is equivalent to [`wait_for_reaction`], except that a matched
+    /// reaction is reported as claimed via [`ProcessResults::claimed`].
+    /// Refer to [`wait_for_message_claiming`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_message_claiming`]: Self::wait_for_message_claiming
+    /// [`wait_for_reaction`]: Self::wait_for_reaction
+    pub fn wait_for_reaction_claiming<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionFuture {
+        tracing::trace!(%message_id, "waiting for reaction on message to claim");
+
+        WaitForReactionFuture {
+            rx: Self::insert_future_with_claim(&self.reactions, message_id, check, true),
+        }
+    }
+
     /// Wait for a stream of reactions on a certain message.
     ///
     /// To wait for only one reaction matching the given predicate use
@@ -620,6 +996,100 @@ impl Standby {
         }
     }
 
+    /// Wait for a thread to be created in a certain parent channel, such as a
+    /// post in a forum channel.
+    ///
+    /// To wait for multiple threads matching the given predicate use
+    /// [`wait_for_thread_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a post in forum channel 123 by user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{gateway::payload::incoming::ThreadCreate, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let channel_id = Id::new(123);
+    /// let owner_id = Id::new(456);
+    ///
+    /// let thread = standby
+    ///     .wait_for_thread(channel_id, move |event: &ThreadCreate| {
+    ///         event.owner_id == Some(owner_id)
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_thread_stream`]: Self::wait_for_thread_stream
+    pub fn wait_for_thread<F: Fn(&ThreadCreate) -> bool + Send + Sync + 'static>(
+        &self,
+        parent_id: Id<ChannelMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForThreadFuture {
+        tracing::trace!(%parent_id, "waiting for thread in channel");
+
+        WaitForThreadFuture {
+            rx: Self::insert_future(&self.threads, parent_id, check),
+        }
+    }
+
+    /// Wait for a stream of threads created in a certain parent channel.
+    ///
+    /// To wait for only one thread matching the given predicate use
+    /// [`wait_for_thread`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple posts in forum channel 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::{gateway::payload::incoming::ThreadCreate, id::Id};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let channel_id = Id::new(123);
+    ///
+    /// let mut threads = standby.wait_for_thread_stream(channel_id, |_: &ThreadCreate| true);
+    ///
+    /// while let Some(thread) = threads.next().await {
+    ///     println!("got a post: {:?}", thread.name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_thread`]: Self::wait_for_thread
+    pub fn wait_for_thread_stream<F: Fn(&ThreadCreate) -> bool + Send + Sync + 'static>(
+        &self,
+        parent_id: Id<ChannelMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForThreadStream {
+        tracing::trace!(%parent_id, "waiting for thread in channel");
+
+        WaitForThreadStream {
+            rx: Self::insert_stream(&self.threads, parent_id, check),
+        }
+    }
+
     /// Wait for a component on a certain message.
     ///
     /// Returns a `Canceled` error if the `Standby` struct was dropped.
@@ -647,67 +1117,260 @@ impl Standby {
     /// # Ok(()) }
     /// ```
     ///
-    /// [`wait_for_component_stream`]: Self::wait_for_component_stream
-    pub fn wait_for_component<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+    /// [`wait_for_component_stream`]: Self::wait_for_component_stream
+    pub fn wait_for_component<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentFuture {
+        tracing::trace!(%message_id, "waiting for component on message");
+
+        WaitForComponentFuture {
+            rx: Self::insert_future(&self.components, message_id, check),
+        }
+    }
+
+    /// Wait for a component on a certain message, claiming it once matched.
+    ///
+    /// This is equivalent to [`wait_for_component`], except that a matched
+    /// component is reported as claimed via [`ProcessResults::claimed`].
+    /// Refer to [`wait_for_message_claiming`] for more information.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// [`wait_for_component`]: Self::wait_for_component
+    /// [`wait_for_message_claiming`]: Self::wait_for_message_claiming
+    pub fn wait_for_component_claiming<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentFuture {
+        tracing::trace!(%message_id, "waiting for component on message to claim");
+
+        WaitForComponentFuture {
+            rx: Self::insert_future_with_claim(&self.components, message_id, check, true),
+        }
+    }
+
+    /// Wait for a stream of components on a certain message.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one component matching the given predicate,
+    /// use [`wait_for_component`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple button components on message 123 with a `custom_id` of
+    /// "Click":
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use tokio_stream::StreamExt;
+    /// use twilight_model::{
+    ///     application::interaction::{Interaction, InteractionData},
+    ///     id::Id,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    /// let message_id = Id::new(123);
+    ///
+    /// let mut components = standby.wait_for_component_stream(message_id, |event: &Interaction| {
+    ///     if let Some(InteractionData::MessageComponent(data)) = &event.data {
+    ///         data.custom_id == "Click".to_string()
+    ///     } else {
+    ///         false
+    ///     }
+    /// });
+    ///
+    /// while let Some(component) = components.next().await {
+    ///     println!("got a component by {}", component.author_id().unwrap());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`wait_for_component`]: Self::wait_for_component
+    pub fn wait_for_component_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: Id<MessageMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentStream {
+        tracing::trace!(%message_id, "waiting for component on message");
+
+        WaitForComponentStream {
+            rx: Self::insert_stream(&self.components, message_id, check),
+        }
+    }
+
+    /// Wait for a component by a certain user on any message.
+    ///
+    /// This is useful when a user is led through multiple messages, such as
+    /// one ephemeral message per step, where the ID of the next message isn't
+    /// known beforehand. A component matching both a bystander created via
+    /// [`wait_for_component`] and one created via this method is sent to
+    /// both.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for multiple components matching the given
+    /// predicate, use [`wait_for_user_component_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a component by user 456 with a `custom_id` of "next":
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     application::interaction::{Interaction, InteractionData},
+    ///     id::Id,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    /// let user_id = Id::new(456);
+    ///
+    /// let component = standby
+    ///     .wait_for_user_component(user_id, |event: &Interaction| {
+    ///         matches!(
+    ///             &event.data,
+    ///             Some(InteractionData::MessageComponent(data)) if data.custom_id == "next",
+    ///         )
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`wait_for_component`]: Self::wait_for_component
+    /// [`wait_for_user_component_stream`]: Self::wait_for_user_component_stream
+    pub fn wait_for_user_component<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        user_id: Id<UserMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentFuture {
+        tracing::trace!(%user_id, "waiting for component by user");
+
+        WaitForComponentFuture {
+            rx: Self::insert_future(&self.user_components, user_id, check),
+        }
+    }
+
+    /// Wait for a stream of components by a certain user on any message.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one component matching the given
+    /// predicate, use [`wait_for_user_component`].
+    ///
+    /// [`wait_for_user_component`]: Self::wait_for_user_component
+    pub fn wait_for_user_component_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        user_id: Id<UserMarker>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentStream {
+        tracing::trace!(%user_id, "waiting for component by user");
+
+        WaitForComponentStream {
+            rx: Self::insert_stream(&self.user_components, user_id, check),
+        }
+    }
+
+    /// Wait for a raw payload of a certain event name.
+    ///
+    /// Payloads must be passed to [`process_raw`] for this future to resolve.
+    /// The check receives the payload as-is, without it being deserialized.
+    ///
+    /// To wait for multiple payloads matching the given predicate use
+    /// [`wait_for_raw_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a `VOICE_CHANNEL_EFFECT_SEND` payload mentioning channel 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let payload = standby
+    ///     .wait_for_raw("VOICE_CHANNEL_EFFECT_SEND", |payload: &str| {
+    ///         payload.contains(r#""channel_id":"123""#)
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`process_raw`]: Self::process_raw
+    /// [`wait_for_raw_stream`]: Self::wait_for_raw_stream
+    pub fn wait_for_raw<F: Fn(&str) -> bool + Send + Sync + 'static>(
         &self,
-        message_id: Id<MessageMarker>,
+        event_name: impl Into<Box<str>>,
         check: impl Into<Box<F>>,
-    ) -> WaitForComponentFuture {
-        tracing::trace!(%message_id, "waiting for component on message");
-
-        WaitForComponentFuture {
-            rx: Self::insert_future(&self.components, message_id, check),
+    ) -> WaitForRawFuture {
+        let event_name = event_name.into();
+        tracing::trace!(%event_name, "waiting for raw payload");
+        let check = check.into();
+
+        WaitForRawFuture {
+            rx: Self::insert_future(&self.raw, event_name, move |payload: &String| {
+                check(payload)
+            }),
         }
     }
 
-    /// Wait for a stream of components on a certain message.
-    ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Wait for a stream of raw payloads of a certain event name.
     ///
-    /// If you need to wait for only one component matching the given predicate,
-    /// use [`wait_for_component`].
+    /// To wait for only one payload matching the given predicate use
+    /// [`wait_for_raw`].
     ///
     /// # Examples
     ///
-    /// Wait for multiple button components on message 123 with a `custom_id` of
-    /// "Click":
+    /// Print every `VOICE_CHANNEL_EFFECT_SEND` payload:
     ///
     /// ```no_run
-    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use tokio_stream::StreamExt;
-    /// use twilight_model::{
-    ///     application::interaction::{Interaction, InteractionData},
-    ///     id::Id,
-    /// };
     /// use twilight_standby::Standby;
     ///
     /// let standby = Standby::new();
-    /// let message_id = Id::new(123);
     ///
-    /// let mut components = standby.wait_for_component_stream(message_id, |event: &Interaction| {
-    ///     if let Some(InteractionData::MessageComponent(data)) = &event.data {
-    ///         data.custom_id == "Click".to_string()
-    ///     } else {
-    ///         false
-    ///     }
-    /// });
+    /// let mut payloads = standby.wait_for_raw_stream("VOICE_CHANNEL_EFFECT_SEND", |_: &str| true);
     ///
-    /// while let Some(component) = components.next().await {
-    ///     println!("got a component by {}", component.author_id().unwrap());
+    /// while let Some(payload) = payloads.next().await {
+    ///     println!("got a payload: {payload}");
     /// }
     /// # Ok(()) }
     /// ```
     ///
-    /// [`wait_for_component`]: Self::wait_for_component
-    pub fn wait_for_component_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_raw`]: Self::wait_for_raw
+    pub fn wait_for_raw_stream<F: Fn(&str) -> bool + Send + Sync + 'static>(
         &self,
-        message_id: Id<MessageMarker>,
+        event_name: impl Into<Box<str>>,
         check: impl Into<Box<F>>,
-    ) -> WaitForComponentStream {
-        tracing::trace!(%message_id, "waiting for component on message");
-
-        WaitForComponentStream {
-            rx: Self::insert_stream(&self.components, message_id, check),
+    ) -> WaitForRawStream {
+        let event_name = event_name.into();
+        tracing::trace!(%event_name, "waiting for raw payloads");
+        let check = check.into();
+
+        WaitForRawStream {
+            rx: Self::insert_stream(&self.raw, event_name, move |payload: &String| {
+                check(payload)
+            }),
         }
     }
 
@@ -721,11 +1384,23 @@ impl Standby {
         map: &BystanderMap<K, V>,
         id: K,
         check: impl Into<Box<F>>,
+    ) -> Receiver<V> {
+        Self::insert_future_with_claim(map, id, check, false)
+    }
+
+    /// Append a new future bystander into a map according to the ID, which
+    /// claims the events it matches if `claims` is true.
+    fn insert_future_with_claim<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
+        map: &BystanderMap<K, V>,
+        id: K,
+        check: impl Into<Box<F>>,
+        claims: bool,
     ) -> Receiver<V> {
         let (tx, rx) = oneshot::channel();
 
         let mut entry = map.entry(id).or_default();
         entry.push(Bystander {
+            claims,
             func: check.into(),
             sender: Some(Sender::Future(tx)),
         });
@@ -743,6 +1418,7 @@ impl Standby {
 
         let mut entry = map.entry(id).or_default();
         entry.push(Bystander {
+            claims: false,
             func: check.into(),
             sender: Some(Sender::Stream(tx)),
         });
@@ -775,33 +1451,30 @@ impl Standby {
         results
     }
 
-    /// Process a general event that is either of a particular type or in a
-    /// particular guild.
+    /// Process an event with bystanders keyed by a particular ID, such as the
+    /// event's type or guild.
     #[tracing::instrument(level = "trace")]
-    fn process_specific_event<
-        K: Debug + Display + Eq + Hash + PartialEq + 'static,
-        V: Clone + Debug,
-    >(
+    fn process_specific_event<K: Debug + Eq + Hash + PartialEq + 'static, V: Clone + Debug>(
         map: &DashMap<K, Vec<Bystander<V>>>,
-        guild_id: K,
+        id: &K,
         event: &V,
     ) -> ProcessResults {
-        // Iterate over a guild's bystanders and mark it for removal if there
-        // are no bystanders remaining.
-        let (remove_guild, results) = if let Some(mut bystanders) = map.get_mut(&guild_id) {
+        // Iterate over an ID's bystanders and mark it for removal if there are
+        // no bystanders remaining.
+        let (remove_id, results) = if let Some(mut bystanders) = map.get_mut(id) {
             let results = Self::bystander_iter(&mut bystanders, event);
 
             (bystanders.is_empty(), results)
         } else {
-            tracing::trace!(%guild_id, "guild has no event bystanders");
+            tracing::trace!(?id, "id has no event bystanders");
 
             return ProcessResults::new();
         };
 
-        if remove_guild {
-            tracing::trace!(%guild_id, "removing guild from map");
+        if remove_id {
+            tracing::trace!(?id, "removing id from map");
 
-            map.remove(&guild_id);
+            map.remove(id);
         }
 
         results
@@ -910,9 +1583,16 @@ impl Standby {
                 // we're going to be tossing out the bystander anyway.
                 drop(tx.send(event.clone()));
 
-                tracing::trace!("bystander matched event, indicating for removal");
+                tracing::trace!(
+                    claims = bystander.claims,
+                    "bystander matched event, indicating for removal"
+                );
 
-                ProcessStatus::SentFuture
+                if bystander.claims {
+                    ProcessStatus::ClaimedFuture
+                } else {
+                    ProcessStatus::SentFuture
+                }
             }
             Sender::Stream(tx) => {
                 // If we can send an event to the receiver and the channel is
@@ -928,12 +1608,22 @@ impl Standby {
                     ProcessStatus::Dropped
                 }
             }
+            #[cfg(feature = "blocking")]
+            Sender::Blocking(tx) => {
+                tx.send(event.clone());
+
+                tracing::trace!("bystander matched event, indicating for removal");
+
+                ProcessStatus::SentFuture
+            }
         }
     }
 }
 /// Number of [`Standby`] calls that were completed.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ProcessResults {
+    /// Whether a claiming future bystander was sent the event.
+    claimed: bool,
     /// Number of bystanders that were dropped due to the receiving end
     /// dropping.
     dropped: usize,
@@ -947,12 +1637,32 @@ impl ProcessResults {
     /// Create a new set of zeroed out results.
     const fn new() -> Self {
         Self {
+            claimed: false,
             dropped: 0,
             fulfilled: 0,
             sent: 0,
         }
     }
 
+    /// Whether a [`Standby`] call claimed the event.
+    ///
+    /// Only futures created via the claiming methods, such as
+    /// [`Standby::wait_for_message_claiming`], claim the events they match.
+    /// The caller may use this to skip further handling of events that a
+    /// future already considers handled.
+    ///
+    /// Streams never claim events, as they don't know whether the event is the
+    /// last one they're interested in.
+    ///
+    /// **Caveat**: like [`fulfilled`], the event is considered claimed once it
+    /// has been sent over the channel, even if the receiver drops shortly
+    /// after.
+    ///
+    /// [`fulfilled`]: Self::fulfilled
+    pub const fn claimed(&self) -> bool {
+        self.claimed
+    }
+
     /// Number of [`Standby`] calls where the receiver had already dropped their
     /// end.
     ///
@@ -1003,6 +1713,7 @@ impl ProcessResults {
 
     /// Add another set of results to this set.
     fn add_with(&mut self, other: &Self) {
+        self.claimed |= other.claimed;
         self.dropped = self.dropped.saturating_add(other.dropped);
         self.fulfilled = self.fulfilled.saturating_add(other.fulfilled);
         self.sent = self.sent.saturating_add(other.sent);
@@ -1014,6 +1725,10 @@ impl ProcessResults {
             ProcessStatus::Dropped => {
                 self.dropped += 1;
             }
+            ProcessStatus::ClaimedFuture => {
+                self.claimed = true;
+                self.fulfilled += 1;
+            }
             ProcessStatus::SentFuture => {
                 self.fulfilled += 1;
             }
@@ -1031,6 +1746,8 @@ enum ProcessStatus {
     /// Call matched but already matched previously and was not removed, so the
     /// subject must be removed and not counted towards results.
     AlreadyComplete,
+    /// Call matched a oneshot that claims the events it matches.
+    ClaimedFuture,
     /// Call matched but the receiver dropped their end.
     Dropped,
     /// Call matched a oneshot.
@@ -1046,7 +1763,7 @@ impl ProcessStatus {
     const fn is_complete(self) -> bool {
         matches!(
             self,
-            Self::AlreadyComplete | Self::Dropped | Self::SentFuture
+            Self::AlreadyComplete | Self::ClaimedFuture | Self::Dropped | Self::SentFuture
         )
     }
 }
@@ -1055,9 +1772,19 @@ impl ProcessStatus {
 mod tests {
     #![allow(clippy::non_ascii_literal)]
 
+    #[cfg(feature = "blocking")]
+    use crate::blocking::TimeoutOrCanceledType;
     use crate::Standby;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    #[cfg(feature = "blocking")]
+    use std::{thread, time::Duration};
     use tokio_stream::StreamExt;
     use twilight_gateway::{Event, EventType};
     use twilight_model::{
@@ -1070,7 +1797,9 @@ mod tests {
             Channel, ChannelType,
         },
         gateway::{
-            payload::incoming::{InteractionCreate, MessageCreate, ReactionAdd, Ready, RoleDelete},
+            payload::incoming::{
+                InteractionCreate, MessageCreate, ReactionAdd, Ready, RoleDelete, ThreadCreate,
+            },
             GatewayReaction, ShardId,
         },
         guild::Permissions,
@@ -1105,6 +1834,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "twilight".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -1239,6 +1969,7 @@ mod tests {
                 mfa_enabled: None,
                 name: "twilight".to_owned(),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 system: None,
                 verified: None,
@@ -1246,6 +1977,46 @@ mod tests {
         }
     }
 
+    fn thread(parent_id: Option<u64>) -> ThreadCreate {
+        ThreadCreate(Channel {
+            application_id: None,
+            applied_tags: Some(Vec::new()),
+            available_tags: None,
+            bitrate: None,
+            default_auto_archive_duration: None,
+            default_forum_layout: None,
+            default_reaction_emoji: None,
+            default_sort_order: None,
+            default_thread_rate_limit_per_user: None,
+            flags: None,
+            guild_id: Some(Id::new(1)),
+            icon: None,
+            id: Id::new(500),
+            invitable: None,
+            kind: ChannelType::PublicThread,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            managed: None,
+            member: None,
+            member_count: Some(1),
+            message_count: Some(0),
+            name: Some("ticket".to_owned()),
+            newly_created: Some(true),
+            nsfw: None,
+            owner_id: Some(Id::new(2)),
+            parent_id: parent_id.map(Id::new),
+            permission_overwrites: None,
+            position: None,
+            rate_limit_per_user: None,
+            recipients: None,
+            rtc_region: None,
+            thread_metadata: None,
+            topic: None,
+            user_limit: None,
+            video_quality_mode: None,
+        })
+    }
+
     /// Test that if a receiver drops their end, the result properly counts the
     /// statistic.
     #[tokio::test]
@@ -1395,6 +2166,7 @@ mod tests {
             user: CurrentUser {
                 accent_color: None,
                 avatar: None,
+                avatar_decoration_data: None,
                 banner: None,
                 bot: false,
                 discriminator: 1,
@@ -1404,6 +2176,7 @@ mod tests {
                 name: "twilight".to_owned(),
                 verified: Some(false),
                 premium_type: None,
+                primary_guild: None,
                 public_flags: None,
                 flags: None,
                 locale: None,
@@ -1439,6 +2212,82 @@ mod tests {
         assert!(standby.events.is_empty());
     }
 
+    /// Test basic functionality of the [`Standby::wait_for_event_typed`]
+    /// method.
+    #[tokio::test]
+    async fn test_wait_for_event_typed() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_event_typed(EventType::Resumed, |_: &Event| true);
+        assert!(!standby.event_types.is_empty());
+        assert!(standby.events.is_empty());
+
+        standby.process(&Event::GatewayReconnect);
+        assert!(!standby.event_types.is_empty());
+
+        standby.process(&Event::Resumed);
+        assert_eq!(Event::Resumed, wait.await.unwrap());
+        assert!(standby.event_types.is_empty());
+    }
+
+    /// Test basic functionality of the [`Standby::wait_for_event_typed_stream`]
+    /// method.
+    #[tokio::test]
+    async fn test_wait_for_event_typed_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_event_typed_stream(EventType::Resumed, |_: &Event| true);
+        standby.process(&Event::Resumed);
+        assert_eq!(stream.next().await, Some(Event::Resumed));
+        assert!(!standby.event_types.is_empty());
+        drop(stream);
+        standby.process(&Event::Resumed);
+        assert!(standby.event_types.is_empty());
+    }
+
+    /// Test that the predicates of typed bystanders are only called for events
+    /// of their type.
+    #[tokio::test]
+    async fn test_wait_for_event_typed_skips_other_types() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let standby = Standby::new();
+        let _wait = standby.wait_for_event_typed(EventType::BanAdd, {
+            let calls = Arc::clone(&calls);
+
+            move |_: &Event| {
+                calls.fetch_add(1, Ordering::Relaxed);
+
+                false
+            }
+        });
+
+        let results = standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert_eq!(0, calls.load(Ordering::Relaxed));
+        assert_eq!(0, results.matched());
+        assert!(!standby.event_types.is_empty());
+    }
+
+    /// Test that [`Standby::process_stream`] processes events and yields them
+    /// unchanged and in order.
+    #[tokio::test]
+    async fn test_process_stream() {
+        let standby = Arc::new(Standby::new());
+        let wait = standby.wait_for_event(|event: &Event| event.kind() == EventType::Resumed);
+        let events = vec![
+            Event::RoleDelete(RoleDelete {
+                guild_id: Id::new(1),
+                role_id: Id::new(2),
+            }),
+            Event::Resumed,
+        ];
+
+        let stream = standby
+            .clone()
+            .process_stream(tokio_stream::iter(events.clone()));
+
+        assert_eq!(events, stream.collect::<Vec<_>>().await);
+        assert_eq!(Event::Resumed, wait.await.unwrap());
+        assert!(standby.events.is_empty());
+    }
+
     /// Test basic functionality of the [`Standby::wait_for_message`] method.
     #[tokio::test]
     async fn test_wait_for_message() {
@@ -1455,6 +2304,111 @@ mod tests {
         assert!(standby.messages.is_empty());
     }
 
+    /// Test that a message matched by [`Standby::wait_for_message_claiming`]
+    /// is claimed, while the same message matched by other bystanders isn't.
+    #[tokio::test]
+    async fn test_wait_for_message_claiming() {
+        let event = Event::MessageCreate(Box::new(MessageCreate(message())));
+
+        let standby = Standby::new();
+        let wait = standby.wait_for_message(Id::new(1), |_: &MessageCreate| true);
+        let mut stream = standby.wait_for_message_stream(Id::new(1), |_: &MessageCreate| true);
+        let results = standby.process(&event);
+        assert!(!results.claimed());
+        assert_eq!(2, results.matched());
+        assert!(wait.await.is_ok());
+        assert!(stream.next().await.is_some());
+
+        let wait = standby.wait_for_message_claiming(Id::new(1), |message: &MessageCreate| {
+            message.author.id.get() == 2
+        });
+        let results = standby.process(&event);
+        assert!(results.claimed());
+        assert_eq!(1, results.fulfilled());
+        assert_eq!(1, results.sent());
+        assert_eq!(3, wait.await.map(|msg| msg.id.get()).unwrap());
+
+        // The claiming bystander completed, so the message is unclaimed again.
+        assert!(!standby.process(&event).claimed());
+    }
+
+    /// Test that a claiming bystander whose check doesn't match doesn't claim
+    /// the event.
+    #[tokio::test]
+    async fn test_wait_for_message_claiming_unmatched() {
+        let event = Event::MessageCreate(Box::new(MessageCreate(message())));
+
+        let standby = Standby::new();
+        let _wait = standby.wait_for_message_claiming(Id::new(1), |message: &MessageCreate| {
+            message.author.id.get() == 3
+        });
+        let results = standby.process(&event);
+        assert!(!results.claimed());
+        assert_eq!(0, results.matched());
+        assert!(!standby.messages.is_empty());
+    }
+
+    /// Test that [`Standby::wait_for_message_blocking`] returns a message
+    /// processed by another thread.
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_wait_for_message_blocking() {
+        let standby = Arc::new(Standby::new());
+        let waiter = thread::spawn({
+            let standby = Arc::clone(&standby);
+            move || {
+                standby.wait_for_message_blocking(
+                    Id::new(1),
+                    |message: &MessageCreate| message.author.id.get() == 2,
+                    Duration::from_secs(5),
+                )
+            }
+        });
+
+        let event = Event::MessageCreate(Box::new(MessageCreate(message())));
+        // The waiting thread may not have registered its bystander yet.
+        while standby.process(&event).fulfilled() == 0 {
+            thread::yield_now();
+        }
+
+        assert_eq!(3, waiter.join().unwrap().unwrap().id.get());
+        assert!(standby.messages.is_empty());
+    }
+
+    /// Test that [`Standby::wait_for_message_blocking`] times out and its
+    /// bystander is then removed.
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_wait_for_message_blocking_timeout() {
+        let standby = Standby::new();
+        let error = standby
+            .wait_for_message_blocking(
+                Id::new(1),
+                |_: &MessageCreate| true,
+                Duration::from_millis(10),
+            )
+            .unwrap_err();
+        assert!(matches!(error.kind(), TimeoutOrCanceledType::TimedOut));
+
+        let results = standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert_eq!(1, results.dropped());
+        assert!(standby.messages.is_empty());
+    }
+
+    /// Test that [`Standby::wait_for_message_blocking`] refuses to block a
+    /// runtime thread.
+    #[cfg(feature = "blocking")]
+    #[should_panic(expected = "must not be called from within a Tokio runtime")]
+    #[tokio::test]
+    async fn test_wait_for_message_blocking_in_runtime() {
+        let standby = Standby::new();
+        drop(standby.wait_for_message_blocking(
+            Id::new(1),
+            |_: &MessageCreate| true,
+            Duration::from_millis(10),
+        ));
+    }
+
     /// Test basic functionality of the [`Standby::wait_for_message_stream`]
     /// method.
     #[tokio::test]
@@ -1491,6 +2445,18 @@ mod tests {
         assert!(standby.reactions.is_empty());
     }
 
+    /// Test that [`Standby::wait_for_reaction_claiming`] claims the reaction.
+    #[tokio::test]
+    async fn test_wait_for_reaction_claiming() {
+        let event = Event::ReactionAdd(Box::new(ReactionAdd(reaction())));
+
+        let standby = Standby::new();
+        let wait = standby.wait_for_reaction_claiming(Id::new(4), |_: &ReactionAdd| true);
+        assert!(standby.process(&event).claimed());
+        assert!(wait.await.is_ok());
+        assert!(standby.reactions.is_empty());
+    }
+
     /// Test basic functionality of the [`Standby::wait_for_reaction_stream`]
     /// method.
     #[tokio::test]
@@ -1508,6 +2474,54 @@ mod tests {
         assert!(standby.reactions.is_empty());
     }
 
+    /// Test basic functionality of the [`Standby::wait_for_thread`] method.
+    #[tokio::test]
+    async fn test_wait_for_thread() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_thread(Id::new(400), |thread: &ThreadCreate| {
+            thread.owner_id == Some(Id::new(2))
+        });
+
+        let results = standby.process(&Event::ThreadCreate(Box::new(thread(Some(401)))));
+        assert_eq!(0, results.matched());
+        assert_eq!(1, standby.threads.len());
+
+        let results = standby.process(&Event::ThreadCreate(Box::new(thread(Some(400)))));
+        assert_eq!(1, results.fulfilled());
+
+        assert_eq!(Id::new(500), wait.await.map(|thread| thread.id).unwrap());
+        assert!(standby.threads.is_empty());
+    }
+
+    /// Test that threads without a parent channel are skipped.
+    #[tokio::test]
+    async fn test_wait_for_thread_no_parent() {
+        let standby = Standby::new();
+        let _wait = standby.wait_for_thread(Id::new(400), |_: &ThreadCreate| true);
+
+        let results = standby.process(&Event::ThreadCreate(Box::new(thread(None))));
+        assert_eq!(0, results.matched());
+        assert_eq!(1, standby.threads.len());
+    }
+
+    /// Test basic functionality of the [`Standby::wait_for_thread_stream`]
+    /// method.
+    #[tokio::test]
+    async fn test_wait_for_thread_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_thread_stream(Id::new(400), |_: &ThreadCreate| true);
+        standby.process(&Event::ThreadCreate(Box::new(thread(Some(400)))));
+        standby.process(&Event::ThreadCreate(Box::new(thread(Some(400)))));
+
+        assert!(stream.next().await.is_some());
+        assert!(stream.next().await.is_some());
+        drop(stream);
+        assert_eq!(1, standby.threads.len());
+        let results = standby.process(&Event::ThreadCreate(Box::new(thread(Some(400)))));
+        assert_eq!(1, results.dropped());
+        assert!(standby.threads.is_empty());
+    }
+
     /// Assert that Standby processing some non-matching events will not affect
     /// the matching of a later event.
     #[tokio::test]
@@ -1528,6 +2542,22 @@ mod tests {
         assert!(standby.components.is_empty());
     }
 
+    /// Test that an interaction is claimed if any of the bystanders it's sent
+    /// to claims it.
+    #[tokio::test]
+    async fn test_wait_for_component_claiming() {
+        let event = Event::InteractionCreate(Box::new(InteractionCreate(button())));
+
+        let standby = Standby::new();
+        let by_user = standby.wait_for_user_component(Id::new(2), |_: &Interaction| true);
+        let by_message = standby.wait_for_component_claiming(Id::new(3), |_: &Interaction| true);
+        let results = standby.process(&event);
+        assert!(results.claimed());
+        assert_eq!(2, results.fulfilled());
+        assert!(by_user.await.is_ok());
+        assert!(by_message.await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_wait_for_component_stream() {
         let standby = Standby::new();
@@ -1549,6 +2579,133 @@ mod tests {
         assert!(standby.components.is_empty());
     }
 
+    /// Test that a component is sent to bystanders waiting on its user and on
+    /// its message alike.
+    #[tokio::test]
+    async fn test_wait_for_user_component() {
+        let standby = Standby::new();
+        let by_user = standby.wait_for_user_component(Id::new(2), |_: &Interaction| true);
+        let by_message = standby.wait_for_component(Id::new(3), |_: &Interaction| true);
+
+        let results = standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+        assert_eq!(2, results.fulfilled());
+
+        assert_eq!(Some(Id::new(2)), by_user.await.unwrap().author_id());
+        assert_eq!(Some(Id::new(2)), by_message.await.unwrap().author_id());
+        assert!(standby.components.is_empty());
+        assert!(standby.user_components.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_user_component_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_user_component_stream(Id::new(2), |_: &Interaction| true);
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+
+        assert!(stream.next().await.is_some());
+        drop(stream);
+        assert_eq!(1, standby.user_components.len());
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            button(),
+        ))));
+        assert!(standby.user_components.is_empty());
+    }
+
+    /// Test that raw payloads resolve the bystanders waiting on their event
+    /// type.
+    #[tokio::test]
+    async fn test_wait_for_raw() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_raw("CUSTOM_EVENT", |payload: &str| payload.contains("456"));
+        assert_eq!(
+            0,
+            standby
+                .process_raw("CUSTOM_EVENT", r#"{"id":"123"}"#)
+                .matched()
+        );
+        assert_eq!(
+            0,
+            standby
+                .process_raw("OTHER_EVENT", r#"{"id":"456"}"#)
+                .matched()
+        );
+        assert_eq!(1, standby.raw.len());
+        assert_eq!(
+            1,
+            standby
+                .process_raw("CUSTOM_EVENT", r#"{"id":"456"}"#)
+                .matched()
+        );
+
+        assert_eq!(r#"{"id":"456"}"#, wait.await.unwrap());
+        assert!(standby.raw.is_empty());
+    }
+
+    /// Test that raw payloads are not processed with typed events and vice
+    /// versa.
+    #[tokio::test]
+    async fn test_wait_for_raw_separate() {
+        let standby = Standby::new();
+        let _wait = standby.wait_for_raw("RESUMED", |_: &str| true);
+        let typed = standby.wait_for_event_typed(EventType::Resumed, |_: &Event| true);
+
+        assert_eq!(1, standby.process(&Event::Resumed).matched());
+        assert_eq!(1, standby.raw.len());
+        assert_eq!(1, standby.process_raw("RESUMED", "{}").matched());
+        assert!(standby.raw.is_empty());
+        assert!(typed.await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_raw_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_raw_stream("CUSTOM_EVENT", |_: &str| true);
+        standby.process_raw("CUSTOM_EVENT", "1");
+        standby.process_raw("OTHER_EVENT", "2");
+        standby.process_raw("CUSTOM_EVENT", "3");
+
+        assert_eq!(Some("1".to_owned()), stream.next().await);
+        assert_eq!(Some("3".to_owned()), stream.next().await);
+        drop(stream);
+        assert_eq!(1, standby.raw.len());
+        let results = standby.process_raw("CUSTOM_EVENT", "4");
+        assert_eq!(1, results.dropped());
+        assert!(standby.raw.is_empty());
+    }
+
+    /// Test that payloads processed without an [`Event`] wrapper resolve
+    /// their bystanders.
+    #[tokio::test]
+    async fn test_process_payloads() {
+        let standby = Standby::new();
+        let message_wait = standby.wait_for_message(Id::new(1), |_: &MessageCreate| true);
+        let reaction_wait = standby.wait_for_reaction(Id::new(4), |_: &ReactionAdd| true);
+        let component_wait = standby.wait_for_component(Id::new(3), |_: &Interaction| true);
+        let thread_wait = standby.wait_for_thread(Id::new(400), |_: &ThreadCreate| true);
+
+        let results = standby.process_message(&MessageCreate(message()));
+        assert_eq!(1, results.fulfilled());
+        let results = standby.process_reaction_add(&ReactionAdd(reaction()));
+        assert_eq!(1, results.fulfilled());
+        let results = standby.process_interaction(&InteractionCreate(button()));
+        assert_eq!(1, results.fulfilled());
+        let results = standby.process_thread(&thread(Some(400)));
+        assert_eq!(1, results.fulfilled());
+
+        assert_eq!(Id::new(3), message_wait.await.unwrap().id);
+        assert_eq!(Id::new(3), reaction_wait.await.unwrap().user_id);
+        assert_eq!(Some(Id::new(2)), component_wait.await.unwrap().author_id());
+        assert_eq!(Id::new(500), thread_wait.await.unwrap().id);
+        assert!(standby.messages.is_empty());
+        assert!(standby.reactions.is_empty());
+        assert!(standby.components.is_empty());
+        assert!(standby.threads.is_empty());
+    }
+
     #[tokio::test]
     async fn test_handles_wrong_events() {
         let standby = Standby::new();
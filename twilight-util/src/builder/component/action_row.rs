@@ -0,0 +1,173 @@
+//! Create action rows.
+
+use twilight_model::channel::message::{component::ActionRow, Component};
+use twilight_validate::component::{action_row as validate_action_row, ComponentValidationError};
+
+/// Create an [`ActionRow`] with a builder.
+///
+/// Action rows contain buttons, a select menu, or a text input. Refer to
+/// [`ACTION_ROW_COMPONENT_COUNT`] for the maximum number of components in a
+/// row.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::channel::message::component::ButtonStyle;
+/// use twilight_util::builder::component::{ActionRowBuilder, ButtonBuilder};
+///
+/// let action_row = ActionRowBuilder::new()
+///     .component(ButtonBuilder::new(ButtonStyle::Success).custom_id("yes").label("Yes"))
+///     .component(ButtonBuilder::new(ButtonStyle::Danger).custom_id("no").label("No"))
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// [`ACTION_ROW_COMPONENT_COUNT`]: twilight_validate::component::ACTION_ROW_COMPONENT_COUNT
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an action row"]
+pub struct ActionRowBuilder(ActionRow);
+
+impl ActionRowBuilder {
+    /// Create a new action row builder.
+    pub const fn new() -> Self {
+        Self(ActionRow {
+            components: Vec::new(),
+        })
+    }
+
+    /// Build into an action row.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of something like a message"]
+    pub fn build(self) -> ActionRow {
+        self.0
+    }
+
+    /// Ensure the action row and its components are valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the documentation of [`twilight_validate::component::action_row`]
+    /// for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        #[allow(clippy::question_mark)]
+        if let Err(source) = validate_action_row(&self.0) {
+            return Err(source);
+        }
+
+        Ok(self)
+    }
+
+    /// Add a component to the action row.
+    pub fn component(mut self, component: impl Into<Component>) -> Self {
+        self.0.components.push(component.into());
+
+        self
+    }
+}
+
+impl Default for ActionRowBuilder {
+    /// Create a new action row builder.
+    ///
+    /// This is equivalent to calling [`ActionRowBuilder::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ActionRowBuilder> for ActionRow {
+    /// Convert an action row builder into an action row.
+    ///
+    /// This is equivalent to calling [`ActionRowBuilder::build`].
+    fn from(builder: ActionRowBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<ActionRowBuilder> for Component {
+    /// Convert an action row builder into a component.
+    fn from(builder: ActionRowBuilder) -> Self {
+        Component::ActionRow(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ActionRowBuilder;
+    use crate::builder::component::{ButtonBuilder, TextInputBuilder};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::channel::message::{
+        component::{ActionRow, ButtonStyle, ComponentType, TextInputStyle},
+        Component,
+    };
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(ActionRowBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ActionRow: From<ActionRowBuilder>);
+    assert_impl_all!(Component: From<ActionRowBuilder>);
+
+    fn button(custom_id: &str) -> ButtonBuilder {
+        ButtonBuilder::new(ButtonStyle::Primary).custom_id(custom_id)
+    }
+
+    #[test]
+    fn builder() {
+        let action_row = ActionRowBuilder::new()
+            .component(button("a"))
+            .component(button("b"))
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(2, action_row.components.len());
+        assert!(matches!(
+            &action_row.components[0],
+            Component::Button(button) if button.custom_id.as_deref() == Some("a"),
+        ));
+    }
+
+    #[test]
+    fn component_count() {
+        let builder = (0..5).fold(ActionRowBuilder::new(), |builder, index| {
+            builder.component(button(&index.to_string()))
+        });
+        let builder = builder.validate().unwrap();
+
+        assert!(matches!(
+            builder
+                .component(button("5"))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ActionRowComponentCount { count: 6 },
+        ));
+    }
+
+    #[test]
+    fn nested_action_row() {
+        let result = ActionRowBuilder::new()
+            .component(ActionRowBuilder::new())
+            .validate();
+
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::ActionRow,
+            },
+        ));
+    }
+
+    #[test]
+    fn invalid_child() {
+        let result = ActionRowBuilder::new()
+            .component(TextInputBuilder::new(TextInputStyle::Short, "a", ""))
+            .validate();
+
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputLabelLength { len: 0 },
+        ));
+    }
+}
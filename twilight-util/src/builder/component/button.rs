@@ -0,0 +1,250 @@
+//! Create buttons.
+
+use twilight_model::{
+    channel::message::{
+        component::{Button, ButtonStyle},
+        Component, EmojiReactionType,
+    },
+    id::{marker::SkuMarker, Id},
+};
+use twilight_validate::component::{button as validate_button, ComponentValidationError};
+
+/// Create a [`Button`] with a builder.
+///
+/// Buttons with the [`ButtonStyle::Link`] style require a [`url`], buttons
+/// with the [`ButtonStyle::Premium`] style require a [`sku_id`], and all other
+/// buttons require a [`custom_id`]. A custom ID and URL are mutually
+/// exclusive.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::channel::message::component::ButtonStyle;
+/// use twilight_util::builder::component::ButtonBuilder;
+///
+/// let button = ButtonBuilder::new(ButtonStyle::Primary)
+///     .custom_id("confirm")
+///     .label("Confirm")
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// [`custom_id`]: Self::custom_id
+/// [`sku_id`]: Self::sku_id
+/// [`url`]: Self::url
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a button"]
+pub struct ButtonBuilder(Button);
+
+impl ButtonBuilder {
+    /// Create a new button builder with a style.
+    pub const fn new(style: ButtonStyle) -> Self {
+        Self(Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: None,
+            style,
+            url: None,
+            sku_id: None,
+        })
+    }
+
+    /// Build into a button.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> Button {
+        self.0
+    }
+
+    /// Ensure the button is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the documentation of [`twilight_validate::component::button`]
+    /// for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        #[allow(clippy::question_mark)]
+        if let Err(source) = validate_button(&self.0) {
+            return Err(source);
+        }
+
+        Ok(self)
+    }
+
+    /// Set the custom ID.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.0.custom_id = Some(custom_id.into());
+
+        self
+    }
+
+    /// Set whether the button is disabled.
+    ///
+    /// Defaults to `false`.
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the emoji.
+    pub fn emoji(mut self, emoji: EmojiReactionType) -> Self {
+        self.0.emoji = Some(emoji);
+
+        self
+    }
+
+    /// Set the label.
+    ///
+    /// Refer to [`COMPONENT_BUTTON_LABEL_LENGTH`] for the maximum number of
+    /// characters that can be in a label.
+    ///
+    /// [`COMPONENT_BUTTON_LABEL_LENGTH`]: twilight_validate::component::COMPONENT_BUTTON_LABEL_LENGTH
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.0.label = Some(label.into());
+
+        self
+    }
+
+    /// Set the SKU ID of a [`ButtonStyle::Premium`] button.
+    pub const fn sku_id(mut self, sku_id: Id<SkuMarker>) -> Self {
+        self.0.sku_id = Some(sku_id);
+
+        self
+    }
+
+    /// Set the URL of a [`ButtonStyle::Link`] button.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = Some(url.into());
+
+        self
+    }
+}
+
+impl From<ButtonBuilder> for Button {
+    /// Convert a button builder into a button.
+    ///
+    /// This is equivalent to calling [`ButtonBuilder::build`].
+    fn from(builder: ButtonBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<ButtonBuilder> for Component {
+    /// Convert a button builder into a component.
+    fn from(builder: ButtonBuilder) -> Self {
+        Component::Button(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ButtonBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::channel::message::{
+        component::{Button, ButtonStyle},
+        Component, EmojiReactionType,
+    };
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(ButtonBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Button: From<ButtonBuilder>);
+    assert_impl_all!(Component: From<ButtonBuilder>);
+
+    #[test]
+    fn builder() {
+        let expected = Button {
+            custom_id: Some("a".to_owned()),
+            disabled: true,
+            emoji: Some(EmojiReactionType::Unicode {
+                name: "🦀".to_owned(),
+            }),
+            label: Some("b".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+            sku_id: None,
+        };
+        let actual = ButtonBuilder::new(ButtonStyle::Secondary)
+            .custom_id("a")
+            .disabled(true)
+            .emoji(EmojiReactionType::Unicode {
+                name: "🦀".to_owned(),
+            })
+            .label("b")
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn custom_id_and_url() {
+        let result = ButtonBuilder::new(ButtonStyle::Link)
+            .custom_id("a")
+            .url("https://twilight.rs")
+            .validate();
+
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            ComponentValidationErrorType::ButtonConflict,
+        ));
+    }
+
+    #[test]
+    fn style_requirements() {
+        assert!(ButtonBuilder::new(ButtonStyle::Link)
+            .url("https://twilight.rs")
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            ButtonBuilder::new(ButtonStyle::Link)
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ButtonStyle {
+                style: ButtonStyle::Link,
+            },
+        ));
+        assert!(matches!(
+            ButtonBuilder::new(ButtonStyle::Danger)
+                .url("https://twilight.rs")
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ButtonStyle {
+                style: ButtonStyle::Danger,
+            },
+        ));
+    }
+
+    #[test]
+    fn lengths() {
+        assert!(matches!(
+            ButtonBuilder::new(ButtonStyle::Primary)
+                .custom_id("a".repeat(101))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ComponentCustomIdLength { chars: 101 },
+        ));
+        assert!(matches!(
+            ButtonBuilder::new(ButtonStyle::Primary)
+                .custom_id("a")
+                .label("a".repeat(81))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ComponentLabelLength { chars: 81 },
+        ));
+    }
+}
@@ -0,0 +1,45 @@
+//! Create [`Component`]s with builders.
+//!
+//! # Examples
+//!
+//! Create an action row containing a link button and a select menu:
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_model::channel::message::component::{ButtonStyle, SelectMenuType};
+//! use twilight_util::builder::component::{
+//!     ActionRowBuilder, ButtonBuilder, SelectMenuBuilder, SelectMenuOptionBuilder,
+//! };
+//!
+//! let action_row = ActionRowBuilder::new()
+//!     .component(
+//!         ButtonBuilder::new(ButtonStyle::Link)
+//!             .label("Documentation")
+//!             .url("https://docs.rs/twilight")
+//!             .validate()?,
+//!     )
+//!     .validate()?
+//!     .build();
+//!
+//! let select_menu = SelectMenuBuilder::new("pony", SelectMenuType::Text)
+//!     .option(SelectMenuOptionBuilder::new("Twilight Sparkle", "twilight").default(true))
+//!     .option(SelectMenuOptionBuilder::new("Rarity", "rarity"))
+//!     .placeholder("Choose the best pony")
+//!     .validate()?
+//!     .build();
+//! # Ok(()) }
+//! ```
+//!
+//! [`Component`]: twilight_model::channel::message::Component
+
+mod action_row;
+mod button;
+mod select_menu;
+mod text_input;
+
+pub use self::{
+    action_row::ActionRowBuilder,
+    button::ButtonBuilder,
+    select_menu::{SelectMenuBuilder, SelectMenuOptionBuilder},
+    text_input::TextInputBuilder,
+};
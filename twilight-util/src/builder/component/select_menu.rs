@@ -0,0 +1,395 @@
+//! Create select menus and their options.
+
+use twilight_model::channel::{
+    message::{
+        component::{SelectDefaultValue, SelectMenu, SelectMenuOption, SelectMenuType},
+        Component, EmojiReactionType,
+    },
+    ChannelType,
+};
+use twilight_validate::component::{select_menu as validate_select_menu, ComponentValidationError};
+
+/// Create a [`SelectMenu`] with a builder.
+///
+/// Menus of the [`SelectMenuType::Text`] type require at least one
+/// [`option`]. Refer to [`SELECT_OPTION_COUNT`] for the maximum number of
+/// options.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::channel::{message::component::SelectMenuType, ChannelType};
+/// use twilight_util::builder::component::SelectMenuBuilder;
+///
+/// let select_menu = SelectMenuBuilder::new("channels", SelectMenuType::Channel)
+///     .channel_types(Vec::from([ChannelType::GuildText]))
+///     .max_values(3)
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// [`option`]: Self::option
+/// [`SELECT_OPTION_COUNT`]: twilight_validate::component::SELECT_OPTION_COUNT
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu"]
+pub struct SelectMenuBuilder(SelectMenu);
+
+impl SelectMenuBuilder {
+    /// Create a new select menu builder.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    pub fn new(custom_id: impl Into<String>, kind: SelectMenuType) -> Self {
+        Self(SelectMenu {
+            channel_types: None,
+            custom_id: custom_id.into(),
+            default_values: None,
+            disabled: false,
+            kind,
+            max_values: None,
+            min_values: None,
+            options: None,
+            placeholder: None,
+        })
+    }
+
+    /// Build into a select menu.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> SelectMenu {
+        self.0
+    }
+
+    /// Ensure the select menu and its options are valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the documentation of
+    /// [`twilight_validate::component::select_menu`] for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        #[allow(clippy::question_mark)]
+        if let Err(source) = validate_select_menu(&self.0) {
+            return Err(source);
+        }
+
+        Ok(self)
+    }
+
+    /// Set the channel types of a [`SelectMenuType::Channel`] menu.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.0.channel_types = Some(channel_types);
+
+        self
+    }
+
+    /// Add a default user, role, or channel of an auto-populated menu.
+    pub fn default_value(mut self, default_value: SelectDefaultValue) -> Self {
+        self.0
+            .default_values
+            .get_or_insert_with(Vec::new)
+            .push(default_value);
+
+        self
+    }
+
+    /// Set whether the select menu is disabled.
+    ///
+    /// Defaults to `false`.
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the maximum number of values that can be chosen.
+    ///
+    /// Refer to [`SELECT_MAXIMUM_VALUES_LIMIT`] for the maximum value.
+    ///
+    /// [`SELECT_MAXIMUM_VALUES_LIMIT`]: twilight_validate::component::SELECT_MAXIMUM_VALUES_LIMIT
+    pub const fn max_values(mut self, max_values: u8) -> Self {
+        self.0.max_values = Some(max_values);
+
+        self
+    }
+
+    /// Set the minimum number of values that must be chosen.
+    ///
+    /// Must not exceed the [maximum][`max_values`].
+    ///
+    /// [`max_values`]: Self::max_values
+    pub const fn min_values(mut self, min_values: u8) -> Self {
+        self.0.min_values = Some(min_values);
+
+        self
+    }
+
+    /// Add an option to a [`SelectMenuType::Text`] menu.
+    pub fn option(mut self, option: impl Into<SelectMenuOption>) -> Self {
+        self.0
+            .options
+            .get_or_insert_with(Vec::new)
+            .push(option.into());
+
+        self
+    }
+
+    /// Set the placeholder shown when nothing is chosen.
+    ///
+    /// Refer to [`SELECT_PLACEHOLDER_LENGTH`] for the maximum number of
+    /// characters that can be in a placeholder.
+    ///
+    /// [`SELECT_PLACEHOLDER_LENGTH`]: twilight_validate::component::SELECT_PLACEHOLDER_LENGTH
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder = Some(placeholder.into());
+
+        self
+    }
+}
+
+impl From<SelectMenuBuilder> for SelectMenu {
+    /// Convert a select menu builder into a select menu.
+    ///
+    /// This is equivalent to calling [`SelectMenuBuilder::build`].
+    fn from(builder: SelectMenuBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<SelectMenuBuilder> for Component {
+    /// Convert a select menu builder into a component.
+    fn from(builder: SelectMenuBuilder) -> Self {
+        Component::SelectMenu(builder.build())
+    }
+}
+
+/// Create a [`SelectMenuOption`] with a builder.
+///
+/// This can be passed into [`SelectMenuBuilder::option`]. Options are validated
+/// as part of their select menu.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu option"]
+pub struct SelectMenuOptionBuilder(SelectMenuOption);
+
+impl SelectMenuOptionBuilder {
+    /// Create a new select menu option builder.
+    ///
+    /// Refer to [`SELECT_OPTION_LABEL_LENGTH`] and
+    /// [`SELECT_OPTION_VALUE_LENGTH`] for the maximum number of characters
+    /// that can be in a label and value.
+    ///
+    /// [`SELECT_OPTION_LABEL_LENGTH`]: twilight_validate::component::SELECT_OPTION_LABEL_LENGTH
+    /// [`SELECT_OPTION_VALUE_LENGTH`]: twilight_validate::component::SELECT_OPTION_VALUE_LENGTH
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: label.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Build into a select menu option.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a select menu builder"]
+    pub fn build(self) -> SelectMenuOption {
+        self.0
+    }
+
+    /// Set whether the option is chosen by default.
+    ///
+    /// Defaults to `false`.
+    pub const fn default(mut self, default: bool) -> Self {
+        self.0.default = default;
+
+        self
+    }
+
+    /// Set the description.
+    ///
+    /// Refer to [`SELECT_OPTION_DESCRIPTION_LENGTH`] for the maximum number of
+    /// characters that can be in a description.
+    ///
+    /// [`SELECT_OPTION_DESCRIPTION_LENGTH`]: twilight_validate::component::SELECT_OPTION_DESCRIPTION_LENGTH
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+
+        self
+    }
+
+    /// Set the emoji.
+    pub fn emoji(mut self, emoji: EmojiReactionType) -> Self {
+        self.0.emoji = Some(emoji);
+
+        self
+    }
+}
+
+impl From<SelectMenuOptionBuilder> for SelectMenuOption {
+    /// Convert a select menu option builder into a select menu option.
+    ///
+    /// This is equivalent to calling [`SelectMenuOptionBuilder::build`].
+    fn from(builder: SelectMenuOptionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SelectMenuBuilder, SelectMenuOptionBuilder};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::{
+            message::{
+                component::{SelectDefaultValue, SelectMenu, SelectMenuOption, SelectMenuType},
+                Component,
+            },
+            ChannelType,
+        },
+        id::Id,
+    };
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(SelectMenuBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenu: From<SelectMenuBuilder>);
+    assert_impl_all!(Component: From<SelectMenuBuilder>);
+    assert_impl_all!(SelectMenuOptionBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenuOption: From<SelectMenuOptionBuilder>);
+
+    fn text(option_count: usize) -> SelectMenuBuilder {
+        (0..option_count).fold(
+            SelectMenuBuilder::new("a", SelectMenuType::Text),
+            |builder, index| builder.option(SelectMenuOptionBuilder::new("b", index.to_string())),
+        )
+    }
+
+    #[test]
+    fn builder() {
+        let expected = SelectMenu {
+            channel_types: None,
+            custom_id: "a".to_owned(),
+            default_values: None,
+            disabled: true,
+            kind: SelectMenuType::Text,
+            max_values: Some(2),
+            min_values: Some(1),
+            options: Some(Vec::from([SelectMenuOption {
+                default: true,
+                description: Some("c".to_owned()),
+                emoji: None,
+                label: "b".to_owned(),
+                value: "0".to_owned(),
+            }])),
+            placeholder: Some("d".to_owned()),
+        };
+        let actual = SelectMenuBuilder::new("a", SelectMenuType::Text)
+            .disabled(true)
+            .max_values(2)
+            .min_values(1)
+            .option(
+                SelectMenuOptionBuilder::new("b", "0")
+                    .default(true)
+                    .description("c"),
+            )
+            .placeholder("d")
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn channel_types() {
+        let select_menu = SelectMenuBuilder::new("a", SelectMenuType::Channel)
+            .channel_types(Vec::from([ChannelType::GuildText, ChannelType::GuildVoice]))
+            .default_value(SelectDefaultValue::Channel(Id::new(1)))
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            Some(Vec::from([ChannelType::GuildText, ChannelType::GuildVoice])),
+            select_menu.channel_types
+        );
+        assert!(select_menu.options.is_none());
+    }
+
+    #[test]
+    fn options() {
+        assert!(matches!(
+            text(0).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionsMissing,
+        ));
+        assert!(text(25).validate().is_ok());
+        assert!(matches!(
+            text(26).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionCount { count: 26 },
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::new("a", SelectMenuType::Text)
+                .option(SelectMenuOptionBuilder::new("b", "c").description("d".repeat(101)))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectOptionDescriptionLength { chars: 101 },
+        ));
+    }
+
+    #[test]
+    fn values() {
+        assert!(matches!(
+            text(1).max_values(0).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMaximumValuesCount { count: 0 },
+        ));
+        assert!(matches!(
+            text(1).max_values(26).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMaximumValuesCount { count: 26 },
+        ));
+        assert!(matches!(
+            text(1).min_values(26).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMinimumValuesCount { count: 26 },
+        ));
+        assert!(matches!(
+            text(1)
+                .min_values(3)
+                .max_values(2)
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectMinimumValuesCount { count: 3 },
+        ));
+    }
+
+    #[test]
+    fn default_values() {
+        assert!(matches!(
+            text(1)
+                .default_value(SelectDefaultValue::User(Id::new(1)))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectUnsupportedDefaultValues {
+                kind: SelectMenuType::Text,
+            },
+        ));
+        assert!(matches!(
+            SelectMenuBuilder::new("a", SelectMenuType::User)
+                .max_values(1)
+                .default_value(SelectDefaultValue::User(Id::new(1)))
+                .default_value(SelectDefaultValue::User(Id::new(2)))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::SelectTooManyDefaultValues {
+                provided: 2,
+                max: 1,
+            },
+        ));
+    }
+}
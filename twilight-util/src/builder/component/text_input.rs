@@ -0,0 +1,236 @@
+//! Create text inputs.
+
+use twilight_model::channel::message::{
+    component::{TextInput, TextInputStyle},
+    Component,
+};
+use twilight_validate::component::{text_input as validate_text_input, ComponentValidationError};
+
+/// Create a [`TextInput`] with a builder.
+///
+/// Text inputs may only be used in modals.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::channel::message::component::TextInputStyle;
+/// use twilight_util::builder::component::TextInputBuilder;
+///
+/// let text_input = TextInputBuilder::new(TextInputStyle::Paragraph, "feedback", "Feedback")
+///     .max_length(500)
+///     .placeholder("What could be better?")
+///     .required(false)
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a text input"]
+pub struct TextInputBuilder(TextInput);
+
+impl TextInputBuilder {
+    /// Create a new text input builder.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// Refer to [`TEXT_INPUT_LABEL_MIN`] and [`TEXT_INPUT_LABEL_MAX`] for the
+    /// bounds of the label's length.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    /// [`TEXT_INPUT_LABEL_MAX`]: twilight_validate::component::TEXT_INPUT_LABEL_MAX
+    /// [`TEXT_INPUT_LABEL_MIN`]: twilight_validate::component::TEXT_INPUT_LABEL_MIN
+    pub fn new(
+        style: TextInputStyle,
+        custom_id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self(TextInput {
+            custom_id: custom_id.into(),
+            label: label.into(),
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: None,
+            style,
+            value: None,
+        })
+    }
+
+    /// Build into a text input.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> TextInput {
+        self.0
+    }
+
+    /// Ensure the text input is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the documentation of
+    /// [`twilight_validate::component::text_input`] for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        #[allow(clippy::question_mark)]
+        if let Err(source) = validate_text_input(&self.0) {
+            return Err(source);
+        }
+
+        Ok(self)
+    }
+
+    /// Set the maximum length of the input.
+    ///
+    /// Refer to [`TEXT_INPUT_LENGTH_MIN`] and [`TEXT_INPUT_LENGTH_MAX`] for
+    /// the bounds of the maximum length.
+    ///
+    /// [`TEXT_INPUT_LENGTH_MAX`]: twilight_validate::component::TEXT_INPUT_LENGTH_MAX
+    /// [`TEXT_INPUT_LENGTH_MIN`]: twilight_validate::component::TEXT_INPUT_LENGTH_MIN
+    pub const fn max_length(mut self, max_length: u16) -> Self {
+        self.0.max_length = Some(max_length);
+
+        self
+    }
+
+    /// Set the minimum length of the input.
+    ///
+    /// Refer to [`TEXT_INPUT_LENGTH_MAX`] for the upper bound of the minimum
+    /// length.
+    ///
+    /// [`TEXT_INPUT_LENGTH_MAX`]: twilight_validate::component::TEXT_INPUT_LENGTH_MAX
+    pub const fn min_length(mut self, min_length: u16) -> Self {
+        self.0.min_length = Some(min_length);
+
+        self
+    }
+
+    /// Set the placeholder shown when the input is empty.
+    ///
+    /// Refer to [`TEXT_INPUT_PLACEHOLDER_MAX`] for the maximum number of
+    /// characters that can be in a placeholder.
+    ///
+    /// [`TEXT_INPUT_PLACEHOLDER_MAX`]: twilight_validate::component::TEXT_INPUT_PLACEHOLDER_MAX
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder = Some(placeholder.into());
+
+        self
+    }
+
+    /// Set whether the input must be filled.
+    ///
+    /// Defaults to `true`.
+    pub const fn required(mut self, required: bool) -> Self {
+        self.0.required = Some(required);
+
+        self
+    }
+
+    /// Set the pre-filled value.
+    ///
+    /// Refer to [`TEXT_INPUT_LENGTH_MAX`] for the maximum number of characters
+    /// that can be in a value.
+    ///
+    /// [`TEXT_INPUT_LENGTH_MAX`]: twilight_validate::component::TEXT_INPUT_LENGTH_MAX
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.0.value = Some(value.into());
+
+        self
+    }
+}
+
+impl From<TextInputBuilder> for TextInput {
+    /// Convert a text input builder into a text input.
+    ///
+    /// This is equivalent to calling [`TextInputBuilder::build`].
+    fn from(builder: TextInputBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<TextInputBuilder> for Component {
+    /// Convert a text input builder into a component.
+    fn from(builder: TextInputBuilder) -> Self {
+        Component::TextInput(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextInputBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::channel::message::{
+        component::{TextInput, TextInputStyle},
+        Component,
+    };
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(TextInputBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(TextInput: From<TextInputBuilder>);
+    assert_impl_all!(Component: From<TextInputBuilder>);
+
+    fn short() -> TextInputBuilder {
+        TextInputBuilder::new(TextInputStyle::Short, "a", "b")
+    }
+
+    #[test]
+    fn builder() {
+        let expected = TextInput {
+            custom_id: "a".to_owned(),
+            label: "b".to_owned(),
+            max_length: Some(100),
+            min_length: Some(10),
+            placeholder: Some("c".to_owned()),
+            required: Some(false),
+            style: TextInputStyle::Short,
+            value: Some("d".to_owned()),
+        };
+        let actual = short()
+            .max_length(100)
+            .min_length(10)
+            .placeholder("c")
+            .required(false)
+            .value("d")
+            .validate()
+            .unwrap()
+            .build();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn lengths() {
+        assert!(matches!(
+            TextInputBuilder::new(TextInputStyle::Short, "a", "b".repeat(46))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::TextInputLabelLength { len: 46 },
+        ));
+        assert!(matches!(
+            short().max_length(0).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputMaxLength { len: 0 },
+        ));
+        assert!(matches!(
+            short().min_length(4001).validate().unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputMinLength { len: 4001 },
+        ));
+        assert!(matches!(
+            short()
+                .placeholder("c".repeat(101))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::TextInputPlaceholderLength { chars: 101 },
+        ));
+        assert!(matches!(
+            short()
+                .value("d".repeat(4001))
+                .validate()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::TextInputValueLength { chars: 4001 },
+        ));
+    }
+}
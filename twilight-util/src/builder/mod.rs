@@ -1,8 +1,10 @@
 //! Builders for large structs.
 
 pub mod command;
+pub mod component;
 pub mod embed;
 
 mod interaction_response_data;
+mod modal;
 
-pub use self::interaction_response_data::InteractionResponseDataBuilder;
+pub use self::{interaction_response_data::InteractionResponseDataBuilder, modal::ModalBuilder};
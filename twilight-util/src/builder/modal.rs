@@ -0,0 +1,244 @@
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, TextInput, TextInputStyle},
+        Component,
+    },
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+use twilight_validate::component::{modal as validate_modal, ComponentValidationError};
+
+/// Create a modal [`InteractionResponse`] with a builder.
+///
+/// Each text input is placed in its own action row. A modal may have at most
+/// [`COMPONENT_COUNT`] text inputs.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::channel::message::component::TextInputStyle;
+/// use twilight_util::builder::{component::TextInputBuilder, ModalBuilder};
+///
+/// let response = ModalBuilder::new()
+///     .custom_id("feedback")
+///     .title("Feedback")
+///     .short_input("subject", "Subject")
+///     .input(
+///         TextInputBuilder::new(TextInputStyle::Paragraph, "details", "Details")
+///             .placeholder("What could be better?")
+///             .required(false),
+///     )
+///     .build()?;
+///
+/// assert_eq!(2, response.data.unwrap().components.unwrap().len());
+/// # Ok(()) }
+/// ```
+///
+/// [`COMPONENT_COUNT`]: twilight_validate::component::COMPONENT_COUNT
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "builders have no effect if unused"]
+pub struct ModalBuilder {
+    components: Vec<Component>,
+    custom_id: String,
+    title: String,
+}
+
+impl ModalBuilder {
+    /// Create a new builder to construct a modal [`InteractionResponse`].
+    pub const fn new() -> Self {
+        Self {
+            components: Vec::new(),
+            custom_id: String::new(),
+            title: String::new(),
+        }
+    }
+
+    /// Consume the builder, returning a modal [`InteractionResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of [`twilight_validate::component::modal`]
+    /// for possible errors.
+    pub fn build(self) -> Result<InteractionResponse, ComponentValidationError> {
+        validate_modal(&self.custom_id, &self.title, &self.components)?;
+
+        Ok(InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(InteractionResponseData {
+                components: Some(self.components),
+                custom_id: Some(self.custom_id),
+                title: Some(self.title),
+                ..InteractionResponseData::default()
+            }),
+        })
+    }
+
+    /// Set the custom ID of the modal.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.custom_id = custom_id.into();
+
+        self
+    }
+
+    /// Add a text input to the modal in its own action row.
+    ///
+    /// Use a [`TextInputBuilder`] to configure whether the input is required,
+    /// its placeholder, and its minimum and maximum length.
+    ///
+    /// [`TextInputBuilder`]: crate::builder::component::TextInputBuilder
+    pub fn input(mut self, input: impl Into<TextInput>) -> Self {
+        self.components.push(Component::ActionRow(ActionRow {
+            components: Vec::from([Component::TextInput(input.into())]),
+        }));
+
+        self
+    }
+
+    /// Add a multi-line text input to the modal.
+    ///
+    /// Use [`input`] to configure the input further.
+    ///
+    /// [`input`]: Self::input
+    pub fn paragraph_input(self, custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.text_input(TextInputStyle::Paragraph, custom_id, label)
+    }
+
+    /// Add a single-line text input to the modal.
+    ///
+    /// Use [`input`] to configure the input further.
+    ///
+    /// [`input`]: Self::input
+    pub fn short_input(self, custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.text_input(TextInputStyle::Short, custom_id, label)
+    }
+
+    /// Set the title of the modal.
+    ///
+    /// Refer to [`MODAL_TITLE_LENGTH`] for the maximum number of characters
+    /// that can be in a title.
+    ///
+    /// [`MODAL_TITLE_LENGTH`]: twilight_validate::component::MODAL_TITLE_LENGTH
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Add a text input with no optional fields set.
+    fn text_input(
+        self,
+        style: TextInputStyle,
+        custom_id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.input(TextInput {
+            custom_id: custom_id.into(),
+            label: label.into(),
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: None,
+            style,
+            value: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModalBuilder;
+    use crate::builder::component::TextInputBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::channel::message::component::TextInputStyle;
+    use twilight_validate::component::ComponentValidationErrorType;
+
+    assert_impl_all!(ModalBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn modal() -> ModalBuilder {
+        ModalBuilder::new().custom_id("modal").title("Title")
+    }
+
+    #[test]
+    fn cap() {
+        assert!(matches!(
+            modal().build().unwrap_err().kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 0 }
+        ));
+
+        let builder = (0..5).fold(modal(), |builder, index| {
+            builder.short_input(index.to_string(), "Label")
+        });
+        assert!(builder.clone().build().is_ok());
+
+        assert!(matches!(
+            builder
+                .paragraph_input("5", "Label")
+                .build()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 6 }
+        ));
+    }
+
+    #[test]
+    fn lengths() {
+        assert!(matches!(
+            modal()
+                .custom_id("a".repeat(101))
+                .build()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ComponentCustomIdLength { chars: 101 }
+        ));
+        assert!(matches!(
+            modal().title("").build().unwrap_err().kind(),
+            ComponentValidationErrorType::ModalTitleLength { chars: 0 }
+        ));
+        assert!(matches!(
+            modal().title("a".repeat(46)).build().unwrap_err().kind(),
+            ComponentValidationErrorType::ModalTitleLength { chars: 46 }
+        ));
+        assert!(matches!(
+            modal()
+                .short_input("input", "a".repeat(46))
+                .build()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::TextInputLabelLength { len: 46 }
+        ));
+        assert!(matches!(
+            modal()
+                .input(TextInputBuilder::new(TextInputStyle::Short, "input", "Label").max_length(0))
+                .build()
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::TextInputMaxLength { len: 0 }
+        ));
+    }
+
+    #[test]
+    fn serialized() {
+        let response = modal()
+            .short_input("name", "Name")
+            .input(
+                TextInputBuilder::new(TextInputStyle::Paragraph, "bio", "Bio")
+                    .max_length(500)
+                    .min_length(10)
+                    .placeholder("About you")
+                    .required(false),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            r#"{"type":9,"data":{"components":[{"type":1,"components":[{"type":4,"custom_id":"name","label":"Name","style":1}]},{"type":1,"components":[{"type":4,"custom_id":"bio","label":"Bio","max_length":500,"min_length":10,"placeholder":"About you","required":false,"style":2}]}],"custom_id":"modal","title":"Title"}}"#,
+            serde_json::to_string(&response).unwrap()
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Encode state into and parse state from component custom IDs.
+//!
+//! Custom IDs are commonly used to carry state, such as the action to perform
+//! and the user to perform it for, between sending a component and receiving
+//! an interaction from it. [`CustomId`] provides a well-defined encoding for
+//! this: a prefix followed by any number of values, each separated by a
+//! [separator]. Occurrences of the separator and [escape] character inside of
+//! the prefix or values are escaped, so any string may be round-tripped.
+//!
+//! [escape]: CustomId::ESCAPE
+//! [separator]: CustomId::SEPARATOR
+
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
+
+/// Error when [encoding] a custom ID.
+///
+/// [encoding]: CustomId::encode
+#[derive(Debug)]
+pub struct CustomIdError {
+    kind: CustomIdErrorType,
+}
+
+impl CustomIdError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CustomIdErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CustomIdErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for CustomIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CustomIdErrorType::TooLong { chars } => {
+                f.write_str("encoded custom id is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&CustomId::MAX_CHARS, f)
+            }
+        }
+    }
+}
+
+impl Error for CustomIdError {}
+
+/// Type of [`CustomIdError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CustomIdErrorType {
+    /// Encoded custom ID is longer than [`CustomId::MAX_CHARS`].
+    TooLong {
+        /// Number of characters of the encoded custom ID.
+        chars: usize,
+    },
+}
+
+/// Custom ID parsed into its prefix and values.
+///
+/// # Examples
+///
+/// Encode the page of a paginated message and the user who may turn it, then
+/// parse it back:
+///
+/// ```
+/// use twilight_util::custom_id::CustomId;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let custom_id = CustomId::encode("page", &[&"114941315417899012", &3])?;
+/// assert_eq!("page:114941315417899012:3", custom_id);
+///
+/// let parsed = CustomId::parse(&custom_id);
+/// assert_eq!("page", parsed.prefix());
+/// assert_eq!(Some("114941315417899012"), parsed.value(0));
+/// assert_eq!(Some(3), parsed.value(1).map(str::parse).transpose()?);
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomId<'a> {
+    prefix: Cow<'a, str>,
+    values: Vec<Cow<'a, str>>,
+}
+
+impl<'a> CustomId<'a> {
+    /// Character escaping the following character.
+    pub const ESCAPE: char = '\\';
+
+    /// Maximum number of characters of a custom ID.
+    ///
+    /// This is the same as `twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH`.
+    pub const MAX_CHARS: usize = 100;
+
+    /// Character separating the prefix and each value.
+    pub const SEPARATOR: char = ':';
+
+    /// Encode a prefix and values into a custom ID.
+    ///
+    /// Values are formatted via their [`Display`] implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CustomIdErrorType::TooLong`] if the encoded
+    /// custom ID is longer than [`MAX_CHARS`].
+    ///
+    /// [`MAX_CHARS`]: Self::MAX_CHARS
+    pub fn encode(prefix: &str, values: &[&dyn Display]) -> Result<String, CustomIdError> {
+        let mut custom_id = String::new();
+        let mut escaper = Escaper(&mut custom_id);
+
+        // Writing to a string and escaping cannot fail.
+        _ = escaper.write_str(prefix);
+
+        for value in values {
+            escaper.0.push(Self::SEPARATOR);
+            _ = write!(escaper, "{value}");
+        }
+
+        let chars = custom_id.chars().count();
+        if chars > Self::MAX_CHARS {
+            return Err(CustomIdError {
+                kind: CustomIdErrorType::TooLong { chars },
+            });
+        }
+
+        Ok(custom_id)
+    }
+
+    /// Parse a custom ID into its prefix and values.
+    ///
+    /// Parsing never fails: a custom ID without separators is parsed as just a
+    /// prefix. Segments that contain no escaped characters are borrowed from
+    /// the input.
+    pub fn parse(custom_id: &'a str) -> Self {
+        let mut prefix = None;
+        let mut values = Vec::new();
+        let mut start = 0;
+        // Unescaped current segment, if it contained any escaped character.
+        let mut owned: Option<String> = None;
+        let mut chars = custom_id.char_indices();
+
+        while let Some((idx, char)) = chars.next() {
+            match char {
+                Self::ESCAPE => {
+                    let segment = owned.get_or_insert_with(|| custom_id[start..idx].to_owned());
+
+                    // A trailing escape character has nothing to escape, so
+                    // keep it.
+                    segment.push(chars.next().map_or(Self::ESCAPE, |(_, next)| next));
+                }
+                Self::SEPARATOR => {
+                    let segment = owned
+                        .take()
+                        .map_or(Cow::Borrowed(&custom_id[start..idx]), Cow::Owned);
+
+                    if prefix.is_none() {
+                        prefix = Some(segment);
+                    } else {
+                        values.push(segment);
+                    }
+                    start = idx + Self::SEPARATOR.len_utf8();
+                }
+                other => {
+                    if let Some(segment) = owned.as_mut() {
+                        segment.push(other);
+                    }
+                }
+            }
+        }
+
+        let last = owned.map_or(Cow::Borrowed(&custom_id[start..]), Cow::Owned);
+
+        match prefix {
+            Some(prefix) => {
+                values.push(last);
+
+                Self { prefix, values }
+            }
+            None => Self {
+                prefix: last,
+                values,
+            },
+        }
+    }
+
+    /// Prefix of the custom ID, typically identifying the action to perform.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Value at the given index, if there is one.
+    pub fn value(&self, index: usize) -> Option<&str> {
+        self.values.get(index).map(AsRef::as_ref)
+    }
+
+    /// Values following the prefix.
+    pub fn values(&self) -> &[Cow<'a, str>] {
+        &self.values
+    }
+
+    /// Consume the custom ID, returning its prefix and values.
+    pub fn into_parts(self) -> (Cow<'a, str>, Vec<Cow<'a, str>>) {
+        (self.prefix, self.values)
+    }
+}
+
+/// Writer escaping the separator and escape characters.
+struct Escaper<'a>(&'a mut String);
+
+impl Write for Escaper<'_> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for char in s.chars() {
+            if matches!(char, CustomId::ESCAPE | CustomId::SEPARATOR) {
+                self.0.push(CustomId::ESCAPE);
+            }
+
+            self.0.push(char);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomId, CustomIdError, CustomIdErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{borrow::Cow, error::Error, fmt::Debug};
+
+    assert_impl_all!(CustomId<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CustomIdError: Debug, Error, Send, Sync);
+    assert_impl_all!(CustomIdErrorType: Debug, Send, Sync);
+
+    #[test]
+    fn encode() {
+        assert_eq!("action", CustomId::encode("action", &[]).unwrap());
+        assert_eq!("action:", CustomId::encode("action", &[&""]).unwrap());
+        assert_eq!(
+            "action:1:two",
+            CustomId::encode("action", &[&1, &"two"]).unwrap()
+        );
+        assert_eq!(
+            r"a\:b:c\\d:\\\:",
+            CustomId::encode("a:b", &[&r"c\d", &r"\:"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_too_long() {
+        let value = "a".repeat(CustomId::MAX_CHARS - 2);
+        assert!(CustomId::encode("a", &[&value]).is_ok());
+
+        let value = "a".repeat(CustomId::MAX_CHARS - 1);
+        let error = CustomId::encode("a", &[&value]).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            CustomIdErrorType::TooLong { chars: 101 }
+        ));
+
+        // Escape characters count towards the limit.
+        let value = ":".repeat(CustomId::MAX_CHARS / 2);
+        let error = CustomId::encode("a", &[&value]).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            CustomIdErrorType::TooLong { chars: 102 }
+        ));
+    }
+
+    #[test]
+    fn parse() {
+        let parsed = CustomId::parse("action:1:two");
+        assert_eq!("action", parsed.prefix());
+        assert_eq!(Some("1"), parsed.value(0));
+        assert_eq!(Some("two"), parsed.value(1));
+        assert!(parsed.value(2).is_none());
+        assert!(parsed
+            .values()
+            .iter()
+            .all(|value| matches!(value, Cow::Borrowed(_))));
+
+        let parsed = CustomId::parse("");
+        assert_eq!("", parsed.prefix());
+        assert!(parsed.values().is_empty());
+
+        let (prefix, values) = CustomId::parse(r"a\:b:c\\d:\\\:").into_parts();
+        assert_eq!("a:b", prefix);
+        assert_eq!([r"c\d", r"\:"].as_slice(), values.as_slice());
+
+        // Trailing escape characters are kept.
+        assert_eq!(r"a\", CustomId::parse(r"a\").prefix());
+    }
+
+    /// Test that every combination of characters, including the separator,
+    /// escape and multi-byte characters, round-trips.
+    #[test]
+    fn round_trip() {
+        const ALPHABET: [&str; 5] = ["a", ":", "\\", "é", "🦀"];
+
+        let mut strings = vec![String::new()];
+        for _ in 0..4 {
+            let longer = strings
+                .iter()
+                .flat_map(|string| ALPHABET.iter().map(move |char| format!("{string}{char}")))
+                .collect::<Vec<_>>();
+            strings.extend(longer);
+        }
+        strings.sort();
+        strings.dedup();
+
+        for prefix in &strings {
+            for value in &strings {
+                let custom_id = CustomId::encode(prefix, &[value, &"", value]).unwrap();
+                let (parsed_prefix, parsed_values) = CustomId::parse(&custom_id).into_parts();
+
+                assert_eq!(prefix, &parsed_prefix);
+                assert_eq!(
+                    [value.as_str(), "", value.as_str()].as_slice(),
+                    parsed_values.as_slice()
+                );
+            }
+        }
+    }
+}
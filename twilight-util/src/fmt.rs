@@ -0,0 +1,307 @@
+//! Utilities for formatting mentions of registered commands.
+//!
+//! Chat input commands are mentioned by their name and ID, such as
+//! `</config:123>`, and subcommands by their full path, such as
+//! `</config log:123>`. Mentions are displayed by the client as clickable
+//! links that prefill the command.
+
+use std::collections::BTreeMap;
+use twilight_model::{
+    application::command::{Command, CommandOption, CommandOptionType, CommandType},
+    id::{marker::CommandMarker, Id},
+};
+
+/// Format mentions of a registered command and its subcommands.
+///
+/// Mentions always use the canonical names of the command and its
+/// subcommands, never their localized names, as required by Discord.
+///
+/// # Examples
+///
+/// Mention a subcommand of a command returned when registering it:
+///
+/// ```no_run
+/// # use twilight_model::application::command::Command;
+/// use twilight_util::fmt::CommandMentionFormatter;
+///
+/// # let command: Command = unimplemented!();
+/// let formatter = CommandMentionFormatter::new(&command);
+///
+/// if let Some(mention) = formatter.mention_subcommand(&["log"]) {
+///     println!("use {mention} to configure logging");
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "has no effect if unused"]
+pub struct CommandMentionFormatter<'a> {
+    /// Command to mention.
+    command: &'a Command,
+}
+
+impl<'a> CommandMentionFormatter<'a> {
+    /// Create a new formatter for a registered command.
+    pub const fn new(command: &'a Command) -> Self {
+        Self { command }
+    }
+
+    /// Mention the command itself.
+    ///
+    /// Returns [`None`] if the command has no ID, such as if it hasn't been
+    /// registered, isn't a [`CommandType::ChatInput`] command, or has
+    /// subcommands, in which case only they can be mentioned.
+    pub fn mention(&self) -> Option<String> {
+        self.mention_subcommand(&[])
+    }
+
+    /// Mention a subcommand by its path of names below the command, such as
+    /// `["log"]` for a subcommand or `["settings", "log"]` for a subcommand
+    /// in a subcommand group.
+    ///
+    /// An empty path mentions the command itself.
+    ///
+    /// Returns [`None`] if the command has no ID, isn't a
+    /// [`CommandType::ChatInput`] command, or has no subcommand at the path.
+    /// Subcommand groups and commands with subcommands can't be mentioned, so
+    /// [`None`] is also returned for paths ending at them.
+    pub fn mention_subcommand(&self, path: &[&str]) -> Option<String> {
+        let id = self.id()?;
+        let mut options = self.command.options.as_slice();
+
+        for name in path {
+            let option = options.iter().find(|option| {
+                option.name == *name
+                    && matches!(
+                        option.kind,
+                        CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+                    )
+            })?;
+
+            options = option.options.as_deref().unwrap_or_default();
+        }
+
+        if options.iter().any(|option| {
+            matches!(
+                option.kind,
+                CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+            )
+        }) {
+            return None;
+        }
+
+        Some(format!("</{}:{id}>", join_path(&self.command.name, path)))
+    }
+
+    /// Mentions of every mentionable path of the command, keyed by the path.
+    ///
+    /// Paths are the space separated names of the command and its
+    /// subcommands, such as `"config log"`. Commands with subcommands can
+    /// only be mentioned via their subcommands, so only the command itself is
+    /// included if it has none.
+    ///
+    /// Returns an empty map if the command has no ID or isn't a
+    /// [`CommandType::ChatInput`] command.
+    pub fn mentions(&self) -> BTreeMap<String, String> {
+        let mut mentions = BTreeMap::new();
+
+        if let Some(id) = self.id() {
+            let mut path = Vec::new();
+            collect(&self.command.options, &mut path, &mut |path| {
+                let key = join_path(&self.command.name, path);
+                let mention = format!("</{key}:{id}>");
+
+                mentions.insert(key, mention);
+            });
+        }
+
+        mentions
+    }
+
+    /// ID of the command, if it can be mentioned.
+    fn id(&self) -> Option<Id<CommandMarker>> {
+        (self.command.kind == CommandType::ChatInput)
+            .then_some(self.command.id)
+            .flatten()
+    }
+}
+
+/// Call `f` with the path of every subcommand below `options`, or the empty
+/// path if there are none.
+fn collect<'a>(options: &'a [CommandOption], path: &mut Vec<&'a str>, f: &mut impl FnMut(&[&str])) {
+    let mut has_subcommands = false;
+
+    for option in options {
+        match option.kind {
+            CommandOptionType::SubCommand => {
+                has_subcommands = true;
+
+                path.push(&option.name);
+                f(path);
+                path.pop();
+            }
+            CommandOptionType::SubCommandGroup => {
+                has_subcommands = true;
+
+                path.push(&option.name);
+                collect(option.options.as_deref().unwrap_or_default(), path, f);
+                path.pop();
+            }
+            _ => {}
+        }
+    }
+
+    if !has_subcommands && path.is_empty() {
+        f(path);
+    }
+}
+
+/// Join the name of a command and the path of names below it with spaces.
+fn join_path(name: &str, path: &[&str]) -> String {
+    let mut joined = name.to_owned();
+
+    for segment in path {
+        joined.push(' ');
+        joined.push_str(segment);
+    }
+
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandMentionFormatter;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        application::command::{Command, CommandOption, CommandOptionType, CommandType},
+        id::Id,
+    };
+
+    assert_impl_all!(CommandMentionFormatter<'_>: Clone, Debug, Send, Sync);
+
+    fn option(
+        kind: CommandOptionType,
+        name: &str,
+        options: Option<Vec<CommandOption>>,
+    ) -> CommandOption {
+        CommandOption {
+            autocomplete: None,
+            channel_types: None,
+            choices: None,
+            description: String::new(),
+            description_localizations: None,
+            kind,
+            max_length: None,
+            max_value: None,
+            min_length: None,
+            min_value: None,
+            name: name.to_owned(),
+            name_localizations: Some([("fr".to_owned(), format!("{name}-fr"))].into()),
+            options,
+            required: None,
+        }
+    }
+
+    #[allow(deprecated)]
+    fn command(options: Vec<CommandOption>) -> Command {
+        Command {
+            application_id: None,
+            contexts: None,
+            default_member_permissions: None,
+            dm_permission: None,
+            description: String::new(),
+            description_localizations: None,
+            guild_id: None,
+            id: Some(Id::new(123_456)),
+            integration_types: None,
+            kind: CommandType::ChatInput,
+            name: "config".to_owned(),
+            name_localizations: Some([("fr".to_owned(), "configurer".to_owned())].into()),
+            nsfw: None,
+            options,
+            version: Id::new(1),
+        }
+    }
+
+    #[test]
+    fn nested() {
+        let command = command(Vec::from([
+            option(
+                CommandOptionType::SubCommand,
+                "log",
+                Some(Vec::from([option(
+                    CommandOptionType::String,
+                    "channel",
+                    None,
+                )])),
+            ),
+            option(
+                CommandOptionType::SubCommandGroup,
+                "roles",
+                Some(Vec::from([
+                    option(CommandOptionType::SubCommand, "add", None),
+                    option(CommandOptionType::SubCommand, "remove", None),
+                ])),
+            ),
+        ]));
+        let formatter = CommandMentionFormatter::new(&command);
+
+        assert_eq!(
+            Some("</config log:123456>".to_owned()),
+            formatter.mention_subcommand(&["log"])
+        );
+        assert_eq!(
+            Some("</config roles add:123456>".to_owned()),
+            formatter.mention_subcommand(&["roles", "add"])
+        );
+        assert!(formatter.mention_subcommand(&["roles"]).is_none());
+        assert!(formatter.mention().is_none());
+        assert!(formatter.mention_subcommand(&["log", "channel"]).is_none());
+        assert!(formatter.mention_subcommand(&["roles", "list"]).is_none());
+        assert!(formatter.mention_subcommand(&["roles-fr"]).is_none());
+
+        let mentions = formatter.mentions();
+        assert_eq!(
+            Vec::from([
+                ("config log", "</config log:123456>"),
+                ("config roles add", "</config roles add:123456>"),
+                ("config roles remove", "</config roles remove:123456>"),
+            ]),
+            mentions
+                .iter()
+                .map(|(path, mention)| (path.as_str(), mention.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn base() {
+        let command = command(Vec::from([option(
+            CommandOptionType::Boolean,
+            "enabled",
+            None,
+        )]));
+        let formatter = CommandMentionFormatter::new(&command);
+
+        assert_eq!(Some("</config:123456>".to_owned()), formatter.mention());
+        assert_eq!(
+            Vec::from([("config".to_owned(), "</config:123456>".to_owned())]),
+            formatter.mentions().into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn unmentionable() {
+        let mut unregistered = command(Vec::new());
+        unregistered.id = None;
+        assert!(CommandMentionFormatter::new(&unregistered)
+            .mention()
+            .is_none());
+        assert!(CommandMentionFormatter::new(&unregistered)
+            .mentions()
+            .is_empty());
+
+        let mut user = command(Vec::new());
+        user.kind = CommandType::User;
+        assert!(CommandMentionFormatter::new(&user).mention().is_none());
+    }
+}
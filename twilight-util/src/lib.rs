@@ -16,6 +16,12 @@
 #[cfg(feature = "builder")]
 pub mod builder;
 
+#[cfg(feature = "custom-id")]
+pub mod custom_id;
+
+#[cfg(feature = "fmt")]
+pub mod fmt;
+
 #[cfg(feature = "link")]
 pub mod link;
 
@@ -24,3 +30,6 @@ pub mod permission_calculator;
 
 #[cfg(feature = "snowflake")]
 pub mod snowflake;
+
+#[cfg(feature = "voice")]
+pub mod voice;
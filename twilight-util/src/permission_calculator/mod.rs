@@ -176,43 +176,25 @@ impl<'a> PermissionCalculator<'a> {
     /// Calculate the guild-level permissions of a member.
     #[must_use = "calculating permissions is only useful if they're used"]
     pub const fn root(&self) -> Permissions {
-        // If the user is the owner, then we can just return all of the
-        // permissions.
-        if matches!(self.owner_id, Some(id) if id.get() == self.user_id.get()) {
-            return Permissions::all();
-        }
-
-        // If the `@everyone` role has the `ADMINISTRATOR` permission for some
-        // reason, then we can just return all permissions.
-        if self.everyone_role.contains(Permissions::ADMINISTRATOR) {
-            return Permissions::all();
-        }
-
-        // The permissions that the @everyone role has is the baseline.
-        let mut permissions = self.everyone_role;
-
         // At time of writing `const` functions don't support `for` loops, so we
         // use a `while` loop.
         let member_role_count = self.member_roles.len();
+        let mut role_permissions = Permissions::empty();
         let mut idx = 0;
 
         // Loop over all of the member's roles, adding them to the total
         // permissions. Role permissions are only additive.
-        //
-        // If one of the roles contains the `ADMINISTRATOR` permission then the
-        // loop can be short-circuited.
         while idx < member_role_count {
-            let (_, role_permissions) = self.member_roles[idx];
-
-            if role_permissions.contains(Permissions::ADMINISTRATOR) {
-                return Permissions::all();
-            }
-
-            permissions = bitops::insert(permissions, role_permissions);
+            let (_, permissions) = self.member_roles[idx];
+            role_permissions = bitops::insert(role_permissions, permissions);
             idx += 1;
         }
 
-        permissions
+        root_permissions(
+            self.everyone_role,
+            role_permissions,
+            is_owner(self.owner_id, self.user_id),
+        )
     }
 
     /// Calculate the permissions of the member in a channel, taking into
@@ -359,68 +341,306 @@ impl<'a> PermissionCalculator<'a> {
         channel_type: ChannelType,
         channel_overwrites: &[PermissionOverwrite],
     ) -> Permissions {
-        let mut permissions = self.root();
+        let root = self.root();
 
         // If the user contains the administrator privilege from the calculated
         // root permissions, then we do not need to do any more work.
-        if permissions.contains(Permissions::ADMINISTRATOR) {
+        if root.contains(Permissions::ADMINISTRATOR) {
             return Permissions::all();
         }
 
-        permissions = bitops::remove(permissions, PERMISSIONS_ROOT_ONLY);
+        let member_role_count = self.member_roles.len();
+        let mut roles_allow = Permissions::empty();
+        let mut roles_deny = Permissions::empty();
+        let mut idx = 0;
+
+        while idx < member_role_count {
+            let (role_id, _) = self.member_roles[idx];
+            let (allow, deny) = role_overwrites(channel_overwrites, self.guild_id, role_id);
+            roles_allow = bitops::insert(roles_allow, allow);
+            roles_deny = bitops::insert(roles_deny, deny);
+            idx += 1;
+        }
 
-        permissions = process_permission_overwrites(
-            permissions,
+        channel_permissions(
+            root,
+            channel_type,
             channel_overwrites,
-            self.member_roles,
+            roles_allow,
+            roles_deny,
             self.guild_id,
             self.user_id,
-        );
+        )
+    }
+}
 
-        // If the permission set is empty then we don't need to do any removals.
-        if permissions.is_empty() {
-            return permissions;
+/// Calculate the permissions of a member from an iterator over their roles.
+///
+/// This is equivalent to [`PermissionCalculator`], but the member's roles and
+/// their permissions don't need to be collected into a slice beforehand, such
+/// as when they're looked up from a cache. Roles are iterated over exactly once
+/// per calculation.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{channel::ChannelType, guild::Permissions, id::Id};
+/// use twilight_util::permission_calculator::IterPermissionCalculator;
+///
+/// let role_ids = [Id::new(2), Id::new(3)];
+/// let member_roles = role_ids
+///     .iter()
+///     .map(|role_id| (*role_id, Permissions::SEND_MESSAGES));
+///
+/// let calculator = IterPermissionCalculator::new(
+///     Id::new(1),
+///     Id::new(4),
+///     Permissions::VIEW_CHANNEL,
+///     member_roles,
+/// );
+///
+/// assert_eq!(
+///     Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+///     calculator.in_channel(ChannelType::GuildText, &[]),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "calculators aren't useful if you don't calculate permissions"]
+pub struct IterPermissionCalculator<I> {
+    /// Permissions of the `@everyone` role for the guild.
+    everyone_role: Permissions,
+    /// ID of the guild.
+    guild_id: Id<GuildMarker>,
+    /// Iterator over tuples of the member's roles and their permissions.
+    member_roles: I,
+    /// ID of the owner.
+    owner_id: Option<Id<UserMarker>>,
+    /// ID of the user whose permissions are being calculated.
+    user_id: Id<UserMarker>,
+}
+
+impl<I: IntoIterator<Item = (Id<RoleMarker>, Permissions)>> IterPermissionCalculator<I> {
+    /// Create a calculator to calculate the permissions of a member.
+    ///
+    /// `everyone_role` is the permissions of the `@everyone` role on a
+    /// guild-level; the permissions may be empty. The `@everyone` role's ID is
+    /// the same as that of the `guild_id`.
+    ///
+    /// The provided member's roles *should not* contain the `@everyone` role.
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub const fn new(
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        everyone_role: Permissions,
+        member_roles: I,
+    ) -> Self {
+        Self {
+            everyone_role,
+            guild_id,
+            member_roles,
+            owner_id: None,
+            user_id,
         }
+    }
+
+    /// Configure the ID of the owner of the guild.
+    ///
+    /// Refer to [`PermissionCalculator::owner_id`] for more information.
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub const fn owner_id(mut self, owner_id: Id<UserMarker>) -> Self {
+        self.owner_id = Some(owner_id);
+
+        self
+    }
+
+    /// Calculate the guild-level permissions of a member.
+    ///
+    /// Refer to [`PermissionCalculator::root`] for more information.
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub fn root(self) -> Permissions {
+        let role_permissions = self
+            .member_roles
+            .into_iter()
+            .fold(Permissions::empty(), |acc, (_, permissions)| {
+                acc | permissions
+            });
+
+        root_permissions(
+            self.everyone_role,
+            role_permissions,
+            is_owner(self.owner_id, self.user_id),
+        )
+    }
+
+    /// Calculate the permissions of the member in a channel.
+    ///
+    /// Refer to [`PermissionCalculator::in_channel`] for more information.
+    #[must_use = "calculating permissions is only useful if they're used"]
+    pub fn in_channel(
+        self,
+        channel_type: ChannelType,
+        channel_overwrites: &[PermissionOverwrite],
+    ) -> Permissions {
+        let guild_id = self.guild_id;
+
+        // Fold over the member's roles once, collecting both their permissions
+        // and the channel's overwrites for them.
+        let (role_permissions, roles_allow, roles_deny) = self.member_roles.into_iter().fold(
+            (
+                Permissions::empty(),
+                Permissions::empty(),
+                Permissions::empty(),
+            ),
+            |(permissions, roles_allow, roles_deny), (role_id, role_permissions)| {
+                let (allow, deny) = role_overwrites(channel_overwrites, guild_id, role_id);
+
+                (
+                    permissions | role_permissions,
+                    roles_allow | allow,
+                    roles_deny | deny,
+                )
+            },
+        );
 
-        // Remove permissions that can't be used in a channel, i.e. are relevant
-        // to guild-level permission calculating.
-        permissions = bitops::remove(permissions, PERMISSIONS_ROOT_ONLY);
-
-        // Remove the permissions not used by a channel depending on the channel
-        // type.
-        if matches!(channel_type, ChannelType::GuildStageVoice) {
-            permissions = bitops::remove(permissions, PERMISSIONS_STAGE_OMIT);
-        } else if matches!(channel_type, ChannelType::GuildText) {
-            permissions = bitops::remove(permissions, PERMISSIONS_TEXT_OMIT);
-        } else if matches!(channel_type, ChannelType::GuildVoice) {
-            permissions = bitops::remove(permissions, PERMISSIONS_VOICE_OMIT);
+        let root = root_permissions(
+            self.everyone_role,
+            role_permissions,
+            is_owner(self.owner_id, self.user_id),
+        );
+
+        // If the user contains the administrator privilege from the calculated
+        // root permissions, then we do not need to do any more work.
+        if root.contains(Permissions::ADMINISTRATOR) {
+            return Permissions::all();
         }
 
-        permissions
+        channel_permissions(
+            root,
+            channel_type,
+            channel_overwrites,
+            roles_allow,
+            roles_deny,
+            guild_id,
+            self.user_id,
+        )
+    }
+}
+
+/// Whether the user is the configured owner of the guild.
+const fn is_owner(owner_id: Option<Id<UserMarker>>, user_id: Id<UserMarker>) -> bool {
+    matches!(owner_id, Some(id) if id.get() == user_id.get())
+}
+
+/// Calculate the guild-level permissions of a member from the combined
+/// permissions of their roles.
+const fn root_permissions(
+    everyone_role: Permissions,
+    role_permissions: Permissions,
+    is_owner: bool,
+) -> Permissions {
+    // If the user is the owner, then we can just return all of the
+    // permissions.
+    if is_owner {
+        return Permissions::all();
+    }
+
+    // The permissions that the @everyone role has is the baseline.
+    let permissions = bitops::insert(everyone_role, role_permissions);
+
+    // If the `@everyone` role or one of the member's roles contains the
+    // `ADMINISTRATOR` permission then we can just return all permissions.
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    permissions
+}
+
+/// Calculate the permissions of a non-administrator member in a channel from
+/// their guild-level permissions and the combined overwrites of their roles.
+const fn channel_permissions(
+    root: Permissions,
+    channel_type: ChannelType,
+    channel_overwrites: &[PermissionOverwrite],
+    roles_allow: Permissions,
+    roles_deny: Permissions,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+) -> Permissions {
+    let mut permissions = bitops::remove(root, PERMISSIONS_ROOT_ONLY);
+
+    permissions = process_permission_overwrites(
+        permissions,
+        channel_overwrites,
+        roles_allow,
+        roles_deny,
+        guild_id,
+        user_id,
+    );
+
+    // If the permission set is empty then we don't need to do any removals.
+    if permissions.is_empty() {
+        return permissions;
+    }
+
+    // Remove permissions that can't be used in a channel, i.e. are relevant
+    // to guild-level permission calculating.
+    permissions = bitops::remove(permissions, PERMISSIONS_ROOT_ONLY);
+
+    // Remove the permissions not used by a channel depending on the channel
+    // type.
+    if matches!(channel_type, ChannelType::GuildStageVoice) {
+        permissions = bitops::remove(permissions, PERMISSIONS_STAGE_OMIT);
+    } else if matches!(channel_type, ChannelType::GuildText) {
+        permissions = bitops::remove(permissions, PERMISSIONS_TEXT_OMIT);
+    } else if matches!(channel_type, ChannelType::GuildVoice) {
+        permissions = bitops::remove(permissions, PERMISSIONS_VOICE_OMIT);
     }
+
+    permissions
 }
 
-const fn has_role(roles: &[(Id<RoleMarker>, Permissions)], role_id: Id<RoleMarker>) -> bool {
-    let len = roles.len();
+/// Combined allowed and denied permissions of a channel's overwrites for a
+/// member's role.
+///
+/// Overwrites for the `@everyone` role are processed separately and so are
+/// ignored.
+const fn role_overwrites(
+    channel_overwrites: &[PermissionOverwrite],
+    guild_id: Id<GuildMarker>,
+    role_id: Id<RoleMarker>,
+) -> (Permissions, Permissions) {
+    let mut allow = Permissions::empty();
+    let mut deny = Permissions::empty();
+
+    if role_id.get() == guild_id.get() {
+        return (allow, deny);
+    }
+
+    let channel_overwrite_len = channel_overwrites.len();
     let mut idx = 0;
 
-    while idx < len {
-        let (iter_role_id, _) = roles[idx];
+    while idx < channel_overwrite_len {
+        let overwrite = &channel_overwrites[idx];
 
-        if iter_role_id.get() == role_id.get() {
-            return true;
+        if matches!(overwrite.kind, PermissionOverwriteType::Role)
+            && overwrite.id.get() == role_id.get()
+        {
+            allow = bitops::insert(allow, overwrite.allow);
+            deny = bitops::insert(deny, overwrite.deny);
         }
 
         idx += 1;
     }
 
-    false
+    (allow, deny)
 }
 
 const fn process_permission_overwrites(
     mut permissions: Permissions,
     channel_overwrites: &[PermissionOverwrite],
-    member_roles: &[(Id<RoleMarker>, Permissions)],
+    mut roles_allow: Permissions,
+    roles_deny: Permissions,
     configured_guild_id: Id<GuildMarker>,
     configured_user_id: Id<UserMarker>,
 ) -> Permissions {
@@ -428,8 +648,6 @@ const fn process_permission_overwrites(
     // <https://discord.com/developers/docs/topics/permissions>
     let mut member_allow = Permissions::empty();
     let mut member_deny = Permissions::empty();
-    let mut roles_allow = Permissions::empty();
-    let mut roles_deny = Permissions::empty();
 
     let channel_overwrite_len = channel_overwrites.len();
     let mut idx = 0;
@@ -440,25 +658,13 @@ const fn process_permission_overwrites(
         match overwrite.kind {
             PermissionOverwriteType::Role => {
                 // We need to process the @everyone role first, so apply it
-                // straight to the permissions. The other roles' permissions
-                // will be applied later.
+                // straight to the permissions. The overwrites of the member's
+                // other roles have already been combined and are applied
+                // later.
                 if overwrite.id.get() == configured_guild_id.get() {
                     permissions = bitops::remove(permissions, overwrite.deny);
                     permissions = bitops::insert(permissions, overwrite.allow);
-
-                    idx += 1;
-
-                    continue;
                 }
-
-                if !has_role(member_roles, overwrite.id.cast()) {
-                    idx += 1;
-
-                    continue;
-                }
-
-                roles_allow = bitops::insert(roles_allow, overwrite.allow);
-                roles_deny = bitops::insert(roles_deny, overwrite.deny);
             }
             PermissionOverwriteType::Member => {
                 if overwrite.id.get() == configured_user_id.get() {
@@ -515,19 +721,20 @@ const fn process_permission_overwrites(
 
 #[cfg(test)]
 mod tests {
-    use super::{preset::PERMISSIONS_ROOT_ONLY, PermissionCalculator};
+    use super::{preset::PERMISSIONS_ROOT_ONLY, IterPermissionCalculator, PermissionCalculator};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, vec::IntoIter};
     use twilight_model::{
         channel::{
             permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
             ChannelType,
         },
         guild::Permissions,
-        id::Id,
+        id::{marker::RoleMarker, Id},
     };
 
     assert_impl_all!(PermissionCalculator<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(IterPermissionCalculator<IntoIter<(Id<RoleMarker>, Permissions)>>: Clone, Debug, Send, Sync);
 
     #[test]
     fn owner_is_admin() {
@@ -701,4 +908,98 @@ mod tests {
             assert!(!calculated.intersects(PERMISSIONS_ROOT_ONLY));
         }
     }
+
+    /// Test that the iterator-based calculator returns the same permissions as
+    /// the slice-based calculator.
+    #[test]
+    fn iter_matches_slice() {
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let role_sets: &[&[_]] = &[
+            &[],
+            &[(Id::new(3), Permissions::empty())],
+            &[(Id::new(3), Permissions::CONNECT | Permissions::SPEAK)],
+            &[
+                (Id::new(3), Permissions::empty()),
+                (Id::new(4), Permissions::ATTACH_FILES),
+            ],
+            &[(Id::new(3), Permissions::ADMINISTRATOR)],
+        ];
+        let everyone_roles = &[
+            Permissions::empty(),
+            Permissions::MENTION_EVERYONE | Permissions::SEND_MESSAGES,
+            Permissions::MANAGE_MESSAGES | Permissions::EMBED_LINKS | Permissions::MENTION_EVERYONE,
+            Permissions::CONNECT | Permissions::SEND_MESSAGES,
+            Permissions::ADMINISTRATOR,
+        ];
+        let overwrite_sets: &[&[_]] = &[
+            &[],
+            &[PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::VIEW_CHANNEL,
+                id: Id::new(1),
+                kind: PermissionOverwriteType::Role,
+            }],
+            &[PermissionOverwrite {
+                allow: Permissions::ATTACH_FILES,
+                deny: Permissions::SEND_MESSAGES,
+                id: Id::new(3),
+                kind: PermissionOverwriteType::Role,
+            }],
+            &[
+                PermissionOverwrite {
+                    allow: Permissions::empty(),
+                    deny: Permissions::VIEW_CHANNEL,
+                    id: Id::new(3),
+                    kind: PermissionOverwriteType::Role,
+                },
+                PermissionOverwrite {
+                    allow: Permissions::VIEW_CHANNEL,
+                    deny: Permissions::empty(),
+                    id: Id::new(4),
+                    kind: PermissionOverwriteType::Role,
+                },
+                PermissionOverwrite {
+                    allow: Permissions::empty(),
+                    deny: Permissions::SEND_MESSAGES,
+                    id: Id::new(2),
+                    kind: PermissionOverwriteType::Member,
+                },
+            ],
+        ];
+        let channel_types = &[
+            ChannelType::GuildStageVoice,
+            ChannelType::GuildText,
+            ChannelType::GuildVoice,
+        ];
+
+        for roles in role_sets {
+            for everyone_role in everyone_roles {
+                let slice = PermissionCalculator::new(guild_id, user_id, *everyone_role, roles);
+                let iter = || {
+                    IterPermissionCalculator::new(
+                        guild_id,
+                        user_id,
+                        *everyone_role,
+                        roles.iter().copied(),
+                    )
+                };
+
+                assert_eq!(slice.root(), iter().root());
+                assert_eq!(
+                    slice.clone().owner_id(user_id).root(),
+                    iter().owner_id(user_id).root()
+                );
+
+                for overwrites in overwrite_sets {
+                    for channel_type in channel_types {
+                        assert_eq!(
+                            slice.clone().in_channel(*channel_type, overwrites),
+                            iter().in_channel(*channel_type, overwrites),
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,327 @@
+//! Track the voice connection information of the current user.
+//!
+//! Connecting to a voice server, either directly or through a service such as
+//! Lavalink, requires the session ID from the current user's
+//! [`VoiceStateUpdate`] and the token and endpoint from the guild's
+//! [`VoiceServerUpdate`]. These events may arrive in any order and either half
+//! may be refreshed while connected, so the [`VoiceStateTracker`] pairs them
+//! per guild.
+//!
+//! [`VoiceServerUpdate`]: twilight_model::gateway::payload::incoming::VoiceServerUpdate
+//! [`VoiceStateUpdate`]: twilight_model::gateway::payload::incoming::VoiceStateUpdate
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+use twilight_model::{
+    gateway::event::Event,
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Information required to connect to a guild's voice server.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VoiceConnectionInfo {
+    /// ID of the voice channel the current user is connected to.
+    pub channel_id: Id<ChannelMarker>,
+    /// Voice server endpoint.
+    pub endpoint: String,
+    /// ID of the guild.
+    pub guild_id: Id<GuildMarker>,
+    /// Voice session ID of the current user.
+    pub session_id: String,
+    /// Voice server authentication token.
+    pub token: String,
+}
+
+/// Halves of a guild's voice connection information received so far.
+#[derive(Debug, Default)]
+struct PartialConnection {
+    /// Channel ID and session ID from the voice state update.
+    state: Option<(Id<ChannelMarker>, String)>,
+    /// Endpoint and token from the voice server update.
+    server: Option<(String, String)>,
+}
+
+impl PartialConnection {
+    /// Connection information, if both halves are present.
+    fn info(&self, guild_id: Id<GuildMarker>) -> Option<VoiceConnectionInfo> {
+        let (channel_id, session_id) = self.state.as_ref()?;
+        let (endpoint, token) = self.server.as_ref()?;
+
+        Some(VoiceConnectionInfo {
+            channel_id: *channel_id,
+            endpoint: endpoint.clone(),
+            guild_id,
+            session_id: session_id.clone(),
+            token: token.clone(),
+        })
+    }
+}
+
+/// Pair voice state and voice server updates of the current user per guild.
+///
+/// Events must be passed to [`process`], which returns the connection
+/// information when an event completes or refreshes a pairing. This may then
+/// be forwarded to a voice implementation. [`connection_info`] returns the
+/// latest complete information of a guild.
+///
+/// A voice server update without an endpoint, sent while Discord migrates the
+/// voice server, removes the server half until a new one arrives. Leaving the
+/// voice channel or the guild clears both halves.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{
+///     gateway::{event::Event, payload::incoming::VoiceServerUpdate},
+///     id::Id,
+/// };
+/// use twilight_util::voice::VoiceStateTracker;
+///
+/// let tracker = VoiceStateTracker::new(Id::new(1));
+///
+/// let event = Event::VoiceServerUpdate(VoiceServerUpdate {
+///     endpoint: Some("voice.discord.media".to_owned()),
+///     guild_id: Id::new(2),
+///     token: "token".to_owned(),
+/// });
+///
+/// // The voice state update of the current user hasn't been received yet.
+/// assert!(tracker.process(&event).is_none());
+/// ```
+///
+/// [`connection_info`]: Self::connection_info
+/// [`process`]: Self::process
+#[derive(Debug)]
+pub struct VoiceStateTracker {
+    /// Partial connection information, keyed by guild.
+    guilds: Mutex<HashMap<Id<GuildMarker>, PartialConnection>>,
+    /// ID of the current user.
+    user_id: Id<UserMarker>,
+}
+
+impl VoiceStateTracker {
+    /// Create a new tracker for the current user.
+    pub fn new(user_id: Id<UserMarker>) -> Self {
+        Self {
+            guilds: Mutex::new(HashMap::new()),
+            user_id,
+        }
+    }
+
+    /// Latest complete connection information of a guild.
+    ///
+    /// Returns `None` if either half has not been received.
+    pub fn connection_info(&self, guild_id: Id<GuildMarker>) -> Option<VoiceConnectionInfo> {
+        self.guilds()
+            .get(&guild_id)
+            .and_then(|connection| connection.info(guild_id))
+    }
+
+    /// Process an event.
+    ///
+    /// Returns the connection information of a guild when the event
+    /// completed or refreshed it. Events other than the current user's voice
+    /// state updates, voice server updates, and guild deletions are ignored.
+    pub fn process(&self, event: &Event) -> Option<VoiceConnectionInfo> {
+        let mut guilds = self.guilds();
+
+        let guild_id = match event {
+            Event::GuildDelete(guild) => {
+                guilds.remove(&guild.id);
+
+                return None;
+            }
+            Event::VoiceServerUpdate(update) => {
+                let connection = guilds.entry(update.guild_id).or_default();
+                connection.server = update
+                    .endpoint
+                    .clone()
+                    .map(|endpoint| (endpoint, update.token.clone()));
+
+                update.guild_id
+            }
+            Event::VoiceStateUpdate(update) => {
+                if update.user_id != self.user_id {
+                    return None;
+                }
+
+                let guild_id = update.guild_id?;
+
+                let Some(channel_id) = update.channel_id else {
+                    guilds.remove(&guild_id);
+
+                    return None;
+                };
+
+                guilds.entry(guild_id).or_default().state =
+                    Some((channel_id, update.session_id.clone()));
+
+                guild_id
+            }
+            _ => return None,
+        };
+
+        guilds
+            .get(&guild_id)
+            .and_then(|connection| connection.info(guild_id))
+    }
+
+    /// Lock the partial connection information.
+    ///
+    /// No user code runs while the lock is held, so the map is consistent even
+    /// if a thread panicked while holding it.
+    fn guilds(&self) -> MutexGuard<'_, HashMap<Id<GuildMarker>, PartialConnection>> {
+        self.guilds.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{VoiceConnectionInfo, VoiceStateTracker};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::{
+            event::Event,
+            payload::incoming::{GuildDelete, VoiceServerUpdate, VoiceStateUpdate},
+        },
+        id::{
+            marker::{ChannelMarker, UserMarker},
+            Id,
+        },
+        voice::VoiceState,
+    };
+
+    assert_impl_all!(VoiceStateTracker: Debug, Send, Sync);
+    assert_impl_all!(VoiceConnectionInfo: Clone, Debug, Eq, Send, Sync);
+
+    fn server(endpoint: Option<&str>, token: &str) -> Event {
+        Event::VoiceServerUpdate(VoiceServerUpdate {
+            endpoint: endpoint.map(ToOwned::to_owned),
+            guild_id: Id::new(2),
+            token: token.to_owned(),
+        })
+    }
+
+    fn state(
+        user_id: Id<UserMarker>,
+        channel_id: Option<Id<ChannelMarker>>,
+        session_id: &str,
+    ) -> Event {
+        Event::VoiceStateUpdate(Box::new(VoiceStateUpdate(VoiceState {
+            channel_id,
+            deaf: false,
+            guild_id: Some(Id::new(2)),
+            member: None,
+            mute: false,
+            self_deaf: false,
+            self_mute: false,
+            self_stream: false,
+            self_video: false,
+            session_id: session_id.to_owned(),
+            suppress: false,
+            user_id,
+            request_to_speak_timestamp: None,
+        })))
+    }
+
+    fn info(endpoint: &str, session_id: &str, token: &str) -> VoiceConnectionInfo {
+        VoiceConnectionInfo {
+            channel_id: Id::new(3),
+            endpoint: endpoint.to_owned(),
+            guild_id: Id::new(2),
+            session_id: session_id.to_owned(),
+            token: token.to_owned(),
+        }
+    }
+
+    #[test]
+    fn state_then_server() {
+        let tracker = VoiceStateTracker::new(Id::new(1));
+
+        assert!(tracker
+            .process(&state(Id::new(1), Some(Id::new(3)), "a"))
+            .is_none());
+        assert_eq!(
+            Some(info("b", "a", "c")),
+            tracker.process(&server(Some("b"), "c"))
+        );
+        assert_eq!(
+            Some(info("b", "a", "c")),
+            tracker.connection_info(Id::new(2))
+        );
+    }
+
+    #[test]
+    fn server_then_state() {
+        let tracker = VoiceStateTracker::new(Id::new(1));
+
+        assert!(tracker.process(&server(Some("b"), "c")).is_none());
+        assert!(tracker.connection_info(Id::new(2)).is_none());
+        assert_eq!(
+            Some(info("b", "a", "c")),
+            tracker.process(&state(Id::new(1), Some(Id::new(3)), "a"))
+        );
+    }
+
+    #[test]
+    fn other_user_ignored() {
+        let tracker = VoiceStateTracker::new(Id::new(1));
+
+        assert!(tracker.process(&server(Some("b"), "c")).is_none());
+        assert!(tracker
+            .process(&state(Id::new(4), Some(Id::new(3)), "a"))
+            .is_none());
+        assert!(tracker.connection_info(Id::new(2)).is_none());
+    }
+
+    #[test]
+    fn endpoint_migration() {
+        let tracker = VoiceStateTracker::new(Id::new(1));
+        tracker.process(&state(Id::new(1), Some(Id::new(3)), "a"));
+        tracker.process(&server(Some("b"), "c"));
+
+        assert!(tracker.process(&server(None, "c")).is_none());
+        assert!(tracker.connection_info(Id::new(2)).is_none());
+
+        assert_eq!(
+            Some(info("d", "a", "e")),
+            tracker.process(&server(Some("d"), "e"))
+        );
+    }
+
+    #[test]
+    fn session_refresh() {
+        let tracker = VoiceStateTracker::new(Id::new(1));
+        tracker.process(&state(Id::new(1), Some(Id::new(3)), "a"));
+        tracker.process(&server(Some("b"), "c"));
+
+        assert_eq!(
+            Some(info("b", "f", "c")),
+            tracker.process(&state(Id::new(1), Some(Id::new(3)), "f"))
+        );
+    }
+
+    #[test]
+    fn disconnect_clears() {
+        let tracker = VoiceStateTracker::new(Id::new(1));
+        tracker.process(&state(Id::new(1), Some(Id::new(3)), "a"));
+        tracker.process(&server(Some("b"), "c"));
+
+        assert!(tracker.process(&state(Id::new(1), None, "a")).is_none());
+        assert!(tracker.connection_info(Id::new(2)).is_none());
+
+        tracker.process(&state(Id::new(1), Some(Id::new(3)), "a"));
+        tracker.process(&server(Some("b"), "c"));
+        tracker.process(&Event::GuildDelete(GuildDelete {
+            id: Id::new(2),
+            unavailable: None,
+        }));
+        assert!(tracker.connection_info(Id::new(2)).is_none());
+    }
+}
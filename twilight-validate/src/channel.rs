@@ -4,7 +4,10 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::ChannelType;
+use twilight_model::{
+    channel::{forum::ForumTag, ChannelType},
+    id::{marker::TagMarker, Id},
+};
 
 /// Minimum bitrate of a voice channel.
 pub const CHANNEL_BITRATE_MIN: u32 = 8000;
@@ -15,6 +18,12 @@ pub const CHANNEL_BULK_DELETE_MESSAGES_MAX: usize = 100;
 /// Minimum number of bulk messages that can be deleted.
 pub const CHANNEL_BULK_DELETE_MESSAGES_MIN: usize = 2;
 
+/// Maximum length of a forum tag's name.
+pub const CHANNEL_FORUM_TAG_NAME_LENGTH_MAX: usize = 20;
+
+/// Maximum number of tags that can be applied to a forum thread.
+pub const CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX: usize = 5;
+
 /// Maximum length of a forum channel's topic.
 pub const CHANNEL_FORUM_TOPIC_LENGTH_MAX: usize = 4096;
 
@@ -86,6 +95,23 @@ impl Display for ChannelValidationError {
 
                 Display::fmt(&CHANNEL_BULK_DELETE_MESSAGES_MAX, f)
             }
+            ChannelValidationErrorType::ForumTagEmojiInvalid => {
+                f.write_str("forum tag can't have both a custom and a unicode emoji")
+            }
+            ChannelValidationErrorType::ForumTagNameInvalid { len } => {
+                f.write_str("forum tag name length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but it must be at most ")?;
+
+                Display::fmt(&CHANNEL_FORUM_TAG_NAME_LENGTH_MAX, f)
+            }
+            ChannelValidationErrorType::ForumThreadAppliedTagsInvalid { len } => {
+                f.write_str("forum thread has ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" applied tags, but it may have at most ")?;
+
+                Display::fmt(&CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX, f)
+            }
             ChannelValidationErrorType::ForumTopicInvalid => {
                 f.write_str("the forum topic is invalid")
             }
@@ -127,6 +153,18 @@ pub enum ChannelValidationErrorType {
     BitrateInvalid,
     /// Number of messages being deleted in bulk is invalid.
     BulkDeleteMessagesInvalid,
+    /// The forum tag has both an emoji ID and an emoji name.
+    ForumTagEmojiInvalid,
+    /// The length of the forum tag's name is more than 20 characters.
+    ForumTagNameInvalid {
+        /// Provided length.
+        len: usize,
+    },
+    /// More than 5 tags were applied to a forum thread.
+    ForumThreadAppliedTagsInvalid {
+        /// Provided number of tags.
+        len: usize,
+    },
     /// The length of the topic is more than 4096 UTF-16 characters.
     ForumTopicInvalid,
     /// The length of the name is either fewer than 1 UTF-16 characters or
@@ -208,6 +246,67 @@ pub const fn is_thread(kind: ChannelType) -> Result<(), ChannelValidationError>
     }
 }
 
+/// Ensure a forum tag is correct.
+///
+/// The name's length must be at most [`CHANNEL_FORUM_TAG_NAME_LENGTH_MAX`], and
+/// at most one of the tag's emoji ID and emoji name may be set. This is based
+/// on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ForumTagNameInvalid`] if the name's length is
+/// invalid.
+///
+/// Returns an error of type [`ForumTagEmojiInvalid`] if both the emoji ID and
+/// the emoji name are set.
+///
+/// [`ForumTagEmojiInvalid`]: ChannelValidationErrorType::ForumTagEmojiInvalid
+/// [`ForumTagNameInvalid`]: ChannelValidationErrorType::ForumTagNameInvalid
+/// [this documentation entry]: https://discord.com/developers/docs/resources/channel#forum-tag-object
+pub fn forum_tag(tag: &ForumTag) -> Result<(), ChannelValidationError> {
+    let len = tag.name.chars().count();
+
+    if len > CHANNEL_FORUM_TAG_NAME_LENGTH_MAX {
+        return Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumTagNameInvalid { len },
+        });
+    }
+
+    if tag.emoji_id.is_some() && tag.emoji_name.is_some() {
+        return Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumTagEmojiInvalid,
+        });
+    }
+
+    Ok(())
+}
+
+/// Ensure the number of tags applied to a forum thread is correct.
+///
+/// The number of tags must be at most [`CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX`].
+/// This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ForumThreadAppliedTagsInvalid`] if there are too
+/// many tags.
+///
+/// [`ForumThreadAppliedTagsInvalid`]: ChannelValidationErrorType::ForumThreadAppliedTagsInvalid
+/// [this documentation entry]: https://discord.com/developers/docs/resources/channel#start-thread-in-forum-or-media-channel-jsonform-params
+pub const fn forum_thread_applied_tags(
+    applied_tags: &[Id<TagMarker>],
+) -> Result<(), ChannelValidationError> {
+    let len = applied_tags.len();
+
+    if len <= CHANNEL_FORUM_THREAD_APPLIED_TAGS_MAX {
+        Ok(())
+    } else {
+        Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::ForumThreadAppliedTagsInvalid { len },
+        })
+    }
+}
+
 /// Ensure a forum channel's topic's length is correct.
 ///
 /// # Errors
@@ -341,6 +440,7 @@ pub const fn user_limit(value: u16) -> Result<(), ChannelValidationError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use twilight_model::id::Id;
 
     #[test]
     fn bulk_delete_messages() {
@@ -375,6 +475,67 @@ mod tests {
         assert!(is_thread(ChannelType::Group).is_err());
     }
 
+    #[test]
+    fn forum_tag_name() {
+        let mut tag = ForumTag {
+            emoji_id: None,
+            emoji_name: None,
+            id: Id::new(1),
+            moderated: false,
+            name: String::new(),
+        };
+        assert!(forum_tag(&tag).is_ok());
+
+        tag.name = "a".repeat(20);
+        assert!(forum_tag(&tag).is_ok());
+
+        tag.name = "a".repeat(21);
+        assert!(matches!(
+            forum_tag(&tag).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagNameInvalid { len: 21 },
+        ));
+    }
+
+    #[test]
+    fn forum_tag_emoji() {
+        let mut tag = ForumTag {
+            emoji_id: Some(Id::new(2)),
+            emoji_name: None,
+            id: Id::new(1),
+            moderated: false,
+            name: "tag".to_owned(),
+        };
+        assert!(forum_tag(&tag).is_ok());
+
+        tag.emoji_name = Some("🦀".to_owned());
+        assert!(matches!(
+            forum_tag(&tag).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumTagEmojiInvalid,
+        ));
+
+        tag.emoji_id = None;
+        assert!(forum_tag(&tag).is_ok());
+    }
+
+    #[test]
+    fn forum_thread_applied_tags() {
+        let tags = [
+            Id::new(1),
+            Id::new(2),
+            Id::new(3),
+            Id::new(4),
+            Id::new(5),
+            Id::new(6),
+        ];
+
+        assert!(super::forum_thread_applied_tags(&[]).is_ok());
+        assert!(super::forum_thread_applied_tags(&tags[..5]).is_ok());
+        assert!(matches!(
+            super::forum_thread_applied_tags(&tags).unwrap_err().kind(),
+            ChannelValidationErrorType::ForumThreadAppliedTagsInvalid { len: 6 },
+        ));
+    }
+
     #[test]
     fn channel_name() {
         assert!(name("a").is_ok());
@@ -46,6 +46,14 @@ pub const COMPONENT_CUSTOM_ID_LENGTH: usize = 100;
 /// [1]: https://discord.com/developers/docs/interactions/message-components#component-object-component-structure
 pub const COMPONENT_BUTTON_LABEL_LENGTH: usize = 80;
 
+/// Maximum length of a modal's title in codepoints.
+///
+/// This is defined in Discord's documentation, per
+/// [Discord Docs/Modal][1].
+///
+/// [1]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+pub const MODAL_TITLE_LENGTH: usize = 45;
+
 /// Maximum number of [`SelectMenuOption`]s that can be chosen in a
 /// [`SelectMenu`].
 ///
@@ -243,6 +251,20 @@ impl Display for ComponentValidationError {
 
                 f.write_str("' component was provided, but can not be a root component")
             }
+            ComponentValidationErrorType::ModalComponentCount { count } => {
+                f.write_str("a modal has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" components, but it must have at least 1 and at most ")?;
+
+                Display::fmt(&COMPONENT_COUNT, f)
+            }
+            ComponentValidationErrorType::ModalTitleLength { chars } => {
+                f.write_str("a modal's title is ")?;
+                Display::fmt(&chars, f)?;
+                f.write_str(" characters long, but it must be at least 1 and at most ")?;
+
+                Display::fmt(&MODAL_TITLE_LENGTH, f)
+            }
             ComponentValidationErrorType::SelectMaximumValuesCount { count } => {
                 f.write_str("maximum number of values that can be chosen is ")?;
                 Display::fmt(count, f)?;
@@ -408,6 +430,16 @@ pub enum ComponentValidationErrorType {
         /// Type of provided component.
         kind: ComponentType,
     },
+    /// Modal has no components or more than [the maximum][`COMPONENT_COUNT`].
+    ModalComponentCount {
+        /// Number of components that were provided.
+        count: usize,
+    },
+    /// Modal title is empty or larger than [the maximum][`MODAL_TITLE_LENGTH`].
+    ModalTitleLength {
+        /// Number of codepoints that were provided.
+        chars: usize,
+    },
     /// Maximum number of items that can be chosen is smaller than
     /// [the minimum][`SELECT_MAXIMUM_VALUES_REQUIREMENT`] or larger than
     /// [the maximum][`SELECT_MAXIMUM_VALUES_LIMIT`].
@@ -722,6 +754,17 @@ pub fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationEr
 
     if let Some(min_values) = select_menu.min_values {
         self::component_select_min_values(usize::from(min_values))?;
+
+        if select_menu
+            .max_values
+            .is_some_and(|max_values| min_values > max_values)
+        {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectMinimumValuesCount {
+                    count: usize::from(min_values),
+                },
+            });
+        }
     }
 
     if let Some(default_values) = select_menu.default_values.as_ref() {
@@ -784,6 +827,77 @@ pub fn text_input(text_input: &TextInput) -> Result<(), ComponentValidationError
     Ok(())
 }
 
+/// Ensure that a modal is correct.
+///
+/// A modal's root components must be action rows, each of which may only
+/// contain [`TextInput`]s. This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ComponentCustomIdLength`] if the custom ID is
+/// too long.
+///
+/// Returns an error of type [`ModalTitleLength`] if the title is empty or too
+/// long.
+///
+/// Returns an error of type [`ModalComponentCount`] if there are no components
+/// or more than [`COMPONENT_COUNT`] components.
+///
+/// Returns an error of type [`InvalidChildComponent`] if an action row
+/// contains a component that is not a text input.
+///
+/// Refer to [`component`] for potential errors when validating each root
+/// component.
+///
+/// [`ComponentCustomIdLength`]: ComponentValidationErrorType::ComponentCustomIdLength
+/// [`InvalidChildComponent`]: ComponentValidationErrorType::InvalidChildComponent
+/// [`ModalComponentCount`]: ComponentValidationErrorType::ModalComponentCount
+/// [`ModalTitleLength`]: ComponentValidationErrorType::ModalTitleLength
+/// [this documentation entry]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+pub fn modal(
+    custom_id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    components: &[Component],
+) -> Result<(), ComponentValidationError> {
+    self::component_custom_id(custom_id)?;
+
+    let chars = title.as_ref().chars().count();
+
+    if chars == 0 || chars > MODAL_TITLE_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalTitleLength { chars },
+        });
+    }
+
+    let count = components.len();
+
+    if count == 0 || count > COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalComponentCount { count },
+        });
+    }
+
+    for component in components {
+        self::component(component)?;
+
+        if let Component::ActionRow(action_row) = component {
+            if let Some(other) = action_row
+                .components
+                .iter()
+                .find(|child| !matches!(child, Component::TextInput(_)))
+            {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidChildComponent {
+                        kind: other.kind(),
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate that an [`ActionRow`] does not contain too many components.
 ///
 /// [`ActionRow`]s may only have so many components within it, defined by
@@ -1190,7 +1304,8 @@ fn component_text_input_value(value: impl AsRef<str>) -> Result<(), ComponentVal
 mod tests {
     use super::*;
     use static_assertions::{assert_fields, assert_impl_all};
-    use twilight_model::channel::message::EmojiReactionType;
+    use std::{array, slice};
+    use twilight_model::channel::message::{component::TextInputStyle, EmojiReactionType};
 
     assert_fields!(ComponentValidationErrorType::ActionRowComponentCount: count);
     assert_fields!(ComponentValidationErrorType::ComponentCount: count);
@@ -1198,6 +1313,8 @@ mod tests {
     assert_fields!(ComponentValidationErrorType::ComponentLabelLength: chars);
     assert_fields!(ComponentValidationErrorType::InvalidChildComponent: kind);
     assert_fields!(ComponentValidationErrorType::InvalidRootComponent: kind);
+    assert_fields!(ComponentValidationErrorType::ModalComponentCount: count);
+    assert_fields!(ComponentValidationErrorType::ModalTitleLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectMaximumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectMinimumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectOptionDescriptionLength: chars);
@@ -1396,6 +1513,29 @@ mod tests {
         assert!(component_select_min_values(26).is_err());
     }
 
+    #[test]
+    fn select_menu_min_values_above_max_values() {
+        let mut menu = SelectMenu {
+            channel_types: None,
+            custom_id: "a".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::User,
+            max_values: Some(2),
+            min_values: Some(2),
+            options: None,
+            placeholder: None,
+        };
+
+        assert!(select_menu(&menu).is_ok());
+
+        menu.min_values = Some(3);
+        assert!(matches!(
+            select_menu(&menu).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMinimumValuesCount { count: 3 }
+        ));
+    }
+
     #[test]
     fn component_select_option_value_length() {
         assert!(component_select_option_value("a").is_ok());
@@ -1488,4 +1628,63 @@ mod tests {
 
         assert!(component_text_input_min(4001).is_err());
     }
+
+    #[test]
+    fn modal_components() {
+        let text_input = Component::ActionRow(ActionRow {
+            components: Vec::from([Component::TextInput(TextInput {
+                custom_id: "input".to_owned(),
+                label: "Input".to_owned(),
+                max_length: None,
+                min_length: None,
+                placeholder: None,
+                required: None,
+                style: TextInputStyle::Short,
+                value: None,
+            })]),
+        });
+        let button = Component::ActionRow(ActionRow {
+            components: Vec::from([Component::Button(Button {
+                custom_id: Some("button".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some("Button".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            })]),
+        });
+
+        let inputs: [Component; 6] = array::from_fn(|_| text_input.clone());
+
+        assert!(modal("modal", "Title", slice::from_ref(&text_input)).is_ok());
+        assert!(modal("modal", "a".repeat(45), slice::from_ref(&text_input)).is_ok());
+
+        assert!(matches!(
+            modal("a".repeat(101), "Title", &[]).unwrap_err().kind(),
+            ComponentValidationErrorType::ComponentCustomIdLength { chars: 101 }
+        ));
+        assert!(matches!(
+            modal("modal", "", &[]).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalTitleLength { chars: 0 }
+        ));
+        assert!(matches!(
+            modal("modal", "a".repeat(46), &[]).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalTitleLength { chars: 46 }
+        ));
+        assert!(matches!(
+            modal("modal", "Title", &[]).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 0 }
+        ));
+        assert!(matches!(
+            modal("modal", "Title", &inputs).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalComponentCount { count: 6 }
+        ));
+        assert!(matches!(
+            modal("modal", "Title", &[button]).unwrap_err().kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::Button
+            }
+        ));
+    }
 }
@@ -18,4 +18,6 @@ pub mod component;
 pub mod embed;
 pub mod message;
 pub mod request;
+pub mod role_connection;
+pub mod scheduled_event;
 pub mod sticker;
@@ -14,7 +14,10 @@ use std::{
 use twilight_model::{
     channel::message::{Component, Embed},
     http::attachment::Attachment,
-    id::{marker::StickerMarker, Id},
+    id::{
+        marker::{AttachmentMarker, StickerMarker},
+        Id,
+    },
 };
 
 /// Maximum length of an attachment's description.
@@ -100,6 +103,9 @@ impl Display for MessageValidationError {
 
                 f.write_str("`is invalid")
             }
+            MessageValidationErrorType::AttachmentsClearedAndKept => {
+                f.write_str("attachments can't be both cleared and kept")
+            }
             MessageValidationErrorType::ComponentCount { count } => {
                 Display::fmt(count, f)?;
                 f.write_str(" components were provided, but only ")?;
@@ -153,6 +159,8 @@ pub enum MessageValidationErrorType {
         /// Provided number of codepoints.
         chars: usize,
     },
+    /// Existing attachments were both cleared and specified to be kept.
+    AttachmentsClearedAndKept,
     /// Too many message components were provided.
     ComponentCount {
         /// Number of components that were provided.
@@ -256,6 +264,29 @@ pub fn attachment_filename(filename: impl AsRef<str>) -> Result<(), MessageValid
     }
 }
 
+/// Ensure attachments to keep don't conflict with clearing all existing
+/// attachments.
+///
+/// # Errors
+///
+/// Returns an error of type [`AttachmentsClearedAndKept`] if attachments are
+/// cleared and `attachment_ids` isn't empty.
+///
+/// [`AttachmentsClearedAndKept`]: MessageValidationErrorType::AttachmentsClearedAndKept
+pub const fn keep_attachment_ids(
+    cleared: bool,
+    attachment_ids: &[Id<AttachmentMarker>],
+) -> Result<(), MessageValidationError> {
+    if cleared && !attachment_ids.is_empty() {
+        Err(MessageValidationError {
+            kind: MessageValidationErrorType::AttachmentsClearedAndKept,
+            source: None,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// Ensure a list of components is correct.
 ///
 /// # Errors
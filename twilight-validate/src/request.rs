@@ -4,9 +4,12 @@
 
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     time::{SystemTime, UNIX_EPOCH},
 };
+use twilight_model::guild::scheduled_event::{
+    GuildScheduledEventRecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleWeekday,
+};
 use twilight_model::id::marker::{ChannelMarker, RoleMarker};
 use twilight_model::id::Id;
 use twilight_model::util::Timestamp;
@@ -140,6 +143,26 @@ pub const SCHEDULED_EVENT_NAME_MAX: usize = 100;
 /// Minimum length of a scheduled event's name.
 pub const SCHEDULED_EVENT_NAME_MIN: usize = 1;
 
+/// Maximum week of the month of a scheduled event recurrence rule's N-th
+/// weekday.
+pub const SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX: u8 = 5;
+
+/// Minimum week of the month of a scheduled event recurrence rule's N-th
+/// weekday.
+pub const SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN: u8 = 1;
+
+/// Sets of weekdays a daily scheduled event recurrence rule may be limited to,
+/// as bitmasks of the weekdays' values.
+const SCHEDULED_EVENT_RECURRENCE_RULE_DAILY_WEEKDAYS: [u8; 6] = [
+    // Monday to Friday.
+    0b001_1111, // Tuesday to Saturday.
+    0b011_1110, // Sunday to Thursday.
+    0b100_1111, // Friday and Saturday.
+    0b011_0000, // Saturday and Sunday.
+    0b110_0000, // Sunday and Monday.
+    0b100_0001,
+];
+
 /// Maximum amount of guild members to search for.
 pub const SEARCH_GUILD_MEMBERS_LIMIT_MAX: u16 = 1000;
 
@@ -171,7 +194,7 @@ pub const USERNAME_LIMIT_MIN: usize = 2;
 pub const WEBHOOK_USERNAME_LIMIT_MAX: usize = 80;
 
 /// Minimum length of a webhook username.
-pub const WEBHOOK_USERNAME_LIMIT_MIN: usize = 2;
+pub const WEBHOOK_USERNAME_LIMIT_MIN: usize = 1;
 
 /// Forbidden substrings in usernames.
 const USERNAME_INVALID_SUBSTRINGS: [&str; 5] = ["@", "#", ":", "```", "discord"];
@@ -179,9 +202,12 @@ const USERNAME_INVALID_SUBSTRINGS: [&str; 5] = ["@", "#", ":", "```", "discord"]
 /// Forbidden usernames.
 const USERNAME_INVALID_STRINGS: [&str; 2] = ["everyone", "here"];
 
-/// Forbidden webhook usernames.
+/// Forbidden webhook usernames, compared case-insensitively.
 const WEBHOOK_INVALID_STRINGS: [&str; 1] = ["clyde"];
 
+/// Forbidden substrings in webhook usernames, compared case-insensitively.
+const WEBHOOK_INVALID_SUBSTRINGS: [&str; 4] = ["@", "#", "`", "discord"];
+
 /// A field is not valid.
 #[derive(Debug)]
 pub struct ValidationError {
@@ -461,6 +487,45 @@ impl Display for ValidationError {
 
                 Display::fmt(&SCHEDULED_EVENT_NAME_MAX, f)
             }
+            ValidationErrorType::ScheduledEventRecurrenceRuleExclusive => f.write_str(
+                "provided scheduled event recurrence rule sets more than one of by_weekday, \
+                 by_n_weekday, and by_month with by_month_day",
+            ),
+            ValidationErrorType::ScheduledEventRecurrenceRuleFrequency { frequency } => {
+                f.write_str(
+                    "provided scheduled event recurrence rule sets fields invalid for the ",
+                )?;
+                Debug::fmt(frequency, f)?;
+
+                f.write_str(" frequency")
+            }
+            ValidationErrorType::ScheduledEventRecurrenceRuleInterval {
+                frequency,
+                interval,
+            } => {
+                f.write_str("provided scheduled event recurrence rule interval is ")?;
+                Display::fmt(interval, f)?;
+                f.write_str(", but it must be 1")?;
+
+                if *frequency == RecurrenceRuleFrequency::Weekly {
+                    f.write_str(" or 2 for the Weekly frequency")?;
+                }
+
+                Ok(())
+            }
+            ValidationErrorType::ScheduledEventRecurrenceRuleNWeekday { n } => {
+                f.write_str("provided scheduled event recurrence rule n-th weekday is ")?;
+                Display::fmt(n, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX, f)
+            }
+            ValidationErrorType::ScheduledEventRecurrenceRuleSystemField => f.write_str(
+                "provided scheduled event recurrence rule sets by_year_day, count, or end, which \
+                 are set by Discord",
+            ),
             ValidationErrorType::SearchGuildMembers { limit } => {
                 f.write_str("provided search guild members limit is ")?;
                 Display::fmt(limit, f)?;
@@ -497,15 +562,21 @@ impl Display for ValidationError {
             }
             ValidationErrorType::Username { len, substring }
             | ValidationErrorType::WebhookUsername { len, substring } => {
+                let (min, max) = if matches!(self.kind, ValidationErrorType::Username { .. }) {
+                    (USERNAME_LIMIT_MIN, USERNAME_LIMIT_MAX)
+                } else {
+                    (WEBHOOK_USERNAME_LIMIT_MIN, WEBHOOK_USERNAME_LIMIT_MAX)
+                };
+
                 f.write_str("provided username")?;
 
                 if let Some(len) = len {
                     f.write_str(" length is ")?;
                     Display::fmt(len, f)?;
                     f.write_str(", but it must be at least ")?;
-                    Display::fmt(&USERNAME_LIMIT_MIN, f)?;
+                    Display::fmt(&min, f)?;
                     f.write_str(" and at most ")?;
-                    Display::fmt(&USERNAME_LIMIT_MAX, f)?;
+                    Display::fmt(&max, f)?;
                 }
 
                 if let Some(substring) = substring {
@@ -691,6 +762,29 @@ pub enum ValidationErrorType {
         /// Invalid length.
         len: usize,
     },
+    /// Scheduled event recurrence rule sets more than one of `by_weekday`,
+    /// `by_n_weekday`, and `by_month` with `by_month_day`.
+    ScheduledEventRecurrenceRuleExclusive,
+    /// Scheduled event recurrence rule sets fields that are invalid for its
+    /// frequency.
+    ScheduledEventRecurrenceRuleFrequency {
+        /// Frequency of the rule.
+        frequency: RecurrenceRuleFrequency,
+    },
+    /// Scheduled event recurrence rule interval is invalid for its frequency.
+    ScheduledEventRecurrenceRuleInterval {
+        /// Frequency of the rule.
+        frequency: RecurrenceRuleFrequency,
+        /// Invalid interval.
+        interval: u16,
+    },
+    /// Scheduled event recurrence rule N-th weekday is invalid.
+    ScheduledEventRecurrenceRuleNWeekday {
+        /// Invalid week of the month.
+        n: u8,
+    },
+    /// Scheduled event recurrence rule sets fields that are set by Discord.
+    ScheduledEventRecurrenceRuleSystemField,
     /// Provided search guild members limit was invalid.
     SearchGuildMembers {
         /// Invalid limit.
@@ -1505,6 +1599,152 @@ pub fn scheduled_event_name(name: impl AsRef<str>) -> Result<(), ValidationError
     }
 }
 
+/// Ensure that a scheduled event's recurrence rule is correct.
+///
+/// Only one of `by_weekday`, `by_n_weekday`, and `by_month` with
+/// `by_month_day` may be set, and which of them may be used depends on the
+/// frequency:
+///
+/// - [`Daily`] rules may limit `by_weekday` to Monday to Friday, Tuesday to
+///   Saturday, Sunday to Thursday, Friday and Saturday, Saturday and Sunday, or
+///   Sunday and Monday;
+/// - [`Weekly`] rules may set `by_weekday` to a single weekday, and are the only
+///   rules whose interval may be 2 rather than 1;
+/// - [`Monthly`] rules may set `by_n_weekday` to a single N-th weekday, whose
+///   week is at least [`SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN`] and at
+///   most [`SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX`];
+/// - [`Yearly`] rules may set both `by_month` and `by_month_day` to a single
+///   value.
+///
+/// `by_year_day`, `count`, and `end` are set by Discord and must not be set.
+/// This is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ScheduledEventRecurrenceRuleSystemField`] if
+/// `by_year_day`, `count`, or `end` is set.
+///
+/// Returns an error of type [`ScheduledEventRecurrenceRuleExclusive`] if more
+/// than one of the mutually exclusive fields is set.
+///
+/// Returns an error of type [`ScheduledEventRecurrenceRuleInterval`] if the
+/// interval is invalid for the frequency.
+///
+/// Returns an error of type [`ScheduledEventRecurrenceRuleFrequency`] if the
+/// set fields are invalid for the frequency.
+///
+/// Returns an error of type [`ScheduledEventRecurrenceRuleNWeekday`] if the
+/// week of an N-th weekday is invalid.
+///
+/// [`Daily`]: RecurrenceRuleFrequency::Daily
+/// [`Monthly`]: RecurrenceRuleFrequency::Monthly
+/// [`ScheduledEventRecurrenceRuleExclusive`]: ValidationErrorType::ScheduledEventRecurrenceRuleExclusive
+/// [`ScheduledEventRecurrenceRuleFrequency`]: ValidationErrorType::ScheduledEventRecurrenceRuleFrequency
+/// [`ScheduledEventRecurrenceRuleInterval`]: ValidationErrorType::ScheduledEventRecurrenceRuleInterval
+/// [`ScheduledEventRecurrenceRuleNWeekday`]: ValidationErrorType::ScheduledEventRecurrenceRuleNWeekday
+/// [`ScheduledEventRecurrenceRuleSystemField`]: ValidationErrorType::ScheduledEventRecurrenceRuleSystemField
+/// [`Weekly`]: RecurrenceRuleFrequency::Weekly
+/// [`Yearly`]: RecurrenceRuleFrequency::Yearly
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-system-limitations
+pub fn scheduled_event_recurrence_rule(
+    rule: &GuildScheduledEventRecurrenceRule,
+) -> Result<(), ValidationError> {
+    if rule.by_year_day.is_some() || rule.count.is_some() || rule.end.is_some() {
+        return Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventRecurrenceRuleSystemField,
+        });
+    }
+
+    let by_weekday = rule.by_weekday.as_deref();
+    let by_n_weekday = rule.by_n_weekday.as_deref();
+    let by_month = rule.by_month.is_some() || rule.by_month_day.is_some();
+
+    if u8::from(by_weekday.is_some()) + u8::from(by_n_weekday.is_some()) + u8::from(by_month) > 1 {
+        return Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventRecurrenceRuleExclusive,
+        });
+    }
+
+    let interval_max = if rule.frequency == RecurrenceRuleFrequency::Weekly {
+        2
+    } else {
+        1
+    };
+
+    if !(1..=interval_max).contains(&rule.interval) {
+        return Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventRecurrenceRuleInterval {
+                frequency: rule.frequency,
+                interval: rule.interval,
+            },
+        });
+    }
+
+    let valid = match rule.frequency {
+        RecurrenceRuleFrequency::Daily => {
+            by_n_weekday.is_none() && !by_month && by_weekday.map_or(true, daily_weekdays)
+        }
+        RecurrenceRuleFrequency::Weekly => {
+            by_n_weekday.is_none()
+                && !by_month
+                && by_weekday.map_or(true, |weekdays| weekdays.len() == 1)
+        }
+        RecurrenceRuleFrequency::Monthly => {
+            by_weekday.is_none()
+                && !by_month
+                && by_n_weekday.map_or(true, |n_weekdays| n_weekdays.len() == 1)
+        }
+        RecurrenceRuleFrequency::Yearly => {
+            by_weekday.is_none()
+                && by_n_weekday.is_none()
+                && match (rule.by_month.as_deref(), rule.by_month_day.as_deref()) {
+                    (Some(months), Some(month_days)) => months.len() == 1 && month_days.len() == 1,
+                    (None, None) => true,
+                    _ => false,
+                }
+        }
+        _ => true,
+    };
+
+    if !valid {
+        return Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventRecurrenceRuleFrequency {
+                frequency: rule.frequency,
+            },
+        });
+    }
+
+    if let Some(n_weekday) = by_n_weekday.into_iter().flatten().find(|n_weekday| {
+        !(SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MIN
+            ..=SCHEDULED_EVENT_RECURRENCE_RULE_N_WEEKDAY_MAX)
+            .contains(&n_weekday.n)
+    }) {
+        return Err(ValidationError {
+            kind: ValidationErrorType::ScheduledEventRecurrenceRuleNWeekday { n: n_weekday.n },
+        });
+    }
+
+    Ok(())
+}
+
+/// Whether a daily scheduled event recurrence rule may be limited to a set of
+/// weekdays.
+fn daily_weekdays(weekdays: &[RecurrenceRuleWeekday]) -> bool {
+    let mut mask = 0_u8;
+
+    for weekday in weekdays {
+        let value = u8::from(*weekday);
+
+        if value > 6 || mask & (1 << value) != 0 {
+            return false;
+        }
+
+        mask |= 1 << value;
+    }
+
+    SCHEDULED_EVENT_RECURRENCE_RULE_DAILY_WEEKDAYS.contains(&mask)
+}
+
 /// Ensure that the limit for the Search Guild Members endpoint is correct.
 ///
 /// The limit must be at least [`SEARCH_GUILD_MEMBERS_LIMIT_MIN`] and at most
@@ -1621,7 +1861,7 @@ pub fn username(value: impl AsRef<str>) -> Result<(), ValidationError> {
         .or_else(|| {
             USERNAME_INVALID_STRINGS
                 .into_iter()
-                .find(|invalid_string| value == *invalid_string)
+                .find(|invalid_string| value.eq_ignore_ascii_case(invalid_string))
         });
 
     if invalid_len.is_none() && invalid_substring.is_none() {
@@ -1636,15 +1876,18 @@ pub fn username(value: impl AsRef<str>) -> Result<(), ValidationError> {
     }
 }
 
-/// Ensure that a webhook is correct.
+/// Ensure that a webhook username is correct.
 ///
 /// The length must be at least [`WEBHOOK_USERNAME_LIMIT_MIN`] and at most
-/// [`WEBHOOK_USERNAME_LIMIT_MAX`]. It must also be free of certain substrings.
-/// This is based on [this documentation entry].
+/// [`WEBHOOK_USERNAME_LIMIT_MAX`]. It must also not contain `@`, `#`, backticks
+/// or `discord`, and must not be `clyde`, all compared case-insensitively. This
+/// applies to both webhook names and username overrides when executing a
+/// webhook. This is based on [this documentation entry].
 ///
 /// # Errors
 ///
-/// Returns an error of type [`WebhookUsername`] if the length is invalid.
+/// Returns an error of type [`WebhookUsername`] if the length is invalid or
+/// the username contains a forbidden substring.
 ///
 /// [`WebhookUsername`]: ValidationErrorType::WebhookUsername
 /// [this documentation entry]: https://discord.com/developers/docs/resources/webhook#create-webhook
@@ -1655,9 +1898,21 @@ pub fn webhook_username(value: impl AsRef<str>) -> Result<(), ValidationError> {
     let range = WEBHOOK_USERNAME_LIMIT_MIN..=WEBHOOK_USERNAME_LIMIT_MAX;
     let invalid_len = (!range.contains(&len)).then_some(len);
 
-    let invalid_substring = WEBHOOK_INVALID_STRINGS
+    // The forbidden substrings are ASCII, so an ASCII case-insensitive search
+    // is equivalent to searching the lowercased value without allocating.
+    let invalid_substring = WEBHOOK_INVALID_SUBSTRINGS
         .into_iter()
-        .find(|invalid_string| value == *invalid_string);
+        .find(|invalid_substring| {
+            value
+                .as_bytes()
+                .windows(invalid_substring.len())
+                .any(|window| window.eq_ignore_ascii_case(invalid_substring.as_bytes()))
+        })
+        .or_else(|| {
+            WEBHOOK_INVALID_STRINGS
+                .into_iter()
+                .find(|invalid_string| value.eq_ignore_ascii_case(invalid_string))
+        });
 
     if invalid_len.is_none() && invalid_substring.is_none() {
         Ok(())
@@ -1979,6 +2234,117 @@ mod tests {
         assert!(scheduled_event_name("a".repeat(101)).is_err());
     }
 
+    #[test]
+    fn scheduled_event_recurrence_rule_constraints() {
+        use twilight_model::guild::scheduled_event::{RecurrenceRuleMonth, RecurrenceRuleNWeekday};
+
+        fn rule(frequency: RecurrenceRuleFrequency) -> GuildScheduledEventRecurrenceRule {
+            GuildScheduledEventRecurrenceRule {
+                by_month: None,
+                by_month_day: None,
+                by_n_weekday: None,
+                by_weekday: None,
+                by_year_day: None,
+                count: None,
+                end: None,
+                frequency,
+                interval: 1,
+                start: Timestamp::from_secs(1_723_453_200).unwrap(),
+            }
+        }
+
+        fn kind(rule: &GuildScheduledEventRecurrenceRule) -> ValidationErrorType {
+            scheduled_event_recurrence_rule(rule)
+                .unwrap_err()
+                .into_parts()
+                .0
+        }
+
+        let weekdays = |days: &[u8]| Some(days.iter().copied().map(Into::into).collect());
+        let n_weekday = |n| {
+            Some(Vec::from([RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Tuesday,
+                n,
+            }]))
+        };
+
+        assert!(scheduled_event_recurrence_rule(&rule(RecurrenceRuleFrequency::Daily)).is_ok());
+
+        let mut daily = rule(RecurrenceRuleFrequency::Daily);
+        daily.by_weekday = weekdays(&[4, 3, 2, 1, 0]);
+        assert!(scheduled_event_recurrence_rule(&daily).is_ok());
+        daily.by_weekday = weekdays(&[6, 0]);
+        assert!(scheduled_event_recurrence_rule(&daily).is_ok());
+        daily.by_weekday = weekdays(&[0, 2]);
+        assert!(matches!(
+            kind(&daily),
+            ValidationErrorType::ScheduledEventRecurrenceRuleFrequency {
+                frequency: RecurrenceRuleFrequency::Daily
+            }
+        ));
+        daily.by_weekday = weekdays(&[5, 6, 6]);
+        assert!(scheduled_event_recurrence_rule(&daily).is_err());
+        daily.by_weekday = None;
+        daily.interval = 2;
+        assert!(matches!(
+            kind(&daily),
+            ValidationErrorType::ScheduledEventRecurrenceRuleInterval { interval: 2, .. }
+        ));
+
+        let mut weekly = rule(RecurrenceRuleFrequency::Weekly);
+        weekly.by_weekday = weekdays(&[4]);
+        weekly.interval = 2;
+        assert!(scheduled_event_recurrence_rule(&weekly).is_ok());
+        weekly.interval = 3;
+        assert!(matches!(
+            kind(&weekly),
+            ValidationErrorType::ScheduledEventRecurrenceRuleInterval { interval: 3, .. }
+        ));
+        weekly.interval = 0;
+        assert!(scheduled_event_recurrence_rule(&weekly).is_err());
+        weekly.interval = 1;
+        weekly.by_weekday = weekdays(&[3, 4]);
+        assert!(scheduled_event_recurrence_rule(&weekly).is_err());
+
+        let mut monthly = rule(RecurrenceRuleFrequency::Monthly);
+        monthly.by_n_weekday = n_weekday(2);
+        assert!(scheduled_event_recurrence_rule(&monthly).is_ok());
+        monthly.by_n_weekday = n_weekday(6);
+        assert!(matches!(
+            kind(&monthly),
+            ValidationErrorType::ScheduledEventRecurrenceRuleNWeekday { n: 6 }
+        ));
+        monthly.by_n_weekday = n_weekday(0);
+        assert!(scheduled_event_recurrence_rule(&monthly).is_err());
+        monthly.by_n_weekday = None;
+        monthly.by_weekday = weekdays(&[1]);
+        assert!(scheduled_event_recurrence_rule(&monthly).is_err());
+
+        let mut yearly = rule(RecurrenceRuleFrequency::Yearly);
+        yearly.by_month = Some(Vec::from([RecurrenceRuleMonth::December]));
+        yearly.by_month_day = Some(Vec::from([25]));
+        assert!(scheduled_event_recurrence_rule(&yearly).is_ok());
+        yearly.by_month_day = None;
+        assert!(scheduled_event_recurrence_rule(&yearly).is_err());
+        yearly.by_month_day = Some(Vec::from([24, 25]));
+        assert!(scheduled_event_recurrence_rule(&yearly).is_err());
+
+        let mut exclusive = rule(RecurrenceRuleFrequency::Monthly);
+        exclusive.by_weekday = weekdays(&[1]);
+        exclusive.by_n_weekday = n_weekday(2);
+        assert!(matches!(
+            kind(&exclusive),
+            ValidationErrorType::ScheduledEventRecurrenceRuleExclusive
+        ));
+
+        let mut system = rule(RecurrenceRuleFrequency::Daily);
+        system.count = Some(5);
+        assert!(matches!(
+            kind(&system),
+            ValidationErrorType::ScheduledEventRecurrenceRuleSystemField
+        ));
+    }
+
     #[test]
     fn search_guild_members_limit_count() {
         assert!(search_guild_members_limit(1).is_ok());
@@ -2034,12 +2400,45 @@ mod tests {
 
     #[test]
     fn webhook_username_length() {
-        assert!(webhook_username("aa").is_ok());
+        assert!(webhook_username("a").is_ok());
         assert!(webhook_username("a".repeat(80)).is_ok());
 
-        assert!(webhook_username("a").is_err());
+        assert!(webhook_username("").is_err());
         assert!(webhook_username("a".repeat(81)).is_err());
 
         assert!(webhook_username("clyde").is_err());
+        assert!(webhook_username("Clyde").is_err());
+        assert!(webhook_username("CLYDE").is_err());
+        assert!(webhook_username("clyde bot").is_ok());
+    }
+
+    #[test]
+    fn webhook_username_substrings() {
+        assert!(webhook_username("discrod").is_ok());
+        assert!(webhook_username("a:b").is_ok());
+
+        for value in [
+            "discord",
+            "Discord",
+            "my DISCORD bot",
+            "@everyone",
+            "#general",
+            "`code`",
+        ] {
+            assert!(matches!(
+                webhook_username(value).unwrap_err().kind(),
+                ValidationErrorType::WebhookUsername {
+                    len: None,
+                    substring: Some(_),
+                }
+            ));
+        }
+
+        let expected = format!(
+            "provided username length is 81, but it must be at least {WEBHOOK_USERNAME_LIMIT_MIN} \
+            and at most {WEBHOOK_USERNAME_LIMIT_MAX}, and cannot contain discord"
+        );
+        let actual = webhook_username(format!("discord{}", "a".repeat(74))).unwrap_err();
+        assert_eq!(expected, actual.to_string());
     }
 }
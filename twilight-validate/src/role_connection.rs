@@ -0,0 +1,433 @@
+//! Constants, error types, and functions for validating [application role
+//! connection metadata] and users' [application role connections].
+//!
+//! [application role connection metadata]: twilight_model::application::role_connection::ApplicationRoleConnectionMetadata
+//! [application role connections]: twilight_model::application::role_connection::ApplicationRoleConnection
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::role_connection::ApplicationRoleConnectionMetadata;
+
+/// Maximum number of metadata records of an application.
+pub const ROLE_CONNECTION_METADATA_COUNT_MAX: usize = 5;
+
+/// Maximum length of a metadata record's description.
+pub const ROLE_CONNECTION_METADATA_DESCRIPTION_LENGTH_MAX: usize = 200;
+
+/// Minimum length of a metadata record's description.
+pub const ROLE_CONNECTION_METADATA_DESCRIPTION_LENGTH_MIN: usize = 1;
+
+/// Maximum length of a metadata record's key.
+pub const ROLE_CONNECTION_METADATA_KEY_LENGTH_MAX: usize = 50;
+
+/// Minimum length of a metadata record's key.
+pub const ROLE_CONNECTION_METADATA_KEY_LENGTH_MIN: usize = 1;
+
+/// Maximum length of a metadata record's name.
+pub const ROLE_CONNECTION_METADATA_NAME_LENGTH_MAX: usize = 100;
+
+/// Minimum length of a metadata record's name.
+pub const ROLE_CONNECTION_METADATA_NAME_LENGTH_MIN: usize = 1;
+
+/// Maximum length of a user's metadata value.
+pub const ROLE_CONNECTION_METADATA_VALUE_LENGTH_MAX: usize = 100;
+
+/// Maximum length of a role connection's platform name.
+pub const ROLE_CONNECTION_PLATFORM_NAME_LENGTH_MAX: usize = 50;
+
+/// Maximum length of a role connection's platform username.
+pub const ROLE_CONNECTION_PLATFORM_USERNAME_LENGTH_MAX: usize = 100;
+
+/// Error created if validation of role connection metadata or a role
+/// connection fails.
+#[derive(Debug)]
+pub struct RoleConnectionValidationError {
+    /// Type of error that occurred.
+    kind: RoleConnectionValidationErrorType,
+}
+
+impl RoleConnectionValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RoleConnectionValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        RoleConnectionValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for RoleConnectionValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RoleConnectionValidationErrorType::Description { len } => {
+                f.write_str("provided metadata description is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" characters long, but it must be at least ")?;
+                Display::fmt(&ROLE_CONNECTION_METADATA_DESCRIPTION_LENGTH_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&ROLE_CONNECTION_METADATA_DESCRIPTION_LENGTH_MAX, f)
+            }
+            RoleConnectionValidationErrorType::Key { key } => {
+                f.write_str("provided metadata key `")?;
+                f.write_str(key)?;
+                f.write_str("` must be at least ")?;
+                Display::fmt(&ROLE_CONNECTION_METADATA_KEY_LENGTH_MIN, f)?;
+                f.write_str(" and at most ")?;
+                Display::fmt(&ROLE_CONNECTION_METADATA_KEY_LENGTH_MAX, f)?;
+
+                f.write_str(" lowercase letters, digits, or underscores long")
+            }
+            RoleConnectionValidationErrorType::MetadataCount { count } => {
+                f.write_str("provided ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" metadata records, but at most ")?;
+                Display::fmt(&ROLE_CONNECTION_METADATA_COUNT_MAX, f)?;
+
+                f.write_str(" are allowed")
+            }
+            RoleConnectionValidationErrorType::Name { len } => {
+                f.write_str("provided metadata name is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" characters long, but it must be at least ")?;
+                Display::fmt(&ROLE_CONNECTION_METADATA_NAME_LENGTH_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&ROLE_CONNECTION_METADATA_NAME_LENGTH_MAX, f)
+            }
+            RoleConnectionValidationErrorType::PlatformName { len } => {
+                f.write_str("provided platform name is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" characters long, but it must be at most ")?;
+
+                Display::fmt(&ROLE_CONNECTION_PLATFORM_NAME_LENGTH_MAX, f)
+            }
+            RoleConnectionValidationErrorType::PlatformUsername { len } => {
+                f.write_str("provided platform username is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(" characters long, but it must be at most ")?;
+
+                Display::fmt(&ROLE_CONNECTION_PLATFORM_USERNAME_LENGTH_MAX, f)
+            }
+            RoleConnectionValidationErrorType::Value { key } => {
+                f.write_str("provided value of metadata key `")?;
+                f.write_str(key)?;
+                f.write_str("` is more than ")?;
+                Display::fmt(&ROLE_CONNECTION_METADATA_VALUE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters long")
+            }
+        }
+    }
+}
+
+impl Error for RoleConnectionValidationError {}
+
+/// Type of [`RoleConnectionValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RoleConnectionValidationErrorType {
+    /// Metadata record's description is invalid.
+    Description {
+        /// Invalid length.
+        len: usize,
+    },
+    /// Metadata record's key is invalid.
+    Key {
+        /// Invalid key.
+        key: String,
+    },
+    /// Too many metadata records were provided.
+    MetadataCount {
+        /// Invalid number of records.
+        count: usize,
+    },
+    /// Metadata record's name is invalid.
+    Name {
+        /// Invalid length.
+        len: usize,
+    },
+    /// Role connection's platform name is invalid.
+    PlatformName {
+        /// Invalid length.
+        len: usize,
+    },
+    /// Role connection's platform username is invalid.
+    PlatformUsername {
+        /// Invalid length.
+        len: usize,
+    },
+    /// User's metadata value is invalid.
+    Value {
+        /// Key of the invalid value.
+        key: String,
+    },
+}
+
+/// Ensure that a metadata record's key is correct.
+///
+/// The key must be at least [`ROLE_CONNECTION_METADATA_KEY_LENGTH_MIN`] and at
+/// most [`ROLE_CONNECTION_METADATA_KEY_LENGTH_MAX`] characters long and may
+/// only consist of lowercase ASCII letters, digits, and underscores. This is
+/// based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`Key`] if the key is invalid.
+///
+/// [`Key`]: RoleConnectionValidationErrorType::Key
+/// [this documentation entry]: https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object-application-role-connection-metadata-structure
+pub fn key(key: impl AsRef<str>) -> Result<(), RoleConnectionValidationError> {
+    let key = key.as_ref();
+
+    if (ROLE_CONNECTION_METADATA_KEY_LENGTH_MIN..=ROLE_CONNECTION_METADATA_KEY_LENGTH_MAX)
+        .contains(&key.len())
+        && key
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_')
+    {
+        Ok(())
+    } else {
+        Err(RoleConnectionValidationError {
+            kind: RoleConnectionValidationErrorType::Key {
+                key: key.to_owned(),
+            },
+        })
+    }
+}
+
+/// Ensure that an application's metadata records are correct.
+///
+/// At most [`ROLE_CONNECTION_METADATA_COUNT_MAX`] records are allowed, each
+/// of which must have a valid [`key`] and a name and description of valid
+/// lengths.
+///
+/// # Errors
+///
+/// Returns an error of type [`MetadataCount`] if there are too many records.
+///
+/// Returns an error of type [`Description`], [`Key`], or [`Name`] if a record
+/// is invalid.
+///
+/// [`Description`]: RoleConnectionValidationErrorType::Description
+/// [`Key`]: RoleConnectionValidationErrorType::Key
+/// [`MetadataCount`]: RoleConnectionValidationErrorType::MetadataCount
+/// [`Name`]: RoleConnectionValidationErrorType::Name
+pub fn metadata(
+    records: &[ApplicationRoleConnectionMetadata],
+) -> Result<(), RoleConnectionValidationError> {
+    if records.len() > ROLE_CONNECTION_METADATA_COUNT_MAX {
+        return Err(RoleConnectionValidationError {
+            kind: RoleConnectionValidationErrorType::MetadataCount {
+                count: records.len(),
+            },
+        });
+    }
+
+    for record in records {
+        self::key(&record.key)?;
+
+        let len = record.name.chars().count();
+        if !(ROLE_CONNECTION_METADATA_NAME_LENGTH_MIN..=ROLE_CONNECTION_METADATA_NAME_LENGTH_MAX)
+            .contains(&len)
+        {
+            return Err(RoleConnectionValidationError {
+                kind: RoleConnectionValidationErrorType::Name { len },
+            });
+        }
+
+        let len = record.description.chars().count();
+        if !(ROLE_CONNECTION_METADATA_DESCRIPTION_LENGTH_MIN
+            ..=ROLE_CONNECTION_METADATA_DESCRIPTION_LENGTH_MAX)
+            .contains(&len)
+        {
+            return Err(RoleConnectionValidationError {
+                kind: RoleConnectionValidationErrorType::Description { len },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure that a user's metadata value is correct.
+///
+/// The value must be at most [`ROLE_CONNECTION_METADATA_VALUE_LENGTH_MAX`]
+/// characters long.
+///
+/// # Errors
+///
+/// Returns an error of type [`Value`] if the value is too long.
+///
+/// [`Value`]: RoleConnectionValidationErrorType::Value
+pub fn metadata_value(
+    key: impl AsRef<str>,
+    value: impl AsRef<str>,
+) -> Result<(), RoleConnectionValidationError> {
+    if value.as_ref().chars().count() <= ROLE_CONNECTION_METADATA_VALUE_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(RoleConnectionValidationError {
+            kind: RoleConnectionValidationErrorType::Value {
+                key: key.as_ref().to_owned(),
+            },
+        })
+    }
+}
+
+/// Ensure that a role connection's platform name is correct.
+///
+/// The name must be at most [`ROLE_CONNECTION_PLATFORM_NAME_LENGTH_MAX`]
+/// characters long.
+///
+/// # Errors
+///
+/// Returns an error of type [`PlatformName`] if the name is too long.
+///
+/// [`PlatformName`]: RoleConnectionValidationErrorType::PlatformName
+pub fn platform_name(name: impl AsRef<str>) -> Result<(), RoleConnectionValidationError> {
+    let len = name.as_ref().chars().count();
+
+    if len <= ROLE_CONNECTION_PLATFORM_NAME_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(RoleConnectionValidationError {
+            kind: RoleConnectionValidationErrorType::PlatformName { len },
+        })
+    }
+}
+
+/// Ensure that a role connection's platform username is correct.
+///
+/// The username must be at most
+/// [`ROLE_CONNECTION_PLATFORM_USERNAME_LENGTH_MAX`] characters long.
+///
+/// # Errors
+///
+/// Returns an error of type [`PlatformUsername`] if the username is too long.
+///
+/// [`PlatformUsername`]: RoleConnectionValidationErrorType::PlatformUsername
+pub fn platform_username(username: impl AsRef<str>) -> Result<(), RoleConnectionValidationError> {
+    let len = username.as_ref().chars().count();
+
+    if len <= ROLE_CONNECTION_PLATFORM_USERNAME_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(RoleConnectionValidationError {
+            kind: RoleConnectionValidationErrorType::PlatformUsername { len },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::application::role_connection::ApplicationRoleConnectionMetadataType;
+
+    assert_impl_all!(RoleConnectionValidationErrorType: Debug, Send, Sync);
+    assert_impl_all!(RoleConnectionValidationError: Error, Send, Sync);
+
+    /// Valid metadata record with the given key.
+    fn record(key: &str) -> ApplicationRoleConnectionMetadata {
+        ApplicationRoleConnectionMetadata {
+            description: "description".to_owned(),
+            description_localizations: None,
+            key: key.to_owned(),
+            kind: ApplicationRoleConnectionMetadataType::BooleanEqual,
+            name: "name".to_owned(),
+            name_localizations: None,
+        }
+    }
+
+    #[test]
+    fn key_characters() {
+        assert!(key("a").is_ok());
+        assert!(key("joined_at_2").is_ok());
+        assert!(key("a".repeat(50)).is_ok());
+
+        assert!(key("").is_err());
+        assert!(key("a".repeat(51)).is_err());
+        assert!(key("Joined").is_err());
+        assert!(key("joined-at").is_err());
+        assert!(key("joined at").is_err());
+        assert!(key("é").is_err());
+    }
+
+    #[test]
+    fn metadata_records() {
+        assert!(metadata(&[]).is_ok());
+        assert!(metadata(&[
+            record("a"),
+            record("b"),
+            record("c"),
+            record("d"),
+            record("e")
+        ])
+        .is_ok());
+
+        assert!(matches!(
+            metadata(&[
+                record("a"),
+                record("b"),
+                record("c"),
+                record("d"),
+                record("e"),
+                record("f")
+            ])
+            .unwrap_err()
+            .kind(),
+            RoleConnectionValidationErrorType::MetadataCount { count: 6 }
+        ));
+        assert!(matches!(
+            metadata(&[record("A")]).unwrap_err().kind(),
+            RoleConnectionValidationErrorType::Key { .. }
+        ));
+
+        let mut invalid = record("a");
+        invalid.name = String::new();
+        assert!(matches!(
+            metadata(&[invalid]).unwrap_err().kind(),
+            RoleConnectionValidationErrorType::Name { len: 0 }
+        ));
+
+        let mut invalid = record("a");
+        invalid.description = "a".repeat(201);
+        assert!(matches!(
+            metadata(&[invalid]).unwrap_err().kind(),
+            RoleConnectionValidationErrorType::Description { len: 201 }
+        ));
+    }
+
+    #[test]
+    fn connection_lengths() {
+        assert!(metadata_value("key", "a".repeat(100)).is_ok());
+        assert!(metadata_value("key", "a".repeat(101)).is_err());
+
+        assert!(platform_name("").is_ok());
+        assert!(platform_name("a".repeat(50)).is_ok());
+        assert!(platform_name("a".repeat(51)).is_err());
+
+        assert!(platform_username("a".repeat(100)).is_ok());
+        assert!(platform_username("a".repeat(101)).is_err());
+    }
+}
@@ -0,0 +1,452 @@
+//! Constants, error types, and functions for validating the interdependent
+//! fields of [`GuildScheduledEvent`]s.
+//!
+//! The name and description of scheduled events are validated by
+//! [`request::scheduled_event_name`] and
+//! [`request::scheduled_event_description`].
+//!
+//! [`GuildScheduledEvent`]: twilight_model::guild::scheduled_event::GuildScheduledEvent
+//! [`request::scheduled_event_description`]: crate::request::scheduled_event_description
+//! [`request::scheduled_event_name`]: crate::request::scheduled_event_name
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::EntityType,
+    id::{marker::ChannelMarker, Id},
+    util::Timestamp,
+};
+
+/// Maximum length of an external scheduled event's location.
+pub const SCHEDULED_EVENT_LOCATION_LENGTH_MAX: usize = 100;
+
+/// Minimum length of an external scheduled event's location.
+pub const SCHEDULED_EVENT_LOCATION_LENGTH_MIN: usize = 1;
+
+/// Error created if validation of a scheduled event fails.
+#[derive(Debug)]
+pub struct ScheduledEventValidationError {
+    /// Type of error that occurred.
+    kind: ScheduledEventValidationErrorType,
+}
+
+impl ScheduledEventValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ScheduledEventValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ScheduledEventValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ScheduledEventValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ScheduledEventValidationErrorType::ChannelMissing => {
+                f.write_str("stage instance and voice scheduled events require a channel")
+            }
+            ScheduledEventValidationErrorType::ChannelUnexpected => {
+                f.write_str("external scheduled events can't have a channel")
+            }
+            ScheduledEventValidationErrorType::EndTimeBeforeStartTime => {
+                f.write_str("scheduled end time is not after the scheduled start time")
+            }
+            ScheduledEventValidationErrorType::EndTimeMissing => {
+                f.write_str("external scheduled events require a scheduled end time")
+            }
+            ScheduledEventValidationErrorType::LocationInvalid => {
+                f.write_str("the location's length is less than ")?;
+                Display::fmt(&SCHEDULED_EVENT_LOCATION_LENGTH_MIN, f)?;
+                f.write_str(" or greater than ")?;
+
+                Display::fmt(&SCHEDULED_EVENT_LOCATION_LENGTH_MAX, f)
+            }
+            ScheduledEventValidationErrorType::LocationMissing => {
+                f.write_str("external scheduled events require a location")
+            }
+            ScheduledEventValidationErrorType::LocationUnexpected => {
+                f.write_str("stage instance and voice scheduled events can't have a location")
+            }
+            ScheduledEventValidationErrorType::StartTimeInPast => {
+                f.write_str("scheduled start time is not in the future")
+            }
+        }
+    }
+}
+
+impl Error for ScheduledEventValidationError {}
+
+/// Type of [`ScheduledEventValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ScheduledEventValidationErrorType {
+    /// Stage instance or voice scheduled event has no channel.
+    ChannelMissing,
+    /// External scheduled event has a channel.
+    ChannelUnexpected,
+    /// Scheduled end time is not after the scheduled start time.
+    EndTimeBeforeStartTime,
+    /// External scheduled event has no scheduled end time.
+    EndTimeMissing,
+    /// External scheduled event's location is invalid.
+    LocationInvalid,
+    /// External scheduled event has no location.
+    LocationMissing,
+    /// Stage instance or voice scheduled event has a location.
+    LocationUnexpected,
+    /// Scheduled start time is not in the future.
+    StartTimeInPast,
+}
+
+/// Ensure that a scheduled event's channel is correct for its entity type.
+///
+/// [`EntityType::StageInstance`] and [`EntityType::Voice`] events require a
+/// channel, whereas [`EntityType::External`] events must not have one. This
+/// is based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`ChannelMissing`] if a stage instance or voice
+/// event has no channel.
+///
+/// Returns an error of type [`ChannelUnexpected`] if an external event has a
+/// channel.
+///
+/// [`ChannelMissing`]: ScheduledEventValidationErrorType::ChannelMissing
+/// [`ChannelUnexpected`]: ScheduledEventValidationErrorType::ChannelUnexpected
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-field-requirements-by-entity-type
+pub const fn channel(
+    entity_type: EntityType,
+    channel_id: Option<Id<ChannelMarker>>,
+) -> Result<(), ScheduledEventValidationError> {
+    let kind = match (entity_type, channel_id) {
+        (EntityType::StageInstance | EntityType::Voice, None) => {
+            ScheduledEventValidationErrorType::ChannelMissing
+        }
+        (EntityType::External, Some(_)) => ScheduledEventValidationErrorType::ChannelUnexpected,
+        _ => return Ok(()),
+    };
+
+    Err(ScheduledEventValidationError { kind })
+}
+
+/// Ensure that a scheduled event's scheduled end time is present if required
+/// by its entity type.
+///
+/// [`EntityType::External`] events require a scheduled end time. This is
+/// based on [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`EndTimeMissing`] if an external event has no
+/// scheduled end time.
+///
+/// [`EndTimeMissing`]: ScheduledEventValidationErrorType::EndTimeMissing
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-field-requirements-by-entity-type
+pub fn end_time(
+    entity_type: EntityType,
+    scheduled_end_time: Option<&Timestamp>,
+) -> Result<(), ScheduledEventValidationError> {
+    if entity_type == EntityType::External && scheduled_end_time.is_none() {
+        return Err(ScheduledEventValidationError {
+            kind: ScheduledEventValidationErrorType::EndTimeMissing,
+        });
+    }
+
+    Ok(())
+}
+
+/// Ensure that a scheduled event's fields are correct for its entity type.
+///
+/// Refer to [`channel`], [`end_time`], and [`location`] for the requirements.
+///
+/// # Errors
+///
+/// Returns an error of type [`ChannelMissing`] or [`ChannelUnexpected`] if
+/// the channel is invalid.
+///
+/// Returns an error of type [`EndTimeMissing`] if the scheduled end time is
+/// invalid.
+///
+/// Returns an error of type [`LocationInvalid`], [`LocationMissing`], or
+/// [`LocationUnexpected`] if the location is invalid.
+///
+/// [`ChannelMissing`]: ScheduledEventValidationErrorType::ChannelMissing
+/// [`ChannelUnexpected`]: ScheduledEventValidationErrorType::ChannelUnexpected
+/// [`EndTimeMissing`]: ScheduledEventValidationErrorType::EndTimeMissing
+/// [`LocationInvalid`]: ScheduledEventValidationErrorType::LocationInvalid
+/// [`LocationMissing`]: ScheduledEventValidationErrorType::LocationMissing
+/// [`LocationUnexpected`]: ScheduledEventValidationErrorType::LocationUnexpected
+pub fn entity(
+    entity_type: EntityType,
+    channel_id: Option<Id<ChannelMarker>>,
+    location: Option<&str>,
+    scheduled_end_time: Option<&Timestamp>,
+) -> Result<(), ScheduledEventValidationError> {
+    self::channel(entity_type, channel_id)?;
+    self::location(entity_type, location)?;
+
+    self::end_time(entity_type, scheduled_end_time)
+}
+
+/// Ensure that a scheduled event's location is correct for its entity type.
+///
+/// [`EntityType::External`] events require a location, whose length must be
+/// at least [`SCHEDULED_EVENT_LOCATION_LENGTH_MIN`] and at most
+/// [`SCHEDULED_EVENT_LOCATION_LENGTH_MAX`]. [`EntityType::StageInstance`] and
+/// [`EntityType::Voice`] events must not have a location. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`LocationInvalid`] if the length is invalid.
+///
+/// Returns an error of type [`LocationMissing`] if an external event has no
+/// location.
+///
+/// Returns an error of type [`LocationUnexpected`] if a stage instance or
+/// voice event has a location.
+///
+/// [`LocationInvalid`]: ScheduledEventValidationErrorType::LocationInvalid
+/// [`LocationMissing`]: ScheduledEventValidationErrorType::LocationMissing
+/// [`LocationUnexpected`]: ScheduledEventValidationErrorType::LocationUnexpected
+/// [this documentation entry]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-field-requirements-by-entity-type
+pub fn location(
+    entity_type: EntityType,
+    location: Option<&str>,
+) -> Result<(), ScheduledEventValidationError> {
+    let kind = match (entity_type, location) {
+        (EntityType::External, Some(location))
+            if !(SCHEDULED_EVENT_LOCATION_LENGTH_MIN..=SCHEDULED_EVENT_LOCATION_LENGTH_MAX)
+                .contains(&location.chars().count()) =>
+        {
+            ScheduledEventValidationErrorType::LocationInvalid
+        }
+        (EntityType::External, None) => ScheduledEventValidationErrorType::LocationMissing,
+        (EntityType::StageInstance | EntityType::Voice, Some(_)) => {
+            ScheduledEventValidationErrorType::LocationUnexpected
+        }
+        _ => return Ok(()),
+    };
+
+    Err(ScheduledEventValidationError { kind })
+}
+
+/// Ensure that a scheduled event's scheduled times are correct.
+///
+/// The scheduled start time must be after `now` and, if present, the
+/// scheduled end time must be after the scheduled start time.
+///
+/// # Errors
+///
+/// Returns an error of type [`StartTimeInPast`] if the scheduled start time
+/// is not after `now`.
+///
+/// Returns an error of type [`EndTimeBeforeStartTime`] if the scheduled end
+/// time is not after the scheduled start time.
+///
+/// [`EndTimeBeforeStartTime`]: ScheduledEventValidationErrorType::EndTimeBeforeStartTime
+/// [`StartTimeInPast`]: ScheduledEventValidationErrorType::StartTimeInPast
+pub fn times(
+    scheduled_start_time: &Timestamp,
+    scheduled_end_time: Option<&Timestamp>,
+    now: &Timestamp,
+) -> Result<(), ScheduledEventValidationError> {
+    if scheduled_start_time.as_micros() <= now.as_micros() {
+        return Err(ScheduledEventValidationError {
+            kind: ScheduledEventValidationErrorType::StartTimeInPast,
+        });
+    }
+
+    if let Some(scheduled_end_time) = scheduled_end_time {
+        end_after_start(scheduled_start_time, scheduled_end_time)?;
+    }
+
+    Ok(())
+}
+
+/// Ensure that a scheduled event's scheduled end time is after its scheduled
+/// start time.
+///
+/// # Errors
+///
+/// Returns an error of type [`EndTimeBeforeStartTime`] if the scheduled end
+/// time is not after the scheduled start time.
+///
+/// [`EndTimeBeforeStartTime`]: ScheduledEventValidationErrorType::EndTimeBeforeStartTime
+pub const fn end_after_start(
+    scheduled_start_time: &Timestamp,
+    scheduled_end_time: &Timestamp,
+) -> Result<(), ScheduledEventValidationError> {
+    if scheduled_end_time.as_micros() <= scheduled_start_time.as_micros() {
+        return Err(ScheduledEventValidationError {
+            kind: ScheduledEventValidationErrorType::EndTimeBeforeStartTime,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ScheduledEventValidationErrorType: Debug, Send, Sync);
+    assert_impl_all!(ScheduledEventValidationError: Error, Send, Sync);
+
+    /// ID of the channel of stage instance and voice events.
+    const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+
+    /// 2000-01-01T00:00:00+00:00
+    fn past() -> Timestamp {
+        Timestamp::from_secs(946_684_800).unwrap()
+    }
+
+    /// 2100-01-01T00:00:00+00:00
+    fn future() -> Timestamp {
+        Timestamp::from_secs(4_102_444_800).unwrap()
+    }
+
+    /// 2100-01-01T02:00:00+00:00
+    fn future_end() -> Timestamp {
+        Timestamp::from_secs(4_102_452_000).unwrap()
+    }
+
+    /// Type of the error of a failed validation.
+    fn kind(
+        result: Result<(), ScheduledEventValidationError>,
+    ) -> ScheduledEventValidationErrorType {
+        result.unwrap_err().into_parts().0
+    }
+
+    #[test]
+    fn external() {
+        let end = future_end();
+        assert!(entity(EntityType::External, None, Some("park"), Some(&end)).is_ok());
+        assert!(times(&future(), Some(&end), &past()).is_ok());
+
+        assert!(matches!(
+            kind(entity(
+                EntityType::External,
+                Some(CHANNEL_ID),
+                Some("park"),
+                Some(&end)
+            )),
+            ScheduledEventValidationErrorType::ChannelUnexpected
+        ));
+        assert!(matches!(
+            kind(entity(EntityType::External, None, None, Some(&end))),
+            ScheduledEventValidationErrorType::LocationMissing
+        ));
+        assert!(matches!(
+            kind(entity(EntityType::External, None, Some(""), Some(&end))),
+            ScheduledEventValidationErrorType::LocationInvalid
+        ));
+        assert!(matches!(
+            kind(entity(
+                EntityType::External,
+                None,
+                Some(&"a".repeat(SCHEDULED_EVENT_LOCATION_LENGTH_MAX + 1)),
+                Some(&end)
+            )),
+            ScheduledEventValidationErrorType::LocationInvalid
+        ));
+        assert!(entity(
+            EntityType::External,
+            None,
+            Some(&"a".repeat(SCHEDULED_EVENT_LOCATION_LENGTH_MAX)),
+            Some(&end)
+        )
+        .is_ok());
+        assert!(matches!(
+            kind(entity(EntityType::External, None, Some("park"), None)),
+            ScheduledEventValidationErrorType::EndTimeMissing
+        ));
+    }
+
+    #[test]
+    fn stage_instance() {
+        assert!(entity(EntityType::StageInstance, Some(CHANNEL_ID), None, None).is_ok());
+        assert!(times(&future(), None, &past()).is_ok());
+
+        assert!(matches!(
+            kind(entity(EntityType::StageInstance, None, None, None)),
+            ScheduledEventValidationErrorType::ChannelMissing
+        ));
+        assert!(matches!(
+            kind(entity(
+                EntityType::StageInstance,
+                Some(CHANNEL_ID),
+                Some("park"),
+                None
+            )),
+            ScheduledEventValidationErrorType::LocationUnexpected
+        ));
+    }
+
+    #[test]
+    fn voice() {
+        let end = future_end();
+        assert!(entity(EntityType::Voice, Some(CHANNEL_ID), None, None).is_ok());
+        assert!(entity(EntityType::Voice, Some(CHANNEL_ID), None, Some(&end)).is_ok());
+
+        assert!(matches!(
+            kind(entity(EntityType::Voice, None, None, None)),
+            ScheduledEventValidationErrorType::ChannelMissing
+        ));
+        assert!(matches!(
+            kind(entity(
+                EntityType::Voice,
+                Some(CHANNEL_ID),
+                Some("park"),
+                None
+            )),
+            ScheduledEventValidationErrorType::LocationUnexpected
+        ));
+    }
+
+    #[test]
+    fn scheduled_times() {
+        let now = past();
+        let start = future();
+
+        assert!(matches!(
+            kind(times(&now, None, &now)),
+            ScheduledEventValidationErrorType::StartTimeInPast
+        ));
+        assert!(matches!(
+            kind(times(&now, None, &start)),
+            ScheduledEventValidationErrorType::StartTimeInPast
+        ));
+        assert!(matches!(
+            kind(times(&start, Some(&start), &now)),
+            ScheduledEventValidationErrorType::EndTimeBeforeStartTime
+        ));
+        assert!(matches!(
+            kind(times(&start, Some(&now), &now)),
+            ScheduledEventValidationErrorType::EndTimeBeforeStartTime
+        ));
+        assert!(end_after_start(&past(), &start).is_ok());
+    }
+}
@@ -6,6 +6,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
+use twilight_model::{channel::message::sticker::StickerFormatType, guild::PremiumTier};
 
 /// Maximum length of a sticker description.
 pub const STICKER_DESCRIPTION_LENGTH_MAX: usize = 200;
@@ -13,6 +14,9 @@ pub const STICKER_DESCRIPTION_LENGTH_MAX: usize = 200;
 /// Minimum length of a sticker description.
 pub const STICKER_DESCRIPTION_LENGTH_MIN: usize = 2;
 
+/// Maximum size of a sticker file in bytes.
+pub const STICKER_FILE_SIZE_MAX: usize = 512 * 1024;
+
 /// Maximum length of a sticker name.
 pub const STICKER_NAME_LENGTH_MAX: usize = 30;
 
@@ -60,10 +64,20 @@ impl StickerValidationError {
 
 impl Display for StickerValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self.kind {
+        match &self.kind {
             StickerValidationErrorType::DescriptionInvalid => {
                 f.write_str("sticker's description is invalid")
             }
+            StickerValidationErrorType::FileSizeInvalid { size } => {
+                f.write_str("sticker's file is ")?;
+                Display::fmt(size, f)?;
+                f.write_str(" bytes, but it must be at least 1 and at most ")?;
+
+                Display::fmt(&STICKER_FILE_SIZE_MAX, f)
+            }
+            StickerValidationErrorType::FileTypeInvalid => {
+                f.write_str("sticker's file isn't a png, apng, gif, or lottie file")
+            }
             StickerValidationErrorType::NameInvalid => f.write_str("sticker's name is invalid"),
             StickerValidationErrorType::TagsInvalid => f.write_str("sticker's tags are invalid"),
         }
@@ -77,6 +91,15 @@ impl Error for StickerValidationError {}
 pub enum StickerValidationErrorType {
     /// Sticker's description is invalid.
     DescriptionInvalid,
+    /// Sticker's file is empty or larger than [`STICKER_FILE_SIZE_MAX`].
+    FileSizeInvalid {
+        /// Provided size in bytes.
+        size: usize,
+    },
+    /// Sticker's file isn't of a supported [format].
+    ///
+    /// [format]: file_format
+    FileTypeInvalid,
     /// Sticker's name is invalid.
     NameInvalid,
     /// Sticker's tags are invalid.
@@ -107,6 +130,98 @@ pub fn description(value: impl AsRef<str>) -> Result<(), StickerValidationError>
     }
 }
 
+/// Ensure that a sticker's file is correct.
+///
+/// The file must not be empty, be at most [`STICKER_FILE_SIZE_MAX`] bytes
+/// large, and be of a [supported format]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`FileSizeInvalid`] if the size is invalid.
+///
+/// Returns an error of type [`FileTypeInvalid`] if the format isn't
+/// supported.
+///
+/// [`FileSizeInvalid`]: StickerValidationErrorType::FileSizeInvalid
+/// [`FileTypeInvalid`]: StickerValidationErrorType::FileTypeInvalid
+/// [supported format]: file_format
+/// [this documentation entry]: https://discord.com/developers/docs/resources/sticker#create-guild-sticker
+pub fn file(value: &[u8]) -> Result<(), StickerValidationError> {
+    if value.is_empty() || value.len() > STICKER_FILE_SIZE_MAX {
+        return Err(StickerValidationError {
+            kind: StickerValidationErrorType::FileSizeInvalid { size: value.len() },
+        });
+    }
+
+    if file_format(value).is_none() {
+        return Err(StickerValidationError {
+            kind: StickerValidationErrorType::FileTypeInvalid,
+        });
+    }
+
+    Ok(())
+}
+
+/// Detect the format of a sticker file from its contents.
+///
+/// PNG files are detected as [`Apng`] if they contain an animation control
+/// chunk, and any JSON object is assumed to be a [`Lottie`] animation.
+/// Returns [`None`] if the file isn't a PNG, APNG, GIF, or Lottie file.
+///
+/// [`Apng`]: StickerFormatType::Apng
+/// [`Lottie`]: StickerFormatType::Lottie
+pub fn file_format(value: &[u8]) -> Option<StickerFormatType> {
+    const GIF_SIGNATURES: [&[u8]; 2] = [b"GIF87a", b"GIF89a"];
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    if GIF_SIGNATURES
+        .iter()
+        .any(|signature| value.starts_with(signature))
+    {
+        return Some(StickerFormatType::Gif);
+    }
+
+    if let Some(mut chunks) = value.strip_prefix(PNG_SIGNATURE) {
+        // Each chunk is its length, type, data, and checksum. The animation
+        // control chunk must precede the first image data chunk.
+        while let (Some(len), Some(kind)) = (chunks.get(..4), chunks.get(4..8)) {
+            match kind {
+                b"acTL" => return Some(StickerFormatType::Apng),
+                b"IDAT" | b"IEND" => break,
+                _ => {}
+            }
+
+            let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize;
+            let Some(rest) = chunks.get(len.saturating_add(12)..) else {
+                break;
+            };
+            chunks = rest;
+        }
+
+        return Some(StickerFormatType::Png);
+    }
+
+    value
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .filter(|byte| **byte == b'{')
+        .map(|_| StickerFormatType::Lottie)
+}
+
+/// Maximum number of stickers a guild may have at a premium tier.
+///
+/// Unknown premium tiers are assumed to allow as many stickers as
+/// [`PremiumTier::Tier3`].
+pub const fn guild_limit(premium_tier: PremiumTier) -> usize {
+    match premium_tier {
+        PremiumTier::None => 5,
+        PremiumTier::Tier1 => 15,
+        PremiumTier::Tier2 => 30,
+        _ => 60,
+    }
+}
+
 /// Ensure that a sticker's name is correct.
 ///
 /// The length must be at least [`STICKER_NAME_LENGTH_MIN`] and at most
@@ -166,6 +281,74 @@ mod tests {
         assert!(description("a".repeat(201)).is_err());
     }
 
+    #[test]
+    fn file_validation() {
+        assert!(file(b"GIF89a").is_ok());
+        assert!(file(&vec![b'{'; STICKER_FILE_SIZE_MAX]).is_ok());
+
+        assert!(matches!(
+            file(&[]).unwrap_err().kind(),
+            StickerValidationErrorType::FileSizeInvalid { size: 0 }
+        ));
+        assert!(matches!(
+            file(&vec![b'{'; STICKER_FILE_SIZE_MAX + 1])
+                .unwrap_err()
+                .kind(),
+            StickerValidationErrorType::FileSizeInvalid { .. }
+        ));
+        assert!(matches!(
+            file(b"\xff\xd8\xff").unwrap_err().kind(),
+            StickerValidationErrorType::FileTypeInvalid
+        ));
+    }
+
+    #[test]
+    fn file_formats() {
+        const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+        let chunk = |kind: &[u8], data: &[u8]| {
+            let mut chunk = u32::try_from(data.len()).unwrap().to_be_bytes().to_vec();
+            chunk.extend(kind);
+            chunk.extend(data);
+            chunk.extend([0; 4]);
+
+            chunk
+        };
+
+        let png = [PNG, &chunk(b"IHDR", &[0; 13]), &chunk(b"IDAT", &[0; 2])].concat();
+        assert_eq!(Some(StickerFormatType::Png), file_format(&png));
+
+        let apng = [PNG, &chunk(b"IHDR", &[0; 13]), &chunk(b"acTL", &[0; 8])].concat();
+        assert_eq!(Some(StickerFormatType::Apng), file_format(&apng));
+
+        // Animation control chunks after the image data aren't valid.
+        let png = [PNG, &chunk(b"IDAT", &[0; 2]), &chunk(b"acTL", &[0; 8])].concat();
+        assert_eq!(Some(StickerFormatType::Png), file_format(&png));
+
+        // Truncated chunks don't panic.
+        assert_eq!(
+            Some(StickerFormatType::Png),
+            file_format(&[PNG, &[0, 0, 0, 20, b'I']].concat())
+        );
+
+        assert_eq!(Some(StickerFormatType::Gif), file_format(b"GIF87a..."));
+        assert_eq!(
+            Some(StickerFormatType::Lottie),
+            file_format(b" \n{\"v\":\"5.5.2\"}")
+        );
+        assert!(file_format(b"[]").is_none());
+        assert!(file_format(b"").is_none());
+    }
+
+    #[test]
+    fn guild_limits() {
+        assert_eq!(5, guild_limit(PremiumTier::None));
+        assert_eq!(15, guild_limit(PremiumTier::Tier1));
+        assert_eq!(30, guild_limit(PremiumTier::Tier2));
+        assert_eq!(60, guild_limit(PremiumTier::Tier3));
+        assert_eq!(60, guild_limit(PremiumTier::Other(4)));
+    }
+
     #[test]
     fn name_length() {
         assert!(name("aa").is_ok());
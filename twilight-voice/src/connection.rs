@@ -0,0 +1,723 @@
+//! Voice connections to a guild's voice server.
+//!
+//! A [`Connection`] is created from the [`ConnectionInfo`] Discord sends over
+//! the gateway after [updating the voice state] of the current user. It
+//! performs the voice gateway handshake, discovers the external address of
+//! its UDP socket, and sends encrypted Opus frames.
+//!
+//! [updating the voice state]: twilight_model::gateway::payload::outgoing::UpdateVoiceState
+
+use crate::{
+    crypto::{Cipher, EncryptionMode},
+    model::{
+        Command, Event, Heartbeat, Hello, Identify, MinimalEvent, Opcode, Ready, SelectProtocol,
+        SelectProtocolData, SessionDescription, Speaking, VERSION,
+    },
+    rtp::{Packetizer, HEADER_LEN},
+    udp,
+};
+use futures_util::{SinkExt, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::Ipv4Addr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    net::{TcpStream, UdpSocket},
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    time::{self, MissedTickBehavior},
+};
+use tokio_websockets::{ClientBuilder, MaybeTlsStream, Message, WebSocketStream};
+use twilight_model::{
+    gateway::payload::incoming::VoiceServerUpdate,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+    voice::VoiceState,
+};
+
+/// Duration of audio in each Opus frame.
+pub const FRAME_DURATION: Duration = Duration::from_millis(20);
+
+/// Opus frame of silence.
+///
+/// Five of these should be sent when stopping transmission to avoid
+/// interpolation with the next transmission.
+pub const SILENCE_FRAME: [u8; 3] = [0xf8, 0xff, 0xfe];
+
+/// Websocket stream of the voice gateway.
+type Stream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Connecting to a voice server failed.
+#[derive(Debug)]
+pub struct ConnectionError {
+    kind: ConnectionErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ConnectionError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ConnectionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ConnectionErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn websocket(source: tokio_websockets::Error) -> Self {
+        Self {
+            kind: ConnectionErrorType::Websocket,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ConnectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ConnectionErrorType::BuildingConnectionRequest => {
+                f.write_str("failed to build connection request")
+            }
+            ConnectionErrorType::Closed => {
+                f.write_str("voice gateway closed the connection during the handshake")
+            }
+            ConnectionErrorType::Deserializing { payload } => {
+                f.write_str("failed to deserialize voice gateway payload: ")?;
+
+                f.write_str(payload)
+            }
+            ConnectionErrorType::Discovery => {
+                f.write_str("failed to discover the external address of the udp socket")
+            }
+            ConnectionErrorType::Udp => f.write_str("failed to connect the udp socket"),
+            ConnectionErrorType::UnsupportedModes { modes } => {
+                f.write_str("voice server offered no supported encryption modes: ")?;
+
+                f.write_str(&modes.join(", "))
+            }
+            ConnectionErrorType::Websocket => {
+                f.write_str("failed to communicate with the voice gateway")
+            }
+        }
+    }
+}
+
+impl Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ConnectionError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConnectionErrorType {
+    /// Building the request to connect to the voice gateway failed, such as
+    /// due to an invalid endpoint.
+    BuildingConnectionRequest,
+    /// Voice gateway closed the connection before the handshake completed.
+    Closed,
+    /// Deserializing a payload from the voice gateway failed.
+    Deserializing {
+        /// Payload that couldn't be deserialized.
+        payload: String,
+    },
+    /// Discovering the external address of the UDP socket failed.
+    Discovery,
+    /// Binding or connecting the UDP socket failed.
+    Udp,
+    /// Voice server didn't offer any supported [`EncryptionMode`].
+    UnsupportedModes {
+        /// Names of the modes offered by the voice server.
+        modes: Vec<String>,
+    },
+    /// Connecting to or communicating with the voice gateway failed.
+    Websocket,
+}
+
+/// Sending audio over a [`Connection`] failed.
+#[derive(Debug)]
+pub struct SendError {
+    kind: SendErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SendError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SendErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SendErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for SendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            SendErrorType::Closed => f.write_str("voice gateway connection is closed"),
+            SendErrorType::Encrypting => f.write_str("failed to encrypt packet"),
+            SendErrorType::Sending => f.write_str("failed to send packet over udp socket"),
+        }
+    }
+}
+
+impl Error for SendError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SendError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SendErrorType {
+    /// Connection to the voice gateway is closed, such as when the current
+    /// user was disconnected from the voice channel.
+    Closed,
+    /// Encrypting a packet failed.
+    Encrypting,
+    /// Sending a packet over the UDP socket failed.
+    Sending,
+}
+
+/// Information required to connect to a voice server.
+///
+/// Discord sends this information in a [`VoiceStateUpdate`] for the current
+/// user and a [`VoiceServerUpdate`] after [updating the voice state] of the
+/// current user.
+///
+/// [`VoiceStateUpdate`]: twilight_model::gateway::payload::incoming::VoiceStateUpdate
+/// [updating the voice state]: twilight_model::gateway::payload::outgoing::UpdateVoiceState
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConnectionInfo {
+    /// Host of the voice server, without a scheme.
+    pub endpoint: String,
+    /// ID of the guild the voice server belongs to.
+    pub guild_id: Id<GuildMarker>,
+    /// ID of the current user's voice session.
+    pub session_id: String,
+    /// Token to authenticate with the voice server.
+    pub token: String,
+    /// ID of the current user.
+    pub user_id: Id<UserMarker>,
+}
+
+impl ConnectionInfo {
+    /// Create connection information from the current user's voice state and
+    /// the guild's voice server.
+    ///
+    /// Returns `None` if the voice server has no endpoint, meaning that it
+    /// was deallocated, or if the voice state and voice server are for
+    /// different guilds.
+    pub fn from_updates(state: &VoiceState, server: &VoiceServerUpdate) -> Option<Self> {
+        if state.guild_id != Some(server.guild_id) {
+            return None;
+        }
+
+        Some(Self {
+            endpoint: server.endpoint.clone()?,
+            guild_id: server.guild_id,
+            session_id: state.session_id.clone(),
+            token: server.token.clone(),
+            user_id: state.user_id,
+        })
+    }
+}
+
+/// Connection to a guild's voice server for sending audio.
+///
+/// The voice gateway websocket is kept alive by a background task for as long
+/// as the connection isn't dropped.
+///
+/// Receiving audio and end-to-end encryption via the DAVE protocol aren't
+/// supported.
+#[derive(Debug)]
+pub struct Connection {
+    /// Buffer packets are written to.
+    buf: Vec<u8>,
+    /// Cipher encrypting packets.
+    cipher: Cipher,
+    /// Sender of commands to the voice gateway task.
+    commands: UnboundedSender<String>,
+    /// ID of the guild the connection is for.
+    guild_id: Id<GuildMarker>,
+    /// Negotiated encryption mode.
+    mode: EncryptionMode,
+    /// State of the RTP stream.
+    packetizer: Packetizer,
+    /// Synchronization source assigned by the voice server.
+    ssrc: u32,
+    /// UDP socket connected to the voice server.
+    socket: UdpSocket,
+    /// Whether the current user is marked as speaking.
+    speaking: bool,
+}
+
+impl Connection {
+    /// Connect to a voice server.
+    ///
+    /// This performs the voice gateway handshake, discovers the external
+    /// address of the UDP socket, and negotiates the most preferred supported
+    /// [`EncryptionMode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::BuildingConnectionRequest`] error type
+    /// if the endpoint is invalid.
+    ///
+    /// Returns a [`ConnectionErrorType::Closed`] error type if the voice
+    /// gateway closed the connection during the handshake, such as when the
+    /// connection information is invalid.
+    ///
+    /// Returns a [`ConnectionErrorType::Deserializing`] error type if a
+    /// payload from the voice gateway couldn't be deserialized.
+    ///
+    /// Returns a [`ConnectionErrorType::Discovery`] error type if the external
+    /// address of the UDP socket couldn't be discovered.
+    ///
+    /// Returns a [`ConnectionErrorType::Udp`] error type if the UDP socket
+    /// couldn't be bound or connected.
+    ///
+    /// Returns a [`ConnectionErrorType::UnsupportedModes`] error type if the
+    /// voice server offered no supported encryption mode.
+    ///
+    /// Returns a [`ConnectionErrorType::Websocket`] error type if connecting
+    /// to or communicating with the voice gateway failed.
+    pub async fn connect(info: &ConnectionInfo) -> Result<Self, ConnectionError> {
+        let uri = format!("wss://{}/?v={VERSION}", info.endpoint);
+        tracing::debug!(guild_id = %info.guild_id, %uri, "connecting to voice gateway");
+
+        let (mut stream, _) = ClientBuilder::new()
+            .uri(&uri)
+            .map_err(|source| ConnectionError {
+                kind: ConnectionErrorType::BuildingConnectionRequest,
+                source: Some(Box::new(source)),
+            })?
+            .connect()
+            .await
+            .map_err(ConnectionError::websocket)?;
+        let mut sequence = None;
+
+        let hello = receive::<Hello>(&mut stream, Opcode::Hello, &mut sequence).await?;
+        send(
+            &mut stream,
+            Opcode::Identify,
+            Identify {
+                server_id: info.guild_id,
+                session_id: &info.session_id,
+                token: &info.token,
+                user_id: info.user_id,
+            },
+        )
+        .await?;
+
+        let ready = receive::<Ready>(&mut stream, Opcode::Ready, &mut sequence).await?;
+        let Some(mode) = EncryptionMode::select(&ready.modes) else {
+            return Err(ConnectionError {
+                kind: ConnectionErrorType::UnsupportedModes { modes: ready.modes },
+                source: None,
+            });
+        };
+
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+            .await
+            .map_err(|source| ConnectionError {
+                kind: ConnectionErrorType::Udp,
+                source: Some(Box::new(source)),
+            })?;
+        socket
+            .connect((ready.ip.as_str(), ready.port))
+            .await
+            .map_err(|source| ConnectionError {
+                kind: ConnectionErrorType::Udp,
+                source: Some(Box::new(source)),
+            })?;
+        let (address, port) =
+            udp::discover(&socket, ready.ssrc)
+                .await
+                .map_err(|source| ConnectionError {
+                    kind: ConnectionErrorType::Discovery,
+                    source: Some(Box::new(source)),
+                })?;
+        tracing::debug!(%address, port, ?mode, "discovered external address");
+
+        send(
+            &mut stream,
+            Opcode::SelectProtocol,
+            SelectProtocol {
+                data: SelectProtocolData {
+                    address: address.to_string(),
+                    mode,
+                    port,
+                },
+                protocol: "udp",
+            },
+        )
+        .await?;
+
+        let description =
+            receive::<SessionDescription>(&mut stream, Opcode::SessionDescription, &mut sequence)
+                .await?;
+
+        let (commands, rx) = mpsc::unbounded_channel();
+        let heartbeat_interval = Duration::from_secs_f64(hello.heartbeat_interval / 1000.);
+        tokio::spawn(run(stream, rx, heartbeat_interval, sequence));
+
+        Ok(Self {
+            buf: Vec::new(),
+            cipher: Cipher::new(description.mode, description.secret_key),
+            commands,
+            guild_id: info.guild_id,
+            mode: description.mode,
+            packetizer: Packetizer::new(ready.ssrc),
+            ssrc: ready.ssrc,
+            socket,
+            speaking: false,
+        })
+    }
+
+    /// ID of the guild the connection is for.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// Negotiated encryption mode.
+    pub const fn mode(&self) -> EncryptionMode {
+        self.mode
+    }
+
+    /// Synchronization source assigned by the voice server.
+    pub const fn ssrc(&self) -> u32 {
+        self.ssrc
+    }
+
+    /// Mark the current user as speaking or no longer speaking.
+    ///
+    /// This is done automatically by [`send_frame`] and [`play`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendErrorType::Closed`] error type if the connection to the
+    /// voice gateway is closed.
+    ///
+    /// [`play`]: Self::play
+    /// [`send_frame`]: Self::send_frame
+    #[allow(clippy::missing_panics_doc)]
+    pub fn speaking(&mut self, speaking: bool) -> Result<(), SendError> {
+        let command = Command::new(
+            Opcode::Speaking,
+            Speaking {
+                delay: 0,
+                speaking: speaking.into(),
+                ssrc: self.ssrc,
+            },
+        );
+        let payload = serde_json::to_string(&command).expect("valid json");
+
+        self.commands.send(payload).map_err(|_| SendError {
+            kind: SendErrorType::Closed,
+            source: None,
+        })?;
+        self.speaking = speaking;
+
+        Ok(())
+    }
+
+    /// Send a single Opus frame.
+    ///
+    /// Frames must be encoded as stereo at 48 kHz with a duration of
+    /// [`FRAME_DURATION`]. Sending them at the right pace is up to the caller;
+    /// refer to [`play`] for a method that does so.
+    ///
+    /// Marks the current user as speaking if they aren't already.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendErrorType::Closed`] error type if the connection to the
+    /// voice gateway is closed.
+    ///
+    /// Returns a [`SendErrorType::Encrypting`] error type if encrypting the
+    /// packet failed.
+    ///
+    /// Returns a [`SendErrorType::Sending`] error type if sending the packet
+    /// failed.
+    ///
+    /// [`play`]: Self::play
+    pub async fn send_frame(&mut self, frame: &[u8]) -> Result<(), SendError> {
+        if !self.speaking {
+            self.speaking(true)?;
+        }
+
+        let nonce = self.packetizer.next(&mut self.buf);
+        self.buf.extend_from_slice(frame);
+        self.cipher
+            .encrypt(&mut self.buf, HEADER_LEN, nonce)
+            .map_err(|source| SendError {
+                kind: SendErrorType::Encrypting,
+                source: Some(Box::new(source)),
+            })?;
+
+        self.socket
+            .send(&self.buf)
+            .await
+            .map_err(|source| SendError {
+                kind: SendErrorType::Sending,
+                source: Some(Box::new(source)),
+            })?;
+
+        Ok(())
+    }
+
+    /// Send Opus frames paced at [`FRAME_DURATION`], followed by five
+    /// [`SILENCE_FRAME`]s, and mark the current user as no longer speaking.
+    ///
+    /// Refer to [`send_frame`] for the format of frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`send_frame`].
+    ///
+    /// [`send_frame`]: Self::send_frame
+    pub async fn play<I>(&mut self, frames: I) -> Result<(), SendError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut interval = time::interval(FRAME_DURATION);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Burst);
+
+        for frame in frames {
+            interval.tick().await;
+            self.send_frame(frame.as_ref()).await?;
+        }
+
+        for _ in 0..5 {
+            interval.tick().await;
+            self.send_frame(&SILENCE_FRAME).await?;
+        }
+
+        self.speaking(false)
+    }
+}
+
+/// Serialize and send a command to the voice gateway.
+async fn send(
+    stream: &mut Stream,
+    opcode: Opcode,
+    data: impl Serialize,
+) -> Result<(), ConnectionError> {
+    let payload = serde_json::to_string(&Command::new(opcode, data)).expect("valid json");
+
+    stream
+        .send(Message::text(payload))
+        .await
+        .map_err(ConnectionError::websocket)
+}
+
+/// Receive payloads from the voice gateway until one with the opcode is
+/// received, deserializing its data.
+async fn receive<T: DeserializeOwned>(
+    stream: &mut Stream,
+    opcode: Opcode,
+    sequence: &mut Option<u64>,
+) -> Result<T, ConnectionError> {
+    loop {
+        let Some(message) = stream.next().await else {
+            return Err(ConnectionError {
+                kind: ConnectionErrorType::Closed,
+                source: None,
+            });
+        };
+        let message = message.map_err(ConnectionError::websocket)?;
+
+        if message.is_close() {
+            tracing::debug!(close = ?message.as_close(), "voice gateway closed during handshake");
+
+            return Err(ConnectionError {
+                kind: ConnectionErrorType::Closed,
+                source: None,
+            });
+        }
+
+        let Some(text) = message.as_text() else {
+            continue;
+        };
+
+        let deserializing = |source: serde_json::Error| ConnectionError {
+            kind: ConnectionErrorType::Deserializing {
+                payload: text.to_owned(),
+            },
+            source: Some(Box::new(source)),
+        };
+        let minimal = serde_json::from_str::<MinimalEvent>(text).map_err(deserializing)?;
+
+        if minimal.seq.is_some() {
+            *sequence = minimal.seq;
+        }
+
+        if Opcode::from(minimal.op) == Some(opcode) {
+            return serde_json::from_str::<Event<T>>(text)
+                .map(|event| event.d)
+                .map_err(deserializing);
+        }
+
+        tracing::trace!(op = minimal.op, "skipping voice gateway payload");
+    }
+}
+
+/// Keep the voice gateway connection alive and forward commands until the
+/// connection is dropped or the voice gateway closes.
+async fn run(
+    mut stream: Stream,
+    mut commands: UnboundedReceiver<String>,
+    heartbeat_interval: Duration,
+    mut sequence: Option<u64>,
+) {
+    let mut heartbeat = time::interval(heartbeat_interval);
+    heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = heartbeat.tick() => {
+                let nonce = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_millis().try_into().unwrap_or(u64::MAX));
+                let command = Command::new(Opcode::Heartbeat, Heartbeat { seq_ack: sequence, t: nonce });
+                let payload = serde_json::to_string(&command).expect("valid json");
+
+                if let Err(source) = stream.send(Message::text(payload)).await {
+                    tracing::debug!(%source, "failed to send heartbeat");
+
+                    break;
+                }
+            }
+            command = commands.recv() => {
+                let Some(payload) = command else {
+                    tracing::debug!("connection dropped, closing voice gateway");
+                    _ = stream.close().await;
+
+                    break;
+                };
+
+                if let Err(source) = stream.send(Message::text(payload)).await {
+                    tracing::debug!(%source, "failed to send command");
+
+                    break;
+                }
+            }
+            message = stream.next() => {
+                let Some(Ok(message)) = message else {
+                    tracing::debug!("voice gateway connection lost");
+
+                    break;
+                };
+
+                if message.is_close() {
+                    tracing::debug!(close = ?message.as_close(), "voice gateway closed");
+
+                    break;
+                }
+
+                if let Some(seq) = message
+                    .as_text()
+                    .and_then(|text| serde_json::from_str::<MinimalEvent>(text).ok())
+                    .and_then(|minimal| minimal.seq)
+                {
+                    sequence = Some(seq);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Connection, ConnectionError, ConnectionErrorType, ConnectionInfo, SendError, SendErrorType,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::payload::incoming::VoiceServerUpdate, id::Id, voice::VoiceState,
+    };
+
+    assert_impl_all!(Connection: Debug, Send, Sync);
+    assert_impl_all!(ConnectionError: Debug, Error, Send, Sync);
+    assert_impl_all!(ConnectionErrorType: Debug, Send, Sync);
+    assert_impl_all!(ConnectionInfo: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SendError: Debug, Error, Send, Sync);
+    assert_impl_all!(SendErrorType: Debug, Send, Sync);
+
+    fn voice_state() -> VoiceState {
+        VoiceState {
+            channel_id: Some(Id::new(1)),
+            deaf: false,
+            guild_id: Some(Id::new(2)),
+            member: None,
+            mute: false,
+            self_deaf: false,
+            self_mute: false,
+            self_stream: false,
+            self_video: false,
+            session_id: "session".to_owned(),
+            suppress: false,
+            user_id: Id::new(3),
+            request_to_speak_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn from_updates() {
+        let mut server = VoiceServerUpdate {
+            endpoint: Some("voice.discord.media".to_owned()),
+            guild_id: Id::new(2),
+            token: "token".to_owned(),
+        };
+
+        assert_eq!(
+            Some(ConnectionInfo {
+                endpoint: "voice.discord.media".to_owned(),
+                guild_id: Id::new(2),
+                session_id: "session".to_owned(),
+                token: "token".to_owned(),
+                user_id: Id::new(3),
+            }),
+            ConnectionInfo::from_updates(&voice_state(), &server),
+        );
+
+        server.guild_id = Id::new(4);
+        assert!(ConnectionInfo::from_updates(&voice_state(), &server).is_none());
+
+        server.guild_id = Id::new(2);
+        server.endpoint = None;
+        assert!(ConnectionInfo::from_updates(&voice_state(), &server).is_none());
+    }
+}
@@ -0,0 +1,249 @@
+//! Encryption of outgoing voice packets.
+
+use chacha20poly1305::{aead::AeadInPlace, Key, KeyInit, XChaCha20Poly1305, XNonce};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM},
+    error::Unspecified,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+/// Length of the secret key provided by the voice server.
+pub(crate) const KEY_LEN: usize = 32;
+
+/// Length of the truncated nonce appended to each packet.
+pub(crate) const NONCE_LEN: usize = 4;
+
+/// Mode used to encrypt voice packets.
+///
+/// Both modes are "RTP size" modes: the RTP header is left unencrypted and
+/// authenticated as additional data, and a 32-bit incrementing nonce is
+/// appended to the end of each packet.
+///
+/// Discord no longer offers the `xsalsa20_poly1305` family of modes to new
+/// connections, so they aren't supported.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum EncryptionMode {
+    /// AES-256-GCM.
+    ///
+    /// Preferred by Discord and used when offered by the voice server.
+    #[serde(rename = "aead_aes256_gcm_rtpsize")]
+    Aes256Gcm,
+    /// XChaCha20-Poly1305.
+    ///
+    /// Always offered by the voice server.
+    #[serde(rename = "aead_xchacha20_poly1305_rtpsize")]
+    XChaCha20Poly1305,
+}
+
+impl EncryptionMode {
+    /// Supported modes in order of preference.
+    pub const PREFERENCE: [Self; 2] = [Self::Aes256Gcm, Self::XChaCha20Poly1305];
+
+    /// Name of the mode as used by the voice gateway.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Aes256Gcm => "aead_aes256_gcm_rtpsize",
+            Self::XChaCha20Poly1305 => "aead_xchacha20_poly1305_rtpsize",
+        }
+    }
+
+    /// Select the most preferred supported mode out of those offered by the
+    /// voice server.
+    pub(crate) fn select(offered: &[String]) -> Option<Self> {
+        Self::PREFERENCE
+            .into_iter()
+            .find(|mode| offered.iter().any(|name| name == mode.name()))
+    }
+}
+
+/// Cipher encrypting packets with a negotiated mode and secret key.
+pub(crate) enum Cipher {
+    /// AES-256-GCM with the secret key.
+    Aes256Gcm(Box<LessSafeKey>),
+    /// XChaCha20-Poly1305 with the secret key.
+    XChaCha20Poly1305(Box<XChaCha20Poly1305>),
+}
+
+impl Debug for Cipher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // The secret key is intentionally omitted.
+        let mode = match self {
+            Self::Aes256Gcm(_) => EncryptionMode::Aes256Gcm,
+            Self::XChaCha20Poly1305(_) => EncryptionMode::XChaCha20Poly1305,
+        };
+
+        f.debug_struct("Cipher").field("mode", &mode).finish()
+    }
+}
+
+impl Cipher {
+    /// Create a new cipher for a mode.
+    pub fn new(mode: EncryptionMode, secret_key: [u8; KEY_LEN]) -> Self {
+        match mode {
+            EncryptionMode::Aes256Gcm => Self::Aes256Gcm(Box::new(LessSafeKey::new(
+                UnboundKey::new(&AES_256_GCM, &secret_key).expect("key has a valid length"),
+            ))),
+            EncryptionMode::XChaCha20Poly1305 => Self::XChaCha20Poly1305(Box::new(
+                XChaCha20Poly1305::new(Key::from_slice(&secret_key)),
+            )),
+        }
+    }
+
+    /// Encrypt the payload of a packet in place.
+    ///
+    /// `packet` must contain the RTP header of length `header_len` followed
+    /// by the payload. The authentication tag and truncated nonce are
+    /// appended.
+    pub fn encrypt(
+        &self,
+        packet: &mut Vec<u8>,
+        header_len: usize,
+        nonce: u32,
+    ) -> Result<(), Unspecified> {
+        let (header, payload) = packet.split_at_mut(header_len);
+
+        match self {
+            Self::Aes256Gcm(key) => {
+                let mut padded = [0; 12];
+                padded[..NONCE_LEN].copy_from_slice(&nonce.to_be_bytes());
+
+                let tag = key.seal_in_place_separate_tag(
+                    Nonce::assume_unique_for_key(padded),
+                    Aad::from(&*header),
+                    payload,
+                )?;
+                packet.extend_from_slice(tag.as_ref());
+            }
+            Self::XChaCha20Poly1305(key) => {
+                let mut padded = [0; 24];
+                padded[..NONCE_LEN].copy_from_slice(&nonce.to_be_bytes());
+
+                let tag = key
+                    .encrypt_in_place_detached(XNonce::from_slice(&padded), header, payload)
+                    .map_err(|_| Unspecified)?;
+                packet.extend_from_slice(&tag);
+            }
+        }
+
+        packet.extend_from_slice(&nonce.to_be_bytes());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cipher, EncryptionMode, KEY_LEN, NONCE_LEN};
+    use chacha20poly1305::{aead::AeadInPlace, Key, KeyInit, Tag, XChaCha20Poly1305, XNonce};
+    use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
+    use serde::{Deserialize, Serialize};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        EncryptionMode: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    const HEADER: [u8; 12] = [0x80, 0x78, 0, 1, 0, 0, 3, 0xc0, 0, 0, 0, 7];
+    const KEY: [u8; KEY_LEN] = [7; KEY_LEN];
+    const PAYLOAD: &[u8] = b"opus frame";
+
+    #[test]
+    fn select() {
+        let offered = |names: &[&str]| names.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert_eq!(
+            Some(EncryptionMode::Aes256Gcm),
+            EncryptionMode::select(&offered(&[
+                "aead_xchacha20_poly1305_rtpsize",
+                "aead_aes256_gcm_rtpsize",
+            ])),
+        );
+        assert_eq!(
+            Some(EncryptionMode::XChaCha20Poly1305),
+            EncryptionMode::select(&offered(&[
+                "xsalsa20_poly1305",
+                "aead_xchacha20_poly1305_rtpsize",
+            ])),
+        );
+        assert_eq!(
+            None,
+            EncryptionMode::select(&offered(&["xsalsa20_poly1305_lite"]))
+        );
+    }
+
+    /// Split an encrypted packet into its header, sealed payload, and nonce.
+    fn split(packet: &[u8]) -> (&[u8], Vec<u8>, [u8; NONCE_LEN]) {
+        let (rest, nonce) = packet.split_at(packet.len() - NONCE_LEN);
+
+        (
+            &rest[..HEADER.len()],
+            rest[HEADER.len()..].to_vec(),
+            nonce.try_into().unwrap(),
+        )
+    }
+
+    fn encrypt(mode: EncryptionMode, nonce: u32) -> Vec<u8> {
+        let mut packet = [HEADER.as_slice(), PAYLOAD].concat();
+        Cipher::new(mode, KEY)
+            .encrypt(&mut packet, HEADER.len(), nonce)
+            .unwrap();
+
+        packet
+    }
+
+    #[test]
+    fn aes256_gcm() {
+        let packet = encrypt(EncryptionMode::Aes256Gcm, 5);
+        let (header, mut sealed, nonce) = split(&packet);
+        assert_eq!(HEADER, header);
+        assert_eq!([0, 0, 0, 5], nonce);
+        assert_ne!(PAYLOAD, &sealed[..PAYLOAD.len()]);
+
+        let mut padded = [0; 12];
+        padded[..NONCE_LEN].copy_from_slice(&nonce);
+        let key = LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &KEY).unwrap());
+        let opened = key
+            .open_in_place(
+                Nonce::assume_unique_for_key(padded),
+                Aad::from(header),
+                &mut sealed,
+            )
+            .unwrap();
+
+        assert_eq!(PAYLOAD, opened);
+    }
+
+    #[test]
+    fn xchacha20_poly1305() {
+        let packet = encrypt(EncryptionMode::XChaCha20Poly1305, 6);
+        let (header, mut sealed, nonce) = split(&packet);
+        assert_eq!(HEADER, header);
+        assert_eq!([0, 0, 0, 6], nonce);
+
+        let (payload, tag) = sealed.split_at_mut(PAYLOAD.len());
+        let mut padded = [0; 24];
+        padded[..NONCE_LEN].copy_from_slice(&nonce);
+        XChaCha20Poly1305::new(Key::from_slice(&KEY))
+            .decrypt_in_place_detached(
+                XNonce::from_slice(&padded),
+                header,
+                payload,
+                Tag::from_slice(tag),
+            )
+            .unwrap();
+
+        assert_eq!(PAYLOAD, payload);
+    }
+}
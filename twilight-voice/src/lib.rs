@@ -0,0 +1,21 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![warn(
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    missing_docs,
+    unsafe_code
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod connection;
+
+mod crypto;
+mod model;
+mod rtp;
+mod udp;
+
+pub use self::{
+    connection::{Connection, ConnectionInfo},
+    crypto::EncryptionMode,
+};
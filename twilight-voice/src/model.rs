@@ -0,0 +1,244 @@
+//! Payloads sent and received over the voice gateway.
+//!
+//! Refer to [Discord Docs/Voice Connections].
+//!
+//! [Discord Docs/Voice Connections]: https://discord.com/developers/docs/topics/voice-connections
+
+use crate::crypto::{EncryptionMode, KEY_LEN};
+use serde::{Deserialize, Serialize};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Version of the voice gateway.
+pub(crate) const VERSION: u8 = 8;
+
+/// Voice gateway opcodes used by the connection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Opcode {
+    /// Begin a voice connection.
+    Identify = 0,
+    /// Select the voice protocol.
+    SelectProtocol = 1,
+    /// Complete the websocket handshake.
+    Ready = 2,
+    /// Keep the websocket connection alive.
+    Heartbeat = 3,
+    /// Describe the session.
+    SessionDescription = 4,
+    /// Indicate which users are speaking.
+    Speaking = 5,
+    /// Acknowledge a received heartbeat.
+    HeartbeatAck = 6,
+    /// Time to wait between sending heartbeats.
+    Hello = 8,
+}
+
+impl Opcode {
+    /// Opcode from its raw value, if it's used by the connection.
+    pub const fn from(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => Self::Identify,
+            1 => Self::SelectProtocol,
+            2 => Self::Ready,
+            3 => Self::Heartbeat,
+            4 => Self::SessionDescription,
+            5 => Self::Speaking,
+            6 => Self::HeartbeatAck,
+            8 => Self::Hello,
+            _ => return None,
+        })
+    }
+}
+
+/// Opcode and sequence of a received payload, used to determine how to
+/// deserialize its data.
+#[derive(Debug, Deserialize)]
+pub(crate) struct MinimalEvent {
+    pub op: u8,
+    #[serde(default)]
+    pub seq: Option<u64>,
+}
+
+/// Data of a received payload.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Event<T> {
+    pub d: T,
+}
+
+/// Payload sent to the voice gateway.
+#[derive(Debug, Serialize)]
+pub(crate) struct Command<T> {
+    pub d: T,
+    pub op: u8,
+}
+
+impl<T> Command<T> {
+    pub const fn new(op: Opcode, d: T) -> Self {
+        Self { d, op: op as u8 }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Hello {
+    /// Interval in milliseconds.
+    pub heartbeat_interval: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Heartbeat {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_ack: Option<u64>,
+    pub t: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Identify<'a> {
+    pub server_id: Id<GuildMarker>,
+    pub session_id: &'a str,
+    pub token: &'a str,
+    pub user_id: Id<UserMarker>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Ready {
+    pub ip: String,
+    pub modes: Vec<String>,
+    pub port: u16,
+    pub ssrc: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SelectProtocol {
+    pub data: SelectProtocolData,
+    pub protocol: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct SelectProtocolData {
+    pub address: String,
+    pub mode: EncryptionMode,
+    pub port: u16,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SessionDescription {
+    pub mode: EncryptionMode,
+    pub secret_key: [u8; KEY_LEN],
+}
+
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Serialize)]
+pub(crate) struct Speaking {
+    pub delay: u32,
+    /// Bitflags of how the user is speaking; `1` for the microphone.
+    pub speaking: u8,
+    pub ssrc: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Command, Event, Heartbeat, Identify, MinimalEvent, Opcode, Ready, SelectProtocol,
+        SelectProtocolData, SessionDescription,
+    };
+    use crate::EncryptionMode;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn identify() {
+        let command = Command::new(
+            Opcode::Identify,
+            Identify {
+                server_id: Id::new(1),
+                session_id: "session",
+                token: "token",
+                user_id: Id::new(2),
+            },
+        );
+
+        assert_eq!(
+            r#"{"d":{"server_id":"1","session_id":"session","token":"token","user_id":"2"},"op":0}"#,
+            serde_json::to_string(&command).unwrap(),
+        );
+    }
+
+    #[test]
+    fn heartbeat() {
+        let command = Command::new(
+            Opcode::Heartbeat,
+            Heartbeat {
+                seq_ack: Some(10),
+                t: 1_501_184_119_561,
+            },
+        );
+
+        assert_eq!(
+            r#"{"d":{"seq_ack":10,"t":1501184119561},"op":3}"#,
+            serde_json::to_string(&command).unwrap(),
+        );
+    }
+
+    #[test]
+    fn select_protocol() {
+        let command = Command::new(
+            Opcode::SelectProtocol,
+            SelectProtocol {
+                data: SelectProtocolData {
+                    address: "127.0.0.1".to_owned(),
+                    mode: EncryptionMode::Aes256Gcm,
+                    port: 1337,
+                },
+                protocol: "udp",
+            },
+        );
+
+        assert_eq!(
+            r#"{"d":{"data":{"address":"127.0.0.1","mode":"aead_aes256_gcm_rtpsize","port":1337},"protocol":"udp"},"op":1}"#,
+            serde_json::to_string(&command).unwrap(),
+        );
+    }
+
+    #[test]
+    fn ready() {
+        let payload = r#"{
+            "op": 2,
+            "d": {
+                "ssrc": 1,
+                "ip": "127.0.0.1",
+                "port": 1234,
+                "modes": ["aead_aes256_gcm_rtpsize", "aead_xchacha20_poly1305_rtpsize"],
+                "heartbeat_interval": 1
+            }
+        }"#;
+
+        let minimal = serde_json::from_str::<MinimalEvent>(payload).unwrap();
+        assert_eq!(Some(Opcode::Ready), Opcode::from(minimal.op));
+        assert!(minimal.seq.is_none());
+
+        let ready = serde_json::from_str::<Event<Ready>>(payload).unwrap().d;
+        assert_eq!(1, ready.ssrc);
+        assert_eq!("127.0.0.1", ready.ip);
+        assert_eq!(1234, ready.port);
+        assert_eq!(2, ready.modes.len());
+    }
+
+    #[test]
+    fn session_description() {
+        let payload = format!(
+            r#"{{"op":4,"seq":3,"d":{{"mode":"aead_xchacha20_poly1305_rtpsize","secret_key":{:?},"dave_protocol_version":0}}}}"#,
+            [9; 32],
+        );
+
+        let minimal = serde_json::from_str::<MinimalEvent>(&payload).unwrap();
+        assert_eq!(Some(Opcode::SessionDescription), Opcode::from(minimal.op));
+        assert_eq!(Some(3), minimal.seq);
+
+        let description = serde_json::from_str::<Event<SessionDescription>>(&payload)
+            .unwrap()
+            .d;
+        assert_eq!(EncryptionMode::XChaCha20Poly1305, description.mode);
+        assert_eq!([9; 32], description.secret_key);
+    }
+}
@@ -0,0 +1,96 @@
+//! RTP packetization of Opus frames.
+
+/// Length of the RTP header.
+pub(crate) const HEADER_LEN: usize = 12;
+
+/// RTP version 2 without padding, extensions, or contributing sources.
+const VERSION: u8 = 0x80;
+
+/// Dynamic payload type Discord uses for Opus.
+const PAYLOAD_TYPE: u8 = 0x78;
+
+/// Number of samples in a 20 millisecond frame at 48 kHz.
+pub(crate) const SAMPLES_PER_FRAME: u32 = 960;
+
+/// State of the RTP stream of a connection.
+///
+/// The sequence, timestamp, and nonce wrap on overflow.
+#[derive(Debug)]
+pub(crate) struct Packetizer {
+    /// Nonce of the next packet.
+    nonce: u32,
+    /// Sequence of the next packet.
+    sequence: u16,
+    /// Synchronization source assigned by the voice server.
+    ssrc: u32,
+    /// Timestamp of the next packet in samples.
+    timestamp: u32,
+}
+
+impl Packetizer {
+    /// Create a new packetizer for a synchronization source.
+    pub const fn new(ssrc: u32) -> Self {
+        Self {
+            nonce: 0,
+            sequence: 0,
+            ssrc,
+            timestamp: 0,
+        }
+    }
+
+    /// Write the header of the next packet to a buffer, returning the nonce
+    /// to encrypt it with.
+    ///
+    /// The buffer is cleared beforehand.
+    pub fn next(&mut self, buf: &mut Vec<u8>) -> u32 {
+        buf.clear();
+        buf.extend_from_slice(&[VERSION, PAYLOAD_TYPE]);
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.extend_from_slice(&self.timestamp.to_be_bytes());
+        buf.extend_from_slice(&self.ssrc.to_be_bytes());
+
+        let nonce = self.nonce;
+        self.nonce = self.nonce.wrapping_add(1);
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add(SAMPLES_PER_FRAME);
+
+        nonce
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Packetizer, HEADER_LEN};
+
+    #[test]
+    fn next() {
+        let mut packetizer = Packetizer::new(0x0102_0304);
+        let mut buf = Vec::new();
+
+        assert_eq!(0, packetizer.next(&mut buf));
+        assert_eq!([0x80, 0x78, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4], buf.as_slice());
+
+        assert_eq!(1, packetizer.next(&mut buf));
+        assert_eq!(HEADER_LEN, buf.len());
+        assert_eq!(
+            [0x80, 0x78, 0, 1, 0, 0, 0x03, 0xc0, 1, 2, 3, 4],
+            buf.as_slice()
+        );
+    }
+
+    #[test]
+    fn wraps() {
+        let mut packetizer = Packetizer {
+            nonce: u32::MAX,
+            sequence: u16::MAX,
+            ssrc: 1,
+            timestamp: u32::MAX - 100,
+        };
+        let mut buf = Vec::new();
+
+        assert_eq!(u32::MAX, packetizer.next(&mut buf));
+        assert_eq!(0, packetizer.next(&mut buf));
+        assert_eq!([0, 0], buf[2..4]);
+        assert_eq!(859_u32.to_be_bytes(), buf[4..8]);
+    }
+}
@@ -0,0 +1,143 @@
+//! Discovery of the external address of a UDP socket.
+//!
+//! Refer to [Discord Docs/IP Discovery].
+//!
+//! [Discord Docs/IP Discovery]: https://discord.com/developers/docs/topics/voice-connections#ip-discovery
+
+use std::{io, net::IpAddr, str, time::Duration};
+use tokio::{net::UdpSocket, time};
+
+/// Length of discovery packets.
+const PACKET_LEN: usize = 74;
+
+/// Length of discovery packets excluding their type and length.
+const BODY_LEN: u16 = 70;
+
+/// Type of discovery requests.
+const REQUEST: u16 = 1;
+
+/// Type of discovery responses.
+const RESPONSE: u16 = 2;
+
+/// Time to wait for a response before resending the request.
+const RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Number of requests sent before giving up.
+const ATTEMPTS: u8 = 5;
+
+/// Discover the external address and port of a socket connected to a voice
+/// server.
+///
+/// Returns [`io::ErrorKind::TimedOut`] if the voice server doesn't respond.
+pub(crate) async fn discover(socket: &UdpSocket, ssrc: u32) -> io::Result<(IpAddr, u16)> {
+    let request = request(ssrc);
+    let mut buf = [0; PACKET_LEN];
+
+    for _ in 0..ATTEMPTS {
+        socket.send(&request).await?;
+
+        if let Ok(received) = time::timeout(RETRY_AFTER, socket.recv(&mut buf)).await {
+            let len = received?;
+
+            if let Some(address) = parse_response(&buf[..len], ssrc) {
+                return Ok(address);
+            }
+
+            tracing::debug!(len, "received invalid discovery response");
+        }
+    }
+
+    Err(io::ErrorKind::TimedOut.into())
+}
+
+/// Create a discovery request.
+fn request(ssrc: u32) -> [u8; PACKET_LEN] {
+    let mut packet = [0; PACKET_LEN];
+    packet[..2].copy_from_slice(&REQUEST.to_be_bytes());
+    packet[2..4].copy_from_slice(&BODY_LEN.to_be_bytes());
+    packet[4..8].copy_from_slice(&ssrc.to_be_bytes());
+
+    packet
+}
+
+/// Parse a discovery response for a synchronization source.
+fn parse_response(packet: &[u8], ssrc: u32) -> Option<(IpAddr, u16)> {
+    if packet.len() != PACKET_LEN
+        || packet[..2] != RESPONSE.to_be_bytes()
+        || packet[2..4] != BODY_LEN.to_be_bytes()
+        || packet[4..8] != ssrc.to_be_bytes()
+    {
+        return None;
+    }
+
+    let address = &packet[8..72];
+    let end = address.iter().position(|byte| *byte == 0)?;
+    let address = str::from_utf8(&address[..end]).ok()?.parse().ok()?;
+    let port = u16::from_be_bytes([packet[72], packet[73]]);
+
+    Some((address, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{discover, parse_response, request, PACKET_LEN};
+    use std::net::{IpAddr, Ipv4Addr};
+    use tokio::net::UdpSocket;
+
+    fn response(ssrc: u32, address: &str, port: u16) -> [u8; PACKET_LEN] {
+        let mut packet = [0; PACKET_LEN];
+        packet[..4].copy_from_slice(&[0, 2, 0, 70]);
+        packet[4..8].copy_from_slice(&ssrc.to_be_bytes());
+        packet[8..8 + address.len()].copy_from_slice(address.as_bytes());
+        packet[72..].copy_from_slice(&port.to_be_bytes());
+
+        packet
+    }
+
+    #[test]
+    fn request_layout() {
+        let packet = request(0x0a0b_0c0d);
+
+        assert_eq!([0, 1, 0, 70, 0x0a, 0x0b, 0x0c, 0x0d], packet[..8]);
+        assert!(packet[8..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn parse() {
+        let address = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 5));
+
+        assert_eq!(
+            Some((address, 50_000)),
+            parse_response(&response(7, "203.0.113.5", 50_000), 7)
+        );
+        assert!(parse_response(&response(8, "203.0.113.5", 50_000), 7).is_none());
+        assert!(parse_response(&response(7, "not an address", 50_000), 7).is_none());
+        assert!(parse_response(&response(7, "203.0.113.5", 50_000)[..70], 7).is_none());
+    }
+
+    #[tokio::test]
+    async fn discover_roundtrip() -> std::io::Result<()> {
+        let server = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let client = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        client.connect(server.local_addr()?).await?;
+
+        let handle = tokio::spawn(async move {
+            let mut buf = [0; PACKET_LEN];
+            let (_, peer) = server.recv_from(&mut buf).await?;
+            assert_eq!(request(3), buf);
+
+            let address = peer.ip().to_string();
+            server
+                .send_to(&response(3, &address, peer.port()), peer)
+                .await
+        });
+
+        let (address, port) = discover(&client, 3).await?;
+        handle.await??;
+
+        assert_eq!(client.local_addr()?.ip(), address);
+        assert_eq!(client.local_addr()?.port(), port);
+
+        Ok(())
+    }
+}